@@ -1,6 +1,10 @@
 mod types;
 
-use std::{f64::consts::PI, io, mem::forget, time::Instant};
+use std::{
+    f64::consts::PI,
+    io,
+    time::{Duration, Instant},
+};
 use types::{
     Deserialize, Serialize,
     types::{ChoiceOut, MessageIn, MessageOut, StructIn, StructOut},
@@ -67,6 +71,14 @@ const S64_TEST_VALUES: &[i64] = &[
 ];
 
 #[allow(clippy::cast_precision_loss)]
+fn report(label: &str, bytes: usize, duration: Duration) {
+    println!("{label} duration: {duration:?}");
+    println!(
+        "{label} rate: {} bytes/second",
+        (bytes as f64) / duration.as_secs_f64(),
+    );
+}
+
 fn benchmark<T: Serialize, U: Deserialize>(message: &T, iterations: usize) -> io::Result<()> {
     let message_size = message.size();
     let mut buffer = Vec::with_capacity(message_size);
@@ -82,29 +94,60 @@ fn benchmark<T: Serialize, U: Deserialize>(message: &T, iterations: usize) -> io
     let serialization_duration = serialization_instant.elapsed();
 
     println!("Wrote {} bytes.", buffer.len());
-    println!("Serialization duration: {serialization_duration:?}");
-    println!(
-        "Serialization rate: {} bytes/second",
-        (buffer.len() as f64) / serialization_duration.as_secs_f64(),
+    report("Serialization", buffer.len(), serialization_duration);
+
+    // Serialize the same messages again, but directly into the slices of a pre-sized buffer.
+    let mut slice_buffer = vec![0; message_size * iterations];
+
+    let slice_serialization_instant = Instant::now();
+
+    for chunk in slice_buffer.chunks_exact_mut(message_size) {
+        message.serialize_into(chunk)?;
+    }
+
+    let slice_serialization_duration = slice_serialization_instant.elapsed();
+
+    assert!(slice_buffer == buffer);
+    report(
+        "Slice serialization",
+        slice_buffer.len(),
+        slice_serialization_duration,
     );
 
+    // Don't deallocate the messages during the timed loops, since that isn't what the benchmark is
+    // intended to measure. They're freed after each loop so only one batch is in memory at a time.
+    let mut messages = Vec::with_capacity(iterations);
+
     let deserialization_instant = Instant::now();
 
     for i in 0..iterations {
         let offset = message_size * i;
-        let message = U::deserialize(&buffer[offset..offset + message_size])?;
-
-        // Don't deallocate the memory in this loop, since that isn't what the benchmark is
-        // intended to measure.
-        forget(message);
+        messages.push(U::deserialize(&buffer[offset..offset + message_size])?);
     }
 
     let deserialization_duration = deserialization_instant.elapsed();
 
-    println!("Deserialization duration: {deserialization_duration:?}");
-    println!(
-        "Deserialization rate: {} bytes/second",
-        (buffer.len() as f64) / deserialization_duration.as_secs_f64(),
+    messages.clear();
+
+    report("Deserialization", buffer.len(), deserialization_duration);
+
+    let slice_deserialization_instant = Instant::now();
+
+    for i in 0..iterations {
+        let offset = message_size * i;
+        messages.push(U::deserialize_from_slice(
+            &buffer[offset..offset + message_size],
+        )?);
+    }
+
+    let slice_deserialization_duration = slice_deserialization_instant.elapsed();
+
+    messages.clear();
+
+    report(
+        "Slice deserialization",
+        buffer.len(),
+        slice_deserialization_duration,
     );
 
     Ok(())
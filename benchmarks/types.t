@@ -35,3 +35,13 @@ struct Message {
     z: [[Struct]] = 25
     aa: [[Choice]] = 26
 }
+
+struct Point {
+    x: U64 = 0
+    y: S64 = 1
+}
+
+struct Polyline {
+    points: [Point] = 0
+    closed: Bool = 1
+}
@@ -1,7 +1,8 @@
 import { hrtime } from 'process';
-import { Message, Struct } from '../generated/types';
+import { Message, Polyline, Struct } from '../generated/types';
 
 const pathologicalIterations = 5_000;
+const smallIterations = 200_000;
 const massiveStringSize = 500_000_000;
 
 const u64Min = 0n;
@@ -210,3 +211,20 @@ benchmark(
   },
   pathologicalIterations,
 );
+
+console.log();
+console.log('Small message test.');
+
+benchmark(
+  Polyline.atlas,
+  Polyline.serializeWithAtlasUnsafe,
+  Polyline.deserialize,
+  {
+    points: Array.from({ length: 16 }, (_, i) => ({
+      x: BigInt(i),
+      y: BigInt(-i),
+    })),
+    closed: true,
+  },
+  smallIterations,
+);
@@ -0,0 +1,141 @@
+import { deepStrictEqual, rejects } from 'assert';
+import { readdirSync } from 'fs';
+import { join } from 'path';
+import { fileURLToPath, pathToFileURL } from 'url';
+import { StructFromBelow } from '../generated/circular_dependency/dependency/types.js';
+import { StructFromAbove } from '../generated/circular_dependency/types.js';
+import { Bar } from '../generated/comprehensive/types.js';
+import { EmptyStruct } from '../generated/degenerate/types.js';
+import { Request as FlattenedRequest } from '../generated/includes/flattened.js';
+import { Request } from '../generated/includes/types.js';
+import { dispatchGreeter, greeterClient } from '../generated/services/types.js';
+
+// The JavaScript code is derived from the TypeScript code, which is covered thoroughly by the
+// TypeScript integration tests. These tests check that the derived modules load and run as plain
+// ES modules without a TypeScript compiler.
+
+// Import every module in a directory of generated code, and collect the objects which represent
+// types, keyed by their paths.
+async function importTypes(directory) {
+  const root = fileURLToPath(new URL(`../${directory}/`, import.meta.url));
+  const types = new Map();
+
+  for (const file of readdirSync(root, { recursive: true }).sort()) {
+    if (file.endsWith('.js')) {
+      const module = await import(pathToFileURL(join(root, file)).href);
+
+      for (const [name, value] of Object.entries(module)) {
+        if (typeof value === 'object' && typeof value.deserialize === 'function') {
+          types.set(`${file}:${name}`, value);
+        }
+      }
+    }
+  }
+
+  return types;
+}
+
+function assertRoundTrip(type, message) {
+  console.log('Message to be serialized:', message);
+
+  const bytes = type.serialize(message);
+  deepStrictEqual(bytes.byteLength, type.size(message));
+  console.log('Bytes from serialization:', bytes);
+
+  const replica = type.deserialize(bytes);
+  console.log('Message deserialized from those bytes:', replica);
+  deepStrictEqual(replica, message);
+}
+
+console.log('Running circular dependency integration test…\n');
+const fromAbove = {
+  field: 'field',
+  size: 'size',
+  elements: 'elements',
+  fallback: 'fallback',
+};
+assertRoundTrip(StructFromAbove, fromAbove);
+assertRoundTrip(StructFromBelow, { x: fromAbove });
+
+console.log('\nRunning comprehensive integration test…\n');
+assertRoundTrip(Bar, { $field: 'aRequired', aRequired: null });
+assertRoundTrip(Bar, { $field: 'bRequired', bRequired: Math.PI });
+assertRoundTrip(Bar, { $field: 'gRequired', gRequired: 'Hello, 幸福!' });
+assertRoundTrip(Bar, { $field: 'mRequired', mRequired: [0n, -64n, 64n] });
+
+console.log('\nRunning degenerate integration test…\n');
+assertRoundTrip(EmptyStruct, {});
+
+console.log('\nRunning includes integration test…\n');
+const request = {
+  requestId: 'request_id',
+  tenant: { name: 'tenant' },
+  marker: {},
+  body: 'body',
+};
+deepStrictEqual(FlattenedRequest.deserialize(Request.serialize(request)), request);
+
+console.log('\nRunning services integration test…\n');
+const server = {
+  greet(request) {
+    return request.name === ''
+      ? { refusal: 'Who are you?' }
+      : { greeting: `Hello, ${request.name}!` };
+  },
+  async ping(request) {
+    return request;
+  },
+};
+const client = greeterClient((methodIndex, request) =>
+  dispatchGreeter(server, methodIndex, request),
+);
+deepStrictEqual(await client.greet({ name: 'Alice' }), {
+  $field: 'greeting',
+  greeting: 'Hello, Alice!',
+});
+deepStrictEqual(await client.greet({ name: '' }), {
+  $field: 'refusal',
+  refusal: 'Who are you?',
+});
+deepStrictEqual(await client.ping({}), {});
+await rejects(dispatchGreeter(server, 1n, new ArrayBuffer(0)));
+
+console.log('\nRunning every generated module\u2026\n');
+for (const directory of ['generated', 'generated-options']) {
+  const types = await importTypes(directory);
+  console.log(`Loaded ${types.size} types from ${directory}.`);
+
+  // Decode an empty message as each type. This fails unless the type has no required fields, in
+  // which case the result should survive a round trip.
+  for (const type of types.values()) {
+    const message = type.deserialize(new ArrayBuffer(0));
+
+    if (!(message instanceof Error) && type.checkOut(message) === undefined) {
+      assertRoundTrip(type, message);
+    }
+  }
+}
+
+// Read an empty container written for each type as every other type. The reader either accepts it
+// or reports that the types are incompatible, and it always accepts its own type.
+const { deserializeContainer, serializeContainerHeader } = await import(
+  '../generated-options/common.js'
+);
+const containerTypes = [...(await importTypes('generated-options')).values()];
+let compatiblePairs = 0;
+for (const writer of containerTypes) {
+  const header = serializeContainerHeader(writer, true, true);
+
+  for (const reader of containerTypes) {
+    const messages = deserializeContainer(reader, new Blob([header]).stream());
+
+    try {
+      deepStrictEqual(await messages.next(), { done: true, value: undefined });
+      compatiblePairs += 1;
+    } catch (error) {
+      deepStrictEqual(error, new Error('Container holds messages of an incompatible type.'));
+      deepStrictEqual(writer === reader, false);
+    }
+  }
+}
+console.log(`${compatiblePairs} pairs of types were found to be compatible.`);
@@ -10,22 +10,73 @@ const SCHEMA_PATH: &str = "../types/types.t";
 fn main() {
     let out_dir = env::var_os("OUT_DIR").unwrap();
 
-    let output = Command::new("typical")
-        .arg("generate")
-        .arg(SCHEMA_PATH)
-        .arg("--list-schemas")
-        .arg("--rust-file")
-        .arg(Path::new(&out_dir).join("types.rs"))
-        .output()
-        .expect("Failed to run Typical. Is it installed?");
+    // The same schema is compiled five times: once for the standard library (with the
+    // asynchronous functions, builders, `Arbitrary` implementations, and container helpers), once
+    // for `no_std` (also with builders and container helpers), once for the `typical-runtime` crate
+    // (also with container helpers), once with custom types for some of the fields, and once as a
+    // tree of files rather than a single file.
+    for (output_flag, output_name, flags) in [
+        (
+            "--rust-file",
+            "types.rs",
+            &[
+                "--rust-async",
+                "--rust-builders",
+                "--rust-arbitrary",
+                "--rust-containers",
+            ][..],
+        ),
+        (
+            "--rust-file",
+            "types_no_std.rs",
+            &["--rust-no-std", "--rust-builders", "--rust-containers"][..],
+        ),
+        (
+            "--rust-file",
+            "types_runtime.rs",
+            &["--rust-runtime", "--rust-async", "--rust-containers"][..],
+        ),
+        (
+            "--rust-file",
+            "types_overrides.rs",
+            &[
+                "--rust-async",
+                "--rust-builders",
+                "--rust-arbitrary",
+                "--rust-type",
+                "bytes=::bytes::Bytes",
+                "--rust-type",
+                "string=::std::sync::Arc<str>",
+                "--rust-type",
+                "array=::smallvec::SmallVec<[{}; 2]>",
+                "--rust-type",
+                "unknown_fields::types::NewStruct.text=Box<str>",
+            ][..],
+        ),
+        (
+            "--rust-dir",
+            "types_dir",
+            &["--rust-async", "--rust-builders", "--rust-arbitrary"][..],
+        ),
+    ] {
+        let output = Command::new("typical")
+            .arg("generate")
+            .arg(SCHEMA_PATH)
+            .arg("--list-schemas")
+            .arg(output_flag)
+            .arg(Path::new(&out_dir).join(output_name))
+            .args(flags)
+            .output()
+            .expect("Failed to run Typical. Is it installed?");
 
-    stderr().write_all(&output.stderr).unwrap();
+        stderr().write_all(&output.stderr).unwrap();
 
-    assert!(output.status.success());
+        assert!(output.status.success());
 
-    for line in output.stdout.lines().map(Result::unwrap) {
-        if !line.is_empty() {
-            println!("cargo:rerun-if-changed={line}");
+        for line in output.stdout.lines().map(Result::unwrap) {
+            if !line.is_empty() {
+                println!("cargo:rerun-if-changed={line}");
+            }
         }
     }
 }
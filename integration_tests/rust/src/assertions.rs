@@ -1,9 +1,11 @@
-use crate::types::{Deserialize, Serialize};
+use crate::types::{Deserialize, DeserializeAsync, Serialize, SerializeAsync};
 use std::{
     fmt::Debug,
     fs::{OpenOptions, remove_file},
-    io::{self, Error, Write},
+    io::{self, Error, ErrorKind, Write},
     mem::drop,
+    pin::pin,
+    task::{Context, Poll, Waker},
 };
 
 const OMNIFILE_PATH: &str = "/tmp/omnifile-rust";
@@ -12,7 +14,89 @@ pub fn start() {
     drop(remove_file(OMNIFILE_PATH));
 }
 
-pub fn assert_match<T: Debug + Serialize, U: Debug + Deserialize>(
+// The asynchronous functions only ever wait on in-memory buffers here, so we can drive them to
+// completion without an executor.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+// Check that the functions which work directly with byte slices agree with their counterparts
+// which are built on the I/O traits.
+fn assert_slice_agrees<T: Serialize, U: Debug + Deserialize>(
+    actual: &T,
+    buffer: &[u8],
+    expected: &U,
+) -> io::Result<()> {
+    let vec_buffer = actual.serialize_to_vec()?;
+    println!("Bytes from serialization into a vector: {vec_buffer:?}");
+    if vec_buffer != buffer {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    // Leave some room at the end of the slice to check that only the beginning is written.
+    let mut slice_buffer = vec![0xff; buffer.len() + 2];
+    let size = actual.serialize_into(&mut slice_buffer)?;
+    if size != buffer.len() || slice_buffer[..size] != *buffer || slice_buffer[size..] != [0xff; 2]
+    {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    if let Some(too_small) = buffer.len().checked_sub(1) {
+        match actual.serialize_into(&mut slice_buffer[..too_small]) {
+            Err(error) if error.kind() == ErrorKind::WriteZero => {}
+            result => {
+                return Err(Error::other(format!(
+                    "Unexpected result from serialization into a buffer which is too small: \
+                        {result:?}",
+                )));
+            }
+        }
+    }
+
+    let replica = U::deserialize_from_slice(buffer)?;
+    println!("Message deserialized directly from those bytes: {replica:?}");
+
+    if format!("{replica:?}") != format!("{expected:?}") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(())
+}
+
+// Check that the asynchronous functions agree with their blocking counterparts.
+fn assert_async_agrees<T: SerializeAsync, U: Debug + DeserializeAsync>(
+    actual: &T,
+    buffer: &[u8],
+    expected: &U,
+) -> io::Result<()> {
+    let mut async_buffer = Vec::<u8>::new();
+    block_on(actual.serialize_async(&mut async_buffer))?;
+    println!("Bytes from asynchronous serialization: {async_buffer:?}");
+    if async_buffer != buffer {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    let replica = block_on(U::deserialize_async(buffer))?;
+    println!("Message deserialized asynchronously from those bytes: {replica:?}");
+
+    if format!("{replica:?}") != format!("{expected:?}") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(())
+}
+
+pub fn assert_match<
+    T: Debug + Serialize + SerializeAsync,
+    U: Debug + Deserialize + DeserializeAsync,
+>(
     actual: &T,
     expected: &U,
 ) -> io::Result<()> {
@@ -43,11 +127,124 @@ pub fn assert_match<T: Debug + Serialize, U: Debug + Deserialize>(
         return Err(Error::other("Mismatch!"));
     }
 
-    Ok(())
+    assert_slice_agrees(actual, &buffer, expected)?;
+    assert_async_agrees(actual, &buffer, expected)
 }
 
-pub fn assert_round_trip<T: Debug + Serialize + Clone, U: Debug + Deserialize + From<T>>(
+pub fn assert_round_trip<
+    T: Debug + Serialize + SerializeAsync + Clone,
+    U: Debug + Deserialize + DeserializeAsync + From<T>,
+>(
     value: &T,
 ) -> io::Result<()> {
     assert_match(value, &U::from(value.clone()))
 }
+
+// This is like `assert_match`, except the bytes aren't recorded in the omnifile. It's for checking
+// compatibility between types whose encodings aren't expected to be identical across generators.
+pub fn assert_compatible<
+    T: Debug + Serialize + SerializeAsync,
+    U: Debug + Deserialize + DeserializeAsync,
+>(
+    actual: &T,
+    expected: &U,
+) -> io::Result<()> {
+    println!("Message to be serialized: {actual:?}");
+
+    let mut buffer = Vec::<u8>::new();
+    actual.serialize(&mut buffer)?;
+    println!("Bytes from serialization: {buffer:?}");
+
+    let replica = U::deserialize(buffer.as_slice())?;
+    println!("Message deserialized from those bytes: {replica:?}");
+
+    if format!("{replica:?}") != format!("{expected:?}") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    assert_slice_agrees(actual, &buffer, expected)?;
+    assert_async_agrees(actual, &buffer, expected)
+}
+
+// Build a selection of messages with the types generated in the given module, and return each
+// message's encoding along with the debug representation of the message decoded from it.
+macro_rules! round_trips {
+    ($types:ident) => {{
+        use crate::$types::{
+            Deserialize, Serialize, UnknownField,
+            comprehensive::types::{BarIn, BarOut, LocalStructOut},
+            degenerate::types::EmptyStructOut,
+            unknown_fields::types::{NewStructIn, NewStructOut, OldStructIn, OldStructOut},
+        };
+        use std::f64::consts::PI;
+
+        fn round_trip<T: Serialize, U: Deserialize + std::fmt::Debug>(
+            message: &T,
+        ) -> std::io::Result<(Vec<u8>, String)> {
+            let mut buffer = Vec::<u8>::new();
+            message
+                .serialize(&mut buffer)
+                .map_err(std::io::Error::other)?;
+            let replica = U::deserialize(buffer.as_slice()).map_err(std::io::Error::other)?;
+
+            // The functions which work directly with byte slices should agree.
+            let slice_buffer = message.serialize_to_vec().map_err(std::io::Error::other)?;
+            let slice_replica =
+                U::deserialize_from_slice(&slice_buffer).map_err(std::io::Error::other)?;
+            if slice_buffer != buffer || format!("{slice_replica:?}") != format!("{replica:?}") {
+                return Err(std::io::Error::other("Mismatch!"));
+            }
+
+            Ok((buffer, format!("{replica:?}")))
+        }
+
+        vec![
+            round_trip::<_, BarIn>(&BarOut::ARequired)?,
+            round_trip::<_, BarIn>(&BarOut::BRequired(PI))?,
+            round_trip::<_, BarIn>(&BarOut::CRequired(u64::MAX))?,
+            round_trip::<_, BarIn>(&BarOut::DRequired(i64::MIN))?,
+            round_trip::<_, BarIn>(&BarOut::FRequired(vec![0, 42, 255]))?,
+            round_trip::<_, BarIn>(&BarOut::GRequired("Hello, \u{5e78}\u{798f}!".to_owned()))?,
+            round_trip::<_, BarIn>(&BarOut::KRequired(vec![0.0_f64, PI, f64::INFINITY]))?,
+            round_trip::<_, BarIn>(&BarOut::MRequired(vec![i64::MIN, 0, i64::MAX]))?,
+            round_trip::<_, BarIn>(&BarOut::XRequired(vec![vec![], vec![vec![0, 42, 255]]]))?,
+            round_trip::<_, BarIn>(&BarOut::YRequired(vec![vec![
+                String::new(),
+                "=8 bytes".to_owned(),
+            ]]))?,
+            round_trip::<_, BarIn>(&BarOut::ZRequired(vec![vec![LocalStructOut {}]]))?,
+            round_trip::<_, BarIn>(&BarOut::AaRequired(vec![vec![EmptyStructOut {}]]))?,
+            round_trip::<_, BarIn>(&BarOut::LOptional(
+                vec![1, 2, 3],
+                Box::new(BarOut::NAsymmetric(
+                    vec![true, false],
+                    Box::new(BarOut::ERequired(true)),
+                )),
+            ))?,
+            round_trip::<_, NewStructIn>(&NewStructOut {
+                known: "known".to_owned(),
+                unrecognized: 42,
+                text: "text".to_owned(),
+                nothing: Some(()),
+                number: Some(1.5_f64),
+            })?,
+            round_trip::<_, OldStructIn>(&OldStructOut {
+                known: "known".to_owned(),
+                _unknown: vec![
+                    UnknownField {
+                        index: 1,
+                        size_mode: 0b10,
+                        payload: vec![85],
+                    },
+                    UnknownField {
+                        index: 2,
+                        size_mode: 0b11,
+                        payload: b"text".to_vec(),
+                    },
+                ],
+            })?,
+        ]
+    }};
+}
+
+pub(crate) use round_trips;
@@ -0,0 +1,123 @@
+use crate::{
+    assertions::assert_compatible,
+    types::{
+        degenerate::types::{EmptyStructIn, EmptyStructOut},
+        schema_evolution::before::{ExampleStructIn, ExampleStructOut},
+        unknown_fields::types::{NewStructIn, NewStructOut, OldStructIn, OldStructOut},
+    },
+};
+use std::{
+    fmt::Debug,
+    io::{self, Error},
+};
+
+// Check that a message produced by a builder is the same as the one written out by hand.
+fn assert_built(built: &impl Debug, expected: &impl Debug) -> io::Result<()> {
+    println!("Message from the builder: {built:?}");
+
+    if format!("{built:?}") != format!("{expected:?}") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(())
+}
+
+pub fn run() -> io::Result<()> {
+    // A struct without any fields can be built right away.
+    let empty = EmptyStructOut::builder().build();
+    assert_built(&empty, &EmptyStructOut {})?;
+    assert_compatible(&empty, &EmptyStructIn {})?;
+
+    // Optional fields which aren't set are omitted.
+    let new = NewStructOut::builder()
+        .text("text".to_owned())
+        .known("known".to_owned())
+        .unrecognized(42)
+        .with_number(1.5)
+        .build();
+    assert_built(
+        &new,
+        &NewStructOut {
+            known: "known".to_owned(),
+            unrecognized: 42,
+            text: "text".to_owned(),
+            nothing: None,
+            number: Some(1.5_f64),
+        },
+    )?;
+    assert_compatible(
+        &new,
+        &NewStructIn {
+            known: "known".to_owned(),
+            unrecognized: 42,
+            text: "text".to_owned(),
+            nothing: None,
+            number: Some(1.5_f64),
+        },
+    )?;
+
+    // An open struct is built without any unknown fields.
+    let old = OldStructOut::builder().known("known".to_owned()).build();
+    assert_built(
+        &old,
+        &OldStructOut {
+            known: "known".to_owned(),
+            _unknown: vec![],
+        },
+    )?;
+    assert_compatible(
+        &old,
+        &OldStructIn {
+            known: "known".to_owned(),
+            _unknown: vec![],
+        },
+    )?;
+
+    // Asymmetric fields must be set just like required fields.
+    let example = ExampleStructOut::builder()
+        .required_to_required("required_to_required".to_owned())
+        .required_to_asymmetric("required_to_asymmetric".to_owned())
+        .required_to_optional("required_to_optional".to_owned())
+        .required_to_nonexistent("required_to_nonexistent".to_owned())
+        .asymmetric_to_required("asymmetric_to_required".to_owned())
+        .asymmetric_to_asymmetric("asymmetric_to_asymmetric".to_owned())
+        .asymmetric_to_optional("asymmetric_to_optional".to_owned())
+        .asymmetric_to_nonexistent("asymmetric_to_nonexistent".to_owned())
+        .with_optional_to_required("optional_to_required".to_owned())
+        .with_optional_to_asymmetric("optional_to_asymmetric".to_owned())
+        .build();
+    assert_built(
+        &example,
+        &ExampleStructOut {
+            required_to_required: "required_to_required".to_owned(),
+            required_to_asymmetric: "required_to_asymmetric".to_owned(),
+            required_to_optional: "required_to_optional".to_owned(),
+            required_to_nonexistent: "required_to_nonexistent".to_owned(),
+            asymmetric_to_required: "asymmetric_to_required".to_owned(),
+            asymmetric_to_asymmetric: "asymmetric_to_asymmetric".to_owned(),
+            asymmetric_to_optional: "asymmetric_to_optional".to_owned(),
+            asymmetric_to_nonexistent: "asymmetric_to_nonexistent".to_owned(),
+            optional_to_required: Some("optional_to_required".to_owned()),
+            optional_to_asymmetric: Some("optional_to_asymmetric".to_owned()),
+            optional_to_optional: None,
+            optional_to_nonexistent: None,
+        },
+    )?;
+    assert_compatible(
+        &example,
+        &ExampleStructIn {
+            required_to_required: "required_to_required".to_owned(),
+            required_to_asymmetric: "required_to_asymmetric".to_owned(),
+            required_to_optional: "required_to_optional".to_owned(),
+            required_to_nonexistent: "required_to_nonexistent".to_owned(),
+            asymmetric_to_required: Some("asymmetric_to_required".to_owned()),
+            asymmetric_to_asymmetric: Some("asymmetric_to_asymmetric".to_owned()),
+            asymmetric_to_optional: Some("asymmetric_to_optional".to_owned()),
+            asymmetric_to_nonexistent: Some("asymmetric_to_nonexistent".to_owned()),
+            optional_to_required: Some("optional_to_required".to_owned()),
+            optional_to_asymmetric: Some("optional_to_asymmetric".to_owned()),
+            optional_to_optional: None,
+            optional_to_nonexistent: None,
+        },
+    )
+}
@@ -1,11 +1,15 @@
 use crate::{
-    assertions::assert_round_trip,
+    assertions::{assert_round_trip, block_on},
     types::{
+        Deserialize, DeserializeAsync,
         comprehensive::types::{BarIn, BarOut, FooIn, FooOut, LocalStructOut},
         degenerate::types::EmptyStructOut,
     },
 };
-use std::{f64::consts::PI, io};
+use std::{
+    f64::consts::PI,
+    io::{self, Error},
+};
 
 const F64_TEST_VALUES: &[f64] = &[
     0.0,
@@ -1626,5 +1630,15 @@ pub fn run() -> io::Result<()> {
         Box::new(fallback),
     ))?;
 
+    // An array of units which claims an absurd length must be rejected rather than built one
+    // element at a time.
+    let too_long = [0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40];
+    if BarIn::deserialize(&too_long[..]).is_ok()
+        || BarIn::deserialize_from_slice(&too_long).is_ok()
+        || block_on(BarIn::deserialize_async(&too_long[..])).is_ok()
+    {
+        return Err(Error::other("An overly long array of units was accepted."));
+    }
+
     Ok(())
 }
@@ -0,0 +1,169 @@
+use crate::{types, types_no_std, types_runtime};
+use std::{
+    env, fs,
+    io::{self, Error},
+    process::Command,
+};
+
+// Check that containers can be read with a different version of their type, as long as the changes
+// are safe.
+fn schema_evolution() -> io::Result<()> {
+    // A container written with an older version of a type should be readable with a newer one, as
+    // long as the changes are safe.
+    let mut bytes = Vec::<u8>::new();
+    types::write_container_header::<types::schema_evolution::before::ExampleChoiceOut, _>(
+        &mut bytes, true, false,
+    )?;
+    for message in [
+        types::schema_evolution::before::ExampleChoiceOut::RequiredToRequired("Hello".to_owned()),
+        types::schema_evolution::before::ExampleChoiceOut::AsymmetricToNonexistent(
+            "Hello".to_owned(),
+            Box::new(
+                types::schema_evolution::before::ExampleChoiceOut::RequiredToRequired(
+                    "World".to_owned(),
+                ),
+            ),
+        ),
+    ] {
+        types::write_framed(&message, &mut bytes, true)?;
+    }
+    let replicas = types::read_container::<types::schema_evolution::after::ExampleChoiceIn, _>(
+        bytes.as_slice(),
+    )?
+    .collect::<io::Result<Vec<_>>>()?;
+    println!("Messages read with the newer version of the type: {replicas:?}");
+    if !matches!(
+        replicas.as_slice(),
+        [
+            types::schema_evolution::after::ExampleChoiceIn::RequiredToRequired(first),
+            types::schema_evolution::after::ExampleChoiceIn::RequiredToRequired(second),
+        ] if first == "Hello" && second == "World",
+    ) {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    // A container written with an older version of a type shouldn't be readable with a newer one
+    // if the changes aren't safe, e.g., if an optional field was made required.
+    let mut bytes = Vec::<u8>::new();
+    types::write_container_header::<types::schema_evolution::before::ExampleStructOut, _>(
+        &mut bytes, true, false,
+    )?;
+    if types::read_container::<types::schema_evolution::after::ExampleStructIn, _>(bytes.as_slice())
+        .is_ok()
+    {
+        return Err(Error::other("Incompatible type was not detected!"));
+    }
+
+    Ok(())
+}
+
+pub fn run() -> io::Result<()> {
+    let messages = [
+        types::comprehensive::types::BarOut::ARequired,
+        types::comprehensive::types::BarOut::CRequired(42),
+        types::comprehensive::types::BarOut::GRequired("Hello!".to_owned()),
+        types::comprehensive::types::BarOut::LRequired(vec![0, 1, u64::MAX]),
+    ];
+
+    for checksum in [false, true] {
+        for embed_schema in [false, true] {
+            // Write a container with the self-contained code.
+            let mut bytes = Vec::<u8>::new();
+            types::write_container_header::<types::comprehensive::types::BarOut, _>(
+                &mut bytes,
+                checksum,
+                embed_schema,
+            )?;
+            for message in &messages {
+                types::write_framed(message, &mut bytes, checksum)?;
+            }
+            println!(
+                "Container (checksum: {checksum}, embedded schema: {embed_schema}): {} bytes",
+                bytes.len(),
+            );
+
+            // The header should be the same when written with the runtime crate.
+            let mut runtime_bytes = Vec::<u8>::new();
+            types_runtime::write_container_header::<types_runtime::comprehensive::types::BarOut, _>(
+                &mut runtime_bytes,
+                checksum,
+                embed_schema,
+            )?;
+            if !bytes.starts_with(&runtime_bytes) {
+                return Err(Error::other("Mismatch!"));
+            }
+
+            // Read the messages back with each copy of the generated code.
+            let replicas =
+                types::read_container::<types::comprehensive::types::BarIn, _>(bytes.as_slice())?
+                    .collect::<io::Result<Vec<_>>>()?;
+            let runtime_replicas = types_runtime::read_container::<
+                types_runtime::comprehensive::types::BarIn,
+                _,
+            >(bytes.as_slice())?
+            .collect::<io::Result<Vec<_>>>()?;
+            let no_std_replicas = types_no_std::read_container::<
+                types_no_std::comprehensive::types::BarIn,
+                _,
+            >(bytes.as_slice())
+            .map_err(Error::other)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::other)?;
+            println!("Messages read from the container: {replicas:?}");
+            if replicas.len() != messages.len()
+                || format!("{replicas:?}") != format!("{runtime_replicas:?}")
+                || format!("{replicas:?}") != format!("{no_std_replicas:?}")
+            {
+                return Err(Error::other("Mismatch!"));
+            }
+
+            // Reading the container as a different type should fail.
+            if types::read_container::<types::comprehensive::types::FooIn, _>(bytes.as_slice())
+                .is_ok()
+            {
+                return Err(Error::other("Fingerprint mismatch was not detected!"));
+            }
+        }
+    }
+
+    schema_evolution()?;
+
+    // The CLI should be able to decode a container with an embedded schema.
+    let mut bytes = Vec::<u8>::new();
+    types::write_container_header::<types::comprehensive::types::BarOut, _>(
+        &mut bytes, true, true,
+    )?;
+    for message in &messages {
+        types::write_framed(message, &mut bytes, true)?;
+    }
+    let path = env::temp_dir().join("typical-integration-test-container.bin");
+    fs::write(&path, &bytes)?;
+    let output = Command::new("typical").arg("decode").arg(&path).output();
+    fs::remove_file(&path)?;
+    let output = output?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("Messages decoded by the CLI:\n{stdout}");
+    if !output.status.success() || stdout.lines().count() != messages.len() {
+        return Err(Error::other("Failed to decode the container!"));
+    }
+
+    // The CLI should print the same fingerprint as the one in the generated code.
+    let output = Command::new("typical")
+        .arg("fingerprint")
+        .arg("../types/types.t")
+        .output()?;
+    let expected_line = format!(
+        "{:016x} comprehensive::types::Bar",
+        <types::comprehensive::types::BarOut as types::SchemaType>::FINGERPRINT,
+    );
+    if !output.status.success()
+        || !String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .any(|line| line == expected_line)
+    {
+        return Err(Error::other("Fingerprint mismatch!"));
+    }
+    println!("Fingerprint printed by the CLI: {expected_line}");
+
+    Ok(())
+}
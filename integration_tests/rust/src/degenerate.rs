@@ -1,8 +1,11 @@
 use crate::{
-    assertions::assert_round_trip,
-    types::degenerate::types::{EmptyChoiceIn, EmptyChoiceOut, EmptyStructIn, EmptyStructOut},
+    assertions::{assert_round_trip, block_on},
+    types::{
+        Deserialize, DeserializeAsync,
+        degenerate::types::{EmptyChoiceIn, EmptyChoiceOut, EmptyStructIn, EmptyStructOut},
+    },
 };
-use std::io;
+use std::io::{self, Error};
 
 #[allow(clippy::needless_pass_by_value)]
 fn _initial_in<T>(x: EmptyChoiceIn) -> T {
@@ -25,5 +28,15 @@ fn _terminal_out<T>(_: T) -> EmptyStructOut {
 pub fn run() -> io::Result<()> {
     assert_round_trip::<EmptyStructOut, EmptyStructIn>(&EmptyStructOut {})?;
 
+    // An unknown field which claims to be longer than the rest of the message must be rejected
+    // rather than skipped forever.
+    let truncated = [0x0a, 0x0a, 0x0a];
+    if EmptyStructIn::deserialize(&truncated[..]).is_ok()
+        || EmptyStructIn::deserialize_from_slice(&truncated).is_ok()
+        || block_on(EmptyStructIn::deserialize_async(&truncated[..])).is_ok()
+    {
+        return Err(Error::other("A truncated message was accepted."));
+    }
+
     Ok(())
 }
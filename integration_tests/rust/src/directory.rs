@@ -0,0 +1,20 @@
+use crate::assertions::round_trips;
+use std::io::{self, Error};
+
+pub fn run() -> io::Result<()> {
+    // The code generated as a tree of files should agree with the code generated as a single file.
+    let expected = round_trips!(types);
+    let actual = round_trips!(types_dir);
+    for ((expected_bytes, expected_replica), (actual_bytes, actual_replica)) in
+        expected.iter().zip(actual.iter())
+    {
+        println!("Bytes from the single file: {expected_bytes:?}");
+        println!("Bytes from the tree of files: {actual_bytes:?}");
+        println!("Message deserialized with the tree of files: {actual_replica:?}");
+        if actual_bytes != expected_bytes || actual_replica != expected_replica {
+            return Err(Error::other("Mismatch!"));
+        }
+    }
+
+    Ok(())
+}
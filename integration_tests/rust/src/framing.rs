@@ -0,0 +1,90 @@
+use crate::{types, types_no_std, types_runtime};
+use std::io::{self, BufReader, Error};
+
+pub fn run() -> io::Result<()> {
+    for checksum in [false, true] {
+        // Write a stream of frames with the self-contained code.
+        let messages = [
+            types::comprehensive::types::BarOut::ARequired,
+            types::comprehensive::types::BarOut::CRequired(42),
+            types::comprehensive::types::BarOut::GRequired("Hello!".to_owned()),
+            types::comprehensive::types::BarOut::LRequired(vec![0, 1, u64::MAX]),
+        ];
+        let mut bytes = Vec::<u8>::new();
+        for message in &messages {
+            types::write_framed(message, &mut bytes, checksum)?;
+        }
+        println!("Frames (checksum: {checksum}): {bytes:?}");
+
+        // The frames should be the same when written with the runtime crate.
+        let mut runtime_bytes = Vec::<u8>::new();
+        for message in [
+            types_runtime::comprehensive::types::BarOut::ARequired,
+            types_runtime::comprehensive::types::BarOut::CRequired(42),
+            types_runtime::comprehensive::types::BarOut::GRequired("Hello!".to_owned()),
+            types_runtime::comprehensive::types::BarOut::LRequired(vec![0, 1, u64::MAX]),
+        ] {
+            types_runtime::write_framed(&message, &mut runtime_bytes, checksum)?;
+        }
+        if runtime_bytes != bytes {
+            return Err(Error::other("Mismatch!"));
+        }
+
+        // Read the frames back through a tiny buffer, so frames span several reads.
+        let replicas = types::read_framed::<types::comprehensive::types::BarIn, _>(
+            BufReader::with_capacity(3, bytes.as_slice()),
+            checksum,
+        )
+        .collect::<io::Result<Vec<_>>>()?;
+        let runtime_replicas = types_runtime::read_framed::<
+            types_runtime::comprehensive::types::BarIn,
+            _,
+        >(bytes.as_slice(), checksum)
+        .collect::<io::Result<Vec<_>>>()?;
+        let no_std_replicas = types_no_std::read_framed::<
+            types_no_std::comprehensive::types::BarIn,
+            _,
+        >(bytes.as_slice(), checksum)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::other)?;
+        println!("Messages read from the frames: {replicas:?}");
+        if replicas.len() != messages.len()
+            || format!("{replicas:?}") != format!("{runtime_replicas:?}")
+            || format!("{replicas:?}") != format!("{no_std_replicas:?}")
+        {
+            return Err(Error::other("Mismatch!"));
+        }
+
+        // A stream which ends in the middle of a frame should produce an error.
+        let mut reader = types::read_framed::<types::comprehensive::types::BarIn, _>(
+            &bytes[..bytes.len() - 1],
+            checksum,
+        );
+        if reader
+            .by_ref()
+            .take(messages.len() - 1)
+            .any(|result| result.is_err())
+            || !matches!(reader.next(), Some(Err(_)))
+            || reader.next().is_some()
+        {
+            return Err(Error::other("Truncated stream was not detected!"));
+        }
+    }
+
+    // With checksums, a corrupted frame should produce an error.
+    let mut bytes = Vec::<u8>::new();
+    types::write_framed(
+        &types::comprehensive::types::BarOut::GRequired("Hello!".to_owned()),
+        &mut bytes,
+        true,
+    )?;
+    bytes[3] ^= 1;
+    if !matches!(
+        types::read_framed::<types::comprehensive::types::BarIn, _>(bytes.as_slice(), true).next(),
+        Some(Err(_)),
+    ) {
+        return Err(Error::other("Corrupted frame was not detected!"));
+    }
+
+    Ok(())
+}
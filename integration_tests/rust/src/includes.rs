@@ -0,0 +1,55 @@
+use crate::{
+    assertions::assert_compatible,
+    types::{
+        degenerate::types::{EmptyStructIn, EmptyStructOut},
+        includes::{
+            flattened,
+            header::{TenantIn, TenantOut},
+            types,
+        },
+    },
+};
+use std::io;
+
+pub fn run() -> io::Result<()> {
+    // A struct with an include should be interchangeable with the equivalent flattened struct.
+    assert_compatible(
+        &types::RequestOut {
+            request_id: "request_id".to_owned(),
+            tenant: TenantOut {
+                name: "tenant".to_owned(),
+            },
+            marker: Some(EmptyStructOut {}),
+            body: "body".to_owned(),
+        },
+        &flattened::RequestIn {
+            body: "body".to_owned(),
+            request_id: "request_id".to_owned(),
+            tenant: TenantIn {
+                name: "tenant".to_owned(),
+            },
+            marker: Some(EmptyStructIn {}),
+        },
+    )?;
+
+    println!();
+
+    assert_compatible(
+        &flattened::RequestOut {
+            body: "body".to_owned(),
+            request_id: "request_id".to_owned(),
+            tenant: TenantOut {
+                name: "tenant".to_owned(),
+            },
+            marker: None,
+        },
+        &types::RequestIn {
+            request_id: "request_id".to_owned(),
+            tenant: TenantIn {
+                name: "tenant".to_owned(),
+            },
+            marker: None,
+            body: "body".to_owned(),
+        },
+    )
+}
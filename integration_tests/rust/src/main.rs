@@ -1,24 +1,70 @@
+extern crate alloc;
+
 mod assertions;
+mod builders;
 mod circular_dependency;
 mod comprehensive;
+mod container;
 mod degenerate;
+mod directory;
+mod framing;
+mod includes;
+mod no_std;
+mod overrides;
+mod runtime;
 mod schema_evolution;
+mod services;
 mod types;
+mod types_dir;
+mod types_no_std;
+mod types_overrides;
+mod types_runtime;
+mod unknown_fields;
 
 use std::io;
 
 fn main() -> io::Result<()> {
     assertions::start();
 
-    println!("Running circular dependency integration test\u{2026}\n");
+    println!("Running builders integration test\u{2026}\n");
+    builders::run()?;
+
+    println!("\nRunning circular dependency integration test\u{2026}\n");
     circular_dependency::run()?;
 
     println!("\nRunning comprehensive integration test\u{2026}\n");
     comprehensive::run()?;
 
+    println!("\nRunning container integration test\u{2026}\n");
+    container::run()?;
+
     println!("\nRunning degenerate integration test\u{2026}\n");
     degenerate::run()?;
 
+    println!("\nRunning directory integration test\u{2026}\n");
+    directory::run()?;
+
+    println!("\nRunning framing integration test\u{2026}\n");
+    framing::run()?;
+
+    println!("\nRunning includes integration test\u{2026}\n");
+    includes::run()?;
+
+    println!("\nRunning no_std integration test\u{2026}\n");
+    no_std::run()?;
+
+    println!("\nRunning overrides integration test\u{2026}\n");
+    overrides::run()?;
+
+    println!("\nRunning runtime integration test\u{2026}\n");
+    runtime::run()?;
+
     println!("\nRunning schema evolution integration test\u{2026}\n");
-    schema_evolution::run()
+    schema_evolution::run()?;
+
+    println!("\nRunning services integration test\u{2026}\n");
+    services::run()?;
+
+    println!("\nRunning unknown fields integration test\u{2026}\n");
+    unknown_fields::run()
 }
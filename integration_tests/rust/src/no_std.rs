@@ -0,0 +1,53 @@
+use crate::{
+    assertions::round_trips,
+    types_no_std::{self, Serialize as _},
+};
+use std::io::{self, Error};
+
+pub fn run() -> io::Result<()> {
+    // The code generated for `no_std` should agree with the code generated for the standard
+    // library.
+    let expected = round_trips!(types);
+    let actual = round_trips!(types_no_std);
+    for ((expected_bytes, expected_replica), (actual_bytes, actual_replica)) in
+        expected.iter().zip(actual.iter())
+    {
+        println!("Bytes from the standard library: {expected_bytes:?}");
+        println!("Bytes from no_std: {actual_bytes:?}");
+        println!("Message deserialized with no_std: {actual_replica:?}");
+        if actual_bytes != expected_bytes || actual_replica != expected_replica {
+            return Err(Error::other("Mismatch!"));
+        }
+    }
+
+    // Messages can also be serialized into fixed-size buffers, as long as they fit.
+    let message = types_no_std::comprehensive::types::BarOut::GRequired("Hello!".to_owned());
+    let size = message.size();
+    let mut buffer = [0; 64];
+    message
+        .serialize(&mut buffer[..size])
+        .map_err(Error::other)?;
+    let mut vec_buffer = Vec::<u8>::new();
+    message.serialize(&mut vec_buffer).map_err(Error::other)?;
+    println!(
+        "Bytes from serialization into a slice: {:?}",
+        &buffer[..size]
+    );
+    if buffer[..size] != vec_buffer[..] {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    match message.serialize(&mut buffer[..size - 1]) {
+        Err(error) if error.kind() == types_no_std::io::ErrorKind::WriteZero => {
+            println!("Serialization into a buffer which is too small failed as expected.");
+        }
+        result => {
+            return Err(Error::other(format!(
+                "Unexpected result from serialization into a buffer which is too small: \
+                    {result:?}",
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use crate::{
+    types,
+    types_overrides::{
+        BytesType, Deserialize, Serialize,
+        comprehensive::types::{BarIn, BarOut},
+        unknown_fields::types::{NewStructIn, NewStructOut},
+    },
+};
+use bytes::Bytes;
+use smallvec::{SmallVec, smallvec};
+use std::{
+    fmt::Debug,
+    io::{self, Error},
+    sync::Arc,
+};
+
+// The generated traits are local to the generated code, so the custom types have to be connected to
+// them here. The `typical-runtime` crate implements its own copies of these traits behind the
+// `bytes` and `smallvec` features.
+impl BytesType for Bytes {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes.into()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+impl<A: smallvec::Array> crate::types_overrides::ArrayType<A::Item> for SmallVec<A> {
+    fn from_vec(elements: Vec<A::Item>) -> Self {
+        Self::from_vec(elements)
+    }
+
+    fn into_vec(self) -> Vec<A::Item> {
+        self.into_vec()
+    }
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+}
+
+// Check that a message with custom types is encoded just like the same message with the default
+// types, and that it's decoded back into the expected message.
+fn assert_same_encoding<T: Serialize, U: Deserialize + Debug, V: types::Serialize>(
+    message: &T,
+    expected_replica: &U,
+    default_message: &V,
+) -> io::Result<()> {
+    let bytes = message.serialize_to_vec()?;
+    let default_bytes = default_message.serialize_to_vec()?;
+    println!("Bytes with the custom types: {bytes:?}");
+    println!("Bytes with the default types: {default_bytes:?}");
+    if bytes != default_bytes {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    let replica = U::deserialize(bytes.as_slice())?;
+    let slice_replica = U::deserialize_from_slice(&bytes)?;
+    println!("Message deserialized with the custom types: {replica:?}");
+    if format!("{replica:?}") != format!("{expected_replica:?}")
+        || format!("{slice_replica:?}") != format!("{expected_replica:?}")
+    {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(())
+}
+
+pub fn run() -> io::Result<()> {
+    assert_same_encoding(
+        &BarOut::FRequired(Bytes::from_static(&[0, 42, 255])),
+        &BarIn::FRequired(Bytes::from_static(&[0, 42, 255])),
+        &types::comprehensive::types::BarOut::FRequired(vec![0, 42, 255]),
+    )?;
+
+    assert_same_encoding(
+        &BarOut::GRequired(Arc::from("Hello, \u{5e78}\u{798f}!")),
+        &BarIn::GRequired(Arc::from("Hello, \u{5e78}\u{798f}!")),
+        &types::comprehensive::types::BarOut::GRequired("Hello, \u{5e78}\u{798f}!".to_owned()),
+    )?;
+
+    // The array spills onto the heap, since it has more elements than the inline capacity.
+    assert_same_encoding(
+        &BarOut::MRequired(smallvec![i64::MIN, 0, i64::MAX]),
+        &BarIn::MRequired(smallvec![i64::MIN, 0, i64::MAX]),
+        &types::comprehensive::types::BarOut::MRequired(vec![i64::MIN, 0, i64::MAX]),
+    )?;
+
+    // Only the outermost array of a nested array has the custom type.
+    assert_same_encoding(
+        &BarOut::XRequired(smallvec![vec![], vec![vec![0, 42, 255]]]),
+        &BarIn::XRequired(smallvec![vec![], vec![vec![0, 42, 255]]]),
+        &types::comprehensive::types::BarOut::XRequired(vec![vec![], vec![vec![0, 42, 255]]]),
+    )?;
+
+    // An override for a specific field takes precedence over the override for its type.
+    assert_same_encoding(
+        &NewStructOut {
+            known: Arc::from("known"),
+            unrecognized: 42,
+            text: Box::from("text"),
+            nothing: Some(()),
+            number: Some(1.5_f64),
+        },
+        &NewStructIn {
+            known: Arc::from("known"),
+            unrecognized: 42,
+            text: Box::from("text"),
+            nothing: Some(()),
+            number: Some(1.5_f64),
+        },
+        &types::unknown_fields::types::NewStructOut {
+            known: "known".to_owned(),
+            unrecognized: 42,
+            text: "text".to_owned(),
+            nothing: Some(()),
+            number: Some(1.5_f64),
+        },
+    )
+}
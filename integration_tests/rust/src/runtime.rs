@@ -0,0 +1,45 @@
+use crate::{
+    assertions::{block_on, round_trips},
+    types_runtime::comprehensive::types::{BarIn, BarOut},
+};
+use std::io::{self, Error};
+use typical_runtime::{Deserialize, DeserializeAsync, Serialize, SerializeAsync};
+
+// This function only knows about the traits from the runtime crate, not the generated code.
+fn encode<T: Serialize>(message: &T) -> io::Result<Vec<u8>> {
+    let mut buffer = Vec::<u8>::new();
+    message.serialize(&mut buffer)?;
+    Ok(buffer)
+}
+
+pub fn run() -> io::Result<()> {
+    // The code generated for the runtime crate should agree with the self-contained code.
+    let expected = round_trips!(types);
+    let actual = round_trips!(types_runtime);
+    for ((expected_bytes, expected_replica), (actual_bytes, actual_replica)) in
+        expected.iter().zip(actual.iter())
+    {
+        println!("Bytes from the self-contained code: {expected_bytes:?}");
+        println!("Bytes from the runtime crate: {actual_bytes:?}");
+        println!("Message deserialized with the runtime crate: {actual_replica:?}");
+        if actual_bytes != expected_bytes || actual_replica != expected_replica {
+            return Err(Error::other("Mismatch!"));
+        }
+    }
+
+    // The generated types implement the traits from the runtime crate, so generic code can handle
+    // them.
+    let message = BarOut::GRequired("Hello!".to_owned());
+    let bytes = encode(&message)?;
+    let replica = BarIn::deserialize(bytes.as_slice())?;
+    println!("Message decoded via the runtime crate's traits: {replica:?}");
+
+    let mut async_bytes = Vec::<u8>::new();
+    block_on(message.serialize_async(&mut async_bytes))?;
+    let async_replica = block_on(BarIn::deserialize_async(async_bytes.as_slice()))?;
+    if async_bytes != bytes || format!("{async_replica:?}") != format!("{replica:?}") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(())
+}
@@ -179,5 +179,21 @@ pub fn run() -> io::Result<()> {
         },
     )?;
 
+    println!();
+
+    // Converting from `In` to `Out` fails when an asymmetric field or its fallback is missing.
+    assert!(
+        after::ExampleChoiceOut::try_from(after::ExampleChoiceIn::RequiredToAsymmetric(
+            "required_to_asymmetric".to_owned(),
+        ))
+        .is_err(),
+    );
+
+    let message = after::ExampleChoiceIn::RequiredToRequired("required_to_required".to_owned());
+    assert_match::<after::ExampleChoiceOut, after::ExampleChoiceIn>(
+        &after::ExampleChoiceOut::try_from(message.clone())?,
+        &message,
+    )?;
+
     Ok(())
 }
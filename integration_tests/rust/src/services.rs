@@ -0,0 +1,79 @@
+use crate::types::{
+    Transport,
+    degenerate::types::{EmptyStructIn, EmptyStructOut},
+    services::types::{
+        GreetRequestIn, GreetRequestOut, GreetResponseIn, GreetResponseOut, GreeterClient,
+        GreeterServer, dispatch_greeter,
+    },
+};
+use std::io::{self, Error};
+
+struct Server {
+    pings: usize,
+}
+
+impl GreeterServer for Server {
+    fn greet(&mut self, request: GreetRequestIn) -> io::Result<GreetResponseOut> {
+        if request.name.is_empty() {
+            Ok(GreetResponseOut::Refusal("Who are you?".to_owned()))
+        } else {
+            Ok(GreetResponseOut::Greeting(format!(
+                "Hello, {}!",
+                request.name
+            )))
+        }
+    }
+
+    fn ping(&mut self, _: EmptyStructIn) -> io::Result<EmptyStructOut> {
+        self.pings += 1;
+        Ok(EmptyStructOut {})
+    }
+}
+
+// This transport delivers requests directly to an in-process server.
+struct LoopbackTransport {
+    server: Server,
+}
+
+impl Transport for LoopbackTransport {
+    fn call(&mut self, method_index: u64, request: Vec<u8>) -> io::Result<Vec<u8>> {
+        dispatch_greeter(&mut self.server, method_index, &request)
+    }
+}
+
+pub fn run() -> io::Result<()> {
+    let mut client = GreeterClient {
+        transport: LoopbackTransport {
+            server: Server { pings: 0 },
+        },
+    };
+
+    let response = client.greet(&GreetRequestOut {
+        name: "Alice".to_owned(),
+    })?;
+    println!("Response: {response:?}");
+    if !matches!(&response, GreetResponseIn::Greeting(greeting) if greeting == "Hello, Alice!") {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    let response = client.greet(&GreetRequestOut {
+        name: String::new(),
+    })?;
+    println!("Response: {response:?}");
+    if !matches!(&response, GreetResponseIn::Refusal(_)) {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    client.ping(&EmptyStructOut {})?;
+    println!("Pings: {}", client.transport.server.pings);
+    if client.transport.server.pings != 1 {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    // Deleted and unknown method indices should be rejected.
+    if dispatch_greeter(&mut client.transport.server, 1, &[]).is_ok() {
+        return Err(Error::other("Unknown method index was accepted!"));
+    }
+
+    Ok(())
+}
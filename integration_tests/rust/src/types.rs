@@ -19,6 +19,7 @@
     clippy::type_complexity,
     clippy::unit_arg,
     clippy::unnecessary_wraps,
+    clippy::unused_async,
     clippy::unused_self,
     clippy::used_underscore_binding,
     clippy::useless_conversion,
@@ -0,0 +1,173 @@
+use crate::{
+    assertions::assert_compatible,
+    types::{
+        Deserialize, Serialize, UnknownField,
+        unknown_fields::types::{
+            EmptyOpenChoiceIn, EmptyOpenStructIn, EmptyOpenStructOut, NewChoiceIn, NewChoiceOut,
+            NewStructIn, NewStructOut, OldChoiceIn, OldChoiceOut, OldStructIn, OldStructOut,
+        },
+    },
+};
+use std::io::{self, Error};
+
+// Serialize a message, and check that reserializing it after a round trip through an older reader
+// produces exactly the same bytes. The older reader forwards the message via the generated `From`
+// conversion, as a proxy would.
+fn assert_forwarded_verbatim<T: Serialize, U: Deserialize, V: Serialize + From<U>>(
+    message: &T,
+) -> io::Result<V> {
+    let mut buffer = Vec::<u8>::new();
+    message.serialize(&mut buffer)?;
+    let forwarded = V::from(U::deserialize(buffer.as_slice())?);
+
+    let mut forwarded_buffer = Vec::<u8>::new();
+    forwarded.serialize(&mut forwarded_buffer)?;
+    if forwarded_buffer != buffer {
+        return Err(Error::other("Mismatch!"));
+    }
+
+    Ok(forwarded)
+}
+
+fn new_struct() -> NewStructOut {
+    NewStructOut {
+        known: "known".to_owned(),
+        unrecognized: 42,
+        text: "text".to_owned(),
+        nothing: Some(()),
+        number: Some(1.5),
+    }
+}
+
+// The fields of `new_struct()` which `OldStruct` doesn't recognize, covering every size mode
+fn unknown_fields() -> Vec<UnknownField> {
+    vec![
+        UnknownField {
+            index: 1,
+            size_mode: 0b10,
+            payload: vec![85],
+        },
+        UnknownField {
+            index: 2,
+            size_mode: 0b11,
+            payload: b"text".to_vec(),
+        },
+        UnknownField {
+            index: 3,
+            size_mode: 0b00,
+            payload: vec![],
+        },
+        UnknownField {
+            index: 4,
+            size_mode: 0b01,
+            payload: 1.5_f64.to_le_bytes().to_vec(),
+        },
+    ]
+}
+
+pub fn run() -> io::Result<()> {
+    // Recognized cases are decoded as usual.
+    assert_compatible(
+        &NewChoiceOut::Known("known".to_owned()),
+        &OldChoiceIn::Known("known".to_owned()),
+    )?;
+
+    println!();
+
+    // Unrecognized cases are preserved.
+    assert_compatible(
+        &NewChoiceOut::Unrecognized(42),
+        &OldChoiceIn::Unknown {
+            index: 1,
+            payload: vec![85],
+        },
+    )?;
+
+    println!();
+
+    assert_compatible(
+        &NewChoiceOut::Unrecognized(42),
+        &EmptyOpenChoiceIn::Unknown {
+            index: 1,
+            payload: vec![85],
+        },
+    )?;
+
+    println!();
+
+    // An unrecognized optional case gives way to its fallback, whether or not the fallback is
+    // recognized.
+    assert_compatible(
+        &NewChoiceOut::Preferred(
+            vec![0, 42, 255],
+            Box::new(NewChoiceOut::Known("fallback".to_owned())),
+        ),
+        &OldChoiceIn::Known("fallback".to_owned()),
+    )?;
+
+    println!();
+
+    assert_compatible(
+        &NewChoiceOut::Preferred(vec![0, 42, 255], Box::new(NewChoiceOut::Unrecognized(42))),
+        &OldChoiceIn::Unknown {
+            index: 1,
+            payload: vec![85],
+        },
+    )?;
+
+    println!();
+
+    // Preserved cases survive being forwarded verbatim by an older reader.
+    let forwarded =
+        assert_forwarded_verbatim::<_, OldChoiceIn, OldChoiceOut>(&NewChoiceOut::Unrecognized(42))?;
+    assert_compatible(&forwarded, &NewChoiceIn::Unrecognized(42))?;
+
+    println!();
+
+    // Open structs keep the fields they don't recognize.
+    assert_compatible(
+        &new_struct(),
+        &OldStructIn {
+            known: "known".to_owned(),
+            _unknown: unknown_fields(),
+        },
+    )?;
+
+    println!();
+
+    assert_compatible(
+        &new_struct(),
+        &EmptyOpenStructIn {
+            _unknown: [
+                vec![UnknownField {
+                    index: 0,
+                    size_mode: 0b11,
+                    payload: b"known".to_vec(),
+                }],
+                unknown_fields(),
+            ]
+            .concat(),
+        },
+    )?;
+
+    println!();
+
+    // Preserved fields survive being forwarded verbatim by an older reader.
+    let forwarded = assert_forwarded_verbatim::<_, OldStructIn, OldStructOut>(&new_struct())?;
+    assert_compatible(&forwarded, &NewStructIn::from(new_struct()))?;
+
+    println!();
+
+    let forwarded =
+        assert_forwarded_verbatim::<_, EmptyOpenStructIn, EmptyOpenStructOut>(&new_struct())?;
+    assert_compatible(&forwarded, &NewStructIn::from(new_struct()))?;
+
+    println!();
+
+    // The `From` conversion from `Out` to `In` carries unknown fields along too.
+    let message = OldStructOut {
+        known: "known".to_owned(),
+        _unknown: unknown_fields(),
+    };
+    assert_compatible(&message, &OldStructIn::from(message.clone()))
+}
@@ -0,0 +1,12 @@
+import 'header.t'
+import '../degenerate/types.t'
+
+# This is how `Request` would be written without an include.
+struct Request {
+    body: String = 0
+    request_id: String = 1
+    tenant: header.Tenant = 2
+    optional marker: types.EmptyStruct = 4
+
+    deleted 3
+}
@@ -0,0 +1,14 @@
+import '../degenerate/types.t'
+
+struct Tenant {
+    name: String = 0
+}
+
+# A header shared by several requests
+struct Header {
+    request_id: String = 0
+    tenant: Tenant = 1
+    optional marker: types.EmptyStruct = 3
+
+    deleted 2
+}
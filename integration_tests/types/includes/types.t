@@ -0,0 +1,7 @@
+import 'header.t'
+
+struct Request {
+    include header.Header at 1
+
+    body: String = 0
+}
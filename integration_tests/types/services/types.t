@@ -0,0 +1,21 @@
+import '../degenerate/types.t' { EmptyStruct }
+
+struct GreetRequest {
+    name: String = 0
+}
+
+choice GreetResponse {
+    greeting: String = 0
+    refusal: String = 1
+}
+
+# This service exercises request/response pairing.
+service Greeter {
+    # Greet someone by name.
+    greet: GreetRequest -> GreetResponse = 0
+
+    # Do nothing at all.
+    ping: EmptyStruct -> EmptyStruct = 2
+
+    deleted 1
+}
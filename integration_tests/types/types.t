@@ -3,4 +3,8 @@
 import 'circular_dependency/types.t' as circular_dependency
 import 'comprehensive/types.t' as comprehensive
 import 'degenerate/types.t' as degenerate
+import 'includes/flattened.t'
+import 'includes/types.t' as includes
 import 'schema_evolution/types.t' as schema_evolution
+import 'services/types.t' as services
+import 'unknown_fields/types.t' as unknown_fields
@@ -0,0 +1,33 @@
+# A newer version of the choices below, with cases they don't know about
+choice NewChoice {
+    known: String = 0
+    unrecognized: U64 = 1
+    optional preferred: Bytes = 2
+}
+
+# An older version of `NewChoice` which keeps the cases it doesn't recognize
+open choice OldChoice {
+    known: String = 0
+}
+
+# An open choice doesn't need any cases of its own.
+open choice EmptyOpenChoice {
+}
+
+# A newer version of the structs below, with fields they don't know about
+struct NewStruct {
+    known: String = 0
+    unrecognized: U64 = 1
+    text: String = 2
+    optional nothing = 3
+    optional number: F64 = 4
+}
+
+# An older version of `NewStruct` which keeps the fields it doesn't recognize
+open struct OldStruct {
+    known: String = 0
+}
+
+# An open struct doesn't need any fields of its own.
+open struct EmptyOpenStruct {
+}
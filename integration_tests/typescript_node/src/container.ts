@@ -0,0 +1,158 @@
+import { deepStrictEqual, rejects } from 'assert';
+import {
+  deserializeContainer,
+  schema,
+  serializeContainerHeader,
+  serializeFramed,
+} from '../generated/common';
+import type { Deserializable } from '../generated/common';
+import { Bar, type BarIn, type BarOut, Foo } from '../generated/comprehensive/types';
+import {
+  ExampleChoice as AfterExampleChoice,
+  type ExampleChoiceIn as AfterExampleChoiceIn,
+  ExampleStruct as AfterExampleStruct,
+} from '../generated/schema_evolution/after';
+import {
+  ExampleChoice as BeforeExampleChoice,
+  ExampleStruct as BeforeExampleStruct,
+  type ExampleStructOut as BeforeExampleStructOut,
+} from '../generated/schema_evolution/before';
+
+// The same messages are written to a container by the Rust integration test.
+const messages: BarOut[] = [
+  { aRequired: null },
+  { cRequired: 42n },
+  { gRequired: 'Hello!' },
+  { lRequired: [0n, 1n, 18_446_744_073_709_551_615n] },
+];
+
+// Write a container with the given messages.
+function containerOf<T>(
+  type: {
+    serialize: (message: T) => ArrayBuffer;
+    fingerprint: bigint;
+    normalForm: string;
+    schemaName: string;
+  },
+  typeMessages: T[],
+  checksum: boolean,
+  embedSchema: boolean,
+): Uint8Array {
+  const chunks = [
+    serializeContainerHeader(type, checksum, embedSchema),
+    ...typeMessages.map((message) => serializeFramed(type, message, checksum)),
+  ];
+  const bytes = new Uint8Array(chunks.reduce((size, chunk) => size + chunk.byteLength, 0));
+  let offset = 0;
+  for (const chunk of chunks) {
+    bytes.set(new Uint8Array(chunk), offset);
+    offset += chunk.byteLength;
+  }
+  return bytes;
+}
+
+// Write a container with the messages above.
+function container(checksum: boolean, embedSchema: boolean): Uint8Array {
+  return containerOf(Bar, messages, checksum, embedSchema);
+}
+
+// Make a stream which delivers the bytes in chunks of the given size.
+function stream(bytes: Uint8Array, chunkSize: number): ReadableStream<Uint8Array> {
+  let offset = 0;
+  return new ReadableStream({
+    pull(controller) {
+      if (offset < bytes.byteLength) {
+        controller.enqueue(bytes.slice(offset, offset + chunkSize));
+        offset += chunkSize;
+      } else {
+        controller.close();
+      }
+    },
+  });
+}
+
+async function readAll<T>(
+  type: {
+    deserialize: (bytes: Deserializable) => T | Error;
+    fingerprint: bigint;
+    normalForm: string;
+  },
+  bytes: Uint8Array,
+  chunkSize: number,
+): Promise<T[]> {
+  const replicas = [];
+  for await (const message of deserializeContainer(type, stream(bytes, chunkSize))) {
+    replicas.push(message);
+  }
+  return replicas;
+}
+
+export default async function run(): Promise<void> {
+  const expected: BarIn[] = messages.map((message) => Bar.deserialize(Bar.serialize(message)));
+
+  for (const checksum of [false, true]) {
+    for (const embedSchema of [false, true]) {
+      const bytes = container(checksum, embedSchema);
+      console.log(
+        `Container (checksum: ${String(checksum)}, embedded schema: ${String(embedSchema)}):`,
+        bytes.byteLength,
+        'bytes',
+      );
+
+      // The header should identify the type of the messages.
+      const dataView = new DataView(bytes.buffer);
+      deepStrictEqual(new TextDecoder().decode(bytes.subarray(1, 8)), 'TYPICAL');
+      deepStrictEqual(dataView.getBigUint64(10, true), Bar.fingerprint);
+
+      // The messages should be decoded regardless of how the stream is split into chunks.
+      for (const chunkSize of [1, 7, bytes.byteLength]) {
+        deepStrictEqual(await readAll(Bar, bytes, chunkSize), expected);
+      }
+
+      // Reading the container as a different type should fail.
+      await rejects(
+        readAll(Foo, bytes, 7),
+        new Error('Container holds messages of an incompatible type.'),
+      );
+    }
+  }
+
+  // The embedded schema and the name of the root type should follow the header.
+  const text = new TextDecoder().decode(container(false, true));
+  deepStrictEqual(text.includes(schema), true);
+  deepStrictEqual(text.includes(Bar.schemaName), true);
+  deepStrictEqual(new TextDecoder().decode(container(false, false)).includes(schema), false);
+
+  // A truncated header should produce an error.
+  await rejects(
+    readAll(Bar, container(false, false).slice(0, 17), 7),
+    new Error('Invalid container header.'),
+  );
+  console.log('Invalid containers were rejected.');
+
+  // A container written with an older version of a type should be readable with a newer one, as
+  // long as the changes are safe.
+  const choiceBytes = containerOf(
+    BeforeExampleChoice,
+    [
+      { requiredToRequired: 'Hello' },
+      { asymmetricToNonexistent: 'Hello', $fallback: { requiredToRequired: 'World' } },
+    ],
+    true,
+    false,
+  );
+  const choiceReplicas: AfterExampleChoiceIn[] = [
+    { $field: 'requiredToRequired', requiredToRequired: 'Hello' },
+    { $field: 'requiredToRequired', requiredToRequired: 'World' },
+  ];
+  deepStrictEqual(await readAll(AfterExampleChoice, choiceBytes, 7), choiceReplicas);
+
+  // A container written with an older version of a type shouldn't be readable with a newer one if
+  // the changes aren't safe, e.g., if an optional field was made required.
+  const structMessages: BeforeExampleStructOut[] = [];
+  await rejects(
+    readAll(AfterExampleStruct, containerOf(BeforeExampleStruct, structMessages, true, false), 7),
+    new Error('Container holds messages of an incompatible type.'),
+  );
+  console.log('Containers written with an older version of a type were checked.');
+}
@@ -0,0 +1,87 @@
+import { deepStrictEqual, rejects } from 'assert';
+import { deserializeFramed, serializeFramed } from '../generated/common';
+import { Bar, type BarIn, type BarOut } from '../generated/comprehensive/types';
+
+// The same messages are framed by the Rust integration test.
+const messages: BarOut[] = [
+  { aRequired: null },
+  { cRequired: 42n },
+  { gRequired: 'Hello!' },
+  { lRequired: [0n, 1n, 18_446_744_073_709_551_615n] },
+];
+
+// The frames which the Rust code writes for the messages above
+const expectedFrames = [
+  3, 1, 5, 21, 85, 17, 55, 13, 72, 101, 108, 108, 111, 33, 27, 95, 23, 1, 3, 0, 127, 191, 223, 239,
+  247, 251, 253, 254,
+];
+
+// Concatenate the frames for the messages.
+function frames(outMessages: BarOut[], checksum: boolean): Uint8Array {
+  const chunks = outMessages.map((message) => serializeFramed(Bar, message, checksum));
+  const bytes = new Uint8Array(chunks.reduce((size, chunk) => size + chunk.byteLength, 0));
+  let offset = 0;
+  for (const chunk of chunks) {
+    bytes.set(new Uint8Array(chunk), offset);
+    offset += chunk.byteLength;
+  }
+  return bytes;
+}
+
+// Make a stream which delivers the bytes in chunks of the given size.
+function stream(bytes: Uint8Array, chunkSize: number): ReadableStream<Uint8Array> {
+  let offset = 0;
+  return new ReadableStream({
+    pull(controller) {
+      if (offset < bytes.byteLength) {
+        controller.enqueue(bytes.slice(offset, offset + chunkSize));
+        offset += chunkSize;
+      } else {
+        controller.close();
+      }
+    },
+  });
+}
+
+async function readAll(
+  bytes: Uint8Array,
+  chunkSize: number,
+  checksum: boolean,
+): Promise<BarIn[]> {
+  const replicas = [];
+  for await (const message of deserializeFramed(Bar, stream(bytes, chunkSize), checksum)) {
+    replicas.push(message);
+  }
+  return replicas;
+}
+
+export default async function run(): Promise<void> {
+  // The framing should agree with the Rust code.
+  deepStrictEqual(Array.from(frames(messages, false)), expectedFrames);
+
+  // Include a message which is larger than the initial buffer of the reader.
+  const allMessages = [...messages, { gRequired: 'x'.repeat(5_000) }];
+  const expected = allMessages.map((message) => Bar.deserialize(Bar.serialize(message)));
+
+  for (const checksum of [false, true]) {
+    const bytes = frames(allMessages, checksum);
+    console.log(`Frames (checksum: ${String(checksum)}):`, bytes.byteLength, 'bytes');
+
+    // The frames should be decoded regardless of how the stream is split into chunks.
+    for (const chunkSize of [1, 7, 4_096, bytes.byteLength]) {
+      deepStrictEqual(await readAll(bytes, chunkSize, checksum), expected);
+    }
+
+    // A stream which ends in the middle of a frame should produce an error.
+    await rejects(
+      readAll(bytes.slice(0, bytes.byteLength - 1), 7, checksum),
+      new Error('Stream ended in the middle of a frame.'),
+    );
+  }
+
+  // With checksums, a corrupted frame should produce an error.
+  const corrupted = frames(messages, true);
+  corrupted[1] ^= 1;
+  await rejects(readAll(corrupted, 7, true), new Error('Frame checksum mismatch.'));
+  console.log('Corrupted frames were rejected.');
+}
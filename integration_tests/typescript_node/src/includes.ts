@@ -0,0 +1,37 @@
+import { deepStrictEqual } from 'assert';
+import { Request as FlattenedRequest } from '../generated/includes/flattened';
+import { Request } from '../generated/includes/types';
+
+export default function run(): void {
+  // A struct with an include should be interchangeable with the equivalent flattened struct.
+  const message = {
+    requestId: 'request_id',
+    tenant: { name: 'tenant' },
+    marker: {},
+    body: 'body',
+  };
+  console.log('Message to be serialized:', message);
+
+  const bytes = Request.serialize(message);
+  console.log('Bytes from serialization:', bytes);
+
+  const replica = FlattenedRequest.deserialize(bytes);
+  console.log('Message deserialized from those bytes:', replica);
+  deepStrictEqual(replica, message);
+
+  console.log();
+
+  const flattenedMessage = {
+    body: 'body',
+    requestId: 'request_id',
+    tenant: { name: 'tenant' },
+  };
+  console.log('Message to be serialized:', flattenedMessage);
+
+  const flattenedBytes = FlattenedRequest.serialize(flattenedMessage);
+  console.log('Bytes from serialization:', flattenedBytes);
+
+  const flattenedReplica = Request.deserialize(flattenedBytes);
+  console.log('Message deserialized from those bytes:', flattenedReplica);
+  deepStrictEqual(flattenedReplica, { ...flattenedMessage, marker: undefined });
+}
@@ -1,7 +1,14 @@
 import runCircularDependency from './circular-dependency';
 import runComprehensive from './comprehensive';
+import runContainer from './container';
 import runDegenerate from './degenerate';
+import runFraming from './framing';
+import runIncludes from './includes';
+import runNumbers from './numbers';
 import runSchemaEvolution from './schema-evolution';
+import runServices from './services';
+import runTypeGuards from './type-guards';
+import runUnknownFields from './unknown-fields';
 
 console.log('Running circular dependency integration test\u2026\n');
 runCircularDependency();
@@ -12,5 +19,32 @@ runComprehensive();
 console.log('\nRunning degenerate integration test\u2026\n');
 runDegenerate();
 
+console.log('\nRunning includes integration test\u2026\n');
+runIncludes();
+
+console.log('\nRunning numbers integration test\u2026\n');
+runNumbers();
+
 console.log('\nRunning schema evolution integration test\u2026\n');
 runSchemaEvolution();
+
+console.log('\nRunning services integration test\u2026\n');
+runServices()
+  .then(() => {
+    console.log('\nRunning unknown fields integration test\u2026\n');
+    runUnknownFields();
+
+    console.log('\nRunning type guards integration test\u2026\n');
+    runTypeGuards();
+
+    console.log('\nRunning framing integration test\u2026\n');
+    return runFraming();
+  })
+  .then(() => {
+    console.log('\nRunning container integration test\u2026\n');
+    return runContainer();
+  })
+  .catch((error: unknown) => {
+    console.error(error);
+    process.exitCode = 1;
+  });
@@ -0,0 +1,100 @@
+import { deepStrictEqual, throws } from 'assert';
+import { Bar, type BarIn, type BarOut } from '../generated/comprehensive/types';
+import { Bar as ClampedNumberBar } from '../generated-clamped-numbers/comprehensive/types';
+import {
+  Bar as NumberBar,
+  type BarIn as NumberBarIn,
+  type BarOut as NumberBarOut,
+} from '../generated-numbers/comprehensive/types';
+
+const overflowError = new Error('Integer cannot be represented exactly as a number.');
+
+// Messages with integers which can be represented exactly as numbers, and how they're decoded
+const safeTestCases: [BarOut, NumberBarIn][] = [
+  [{ cRequired: 0n }, { $field: 'cRequired', cRequired: 0 }],
+  [
+    { cRequired: 9_007_199_254_740_991n },
+    { $field: 'cRequired', cRequired: Number.MAX_SAFE_INTEGER },
+  ],
+  [
+    { dRequired: -9_007_199_254_740_991n },
+    { $field: 'dRequired', dRequired: Number.MIN_SAFE_INTEGER },
+  ],
+  [
+    { lRequired: [0n, 9_007_199_254_740_991n] },
+    { $field: 'lRequired', lRequired: [0, Number.MAX_SAFE_INTEGER] },
+  ],
+  [
+    { mRequired: [-9_007_199_254_740_991n, 9_007_199_254_740_991n] },
+    { $field: 'mRequired', mRequired: [Number.MIN_SAFE_INTEGER, Number.MAX_SAFE_INTEGER] },
+  ],
+];
+
+// Messages with integers which can't be represented exactly as numbers, and how they're decoded
+// when the integers are clamped
+const unsafeTestCases: [BarOut, NumberBarIn][] = [
+  [
+    { cRequired: 9_007_199_254_740_992n },
+    { $field: 'cRequired', cRequired: Number.MAX_SAFE_INTEGER },
+  ],
+  [
+    { cRequired: 18_446_744_073_709_551_615n },
+    { $field: 'cRequired', cRequired: Number.MAX_SAFE_INTEGER },
+  ],
+  [
+    { dRequired: -9_007_199_254_740_992n },
+    { $field: 'dRequired', dRequired: Number.MIN_SAFE_INTEGER },
+  ],
+  [
+    { dRequired: 9_223_372_036_854_775_807n },
+    { $field: 'dRequired', dRequired: Number.MAX_SAFE_INTEGER },
+  ],
+  [
+    { dRequired: -9_223_372_036_854_775_808n },
+    { $field: 'dRequired', dRequired: Number.MIN_SAFE_INTEGER },
+  ],
+  [
+    { lRequired: [0n, 9_007_199_254_740_992n] },
+    { $field: 'lRequired', lRequired: [0, Number.MAX_SAFE_INTEGER] },
+  ],
+  [
+    { mRequired: [-9_007_199_254_740_992n, 1n] },
+    { $field: 'mRequired', mRequired: [Number.MIN_SAFE_INTEGER, 1] },
+  ],
+];
+
+// Messages with integers which are represented as numbers, and how they're decoded when the
+// integers are represented as bigints. The numbers 2^53 and -2^53 are just beyond the safe range,
+// but they can still be represented exactly.
+const roundTripTestCases: [NumberBarOut, BarIn][] = [
+  [{ cRequired: 2 ** 53 }, { $field: 'cRequired', cRequired: 9_007_199_254_740_992n }],
+  [{ dRequired: -(2 ** 53) }, { $field: 'dRequired', dRequired: -9_007_199_254_740_992n }],
+  [
+    { mRequired: [-(2 ** 53), 2 ** 53] },
+    { $field: 'mRequired', mRequired: [-9_007_199_254_740_992n, 9_007_199_254_740_992n] },
+  ],
+];
+
+export default function run(): void {
+  for (const [message, expected] of safeTestCases) {
+    const bytes = Bar.serialize(message);
+    deepStrictEqual(NumberBar.deserialize(bytes), expected);
+    deepStrictEqual(ClampedNumberBar.deserialize(bytes), expected);
+  }
+  console.log('Integers in the safe range were decoded exactly.');
+
+  for (const [message, expected] of unsafeTestCases) {
+    const bytes = Bar.serialize(message);
+    deepStrictEqual(NumberBar.deserialize(bytes), overflowError);
+    throws(() => NumberBar.deserializeUnsafe(new DataView(bytes)), overflowError);
+    deepStrictEqual(ClampedNumberBar.deserialize(bytes), expected);
+  }
+  console.log('Integers beyond the safe range were rejected or clamped.');
+
+  for (const [message, expected] of roundTripTestCases) {
+    const bytes = NumberBar.serialize(message);
+    deepStrictEqual(Bar.deserialize(bytes), expected);
+    deepStrictEqual(NumberBar.deserialize(bytes), overflowError);
+  }
+  console.log('Integers represented as numbers survived a round trip.');
+}
@@ -0,0 +1,56 @@
+import { deepStrictEqual, rejects } from 'assert';
+import {
+  type EmptyStructIn,
+  type EmptyStructOut,
+} from '../generated/degenerate/types';
+import {
+  type GreetRequestIn,
+  type GreetResponseOut,
+  type GreeterServer,
+  dispatchGreeter,
+  greeterClient,
+} from '../generated/services/types';
+
+class Server implements GreeterServer {
+  pings = 0;
+
+  greet(request: GreetRequestIn): GreetResponseOut {
+    if (request.name === '') {
+      return { refusal: 'Who are you?' };
+    }
+
+    return { greeting: `Hello, ${request.name}!` };
+  }
+
+  async ping(request: EmptyStructIn): Promise<EmptyStructOut> {
+    this.pings += 1;
+    return request;
+  }
+}
+
+export default async function run(): Promise<void> {
+  const server = new Server();
+
+  // This transport delivers requests directly to an in-process server.
+  const client = greeterClient((methodIndex, request) =>
+    dispatchGreeter(server, methodIndex, request),
+  );
+
+  const greeting = await client.greet({ name: 'Alice' });
+  console.log('Response:', greeting);
+  deepStrictEqual(greeting, {
+    $field: 'greeting',
+    greeting: 'Hello, Alice!',
+  });
+
+  const refusal = await client.greet({ name: '' });
+  console.log('Response:', refusal);
+  deepStrictEqual(refusal, { $field: 'refusal', refusal: 'Who are you?' });
+
+  await client.ping({});
+  console.log('Pings:', server.pings);
+  deepStrictEqual(server.pings, 1);
+
+  // Deleted and unknown method indices should be rejected.
+  await rejects(dispatchGreeter(server, 1n, new ArrayBuffer(0)));
+}
@@ -0,0 +1,96 @@
+import { deepStrictEqual, throws } from 'assert';
+import { assertStructFromBelowOut } from '../generated/circular_dependency/dependency/types';
+import { isBarOut } from '../generated/comprehensive/types';
+import {
+  assertNewChoiceOut,
+  assertNewStructOut,
+  isEmptyOpenChoiceOut,
+  isNewChoiceOut,
+  isOldStructOut,
+} from '../generated/unknown_fields/types';
+
+function assertInvalid(assert: (value: unknown) => void, value: unknown, message: string): void {
+  console.log('Value to be checked:', value);
+  throws(() => assert(value), new TypeError(message));
+}
+
+export default function run(): void {
+  // Messages which can be serialized pass the checks, including input messages.
+  deepStrictEqual(isNewChoiceOut({ known: 'known' }), true);
+  deepStrictEqual(isNewChoiceOut({ $field: 'unrecognized', unrecognized: 42n }), true);
+  deepStrictEqual(
+    isNewChoiceOut({ preferred: new ArrayBuffer(1), $fallback: { known: 'fallback' } }),
+    true,
+  );
+  deepStrictEqual(isBarOut({ uRequired: [[], [0n, 18_446_744_073_709_551_615n]] }), true);
+  deepStrictEqual(
+    isOldStructOut({
+      known: 'known',
+      $unknown: [{ index: 1n, sizeMode: 0, payload: new ArrayBuffer(0) }],
+    }),
+    true,
+  );
+  deepStrictEqual(
+    isEmptyOpenChoiceOut({ unknown: { index: 1n, payload: new ArrayBuffer(0) } }),
+    true,
+  );
+  assertNewStructOut({
+    known: 'known',
+    unrecognized: 0n,
+    text: 'text',
+    nothing: undefined,
+    _number: 1.5,
+  });
+
+  // Anything else is rejected with a description of the problem.
+  deepStrictEqual(isNewChoiceOut(null), false);
+  deepStrictEqual(isNewChoiceOut({}), false);
+  deepStrictEqual(isBarOut({ uRequired: [[0n], [-1n]] }), false);
+  deepStrictEqual(isEmptyOpenChoiceOut({ unknown: { index: 1n } }), false);
+  deepStrictEqual(
+    isOldStructOut({ known: 'known', $unknown: [{ index: 1n, sizeMode: 4, payload: null }] }),
+    false,
+  );
+
+  assertInvalid(assertNewStructOut, 'text', 'Invalid NewStructOut: value must be an object.');
+
+  console.log();
+
+  assertInvalid(
+    assertNewStructOut,
+    { known: 'known', unrecognized: 42, text: 'text' },
+    'Invalid NewStructOut: value.unrecognized must be a bigint from 0 to 18446744073709551615.',
+  );
+
+  console.log();
+
+  assertInvalid(
+    assertNewChoiceOut,
+    { $field: 'known', unrecognized: 42n },
+    'Invalid NewChoiceOut: value.$field must be "unrecognized".',
+  );
+
+  console.log();
+
+  assertInvalid(
+    assertNewChoiceOut,
+    { preferred: new ArrayBuffer(1), $fallback: { preferred: new Uint8Array(1) } },
+    'Invalid NewChoiceOut: value.$fallback.preferred must be an ArrayBuffer.',
+  );
+
+  console.log();
+
+  assertInvalid(
+    assertNewChoiceOut,
+    { other: 'other' },
+    'Invalid NewChoiceOut: value must have one of the fields known, unrecognized, preferred.',
+  );
+
+  console.log();
+
+  assertInvalid(
+    assertStructFromBelowOut,
+    { x: { field: 'field', size: 'size', elements: 'elements', fallback: 0 } },
+    'Invalid StructFromBelowOut: value.x.fallback must be a string.',
+  );
+}
@@ -0,0 +1,156 @@
+import { deepStrictEqual } from 'assert';
+import type { UnknownField } from '../generated/common';
+import {
+  EmptyOpenChoice,
+  EmptyOpenStruct,
+  NewChoice,
+  type NewChoiceOut,
+  NewStruct,
+  type NewStructOut,
+  OldChoice,
+  OldStruct,
+} from '../generated/unknown_fields/types';
+
+function assertCompatible(
+  deserialize: (bytes: ArrayBuffer) => unknown,
+  actual: NewChoiceOut,
+  expected: unknown,
+): void {
+  console.log('Message to be serialized:', actual);
+
+  const bytes = NewChoice.serialize(actual);
+  console.log('Bytes from serialization:', bytes);
+
+  const replica = deserialize(bytes);
+  console.log('Message deserialized from those bytes:', replica);
+  deepStrictEqual(replica, expected);
+}
+
+function assertStructForwardedVerbatim<I, O>(
+  deserialize: (bytes: ArrayBuffer) => I | Error,
+  serialize: (message: O) => ArrayBuffer,
+  forward: (message: I) => O,
+  message: NewStructOut,
+): void {
+  const bytes = NewStruct.serialize(message);
+  const decoded = deserialize(bytes);
+  if (decoded instanceof Error) {
+    throw decoded;
+  }
+
+  const forwardedBytes = serialize(forward(decoded));
+  console.log('Bytes from forwarding:', forwardedBytes);
+  deepStrictEqual(forwardedBytes, bytes);
+  deepStrictEqual(NewStruct.deserialize(forwardedBytes), message);
+}
+
+export default function run(): void {
+  const unrecognizedPayload = new Uint8Array([85]).buffer;
+
+  // Recognized cases are decoded as usual.
+  assertCompatible(
+    OldChoice.deserialize,
+    { known: 'known' },
+    { $field: 'known', known: 'known' },
+  );
+
+  console.log();
+
+  // Unrecognized cases are preserved.
+  assertCompatible(
+    OldChoice.deserialize,
+    { unrecognized: 42n },
+    { $field: 'unknown', unknown: { index: 1n, payload: unrecognizedPayload } },
+  );
+
+  console.log();
+
+  assertCompatible(
+    EmptyOpenChoice.deserialize,
+    { unrecognized: 42n },
+    { $field: 'unknown', unknown: { index: 1n, payload: unrecognizedPayload } },
+  );
+
+  console.log();
+
+  // An unrecognized optional case gives way to its fallback, whether or not the fallback is
+  // recognized.
+  assertCompatible(
+    OldChoice.deserialize,
+    {
+      preferred: new Uint8Array([0, 42, 255]).buffer,
+      $fallback: { known: 'fallback' },
+    },
+    { $field: 'known', known: 'fallback' },
+  );
+
+  console.log();
+
+  assertCompatible(
+    OldChoice.deserialize,
+    {
+      preferred: new Uint8Array([0, 42, 255]).buffer,
+      $fallback: { unrecognized: 42n },
+    },
+    { $field: 'unknown', unknown: { index: 1n, payload: unrecognizedPayload } },
+  );
+
+  console.log();
+
+  // Preserved cases survive being forwarded verbatim by an older reader.
+  const bytes = NewChoice.serialize({ unrecognized: 42n });
+  const forwarded = OldChoice.deserialize(bytes);
+  if (forwarded instanceof Error) {
+    throw forwarded;
+  }
+
+  const forwardedBytes = OldChoice.serialize(forwarded);
+  console.log('Bytes from forwarding:', forwardedBytes);
+  deepStrictEqual(forwardedBytes, bytes);
+  deepStrictEqual(NewChoice.deserialize(forwardedBytes), {
+    $field: 'unrecognized',
+    unrecognized: 42n,
+  });
+
+  console.log();
+
+  // Open structs keep the fields they don't recognize, covering every size mode.
+  const newStruct: NewStructOut = {
+    known: 'known',
+    unrecognized: 42n,
+    text: 'text',
+    nothing: null,
+    _number: 1.5,
+  };
+  const numberPayload = new ArrayBuffer(8);
+  new DataView(numberPayload).setFloat64(0, 1.5, true);
+  const unknownFields: UnknownField[] = [
+    { index: 1n, sizeMode: 2, payload: unrecognizedPayload },
+    { index: 2n, sizeMode: 3, payload: new TextEncoder().encode('text').buffer },
+    { index: 3n, sizeMode: 0, payload: new ArrayBuffer(0) },
+    { index: 4n, sizeMode: 1, payload: numberPayload },
+  ];
+
+  const oldStruct = OldStruct.deserialize(NewStruct.serialize(newStruct));
+  console.log('Message deserialized by an older reader:', oldStruct);
+  deepStrictEqual(oldStruct, { known: 'known', $unknown: unknownFields });
+
+  console.log();
+
+  // Preserved fields survive being forwarded verbatim by an older reader.
+  assertStructForwardedVerbatim(
+    OldStruct.deserialize,
+    OldStruct.serialize,
+    (message) => message,
+    newStruct,
+  );
+
+  console.log();
+
+  assertStructForwardedVerbatim(
+    EmptyOpenStruct.deserialize,
+    EmptyOpenStruct.serialize,
+    (message) => message,
+    newStruct,
+  );
+}
@@ -0,0 +1,494 @@
+// This module contains the helpers which generated code uses to encode and decode messages. It's
+// not part of the public API, and it's kept in sync with the prelude that the code generator emits
+// when it doesn't use this crate [ref:rust_prelude].
+
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::identity_op,
+    clippy::missing_errors_doc,
+    clippy::must_use_candidate,
+    clippy::needless_borrow
+)]
+
+use crate::UnknownField;
+use std::{
+    cmp::min,
+    io::{self, BufRead, Error, ErrorKind, Write},
+};
+
+pub const MISSING_FIELDS_ERROR_MESSAGE: &str = "Struct missing one or more required field(s).";
+
+pub fn zigzag_encode(value: i64) -> u64 {
+    i64::cast_unsigned(value >> 63_u32) ^ i64::cast_unsigned(value << 1_u32)
+}
+
+pub fn zigzag_decode(value: u64) -> i64 {
+    u64::cast_signed(value >> 1_u32) ^ -u64::cast_signed(value & 1)
+}
+
+pub fn varint_size_from_value(value: u64) -> usize {
+    match value {
+        0_u64..=127_u64 => 1,
+        128_u64..=16_511_u64 => 2,
+        16_512_u64..=2_113_663_u64 => 3,
+        2_113_664_u64..=270_549_119_u64 => 4,
+        270_549_120_u64..=34_630_287_487_u64 => 5,
+        34_630_287_488_u64..=4_432_676_798_591_u64 => 6,
+        4_432_676_798_592_u64..=567_382_630_219_903_u64 => 7,
+        567_382_630_219_904_u64..=72_624_976_668_147_839_u64 => 8,
+        72_624_976_668_147_840_u64..=18_446_744_073_709_551_615_u64 => 9,
+    }
+}
+
+pub fn varint_size_from_first_byte(first_byte: u8) -> u32 {
+    first_byte.trailing_zeros() + 1
+}
+
+pub fn serialize_varint<T: Write>(mut value: u64, writer: &mut T) -> io::Result<()> {
+    match value {
+        0_u64..=127_u64 => writer.write_all(&[((value << 1_u32) as u8) | 0b0000_0001]),
+        128_u64..=16_511_u64 => {
+            value -= 128_u64;
+            writer.write_all(&[
+                ((value << 2_u32) as u8) | 0b0000_0010,
+                (value >> 6_u32) as u8,
+            ])
+        }
+        16_512_u64..=2_113_663_u64 => {
+            value -= 16_512_u64;
+            writer.write_all(&[
+                ((value << 3_u32) as u8) | 0b0000_0100,
+                (value >> 5_u32) as u8,
+                (value >> 13_u32) as u8,
+            ])
+        }
+        2_113_664_u64..=270_549_119_u64 => {
+            value -= 2_113_664_u64;
+            writer.write_all(&[
+                ((value << 4_u32) as u8) | 0b0000_1000,
+                (value >> 4_u32) as u8,
+                (value >> 12_u32) as u8,
+                (value >> 20_u32) as u8,
+            ])
+        }
+        270_549_120_u64..=34_630_287_487_u64 => {
+            value -= 270_549_120_u64;
+            writer.write_all(&[
+                ((value << 5_u32) as u8) | 0b0001_0000,
+                (value >> 3_u32) as u8,
+                (value >> 11_u32) as u8,
+                (value >> 19_u32) as u8,
+                (value >> 27_u32) as u8,
+            ])
+        }
+        34_630_287_488_u64..=4_432_676_798_591_u64 => {
+            value -= 34_630_287_488_u64;
+            writer.write_all(&[
+                ((value << 6_u32) as u8) | 0b0010_0000,
+                (value >> 2_u32) as u8,
+                (value >> 10_u32) as u8,
+                (value >> 18_u32) as u8,
+                (value >> 26_u32) as u8,
+                (value >> 34_u32) as u8,
+            ])
+        }
+        4_432_676_798_592_u64..=567_382_630_219_903_u64 => {
+            value -= 4_432_676_798_592_u64;
+            writer.write_all(&[
+                ((value << 7_u32) as u8) | 0b0100_0000,
+                (value >> 1_u32) as u8,
+                (value >> 9_u32) as u8,
+                (value >> 17_u32) as u8,
+                (value >> 25_u32) as u8,
+                (value >> 33_u32) as u8,
+                (value >> 41_u32) as u8,
+            ])
+        }
+        567_382_630_219_904_u64..=72_624_976_668_147_839_u64 => {
+            value -= 567_382_630_219_904_u64;
+            writer.write_all(&[
+                0b1000_0000,
+                value as u8,
+                (value >> 8_u32) as u8,
+                (value >> 16_u32) as u8,
+                (value >> 24_u32) as u8,
+                (value >> 32_u32) as u8,
+                (value >> 40_u32) as u8,
+                (value >> 48_u32) as u8,
+            ])
+        }
+        72_624_976_668_147_840_u64..=18_446_744_073_709_551_615_u64 => {
+            value -= 72_624_976_668_147_840_u64;
+            writer.write_all(&[
+                0b0000_0000,
+                value as u8,
+                (value >> 8_u32) as u8,
+                (value >> 16_u32) as u8,
+                (value >> 24_u32) as u8,
+                (value >> 32_u32) as u8,
+                (value >> 40_u32) as u8,
+                (value >> 48_u32) as u8,
+                (value >> 56_u32) as u8,
+            ])
+        }
+    }
+}
+
+pub fn deserialize_varint<T: BufRead>(reader: &mut T) -> io::Result<u64> {
+    let mut first_byte_buffer = [0; 1];
+    reader.read_exact(&mut first_byte_buffer[..])?;
+    let first_byte = first_byte_buffer[0];
+    let size_minus_one = first_byte.trailing_zeros();
+
+    let mut remaining_bytes_buffer = [0; 8];
+    reader.read_exact(&mut remaining_bytes_buffer[0..size_minus_one as usize])?;
+    let remaining_bytes_value = u64::from_le_bytes(remaining_bytes_buffer);
+
+    match size_minus_one {
+        0 => Ok(u64::from(first_byte >> 1_u32)),
+        1 => Ok(128_u64 + u64::from(first_byte >> 2_u32) + (remaining_bytes_value << 6_u32)),
+        2 => Ok(16_512_u64 + u64::from(first_byte >> 3_u32) + (remaining_bytes_value << 5_u32)),
+        3 => Ok(2_113_664_u64 + u64::from(first_byte >> 4_u32) + (remaining_bytes_value << 4_u32)),
+        4 => {
+            Ok(270_549_120_u64 + u64::from(first_byte >> 5_u32) + (remaining_bytes_value << 3_u32))
+        }
+        5 => Ok(34_630_287_488_u64
+            + u64::from(first_byte >> 6_u32)
+            + (remaining_bytes_value << 2_u32)),
+        6 => Ok(4_432_676_798_592_u64
+            + u64::from(first_byte >> 7_u32)
+            + (remaining_bytes_value << 1_u32)),
+        7 => Ok(567_382_630_219_904_u64 + remaining_bytes_value),
+        _ => Ok(72_624_976_668_147_840_u64.wrapping_add(remaining_bytes_value)),
+    }
+}
+
+pub fn payload_integer_encoded(payload: &[u8]) -> bool {
+    payload.first().is_some_and(|first_byte| {
+        varint_size_from_first_byte(*first_byte) as usize == payload.len()
+    })
+}
+
+pub fn field_header_size(index: u64, payload_size: usize, integer_encoded: bool) -> usize {
+    match payload_size {
+        0 => varint_size_from_value((index << 2_u32) | 0b00),
+        8 => varint_size_from_value((index << 2_u32) | 0b01),
+        size => {
+            if integer_encoded {
+                varint_size_from_value((index << 2_u32) | 0b10)
+            } else {
+                varint_size_from_value((index << 2_u32) | 0b11)
+                    + varint_size_from_value(size as u64)
+            }
+        }
+    }
+}
+
+pub fn serialize_field_header<T: Write>(
+    writer: &mut T,
+    index: u64,
+    payload_size: usize,
+    integer_encoded: bool,
+) -> io::Result<()> {
+    match payload_size {
+        0 => serialize_varint((index << 2_u32) | 0b00, writer),
+        8 => serialize_varint((index << 2_u32) | 0b01, writer),
+        size => {
+            if integer_encoded {
+                serialize_varint((index << 2_u32) | 0b10, writer)
+            } else {
+                serialize_varint((index << 2_u32) | 0b11, writer)?;
+                serialize_varint(size as u64, writer)
+            }
+        }
+    }
+}
+
+pub fn unknown_field_size(field: &UnknownField) -> usize {
+    let size_mode = field.size_mode & 0b11;
+    let tag_size = varint_size_from_value((field.index << 2_u32) | u64::from(size_mode));
+
+    if size_mode == 0b11 {
+        tag_size + varint_size_from_value(field.payload.len() as u64) + field.payload.len()
+    } else {
+        tag_size + field.payload.len()
+    }
+}
+
+pub fn serialize_unknown_field<T: Write>(writer: &mut T, field: &UnknownField) -> io::Result<()> {
+    let size_mode = field.size_mode & 0b11;
+    serialize_varint((field.index << 2_u32) | u64::from(size_mode), writer)?;
+
+    if size_mode == 0b11 {
+        serialize_varint(field.payload.len() as u64, writer)?;
+    }
+
+    writer.write_all(&field.payload)
+}
+
+pub fn deserialize_field_header<T: BufRead>(reader: &mut T) -> io::Result<(u64, usize)> {
+    let (index, _, size) = deserialize_field_header_with_size_mode(reader)?;
+
+    Ok((index, size))
+}
+
+pub fn deserialize_field_header_with_size_mode<T: BufRead>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {
+    let tag = deserialize_varint(&mut *reader)?;
+
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => {
+            let buffer = (&mut *reader).fill_buf()?;
+
+            if buffer.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "Error decoding field.",
+                ));
+            }
+
+            varint_size_from_first_byte(buffer[0]) as usize
+        }
+        _ => deserialize_varint(&mut *reader)? as usize,
+    };
+
+    Ok((index, size_mode, size))
+}
+
+pub fn skip<T: BufRead>(reader: &mut T, mut amount: usize) -> io::Result<()> {
+    while amount > 0 {
+        let buffer = reader.fill_buf()?;
+
+        if buffer.is_empty() {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        let num_bytes_to_consume = min(buffer.len(), amount);
+        reader.consume(num_bytes_to_consume);
+        amount -= num_bytes_to_consume;
+    }
+
+    Ok(())
+}
+
+pub fn finish<T: BufRead>(reader: &mut T) -> io::Result<()> {
+    loop {
+        let buffer = reader.fill_buf()?;
+
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        let buffer_size = buffer.len();
+        reader.consume(buffer_size);
+    }
+}
+
+pub fn sized_buffer(buffer: &mut [u8], size: usize) -> io::Result<&mut [u8]> {
+    buffer.get_mut(..size).ok_or_else(|| {
+        Error::new(
+            ErrorKind::WriteZero,
+            "The buffer is too small for the message.",
+        )
+    })
+}
+
+pub trait SliceRead {
+    fn peek(&self) -> &[u8];
+
+    fn take(&mut self, limit: u64) -> &[u8];
+
+    fn read_slice(&mut self, size: usize) -> io::Result<&[u8]> {
+        if self.peek().len() < size {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(self.take(size as u64))
+    }
+
+    fn read_exact(&mut self, buffer: &mut [u8]) -> io::Result<()> {
+        buffer.copy_from_slice(self.read_slice(buffer.len())?);
+        Ok(())
+    }
+
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<usize> {
+        let bytes = self.take(u64::MAX);
+        buffer.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<usize> {
+        let string = str::from_utf8(self.peek()).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "Stream did not contain valid UTF-8.",
+            )
+        })?;
+        buffer.push_str(string);
+        Ok(self.take(u64::MAX).len())
+    }
+
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.peek())
+    }
+}
+
+impl SliceRead for &[u8] {
+    fn peek(&self) -> &[u8] {
+        self
+    }
+
+    fn take(&mut self, limit: u64) -> &[u8] {
+        let bytes = *self;
+        let (head, tail) = bytes.split_at(min(limit, bytes.len() as u64) as usize);
+        *self = tail;
+        head
+    }
+}
+
+impl<T: SliceRead> SliceRead for &mut T {
+    fn peek(&self) -> &[u8] {
+        (**self).peek()
+    }
+
+    fn take(&mut self, limit: u64) -> &[u8] {
+        (**self).take(limit)
+    }
+}
+
+pub fn deserialize_varint_slice<T: SliceRead>(reader: &mut T) -> io::Result<u64> {
+    let size = reader.peek().first().map_or(1, |first_byte| {
+        varint_size_from_first_byte(*first_byte) as usize
+    });
+
+    deserialize_varint(&mut reader.read_slice(size)?)
+}
+
+pub fn deserialize_field_header_slice<T: SliceRead>(reader: &mut T) -> io::Result<(u64, usize)> {
+    let (index, _, size) = deserialize_field_header_with_size_mode_slice(reader)?;
+
+    Ok((index, size))
+}
+
+pub fn deserialize_field_header_with_size_mode_slice<T: SliceRead>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {
+    let tag = deserialize_varint_slice(&mut *reader)?;
+
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => match reader.peek().first() {
+            Some(first_byte) => varint_size_from_first_byte(*first_byte) as usize,
+            None => {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "Error decoding field.",
+                ));
+            }
+        },
+        _ => deserialize_varint_slice(&mut *reader)? as usize,
+    };
+
+    Ok((index, size_mode, size))
+}
+
+pub fn skip_slice<T: SliceRead>(reader: &mut T, amount: usize) -> io::Result<()> {
+    reader.read_slice(amount)?;
+    Ok(())
+}
+
+pub fn finish_slice<T: SliceRead>(reader: &mut T) -> io::Result<()> {
+    reader.take(u64::MAX);
+    Ok(())
+}
+
+pub const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0_u32; 256];
+    let mut index = 0_usize;
+
+    while index < table.len() {
+        let mut value = index as u32;
+        let mut bit = 0_u32;
+
+        while bit < 8 {
+            value = if value & 1 == 0 {
+                value >> 1_u32
+            } else {
+                (value >> 1_u32) ^ 0xedb8_8320
+            };
+            bit += 1;
+        }
+
+        table[index] = value;
+        index += 1;
+    }
+
+    table
+}
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0_u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8_u32)
+    })
+}
+
+// A frame consists of the size of the payload as a varint, the payload itself, and (if checksums
+// are enabled) the CRC-32 of the payload as 4 little-endian bytes [tag:framing].
+pub fn write_frame_payload<T: Write>(
+    payload: &[u8],
+    writer: &mut T,
+    checksum: bool,
+) -> io::Result<()> {
+    serialize_varint(payload.len() as u64, writer)?;
+    writer.write_all(payload)?;
+
+    if checksum {
+        writer.write_all(&crc32(payload).to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+pub fn read_frame_payload<T: BufRead>(reader: &mut T, checksum: bool) -> io::Result<Vec<u8>> {
+    let mut remaining = deserialize_varint(reader)? as usize;
+    let mut payload = Vec::new();
+
+    while remaining > 0 {
+        let buffer = reader.fill_buf()?;
+
+        if buffer.is_empty() {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        let num_bytes_to_consume = min(buffer.len(), remaining);
+        payload.extend_from_slice(&buffer[..num_bytes_to_consume]);
+        reader.consume(num_bytes_to_consume);
+        remaining -= num_bytes_to_consume;
+    }
+
+    if checksum {
+        let mut checksum = [0; 4];
+        reader.read_exact(&mut checksum)?;
+
+        if crc32(&payload) != u32::from_le_bytes(checksum) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Frame checksum mismatch.",
+            ));
+        }
+    }
+
+    Ok(payload)
+}
@@ -0,0 +1,132 @@
+// This module contains the asynchronous counterparts of the helpers in
+// [file:runtime/rust/src/internal.rs].
+
+#![allow(clippy::cast_possible_truncation, clippy::missing_errors_doc)]
+
+use crate::{
+    UnknownField,
+    internal::{
+        deserialize_varint, field_header_size, serialize_field_header, serialize_varint,
+        varint_size_from_first_byte, varint_size_from_value,
+    },
+};
+use std::{
+    cmp::min,
+    io::{self, Error, ErrorKind},
+};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+pub async fn serialize_varint_async<T: AsyncWrite + Unpin>(
+    value: u64,
+    writer: &mut T,
+) -> io::Result<()> {
+    let mut buffer = [0; 9];
+    serialize_varint(value, &mut &mut buffer[..])?;
+    writer
+        .write_all(&buffer[..varint_size_from_value(value)])
+        .await
+}
+
+pub async fn deserialize_varint_async<T: AsyncBufRead + Unpin>(reader: &mut T) -> io::Result<u64> {
+    let mut buffer = [0; 9];
+    reader.read_exact(&mut buffer[..1]).await?;
+    let size = varint_size_from_first_byte(buffer[0]) as usize;
+    reader.read_exact(&mut buffer[1..size]).await?;
+    deserialize_varint(&mut &buffer[..size])
+}
+
+pub async fn serialize_field_header_async<T: AsyncWrite + Unpin>(
+    writer: &mut T,
+    index: u64,
+    payload_size: usize,
+    integer_encoded: bool,
+) -> io::Result<()> {
+    let mut buffer = [0; 18];
+    serialize_field_header(&mut &mut buffer[..], index, payload_size, integer_encoded)?;
+    writer
+        .write_all(&buffer[..field_header_size(index, payload_size, integer_encoded)])
+        .await
+}
+
+pub async fn serialize_unknown_field_async<T: AsyncWrite + Unpin>(
+    writer: &mut T,
+    field: &UnknownField,
+) -> io::Result<()> {
+    let size_mode = field.size_mode & 0b11;
+    serialize_varint_async((field.index << 2_u32) | u64::from(size_mode), writer).await?;
+
+    if size_mode == 0b11 {
+        serialize_varint_async(field.payload.len() as u64, writer).await?;
+    }
+
+    writer.write_all(&field.payload).await
+}
+
+pub async fn deserialize_field_header_async<T: AsyncBufRead + Unpin>(
+    reader: &mut T,
+) -> io::Result<(u64, usize)> {
+    let (index, _, size) = deserialize_field_header_with_size_mode_async(reader).await?;
+
+    Ok((index, size))
+}
+
+pub async fn deserialize_field_header_with_size_mode_async<T: AsyncBufRead + Unpin>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {
+    let tag = deserialize_varint_async(&mut *reader).await?;
+
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => {
+            let buffer = reader.fill_buf().await?;
+
+            if buffer.is_empty() {
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "Error decoding field.",
+                ));
+            }
+
+            varint_size_from_first_byte(buffer[0]) as usize
+        }
+        _ => deserialize_varint_async(&mut *reader).await? as usize,
+    };
+
+    Ok((index, size_mode, size))
+}
+
+pub async fn skip_async<T: AsyncBufRead + Unpin>(
+    reader: &mut T,
+    mut amount: usize,
+) -> io::Result<()> {
+    while amount > 0 {
+        let buffer = reader.fill_buf().await?;
+
+        if buffer.is_empty() {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        let num_bytes_to_consume = min(buffer.len(), amount);
+        reader.consume(num_bytes_to_consume);
+        amount -= num_bytes_to_consume;
+    }
+
+    Ok(())
+}
+
+pub async fn finish_async<T: AsyncBufRead + Unpin>(reader: &mut T) -> io::Result<()> {
+    loop {
+        let buffer = reader.fill_buf().await?;
+
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        let buffer_size = buffer.len();
+        reader.consume(buffer_size);
+    }
+}
@@ -0,0 +1,1082 @@
+//! This crate contains the traits and helpers used by Rust code which was generated by
+//! [Typical](https://github.com/stepchowfun/typical) with the `--rust-runtime` option. Without that
+//! option, every generated file defines its own copy of them. With it, the messages from all the
+//! generated files implement the same [`Serialize`] and [`Deserialize`] traits, so they can be
+//! handled by generic code.
+//!
+//! The asynchronous traits are available with the `tokio` feature.
+//!
+//! Fields which were given custom types with the `--rust-type` option are converted via the
+//! [`BytesType`], [`StringType`], and [`ArrayType`] traits. This crate implements them for the
+//! standard vectors, boxes, and reference-counted pointers, for `bytes::Bytes` with the `bytes`
+//! feature, and for `smallvec::SmallVec` with the `smallvec` feature.
+
+#[doc(hidden)]
+pub mod internal;
+
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+pub mod internal_async;
+
+use crate::internal::{read_frame_payload, serialize_varint, write_frame_payload};
+use std::{
+    io::{self, BufRead, Error, ErrorKind, Write},
+    marker::PhantomData,
+    rc::Rc,
+    sync::Arc,
+};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncBufRead, AsyncWrite};
+
+/// The version of this crate
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A message which can be serialized
+pub trait Serialize {
+    /// Compute the size of the encoded message in bytes.
+    fn size(&self) -> usize;
+
+    /// Serialize the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message can't be written.
+    fn serialize<T: Write>(&self, writer: T) -> io::Result<()>;
+
+    /// Serialize the message into the beginning of a buffer, and return the size of the message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer is too small for the message.
+    fn serialize_into(&self, buffer: &mut [u8]) -> io::Result<usize>;
+
+    /// Serialize the message into a new vector of exactly the right size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message can't be written.
+    fn serialize_to_vec(&self) -> io::Result<Vec<u8>>;
+}
+
+/// A message which can be deserialized
+pub trait Deserialize: Sized {
+    /// Deserialize a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message can't be read or decoded.
+    fn deserialize<T: BufRead>(reader: T) -> io::Result<Self>;
+
+    /// Deserialize a message directly from a byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message can't be decoded.
+    fn deserialize_from_slice(bytes: &[u8]) -> io::Result<Self>;
+}
+
+/// A means of sending requests to a service and receiving its responses
+pub trait Transport {
+    /// Send a request for the method with the given index, and return the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request can't be delivered or the response can't be received.
+    fn call(&mut self, method_index: u64, request: Vec<u8>) -> io::Result<Vec<u8>>;
+}
+
+/// A field which was retained by an open struct even though it wasn't recognized
+#[derive(Clone, Debug)]
+pub struct UnknownField {
+    /// The index of the field
+    pub index: u64,
+
+    /// The size mode from the field header
+    pub size_mode: u8,
+
+    /// The encoded value of the field
+    pub payload: Vec<u8>,
+}
+
+/// A custom type for fields of type `Bytes`
+pub trait BytesType: Sized {
+    /// Convert the decoded bytes into this type.
+    fn from_vec(bytes: Vec<u8>) -> Self;
+
+    /// Borrow the bytes to be encoded.
+    fn as_slice(&self) -> &[u8];
+}
+
+/// A custom type for fields of type `String`
+pub trait StringType: Sized {
+    /// Convert the decoded string into this type.
+    fn from_string(string: String) -> Self;
+
+    /// Borrow the string to be encoded.
+    fn as_str(&self) -> &str;
+}
+
+/// A custom type for fields of an array type
+pub trait ArrayType<T>: Sized {
+    /// Convert the decoded elements into this type.
+    fn from_vec(elements: Vec<T>) -> Self;
+
+    /// Convert this type back into a vector of elements.
+    fn into_vec(self) -> Vec<T>;
+
+    /// Borrow the elements to be encoded.
+    fn as_slice(&self) -> &[T];
+}
+
+impl BytesType for Vec<u8> {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+impl BytesType for Box<[u8]> {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes.into_boxed_slice()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+impl BytesType for Rc<[u8]> {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes.into()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+impl BytesType for Arc<[u8]> {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes.into()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BytesType for bytes::Bytes {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        bytes.into()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+impl StringType for String {
+    fn from_string(string: String) -> Self {
+        string
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl StringType for Box<str> {
+    fn from_string(string: String) -> Self {
+        string.into_boxed_str()
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl StringType for Rc<str> {
+    fn from_string(string: String) -> Self {
+        string.into()
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl StringType for Arc<str> {
+    fn from_string(string: String) -> Self {
+        string.into()
+    }
+
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+impl<T> ArrayType<T> for Vec<T> {
+    fn from_vec(elements: Vec<T>) -> Self {
+        elements
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> ArrayType<T> for Box<[T]> {
+    fn from_vec(elements: Vec<T>) -> Self {
+        elements.into_boxed_slice()
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        <[T]>::into_vec(self)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> ArrayType<A::Item> for smallvec::SmallVec<A> {
+    fn from_vec(elements: Vec<A::Item>) -> Self {
+        Self::from_vec(elements)
+    }
+
+    fn into_vec(self) -> Vec<A::Item> {
+        self.into_vec()
+    }
+
+    fn as_slice(&self) -> &[A::Item] {
+        self
+    }
+}
+
+/// A message which can be serialized asynchronously
+#[cfg(feature = "tokio")]
+pub trait SerializeAsync {
+    /// Serialize the message asynchronously.
+    fn serialize_async<T: AsyncWrite + Send + Unpin>(
+        &self,
+        writer: T,
+    ) -> impl Future<Output = io::Result<()>> + Send;
+}
+
+/// A message which can be deserialized asynchronously
+#[cfg(feature = "tokio")]
+pub trait DeserializeAsync: Sized {
+    /// Deserialize a message asynchronously.
+    fn deserialize_async<T: AsyncBufRead + Send + Unpin>(
+        reader: T,
+    ) -> impl Future<Output = io::Result<Self>> + Send;
+}
+
+/// Write a message as a frame: its size as a varint, then the message itself, then (if `checksum`
+/// is set) the CRC-32 of the message as 4 little-endian bytes. Frames can be written one after
+/// another to form a stream, which can be read back with [`read_framed`].
+///
+/// # Errors
+///
+/// Returns an error if the frame can't be written.
+pub fn write_framed<M: Serialize, T: Write>(
+    message: &M,
+    mut writer: T,
+    checksum: bool,
+) -> io::Result<()> {
+    if checksum {
+        write_frame_payload(&message.serialize_to_vec()?, &mut writer, true)
+    } else {
+        serialize_varint(message.size() as u64, &mut writer)?;
+        message.serialize(writer)
+    }
+}
+
+/// Read a stream of frames written by [`write_framed`]. The `checksum` argument must match the one
+/// the frames were written with.
+pub fn read_framed<M: Deserialize, T: BufRead>(reader: T, checksum: bool) -> FramedReader<M, T> {
+    FramedReader {
+        reader,
+        checksum,
+        failed: false,
+        message: PhantomData,
+    }
+}
+
+/// An iterator over the messages in a stream of frames, returned by [`read_framed`]
+///
+/// The iterator ends when the stream ends between two frames. A stream which ends in the middle of
+/// a frame, a checksum mismatch, or a message which can't be decoded produces an error, after which
+/// the iterator ends.
+pub struct FramedReader<M, T> {
+    reader: T,
+    checksum: bool,
+    failed: bool,
+    message: PhantomData<fn() -> M>,
+}
+
+impl<M: Deserialize, T: BufRead> FramedReader<M, T> {
+    /// Return the underlying reader, positioned after the last frame that was read.
+    pub fn into_inner(self) -> T {
+        self.reader
+    }
+
+    // Read the next frame, or return `None` if the stream ended.
+    fn read_frame(&mut self) -> io::Result<Option<M>> {
+        if self.reader.fill_buf()?.is_empty() {
+            return Ok(None);
+        }
+
+        let payload = read_frame_payload(&mut self.reader, self.checksum)?;
+        M::deserialize_from_slice(&payload).map(Some)
+    }
+}
+
+impl<M: Deserialize, T: BufRead> Iterator for FramedReader<M, T> {
+    type Item = io::Result<M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let result = self.read_frame().transpose();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }
+}
+
+/// A message type which can be stored in a container, identified by the fingerprint of its schema
+///
+/// Generated code implements this trait for the input and output types of every struct and choice.
+pub trait SchemaType {
+    /// A hash of the structure of the type and the types it refers to, which doesn't depend on
+    /// names, comments, or the order of the fields
+    const FINGERPRINT: u64;
+
+    /// The structure of the type and the types it refers to, of which
+    /// [`FINGERPRINT`](Self::FINGERPRINT) is a hash. Containers include it so a reader can check
+    /// whether messages written with a different version of the type are compatible.
+    const NORMAL_FORM: &'static str;
+
+    /// The name of the type in [`SCHEMA`](Self::SCHEMA)
+    const NAME: &'static str;
+
+    /// A self-contained schema which declares the type, for embedding in containers
+    const SCHEMA: &'static str;
+}
+
+// The header of a container starts with these bytes.
+const CONTAINER_MAGIC: &[u8; 8] = b"\x89TYPICAL";
+
+// The version of the container format
+const CONTAINER_VERSION: u8 = 1;
+
+// The flags in the container header
+const CONTAINER_CHECKSUMS: u8 = 0b01;
+const CONTAINER_SCHEMA: u8 = 0b10;
+
+/// Write the header of a container of messages of type `M`: the magic bytes, the version of the
+/// format, the flags, the fingerprint of `M`, and the normal form of `M`, followed by the schema if
+/// `embed_schema` is set.
+/// The messages are then written with [`write_framed`], using the same value for `checksum`. A
+/// container can be read back with [`read_container`], or with `typical decode` if it includes the
+/// schema.
+///
+/// # Errors
+///
+/// Returns an error if the header can't be written.
+pub fn write_container_header<M: SchemaType, T: Write>(
+    mut writer: T,
+    checksum: bool,
+    embed_schema: bool,
+) -> io::Result<()> {
+    let mut flags = 0;
+
+    if checksum {
+        flags |= CONTAINER_CHECKSUMS;
+    }
+
+    if embed_schema {
+        flags |= CONTAINER_SCHEMA;
+    }
+
+    writer.write_all(CONTAINER_MAGIC)?;
+    writer.write_all(&[CONTAINER_VERSION, flags])?;
+    writer.write_all(&M::FINGERPRINT.to_le_bytes())?;
+    write_frame_payload(M::NORMAL_FORM.as_bytes(), &mut writer, checksum)?;
+
+    if embed_schema {
+        write_frame_payload(M::SCHEMA.as_bytes(), &mut writer, checksum)?;
+        write_frame_payload(M::NAME.as_bytes(), &mut writer, checksum)?;
+    }
+
+    Ok(())
+}
+
+/// Read the header of a container written with [`write_container_header`], and return an iterator
+/// over its messages.
+///
+/// # Errors
+///
+/// Returns an error if the header is invalid, or if the container holds messages of a type which
+/// can't be read as `M`. A container written with a different version of `M` is accepted as long as
+/// the changes between the versions are [safe](https://github.com/stepchowfun/typical#summary-of-what-kinds-of-schema-changes-are-safe)
+/// in the direction from the writer to the reader.
+pub fn read_container<M: Deserialize + SchemaType, T: BufRead>(
+    mut reader: T,
+) -> io::Result<FramedReader<M, T>> {
+    let mut header = [0; 18];
+    reader.read_exact(&mut header)?;
+    let flags = header[9];
+
+    if header[..8] != CONTAINER_MAGIC[..]
+        || header[8] != CONTAINER_VERSION
+        || flags & !(CONTAINER_CHECKSUMS | CONTAINER_SCHEMA) != 0
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Invalid container header.",
+        ));
+    }
+
+    let mut fingerprint = [0; 8];
+    fingerprint.copy_from_slice(&header[10..]);
+    let checksum = flags & CONTAINER_CHECKSUMS != 0;
+    let normal_form = read_frame_payload(&mut reader, checksum)?;
+
+    if u64::from_le_bytes(fingerprint) != M::FINGERPRINT && !readable(&normal_form, M::NORMAL_FORM)
+    {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Container holds messages of an incompatible type.",
+        ));
+    }
+
+    if flags & CONTAINER_SCHEMA != 0 {
+        read_frame_payload(&mut reader, checksum)?;
+        read_frame_payload(&mut reader, checksum)?;
+    }
+
+    Ok(read_framed(reader, checksum))
+}
+
+// A declaration in a normal form: whether it's a choice, whether it's open, and the index, rule,
+// and type of each field
+struct NormalDeclaration<'a> {
+    choice: bool,
+    open: bool,
+    fields: Vec<(&'a str, &'a str, &'a str)>,
+}
+
+impl<'a> NormalDeclaration<'a> {
+    // Find the rule and type of the field with the given index.
+    fn field(&self, index: &str) -> Option<(&'a str, &'a str)> {
+        self.fields
+            .iter()
+            .find(|(other_index, _, _)| *other_index == index)
+            .map(|&(_, rule, r#type)| (rule, r#type))
+    }
+}
+
+// Parse a normal form, which has one line per declaration. Each line consists of `struct` or
+// `choice`, optionally `open`, and then the fields as `index:rule:type`.
+fn parse_normal_form(normal_form: &str) -> Option<Vec<NormalDeclaration<'_>>> {
+    normal_form
+        .lines()
+        .map(|line| {
+            let mut words = line.split(' ').peekable();
+            let choice = match words.next()? {
+                "struct" => false,
+                "choice" => true,
+                _ => return None,
+            };
+            let open = words.next_if_eq(&"open").is_some();
+            let fields = words
+                .map(|word| {
+                    let mut parts = word.splitn(3, ':');
+                    Some((parts.next()?, parts.next()?, parts.next()?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(NormalDeclaration {
+                choice,
+                open,
+                fields,
+            })
+        })
+        .collect()
+}
+
+// Determine whether messages written with the normal form `writer` can be read with the normal
+// form `reader`.
+fn readable(writer: &[u8], reader: &str) -> bool {
+    let (Some(writer), Some(reader)) = (
+        core::str::from_utf8(writer)
+            .ok()
+            .and_then(parse_normal_form),
+        parse_normal_form(reader),
+    ) else {
+        return false;
+    };
+
+    declaration_readable(&writer, &reader, 0, 0, &mut vec![])
+}
+
+// Determine whether messages of a declaration in one normal form can be read as a declaration in
+// another. Pairs which are already being compared are assumed to be readable, so recursive types
+// don't lead to infinite recursion.
+fn declaration_readable(
+    writers: &[NormalDeclaration<'_>],
+    readers: &[NormalDeclaration<'_>],
+    writer: usize,
+    reader: usize,
+    visited: &mut Vec<(usize, usize)>,
+) -> bool {
+    if visited.contains(&(writer, reader)) {
+        return true;
+    }
+
+    visited.push((writer, reader));
+
+    let (Some(writer), Some(reader)) = (writers.get(writer), readers.get(reader)) else {
+        return false;
+    };
+
+    if writer.choice != reader.choice {
+        // A struct with a single required field is encoded in the same way as a choice with just
+        // that field.
+        return match (writer.fields.as_slice(), reader.fields.as_slice()) {
+            (
+                [(writer_index, "required", writer_type)],
+                [(reader_index, "required", reader_type)],
+            ) => {
+                writer_index == reader_index
+                    && type_readable(writers, readers, writer_type, reader_type, visited)
+            }
+            _ => false,
+        };
+    }
+
+    if reader.choice {
+        // The reader must recognize every field the writer might write, unless the writer
+        // provides a fallback or the reader preserves unknown fields.
+        writer
+            .fields
+            .iter()
+            .all(|&(index, writer_rule, writer_type)| {
+                reader.field(index).map_or(
+                    reader.open || writer_rule != "required",
+                    |(_, reader_type)| {
+                        type_readable(writers, readers, writer_type, reader_type, visited)
+                    },
+                )
+            })
+    } else {
+        // The writer must write every field which the reader requires.
+        reader
+            .fields
+            .iter()
+            .all(|&(index, reader_rule, reader_type)| {
+                writer.field(index).map_or(
+                    reader_rule != "required",
+                    |(writer_rule, writer_type)| {
+                        (reader_rule != "required" || writer_rule != "optional")
+                            && type_readable(writers, readers, writer_type, reader_type, visited)
+                    },
+                )
+            })
+    }
+}
+
+// Determine whether values of a type in one normal form can be read as a type in another.
+fn type_readable(
+    writers: &[NormalDeclaration<'_>],
+    readers: &[NormalDeclaration<'_>],
+    writer: &str,
+    reader: &str,
+    visited: &mut Vec<(usize, usize)>,
+) -> bool {
+    fn element(r#type: &str) -> Option<&str> {
+        r#type.strip_prefix('[')?.strip_suffix(']')
+    }
+
+    fn number(r#type: &str) -> Option<usize> {
+        r#type.strip_prefix('#')?.parse().ok()
+    }
+
+    if let (Some(writer), Some(reader)) = (element(writer), element(reader)) {
+        type_readable(writers, readers, writer, reader, visited)
+    } else if let (Some(writer), Some(reader)) = (number(writer), number(reader)) {
+        declaration_readable(writers, readers, writer, reader, visited)
+    } else {
+        writer == reader
+    }
+}
+
+/// Generated code calls this in a constant to check that it was generated by a version of Typical
+/// which is compatible with this version of the runtime. So an incompatible runtime is reported at
+/// compile time.
+///
+/// # Panics
+///
+/// Panics if the versions are incompatible.
+pub const fn check_version(typical_version: &str) {
+    assert!(
+        compatible(typical_version, VERSION),
+        "This code was generated by a version of Typical which is incompatible with this version \
+            of typical-runtime.",
+    );
+}
+
+// Determine whether code generated by one version of Typical can use a given version of the
+// runtime. Following Semantic Versioning, the runtime must have the same major version and at least
+// the same minor version. Before 1.0.0, the minor versions must match exactly.
+const fn compatible(typical_version: &str, runtime_version: &str) -> bool {
+    let (typical_major, typical_minor) = major_and_minor(typical_version);
+    let (runtime_major, runtime_minor) = major_and_minor(runtime_version);
+
+    if typical_major != runtime_major {
+        false
+    } else if typical_major == 0 {
+        typical_minor == runtime_minor
+    } else {
+        typical_minor <= runtime_minor
+    }
+}
+
+// Parse the major and minor components of a version.
+const fn major_and_minor(version: &str) -> (u64, u64) {
+    let bytes = version.as_bytes();
+    let mut components = [0_u64; 2];
+    let mut component = 0;
+    let mut i = 0;
+
+    while i < bytes.len() && component < components.len() {
+        match bytes[i] {
+            b'.' => component += 1,
+            digit @ b'0'..=b'9' => {
+                components[component] = components[component] * 10 + (digit - b'0') as u64;
+            }
+            _ => break,
+        }
+
+        i += 1;
+    }
+
+    (components[0], components[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ArrayType, BytesType, Deserialize, SchemaType, Serialize, StringType, VERSION,
+        check_version, compatible,
+        internal::{
+            crc32, deserialize_varint, deserialize_varint_slice, serialize_varint, skip, skip_slice,
+        },
+        read_container, read_framed, readable, write_container_header, write_framed,
+    };
+    use std::{
+        io::{self, BufRead, ErrorKind, Write},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    // A message which is encoded as its raw bytes
+    #[derive(Debug, Eq, PartialEq)]
+    struct Raw(Vec<u8>);
+
+    impl Serialize for Raw {
+        fn size(&self) -> usize {
+            self.0.len()
+        }
+
+        fn serialize<T: Write>(&self, mut writer: T) -> io::Result<()> {
+            writer.write_all(&self.0)
+        }
+
+        fn serialize_into(&self, buffer: &mut [u8]) -> io::Result<usize> {
+            self.serialize(&mut buffer[..])?;
+            Ok(self.0.len())
+        }
+
+        fn serialize_to_vec(&self) -> io::Result<Vec<u8>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    impl Deserialize for Raw {
+        fn deserialize<T: BufRead>(mut reader: T) -> io::Result<Self> {
+            let mut bytes = vec![];
+            reader.read_to_end(&mut bytes)?;
+            Ok(Self(bytes))
+        }
+
+        fn deserialize_from_slice(bytes: &[u8]) -> io::Result<Self> {
+            Ok(Self(bytes.to_vec()))
+        }
+    }
+
+    impl SchemaType for Raw {
+        const FINGERPRINT: u64 = 0x0123_4567_89ab_cdef;
+        const NORMAL_FORM: &'static str = "struct 0:optional:Bytes\n";
+        const NAME: &'static str = "Raw";
+        const SCHEMA: &'static str = "struct Raw {\n    optional x: Bytes = 0\n}\n";
+    }
+
+    // A newer version of `Raw` in which the field is required, so it can read messages written as
+    // `Other` but not the other way around
+    struct Other;
+
+    impl Deserialize for Other {
+        fn deserialize<T: BufRead>(_: T) -> io::Result<Self> {
+            Ok(Self)
+        }
+
+        fn deserialize_from_slice(_: &[u8]) -> io::Result<Self> {
+            Ok(Self)
+        }
+    }
+
+    impl SchemaType for Other {
+        const FINGERPRINT: u64 = 0;
+        const NORMAL_FORM: &'static str = "struct 0:required:Bytes\n";
+        const NAME: &'static str = "Raw";
+        const SCHEMA: &'static str = "struct Raw {\n    x: Bytes = 0\n}\n";
+    }
+
+    fn framed(messages: &[Raw], checksum: bool) -> Vec<u8> {
+        let mut buffer = vec![];
+
+        for message in messages {
+            write_framed(message, &mut buffer, checksum).unwrap();
+        }
+
+        buffer
+    }
+
+    #[test]
+    fn check_version_current() {
+        check_version(VERSION);
+    }
+
+    #[test]
+    #[should_panic(expected = "incompatible")]
+    fn check_version_incompatible() {
+        check_version("999.0.0");
+    }
+
+    #[test]
+    fn compatible_same_version() {
+        assert!(compatible("0.15.0", "0.15.0"));
+        assert!(compatible("1.2.3", "1.2.3"));
+    }
+
+    #[test]
+    fn compatible_patch_versions() {
+        assert!(compatible("0.15.3", "0.15.0"));
+        assert!(compatible("0.15.0", "0.15.3"));
+    }
+
+    #[test]
+    fn compatible_minor_versions_before_1_0() {
+        assert!(!compatible("0.15.0", "0.16.0"));
+        assert!(!compatible("0.16.0", "0.15.0"));
+    }
+
+    #[test]
+    fn compatible_minor_versions_after_1_0() {
+        assert!(compatible("1.2.0", "1.3.0"));
+        assert!(!compatible("1.3.0", "1.2.0"));
+    }
+
+    #[test]
+    fn compatible_major_versions() {
+        assert!(!compatible("1.0.0", "2.0.0"));
+        assert!(!compatible("2.0.0", "1.0.0"));
+    }
+
+    #[test]
+    fn varint_round_trip() {
+        for value in [0, 127, 128, 16_511, 16_512, u64::MAX] {
+            let mut buffer = vec![];
+            serialize_varint(value, &mut buffer).unwrap();
+            assert_eq!(deserialize_varint(&mut buffer.as_slice()).unwrap(), value);
+            assert_eq!(
+                deserialize_varint_slice(&mut buffer.as_slice()).unwrap(),
+                value,
+            );
+        }
+    }
+
+    #[test]
+    fn varint_slice_truncated() {
+        let mut buffer = vec![];
+        serialize_varint(u64::MAX, &mut buffer).unwrap();
+        assert!(deserialize_varint_slice(&mut &buffer[..buffer.len() - 1]).is_err());
+        assert!(deserialize_varint_slice(&mut &buffer[..0]).is_err());
+    }
+
+    #[test]
+    fn skip_truncated() {
+        assert!(skip(&mut &[0_u8; 2][..], 3).is_err());
+        assert!(skip(&mut &[][..], 1).is_err());
+        assert!(skip_slice(&mut &[0_u8; 2][..], 3).is_err());
+        assert!(skip_slice(&mut &[][..], 1).is_err());
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn framed_round_trip() {
+        let messages = [Raw(vec![]), Raw(vec![1, 2, 3]), Raw(vec![42; 200])];
+
+        for checksum in [false, true] {
+            let buffer = framed(&messages, checksum);
+            let decoded = read_framed::<Raw, _>(buffer.as_slice(), checksum)
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(decoded, messages);
+        }
+    }
+
+    #[test]
+    fn framed_layout() {
+        assert_eq!(framed(&[Raw(vec![7, 8])], false), [0b101, 7, 8]);
+        assert_eq!(
+            framed(&[Raw(vec![7, 8])], true),
+            [&[0b101, 7, 8][..], &crc32(&[7, 8]).to_le_bytes()].concat(),
+        );
+    }
+
+    #[test]
+    fn framed_truncated() {
+        let buffer = framed(&[Raw(vec![1]), Raw(vec![2, 3])], true);
+        let mut reader = read_framed::<Raw, _>(&buffer[..buffer.len() - 1], true);
+        assert_eq!(reader.next().unwrap().unwrap(), Raw(vec![1]));
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof,
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn framed_checksum_mismatch() {
+        let mut buffer = framed(&[Raw(vec![1, 2, 3])], true);
+        buffer[1] ^= 1;
+        let mut reader = read_framed::<Raw, _>(buffer.as_slice(), true);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::InvalidData,
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn container_round_trip() {
+        let messages = [Raw(vec![]), Raw(vec![1, 2, 3])];
+
+        for checksum in [false, true] {
+            for embed_schema in [false, true] {
+                let mut buffer = vec![];
+                write_container_header::<Raw, _>(&mut buffer, checksum, embed_schema).unwrap();
+                buffer.extend(framed(&messages, checksum));
+                let decoded = read_container::<Raw, _>(buffer.as_slice())
+                    .unwrap()
+                    .collect::<io::Result<Vec<_>>>()
+                    .unwrap();
+                assert_eq!(decoded, messages);
+            }
+        }
+    }
+
+    #[test]
+    fn container_layout() {
+        let mut buffer = vec![];
+        write_container_header::<Raw, _>(&mut buffer, false, true).unwrap();
+        assert_eq!(
+            buffer,
+            [
+                &b"\x89TYPICAL\x01\x02"[..],
+                &0x0123_4567_89ab_cdef_u64.to_le_bytes(),
+                &[0b0011_0001],
+                b"struct 0:optional:Bytes\n",
+                &[0b0101_0011],
+                b"struct Raw {\n    optional x: Bytes = 0\n}\n",
+                &[0b0000_0111],
+                b"Raw",
+            ]
+            .concat(),
+        );
+    }
+
+    #[test]
+    fn container_compatible_type() {
+        let mut buffer = vec![];
+        write_container_header::<Other, _>(&mut buffer, true, true).unwrap();
+        buffer.extend(framed(&[Raw(vec![1, 2, 3])], true));
+        let decoded = read_container::<Raw, _>(buffer.as_slice())
+            .unwrap()
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(decoded, [Raw(vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn container_incompatible_type() {
+        let mut buffer = vec![];
+        write_container_header::<Raw, _>(&mut buffer, false, false).unwrap();
+        assert_eq!(
+            read_container::<Other, _>(buffer.as_slice())
+                .err()
+                .unwrap()
+                .kind(),
+            ErrorKind::InvalidData,
+        );
+    }
+
+    #[test]
+    fn readable_structs() {
+        let before = "struct 0:required:String 1:optional:#1 2:optional:U64\nstruct open\n";
+        let after = "struct 0:required:String 1:asymmetric:#1 3:optional:Bool\nstruct\n";
+        assert!(readable(before.as_bytes(), after));
+        assert!(readable(after.as_bytes(), before));
+        assert!(!readable(
+            before.as_bytes(),
+            "struct 1:required:#1\nstruct\n"
+        ));
+        assert!(!readable(before.as_bytes(), "struct 4:required:U64\n"));
+        assert!(!readable(before.as_bytes(), "struct 0:required:Bytes\n"));
+        assert!(!readable(before.as_bytes(), "struct 2:optional:[U64]\n"));
+    }
+
+    #[test]
+    fn readable_choices() {
+        let before = "choice 0:required:String 1:asymmetric:U64 2:optional:Unit\n";
+        let after = "choice 0:required:String 3:required:Bool\n";
+        assert!(readable(before.as_bytes(), after));
+        assert!(!readable(after.as_bytes(), before));
+        assert!(readable(
+            after.as_bytes(),
+            "choice open 0:optional:String\n"
+        ));
+        assert!(!readable(before.as_bytes(), "choice 0:required:Bytes\n"));
+    }
+
+    #[test]
+    fn readable_single_field() {
+        let choice = "choice 0:required:[#1]\nstruct\n";
+        assert!(readable(
+            choice.as_bytes(),
+            "struct 0:required:[#1]\nstruct\n"
+        ));
+        assert!(!readable(
+            choice.as_bytes(),
+            "struct 0:asymmetric:[#1]\nstruct\n"
+        ));
+        assert!(!readable(
+            choice.as_bytes(),
+            "struct 0:required:#1\nstruct\n"
+        ));
+        assert!(!readable(
+            choice.as_bytes(),
+            "struct 1:required:[#1]\nstruct\n"
+        ));
+    }
+
+    #[test]
+    fn readable_recursive() {
+        let list = "choice 0:required:Unit 1:required:#1\nstruct 0:required:U64 1:required:#0\n";
+        assert!(readable(list.as_bytes(), list));
+        assert!(!readable(
+            list.as_bytes(),
+            "choice 0:required:Unit 1:required:#1\nstruct 0:required:S64 1:required:#0\n",
+        ));
+    }
+
+    #[test]
+    fn readable_malformed() {
+        assert!(!readable(b"\xff", "struct\n"));
+        assert!(!readable(b"enum\n", "struct\n"));
+        assert!(!readable(b"struct 0\n", "struct\n"));
+        assert!(!readable(
+            b"struct 0:required:#2\n",
+            "struct 0:required:#2\n"
+        ));
+    }
+
+    #[test]
+    fn container_invalid_header() {
+        let mut buffer = vec![];
+        write_container_header::<Raw, _>(&mut buffer, false, false).unwrap();
+        buffer[0] = 0;
+        assert_eq!(
+            read_container::<Raw, _>(buffer.as_slice())
+                .err()
+                .unwrap()
+                .kind(),
+            ErrorKind::InvalidData,
+        );
+        assert_eq!(
+            read_container::<Raw, _>(&buffer[..10])
+                .err()
+                .unwrap()
+                .kind(),
+            ErrorKind::UnexpectedEof,
+        );
+    }
+
+    #[test]
+    fn bytes_types_round_trip() {
+        fn round_trip<T: BytesType>() {
+            assert_eq!(T::from_vec(vec![1, 2, 3]).as_slice(), &[1, 2, 3]);
+        }
+
+        round_trip::<Vec<u8>>();
+        round_trip::<Box<[u8]>>();
+        round_trip::<Rc<[u8]>>();
+        round_trip::<Arc<[u8]>>();
+        #[cfg(feature = "bytes")]
+        round_trip::<bytes::Bytes>();
+    }
+
+    #[test]
+    fn string_types_round_trip() {
+        fn round_trip<T: StringType>() {
+            assert_eq!(T::from_string("foo".to_owned()).as_str(), "foo");
+        }
+
+        round_trip::<String>();
+        round_trip::<Box<str>>();
+        round_trip::<Rc<str>>();
+        round_trip::<Arc<str>>();
+    }
+
+    #[test]
+    fn array_types_round_trip() {
+        fn round_trip<T: ArrayType<u64>>() {
+            assert_eq!(T::from_vec(vec![1, 2, 3]).as_slice(), &[1, 2, 3]);
+            assert_eq!(T::from_vec(vec![1, 2, 3]).into_vec(), vec![1, 2, 3]);
+        }
+
+        round_trip::<Vec<u64>>();
+        round_trip::<Box<[u64]>>();
+        #[cfg(feature = "smallvec")]
+        round_trip::<smallvec::SmallVec<[u64; 2]>>();
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{
+    fingerprint::{Detail, fingerprint, normal_form},
+    identifier::Identifier,
+    schema,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+// The bytes which every container starts with [tag:container_magic]
+pub const MAGIC: &[u8; 8] = b"\x89TYPICAL";
+
+// The version of the container format
+pub const VERSION: u8 = 1;
+
+// The bits of the flags byte in the container header
+pub const FLAG_CHECKSUMS: u8 = 0b01;
+pub const FLAG_SCHEMA: u8 = 0b10;
+
+// A single schema which contains every declaration from a set of schemas, so it can be embedded in
+// containers
+pub struct EmbeddedSchema {
+    pub text: String,
+    pub declarations: BTreeMap<(schema::Namespace, Identifier), EmbeddedDeclaration>,
+}
+
+// How a declaration is identified in a container
+pub struct EmbeddedDeclaration {
+    pub name: Identifier,    // The name of the declaration in the embedded schema
+    pub fingerprint: u64,    // [ref:fingerprint]
+    pub normal_form: String, // The structure which the fingerprint is a hash of
+}
+
+// Combine a set of schemas into one which doesn't import anything. Declarations keep their names
+// unless those names are used in more than one schema, in which case they're prefixed with their
+// namespaces. Services are omitted, since containers only hold messages. The schemas are assumed to
+// be valid and desugared, so the declarations don't have any includes.
+pub fn embedded_schema(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> EmbeddedSchema {
+    // Count how many times each name is used.
+    let mut counts = BTreeMap::<Identifier, usize>::new();
+    for (schema, _, _) in schemas.values() {
+        for declaration in &schema.declarations {
+            *counts.entry(declaration.name.clone()).or_default() += 1;
+        }
+    }
+
+    // Choose a unique name for each declaration.
+    let mut names = BTreeMap::new();
+    let mut used_names = BTreeSet::new();
+    for (namespace, (schema, _, _)) in schemas {
+        for declaration in &schema.declarations {
+            let mut name = declaration.name.clone();
+
+            if counts[&name] > 1 {
+                for component in namespace.components.iter().rev() {
+                    name = component.join(&name);
+                }
+            }
+
+            // The prefixed name might still collide with another name, e.g., if a schema `foo`
+            // declares `Bar` and another schema declares `FooBar`.
+            let mut suffix = 1_usize;
+            let base_name = name.clone();
+            while used_names.contains(&name) {
+                suffix += 1;
+                name = base_name.join(&AsRef::<str>::as_ref(&suffix.to_string()).into());
+            }
+
+            used_names.insert(name.clone());
+            names.insert((namespace.clone(), declaration.name.clone()), name);
+        }
+    }
+
+    // Rename the declarations and the types which refer to them. Comments are dropped, since
+    // they'd otherwise be published in every container.
+    let mut combined_schema = schema::Schema {
+        comment: vec![],
+        imports: BTreeMap::new(),
+        declarations: vec![],
+        services: vec![],
+    };
+    for (namespace, (schema, _, _)) in schemas {
+        for declaration in &schema.declarations {
+            let mut declaration = declaration.clone();
+            declaration.name = names[&(namespace.clone(), declaration.name.clone())].clone();
+            declaration.comment.clear();
+
+            for field in &mut declaration.fields {
+                field.comment.clear();
+                rename_type(&mut field.r#type, namespace, schemas, &names);
+            }
+
+            combined_schema.declarations.push(declaration);
+        }
+    }
+
+    EmbeddedSchema {
+        text: combined_schema.to_string(),
+        declarations: names
+            .into_iter()
+            .map(|((namespace, name), embedded_name)| {
+                let fingerprint = fingerprint(schemas, &namespace, &name);
+                let normal_form = normal_form(schemas, &namespace, &name, Detail::Exact);
+                (
+                    (namespace, name),
+                    EmbeddedDeclaration {
+                        name: embedded_name,
+                        fingerprint,
+                        normal_form,
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+// Rewrite a type to refer to the declarations by their names in the embedded schema.
+fn rename_type(
+    r#type: &mut schema::Type,
+    namespace: &schema::Namespace,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    names: &BTreeMap<(schema::Namespace, Identifier), Identifier>,
+) {
+    match &mut r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            rename_type(inner_type, namespace, schemas, names);
+        }
+        schema::TypeVariant::Custom(import, name) => {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let target_namespace = import.as_ref().map_or_else(
+                || namespace.clone(),
+                |import| {
+                    schemas[namespace].0.imports[import]
+                        .namespace
+                        .clone()
+                        .unwrap()
+                },
+            );
+
+            r#type.variant =
+                schema::TypeVariant::Custom(None, names[&(target_namespace, name.clone())].clone());
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        container::embedded_schema, desugar::desugar, fingerprint::fingerprint, parser::parse,
+        schema, tokenizer::tokenize, validator::validate,
+    };
+    use std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+    };
+
+    // Load a set of schemas from `(name, contents)` pairs. Each schema may import the others.
+    fn load(
+        sources: &[(&str, &str)],
+    ) -> BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)> {
+        let mut schemas = BTreeMap::new();
+
+        for (name, contents) in sources {
+            let path = Path::new(name).with_extension("t");
+            let tokens = tokenize(&path, contents).unwrap();
+            let mut schema = parse(&path, contents, &tokens).unwrap();
+            for (import_name, import) in &mut schema.imports {
+                import.namespace = Some(schema::Namespace {
+                    components: vec![import_name.clone()],
+                });
+            }
+            schemas.insert(
+                schema::Namespace {
+                    components: vec![(*name).into()],
+                },
+                (schema, path, (*contents).to_owned()),
+            );
+        }
+
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        schemas
+    }
+
+    #[test]
+    fn embedded_schema_renames_collisions() {
+        let schemas = load(&[
+            (
+                "foo",
+                "
+                import 'bar.t' { Bar as Baz }
+
+                # This comment isn't embedded.
+                struct Bar {
+                    # Neither is this one.
+                    x: Baz = 0
+                    y: [bar.Qux] = 1
+                }
+                ",
+            ),
+            (
+                "bar",
+                "
+                struct Bar {
+                }
+
+                choice Qux {
+                    z: Unit = 0
+                }
+                ",
+            ),
+        ]);
+
+        let embedded = embedded_schema(&schemas);
+
+        assert_eq!(
+            embedded.text,
+            "\
+struct BarBar {
+}
+
+choice Qux {
+    z = 0
+}
+
+struct FooBar {
+    x: BarBar = 0
+    y: [Qux] = 1
+}
+",
+        );
+
+        // The embedded schema should be valid, and the fingerprints should be preserved.
+        let embedded_schemas = load(&[("embedded", &embedded.text)]);
+        for ((namespace, name), declaration) in &embedded.declarations {
+            assert_eq!(
+                fingerprint(&schemas, namespace, name),
+                declaration.fingerprint
+            );
+            assert_eq!(
+                fingerprint(
+                    &embedded_schemas,
+                    &schema::Namespace {
+                        components: vec!["embedded".into()],
+                    },
+                    &declaration.name,
+                ),
+                declaration.fingerprint,
+            );
+        }
+    }
+}
@@ -0,0 +1,745 @@
+use crate::{
+    container::{FLAG_CHECKSUMS, FLAG_SCHEMA, MAGIC, VERSION},
+    count::count,
+    desugar::desugar,
+    error::{Error, throw},
+    error_merger::merge_errors,
+    fingerprint::{Detail, fingerprint, normal_form},
+    format::CodeStr,
+    identifier::Identifier,
+    parser::parse,
+    schema,
+    tokenizer::tokenize,
+    validator::validate,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs::read,
+    path::{Path, PathBuf},
+};
+
+// The smallest value which a varint of each size represents
+const VARINT_OFFSETS: [u64; 9] = [
+    0,
+    128,
+    16_512,
+    2_113_664,
+    270_549_120,
+    34_630_287_488,
+    4_432_676_798_592,
+    567_382_630_219_904,
+    72_624_976_668_147_840,
+];
+
+// The name of the key for the fallback of an optional field of a choice in the JSON output. It
+// can't collide with a field name, since identifiers can't contain `$`.
+const FALLBACK_KEY: &str = "$fallback";
+
+// The name of the key for unrecognized fields of open structs and choices in the JSON output
+const UNKNOWN_KEY: &str = "$unknown";
+
+// A cursor over a byte slice which produces descriptive errors when the slice ends prematurely
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn take(&mut self, size: usize) -> Result<&'a [u8], String> {
+        if size > self.bytes.len() {
+            return Err("Unexpected end of input.".to_owned());
+        }
+
+        let (head, tail) = self.bytes.split_at(size);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn varint_size(&self) -> Result<usize, String> {
+        self.bytes
+            .first()
+            .map(|first_byte| first_byte.trailing_zeros() as usize + 1)
+            .ok_or_else(|| "Unexpected end of input.".to_owned())
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let size = self.varint_size()?;
+        let bytes = self.take(size)?;
+        let mut buffer = [0; 8];
+        buffer[..size - 1].copy_from_slice(&bytes[1..]);
+        let remaining_bytes_value = u64::from_le_bytes(buffer);
+
+        Ok(if size == 9 {
+            VARINT_OFFSETS[8].wrapping_add(remaining_bytes_value)
+        } else {
+            VARINT_OFFSETS[size - 1]
+                + (u64::from(bytes[0]) >> size)
+                + (remaining_bytes_value << (8 - size))
+        })
+    }
+
+    // Read a frame [ref:framing], and check its checksum if applicable.
+    fn frame(&mut self, checksum: bool) -> Result<&'a [u8], String> {
+        let size =
+            usize::try_from(self.varint()?).map_err(|_| "Frame size is too large.".to_owned())?;
+        let payload = self.take(size)?;
+
+        if checksum {
+            let mut expected = [0; 4];
+            expected.copy_from_slice(self.take(4)?);
+
+            if crc32(payload) != u32::from_le_bytes(expected) {
+                return Err("Frame checksum mismatch.".to_owned());
+            }
+        }
+
+        Ok(payload)
+    }
+}
+
+// Decode the messages in a container, using the schema embedded in the container, and print them to
+// STDOUT as JSON with one message per line.
+#[allow(clippy::too_many_lines)]
+pub fn decode(container_path: &Path) -> Result<(), Error> {
+    eprintln!(
+        "Reading {}\u{2026}",
+        container_path.to_string_lossy().code_str(),
+    );
+    let contents = read(container_path).map_err(|error| {
+        throw(
+            &format!(
+                "Unable to read {}.",
+                container_path.to_string_lossy().code_str(),
+            ),
+            None,
+            None,
+            Some(error),
+        )
+    })?;
+    let invalid = |message: &str| {
+        throw::<Error>(
+            &format!(
+                "{} is not a valid container. {message}",
+                container_path.to_string_lossy().code_str(),
+            ),
+            None,
+            None,
+            None,
+        )
+    };
+    let mut cursor = Cursor { bytes: &contents };
+
+    // Read the header [ref:container_magic].
+    if cursor.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+        return Err(invalid("It doesn't start with the expected magic bytes."));
+    }
+    let header = cursor
+        .take(10)
+        .map_err(|_| invalid("The header is truncated."))?;
+    if header[0] != VERSION {
+        return Err(invalid(&format!(
+            "Version {} of the container format isn't supported.",
+            header[0],
+        )));
+    }
+    let flags = header[1];
+    if flags & !(FLAG_CHECKSUMS | FLAG_SCHEMA) != 0 {
+        return Err(invalid("The header has unrecognized flags."));
+    }
+    let checksum = flags & FLAG_CHECKSUMS != 0;
+    let mut fingerprint_bytes = [0; 8];
+    fingerprint_bytes.copy_from_slice(&header[2..]);
+    let expected_fingerprint = u64::from_le_bytes(fingerprint_bytes);
+    let expected_normal_form = cursor
+        .frame(checksum)
+        .map_err(|message| invalid(&message))?
+        .to_owned();
+
+    // Read the embedded schema.
+    if flags & FLAG_SCHEMA == 0 {
+        return Err(throw::<Error>(
+            &format!(
+                "{} doesn't include its schema, so it can't be decoded.",
+                container_path.to_string_lossy().code_str(),
+            ),
+            None,
+            None,
+            None,
+        ));
+    }
+    let schema_contents = cursor
+        .frame(checksum)
+        .map_err(|message| invalid(&message))
+        .and_then(|frame| {
+            String::from_utf8(frame.to_owned())
+                .map_err(|_| invalid("The schema isn't valid UTF-8."))
+        })?;
+    let root_name = cursor
+        .frame(checksum)
+        .map_err(|message| invalid(&message))
+        .and_then(|frame| {
+            std::str::from_utf8(frame)
+                .map(Identifier::from)
+                .map_err(|_| invalid("The name of the type isn't valid UTF-8."))
+        })?;
+
+    // Load the schema.
+    eprintln!("Loading the embedded schema\u{2026}");
+    let namespace = schema::Namespace { components: vec![] };
+    let tokens =
+        tokenize(container_path, &schema_contents).map_err(|errors| merge_errors(&errors))?;
+    let schema =
+        parse(container_path, &schema_contents, &tokens).map_err(|errors| merge_errors(&errors))?;
+    let mut schemas = BTreeMap::new();
+    schemas.insert(
+        namespace.clone(),
+        (schema, container_path.to_owned(), schema_contents.clone()),
+    );
+    validate(&schemas).map_err(|errors| merge_errors(&errors))?;
+    desugar(&mut schemas);
+
+    // Make sure the schema agrees with the header [ref:fingerprint].
+    if !schemas[&namespace]
+        .0
+        .declarations
+        .iter()
+        .any(|declaration| declaration.name == root_name)
+    {
+        return Err(invalid(&format!(
+            "The embedded schema doesn't declare {}.",
+            root_name.code_str(),
+        )));
+    }
+    if fingerprint(&schemas, &namespace, &root_name) != expected_fingerprint
+        || normal_form(&schemas, &namespace, &root_name, Detail::Exact).as_bytes()
+            != expected_normal_form
+    {
+        return Err(invalid(
+            "The embedded schema doesn't match the fingerprint and structure in the header.",
+        ));
+    }
+
+    // Decode the messages.
+    eprintln!("Decoding messages of type {}\u{2026}", root_name.code_str());
+    let mut messages = 0_usize;
+    while !cursor.is_empty() {
+        let mut output = String::new();
+        cursor
+            .frame(checksum)
+            .and_then(|frame| decode_message(&schemas, &namespace, &root_name, frame, &mut output))
+            .map_err(|message| {
+                invalid(&format!(
+                    "Unable to decode message {}: {message}",
+                    messages + 1,
+                ))
+            })?;
+        println!("{output}");
+        messages += 1;
+    }
+
+    eprintln!("{} decoded.", count(messages, "message"));
+    Ok(())
+}
+
+// Compute the CRC-32 of a byte slice with the same parameters as zlib.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0_u32, |crc, byte| {
+        (0_u32..8).fold(crc ^ u32::from(*byte), |crc, _| {
+            if crc & 1 == 0 {
+                crc >> 1_u32
+            } else {
+                (crc >> 1_u32) ^ 0xedb8_8320
+            }
+        })
+    })
+}
+
+// Decode a message as JSON. The schema is assumed to be valid and desugared, and it doesn't have
+// any imports.
+#[allow(clippy::too_many_lines)]
+fn decode_message(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+    bytes: &[u8],
+    output: &mut String,
+) -> Result<(), String> {
+    // The `unwrap` is safe since the schema is valid.
+    let declaration = schemas[namespace]
+        .0
+        .declarations
+        .iter()
+        .find(|declaration| declaration.name == *name)
+        .unwrap();
+    let mut cursor = Cursor { bytes };
+
+    match declaration.variant {
+        schema::DeclarationVariant::Struct => {
+            let mut values = BTreeMap::new();
+            let mut unknown_fields = vec![];
+
+            while !cursor.is_empty() {
+                let (index, size_mode, payload) = decode_field(&mut cursor)?;
+
+                if let Some(field) = declaration
+                    .fields
+                    .iter()
+                    .find(|field| field.index as u64 == index)
+                {
+                    let mut value = String::new();
+                    decode_value(
+                        schemas,
+                        namespace,
+                        &field.r#type.variant,
+                        payload,
+                        true,
+                        &mut value,
+                    )?;
+                    values.insert(field.index, value);
+                } else if declaration.open {
+                    unknown_fields.push((index, size_mode, payload));
+                }
+            }
+
+            output.push('{');
+            let mut first = true;
+            for field in &declaration.fields {
+                if let Some(value) = values.get(&field.index) {
+                    if !first {
+                        output.push(',');
+                    }
+                    first = false;
+                    write_string(output, &field.name.snake_case());
+                    output.push(':');
+                    output.push_str(value);
+                } else if matches!(field.rule, schema::Rule::Required) {
+                    return Err("Struct missing one or more required field(s).".to_owned());
+                }
+            }
+            if !unknown_fields.is_empty() {
+                if !first {
+                    output.push(',');
+                }
+                write_string(output, UNKNOWN_KEY);
+                output.push_str(":[");
+                for (i, (index, size_mode, payload)) in unknown_fields.into_iter().enumerate() {
+                    if i != 0 {
+                        output.push(',');
+                    }
+                    write_unknown_field(output, index, Some(size_mode), payload);
+                }
+                output.push(']');
+            }
+            output.push('}');
+        }
+        schema::DeclarationVariant::Choice => loop {
+            if cursor.is_empty() {
+                return Err("Choice has no recognized fields.".to_owned());
+            }
+
+            let (index, _, payload) = decode_field(&mut cursor)?;
+
+            if let Some(field) = declaration
+                .fields
+                .iter()
+                .find(|field| field.index as u64 == index)
+            {
+                output.push('{');
+                write_string(output, &field.name.snake_case());
+                output.push(':');
+                decode_value(
+                    schemas,
+                    namespace,
+                    &field.r#type.variant,
+                    payload,
+                    true,
+                    output,
+                )?;
+
+                // An optional field is followed by a fallback. Any other field is the last one
+                // recognized by the reader.
+                if matches!(field.rule, schema::Rule::Optional) {
+                    output.push(',');
+                    write_string(output, FALLBACK_KEY);
+                    output.push(':');
+                    decode_message(schemas, namespace, name, cursor.bytes, output)?;
+                }
+
+                output.push('}');
+                break;
+            }
+
+            // An open choice keeps the last field if it's unrecognized.
+            if declaration.open && cursor.is_empty() {
+                output.push('{');
+                write_string(output, UNKNOWN_KEY);
+                output.push(':');
+                write_unknown_field(output, index, None, payload);
+                output.push('}');
+                break;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+// Decode the header of a field, and return the index, the size mode, and the payload.
+fn decode_field<'a>(cursor: &mut Cursor<'a>) -> Result<(u64, u8, &'a [u8]), String> {
+    let tag = cursor.varint()?;
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => cursor.varint_size()?,
+        _ => {
+            usize::try_from(cursor.varint()?).map_err(|_| "Field size is too large.".to_owned())?
+        }
+    };
+
+    Ok((index, size_mode, cursor.take(size)?))
+}
+
+// Decode a value of the given type as JSON. The value must take up the entire payload. Field values
+// have more compact encodings for some types.
+#[allow(clippy::too_many_lines)]
+fn decode_value(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+    payload: &[u8],
+    is_field: bool,
+    output: &mut String,
+) -> Result<(), String> {
+    let mut cursor = Cursor { bytes: payload };
+
+    // The `unwrap`s are safe because the `std::fmt::Write` impl for `String` is infallible.
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            output.push('[');
+
+            match &inner_type.variant {
+                schema::TypeVariant::Unit => {
+                    let mut size = String::new();
+                    decode_value(
+                        schemas,
+                        namespace,
+                        &schema::TypeVariant::U64,
+                        payload,
+                        is_field,
+                        &mut size,
+                    )?;
+                    let size = size
+                        .parse::<u64>()
+                        .map_err(|_| "Array size is too large.".to_owned())?;
+                    for i in 0..size {
+                        if i != 0 {
+                            output.push(',');
+                        }
+                        output.push_str("null");
+                    }
+                }
+                schema::TypeVariant::Bool
+                | schema::TypeVariant::F64
+                | schema::TypeVariant::S64
+                | schema::TypeVariant::U64 => {
+                    let mut first = true;
+                    while !cursor.is_empty() {
+                        if !first {
+                            output.push(',');
+                        }
+                        first = false;
+                        let size = if matches!(inner_type.variant, schema::TypeVariant::F64) {
+                            8
+                        } else {
+                            cursor.varint_size()?
+                        };
+                        decode_value(
+                            schemas,
+                            namespace,
+                            &inner_type.variant,
+                            cursor.take(size)?,
+                            false,
+                            output,
+                        )?;
+                    }
+                }
+                schema::TypeVariant::Array(_)
+                | schema::TypeVariant::Bytes
+                | schema::TypeVariant::Custom(_, _)
+                | schema::TypeVariant::String => {
+                    let mut first = true;
+                    while !cursor.is_empty() {
+                        if !first {
+                            output.push(',');
+                        }
+                        first = false;
+                        let size = usize::try_from(cursor.varint()?)
+                            .map_err(|_| "Element size is too large.".to_owned())?;
+                        decode_value(
+                            schemas,
+                            namespace,
+                            &inner_type.variant,
+                            cursor.take(size)?,
+                            false,
+                            output,
+                        )?;
+                    }
+                }
+            }
+
+            output.push(']');
+            return Ok(());
+        }
+        schema::TypeVariant::Bool | schema::TypeVariant::S64 | schema::TypeVariant::U64 => {
+            let value = if is_field && payload.is_empty() {
+                0
+            } else if is_field && payload.len() == 8 {
+                let mut buffer = [0; 8];
+                buffer.copy_from_slice(cursor.take(8)?);
+                u64::from_le_bytes(buffer)
+            } else {
+                cursor.varint()?
+            };
+
+            match type_variant {
+                schema::TypeVariant::Bool => {
+                    output.push_str(if value == 0 { "false" } else { "true" });
+                }
+                schema::TypeVariant::S64 => {
+                    write!(
+                        output,
+                        "{}",
+                        (value >> 1_u32).cast_signed() ^ -(value & 1).cast_signed()
+                    )
+                    .unwrap();
+                }
+                _ => write!(output, "{value}").unwrap(),
+            }
+        }
+        schema::TypeVariant::Bytes => {
+            output.push('[');
+            for (i, byte) in cursor.take(payload.len())?.iter().enumerate() {
+                if i != 0 {
+                    output.push(',');
+                }
+                write!(output, "{byte}").unwrap();
+            }
+            output.push(']');
+        }
+        schema::TypeVariant::Custom(_, name) => {
+            decode_message(
+                schemas,
+                namespace,
+                name,
+                cursor.take(payload.len())?,
+                output,
+            )?;
+        }
+        schema::TypeVariant::F64 => {
+            let value = if is_field && payload.is_empty() {
+                0.0_f64
+            } else {
+                let mut buffer = [0; 8];
+                buffer.copy_from_slice(cursor.take(8)?);
+                f64::from_le_bytes(buffer)
+            };
+
+            // JSON can't represent infinities or NaN, so they're written as strings.
+            if value.is_finite() {
+                write!(output, "{value:?}").unwrap();
+            } else {
+                write_string(output, &value.to_string());
+            }
+        }
+        schema::TypeVariant::String => {
+            write_string(
+                output,
+                std::str::from_utf8(cursor.take(payload.len())?)
+                    .map_err(|_| "String isn't valid UTF-8.".to_owned())?,
+            );
+        }
+        schema::TypeVariant::Unit => output.push_str("null"),
+    }
+
+    if cursor.is_empty() {
+        Ok(())
+    } else {
+        Err("Unexpected data after the end of a value.".to_owned())
+    }
+}
+
+// Write an unrecognized field as JSON.
+fn write_unknown_field(output: &mut String, index: u64, size_mode: Option<u8>, payload: &[u8]) {
+    // The `unwrap`s are safe because the `std::fmt::Write` impl for `String` is infallible.
+    write!(output, "{{\"index\":{index},").unwrap();
+    if let Some(size_mode) = size_mode {
+        write!(output, "\"size_mode\":{size_mode},").unwrap();
+    }
+    output.push_str("\"payload\":[");
+    for (i, byte) in payload.iter().enumerate() {
+        if i != 0 {
+            output.push(',');
+        }
+        write!(output, "{byte}").unwrap();
+    }
+    output.push_str("]}");
+}
+
+// Write a string as a JSON string literal.
+fn write_string(output: &mut String, string: &str) {
+    output.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+            _ if c < ' ' => write!(output, "\\u{:04x}", u32::from(c)).unwrap(),
+            _ => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        decode::{Cursor, crc32, decode_message},
+        desugar::desugar,
+        parser::parse,
+        schema,
+        tokenizer::tokenize,
+        validator::validate,
+    };
+    use std::{collections::BTreeMap, path::Path};
+
+    // Decode a message of type `Foo` from the given schema.
+    fn decode(schema_contents: &str, bytes: &[u8]) -> Result<String, String> {
+        let path = Path::new("foo.t");
+        let namespace = schema::Namespace { components: vec![] };
+        let tokens = tokenize(path, schema_contents).unwrap();
+        let schema = parse(path, schema_contents, &tokens).unwrap();
+        let mut schemas = BTreeMap::new();
+        schemas.insert(
+            namespace.clone(),
+            (schema, path.to_owned(), schema_contents.to_owned()),
+        );
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let mut output = String::new();
+        decode_message(&schemas, &namespace, &"Foo".into(), bytes, &mut output)?;
+        Ok(output)
+    }
+
+    #[test]
+    fn varints() {
+        for (bytes, expected) in [
+            (vec![0b0000_0001], 0),
+            (vec![0b1111_1111], 127),
+            (vec![0b0000_0010, 0], 128),
+            (vec![0b0000_0100, 0, 0], 16_512),
+            (
+                vec![0b1000_0000, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+                72_624_976_668_147_839,
+            ),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0], 72_624_976_668_147_840),
+        ] {
+            assert_eq!(Cursor { bytes: &bytes }.varint(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn decode_struct() {
+        assert_eq!(
+            decode(
+                "
+                struct Foo {
+                    a: U64 = 0
+                    b: S64 = 1
+                    optional c: String = 2
+                    d: [Bool] = 3
+                    e: F64 = 4
+                    f: Bar = 5
+                }
+
+                choice Bar {
+                    optional x: Bytes = 0
+                    y = 1
+                }
+                ",
+                &[
+                    // a = 42
+                    0b0000_0101,
+                    0b0101_0101,
+                    // b = -2
+                    0b0000_1101,
+                    0b0000_0111,
+                    // d = [true, false]
+                    0b0001_1111,
+                    0b0000_0101,
+                    0b0000_0011,
+                    0b0000_0001,
+                    // e = 0.0
+                    0b0010_0001,
+                    // f = x([1]) with y as the fallback
+                    0b0010_1111,
+                    0b0000_0111,
+                    0b0000_0101,
+                    0b0000_0001,
+                    0b0000_1001,
+                    // An unrecognized field
+                    0b0011_0001,
+                ],
+            ),
+            Ok(
+                r#"{"a":42,"b":-2,"d":[true,false],"e":0.0,"f":{"x":[1],"$fallback":{"y":null}}}"#
+                    .to_owned()
+            ),
+        );
+    }
+
+    #[test]
+    fn decode_missing_required_field() {
+        assert!(
+            decode(
+                "
+                struct Foo {
+                    a: String = 0
+                }
+                ",
+                &[],
+            )
+            .is_err(),
+        );
+    }
+
+    #[test]
+    fn decode_open_choice() {
+        assert_eq!(
+            decode(
+                "
+                open choice Foo {
+                    a: String = 0
+                }
+                ",
+                &[0b0000_1111, 0b0000_0101, 0x68, 0x69],
+            ),
+            Ok(r#"{"$unknown":{"index":1,"payload":[104,105]}}"#.to_owned()),
+        );
+    }
+}
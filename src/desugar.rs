@@ -0,0 +1,429 @@
+use crate::{identifier::Identifier, schema};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem::take,
+    path::PathBuf,
+};
+
+// This function rewrites convenience syntax into the core language understood by the code
+// generators:
+//
+//   1. References to types imported by name are qualified with the name of the import.
+//   2. Includes are replaced by the fields of the included structs, with their indices offset
+//      accordingly. Imports are added as needed for the types of those fields.
+//
+// The schemas are assumed to be valid.
+pub fn desugar(schemas: &mut BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>) {
+    for (schema, _, _) in schemas.values_mut() {
+        // Qualify the types of fields and includes.
+        let mut declarations = take(&mut schema.declarations);
+        for declaration in &mut declarations {
+            for include in &mut declaration.includes {
+                qualify_type(schema, &mut include.r#type);
+            }
+
+            for field in &mut declaration.fields {
+                qualify_type(schema, &mut field.r#type);
+            }
+        }
+        schema.declarations = declarations;
+
+        // Qualify the request and response types of methods.
+        let mut services = take(&mut schema.services);
+        for service in &mut services {
+            for method in &mut service.methods {
+                qualify_type(schema, &mut method.request);
+                qualify_type(schema, &mut method.response);
+            }
+        }
+        schema.services = services;
+    }
+
+    // Compute the flattened fields for every declaration with includes. This is done before
+    // updating any of the schemas, since the included structs may live in other schemas.
+    let mut flattened_schemas = vec![];
+    for (namespace, (schema, _, _)) in schemas.iter() {
+        let mut imports = schema.imports.clone();
+        let mut flattened_declarations = vec![];
+
+        for (i, declaration) in schema.declarations.iter().enumerate() {
+            if declaration.includes.is_empty() {
+                continue;
+            }
+
+            let mut fields = vec![];
+            let mut deleted = declaration.deleted.clone();
+            for include in &declaration.includes {
+                splice_include(
+                    schemas,
+                    namespace,
+                    &mut imports,
+                    &mut fields,
+                    &mut deleted,
+                    namespace,
+                    schema,
+                    include,
+                    0,
+                );
+            }
+            fields.extend(declaration.fields.iter().cloned());
+
+            flattened_declarations.push((i, fields, deleted));
+        }
+
+        if !flattened_declarations.is_empty() {
+            flattened_schemas.push((namespace.clone(), imports, flattened_declarations));
+        }
+    }
+
+    // Replace the includes with the flattened fields.
+    for (namespace, imports, flattened_declarations) in flattened_schemas {
+        // The `unwrap` is safe since the namespace came from `schemas` above.
+        let (schema, _, _) = schemas.get_mut(&namespace).unwrap();
+        schema.imports = imports;
+
+        for (i, fields, deleted) in flattened_declarations {
+            let declaration = &mut schema.declarations[i];
+            declaration.includes = vec![];
+            declaration.fields = fields;
+            declaration.deleted = deleted;
+        }
+    }
+}
+
+// Append the fields of an included struct (and any structs it includes) to `fields`, and add its
+// deleted indices to `deleted`. The types of those fields are rewritten to be valid in the host
+// schema, adding imports to `host_imports` as necessary.
+#[allow(clippy::too_many_arguments)]
+fn splice_include(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    host_namespace: &schema::Namespace,
+    host_imports: &mut BTreeMap<Identifier, schema::Import>,
+    fields: &mut Vec<schema::Field>,
+    deleted: &mut BTreeSet<usize>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    include: &schema::Include,
+    offset: usize,
+) {
+    // Find the included struct. The `unwrap`s are safe since the schemas are valid.
+    let (included_namespace, included_name) = type_namespace(namespace, schema, &include.r#type);
+    let (included_schema, _, _) = &schemas[&included_namespace];
+    let included_declaration = included_schema
+        .declarations
+        .iter()
+        .find(|declaration| declaration.name == included_name)
+        .unwrap();
+    // The `unwrap` is safe due to [ref:included_indices_fit].
+    let offset = offset.checked_add(include.offset).unwrap();
+
+    // Splice in the fields from any nested includes.
+    for nested_include in &included_declaration.includes {
+        splice_include(
+            schemas,
+            host_namespace,
+            host_imports,
+            fields,
+            deleted,
+            &included_namespace,
+            included_schema,
+            nested_include,
+            offset,
+        );
+    }
+
+    // Splice in the fields of the included struct itself.
+    for field in &included_declaration.fields {
+        let mut field = field.clone();
+        // The `unwrap` is safe due to [ref:included_indices_fit].
+        field.index = field.index.checked_add(offset).unwrap();
+        rehome_type(
+            host_namespace,
+            host_imports,
+            &included_namespace,
+            included_schema,
+            &mut field.r#type,
+        );
+        fields.push(field);
+    }
+
+    // Reserve the indices deleted from the included struct.
+    for index in &included_declaration.deleted {
+        // The `unwrap` is safe due to [ref:included_indices_fit].
+        deleted.insert(index.checked_add(offset).unwrap());
+    }
+}
+
+// Rewrite a type from one schema so it refers to the same declaration from the host schema. An
+// import is added to `host_imports` if the host schema doesn't already import the relevant file.
+fn rehome_type(
+    host_namespace: &schema::Namespace,
+    host_imports: &mut BTreeMap<Identifier, schema::Import>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    r#type: &mut schema::Type,
+) {
+    match &mut r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            rehome_type(host_namespace, host_imports, namespace, schema, inner_type);
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {}
+        schema::TypeVariant::Custom(_, _) => {
+            let (target_namespace, name) = type_namespace(namespace, schema, r#type);
+
+            let import = if target_namespace == *host_namespace {
+                None
+            } else if let Some((import_name, _)) = host_imports
+                .iter()
+                .find(|(_, import)| import.namespace.as_ref() == Some(&target_namespace))
+            {
+                Some(import_name.clone())
+            } else {
+                // Choose a name which doesn't collide with any existing imports.
+                let mut counter: usize = 0;
+                let import_name = loop {
+                    let candidate: Identifier = format!("included_{counter}").as_str().into();
+                    if !host_imports.contains_key(&candidate) {
+                        break candidate;
+                    }
+                    counter += 1;
+                };
+
+                // The empty path marks the import as synthetic [tag:synthetic_import].
+                host_imports.insert(
+                    import_name.clone(),
+                    schema::Import {
+                        source_range: r#type.source_range,
+                        path: PathBuf::new(),
+                        namespace: Some(target_namespace),
+                        names: vec![],
+                    },
+                );
+
+                Some(import_name)
+            };
+
+            r#type.variant = schema::TypeVariant::Custom(import, name);
+        }
+    }
+}
+
+// Determine the namespace and name of the declaration referred to by a qualified type. The type is
+// assumed to be a valid reference to a user-defined type.
+fn type_namespace(
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    r#type: &schema::Type,
+) -> (schema::Namespace, Identifier) {
+    if let schema::TypeVariant::Custom(import, name) = &r#type.variant {
+        (
+            import.as_ref().map_or_else(
+                || namespace.clone(),
+                // The `unwrap` is safe due to [ref:namespace_populated].
+                |import| schema.imports[import].namespace.clone().unwrap(),
+            ),
+            name.clone(),
+        )
+    } else {
+        // Impossible since the schemas are valid.
+        panic!()
+    }
+}
+
+// Replace any unqualified references to types imported by name with qualified references.
+fn qualify_type(schema: &schema::Schema, r#type: &mut schema::Type) {
+    match &mut r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            qualify_type(schema, inner_type);
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {}
+        schema::TypeVariant::Custom(import, name) => {
+            let (qualified_import, qualified_name) = schema.qualify(import.as_ref(), name);
+            *import = qualified_import;
+            *name = qualified_name;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        desugar::desugar, parser::parse, schema, tokenizer::tokenize, validator::validate,
+    };
+    use std::{collections::BTreeMap, path::Path};
+
+    #[test]
+    fn desugar_imported_names() {
+        let foo_namespace = schema::Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Bar, Baz as Qux }
+
+            struct Foo {
+                x: Bar = 0
+                y: [Qux] = 1
+                z: bar.Baz = 2
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = schema::Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
+            }
+
+            choice Baz {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace.clone(), (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        assert_eq!(
+            schemas[&foo_namespace].0.declarations[0]
+                .fields
+                .iter()
+                .map(|field| field.r#type.to_string())
+                .collect::<Vec<_>>(),
+            vec!["bar.Bar", "[bar.Baz]", "bar.Baz"],
+        );
+    }
+
+    #[test]
+    fn desugar_includes() {
+        let foo_namespace = schema::Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                include bar.Bar at 10
+
+                x: Bool = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = schema::Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            import 'grault.t'
+
+            struct Bar {
+                include Qux at 2
+
+                y: grault.Grault = 0
+
+                deleted 1
+            }
+
+            struct Qux {
+                z: [Corge] = 0
+            }
+
+            struct Corge {
+            }
+        "
+        .to_owned();
+
+        let grault_namespace = schema::Namespace {
+            components: vec!["grault".into()],
+        };
+        let grault_path = Path::new("grault.t").to_owned();
+        let grault_contents = "
+            struct Grault {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let mut bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+        bar_schema
+            .imports
+            .get_mut(&"grault".into())
+            .unwrap()
+            .namespace = Some(grault_namespace.clone());
+
+        let grault_tokens = tokenize(&grault_path, &grault_contents).unwrap();
+        let grault_schema = parse(&grault_path, &grault_contents, &grault_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace.clone(), (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+        schemas.insert(
+            grault_namespace,
+            (grault_schema, grault_path, grault_contents),
+        );
+
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let foo_schema = &schemas[&foo_namespace].0;
+        let declaration = &foo_schema.declarations[0];
+        assert!(declaration.includes.is_empty());
+        assert_eq!(
+            declaration
+                .fields
+                .iter()
+                .map(|field| format!(
+                    "{}: {} = {}",
+                    field.name.snake_case(),
+                    field.r#type,
+                    field.index
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                "z: [bar.Corge] = 12",
+                "y: included_0.Grault = 10",
+                "x: Bool = 0",
+            ],
+        );
+        assert_eq!(
+            declaration.deleted.iter().copied().collect::<Vec<_>>(),
+            vec![11]
+        );
+        assert_eq!(
+            foo_schema.imports[&"included_0".into()].namespace,
+            Some(schema::Namespace {
+                components: vec!["grault".into()],
+            }),
+        );
+    }
+}
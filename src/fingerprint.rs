@@ -0,0 +1,572 @@
+use crate::{
+    identifier::Identifier,
+    schema,
+    token::{
+        ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD, F64_KEYWORD, OPEN_KEYWORD,
+        OPTIONAL_KEYWORD, S64_KEYWORD, STRING_KEYWORD, STRUCT_KEYWORD, U64_KEYWORD, UNIT_KEYWORD,
+    },
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::Write,
+    path::PathBuf,
+};
+
+// The parameters of the 64-bit FNV-1a hash function
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// The label for required fields in the normal form, since they don't have a keyword
+const REQUIRED_LABEL: &str = "required";
+
+// The label for declarations with a single field which isn't optional in the wire normal form,
+// since such a struct is encoded in the same way as a choice with just that field
+const SINGLE_LABEL: &str = "single";
+
+// Which details of the declarations are reflected in a normal form
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Detail {
+    // The structure of the declarations
+    Exact,
+
+    // Only the details which could stop two versions of the declarations from exchanging messages
+    Wire,
+}
+
+// Compute the fingerprint of a declaration, which is the 64-bit FNV-1a hash of its normal form
+// [tag:fingerprint]. The schemas are assumed to be valid and desugared.
+pub fn fingerprint(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+) -> u64 {
+    hash(&normal_form(schemas, namespace, name, Detail::Exact))
+}
+
+// Compute the wire fingerprint of a declaration, which is the 64-bit FNV-1a hash of its wire
+// normal form [tag:wire_fingerprint]. Declarations with the same wire fingerprint can always
+// exchange messages, provided that the indices of removed fields aren't reused. The converse
+// doesn't hold: compatibility isn't transitive, so no hash could be preserved by every compatible
+// change. In particular, adding or removing an asymmetric field changes the wire fingerprint. The
+// schemas are assumed to be valid and desugared.
+pub fn wire_fingerprint(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+) -> u64 {
+    hash(&normal_form(schemas, namespace, name, Detail::Wire))
+}
+
+// Compute the 64-bit FNV-1a hash of a string.
+fn hash(string: &str) -> u64 {
+    string.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+// Render a declaration and the declarations it refers to (transitively) in a form which only
+// reflects their structure. There is one line per declaration, starting with the given one and
+// continuing in the order in which the declarations are first referred to. Each line lists the
+// fields ordered by index, and references to declarations are written as their line numbers. So
+// the names of the declarations and fields, the comments, the deleted indices, the order of the
+// fields, and the files in which the declarations live don't affect the result.
+//
+// With `Detail::Wire`, the result also doesn't reflect whether the declarations are open, which
+// fields are asymmetric rather than required, or the optional fields, and declarations with a
+// single field which isn't optional are rendered in the same way regardless of whether they're
+// structs or choices. Those are the differences which don't affect whether two versions of a
+// declaration can exchange messages. Leaving out the optional fields means that changing the type
+// of one isn't reflected either, but that amounts to reusing the index of a removed field, which
+// isn't safe regardless.
+//
+// The schemas are assumed to be valid and desugared.
+pub fn normal_form(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+    detail: Detail,
+) -> String {
+    let mut numbers = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    numbers.insert((namespace.clone(), name.clone()), 0_usize);
+    queue.push_back((namespace.clone(), name.clone()));
+
+    let mut buffer = String::new();
+
+    while let Some((namespace, name)) = queue.pop_front() {
+        // The `unwrap` is safe since the schemas are valid.
+        let schema = &schemas[&namespace].0;
+        let declaration = schema
+            .declarations
+            .iter()
+            .find(|declaration| declaration.name == name)
+            .unwrap();
+
+        let mut fields = declaration
+            .fields
+            .iter()
+            .filter(|field| {
+                detail == Detail::Exact || !matches!(field.rule, schema::Rule::Optional)
+            })
+            .collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.index);
+
+        let single = detail == Detail::Wire && fields.len() == 1;
+
+        buffer.push_str(match (single, &declaration.variant) {
+            (true, _) => SINGLE_LABEL,
+            (false, schema::DeclarationVariant::Struct) => STRUCT_KEYWORD,
+            (false, schema::DeclarationVariant::Choice) => CHOICE_KEYWORD,
+        });
+
+        if declaration.open && detail == Detail::Exact {
+            buffer.push(' ');
+            buffer.push_str(OPEN_KEYWORD);
+        }
+
+        for field in fields {
+            // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+            write!(
+                buffer,
+                " {}:{}:",
+                field.index,
+                match field.rule {
+                    schema::Rule::Asymmetric if detail == Detail::Exact => ASYMMETRIC_KEYWORD,
+                    schema::Rule::Optional => OPTIONAL_KEYWORD,
+                    schema::Rule::Asymmetric | schema::Rule::Required => REQUIRED_LABEL,
+                },
+            )
+            .unwrap();
+
+            write_type(
+                &mut buffer,
+                schemas,
+                &namespace,
+                &field.r#type.variant,
+                &mut numbers,
+                &mut queue,
+            );
+        }
+
+        buffer.push('\n');
+    }
+
+    buffer
+}
+
+// Write a type for the normal form, numbering any declaration which hasn't been seen yet.
+fn write_type(
+    buffer: &mut String,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+    numbers: &mut BTreeMap<(schema::Namespace, Identifier), usize>,
+    queue: &mut VecDeque<(schema::Namespace, Identifier)>,
+) {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            buffer.push('[');
+            write_type(
+                buffer,
+                schemas,
+                namespace,
+                &inner_type.variant,
+                numbers,
+                queue,
+            );
+            buffer.push(']');
+        }
+        schema::TypeVariant::Bool => buffer.push_str(BOOL_KEYWORD),
+        schema::TypeVariant::Bytes => buffer.push_str(BYTES_KEYWORD),
+        schema::TypeVariant::Custom(import, name) => {
+            // The `unwrap`s are safe due to [ref:namespace_populated].
+            let key = (
+                import.as_ref().map_or_else(
+                    || namespace.clone(),
+                    |import| {
+                        schemas[namespace].0.imports[import]
+                            .namespace
+                            .clone()
+                            .unwrap()
+                    },
+                ),
+                name.clone(),
+            );
+
+            let next_number = numbers.len();
+            let number = *numbers.entry(key.clone()).or_insert_with(|| {
+                queue.push_back(key);
+                next_number
+            });
+
+            // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+            write!(buffer, "#{number}").unwrap();
+        }
+        schema::TypeVariant::F64 => buffer.push_str(F64_KEYWORD),
+        schema::TypeVariant::S64 => buffer.push_str(S64_KEYWORD),
+        schema::TypeVariant::String => buffer.push_str(STRING_KEYWORD),
+        schema::TypeVariant::U64 => buffer.push_str(U64_KEYWORD),
+        schema::TypeVariant::Unit => buffer.push_str(UNIT_KEYWORD),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        desugar::desugar,
+        fingerprint::{Detail, fingerprint, normal_form, wire_fingerprint},
+        parser::parse,
+        schema,
+        tokenizer::tokenize,
+        validator::validate,
+    };
+    use std::{collections::BTreeMap, path::Path};
+
+    // Load a schema named `foo` which may import a schema named `bar`.
+    fn load(
+        foo_contents: &str,
+        bar_contents: &str,
+    ) -> BTreeMap<schema::Namespace, (schema::Schema, std::path::PathBuf, String)> {
+        let foo_namespace = schema::Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let bar_namespace = schema::Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+
+        let foo_tokens = tokenize(&foo_path, foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, foo_contents, &foo_tokens).unwrap();
+        if let Some(import) = foo_schema.imports.get_mut(&"bar".into()) {
+            import.namespace = Some(bar_namespace.clone());
+        }
+
+        let bar_tokens = tokenize(&bar_path, bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(
+            foo_namespace,
+            (foo_schema, foo_path, foo_contents.to_owned()),
+        );
+        schemas.insert(
+            bar_namespace,
+            (bar_schema, bar_path, bar_contents.to_owned()),
+        );
+
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        schemas
+    }
+
+    fn foo_fingerprint(foo_contents: &str, bar_contents: &str) -> u64 {
+        fingerprint(
+            &load(foo_contents, bar_contents),
+            &schema::Namespace {
+                components: vec!["foo".into()],
+            },
+            &"Foo".into(),
+        )
+    }
+
+    fn foo_wire_fingerprint(foo_contents: &str) -> u64 {
+        wire_fingerprint(
+            &load(foo_contents, ""),
+            &schema::Namespace {
+                components: vec!["foo".into()],
+            },
+            &"Foo".into(),
+        )
+    }
+
+    #[test]
+    fn normal_form_structure() {
+        let schemas = load(
+            "
+            import 'bar.t'
+
+            struct Foo {
+                c: [bar.Bar] = 2
+                optional a: Baz = 0
+                asymmetric b: bar.Bar = 1
+            }
+
+            choice Baz {
+                x: Unit = 0
+            }
+            ",
+            "
+            struct Bar {
+                y: [[U64]] = 0
+            }
+            ",
+        );
+
+        assert_eq!(
+            normal_form(
+                &schemas,
+                &schema::Namespace {
+                    components: vec!["foo".into()],
+                },
+                &"Foo".into(),
+                Detail::Exact,
+            ),
+            "\
+struct 0:optional:#1 1:asymmetric:#2 2:required:[#2]
+choice 0:required:Unit
+struct 0:required:[[U64]]
+",
+        );
+    }
+
+    #[test]
+    fn fingerprint_ignores_names_and_order() {
+        assert_eq!(
+            foo_fingerprint(
+                "
+                # A comment
+                struct Foo {
+                    x: String = 0
+                    y: Bar = 1
+                    deleted 2
+                }
+
+                struct Bar {
+                }
+                ",
+                "",
+            ),
+            foo_fingerprint(
+                "
+                import 'bar.t'
+
+                struct Foo {
+                    b: bar.Qux = 1
+                    a: String = 0
+                }
+                ",
+                "
+                struct Qux {
+                }
+                ",
+            ),
+        );
+    }
+
+    #[test]
+    fn fingerprint_includes() {
+        assert_eq!(
+            foo_fingerprint(
+                "
+                struct Foo {
+                    include Bar at 1
+                    x: String = 0
+                }
+
+                struct Bar {
+                    y: U64 = 0
+                }
+                ",
+                "",
+            ),
+            foo_fingerprint(
+                "
+                struct Foo {
+                    x: String = 0
+                    y: U64 = 1
+                }
+                ",
+                "",
+            ),
+        );
+    }
+
+    #[test]
+    fn fingerprint_structure() {
+        let original = foo_fingerprint(
+            "
+            struct Foo {
+                x: String = 0
+            }
+            ",
+            "",
+        );
+
+        for changed in [
+            "
+            choice Foo {
+                x: String = 0
+            }
+            ",
+            "
+            struct Foo {
+                x: String = 1
+                deleted 0
+            }
+            ",
+            "
+            struct Foo {
+                optional x: String = 0
+            }
+            ",
+            "
+            struct Foo {
+                x: Bytes = 0
+            }
+            ",
+            "
+            struct Foo {
+                x: String = 0
+                y: Unit = 1
+            }
+            ",
+        ] {
+            assert_ne!(foo_fingerprint(changed, ""), original);
+        }
+    }
+
+    #[test]
+    fn normal_form_wire() {
+        let schemas = load(
+            "
+            open struct Foo {
+                a: Bar = 0
+                asymmetric b: [Bar] = 1
+                optional c: String = 2
+            }
+
+            open choice Bar {
+                x: U64 = 0
+            }
+            ",
+            "",
+        );
+
+        assert_eq!(
+            normal_form(
+                &schemas,
+                &schema::Namespace {
+                    components: vec!["foo".into()],
+                },
+                &"Foo".into(),
+                Detail::Wire,
+            ),
+            "\
+struct 0:required:#1 1:required:[#1]
+single 0:required:U64
+",
+        );
+    }
+
+    #[test]
+    fn wire_fingerprint_ignores_compatible_changes() {
+        let original = foo_wire_fingerprint(
+            "
+            struct Foo {
+                x: Bar = 0
+                optional y: String = 1
+            }
+
+            choice Bar {
+                z: U64 = 0
+            }
+            ",
+        );
+
+        for changed in [
+            "
+            open struct Foo {
+                optional why: String = 1
+                ex: Bar = 0
+            }
+
+            open choice Bar {
+                z: U64 = 0
+            }
+            ",
+            "
+            struct Foo {
+                asymmetric x: Bar = 0
+                optional y: String = 1
+            }
+
+            struct Bar {
+                z: U64 = 0
+            }
+            ",
+            "
+            struct Foo {
+                x: Bar = 0
+            }
+
+            choice Bar {
+                z: U64 = 0
+            }
+            ",
+            "
+            struct Foo {
+                x: Bar = 0
+                optional y: String = 1
+                optional w: Bytes = 2
+            }
+
+            struct Bar {
+                z: U64 = 0
+                optional v: F64 = 1
+            }
+            ",
+        ] {
+            assert_eq!(foo_wire_fingerprint(changed), original);
+        }
+    }
+
+    #[test]
+    fn wire_fingerprint_detects_incompatible_changes() {
+        let original = foo_wire_fingerprint(
+            "
+            struct Foo {
+                x: String = 0
+                optional y: U64 = 1
+            }
+            ",
+        );
+
+        for changed in [
+            "
+            struct Foo {
+                x: Bytes = 0
+                optional y: U64 = 1
+            }
+            ",
+            "
+            struct Foo {
+                x: String = 0
+                optional y: U64 = 1
+                asymmetric z: Bool = 2
+            }
+            ",
+            "
+            struct Foo {
+                optional x: String = 0
+                optional y: U64 = 1
+            }
+            ",
+            "
+            struct Foo {
+                x: String = 0
+                y: U64 = 1
+            }
+            ",
+            "
+            choice Foo {
+                x: String = 0
+                y: U64 = 1
+            }
+            ",
+        ] {
+            assert_ne!(foo_wire_fingerprint(changed), original);
+        }
+    }
+}
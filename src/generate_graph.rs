@@ -0,0 +1,483 @@
+use crate::{
+    count::count,
+    identifier::Identifier,
+    schema,
+    token::{ASYMMETRIC_KEYWORD, OPTIONAL_KEYWORD},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+// The indentation used for the generated graphs
+const INDENTATION: &str = "  ";
+
+// The label for required fields, since they don't have a keyword
+const REQUIRED_LABEL: &str = "required";
+
+// This enum represents the languages in which a graph can be described.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Dot,
+    Mermaid,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "dot" => Ok(Format::Dot),
+            "mermaid" => Ok(Format::Mermaid),
+            format => Err(format!("Expected dot or mermaid, but got {format}.")),
+        }
+    }
+}
+
+// The kinds of nodes in a graph
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum NodeKind {
+    Struct,
+    Choice,
+    Namespace,
+}
+
+// A node in a graph, which is either a declaration or a whole namespace
+struct Node {
+    label: String,
+    kind: NodeKind,
+    cluster: Option<usize>, // The index of the namespace which the node is drawn in, if any
+}
+
+// An edge in a graph, which represents one or more fields
+struct Edge {
+    from: usize,
+    to: usize,
+    label: String,
+    array: bool, // Whether the fields are arrays
+}
+
+// Describe the graph of declarations in a schema and its transitive dependencies. There is a node
+// for each declaration, grouped by namespace, and an edge for each field which refers to another
+// declaration. Edges for arrays are dashed. If `collapse_namespaces` is set, there is a node for
+// each namespace instead, and an edge from one namespace to another if any of its fields refer to
+// the other. The schemas are assumed to be valid and desugared.
+pub fn generate(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    format: Format,
+    collapse_namespaces: bool,
+) -> String {
+    let (clusters, nodes, edges) = if collapse_namespaces {
+        namespace_graph(schemas)
+    } else {
+        declaration_graph(schemas)
+    };
+
+    let mut buffer = String::new();
+
+    // The `unwrap`s are safe because the `std::fmt::Write` impl for `String` is infallible.
+    match format {
+        Format::Dot => write_dot(&mut buffer, &clusters, &nodes, &edges).unwrap(),
+        Format::Mermaid => write_mermaid(&mut buffer, &clusters, &nodes, &edges).unwrap(),
+    }
+
+    buffer
+}
+
+// Compute the graph with a node for each declaration. The clusters are the namespaces.
+fn declaration_graph(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> (Vec<String>, Vec<Node>, Vec<Edge>) {
+    let mut clusters = vec![];
+    let mut nodes = vec![];
+    let mut node_indices = BTreeMap::new();
+
+    for (namespace, (schema, _, _)) in schemas {
+        for declaration in &schema.declarations {
+            node_indices.insert((namespace.clone(), declaration.name.clone()), nodes.len());
+            nodes.push(Node {
+                label: declaration.name.original().to_owned(),
+                kind: match declaration.variant {
+                    schema::DeclarationVariant::Struct => NodeKind::Struct,
+                    schema::DeclarationVariant::Choice => NodeKind::Choice,
+                },
+                cluster: Some(clusters.len()),
+            });
+        }
+
+        clusters.push(namespace_label(namespace));
+    }
+
+    let mut edges = vec![];
+
+    for (namespace, (schema, _, _)) in schemas {
+        for declaration in &schema.declarations {
+            for field in &declaration.fields {
+                if let Some((target, array)) =
+                    field_target(schemas, namespace, &field.r#type.variant)
+                {
+                    edges.push(Edge {
+                        from: node_indices[&(namespace.clone(), declaration.name.clone())],
+                        to: node_indices[&target],
+                        label: format!(
+                            "{} ({})",
+                            field.name.original(),
+                            match field.rule {
+                                schema::Rule::Asymmetric => ASYMMETRIC_KEYWORD,
+                                schema::Rule::Optional => OPTIONAL_KEYWORD,
+                                schema::Rule::Required => REQUIRED_LABEL,
+                            },
+                        ),
+                        array,
+                    });
+                }
+            }
+        }
+    }
+
+    (clusters, nodes, edges)
+}
+
+// Compute the graph with a node for each namespace. There are no clusters.
+fn namespace_graph(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> (Vec<String>, Vec<Node>, Vec<Edge>) {
+    let node_indices = schemas
+        .keys()
+        .enumerate()
+        .map(|(i, namespace)| (namespace.clone(), i))
+        .collect::<BTreeMap<_, _>>();
+
+    let nodes = schemas
+        .keys()
+        .map(|namespace| Node {
+            label: namespace_label(namespace),
+            kind: NodeKind::Namespace,
+            cluster: None,
+        })
+        .collect();
+
+    // For each pair of namespaces, count the fields in one which refer to the other, and whether
+    // they're all arrays.
+    let mut references = BTreeMap::<(usize, usize), (usize, bool)>::new();
+
+    for (namespace, (schema, _, _)) in schemas {
+        for field in schema
+            .declarations
+            .iter()
+            .flat_map(|declaration| &declaration.fields)
+        {
+            if let Some(((target_namespace, _), array)) =
+                field_target(schemas, namespace, &field.r#type.variant)
+                && target_namespace != *namespace
+            {
+                let (fields, arrays) = references
+                    .entry((node_indices[namespace], node_indices[&target_namespace]))
+                    .or_insert((0, true));
+                *fields += 1;
+                *arrays &= array;
+            }
+        }
+    }
+
+    let edges = references
+        .into_iter()
+        .map(|((from, to), (fields, array))| Edge {
+            from,
+            to,
+            label: count(fields, "field"),
+            array,
+        })
+        .collect();
+
+    (vec![], nodes, edges)
+}
+
+// Find the declaration which the type of a field refers to, and whether the field is an array.
+// Arrays don't break cycles, since the validator looks through them when checking for cycles
+// [ref:cycles_through_arrays], but they're distinguished here since they're often what a reader
+// needs to notice.
+fn field_target(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+) -> Option<((schema::Namespace, Identifier), bool)> {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            field_target(schemas, namespace, &inner_type.variant).map(|(target, _)| (target, true))
+        }
+        schema::TypeVariant::Custom(import, name) => {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let target_namespace = import.as_ref().map_or_else(
+                || namespace.clone(),
+                |import| {
+                    schemas[namespace].0.imports[import]
+                        .namespace
+                        .clone()
+                        .unwrap()
+                },
+            );
+
+            Some(((target_namespace, name.clone()), false))
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => None,
+    }
+}
+
+// Render a namespace in the same form as the selectors accepted by the code generators, e.g.,
+// `foo::types`.
+fn namespace_label(namespace: &schema::Namespace) -> String {
+    namespace
+        .components
+        .iter()
+        .map(Identifier::original)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+// Write a graph in the Graphviz DOT language.
+fn write_dot<T: Write>(
+    buffer: &mut T,
+    clusters: &[String],
+    nodes: &[Node],
+    edges: &[Edge],
+) -> Result<(), fmt::Error> {
+    writeln!(buffer, "digraph {{")?;
+
+    let write_node = |buffer: &mut T, indentation: &str, i: usize, node: &Node| {
+        writeln!(
+            buffer,
+            "{indentation}n{i} [label=\"{}\", shape={}];",
+            node.label,
+            match node.kind {
+                NodeKind::Struct => "box",
+                NodeKind::Choice => "hexagon",
+                NodeKind::Namespace => "folder",
+            },
+        )
+    };
+
+    for (cluster_index, cluster) in clusters.iter().enumerate() {
+        writeln!(buffer, "{INDENTATION}subgraph cluster_{cluster_index} {{")?;
+        writeln!(buffer, "{INDENTATION}{INDENTATION}label=\"{cluster}\";")?;
+        for (i, node) in nodes.iter().enumerate() {
+            if node.cluster == Some(cluster_index) {
+                write_node(buffer, &INDENTATION.repeat(2), i, node)?;
+            }
+        }
+        writeln!(buffer, "{INDENTATION}}}")?;
+    }
+
+    for (i, node) in nodes.iter().enumerate() {
+        if node.cluster.is_none() {
+            write_node(buffer, INDENTATION, i, node)?;
+        }
+    }
+
+    for edge in edges {
+        writeln!(
+            buffer,
+            "{INDENTATION}n{} -> n{} [label=\"{}\"{}];",
+            edge.from,
+            edge.to,
+            edge.label,
+            if edge.array { ", style=dashed" } else { "" },
+        )?;
+    }
+
+    writeln!(buffer, "}}")
+}
+
+// Write a graph as a Mermaid flowchart.
+fn write_mermaid<T: Write>(
+    buffer: &mut T,
+    clusters: &[String],
+    nodes: &[Node],
+    edges: &[Edge],
+) -> Result<(), fmt::Error> {
+    writeln!(buffer, "flowchart LR")?;
+
+    let write_node = |buffer: &mut T, indentation: &str, i: usize, node: &Node| {
+        let (open, close) = match node.kind {
+            NodeKind::Struct => ("[", "]"),
+            NodeKind::Choice => ("{{", "}}"),
+            NodeKind::Namespace => ("[[", "]]"),
+        };
+        writeln!(buffer, "{indentation}n{i}{open}\"{}\"{close}", node.label)
+    };
+
+    for (cluster_index, cluster) in clusters.iter().enumerate() {
+        writeln!(
+            buffer,
+            "{INDENTATION}subgraph s{cluster_index} [\"{cluster}\"]"
+        )?;
+        for (i, node) in nodes.iter().enumerate() {
+            if node.cluster == Some(cluster_index) {
+                write_node(buffer, &INDENTATION.repeat(2), i, node)?;
+            }
+        }
+        writeln!(buffer, "{INDENTATION}end")?;
+    }
+
+    for (i, node) in nodes.iter().enumerate() {
+        if node.cluster.is_none() {
+            write_node(buffer, INDENTATION, i, node)?;
+        }
+    }
+
+    for edge in edges {
+        writeln!(
+            buffer,
+            "{INDENTATION}n{} {}|\"{}\"| n{}",
+            edge.from,
+            if edge.array { "-.->" } else { "-->" },
+            edge.label,
+            edge.to,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        desugar::desugar,
+        generate_graph::{Format, generate},
+        parser::parse,
+        schema,
+        tokenizer::tokenize,
+        validator::validate,
+    };
+    use std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+    };
+
+    // Load a schema named `foo` which imports a schema named `bar`.
+    fn load() -> BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)> {
+        let mut schemas = BTreeMap::new();
+
+        for (name, contents) in [
+            (
+                "foo",
+                "
+                import 'bar.t'
+
+                struct Foo {
+                    x: Baz = 0
+                    optional y: [bar.Bar] = 1
+                    z: String = 2
+                }
+
+                choice Baz {
+                    asymmetric w: bar.Bar = 0
+                }
+                ",
+            ),
+            (
+                "bar",
+                "
+                struct Bar {
+                }
+                ",
+            ),
+        ] {
+            let path = Path::new(name).with_extension("t");
+            let tokens = tokenize(&path, contents).unwrap();
+            let mut schema = parse(&path, contents, &tokens).unwrap();
+            for (import_name, import) in &mut schema.imports {
+                import.namespace = Some(schema::Namespace {
+                    components: vec![import_name.clone()],
+                });
+            }
+            schemas.insert(
+                schema::Namespace {
+                    components: vec![name.into()],
+                },
+                (schema, path, contents.to_owned()),
+            );
+        }
+
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        schemas
+    }
+
+    #[test]
+    fn generate_dot() {
+        assert_eq!(
+            generate(&load(), Format::Dot, false),
+            "\
+digraph {
+  subgraph cluster_0 {
+    label=\"bar\";
+    n0 [label=\"Bar\", shape=box];
+  }
+  subgraph cluster_1 {
+    label=\"foo\";
+    n1 [label=\"Foo\", shape=box];
+    n2 [label=\"Baz\", shape=hexagon];
+  }
+  n1 -> n2 [label=\"x (required)\"];
+  n1 -> n0 [label=\"y (optional)\", style=dashed];
+  n2 -> n0 [label=\"w (asymmetric)\"];
+}
+",
+        );
+    }
+
+    #[test]
+    fn generate_mermaid() {
+        assert_eq!(
+            generate(&load(), Format::Mermaid, false),
+            "\
+flowchart LR
+  subgraph s0 [\"bar\"]
+    n0[\"Bar\"]
+  end
+  subgraph s1 [\"foo\"]
+    n1[\"Foo\"]
+    n2{{\"Baz\"}}
+  end
+  n1 -->|\"x (required)\"| n2
+  n1 -.->|\"y (optional)\"| n0
+  n2 -->|\"w (asymmetric)\"| n0
+",
+        );
+    }
+
+    #[test]
+    fn generate_collapsed() {
+        assert_eq!(
+            generate(&load(), Format::Dot, true),
+            "\
+digraph {
+  n0 [label=\"bar\", shape=folder];
+  n1 [label=\"foo\", shape=folder];
+  n1 -> n0 [label=\"2 fields\"];
+}
+",
+        );
+
+        assert_eq!(
+            generate(&load(), Format::Mermaid, true),
+            "\
+flowchart LR
+  n0[[\"bar\"]]
+  n1[[\"foo\"]]
+  n1 -->|\"2 fields\"| n0
+",
+        );
+    }
+}
@@ -0,0 +1,633 @@
+use crate::{generate_typescript, schema};
+use std::{collections::BTreeMap, fmt::Write, path::PathBuf};
+
+// This enum represents the kinds of tokens in the generated TypeScript code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TokenKind {
+    Comment,
+    Punctuation,
+    String,
+    Whitespace,
+    Word,
+}
+
+// A token of the generated TypeScript code. Concatenating the tokens reproduces the code exactly.
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+}
+
+// Generate JavaScript files (ES modules) and the corresponding TypeScript declaration files from a
+// schema and its transitive dependencies. The paths are relative to the configured output
+// directory.
+//
+// The code is produced by the TypeScript generator and then converted. The TypeScript generator
+// only emits erasable syntax [ref:typescript_erasable_syntax], so the JavaScript is obtained by
+// erasing the types, and the declarations are obtained by erasing the function bodies.
+pub fn generate(
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    options: &generate_typescript::Options,
+) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::new();
+
+    // The imports need the `.js` extension, since ES modules are resolved without guessing it.
+    for (path, typescript) in generate_typescript::generate_with_import_extension(
+        typical_version,
+        schemas,
+        options,
+        ".js",
+    ) {
+        let tokens = tokenize(&typescript);
+        files.insert(path.with_extension("js"), erase_types(&tokens));
+        files.insert(path.with_extension("d.ts"), declarations(&tokens));
+    }
+
+    files
+}
+
+// Split the generated TypeScript code into tokens. This only needs to handle the syntax which the
+// TypeScript generator emits. In particular, there are no regular expression literals.
+fn tokenize(code: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut iter = code.char_indices().peekable();
+
+    while let Some((start, c)) = iter.next() {
+        let kind = match c {
+            '/' if matches!(iter.peek(), Some((_, '/'))) => {
+                while iter.next_if(|(_, c)| *c != '\n').is_some() {}
+                TokenKind::Comment
+            }
+            '/' if matches!(iter.peek(), Some((_, '*'))) => {
+                iter.next();
+                let mut previous = ' ';
+                for (_, c) in iter.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+                TokenKind::Comment
+            }
+            '\'' | '"' | '`' => {
+                while let Some((_, d)) = iter.next() {
+                    if d == '\\' {
+                        iter.next();
+                    } else if d == c {
+                        break;
+                    }
+                }
+                TokenKind::String
+            }
+            _ if c.is_whitespace() => {
+                while iter.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                TokenKind::Whitespace
+            }
+            _ if c.is_alphanumeric() || c == '_' || c == '$' => {
+                while iter
+                    .next_if(|(_, c)| c.is_alphanumeric() || *c == '_' || *c == '$')
+                    .is_some()
+                {}
+                TokenKind::Word
+            }
+            _ => TokenKind::Punctuation,
+        };
+
+        let end = iter.peek().map_or(code.len(), |(end, _)| *end);
+        tokens.push(Token {
+            kind,
+            text: &code[start..end],
+        });
+    }
+
+    tokens
+}
+
+// Determine whether a token is the given punctuation character.
+fn is_punctuation(token: Option<&Token<'_>>, punctuation: &str) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::Punctuation && token.text == punctuation)
+}
+
+// Determine whether a token is the given word.
+fn is_word(token: Option<&Token<'_>>, word: &str) -> bool {
+    token.is_some_and(|token| token.kind == TokenKind::Word && token.text == word)
+}
+
+// Find the first token at or after the given position which isn't whitespace or a comment.
+fn skip_trivia(tokens: &[Token<'_>], mut i: usize) -> usize {
+    while tokens
+        .get(i)
+        .is_some_and(|token| matches!(token.kind, TokenKind::Whitespace | TokenKind::Comment))
+    {
+        i += 1;
+    }
+
+    i
+}
+
+// Find the end of a type starting at the given position, excluding any trailing whitespace. The
+// type ends before the first comma, semicolon, closing bracket, or `=` which isn't nested in the
+// type. If `before_body` is set, it also ends before an opening brace which follows the type.
+fn skip_type(tokens: &[Token<'_>], start: usize, before_body: bool) -> usize {
+    let mut depth = 0_usize;
+    let mut i = start;
+    let mut end = start;
+
+    while let Some(token) = tokens.get(i) {
+        if token.kind == TokenKind::Punctuation {
+            match token.text {
+                // The arrow in a function type isn't a closing bracket.
+                "=" if is_punctuation(tokens.get(i + 1), ">") => {
+                    i += 2;
+                    end = i;
+                    continue;
+                }
+                "{" if depth == 0 && before_body && end > start => break,
+                "(" | "[" | "{" | "<" => depth += 1,
+                ")" | "]" | "}" | ">" if depth > 0 => depth -= 1,
+                "," | ";" | ")" | "]" | "}" | "=" if depth == 0 => break,
+                _ => {}
+            }
+        }
+
+        i += 1;
+
+        if !matches!(token.kind, TokenKind::Whitespace | TokenKind::Comment) {
+            end = i;
+        }
+    }
+
+    end
+}
+
+// Find the end of a list of type parameters starting at the given position, including the closing
+// angle bracket.
+fn skip_type_parameters(tokens: &[Token<'_>], start: usize) -> usize {
+    let mut depth = 0_usize;
+    let mut i = start;
+
+    while let Some(token) = tokens.get(i) {
+        i += 1;
+
+        if token.kind == TokenKind::Punctuation {
+            match token.text {
+                "<" => depth += 1,
+                ">" => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return i;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    i
+}
+
+// Find the end of a statement starting at the given position, including the semicolon or the
+// braces which end it.
+fn skip_statement(tokens: &[Token<'_>], start: usize) -> usize {
+    // Function and interface declarations end with their closing brace. Any other statement ends
+    // with a semicolon.
+    let mut keyword = start;
+    while ["export", "async"]
+        .iter()
+        .any(|word| is_word(tokens.get(keyword), word))
+    {
+        keyword = skip_trivia(tokens, keyword + 1);
+    }
+    let ends_with_brace =
+        is_word(tokens.get(keyword), "function") || is_word(tokens.get(keyword), "interface");
+
+    let mut depth = 0_usize;
+    let mut i = start;
+
+    while let Some(token) = tokens.get(i) {
+        i += 1;
+
+        if token.kind == TokenKind::Punctuation {
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" => depth -= 1,
+                "}" => {
+                    depth -= 1;
+
+                    if depth == 0 && ends_with_brace {
+                        return i;
+                    }
+                }
+                ";" if depth == 0 => return i,
+                _ => {}
+            }
+        }
+    }
+
+    i
+}
+
+// Determine whether the statement at the given position only exists at the type level.
+fn is_type_statement(tokens: &[Token<'_>], i: usize) -> bool {
+    let first = tokens.get(i);
+    let second = tokens.get(skip_trivia(tokens, i + 1));
+
+    (is_word(first, "import") && is_word(second, "type"))
+        || (is_word(first, "export") && (is_word(second, "type") || is_word(second, "interface")))
+        || is_word(first, "type")
+        || is_word(first, "interface")
+}
+
+// Copy the parameters of a function, starting at the opening parenthesis, and return the position
+// after the closing parenthesis. If `erase` is set, the types of the parameters are omitted.
+fn write_parameters(buffer: &mut String, tokens: &[Token<'_>], start: usize, erase: bool) -> usize {
+    let mut depth = 0_usize;
+    let mut i = start;
+
+    while let Some(token) = tokens.get(i) {
+        if token.kind == TokenKind::Punctuation {
+            match token.text {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        buffer.push_str(token.text);
+                        return i + 1;
+                    }
+                }
+                ":" if erase && depth == 1 => {
+                    i = skip_type(tokens, skip_trivia(tokens, i + 1), false);
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        buffer.push_str(token.text);
+        i += 1;
+    }
+
+    i
+}
+
+// Remove any blank lines which immediately follow other blank lines, along with any blank lines at
+// the end. These are left behind by the statements which were erased.
+fn collapse_blank_lines(code: &str) -> String {
+    let mut result = String::new();
+    let mut previous_blank = false;
+
+    for line in code.lines() {
+        let blank = line.trim().is_empty();
+
+        if !(blank && previous_blank) {
+            result.push_str(line);
+            result.push('\n');
+        }
+
+        previous_blank = blank;
+    }
+
+    while result.ends_with("\n\n") {
+        result.pop();
+    }
+
+    result
+}
+
+// Convert the generated TypeScript code to JavaScript by erasing the types.
+fn erase_types(tokens: &[Token<'_>]) -> String {
+    let mut buffer = String::new();
+    let mut depth = 0_usize;
+    let mut i = 0;
+
+    while let Some(token) = tokens.get(i) {
+        // Remove the statements which only exist at the type level.
+        if depth == 0 && token.kind == TokenKind::Word && is_type_statement(tokens, i) {
+            let trimmed_length = buffer.trim_end().len();
+            buffer.truncate(trimmed_length);
+            buffer.push('\n');
+            i = skip_statement(tokens, i);
+            continue;
+        }
+
+        match (token.kind, token.text) {
+            // The other imports are copied as is.
+            (TokenKind::Word, "import") if depth == 0 => {
+                let end = skip_statement(tokens, i);
+                for token in &tokens[i..end] {
+                    buffer.push_str(token.text);
+                }
+                i = end;
+            }
+
+            // Erase the type parameters, the types of the parameters, and the return type of a
+            // function or method.
+            (TokenKind::Word, "function" | "async") => {
+                while let Some(next) = tokens.get(i) {
+                    if is_punctuation(Some(next), "(") {
+                        break;
+                    }
+                    if is_punctuation(Some(next), "<") {
+                        i = skip_type_parameters(tokens, i);
+                        continue;
+                    }
+                    buffer.push_str(next.text);
+                    i += 1;
+                }
+                i = write_parameters(&mut buffer, tokens, i, true);
+
+                let colon = skip_trivia(tokens, i);
+                if is_punctuation(tokens.get(colon), ":") {
+                    i = skip_type(tokens, skip_trivia(tokens, colon + 1), true);
+                }
+            }
+
+            // Erase the type of a variable. Destructuring patterns never have types.
+            (TokenKind::Word, "let" | "const" | "var")
+                if tokens
+                    .get(skip_trivia(tokens, i + 1))
+                    .is_some_and(|token| token.kind == TokenKind::Word) =>
+            {
+                let name = skip_trivia(tokens, i + 1);
+                for token in &tokens[i..=name] {
+                    buffer.push_str(token.text);
+                }
+                i = name + 1;
+
+                if is_punctuation(tokens.get(i), ":") {
+                    i = skip_type(tokens, skip_trivia(tokens, i + 1), false);
+                }
+            }
+
+            // Erase a type assertion, along with the whitespace before it.
+            (TokenKind::Word, "as") => {
+                let trimmed_length = buffer.trim_end_matches(' ').len();
+                buffer.truncate(trimmed_length);
+                i = skip_type(tokens, skip_trivia(tokens, i + 1), false);
+            }
+
+            (kind, text) => {
+                if kind == TokenKind::Punctuation {
+                    match text {
+                        "(" | "[" | "{" => depth += 1,
+                        ")" | "]" | "}" => depth -= 1,
+                        _ => {}
+                    }
+                }
+
+                buffer.push_str(text);
+                i += 1;
+            }
+        }
+    }
+
+    collapse_blank_lines(&buffer)
+}
+
+// Write the type of an exported constant for the declaration file. The generated code only exports
+// objects whose properties refer to functions, strings, and instances of classes.
+fn write_constant_type(buffer: &mut String, tokens: &[Token<'_>], start: usize, end: usize) {
+    let first = skip_trivia(tokens, start);
+
+    match tokens.get(first) {
+        Some(token) if token.kind == TokenKind::String => buffer.push_str("string"),
+        Some(token) if is_word(Some(token), "new") => {
+            buffer.push_str(tokens[skip_trivia(tokens, first + 1)].text);
+        }
+        Some(token) if is_punctuation(Some(token), "{") => {
+            buffer.push_str("{\n");
+            let mut i = skip_trivia(tokens, first + 1);
+            while i < end && tokens[i].kind == TokenKind::Word {
+                let value = skip_trivia(tokens, skip_trivia(tokens, i + 1) + 1);
+                // The values are either references to functions or literals. Bigint literals are
+                // the only words which start with a digit.
+                let value_type = if tokens[value].kind == TokenKind::String {
+                    "string".to_owned()
+                } else if tokens[value].text.starts_with(|c: char| c.is_ascii_digit()) {
+                    "bigint".to_owned()
+                } else {
+                    format!("typeof {}", tokens[value].text)
+                };
+                // The `unwrap` is safe because `String`'s `Write` implementation is infallible.
+                writeln!(buffer, "  {}: {value_type};", tokens[i].text).unwrap();
+                i = skip_trivia(tokens, skip_trivia(tokens, value + 1) + 1);
+            }
+            buffer.push('}');
+        }
+        _ => {
+            // Impossible since the TypeScript generator doesn't export any other constants.
+            panic!()
+        }
+    }
+}
+
+// Convert the generated TypeScript code to a declaration file by erasing the function bodies and
+// the values of the constants.
+fn declarations(tokens: &[Token<'_>]) -> String {
+    let mut buffer = String::new();
+    let mut i = 0;
+
+    while let Some(token) = tokens.get(i) {
+        if token.kind != TokenKind::Word {
+            buffer.push_str(token.text);
+            i += 1;
+            continue;
+        }
+
+        let end = skip_statement(tokens, i);
+        let exported = token.text == "export";
+        let keyword = if exported {
+            skip_trivia(tokens, i + 1)
+        } else {
+            i
+        };
+
+        match tokens[keyword].text {
+            // Imports and types are copied as is.
+            "import" | "type" | "interface" => {
+                for token in &tokens[i..end] {
+                    buffer.push_str(token.text);
+                }
+            }
+
+            // Functions are declared without their bodies.
+            "function" | "async" => {
+                let function = if tokens[keyword].text == "async" {
+                    skip_trivia(tokens, keyword + 1)
+                } else {
+                    keyword
+                };
+                let mut parameters = function;
+                while !is_punctuation(tokens.get(parameters), "(") {
+                    parameters += 1;
+                }
+
+                if exported {
+                    buffer.push_str("export ");
+                }
+                buffer.push_str("declare ");
+                // Generators are declared as ordinary functions which return the generator.
+                for token in &tokens[function..parameters] {
+                    if !is_punctuation(Some(token), "*") {
+                        buffer.push_str(token.text);
+                    }
+                }
+                let after_parameters = write_parameters(&mut buffer, tokens, parameters, false);
+                let return_type_end = skip_type(tokens, after_parameters, true);
+                for token in &tokens[after_parameters..return_type_end] {
+                    buffer.push_str(token.text);
+                }
+                buffer.push(';');
+            }
+
+            // Exported constants are declared with their types. The other constants aren't needed.
+            "const" if exported => {
+                let name = skip_trivia(tokens, keyword + 1);
+                let equals = skip_trivia(tokens, name + 1);
+                // The `unwrap` is safe because `String`'s `Write` implementation is infallible.
+                write!(buffer, "export declare const {}: ", tokens[name].text).unwrap();
+                write_constant_type(&mut buffer, tokens, equals + 1, end);
+                buffer.push(';');
+            }
+            "const" => {}
+
+            _ => {
+                // Impossible since the TypeScript generator doesn't emit any other statements.
+                panic!("{:?}", &tokens[keyword..keyword + 5])
+            }
+        }
+
+        i = end;
+    }
+
+    collapse_blank_lines(&buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        desugar::desugar,
+        generate_javascript::{declarations, erase_types, generate, tokenize},
+        generate_typescript::Options,
+        schema_loader::load_schemas,
+        validator::validate,
+    };
+    use std::{
+        collections::BTreeMap,
+        fs::{read_dir, read_to_string},
+        path::{Path, PathBuf},
+    };
+
+    // Read all the files in a directory (recursively) into a map from relative path to contents.
+    fn read_directory(root: &Path, relative_path: &Path, files: &mut BTreeMap<PathBuf, String>) {
+        for entry in read_dir(root.join(relative_path)).unwrap() {
+            let entry = entry.unwrap();
+            let path = relative_path.join(entry.file_name());
+            if entry.file_type().unwrap().is_dir() {
+                read_directory(root, &path, files);
+            } else {
+                files.insert(path.clone(), read_to_string(root.join(&path)).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn generate_example() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let mut expected = BTreeMap::new();
+        read_directory(
+            Path::new("test_data/javascript"),
+            Path::new(""),
+            &mut expected,
+        );
+
+        assert_eq!(generate("0.0.0", &schemas, &Options::default()), expected,);
+    }
+
+    #[test]
+    fn erase_types_example() {
+        let typescript = "\
+import { foo } from './foo.js';
+import type { Bar } from './foo.js';
+
+export type Baz = { qux: number };
+
+function size(message: Baz, [a, b]: [number, bigint]): number | undefined {
+  const { qux } = message;
+  let total: number = qux as number;
+  return a > 0 ? total : (message as any).qux as number;
+}
+
+async function* each<T>(
+  type: { make: (x: Map<string, T>) => T | Error },
+  options: Baz = { qux: 0 },
+): AsyncGenerator<T, void, undefined> {
+  yield type.make(new Map()) as T;
+}
+";
+
+        let expected = "\
+import { foo } from './foo.js';
+
+function size(message, [a, b]) {
+  const { qux } = message;
+  let total = qux;
+  return a > 0 ? total : (message).qux;
+}
+
+async function* each(
+  type,
+  options = { qux: 0 },
+) {
+  yield type.make(new Map());
+}
+";
+
+        assert_eq!(erase_types(&tokenize(typescript)), expected);
+    }
+
+    #[test]
+    fn declarations_example() {
+        let typescript = "\
+export type Baz = { qux: number };
+
+function bazSize(message: Baz): number {
+  return message.qux;
+}
+
+export async function* bazEach<T>(make: () => T): AsyncGenerator<T, void, undefined> {
+  yield make();
+}
+
+const scratch = new DataView(new ArrayBuffer(8));
+
+export const message = 'Hello, World!';
+
+export const Baz = {
+  size: bazSize,
+};
+";
+
+        let expected = "\
+export type Baz = { qux: number };
+
+declare function bazSize(message: Baz): number;
+
+export declare function bazEach<T>(make: () => T): AsyncGenerator<T, void, undefined>;
+
+export declare const message: string;
+
+export declare const Baz: {
+  size: typeof bazSize;
+};
+";
+
+        assert_eq!(declarations(&tokenize(typescript)), expected);
+    }
+}
@@ -0,0 +1,569 @@
+use crate::{
+    error::{Error, throw},
+    identifier::Identifier,
+    schema,
+    token::{
+        ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, F64_KEYWORD, OPTIONAL_KEYWORD,
+        S64_KEYWORD, STRING_KEYWORD, U64_KEYWORD, UNIT_KEYWORD,
+    },
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write},
+    path::PathBuf,
+};
+
+// The name of the page which lists all the schemas, without the `.md` suffix
+pub const INDEX_FILE_STEM: &str = "index";
+
+// The label for required fields, since they don't have a keyword
+const REQUIRED_LABEL: &str = "required";
+
+// The error message shown when a schema would overwrite the index page
+const INDEX_COLLISION_ERROR: &str =
+    "The name of this file conflicts with the index page of the generated documentation.";
+
+// Make sure no schema would be documented on the same page as the index.
+pub fn check_schema_paths(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> Result<(), Vec<Error>> {
+    let index_namespace = schema::Namespace {
+        components: vec![INDEX_FILE_STEM.into()],
+    };
+
+    match schemas.get(&index_namespace) {
+        Some((_, source_path, _)) => Err(vec![throw::<Error>(
+            INDEX_COLLISION_ERROR,
+            Some(source_path),
+            None,
+            None,
+        )]),
+        None => Ok(()),
+    }
+}
+
+// Generate Markdown documentation for a schema and its transitive dependencies. There is one page
+// per schema, mirroring the schema paths, plus an index page which links to all of them. The paths
+// are relative to the configured output directory. The schemas are assumed to be valid and
+// desugared, so the fields of included structs are documented as part of the structs which include
+// them.
+pub fn generate(
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> BTreeMap<PathBuf, String> {
+    let users = users(schemas);
+    let mut files = BTreeMap::new();
+
+    let mut index_buffer = String::new();
+    // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+    write_index_page(&mut index_buffer, typical_version, schemas).unwrap();
+    files.insert(
+        PathBuf::from(INDEX_FILE_STEM).with_extension("md"),
+        index_buffer,
+    );
+
+    for (namespace, (schema, _, _)) in schemas {
+        let mut buffer = String::new();
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        write_schema_page(
+            &mut buffer,
+            typical_version,
+            schemas,
+            namespace,
+            schema,
+            &users,
+        )
+        .unwrap();
+        // This cannot overwrite the index page due to `check_schema_paths`.
+        files.insert(page_path(namespace), buffer);
+    }
+
+    files
+}
+
+// Compute which declarations and services refer to each declaration.
+fn users(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> BTreeMap<(schema::Namespace, Identifier), BTreeSet<(schema::Namespace, Identifier)>> {
+    let mut users = BTreeMap::<_, BTreeSet<_>>::new();
+
+    for (namespace, (schema, _, _)) in schemas {
+        let user_types = schema
+            .declarations
+            .iter()
+            .flat_map(|declaration| {
+                declaration
+                    .fields
+                    .iter()
+                    .map(|field| (&declaration.name, &field.r#type))
+            })
+            .chain(schema.services.iter().flat_map(|service| {
+                service.methods.iter().flat_map(|method| {
+                    [
+                        (&service.name, &method.request),
+                        (&service.name, &method.response),
+                    ]
+                })
+            }));
+
+        for (user, r#type) in user_types {
+            if let Some(target) = custom_type_target(schemas, namespace, &r#type.variant) {
+                users
+                    .entry(target)
+                    .or_default()
+                    .insert((namespace.clone(), user.clone()));
+            }
+        }
+    }
+
+    users
+}
+
+// Find the declaration which a type refers to, looking through arrays.
+fn custom_type_target(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+) -> Option<(schema::Namespace, Identifier)> {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            custom_type_target(schemas, namespace, &inner_type.variant)
+        }
+        schema::TypeVariant::Custom(import, name) => {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let target_namespace = import.as_ref().map_or_else(
+                || namespace.clone(),
+                |import| {
+                    schemas[namespace].0.imports[import]
+                        .namespace
+                        .clone()
+                        .unwrap()
+                },
+            );
+
+            Some((target_namespace, name.clone()))
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => None,
+    }
+}
+
+// Write the preamble that appears on every page.
+fn write_generated_file_header<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
+<!-- This file was automatically generated by Typical {typical_version}. -->
+<!-- Visit https://github.com/stepchowfun/typical for more information. -->",
+    )
+}
+
+// Write the page which lists the schemas and their declarations and services.
+fn write_index_page<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> Result<(), fmt::Error> {
+    write_generated_file_header(buffer, typical_version)?;
+    writeln!(buffer)?;
+    writeln!(buffer, "# Schemas")?;
+
+    if !schemas.is_empty() {
+        writeln!(buffer)?;
+    }
+
+    let index_namespace = schema::Namespace {
+        components: vec![INDEX_FILE_STEM.into()],
+    };
+
+    for (namespace, (schema, _, _)) in schemas {
+        let path = relative_path(&index_namespace, namespace);
+        write!(buffer, "- [`")?;
+        write_namespace(buffer, namespace)?;
+        writeln!(buffer, "`]({path})")?;
+
+        for name in schema
+            .declarations
+            .iter()
+            .map(|declaration| &declaration.name)
+            .chain(schema.services.iter().map(|service| &service.name))
+        {
+            writeln!(
+                buffer,
+                "  - [`{}`]({path}#{})",
+                name.original(),
+                anchor(name),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// Write the page for an individual schema.
+fn write_schema_page<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    users: &BTreeMap<(schema::Namespace, Identifier), BTreeSet<(schema::Namespace, Identifier)>>,
+) -> Result<(), fmt::Error> {
+    write_generated_file_header(buffer, typical_version)?;
+    writeln!(buffer)?;
+    write!(buffer, "# `")?;
+    write_namespace(buffer, namespace)?;
+    writeln!(buffer, "`")?;
+    write_comment(buffer, &schema.comment)?;
+
+    // Link to the imported schemas, skipping those added by desugaring [ref:synthetic_import].
+    let imports = schema
+        .imports
+        .values()
+        .filter(|import| !import.path.as_os_str().is_empty())
+        .collect::<Vec<_>>();
+    if !imports.is_empty() {
+        writeln!(buffer)?;
+        write!(buffer, "Imports:")?;
+        for (i, import) in imports.into_iter().enumerate() {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let import_namespace = import.namespace.as_ref().unwrap();
+            write!(buffer, "{} [`", if i == 0 { "" } else { "," })?;
+            write_namespace(buffer, import_namespace)?;
+            write!(buffer, "`]({})", relative_path(namespace, import_namespace))?;
+        }
+        writeln!(buffer)?;
+    }
+
+    // Write the declarations.
+    for declaration in &schema.declarations {
+        writeln!(buffer)?;
+        writeln!(buffer, "## `{}`", declaration.name.original())?;
+        write_comment(buffer, &declaration.comment)?;
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "{} {}.",
+            if declaration.open { "An" } else { "A" },
+            match (declaration.open, &declaration.variant) {
+                (false, schema::DeclarationVariant::Struct) => "struct",
+                (false, schema::DeclarationVariant::Choice) => "choice",
+                (true, schema::DeclarationVariant::Struct) => "open struct",
+                (true, schema::DeclarationVariant::Choice) => "open choice",
+            },
+        )?;
+
+        writeln!(buffer)?;
+        if declaration.fields.is_empty() {
+            writeln!(buffer, "It has no fields.")?;
+        } else {
+            writeln!(buffer, "| Index | Field | Rule | Type | Description |")?;
+            writeln!(buffer, "| ----- | ----- | ---- | ---- | ----------- |")?;
+
+            let mut fields = declaration.fields.iter().collect::<Vec<_>>();
+            fields.sort_by_key(|field| field.index);
+
+            for field in fields {
+                write!(
+                    buffer,
+                    "| {} | `{}` | {} | ",
+                    field.index,
+                    field.name.original(),
+                    match field.rule {
+                        schema::Rule::Asymmetric => ASYMMETRIC_KEYWORD,
+                        schema::Rule::Optional => OPTIONAL_KEYWORD,
+                        schema::Rule::Required => REQUIRED_LABEL,
+                    },
+                )?;
+                write_type(buffer, schemas, namespace, &field.r#type.variant)?;
+                write!(buffer, " | ")?;
+                write_table_comment(buffer, &field.comment)?;
+                writeln!(buffer, " |")?;
+            }
+        }
+
+        write_deleted(buffer, &declaration.deleted)?;
+        write_users(
+            buffer,
+            namespace,
+            users.get(&(namespace.clone(), declaration.name.clone())),
+        )?;
+    }
+
+    // Write the services.
+    for service in &schema.services {
+        write_service(buffer, schemas, namespace, service)?;
+    }
+
+    Ok(())
+}
+
+// Write the section for a service.
+fn write_service<T: Write>(
+    buffer: &mut T,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    service: &schema::Service,
+) -> Result<(), fmt::Error> {
+    writeln!(buffer)?;
+    writeln!(buffer, "## `{}`", service.name.original())?;
+    write_comment(buffer, &service.comment)?;
+    writeln!(buffer)?;
+    writeln!(buffer, "A service.")?;
+
+    writeln!(buffer)?;
+    if service.methods.is_empty() {
+        writeln!(buffer, "It has no methods.")?;
+    } else {
+        writeln!(
+            buffer,
+            "| Index | Method | Request | Response | Description |"
+        )?;
+        writeln!(
+            buffer,
+            "| ----- | ------ | ------- | -------- | ----------- |"
+        )?;
+
+        let mut methods = service.methods.iter().collect::<Vec<_>>();
+        methods.sort_by_key(|method| method.index);
+
+        for method in methods {
+            write!(
+                buffer,
+                "| {} | `{}` | ",
+                method.index,
+                method.name.original()
+            )?;
+            write_type(buffer, schemas, namespace, &method.request.variant)?;
+            write!(buffer, " | ")?;
+            write_type(buffer, schemas, namespace, &method.response.variant)?;
+            write!(buffer, " | ")?;
+            write_table_comment(buffer, &method.comment)?;
+            writeln!(buffer, " |")?;
+        }
+    }
+
+    write_deleted(buffer, &service.deleted)?;
+
+    Ok(())
+}
+
+// Write a comment as its own paragraph, if there is one.
+fn write_comment<T: Write>(buffer: &mut T, comment: &[String]) -> Result<(), fmt::Error> {
+    if !comment.is_empty() {
+        writeln!(buffer)?;
+        for line in comment {
+            writeln!(buffer, "{line}")?;
+        }
+    }
+
+    Ok(())
+}
+
+// Write a comment in a table cell, which can't contain line breaks or unescaped pipes.
+fn write_table_comment<T: Write>(buffer: &mut T, comment: &[String]) -> Result<(), fmt::Error> {
+    write!(buffer, "{}", comment.join(" ").replace('|', "\\|"))
+}
+
+// Write a note about the deleted indices, if there are any.
+fn write_deleted<T: Write>(buffer: &mut T, deleted: &BTreeSet<usize>) -> Result<(), fmt::Error> {
+    if !deleted.is_empty() {
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "Deleted {}: {}. {} can't be reused.",
+            if deleted.len() == 1 {
+                "index"
+            } else {
+                "indices"
+            },
+            deleted
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            if deleted.len() == 1 { "It" } else { "They" },
+        )?;
+    }
+
+    Ok(())
+}
+
+// Write links to the declarations and services which refer to a declaration, if there are any.
+fn write_users<T: Write>(
+    buffer: &mut T,
+    namespace: &schema::Namespace,
+    users: Option<&BTreeSet<(schema::Namespace, Identifier)>>,
+) -> Result<(), fmt::Error> {
+    if let Some(users) = users {
+        writeln!(buffer)?;
+        write!(buffer, "Used by:")?;
+        for (i, (user_namespace, user_name)) in users.iter().enumerate() {
+            write!(buffer, "{} ", if i == 0 { "" } else { "," })?;
+            write_link(buffer, namespace, user_namespace, user_name)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
+
+// Write a type, with links to the declarations it refers to.
+fn write_type<T: Write>(
+    buffer: &mut T,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+) -> Result<(), fmt::Error> {
+    match type_variant {
+        // The brackets are escaped, so they can't be mistaken for part of a link.
+        schema::TypeVariant::Array(inner_type) => {
+            write!(buffer, "\\[")?;
+            write_type(buffer, schemas, namespace, &inner_type.variant)?;
+            write!(buffer, "\\]")
+        }
+        schema::TypeVariant::Bool => write!(buffer, "`{BOOL_KEYWORD}`"),
+        schema::TypeVariant::Bytes => write!(buffer, "`{BYTES_KEYWORD}`"),
+        schema::TypeVariant::Custom(_, _) => {
+            // The `unwrap` is safe since the type is a custom type.
+            let (target_namespace, name) =
+                custom_type_target(schemas, namespace, type_variant).unwrap();
+            write_link(buffer, namespace, &target_namespace, &name)
+        }
+        schema::TypeVariant::F64 => write!(buffer, "`{F64_KEYWORD}`"),
+        schema::TypeVariant::S64 => write!(buffer, "`{S64_KEYWORD}`"),
+        schema::TypeVariant::String => write!(buffer, "`{STRING_KEYWORD}`"),
+        schema::TypeVariant::U64 => write!(buffer, "`{U64_KEYWORD}`"),
+        schema::TypeVariant::Unit => write!(buffer, "`{UNIT_KEYWORD}`"),
+    }
+}
+
+// Write a link to a declaration or service. Those in other schemas are qualified with their
+// namespaces.
+fn write_link<T: Write>(
+    buffer: &mut T,
+    namespace: &schema::Namespace,
+    target_namespace: &schema::Namespace,
+    name: &Identifier,
+) -> Result<(), fmt::Error> {
+    if target_namespace == namespace {
+        write!(buffer, "[`{}`](#{})", name.original(), anchor(name))
+    } else {
+        write!(buffer, "[`")?;
+        write_namespace(buffer, target_namespace)?;
+        write!(
+            buffer,
+            "::{}`]({}#{})",
+            name.original(),
+            relative_path(namespace, target_namespace),
+            anchor(name),
+        )
+    }
+}
+
+// Write a namespace in the same form as the selectors accepted by the code generators, e.g.,
+// `foo::types`.
+fn write_namespace<T: Write>(
+    buffer: &mut T,
+    namespace: &schema::Namespace,
+) -> Result<(), fmt::Error> {
+    write!(
+        buffer,
+        "{}",
+        namespace
+            .components
+            .iter()
+            .map(Identifier::original)
+            .collect::<Vec<_>>()
+            .join("::"),
+    )
+}
+
+// Compute the anchor of the section for a declaration or service, as generated by common Markdown
+// renderers for its heading.
+fn anchor(name: &Identifier) -> String {
+    name.original().to_lowercase()
+}
+
+// Compute the path of the page for a schema, relative to the output directory.
+fn page_path(namespace: &schema::Namespace) -> PathBuf {
+    let mut path = PathBuf::new();
+    for component in &namespace.components {
+        path.push(component.snake_case());
+    }
+    path.set_extension("md");
+    path
+}
+
+// Compute the relative path from the page for one schema to the page for another.
+fn relative_path(from: &schema::Namespace, to: &schema::Namespace) -> String {
+    let from_directory = &from.components[..from.components.len().saturating_sub(1)];
+    let common_components = from_directory
+        .iter()
+        .zip(to.components.iter())
+        .take_while(|(left, right)| left == right)
+        .count();
+
+    let mut components = vec![];
+
+    for _ in common_components..from_directory.len() {
+        components.push(String::from(".."));
+    }
+
+    for component in &to.components[common_components..] {
+        components.push(component.snake_case());
+    }
+
+    components.join("/") + ".md"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        desugar::desugar,
+        generate_markdown::{INDEX_FILE_STEM, generate},
+        schema_loader::load_schemas,
+        validator::validate,
+    };
+    use std::{
+        collections::BTreeMap,
+        fs::read_to_string,
+        path::{Path, PathBuf},
+    };
+
+    // Check that Markdown generation matches the golden fixture tree.
+    #[test]
+    fn generate_example() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let fixture_root = Path::new("test_data/markdown");
+        let mut expected = BTreeMap::new();
+
+        let index_path = PathBuf::from(INDEX_FILE_STEM).with_extension("md");
+        let index_contents = read_to_string(fixture_root.join(&index_path)).unwrap();
+        expected.insert(index_path, index_contents);
+
+        for namespace in schemas.keys() {
+            let mut path = PathBuf::new();
+            for component in &namespace.components {
+                path.push(component.snake_case());
+            }
+            path.set_extension("md");
+
+            let contents = read_to_string(fixture_root.join(&path)).unwrap();
+            expected.insert(path, contents);
+        }
+
+        assert_eq!(generate("0.0.0", &schemas), expected);
+    }
+}
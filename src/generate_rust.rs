@@ -1,11 +1,17 @@
 use crate::{
+    container,
+    error::{Error, listing, throw},
+    format::CodeStr,
+    generate_typescript::COMMON_FILE_STEM,
     identifier::Identifier,
-    schema::{self, relativize_namespace},
+    schema::{self, UNKNOWN_FIELD_NAME, relativize_namespace},
 };
 use std::{
-    collections::BTreeMap,
-    fmt::{self, Write},
-    path::PathBuf,
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display, Write},
+    iter::once,
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
 // The string to be used for each indentation level.
@@ -97,19 +103,462 @@ enum Direction {
 
 use Direction::{Atlas, In, Out};
 
+// This enum is used to distinguish between the blocking and asynchronous versions of the
+// serialization and deserialization logic. The blocking logic is built on either `std::io` or, for
+// code which can't depend on the standard library, the `io` module in the generated prelude. The
+// slice flavor is only used for deserialization, and it reads directly from a byte slice via the
+// `SliceRead` trait in the generated prelude.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Flavor {
+    Async,
+    Blocking,
+    NoStd,
+    Slice,
+}
+
+use Flavor::{Async, Blocking, NoStd, Slice};
+
+impl Flavor {
+    // The suffix which distinguishes the names of asynchronous functions
+    fn suffix(self) -> &'static str {
+        match self {
+            Async => "_async",
+            Blocking | NoStd => "",
+            Slice => "_slice",
+        }
+    }
+
+    // The visibility of the functions which serialize or deserialize a message. The slice
+    // functions are only visible within the crate, like the `SliceRead` trait they're bounded by.
+    fn visibility(self) -> &'static str {
+        match self {
+            Async | Blocking | NoStd => "pub ",
+            Slice => "pub(crate) ",
+        }
+    }
+
+    // The qualifier, if any, which precedes `fn` in a function definition
+    fn qualifier(self) -> &'static str {
+        match self {
+            Async => "async ",
+            Blocking | NoStd | Slice => "",
+        }
+    }
+
+    // The postfix, if any, for invocations of functions which come in both flavors
+    fn postfix(self) -> &'static str {
+        match self {
+            Async => ".await",
+            Blocking | NoStd | Slice => "",
+        }
+    }
+
+    // The path to the module which provides `Error`, `ErrorKind`, and `Result`, as seen from a
+    // module nested `supers` levels deep
+    fn io_module(self, supers: usize) -> String {
+        match self {
+            Async | Blocking => "::std::io".to_owned(),
+            NoStd | Slice => format!("{}io", "super::".repeat(supers)),
+        }
+    }
+
+    // The bound for generic readers
+    fn reader_bound(self, supers: usize) -> String {
+        match self {
+            Async => "::tokio::io::AsyncBufRead + Unpin".to_owned(),
+            Blocking | NoStd => format!("{}::BufRead", self.io_module(supers)),
+            Slice => format!("{}SliceRead", "super::".repeat(supers)),
+        }
+    }
+
+    // The bound for generic writers
+    fn writer_bound(self, supers: usize) -> String {
+        match self {
+            Async => "::tokio::io::AsyncWrite + Unpin".to_owned(),
+            Blocking | NoStd | Slice => format!("{}::Write", self.io_module(supers)),
+        }
+    }
+
+    // The trait which provides `read_exact`, `read_to_end`, `read_to_string`, and `take`
+    fn read_trait(self, supers: usize) -> String {
+        match self {
+            Async => "::tokio::io::AsyncReadExt".to_owned(),
+            Blocking | NoStd => format!("{}::Read", self.io_module(supers)),
+            Slice => format!("{}SliceRead", "super::".repeat(supers)),
+        }
+    }
+
+    // The trait which provides `fill_buf`
+    fn buf_read_trait(self, supers: usize) -> String {
+        match self {
+            Async => "::tokio::io::AsyncBufReadExt".to_owned(),
+            Blocking | NoStd => format!("{}::BufRead", self.io_module(supers)),
+            Slice => format!("{}SliceRead", "super::".repeat(supers)),
+        }
+    }
+}
+
+// This struct represents the options which control the Rust code generator.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    // Whether to also generate asynchronous serialization and deserialization functions
+    pub asynchronous: bool,
+
+    // Whether to generate code which only depends on `core` and `alloc` (this is incompatible with
+    // `asynchronous`)
+    pub no_std: bool,
+
+    // Whether to import the traits and helpers from the `typical-runtime` crate rather than
+    // defining them in the generated code (this is incompatible with `no_std`)
+    pub runtime: bool,
+
+    // Whether to generate a typestate builder for the output type of each struct
+    pub builders: bool,
+
+    // Whether to implement `arbitrary::Arbitrary` for the output types, along with tests which
+    // check that arbitrary messages survive a round trip (this is incompatible with `no_std`)
+    pub arbitrary: bool,
+
+    // Whether to generate the helpers for framed messages and containers, along with an embedded
+    // copy of the schema and an implementation of `SchemaType` for each message
+    pub containers: bool,
+
+    // The Rust types which replace the default representations of some fields
+    pub type_overrides: Vec<TypeOverride>,
+}
+
+// This struct represents a Rust type which replaces the default representation of some fields,
+// e.g., `::bytes::Bytes` instead of `Vec<u8>`. The replacement is converted to and from the default
+// representation with the `BytesType`, `StringType`, or `ArrayType` trait from the prelude. For
+// arrays, any `{}` in the type is replaced with the element type.
+#[derive(Clone, Debug)]
+pub struct TypeOverride {
+    pub target: TypeOverrideTarget,
+    pub rust_type: String,
+}
+
+// This enum represents the fields which a type override applies to. The first three apply to every
+// field of the given type, but not to the elements of arrays.
+#[derive(Clone, Debug)]
+pub enum TypeOverrideTarget {
+    Bytes,
+    String,
+    Array,
+    Field(schema::Namespace, Identifier, Identifier),
+}
+
+impl Display for TypeOverrideTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypeOverrideTarget::Bytes => write!(f, "bytes"),
+            TypeOverrideTarget::String => write!(f, "string"),
+            TypeOverrideTarget::Array => write!(f, "array"),
+            TypeOverrideTarget::Field(namespace, declaration_name, field_name) => {
+                for component in &namespace.components {
+                    write!(f, "{}::", component.original())?;
+                }
+
+                write!(
+                    f,
+                    "{}.{}",
+                    declaration_name.original(),
+                    field_name.original(),
+                )
+            }
+        }
+    }
+}
+
+// Type overrides are given on the command line as `SELECTOR=TYPE`, where the selector is `bytes`,
+// `string`, `array`, or a field such as `foo::types::Bar.baz`.
+impl FromStr for TypeOverride {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let Some((selector, rust_type)) = string.split_once('=') else {
+            return Err("Expected SELECTOR=TYPE.".to_owned());
+        };
+
+        let rust_type = rust_type.trim();
+        if rust_type.is_empty() {
+            return Err("The type is empty.".to_owned());
+        }
+
+        let target = match selector.trim() {
+            "bytes" => TypeOverrideTarget::Bytes,
+            "string" => TypeOverrideTarget::String,
+            "array" => TypeOverrideTarget::Array,
+            selector => {
+                let mut components = selector
+                    .rsplit_once('.')
+                    .map(|(path, field_name)| {
+                        path.split("::").chain(once(field_name)).collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                if components.len() < 2 || components.iter().any(|component| component.is_empty()) {
+                    return Err(format!(
+                        "Expected bytes, string, array, or a field such as foo::types::Bar.baz, \
+                            but got {selector}.",
+                    ));
+                }
+
+                // The `unwrap`s are safe because there are at least two components.
+                let field_name = components.pop().unwrap().into();
+                let declaration_name = components.pop().unwrap().into();
+
+                TypeOverrideTarget::Field(
+                    schema::Namespace {
+                        components: components.into_iter().map(Identifier::from).collect(),
+                    },
+                    declaration_name,
+                    field_name,
+                )
+            }
+        };
+
+        Ok(TypeOverride {
+            target,
+            rust_type: rust_type.to_owned(),
+        })
+    }
+}
+
+// Check that every type override for a specific field refers to a field which exists and which has
+// a type that can be overridden.
+pub fn check_type_overrides(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    type_overrides: &[TypeOverride],
+) -> Result<(), Vec<Error>> {
+    let mut errors = vec![];
+
+    for type_override in type_overrides {
+        if let TypeOverrideTarget::Field(namespace, declaration_name, field_name) =
+            &type_override.target
+        {
+            let field = schemas.get(namespace).and_then(|(schema, _, _)| {
+                schema
+                    .declarations
+                    .iter()
+                    .find(|declaration| declaration.name == *declaration_name)
+                    .and_then(|declaration| {
+                        declaration
+                            .fields
+                            .iter()
+                            .find(|field| field.name == *field_name)
+                    })
+            });
+
+            match field {
+                None => {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "There is no field {} to override the type of.",
+                            type_override.target.to_string().code_str(),
+                        ),
+                        None,
+                        None,
+                        None,
+                    ));
+                }
+                Some(field) => match field.r#type.variant {
+                    schema::TypeVariant::Array(_)
+                    | schema::TypeVariant::Bytes
+                    | schema::TypeVariant::String => {}
+                    schema::TypeVariant::Bool
+                    | schema::TypeVariant::Custom(_, _)
+                    | schema::TypeVariant::F64
+                    | schema::TypeVariant::S64
+                    | schema::TypeVariant::U64
+                    | schema::TypeVariant::Unit => {
+                        errors.push(throw::<Error>(
+                            &format!(
+                                "The type of {} is {}, but only fields of type {}, {}, or an \
+                                    array type can have their types overridden.",
+                                type_override.target.to_string().code_str(),
+                                field.r#type.to_string().code_str(),
+                                "Bytes".code_str(),
+                                "String".code_str(),
+                            ),
+                            None,
+                            None,
+                            None,
+                        ));
+                    }
+                },
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+// Check that the setters of the typestate builders don't collide. The setter for a required or
+// asymmetric field `with_x` would otherwise have the same name as the setter for an optional field
+// `x`, and both are available while the former is unset.
+pub fn check_builder_setters(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> Result<(), Vec<Error>> {
+    let mut errors = vec![];
+
+    for (schema, source_path, source_contents) in schemas.values() {
+        for declaration in &schema.declarations {
+            if let schema::DeclarationVariant::Choice = declaration.variant {
+                continue;
+            }
+
+            for optional_field in &declaration.fields {
+                if !matches!(optional_field.rule, schema::Rule::Optional) {
+                    continue;
+                }
+
+                let setter_name = Identifier::from("with").join(&optional_field.name);
+                for field in &declaration.fields {
+                    if !matches!(field.rule, schema::Rule::Optional)
+                        && field.name.snake_case() == setter_name.snake_case()
+                    {
+                        errors.push(throw::<Error>(
+                            &format!(
+                                "The builder setter {} for optional field {} of {} conflicts with \
+                                    the setter for field {}. Rename one of the fields, or don't \
+                                    generate builders.",
+                                setter_name.snake_case().code_str(),
+                                optional_field.name.code_str(),
+                                declaration.name.code_str(),
+                                field.name.code_str(),
+                            ),
+                            Some(source_path),
+                            Some(&listing(source_contents, declaration.source_range)),
+                            None,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 // Generate Rust code from a schema and its transitive dependencies.
-#[allow(clippy::too_many_lines)]
 pub fn generate(
     typical_version: &str,
     schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    options: &Options,
 ) -> String {
-    // Construct a tree of modules and schemas. We start with an empty tree.
+    let tree = module_tree(schemas);
+    let embedded_schema = container::embedded_schema(schemas);
+
+    // Write the code.
+    let mut buffer = String::new();
+
+    if !module_empty(&tree) {
+        // For functions that take abstract parameters which implement `std::io::Read` or
+        // `std::io::Write`, it's idiomatic to consume the reader or writer rather than borrowing
+        // them (https://rust-lang.github.io/api-guidelines/interoperability.html
+        // #generic-readerwriter-functions-take-r-read-and-w-write-by-value-c-rw-value). However,
+        // we borrow them anyway since that allows us to pass the reference (after reborrowing)
+        // to recursive calls, rather than building up and following chains of references.
+        //
+        // The `unwrap`s are safe because the `std::fmt::Write` impl for `String` is infallible.
+        write_generated_file_header(&mut buffer, typical_version).unwrap();
+        write_prelude(&mut buffer, typical_version, options);
+        if options.containers {
+            write_embedded_schema(&mut buffer, &embedded_schema).unwrap();
+        }
+        writeln!(&mut buffer).unwrap();
+        write_module_contents(
+            &mut buffer,
+            0,
+            &schema::Namespace { components: vec![] },
+            &tree.children,
+            &tree.schema,
+            options,
+            &embedded_schema,
+            &fallible_conversions(schemas),
+        )
+        .unwrap();
+    }
+
+    buffer
+}
+
+// Generate Rust code from a schema and its transitive dependencies as a tree of files, with one
+// file per module. The paths are relative to the configured output directory. The root module is
+// `mod.rs`, and it includes the traits and helpers from a separate file.
+pub fn generate_files(
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    options: &Options,
+) -> BTreeMap<PathBuf, String> {
+    let tree = module_tree(schemas);
+    let embedded_schema = container::embedded_schema(schemas);
+
+    let mut files = BTreeMap::new();
+
+    if !module_empty(&tree) {
+        // The `unwrap`s are safe because the `std::fmt::Write` impl for `String` is infallible.
+        let mut prelude = String::new();
+        write_generated_file_header(&mut prelude, typical_version).unwrap();
+        write_prelude(&mut prelude, typical_version, options);
+        if options.containers {
+            write_embedded_schema(&mut prelude, &embedded_schema).unwrap();
+        }
+
+        // This cannot collide with the file for a module due to
+        // [ref:typescript_common_file_collision_rejected].
+        let prelude_path = PathBuf::from(COMMON_FILE_STEM).with_extension("rs");
+
+        // The helpers are included rather than declared as a module, so the generated code can
+        // refer to them (including the private ones) from the root module, just as it does when
+        // everything is in one file.
+        let mut root = String::new();
+        write_generated_file_header(&mut root, typical_version).unwrap();
+        writeln!(
+            &mut root,
+            "include!(\"{}\");\n",
+            prelude_path.to_string_lossy(),
+        )
+        .unwrap();
+        write_module_file(
+            &mut files,
+            &mut root,
+            Path::new(""),
+            &schema::Namespace { components: vec![] },
+            &tree,
+            typical_version,
+            options,
+            &embedded_schema,
+            &fallible_conversions(schemas),
+        )
+        .unwrap();
+
+        files.insert(prelude_path, prelude);
+        files.insert(PathBuf::from("mod.rs"), root);
+    }
+
+    files
+}
+
+// Construct a tree of modules from a schema and its transitive dependencies.
+fn module_tree(schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>) -> Module {
+    // We start with an empty tree.
     let mut tree = Module {
         children: BTreeMap::new(),
         schema: schema::Schema {
             comment: vec![],
             imports: BTreeMap::new(),
             declarations: vec![],
+            services: vec![],
         },
     };
 
@@ -118,38 +567,110 @@ pub fn generate(
         insert_schema(&mut tree, namespace, schema);
     }
 
-    // Write the code.
-    let mut buffer = String::new();
+    tree
+}
 
-    if !tree.children.is_empty() || !tree.schema.declarations.is_empty() {
-        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
-        // For functions that take abstract parameters which implement `std::io::Read` or
-        // `std::io::Write`, it's idiomatic to consume the reader or writer rather than borrowing
-        // them (https://rust-lang.github.io/api-guidelines/interoperability.html
-        // #generic-readerwriter-functions-take-r-read-and-w-write-by-value-c-rw-value). However,
-        // we borrow them anyway since that allows us to pass the reference (after reborrowing)
-        // to recursive calls, rather than building up and following chains of references.
-        writeln!(
-            &mut buffer,
-            "\
+// Determine whether there is any code to generate for a module.
+fn module_empty(module: &Module) -> bool {
+    module.children.is_empty()
+        && module.schema.declarations.is_empty()
+        && module.schema.services.is_empty()
+}
+
+// Write the preamble that appears in every generated file.
+fn write_generated_file_header<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
 // This file was automatically generated by Typical {typical_version}.
 // Visit https://github.com/stepchowfun/typical for more information.
+",
+    )
+}
+
+// Write the schema which the generated code embeds in containers. It's a raw string literal with
+// enough `#`s that the schema can't end it early.
+fn write_embedded_schema<T: Write>(
+    buffer: &mut T,
+    embedded_schema: &container::EmbeddedSchema,
+) -> Result<(), fmt::Error> {
+    let mut hashes = String::new();
+    while embedded_schema.text.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+
+    writeln!(
+        buffer,
+        "\npub const SCHEMA: &str = r{hashes}\"{}\"{hashes};",
+        embedded_schema.text,
+    )
+}
 
+// Write the traits and helpers which the generated code for every schema refers to.
+#[allow(clippy::too_many_lines)]
+fn write_prelude(buffer: &mut String, typical_version: &str, options: &Options) {
+    // The traits and helpers below are also defined by the `typical-runtime` crate, and the two
+    // copies need to be kept in sync [tag:rust_prelude].
+    if options.runtime {
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        write_runtime_imports(
+            buffer,
+            typical_version,
+            options.asynchronous,
+            options.containers,
+        )
+        .unwrap();
+    } else {
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        if options.no_std {
+            write_no_std_imports_and_io_module(buffer).unwrap();
+        } else {
+            writeln!(
+                buffer,
+                "\
 use std::{{
     cmp::min,
     io::{{self, BufRead, Error, ErrorKind, Write}},
 }};
+",
+            )
+            .unwrap();
+        }
 
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        writeln!(
+            buffer,
+            "\
 const MISSING_FIELDS_ERROR_MESSAGE: &str = \"Struct missing one or more required field(s).\";
 
 pub trait Serialize {{
     fn size(&self) -> usize;
 
     fn serialize<T: Write>(&self, writer: T) -> io::Result<()>;
+
+    fn serialize_into(&self, buffer: &mut [u8]) -> io::Result<usize>;
+
+    fn serialize_to_vec(&self) -> io::Result<Vec<u8>>;
 }}
 
 pub trait Deserialize: Sized {{
     fn deserialize<T: BufRead>(reader: T) -> io::Result<Self>;
+
+    fn deserialize_from_slice(bytes: &[u8]) -> io::Result<Self>;
+}}
+
+pub trait Transport {{
+    fn call(&mut self, method_index: u64, request: Vec<u8>) -> io::Result<Vec<u8>>;
+}}
+
+#[derive(Clone, Debug)]
+pub struct UnknownField {{
+    pub index: u64,
+    pub size_mode: u8,
+    pub payload: Vec<u8>,
 }}
 
 fn zigzag_encode(value: i64) -> u64 {{
@@ -297,6 +818,12 @@ fn deserialize_varint<T: BufRead>(reader: &mut T) -> io::Result<u64> {{
     }}
 }}
 
+fn payload_integer_encoded(payload: &[u8]) -> bool {{
+    payload.first().is_some_and(|first_byte| {{
+        varint_size_from_first_byte(*first_byte) as usize == payload.len()
+    }})
+}}
+
 fn field_header_size(index: u64, payload_size: usize, integer_encoded: bool) -> usize {{
     match payload_size {{
         0 => varint_size_from_value((index << 2_u32) | 0b00),
@@ -332,12 +859,43 @@ fn serialize_field_header<T: Write>(
     }}
 }}
 
+fn unknown_field_size(field: &UnknownField) -> usize {{
+    let size_mode = field.size_mode & 0b11;
+    let tag_size = varint_size_from_value((field.index << 2_u32) | u64::from(size_mode));
+
+    if size_mode == 0b11 {{
+        tag_size + varint_size_from_value(field.payload.len() as u64) + field.payload.len()
+    }} else {{
+        tag_size + field.payload.len()
+    }}
+}}
+
+fn serialize_unknown_field<T: Write>(writer: &mut T, field: &UnknownField) -> io::Result<()> {{
+    let size_mode = field.size_mode & 0b11;
+    serialize_varint((field.index << 2_u32) | u64::from(size_mode), writer)?;
+
+    if size_mode == 0b11 {{
+        serialize_varint(field.payload.len() as u64, writer)?;
+    }}
+
+    writer.write_all(&field.payload)
+}}
+
 fn deserialize_field_header<T: BufRead>(reader: &mut T) -> io::Result<(u64, usize)> {{
+    let (index, _, size) = deserialize_field_header_with_size_mode(reader)?;
+
+    Ok((index, size))
+}}
+
+fn deserialize_field_header_with_size_mode<T: BufRead>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {{
     let tag = deserialize_varint(&mut *reader)?;
 
     let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
 
-    let size = match tag & 0b11 {{
+    let size = match size_mode {{
         0b00 => 0,
         0b01 => 8,
         0b10 => {{
@@ -355,12 +913,17 @@ fn deserialize_field_header<T: BufRead>(reader: &mut T) -> io::Result<(u64, usiz
         _ => deserialize_varint(&mut *reader)? as usize,
     }};
 
-    Ok((index, size))
+    Ok((index, size_mode, size))
 }}
 
 fn skip<T: BufRead>(reader: &mut T, mut amount: usize) -> io::Result<()> {{
     while amount > 0 {{
         let buffer = reader.fill_buf()?;
+
+        if buffer.is_empty() {{
+            return Err(ErrorKind::UnexpectedEof.into());
+        }}
+
         let num_bytes_to_consume = min(buffer.len(), amount);
         reader.consume(num_bytes_to_consume);
         amount -= num_bytes_to_consume;
@@ -380,1095 +943,3911 @@ fn finish<T: BufRead>(reader: &mut T) -> io::Result<()> {{
         let buffer_size = buffer.len();
         reader.consume(buffer_size);
     }}
-}}",
+}}
+
+fn sized_buffer(buffer: &mut [u8], size: usize) -> io::Result<&mut [u8]> {{
+    buffer.get_mut(..size).ok_or_else(|| {{
+        Error::new(
+            ErrorKind::WriteZero,
+            \"The buffer is too small for the message.\",
         )
-        .unwrap();
+    }})
+}}
 
-        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
-        writeln!(&mut buffer).unwrap();
+pub(crate) trait SliceRead {{
+    fn peek(&self) -> &[u8];
 
-        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
-        write_module_contents(
-            &mut buffer,
-            0,
-            &schema::Namespace { components: vec![] },
-            &tree.children,
-            &tree.schema,
-        )
-        .unwrap();
-    }
+    fn take(&mut self, limit: u64) -> &[u8];
 
-    buffer
-}
+    fn read_slice(&mut self, size: usize) -> io::Result<&[u8]> {{
+        if self.peek().len() < size {{
+            return Err(ErrorKind::UnexpectedEof.into());
+        }}
 
-// Insert a schema into a module.
-fn insert_schema(module: &mut Module, namespace: &schema::Namespace, schema: &schema::Schema) {
-    let mut iter = namespace.components.iter();
+        Ok(self.take(size as u64))
+    }}
 
-    if let Some(head) = iter.next() {
-        if let Some(child) = module.children.get_mut(head) {
-            insert_schema(
-                child,
-                &schema::Namespace {
-                    components: iter.cloned().collect(),
-                },
-                schema,
-            );
-        } else {
-            let mut child = Module {
-                children: BTreeMap::new(),
-                schema: schema::Schema {
-                    comment: vec![],
-                    imports: BTreeMap::new(),
-                    declarations: vec![],
-                },
-            };
+    fn read_exact(&mut self, buffer: &mut [u8]) -> io::Result<()> {{
+        buffer.copy_from_slice(self.read_slice(buffer.len())?);
+        Ok(())
+    }}
 
-            insert_schema(
-                &mut child,
-                &schema::Namespace {
-                    components: iter.cloned().collect(),
-                },
-                schema,
-            );
+    fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> io::Result<usize> {{
+        let bytes = self.take(u64::MAX);
+        buffer.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }}
 
-            module.children.insert(head.clone(), child);
-        }
-    } else {
-        module.schema = schema.clone();
-    }
-}
+    fn read_to_string(&mut self, buffer: &mut String) -> io::Result<usize> {{
+        let string = str::from_utf8(self.peek()).map_err(|_| {{
+            Error::new(
+                ErrorKind::InvalidData,
+                \"Stream did not contain valid UTF-8.\",
+            )
+        }})?;
+        buffer.push_str(string);
+        Ok(self.take(u64::MAX).len())
+    }}
 
-// Write a module, including a trailing line break.
-fn write_module<T: Write>(
-    buffer: &mut T,
-    indentation: usize,
-    namespace: &schema::Namespace,
-    name: &Identifier,
-    module: &Module,
-) -> Result<(), fmt::Error> {
-    write_indentation(buffer, indentation)?;
-    write!(buffer, "pub mod ")?;
-    write_identifier(buffer, name, Snake, None)?;
-    writeln!(buffer, " {{")?;
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {{
+        Ok(self.peek())
+    }}
+}}
 
-    let mut new_namespace = namespace.clone();
-    new_namespace.components.push(name.clone());
+impl SliceRead for &[u8] {{
+    fn peek(&self) -> &[u8] {{
+        self
+    }}
 
-    write_module_contents(
-        buffer,
-        indentation + 1,
-        &new_namespace,
-        &module.children,
-        &module.schema,
-    )?;
+    fn take(&mut self, limit: u64) -> &[u8] {{
+        let bytes = *self;
+        let (head, tail) = bytes.split_at(min(limit, bytes.len() as u64) as usize);
+        *self = tail;
+        head
+    }}
+}}
 
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "}}")?;
+impl<T: SliceRead> SliceRead for &mut T {{
+    fn peek(&self) -> &[u8] {{
+        (**self).peek()
+    }}
+
+    fn take(&mut self, limit: u64) -> &[u8] {{
+        (**self).take(limit)
+    }}
+}}
+
+fn deserialize_varint_slice<T: SliceRead>(reader: &mut T) -> io::Result<u64> {{
+    let size = reader.peek().first().map_or(1, |first_byte| {{
+        varint_size_from_first_byte(*first_byte) as usize
+    }});
+
+    deserialize_varint(&mut reader.read_slice(size)?)
+}}
+
+fn deserialize_field_header_slice<T: SliceRead>(reader: &mut T) -> io::Result<(u64, usize)> {{
+    let (index, _, size) = deserialize_field_header_with_size_mode_slice(reader)?;
+
+    Ok((index, size))
+}}
+
+fn deserialize_field_header_with_size_mode_slice<T: SliceRead>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {{
+    let tag = deserialize_varint_slice(&mut *reader)?;
+
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {{
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => match reader.peek().first() {{
+            Some(first_byte) => varint_size_from_first_byte(*first_byte) as usize,
+            None => {{
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    \"Error decoding field.\",
+                ));
+            }}
+        }},
+        _ => deserialize_varint_slice(&mut *reader)? as usize,
+    }};
+
+    Ok((index, size_mode, size))
+}}
 
+fn skip_slice<T: SliceRead>(reader: &mut T, amount: usize) -> io::Result<()> {{
+    reader.read_slice(amount)?;
     Ok(())
-}
+}}
 
-// Write the contents of a module, including a trailing line break if there was anything to render.
-fn write_module_contents<T: Write>(
-    buffer: &mut T,
-    indentation: usize,
-    namespace: &schema::Namespace,
-    children: &BTreeMap<Identifier, Module>,
-    schema: &schema::Schema,
-) -> Result<(), fmt::Error> {
-    let schema_empty = schema.declarations.is_empty();
+fn finish_slice<T: SliceRead>(reader: &mut T) -> io::Result<()> {{
+    reader.take(u64::MAX);
+    Ok(())
+}}",
+        )
+        .unwrap();
 
-    for (i, (child_name, child)) in children.iter().enumerate() {
-        write_module(buffer, indentation, namespace, child_name, child)?;
+        if options.containers {
+            // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is
+            // infallible.
+            writeln!(
+                buffer,
+                "
+const CRC32_TABLE: [u32; 256] = crc32_table();
 
-        if i < children.len() - 1 || !schema_empty {
-            writeln!(buffer)?;
-        }
-    }
+const fn crc32_table() -> [u32; 256] {{
+    let mut table = [0_u32; 256];
+    let mut index = 0_usize;
+
+    while index < table.len() {{
+        let mut value = index as u32;
+        let mut bit = 0_u32;
+
+        while bit < 8 {{
+            value = if value & 1 == 0 {{
+                value >> 1_u32
+            }} else {{
+                (value >> 1_u32) ^ 0xedb8_8320
+            }};
+            bit += 1;
+        }}
+
+        table[index] = value;
+        index += 1;
+    }}
+
+    table
+}}
 
-    write_schema(buffer, indentation, namespace, schema)?;
+fn crc32(bytes: &[u8]) -> u32 {{
+    !bytes.iter().fold(!0_u32, |crc, byte| {{
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8_u32)
+    }})
+}}
+
+fn write_frame_payload<T: Write>(
+    payload: &[u8],
+    writer: &mut T,
+    checksum: bool,
+) -> io::Result<()> {{
+    serialize_varint(payload.len() as u64, writer)?;
+    writer.write_all(payload)?;
+
+    if checksum {{
+        writer.write_all(&crc32(payload).to_le_bytes())?;
+    }}
 
     Ok(())
-}
+}}
 
-// Write a schema, including a trailing line break if there was anything to render.
-#[allow(clippy::too_many_lines)]
-fn write_schema<T: Write>(
-    buffer: &mut T,
-    indentation: usize,
-    namespace: &schema::Namespace,
-    schema: &schema::Schema,
-) -> Result<(), fmt::Error> {
-    // Construct a map from import name to namespace.
-    let mut imports = BTreeMap::new();
-    for (name, import) in &schema.imports {
-        // The `unwrap` is safe due to [ref:namespace_populated].
-        imports.insert(name.clone(), import.namespace.clone().unwrap());
-    }
+fn read_frame_payload<T: BufRead>(reader: &mut T, checksum: bool) -> io::Result<Vec<u8>> {{
+    let mut remaining = deserialize_varint(reader)? as usize;
+    let mut payload = Vec::new();
 
-    // Write the declarations.
-    let mut iter = schema.declarations.iter().peekable();
-    while let Some(declaration) = iter.next() {
-        match &declaration.variant {
-            schema::DeclarationVariant::Struct => {
-                write_struct(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    Atlas,
-                )?;
+    while remaining > 0 {{
+        let buffer = reader.fill_buf()?;
 
-                writeln!(buffer)?;
+        if buffer.is_empty() {{
+            return Err(ErrorKind::UnexpectedEof.into());
+        }}
 
-                write_struct(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    Out,
-                )?;
+        let num_bytes_to_consume = min(buffer.len(), remaining);
+        payload.extend_from_slice(&buffer[..num_bytes_to_consume]);
+        reader.consume(num_bytes_to_consume);
+        remaining -= num_bytes_to_consume;
+    }}
 
-                writeln!(buffer)?;
+    if checksum {{
+        let mut checksum = [0; 4];
+        reader.read_exact(&mut checksum)?;
 
-                write_struct(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    In,
-                )?;
+        if crc32(&payload) != u32::from_le_bytes(checksum) {{
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                \"Frame checksum mismatch.\",
+            ));
+        }}
+    }}
 
-                writeln!(buffer)?;
+    Ok(payload)
+}}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_supers(buffer, indentation)?;
-                write!(buffer, "Serialize for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, " {{")?;
-                write_size_function(buffer, indentation + 1)?;
-                writeln!(buffer)?;
-                write_serialize_function(buffer, indentation + 1)?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+pub fn write_framed<M: Serialize, T: Write>(
+    message: &M,
+    mut writer: T,
+    checksum: bool,
+) -> io::Result<()> {{
+    if checksum {{
+        write_frame_payload(&message.serialize_to_vec()?, &mut writer, true)
+    }} else {{
+        serialize_varint(message.size() as u64, &mut writer)?;
+        message.serialize(writer)
+    }}
+}}
 
-                writeln!(buffer)?;
+pub fn read_framed<M: Deserialize, T: BufRead>(reader: T, checksum: bool) -> FramedReader<M, T> {{
+    FramedReader {{
+        reader,
+        checksum,
+        failed: false,
+        message: core::marker::PhantomData,
+    }}
+}}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_supers(buffer, indentation)?;
-                write!(buffer, "Deserialize for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_deserialize_function(buffer, indentation + 1)?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+pub struct FramedReader<M, T> {{
+    reader: T,
+    checksum: bool,
+    failed: bool,
+    message: core::marker::PhantomData<fn() -> M>,
+}}
 
-                writeln!(buffer)?;
+impl<M: Deserialize, T: BufRead> FramedReader<M, T> {{
+    pub fn into_inner(self) -> T {{
+        self.reader
+    }}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl From<")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                write!(buffer, "> for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                write!(buffer, "fn from(message: ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, ") -> Self {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                for field in &declaration.fields {
-                    match field.rule {
-                        schema::Rule::Asymmetric => {
-                            write_indentation(buffer, indentation + 3)?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, ": Some(message.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                        }
-                        schema::Rule::Optional => {
-                            write_indentation(buffer, indentation + 3)?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, ": message.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, ".map(|payload| payload")?;
-                        }
-                        schema::Rule::Required => {
-                            write_indentation(buffer, indentation + 3)?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, ": message.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                        }
-                    }
-                    write_into_invocation(buffer, &field.r#type.variant)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            writeln!(buffer, "),")?;
-                        }
-                        schema::Rule::Required => {
-                            writeln!(buffer, ",")?;
-                        }
-                    }
-                }
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+    fn read_frame(&mut self) -> io::Result<Option<M>> {{
+        if self.reader.fill_buf()?.is_empty() {{
+            return Ok(None);
+        }}
 
-                writeln!(buffer)?;
+        let payload = read_frame_payload(&mut self.reader, self.checksum)?;
+        M::deserialize_from_slice(&payload).map(Some)
+    }}
+}}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                write!(buffer, "pub fn atlas(&self) -> ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                writeln!(buffer, " {{")?;
-                if !&declaration.fields.is_empty() {
-                    for field in &declaration.fields {
-                        write_indentation(buffer, indentation + 2)?;
-                        write!(buffer, "let _")?;
-                        write_identifier(buffer, &field.name, Snake, None)?;
-                        match field.rule {
-                            schema::Rule::Asymmetric | schema::Rule::Required => {
-                                write!(buffer, " = {{ let payload = &self.")?;
-                                write_identifier(buffer, &field.name, Snake, None)?;
-                                write!(buffer, "; ")?;
-                                write_atlas_calculation(
-                                    buffer,
-                                    indentation,
-                                    &field.r#type.variant,
+impl<M: Deserialize, T: BufRead> Iterator for FramedReader<M, T> {{
+    type Item = io::Result<M>;
+
+    fn next(&mut self) -> Option<Self::Item> {{
+        if self.failed {{
+            return None;
+        }}
+
+        let result = self.read_frame().transpose();
+        self.failed = matches!(result, Some(Err(_)));
+        result
+    }}
+}}
+
+pub trait SchemaType {{
+    const FINGERPRINT: u64;
+    const NORMAL_FORM: &'static str;
+    const NAME: &'static str;
+    const SCHEMA: &'static str;
+}}
+
+const CONTAINER_MAGIC: &[u8; 8] = b\"\\x89TYPICAL\";
+const CONTAINER_VERSION: u8 = 1;
+const CONTAINER_CHECKSUMS: u8 = 0b01;
+const CONTAINER_SCHEMA: u8 = 0b10;
+
+pub fn write_container_header<M: SchemaType, T: Write>(
+    mut writer: T,
+    checksum: bool,
+    embed_schema: bool,
+) -> io::Result<()> {{
+    let mut flags = 0;
+
+    if checksum {{
+        flags |= CONTAINER_CHECKSUMS;
+    }}
+
+    if embed_schema {{
+        flags |= CONTAINER_SCHEMA;
+    }}
+
+    writer.write_all(CONTAINER_MAGIC)?;
+    writer.write_all(&[CONTAINER_VERSION, flags])?;
+    writer.write_all(&M::FINGERPRINT.to_le_bytes())?;
+    write_frame_payload(M::NORMAL_FORM.as_bytes(), &mut writer, checksum)?;
+
+    if embed_schema {{
+        write_frame_payload(M::SCHEMA.as_bytes(), &mut writer, checksum)?;
+        write_frame_payload(M::NAME.as_bytes(), &mut writer, checksum)?;
+    }}
+
+    Ok(())
+}}
+
+pub fn read_container<M: Deserialize + SchemaType, T: BufRead>(
+    mut reader: T,
+) -> io::Result<FramedReader<M, T>> {{
+    let mut header = [0; 18];
+    reader.read_exact(&mut header)?;
+    let flags = header[9];
+
+    if header[..8] != CONTAINER_MAGIC[..]
+        || header[8] != CONTAINER_VERSION
+        || flags & !(CONTAINER_CHECKSUMS | CONTAINER_SCHEMA) != 0
+    {{
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            \"Invalid container header.\",
+        ));
+    }}
+
+    let mut fingerprint = [0; 8];
+    fingerprint.copy_from_slice(&header[10..]);
+    let checksum = flags & CONTAINER_CHECKSUMS != 0;
+    let normal_form = read_frame_payload(&mut reader, checksum)?;
+
+    if u64::from_le_bytes(fingerprint) != M::FINGERPRINT
+        && !readable(&normal_form, M::NORMAL_FORM)
+    {{
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            \"Container holds messages of an incompatible type.\",
+        ));
+    }}
+
+    if flags & CONTAINER_SCHEMA != 0 {{
+        read_frame_payload(&mut reader, checksum)?;
+        read_frame_payload(&mut reader, checksum)?;
+    }}
+
+    Ok(read_framed(reader, checksum))
+}}
+
+struct NormalDeclaration<'a> {{
+    choice: bool,
+    open: bool,
+    fields: Vec<(&'a str, &'a str, &'a str)>,
+}}
+
+impl<'a> NormalDeclaration<'a> {{
+    fn field(&self, index: &str) -> Option<(&'a str, &'a str)> {{
+        self.fields
+            .iter()
+            .find(|(other_index, _, _)| *other_index == index)
+            .map(|&(_, rule, r#type)| (rule, r#type))
+    }}
+}}
+
+fn parse_normal_form(normal_form: &str) -> Option<Vec<NormalDeclaration<'_>>> {{
+    normal_form
+        .lines()
+        .map(|line| {{
+            let mut words = line.split(' ').peekable();
+            let choice = match words.next()? {{
+                \"struct\" => false,
+                \"choice\" => true,
+                _ => return None,
+            }};
+            let open = words.next_if_eq(&\"open\").is_some();
+            let fields = words
+                .map(|word| {{
+                    let mut parts = word.splitn(3, ':');
+                    Some((parts.next()?, parts.next()?, parts.next()?))
+                }})
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(NormalDeclaration {{
+                choice,
+                open,
+                fields,
+            }})
+        }})
+        .collect()
+}}
+
+fn readable(writer: &[u8], reader: &str) -> bool {{
+    let (Some(writer), Some(reader)) = (
+        core::str::from_utf8(writer)
+            .ok()
+            .and_then(parse_normal_form),
+        parse_normal_form(reader),
+    ) else {{
+        return false;
+    }};
+
+    declaration_readable(&writer, &reader, 0, 0, &mut vec![])
+}}
+
+fn declaration_readable(
+    writers: &[NormalDeclaration<'_>],
+    readers: &[NormalDeclaration<'_>],
+    writer: usize,
+    reader: usize,
+    visited: &mut Vec<(usize, usize)>,
+) -> bool {{
+    if visited.contains(&(writer, reader)) {{
+        return true;
+    }}
+
+    visited.push((writer, reader));
+
+    let (Some(writer), Some(reader)) = (writers.get(writer), readers.get(reader)) else {{
+        return false;
+    }};
+
+    if writer.choice != reader.choice {{
+        return match (writer.fields.as_slice(), reader.fields.as_slice()) {{
+            (
+                [(writer_index, \"required\", writer_type)],
+                [(reader_index, \"required\", reader_type)],
+            ) => {{
+                writer_index == reader_index
+                    && type_readable(writers, readers, writer_type, reader_type, visited)
+            }}
+            _ => false,
+        }};
+    }}
+
+    if reader.choice {{
+        writer
+            .fields
+            .iter()
+            .all(|&(index, writer_rule, writer_type)| {{
+                reader.field(index).map_or(
+                    reader.open || writer_rule != \"required\",
+                    |(_, reader_type)| {{
+                        type_readable(writers, readers, writer_type, reader_type, visited)
+                    }},
+                )
+            }})
+    }} else {{
+        reader
+            .fields
+            .iter()
+            .all(|&(index, reader_rule, reader_type)| {{
+                writer.field(index).map_or(
+                    reader_rule != \"required\",
+                    |(writer_rule, writer_type)| {{
+                        (reader_rule != \"required\" || writer_rule != \"optional\")
+                            && type_readable(writers, readers, writer_type, reader_type, visited)
+                    }},
+                )
+            }})
+    }}
+}}
+
+fn type_readable(
+    writers: &[NormalDeclaration<'_>],
+    readers: &[NormalDeclaration<'_>],
+    writer: &str,
+    reader: &str,
+    visited: &mut Vec<(usize, usize)>,
+) -> bool {{
+    fn element(r#type: &str) -> Option<&str> {{
+        r#type.strip_prefix('[')?.strip_suffix(']')
+    }}
+
+    fn number(r#type: &str) -> Option<usize> {{
+        r#type.strip_prefix('#')?.parse().ok()
+    }}
+
+    if let (Some(writer), Some(reader)) = (element(writer), element(reader)) {{
+        type_readable(writers, readers, writer, reader, visited)
+    }} else if let (Some(writer), Some(reader)) = (number(writer), number(reader)) {{
+        declaration_readable(writers, readers, writer, reader, visited)
+    }} else {{
+        writer == reader
+    }}
+}}",
+            )
+            .unwrap();
+        }
+
+        // The asynchronous functions are built on top of the blocking ones. Each varint or
+        // field header is encoded to or decoded from a small buffer which is then written or
+        // read in one operation.
+        if options.asynchronous {
+            // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is
+            // infallible.
+            writeln!(
+                buffer,
+                "
+use tokio::io::{{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt}};
+
+pub trait SerializeAsync {{
+    fn serialize_async<T: AsyncWrite + Send + Unpin>(
+        &self,
+        writer: T,
+    ) -> impl std::future::Future<Output = io::Result<()>> + Send;
+}}
+
+pub trait DeserializeAsync: Sized {{
+    fn deserialize_async<T: AsyncBufRead + Send + Unpin>(
+        reader: T,
+    ) -> impl std::future::Future<Output = io::Result<Self>> + Send;
+}}
+
+async fn serialize_varint_async<T: AsyncWrite + Unpin>(
+    value: u64,
+    writer: &mut T,
+) -> io::Result<()> {{
+    let mut buffer = [0; 9];
+    serialize_varint(value, &mut &mut buffer[..])?;
+    writer
+        .write_all(&buffer[..varint_size_from_value(value)])
+        .await
+}}
+
+async fn deserialize_varint_async<T: AsyncBufRead + Unpin>(reader: &mut T) -> io::Result<u64> {{
+    let mut buffer = [0; 9];
+    reader.read_exact(&mut buffer[..1]).await?;
+    let size = varint_size_from_first_byte(buffer[0]) as usize;
+    reader.read_exact(&mut buffer[1..size]).await?;
+    deserialize_varint(&mut &buffer[..size])
+}}
+
+async fn serialize_field_header_async<T: AsyncWrite + Unpin>(
+    writer: &mut T,
+    index: u64,
+    payload_size: usize,
+    integer_encoded: bool,
+) -> io::Result<()> {{
+    let mut buffer = [0; 18];
+    serialize_field_header(&mut &mut buffer[..], index, payload_size, integer_encoded)?;
+    writer
+        .write_all(&buffer[..field_header_size(index, payload_size, integer_encoded)])
+        .await
+}}
+
+async fn serialize_unknown_field_async<T: AsyncWrite + Unpin>(
+    writer: &mut T,
+    field: &UnknownField,
+) -> io::Result<()> {{
+    let size_mode = field.size_mode & 0b11;
+    serialize_varint_async((field.index << 2_u32) | u64::from(size_mode), writer).await?;
+
+    if size_mode == 0b11 {{
+        serialize_varint_async(field.payload.len() as u64, writer).await?;
+    }}
+
+    writer.write_all(&field.payload).await
+}}
+
+async fn deserialize_field_header_async<T: AsyncBufRead + Unpin>(
+    reader: &mut T,
+) -> io::Result<(u64, usize)> {{
+    let (index, _, size) = deserialize_field_header_with_size_mode_async(reader).await?;
+
+    Ok((index, size))
+}}
+
+async fn deserialize_field_header_with_size_mode_async<T: AsyncBufRead + Unpin>(
+    reader: &mut T,
+) -> io::Result<(u64, u8, usize)> {{
+    let tag = deserialize_varint_async(&mut *reader).await?;
+
+    let index = tag >> 2_u32;
+    let size_mode = (tag & 0b11) as u8;
+
+    let size = match size_mode {{
+        0b00 => 0,
+        0b01 => 8,
+        0b10 => {{
+            let buffer = reader.fill_buf().await?;
+
+            if buffer.is_empty() {{
+                return Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    \"Error decoding field.\",
+                ));
+            }}
+
+            varint_size_from_first_byte(buffer[0]) as usize
+        }}
+        _ => deserialize_varint_async(&mut *reader).await? as usize,
+    }};
+
+    Ok((index, size_mode, size))
+}}
+
+async fn skip_async<T: AsyncBufRead + Unpin>(reader: &mut T, mut amount: usize) -> io::Result<()> {{
+    while amount > 0 {{
+        let buffer = reader.fill_buf().await?;
+
+        if buffer.is_empty() {{
+            return Err(ErrorKind::UnexpectedEof.into());
+        }}
+
+        let num_bytes_to_consume = min(buffer.len(), amount);
+        reader.consume(num_bytes_to_consume);
+        amount -= num_bytes_to_consume;
+    }}
+
+    Ok(())
+}}
+
+async fn finish_async<T: AsyncBufRead + Unpin>(reader: &mut T) -> io::Result<()> {{
+    loop {{
+        let buffer = reader.fill_buf().await?;
+
+        if buffer.is_empty() {{
+            return Ok(());
+        }}
+
+        let buffer_size = buffer.len();
+        reader.consume(buffer_size);
+    }}
+}}",
+            )
+            .unwrap();
+        }
+    }
+
+    if options.arbitrary {
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        write_arbitrary_helpers(buffer).unwrap();
+    }
+
+    if !options.type_overrides.is_empty() {
+        // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
+        write_type_override_traits(buffer, options).unwrap();
+    }
+}
+
+// Generate a cargo-fuzz crate with a fuzz target for each type in a schema and its transitive
+// dependencies. The crate contains its own copy of the generated code. Each target checks that the
+// deserializers agree on the input and that a successfully decoded message survives a round trip.
+pub fn generate_fuzz_crate(
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::new();
+
+    // Find the name of the fuzz target for each type.
+    let mut targets = vec![];
+    for (namespace, (schema, _, _)) in schemas {
+        for declaration in &schema.declarations {
+            let name = namespace
+                .components
+                .iter()
+                .chain(once(&declaration.name))
+                .map(Identifier::snake_case)
+                .collect::<Vec<_>>()
+                .join("_");
+            targets.push((namespace, declaration, name));
+        }
+    }
+
+    // The `unwrap`s below are safe because the `std::fmt::Write` impl for `String` is infallible.
+    let mut manifest = String::new();
+    write_fuzz_manifest(&mut manifest, typical_version, &targets).unwrap();
+    files.insert(PathBuf::from("Cargo.toml"), manifest);
+
+    files.insert(
+        PathBuf::from(".gitignore"),
+        "target\ncorpus\nartifacts\ncoverage\n".to_owned(),
+    );
+
+    files.insert(
+        PathBuf::from("src").join("types.rs"),
+        generate(typical_version, schemas, &Options::default()),
+    );
+
+    let mut library = String::new();
+    write_fuzz_library(&mut library, typical_version).unwrap();
+    files.insert(PathBuf::from("src").join("lib.rs"), library);
+
+    for (namespace, declaration, target) in &targets {
+        let mut buffer = String::new();
+        write_fuzz_target(&mut buffer, typical_version, namespace, &declaration.name).unwrap();
+        files.insert(
+            PathBuf::from("fuzz_targets").join(format!("{target}.rs")),
+            buffer,
+        );
+    }
+
+    files
+}
+
+// Write the manifest of the fuzz crate.
+fn write_fuzz_manifest<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    targets: &[(&schema::Namespace, &schema::Declaration, String)],
+) -> Result<(), fmt::Error> {
+    // The empty `[workspace]` table keeps the crate out of any enclosing workspace.
+    writeln!(
+        buffer,
+        "\
+# This file was automatically generated by Typical {typical_version}.
+# Visit https://github.com/stepchowfun/typical for more information.
+
+[package]
+name = \"fuzz\"
+version = \"0.0.0\"
+publish = false
+edition = \"2024\"
+
+[package.metadata]
+cargo-fuzz = true
+
+[dependencies]
+libfuzzer-sys = \"0.4\"
+
+[workspace]",
+    )?;
+
+    for (_, _, target) in targets {
+        writeln!(
+            buffer,
+            "
+[[bin]]
+name = \"{target}\"
+path = \"fuzz_targets/{target}.rs\"
+test = false
+doc = false
+bench = false",
+        )?;
+    }
+
+    Ok(())
+}
+
+// Write the library of the fuzz crate. It contains the generated code and the logic which is shared
+// by the fuzz targets.
+fn write_fuzz_library<T: Write>(buffer: &mut T, typical_version: &str) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
+// This file was automatically generated by Typical {typical_version}.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+#[allow(warnings, clippy::all, clippy::pedantic)]
+pub mod types;
+
+use std::fmt::Debug;
+use types::{{Deserialize, Serialize}};
+
+// Check that both deserializers agree on the given input and that, if it was decoded successfully,
+// serializing the message and deserializing it again produces the same message. The message can't
+// be serialized again if an asymmetric field is missing or if an asymmetric field of a choice was
+// decoded (since then the fallback isn't retained).
+pub fn round_trip<T: Clone + Debug + Deserialize, U: Serialize + TryFrom<T>>(data: &[u8]) {{
+    let message = match (T::deserialize(data), T::deserialize_from_slice(data)) {{
+        (Ok(message), Ok(message_from_slice)) => {{
+            assert_eq!(format!(\"{{message:?}}\"), format!(\"{{message_from_slice:?}}\"));
+            message
+        }}
+        (Err(_), Err(_)) => return,
+        (message, message_from_slice) => panic!(
+            \"The deserializers disagree: {{message:?}} vs. {{message_from_slice:?}}\",
+        ),
+    }};
+
+    let Ok(replica) = U::try_from(message.clone()) else {{
+        return;
+    }};
+
+    let bytes = replica.serialize_to_vec().unwrap();
+    let decoded = T::deserialize(bytes.as_slice()).unwrap();
+    assert_eq!(format!(\"{{decoded:?}}\"), format!(\"{{message:?}}\"));
+}}",
+    )
+}
+
+// Write a fuzz target for a type.
+fn write_fuzz_target<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
+// This file was automatically generated by Typical {typical_version}.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {{",
+    )?;
+    write_indentation(buffer, 1)?;
+    write!(buffer, "fuzz::round_trip::<")?;
+    write_fuzz_type_path(buffer, namespace, name, In)?;
+    write!(buffer, ", ")?;
+    write_fuzz_type_path(buffer, namespace, name, Out)?;
+    writeln!(buffer, ">(data);")?;
+    writeln!(buffer, "}});")
+}
+
+// Write the path of a generated type as seen from the fuzz targets.
+fn write_fuzz_type_path<T: Write>(
+    buffer: &mut T,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+    direction: Direction,
+) -> Result<(), fmt::Error> {
+    write!(buffer, "fuzz::types::")?;
+    for component in &namespace.components {
+        write_identifier(buffer, component, Snake, None)?;
+        write!(buffer, "::")?;
+    }
+    write_identifier(buffer, name, Pascal, Some(direction))
+}
+
+// Determine whether a type is or contains a user-defined type.
+fn contains_custom_type(type_variant: &schema::TypeVariant) -> bool {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => contains_custom_type(&inner_type.variant),
+        schema::TypeVariant::Custom(_, _) => true,
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => false,
+    }
+}
+
+// Write the traits which convert type overrides to and from the default representations, along
+// with their implementations for the standard types which can be used as overrides. They're also
+// defined by the `typical-runtime` crate [ref:rust_prelude].
+#[allow(clippy::too_many_lines)]
+fn write_type_override_traits<T: Write>(
+    buffer: &mut T,
+    options: &Options,
+) -> Result<(), fmt::Error> {
+    if options.runtime {
+        return writeln!(
+            buffer,
+            "
+pub use typical_runtime::{{ArrayType, BytesType, StringType}};",
+        );
+    }
+
+    let library = if options.no_std { "alloc" } else { "std" };
+
+    writeln!(
+        buffer,
+        "
+pub trait BytesType: Sized {{
+    fn from_vec(bytes: Vec<u8>) -> Self;
+
+    fn as_slice(&self) -> &[u8];
+}}
+
+pub trait StringType: Sized {{
+    fn from_string(string: String) -> Self;
+
+    fn as_str(&self) -> &str;
+}}
+
+pub trait ArrayType<T>: Sized {{
+    fn from_vec(elements: Vec<T>) -> Self;
+
+    fn into_vec(self) -> Vec<T>;
+
+    fn as_slice(&self) -> &[T];
+}}
+
+impl BytesType for Vec<u8> {{
+    fn from_vec(bytes: Vec<u8>) -> Self {{
+        bytes
+    }}
+
+    fn as_slice(&self) -> &[u8] {{
+        self
+    }}
+}}
+
+impl BytesType for Box<[u8]> {{
+    fn from_vec(bytes: Vec<u8>) -> Self {{
+        bytes.into_boxed_slice()
+    }}
+
+    fn as_slice(&self) -> &[u8] {{
+        self
+    }}
+}}
+
+impl BytesType for {library}::rc::Rc<[u8]> {{
+    fn from_vec(bytes: Vec<u8>) -> Self {{
+        bytes.into()
+    }}
+
+    fn as_slice(&self) -> &[u8] {{
+        self
+    }}
+}}
+
+impl BytesType for {library}::sync::Arc<[u8]> {{
+    fn from_vec(bytes: Vec<u8>) -> Self {{
+        bytes.into()
+    }}
+
+    fn as_slice(&self) -> &[u8] {{
+        self
+    }}
+}}
+
+impl StringType for String {{
+    fn from_string(string: String) -> Self {{
+        string
+    }}
+
+    fn as_str(&self) -> &str {{
+        self
+    }}
+}}
+
+impl StringType for Box<str> {{
+    fn from_string(string: String) -> Self {{
+        string.into_boxed_str()
+    }}
+
+    fn as_str(&self) -> &str {{
+        self
+    }}
+}}
+
+impl StringType for {library}::rc::Rc<str> {{
+    fn from_string(string: String) -> Self {{
+        string.into()
+    }}
+
+    fn as_str(&self) -> &str {{
+        self
+    }}
+}}
+
+impl StringType for {library}::sync::Arc<str> {{
+    fn from_string(string: String) -> Self {{
+        string.into()
+    }}
+
+    fn as_str(&self) -> &str {{
+        self
+    }}
+}}
+
+impl<T> ArrayType<T> for Vec<T> {{
+    fn from_vec(elements: Vec<T>) -> Self {{
+        elements
+    }}
+
+    fn into_vec(self) -> Vec<T> {{
+        self
+    }}
+
+    fn as_slice(&self) -> &[T] {{
+        self
+    }}
+}}
+
+impl<T> ArrayType<T> for Box<[T]> {{
+    fn from_vec(elements: Vec<T>) -> Self {{
+        elements.into_boxed_slice()
+    }}
+
+    fn into_vec(self) -> Vec<T> {{
+        <[T]>::into_vec(self)
+    }}
+
+    fn as_slice(&self) -> &[T] {{
+        self
+    }}
+}}",
+    )
+}
+
+// Write the helpers for generating arbitrary messages and checking that they survive a round trip.
+// Arrays and choice fallbacks are the only ways for a type to refer to itself, so limiting how
+// deeply they're nested ensures that generation terminates.
+fn write_arbitrary_helpers<T: Write>(buffer: &mut T) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "
+const ARBITRARY_DEPTH: usize = 4;
+
+#[cfg(test)]
+const ARBITRARY_ROUND_TRIPS: usize = 256;
+
+#[cfg(test)]
+const ARBITRARY_INPUT_SIZE: usize = 1024;
+
+fn arbitrary_array<'a, T>(
+    u: &mut ::arbitrary::Unstructured<'a>,
+    depth: usize,
+    mut element: impl FnMut(&mut ::arbitrary::Unstructured<'a>, usize) -> ::arbitrary::Result<T>,
+) -> ::arbitrary::Result<Vec<T>> {{
+    let mut elements = Vec::new();
+
+    if depth > 0 {{
+        while u.arbitrary()? {{
+            elements.push(element(u, depth - 1)?);
+        }}
+    }}
+
+    Ok(elements)
+}}
+
+#[cfg(test)]
+fn assert_arbitrary_round_trips<T, U>()
+where
+    T: Serialize + for<'a> ::arbitrary::Arbitrary<'a>,
+    U: Deserialize + From<T> + ::std::fmt::Debug,
+{{
+    // The input is generated from a fixed seed (with xorshift) so the tests are deterministic.
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+
+    for _ in 0..ARBITRARY_ROUND_TRIPS {{
+        let input = (0..ARBITRARY_INPUT_SIZE)
+            .map(|_| {{
+                state ^= state << 13_u32;
+                state ^= state >> 7_u32;
+                state ^= state << 17_u32;
+                state as u8
+            }})
+            .collect::<Vec<u8>>();
+
+        // Some types have no values (e.g., empty choices), so generation can fail.
+        let Ok(message) = T::arbitrary(&mut ::arbitrary::Unstructured::new(&input)) else {{
+            continue;
+        }};
+
+        let bytes = message.serialize_to_vec().unwrap();
+        let expected = format!(\"{{:?}}\", U::from(message));
+        let actual = format!(\"{{:?}}\", U::deserialize_from_slice(&bytes).unwrap());
+        assert_eq!(actual, expected);
+    }}
+}}",
+    )
+}
+
+// Write the imports of the traits and helpers from the `typical-runtime` crate, along with a check
+// that the version of the runtime is compatible with the version of the code generator. The traits
+// are re-exported so the generated code has the same interface with or without the runtime.
+fn write_runtime_imports<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    asynchronous: bool,
+    containers: bool,
+) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
+pub use typical_runtime::{{Deserialize, Serialize, Transport, UnknownField}};
+
+#[allow(unused_imports)]
+use std::io;
+
+#[allow(unused_imports)]
+use typical_runtime::internal::{{
+    MISSING_FIELDS_ERROR_MESSAGE, SliceRead, deserialize_field_header,
+    deserialize_field_header_slice, deserialize_field_header_with_size_mode,
+    deserialize_field_header_with_size_mode_slice, deserialize_varint, deserialize_varint_slice,
+    field_header_size, finish, finish_slice, payload_integer_encoded, serialize_field_header,
+    serialize_unknown_field, serialize_varint, sized_buffer, skip, skip_slice,
+    unknown_field_size, varint_size_from_first_byte, varint_size_from_value, zigzag_decode,
+    zigzag_encode,
+}};",
+    )?;
+
+    if containers {
+        writeln!(
+            buffer,
+            "
+#[allow(unused_imports)]
+pub use typical_runtime::{{
+    FramedReader, SchemaType, read_container, read_framed, write_container_header, write_framed,
+}};",
+        )?;
+    }
+
+    if asynchronous {
+        writeln!(
+            buffer,
+            "
+pub use typical_runtime::{{DeserializeAsync, SerializeAsync}};
+
+#[allow(unused_imports)]
+use typical_runtime::internal_async::{{
+    deserialize_field_header_async, deserialize_field_header_with_size_mode_async,
+    deserialize_varint_async, finish_async, serialize_field_header_async,
+    serialize_unknown_field_async, serialize_varint_async, skip_async,
+}};",
+        )?;
+    }
+
+    writeln!(
+        buffer,
+        "
+const _: () = typical_runtime::check_version(\"{typical_version}\");",
+    )
+}
+
+// Write the imports for code which doesn't depend on the standard library, followed by a minimal
+// replacement for the parts of `std::io` that the generated code uses. It's implemented for
+// `Vec<u8>` and `&mut [u8]` (for writing) and `&[u8]` (for reading).
+#[allow(clippy::too_many_lines)]
+fn write_no_std_imports_and_io_module<T: Write>(buffer: &mut T) -> Result<(), fmt::Error> {
+    writeln!(
+        buffer,
+        "\
+#[allow(unused_imports)]
+use alloc::{{boxed::Box, string::String, vec, vec::Vec}};
+use core::cmp::min;
+use io::{{BufRead, Error, ErrorKind, Write}};
+
+pub mod io {{
+    use alloc::{{string::String, vec::Vec}};
+    use core::{{cmp::min, fmt, mem::take, result, str}};
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum ErrorKind {{
+        InvalidData,
+        InvalidInput,
+        UnexpectedEof,
+        WriteZero,
+    }}
+
+    #[derive(Debug)]
+    pub struct Error {{
+        kind: ErrorKind,
+        message: &'static str,
+    }}
+
+    impl Error {{
+        pub fn new(kind: ErrorKind, message: &'static str) -> Self {{
+            Self {{ kind, message }}
+        }}
+
+        pub fn kind(&self) -> ErrorKind {{
+            self.kind
+        }}
+    }}
+
+    impl From<ErrorKind> for Error {{
+        fn from(kind: ErrorKind) -> Self {{
+            let message = match kind {{
+                ErrorKind::InvalidData => \"Invalid data.\",
+                ErrorKind::InvalidInput => \"Invalid input.\",
+                ErrorKind::UnexpectedEof => \"Unexpected end of file.\",
+                ErrorKind::WriteZero => \"Failed to write whole buffer.\",
+            }};
+
+            Self {{ kind, message }}
+        }}
+    }}
+
+    impl fmt::Display for Error {{
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {{
+            formatter.write_str(self.message)
+        }}
+    }}
+
+    impl core::error::Error for Error {{}}
+
+    pub type Result<T> = result::Result<T, Error>;
+
+    pub trait Write {{
+        fn write_all(&mut self, buffer: &[u8]) -> Result<()>;
+    }}
+
+    impl Write for Vec<u8> {{
+        fn write_all(&mut self, buffer: &[u8]) -> Result<()> {{
+            self.extend_from_slice(buffer);
+            Ok(())
+        }}
+    }}
+
+    impl Write for &mut [u8] {{
+        fn write_all(&mut self, buffer: &[u8]) -> Result<()> {{
+            if buffer.len() > self.len() {{
+                return Err(Error::new(
+                    ErrorKind::WriteZero,
+                    \"Failed to write whole buffer.\",
+                ));
+            }}
+
+            let (head, tail) = take(self).split_at_mut(buffer.len());
+            head.copy_from_slice(buffer);
+            *self = tail;
+            Ok(())
+        }}
+    }}
+
+    impl<T: Write + ?Sized> Write for &mut T {{
+        fn write_all(&mut self, buffer: &[u8]) -> Result<()> {{
+            (**self).write_all(buffer)
+        }}
+    }}
+
+    pub trait Read {{
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize>;
+
+        fn read_exact(&mut self, mut buffer: &mut [u8]) -> Result<()> {{
+            while !buffer.is_empty() {{
+                let size = self.read(buffer)?;
+
+                if size == 0 {{
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        \"Failed to fill whole buffer.\",
+                    ));
+                }}
+
+                buffer = &mut buffer[size..];
+            }}
+
+            Ok(())
+        }}
+
+        fn read_to_end(&mut self, buffer: &mut Vec<u8>) -> Result<usize> {{
+            let start = buffer.len();
+            let mut chunk = [0; 256];
+
+            loop {{
+                let size = self.read(&mut chunk)?;
+
+                if size == 0 {{
+                    return Ok(buffer.len() - start);
+                }}
+
+                buffer.extend_from_slice(&chunk[..size]);
+            }}
+        }}
+
+        fn read_to_string(&mut self, buffer: &mut String) -> Result<usize> {{
+            let mut bytes = Vec::new();
+            let size = self.read_to_end(&mut bytes)?;
+            let string = str::from_utf8(&bytes).map_err(|_| {{
+                Error::new(
+                    ErrorKind::InvalidData,
+                    \"Stream did not contain valid UTF-8.\",
+                )
+            }})?;
+            buffer.push_str(string);
+            Ok(size)
+        }}
+
+        fn take(self, limit: u64) -> Take<Self>
+        where
+            Self: Sized,
+        {{
+            Take {{ inner: self, limit }}
+        }}
+    }}
+
+    pub trait BufRead: Read {{
+        fn fill_buf(&mut self) -> Result<&[u8]>;
+
+        fn consume(&mut self, amount: usize);
+    }}
+
+    impl Read for &[u8] {{
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {{
+            let size = min(buffer.len(), self.len());
+            let (head, tail) = self.split_at(size);
+            buffer[..size].copy_from_slice(head);
+            *self = tail;
+            Ok(size)
+        }}
+    }}
+
+    impl BufRead for &[u8] {{
+        fn fill_buf(&mut self) -> Result<&[u8]> {{
+            Ok(*self)
+        }}
+
+        fn consume(&mut self, amount: usize) {{
+            *self = &self[amount..];
+        }}
+    }}
+
+    impl<T: Read + ?Sized> Read for &mut T {{
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {{
+            (**self).read(buffer)
+        }}
+    }}
+
+    impl<T: BufRead + ?Sized> BufRead for &mut T {{
+        fn fill_buf(&mut self) -> Result<&[u8]> {{
+            (**self).fill_buf()
+        }}
+
+        fn consume(&mut self, amount: usize) {{
+            (**self).consume(amount);
+        }}
+    }}
+
+    pub struct Take<T> {{
+        inner: T,
+        limit: u64,
+    }}
+
+    impl<T: Read> Read for Take<T> {{
+        fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {{
+            let max_size = min(buffer.len() as u64, self.limit) as usize;
+            let size = self.inner.read(&mut buffer[..max_size])?;
+            self.limit -= size as u64;
+            Ok(size)
+        }}
+    }}
+
+    impl<T: BufRead> BufRead for Take<T> {{
+        fn fill_buf(&mut self) -> Result<&[u8]> {{
+            if self.limit == 0 {{
+                return Ok(&[]);
+            }}
+
+            let buffer = self.inner.fill_buf()?;
+            let size = min(buffer.len() as u64, self.limit) as usize;
+            Ok(&buffer[..size])
+        }}
+
+        fn consume(&mut self, amount: usize) {{
+            let amount = min(amount as u64, self.limit) as usize;
+            self.limit -= amount as u64;
+            self.inner.consume(amount);
+        }}
+    }}
+}}
+",
+    )
+}
+
+// Insert a schema into a module.
+fn insert_schema(module: &mut Module, namespace: &schema::Namespace, schema: &schema::Schema) {
+    let mut iter = namespace.components.iter();
+
+    if let Some(head) = iter.next() {
+        if let Some(child) = module.children.get_mut(head) {
+            insert_schema(
+                child,
+                &schema::Namespace {
+                    components: iter.cloned().collect(),
+                },
+                schema,
+            );
+        } else {
+            let mut child = Module {
+                children: BTreeMap::new(),
+                schema: schema::Schema {
+                    comment: vec![],
+                    imports: BTreeMap::new(),
+                    declarations: vec![],
+                    services: vec![],
+                },
+            };
+
+            insert_schema(
+                &mut child,
+                &schema::Namespace {
+                    components: iter.cloned().collect(),
+                },
+                schema,
+            );
+
+            module.children.insert(head.clone(), child);
+        }
+    } else {
+        module.schema = schema.clone();
+    }
+}
+
+// Write a module, including a trailing line break.
+#[allow(clippy::too_many_arguments)]
+fn write_module<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+    module: &Module,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub mod ")?;
+    write_identifier(buffer, name, Snake, None)?;
+    writeln!(buffer, " {{")?;
+
+    // Without the standard library, the types from `alloc` must be imported explicitly.
+    if options.no_std {
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "#[allow(unused_imports)]")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(
+            buffer,
+            "use alloc::{{boxed::Box, string::String, vec, vec::Vec}};",
+        )?;
+        writeln!(buffer)?;
+    }
+
+    let mut new_namespace = namespace.clone();
+    new_namespace.components.push(name.clone());
+
+    write_module_contents(
+        buffer,
+        indentation + 1,
+        &new_namespace,
+        &module.children,
+        &module.schema,
+        options,
+        embedded_schema,
+        fallible_conversions,
+    )?;
+
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+// Write the contents of a module, including a trailing line break if there was anything to render.
+#[allow(clippy::too_many_arguments)]
+fn write_module_contents<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    namespace: &schema::Namespace,
+    children: &BTreeMap<Identifier, Module>,
+    schema: &schema::Schema,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    let schema_empty = schema.declarations.is_empty() && schema.services.is_empty();
+
+    for (i, (child_name, child)) in children.iter().enumerate() {
+        write_module(
+            buffer,
+            indentation,
+            namespace,
+            child_name,
+            child,
+            options,
+            embedded_schema,
+            fallible_conversions,
+        )?;
+
+        if i < children.len() - 1 || !schema_empty {
+            writeln!(buffer)?;
+        }
+    }
+
+    write_schema(
+        buffer,
+        indentation,
+        namespace,
+        schema,
+        options,
+        embedded_schema,
+        fallible_conversions,
+    )?;
+
+    Ok(())
+}
+
+// Write the contents of a module as a file for `generate_files`, and add the files for its
+// submodules. A submodule which has submodules of its own gets a directory with a `mod.rs` file.
+#[allow(clippy::too_many_arguments)]
+fn write_module_file<T: Write>(
+    files: &mut BTreeMap<PathBuf, String>,
+    buffer: &mut T,
+    directory: &Path,
+    namespace: &schema::Namespace,
+    module: &Module,
+    typical_version: &str,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    for (child_name, child) in &module.children {
+        write!(buffer, "pub mod ")?;
+        write_identifier(buffer, child_name, Snake, None)?;
+        writeln!(buffer, ";")?;
+
+        let mut child_buffer = String::new();
+        write_generated_file_header(&mut child_buffer, typical_version)?;
+
+        // Without the standard library, the types from `alloc` must be imported explicitly.
+        if options.no_std {
+            writeln!(
+                child_buffer,
+                "\
+#[allow(unused_imports)]
+use alloc::{{boxed::Box, string::String, vec, vec::Vec}};
+",
+            )?;
+        }
+
+        let mut child_namespace = namespace.clone();
+        child_namespace.components.push(child_name.clone());
+
+        let child_directory = directory.join(child_name.snake_case());
+
+        write_module_file(
+            files,
+            &mut child_buffer,
+            &child_directory,
+            &child_namespace,
+            child,
+            typical_version,
+            options,
+            embedded_schema,
+            fallible_conversions,
+        )?;
+
+        files.insert(
+            if child.children.is_empty() {
+                child_directory.with_extension("rs")
+            } else {
+                child_directory.join("mod.rs")
+            },
+            child_buffer,
+        );
+    }
+
+    let schema_empty = module.schema.declarations.is_empty() && module.schema.services.is_empty();
+    if !module.children.is_empty() && !schema_empty {
+        writeln!(buffer)?;
+    }
+
+    // The code generator uses the indentation to determine how many `super`s it takes to get from
+    // the code to the root module. So the schema is rendered as if it were nested in the root
+    // module, and then the indentation is removed.
+    let indentation = namespace.components.len();
+    let mut schema_buffer = String::new();
+    write_schema(
+        &mut schema_buffer,
+        indentation,
+        namespace,
+        &module.schema,
+        options,
+        embedded_schema,
+        fallible_conversions,
+    )?;
+    let prefix = INDENTATION.repeat(indentation);
+    for line in schema_buffer.lines() {
+        writeln!(buffer, "{}", line.strip_prefix(&prefix).unwrap_or(line))?;
+    }
+
+    Ok(())
+}
+
+// Write a schema, including a trailing line break if there was anything to render.
+#[allow(clippy::too_many_lines)]
+fn write_schema<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    // Construct a map from import name to namespace.
+    let mut imports = BTreeMap::new();
+    for (name, import) in &schema.imports {
+        // The `unwrap` is safe due to [ref:namespace_populated].
+        imports.insert(name.clone(), import.namespace.clone().unwrap());
+    }
+
+    // Determine which flavor of the blocking logic to use.
+    let blocking = if options.no_std { NoStd } else { Blocking };
+
+    // Write the declarations.
+    let mut iter = schema.declarations.iter().peekable();
+    while let Some(declaration) = iter.next() {
+        let type_overrides = declaration_type_overrides(options, namespace, declaration);
+
+        match &declaration.variant {
+            schema::DeclarationVariant::Struct => {
+                write_struct(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    Atlas,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    Out,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    In,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_supers(buffer, indentation)?;
+                write!(buffer, "Serialize for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, " {{")?;
+                write_size_function(buffer, indentation + 1)?;
+                writeln!(buffer)?;
+                write_serialize_function(buffer, indentation + 1, indentation, blocking)?;
+                writeln!(buffer)?;
+                write_serialize_into_functions(buffer, indentation + 1, indentation, blocking)?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_supers(buffer, indentation)?;
+                write!(buffer, "Deserialize for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_deserialize_function(buffer, indentation + 1, indentation, blocking)?;
+                writeln!(buffer)?;
+                write_deserialize_from_slice_function(buffer, indentation + 1, indentation)?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                if options.containers {
+                    writeln!(buffer)?;
+
+                    write_schema_type_impls(
+                        buffer,
+                        indentation,
+                        &declaration.name,
+                        &embedded_schema.declarations
+                            [&(namespace.clone(), declaration.name.clone())],
+                    )?;
+                }
+
+                if options.asynchronous {
+                    writeln!(buffer)?;
+                    write_async_trait_impls(buffer, indentation, &declaration.name)?;
+                }
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl From<")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                write!(buffer, "> for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                write!(buffer, "fn from(message: ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, ") -> Self {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                for field in &declaration.fields {
+                    let mut value = "message.".to_owned();
+                    write_identifier(&mut value, &field.name, Snake, None)?;
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &field.name, Snake, None)?;
+                    match field.rule {
+                        schema::Rule::Asymmetric => {
+                            write!(buffer, ": Some(")?;
+                            write_field_into_expression(
+                                buffer,
+                                indentation,
+                                field,
+                                &type_overrides,
+                                &value,
+                            )?;
+                        }
+                        schema::Rule::Optional => {
+                            write!(buffer, ": {value}.map(|payload| ")?;
+                            write_field_into_expression(
+                                buffer,
+                                indentation,
+                                field,
+                                &type_overrides,
+                                "payload",
+                            )?;
+                        }
+                        schema::Rule::Required => {
+                            write!(buffer, ": ")?;
+                            write_field_into_expression(
+                                buffer,
+                                indentation,
+                                field,
+                                &type_overrides,
+                                &value,
+                            )?;
+                        }
+                    }
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Optional => {
+                            writeln!(buffer, "),")?;
+                        }
+                        schema::Rule::Required => {
+                            writeln!(buffer, ",")?;
+                        }
+                    }
+                }
+                if declaration.open {
+                    write_indentation(buffer, indentation + 3)?;
+                    writeln!(buffer, "_unknown: message._unknown,")?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_struct_out_conversion(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                    fallible_conversions,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                write!(buffer, "pub fn atlas(&self) -> ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                writeln!(buffer, " {{")?;
+                if !&declaration.fields.is_empty() {
+                    for field in &declaration.fields {
+                        write_indentation(buffer, indentation + 2)?;
+                        write!(buffer, "let _")?;
+                        write_identifier(buffer, &field.name, Snake, None)?;
+                        match field.rule {
+                            schema::Rule::Asymmetric | schema::Rule::Required => {
+                                let mut value = "&self.".to_owned();
+                                write_identifier(&mut value, &field.name, Snake, None)?;
+                                write!(buffer, " = {{ let payload = ")?;
+                                write_type_override_borrow(
+                                    buffer,
+                                    indentation,
+                                    field,
+                                    &type_overrides,
+                                    &value,
+                                )?;
+                                write!(buffer, "; ")?;
+                                write_atlas_calculation(
+                                    buffer,
+                                    indentation,
+                                    &field.r#type.variant,
+                                    true,
+                                )?;
+                                writeln!(buffer, " }};")?;
+                            }
+                            schema::Rule::Optional => {
+                                write!(buffer, " = self.")?;
+                                write_identifier(buffer, &field.name, Snake, None)?;
+                                write!(buffer, ".as_ref()")?;
+                                if type_overrides.contains_key(&field.name) {
+                                    let (trait_name, _, borrow) =
+                                        type_override_trait(&field.r#type.variant);
+                                    write!(buffer, ".map(")?;
+                                    write_supers(buffer, indentation)?;
+                                    write!(buffer, "{trait_name}::{borrow})")?;
+                                }
+                                write!(buffer, ".map(|payload| ")?;
+                                write_atlas_calculation(
+                                    buffer,
+                                    indentation,
+                                    &field.r#type.variant,
                                     true,
                                 )?;
-                                writeln!(buffer, " }};")?;
+                                writeln!(buffer, ");")?;
+                            }
+                        }
+                    }
+                    writeln!(buffer)?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 3)?;
+                write!(buffer, "_size:")?;
+                if declaration.fields.is_empty() && !declaration.open {
+                    write!(buffer, " 0")?;
+                }
+                let mut first = true;
+                for field in &declaration.fields {
+                    writeln!(buffer)?;
+                    write_indentation(buffer, indentation + 4)?;
+                    if first {
+                        first = false;
+                    } else {
+                        write!(buffer, "+ ")?;
+                    }
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Required => {
+                            write!(buffer, "{{ let payload_atlas = &_")?;
+                            write_identifier(buffer, &field.name, Snake, None)?;
+                            write!(buffer, "; let payload_size = ")?;
+                        }
+                        schema::Rule::Optional => {
+                            write!(buffer, "_")?;
+                            write_identifier(buffer, &field.name, Snake, None)?;
+                            write!(
+                                buffer,
+                                ".as_ref().map_or(0_usize, |payload_atlas| {{ let payload_size = ",
+                            )?;
+                        }
+                    }
+                    write_atlas_lookup(buffer, &field.r#type.variant)?;
+                    write!(buffer, "; ")?;
+                    write_supers(buffer, indentation)?;
+                    write!(
+                        buffer,
+                        "field_header_size({}_u64, payload_size, {}) + payload_size }}",
+                        field.index,
+                        integer_encoded(&field.r#type),
+                    )?;
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Required => {}
+                        schema::Rule::Optional => {
+                            write!(buffer, ")")?;
+                        }
+                    }
+                }
+                if declaration.open {
+                    writeln!(buffer)?;
+                    write_indentation(buffer, indentation + 4)?;
+                    if !first {
+                        write!(buffer, "+ ")?;
+                    }
+                    write!(buffer, "self._unknown.iter().map(")?;
+                    write_supers(buffer, indentation)?;
+                    write!(buffer, "unknown_field_size).sum::<usize>()")?;
+                }
+                writeln!(buffer, ",")?;
+                for field in &declaration.fields {
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &field.name, Snake, None)?;
+                    write!(buffer, ": _")?;
+                    write_identifier(buffer, &field.name, Snake, None)?;
+                    writeln!(buffer, ",")?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+                write_struct_serialize_with_atlas_function(
+                    buffer,
+                    indentation,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                )?;
+                if options.asynchronous {
+                    writeln!(buffer)?;
+                    write_struct_serialize_with_atlas_function(
+                        buffer,
+                        indentation,
+                        declaration,
+                        &type_overrides,
+                        Async,
+                    )?;
+                }
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_struct_deserialize_from_reader_ref_function(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                )?;
+                writeln!(buffer)?;
+                write_struct_deserialize_from_reader_ref_function(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    Slice,
+                )?;
+                if options.asynchronous {
+                    writeln!(buffer)?;
+                    write_struct_deserialize_from_reader_ref_function(
+                        buffer,
+                        indentation,
+                        &imports,
+                        namespace,
+                        declaration,
+                        &type_overrides,
+                        Async,
+                    )?;
+                }
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "pub fn size(&self) -> usize {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "self._size")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                if options.builders {
+                    writeln!(buffer)?;
+                    write_struct_builder(
+                        buffer,
+                        indentation,
+                        &imports,
+                        namespace,
+                        declaration,
+                        &type_overrides,
+                    )?;
+                }
+
+                if options.arbitrary {
+                    writeln!(buffer)?;
+                    write_arbitrary_impls(
+                        buffer,
+                        indentation,
+                        &imports,
+                        namespace,
+                        declaration,
+                        &type_overrides,
+                    )?;
+                }
+            }
+            schema::DeclarationVariant::Choice => {
+                write_choice(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    Atlas,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_choice(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    Out,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_choice(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &type_overrides,
+                    In,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_supers(buffer, indentation)?;
+                write!(buffer, "Serialize for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, " {{")?;
+                write_size_function(buffer, indentation + 1)?;
+                writeln!(buffer)?;
+                write_serialize_function(buffer, indentation + 1, indentation, blocking)?;
+                writeln!(buffer)?;
+                write_serialize_into_functions(buffer, indentation + 1, indentation, blocking)?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_supers(buffer, indentation)?;
+                write!(buffer, "Deserialize for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_deserialize_function(buffer, indentation + 1, indentation, blocking)?;
+                writeln!(buffer)?;
+                write_deserialize_from_slice_function(buffer, indentation + 1, indentation)?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                if options.containers {
+                    writeln!(buffer)?;
+
+                    write_schema_type_impls(
+                        buffer,
+                        indentation,
+                        &declaration.name,
+                        &embedded_schema.declarations
+                            [&(namespace.clone(), declaration.name.clone())],
+                    )?;
+                }
+
+                if options.asynchronous {
+                    writeln!(buffer)?;
+                    write_async_trait_impls(buffer, indentation, &declaration.name)?;
+                }
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl From<")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                write!(buffer, "> for ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                write!(buffer, "fn from(message: ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, ") -> Self {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "match message {{")?;
+                for field in &declaration.fields {
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &field.name, Pascal, None)?;
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Optional => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                write!(buffer, "(fallback) => ")?;
+                            } else {
+                                write!(buffer, "(payload, fallback) => ")?;
+                            }
+                        }
+                        schema::Rule::Required => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                write!(buffer, " => ")?;
+                            } else {
+                                write!(buffer, "(payload) => ")?;
+                            }
+                        }
+                    }
+                    write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &field.name, Pascal, None)?;
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Required => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                writeln!(buffer, ",")?;
+                            } else {
+                                write!(buffer, "(")?;
+                                write_field_into_expression(
+                                    buffer,
+                                    indentation,
+                                    field,
+                                    &type_overrides,
+                                    "payload",
+                                )?;
+                                writeln!(buffer, "),")?;
                             }
-                            schema::Rule::Optional => {
-                                write!(buffer, " = self.")?;
-                                write_identifier(buffer, &field.name, Snake, None)?;
-                                write!(buffer, ".as_ref().map(|payload| ")?;
-                                write_atlas_calculation(
+                        }
+                        schema::Rule::Optional => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                writeln!(buffer, "(Box::new((*fallback).into())),")?;
+                            } else {
+                                write!(buffer, "(")?;
+                                write_field_into_expression(
                                     buffer,
                                     indentation,
-                                    &field.r#type.variant,
-                                    true,
+                                    field,
+                                    &type_overrides,
+                                    "payload",
                                 )?;
-                                writeln!(buffer, ");")?;
+                                writeln!(buffer, ", Box::new((*fallback).into())),")?;
+                            }
+                        }
+                    }
+                }
+                if declaration.open {
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+                    write!(buffer, " {{ index, payload }} => ")?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+                    writeln!(buffer, " {{ index, payload }},")?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_choice_out_conversion(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                    fallible_conversions,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                write!(buffer, "pub fn atlas(&self) -> ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                writeln!(buffer, " {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                // [tag:empty_enum_ref_match] We match on `self*` instead of `self` due to
+                // https://github.com/rust-lang/rust/issues/78123.
+                writeln!(buffer, "match *self {{")?;
+                for field in &declaration.fields {
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &field.name, Pascal, None)?;
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Optional => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                writeln!(buffer, "(ref fallback) => {{")?;
+                                write_indentation(buffer, indentation + 4)?;
+                                writeln!(buffer, "let payload = &();")?;
+                            } else {
+                                writeln!(buffer, "(ref payload, ref fallback) => {{")?;
+                            }
+                        }
+                        schema::Rule::Required => {
+                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                                writeln!(buffer, " => {{")?;
+                                write_indentation(buffer, indentation + 4)?;
+                                writeln!(buffer, "let payload = &();")?;
+                            } else {
+                                writeln!(buffer, "(ref payload) => {{")?;
                             }
                         }
                     }
+                    write_type_override_rebinding(
+                        buffer,
+                        indentation + 4,
+                        indentation,
+                        field,
+                        &type_overrides,
+                    )?;
+                    write_indentation(buffer, indentation + 4)?;
+                    write!(buffer, "let payload_atlas = ")?;
+                    write_atlas_calculation(buffer, indentation, &field.r#type.variant, true)?;
+                    writeln!(buffer, ";")?;
+                    write_indentation(buffer, indentation + 4)?;
+                    write!(
+                        buffer,
+                        "let payload_size = {{ let payload_atlas = &payload_atlas; ",
+                    )?;
+                    write_atlas_lookup(buffer, &field.r#type.variant)?;
+                    writeln!(buffer, " }};")?;
+                    match field.rule {
+                        schema::Rule::Asymmetric | schema::Rule::Optional => {
+                            write_indentation(buffer, indentation + 4)?;
+                            writeln!(buffer, "let fallback_atlas = fallback.atlas();")?;
+                            write_indentation(buffer, indentation + 4)?;
+                            write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                            write!(buffer, "::")?;
+                            write_identifier(buffer, &field.name, Pascal, None)?;
+                            write!(buffer, "(")?;
+                            write_supers(buffer, indentation)?;
+                            writeln!(
+                                buffer,
+                                "field_header_size({}_u64, payload_size, {}) + \
+                                    payload_size + fallback_atlas.size(), \
+                                    payload_atlas, Box::new(fallback_atlas))",
+                                field.index,
+                                integer_encoded(&field.r#type),
+                            )?;
+                        }
+                        schema::Rule::Required => {
+                            write_indentation(buffer, indentation + 4)?;
+                            write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                            write!(buffer, "::")?;
+                            write_identifier(buffer, &field.name, Pascal, None)?;
+                            write!(buffer, "(")?;
+                            write_supers(buffer, indentation)?;
+                            writeln!(
+                                buffer,
+                                "field_header_size({}_u64, payload_size, {}) + payload_size, \
+                                    payload_atlas)",
+                                field.index,
+                                integer_encoded(&field.r#type),
+                            )?;
+                        }
+                    }
+                    write_indentation(buffer, indentation + 3)?;
+                    writeln!(buffer, "}}")?;
+                }
+                if declaration.open {
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+                    writeln!(buffer, " {{ index, ref payload }} => {{")?;
+                    write_indentation(buffer, indentation + 4)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+                    write!(buffer, "(")?;
+                    write_supers(buffer, indentation)?;
+                    write!(buffer, "field_header_size(index, payload.len(), ")?;
+                    write_supers(buffer, indentation)?;
+                    writeln!(buffer, "payload_integer_encoded(payload)) + payload.len())")?;
+                    write_indentation(buffer, indentation + 3)?;
+                    writeln!(buffer, "}}")?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+                write_choice_serialize_with_atlas_function(
+                    buffer,
+                    indentation,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                )?;
+                if options.asynchronous {
+                    writeln!(buffer)?;
+                    write_choice_serialize_with_atlas_function(
+                        buffer,
+                        indentation,
+                        declaration,
+                        &type_overrides,
+                        Async,
+                    )?;
+                }
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                writeln!(buffer, " {{")?;
+                write_choice_deserialize_from_reader_ref_function(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    blocking,
+                )?;
+                writeln!(buffer)?;
+                write_choice_deserialize_from_reader_ref_function(
+                    buffer,
+                    indentation,
+                    &imports,
+                    namespace,
+                    declaration,
+                    &type_overrides,
+                    Slice,
+                )?;
+                if options.asynchronous {
                     writeln!(buffer)?;
+                    write_choice_deserialize_from_reader_ref_function(
+                        buffer,
+                        indentation,
+                        &imports,
+                        namespace,
+                        declaration,
+                        &type_overrides,
+                        Async,
+                    )?;
                 }
-                write_indentation(buffer, indentation + 2)?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                writeln!(buffer)?;
+
+                write_indentation(buffer, indentation)?;
+                write!(buffer, "impl ")?;
                 write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
                 writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 3)?;
-                write!(buffer, "_size:")?;
-                if declaration.fields.is_empty() {
-                    write!(buffer, " 0")?;
-                }
-                let mut first = true;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "pub fn size(&self) -> usize {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "match *self {{")?; // [ref:empty_enum_ref_match]
                 for field in &declaration.fields {
-                    writeln!(buffer)?;
-                    write_indentation(buffer, indentation + 4)?;
-                    if first {
-                        first = false;
-                    } else {
-                        write!(buffer, "+ ")?;
-                    }
+                    write_indentation(buffer, indentation + 3)?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &field.name, Pascal, None)?;
                     match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Required => {
-                            write!(buffer, "{{ let payload_atlas = &_")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, "; let payload_size = ")?;
-                        }
-                        schema::Rule::Optional => {
-                            write!(buffer, "_")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(
-                                buffer,
-                                ".as_ref().map_or(0_usize, |payload_atlas| {{ let payload_size = ",
-                            )?;
+                        schema::Rule::Asymmetric | schema::Rule::Optional => {
+                            writeln!(buffer, "(ref size, _, _) => *size,")?;
                         }
-                    }
-                    write_atlas_lookup(buffer, &field.r#type.variant)?;
-                    write!(buffer, "; ")?;
-                    write_supers(buffer, indentation)?;
-                    write!(
-                        buffer,
-                        "field_header_size({}_u64, payload_size, {}) + payload_size }}",
-                        field.index,
-                        integer_encoded(&field.r#type),
-                    )?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Required => {}
-                        schema::Rule::Optional => {
-                            write!(buffer, ")")?;
+                        schema::Rule::Required => {
+                            writeln!(buffer, "(ref size, _) => *size,")?;
                         }
                     }
                 }
-                writeln!(buffer, ",")?;
-                for field in &declaration.fields {
+                if declaration.open {
                     write_indentation(buffer, indentation + 3)?;
-                    write_identifier(buffer, &field.name, Snake, None)?;
-                    write!(buffer, ": _")?;
-                    write_identifier(buffer, &field.name, Snake, None)?;
-                    writeln!(buffer, ",")?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                    write!(buffer, "::")?;
+                    write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+                    writeln!(buffer, "(ref size) => *size,")?;
                 }
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "}}")?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "}}")?;
-                writeln!(buffer)?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "pub fn serialize_with_atlas<T: ::std::io::Write>(")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "&self,")?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
+
+                if options.arbitrary {
+                    writeln!(buffer)?;
+                    write_arbitrary_impls(
+                        buffer,
+                        indentation,
+                        &imports,
+                        namespace,
+                        declaration,
+                        &type_overrides,
+                    )?;
+                }
+            }
+        }
+
+        if iter.peek().is_some() || !schema.services.is_empty() {
+            writeln!(buffer)?;
+        }
+    }
+
+    // Write the services.
+    let mut iter = schema.services.iter().peekable();
+    while let Some(service) = iter.next() {
+        write_service(buffer, indentation, &imports, namespace, service, blocking)?;
+
+        if iter.peek().is_some() {
+            writeln!(buffer)?;
+        }
+    }
+
+    // Write the tests which check that arbitrary messages survive a round trip.
+    if options.arbitrary && !schema.declarations.is_empty() {
+        writeln!(buffer)?;
+        write_arbitrary_round_trip_tests(buffer, indentation, schema)?;
+    }
+
+    Ok(())
+}
+
+// Write the function that serializes a struct using a precomputed atlas.
+#[allow(clippy::too_many_lines)]
+fn write_struct_serialize_with_atlas_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "pub {}fn serialize_with_atlas{}<T: {}>(",
+        flavor.qualifier(),
+        flavor.suffix(),
+        flavor.writer_bound(indentation),
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "&self,")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "writer: &mut T,")?;
+    write_indentation(buffer, indentation + 2)?;
+    write!(buffer, "atlas: &")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+    writeln!(buffer, ",")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        ") -> {}::Result<()> {{",
+        flavor.io_module(indentation)
+    )?;
+    for field in &declaration.fields {
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Required => {
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "writer: &mut T,")?;
+                writeln!(buffer, "{{")?;
+                write_indentation(buffer, indentation + 3)?;
+                write!(buffer, "let payload = &self.")?;
+                write_identifier(buffer, &field.name, Snake, None)?;
+                writeln!(buffer, ";")?;
+                write_indentation(buffer, indentation + 3)?;
+                write!(buffer, "let payload_atlas = &atlas.")?;
+                write_identifier(buffer, &field.name, Snake, None)?;
+                writeln!(buffer, ";")?;
+            }
+            schema::Rule::Optional => {
                 write_indentation(buffer, indentation + 2)?;
-                write!(buffer, "atlas: &")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                writeln!(buffer, ",")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, ") -> ::std::io::Result<()> {{")?;
-                for field in &declaration.fields {
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Required => {
-                            write_indentation(buffer, indentation + 2)?;
-                            writeln!(buffer, "{{")?;
-                            write_indentation(buffer, indentation + 3)?;
-                            write!(buffer, "let payload = &self.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            writeln!(buffer, ";")?;
-                            write_indentation(buffer, indentation + 3)?;
-                            write!(buffer, "let payload_atlas = &atlas.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            writeln!(buffer, ";")?;
-                        }
-                        schema::Rule::Optional => {
-                            write_indentation(buffer, indentation + 2)?;
-                            write!(
-                                buffer,
-                                "if let (Some(payload), Some(payload_atlas)) = (&self.",
-                            )?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            write!(buffer, ", &atlas.")?;
-                            write_identifier(buffer, &field.name, Snake, None)?;
-                            writeln!(buffer, ") {{")?;
-                        }
+                write!(
+                    buffer,
+                    "if let (Some(payload), Some(payload_atlas)) = (&self.",
+                )?;
+                write_identifier(buffer, &field.name, Snake, None)?;
+                write!(buffer, ", &atlas.")?;
+                write_identifier(buffer, &field.name, Snake, None)?;
+                writeln!(buffer, ") {{")?;
+            }
+        }
+        write_type_override_rebinding(buffer, indentation + 3, indentation, field, type_overrides)?;
+        write_indentation(buffer, indentation + 3)?;
+        write_supers(buffer, indentation)?;
+        write!(
+            buffer,
+            "serialize_field_header{}(writer, {}_u64, ",
+            flavor.suffix(),
+            field.index,
+        )?;
+        write_atlas_lookup(buffer, &field.r#type.variant)?;
+        writeln!(
+            buffer,
+            ", {}){}?;",
+            integer_encoded(&field.r#type),
+            flavor.postfix(),
+        )?;
+        write_serialization_invocation(
+            buffer,
+            indentation + 3,
+            indentation,
+            &field.r#type.variant,
+            true,
+            flavor,
+        )?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        writeln!(buffer)?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "for field in &self._unknown {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "serialize_unknown_field{}(writer, field){}?;",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        writeln!(buffer)?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "Ok(())")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+// Write the function that deserializes a struct from a reader.
+#[allow(clippy::too_many_lines)]
+fn write_struct_deserialize_from_reader_ref_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "{}{}fn deserialize_from_reader_ref{}<T: {}>(",
+        flavor.visibility(),
+        flavor.qualifier(),
+        flavor.suffix(),
+        flavor.reader_bound(indentation),
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "reader: &mut T,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        ") -> {}::Result<Self> {{",
+        flavor.io_module(indentation)
+    )?;
+    if !&declaration.fields.is_empty() {
+        for field in &declaration.fields {
+            write_indentation(buffer, indentation + 2)?;
+            write!(buffer, "let mut _")?;
+            write_identifier(buffer, &field.name, Snake, None)?;
+            write!(buffer, ": Option<")?;
+            write_field_type(buffer, imports, namespace, field, type_overrides, In)?;
+            writeln!(buffer, "> = None;")?;
+        }
+        writeln!(buffer)?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "let mut unknown_fields = vec![];")?;
+        writeln!(buffer)?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "loop {{")?;
+    write_indentation(buffer, indentation + 3)?;
+    if declaration.open {
+        write!(buffer, "let (index, size_mode, payload_size) = match ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "deserialize_field_header_with_size_mode{}(&mut *reader){} {{",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+    } else {
+        write!(buffer, "let (index, payload_size) = match ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "deserialize_field_header{}(&mut *reader){} {{",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+    }
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "Ok(header) => header,")?;
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "Err(err) => {{")?;
+    write_indentation(buffer, indentation + 5)?;
+    writeln!(
+        buffer,
+        "if let {}::ErrorKind::UnexpectedEof = err.kind() {{",
+        flavor.io_module(indentation),
+    )?;
+    write_indentation(buffer, indentation + 6)?;
+    writeln!(buffer, "break;")?;
+    write_indentation(buffer, indentation + 5)?;
+    writeln!(buffer, "}}")?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation + 5)?;
+    writeln!(buffer, "return Err(err);")?;
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "}};")?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(
+        buffer,
+        "let mut sub_reader = {}::take(&mut *reader, payload_size as u64);",
+        flavor.read_trait(indentation),
+    )?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "match index {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "{} => {{", field.index)?;
+        write_deserialization_invocation(
+            buffer,
+            indentation + 5,
+            indentation,
+            imports,
+            namespace,
+            &field.r#type.variant,
+            true,
+            flavor,
+        )?;
+        write_type_override_conversion(
+            buffer,
+            indentation + 5,
+            indentation,
+            field,
+            type_overrides,
+        )?;
+        write_indentation(buffer, indentation + 5)?;
+        write!(buffer, "_")?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        writeln!(buffer, ".get_or_insert(payload);")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "_ => {{")?;
+    if declaration.open {
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(buffer, "let mut payload = vec![];")?;
+        write_bounded_reservation(buffer, indentation + 5, indentation, 1, flavor)?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(
+            buffer,
+            "{}::read_to_end(&mut sub_reader, &mut payload){}?;",
+            flavor.read_trait(indentation),
+            flavor.postfix(),
+        )?;
+        write_indentation(buffer, indentation + 5)?;
+        write!(buffer, "unknown_fields.push(")?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "UnknownField {{ index, size_mode, payload }});")?;
+    } else {
+        write_indentation(buffer, indentation + 5)?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "skip{}(&mut sub_reader, payload_size){}?;",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+    }
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "}}")?;
+    write_finish_sub_reader(buffer, indentation + 3, indentation, flavor)?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}")?;
+    writeln!(buffer)?;
+    // The logic below ensures that all required fields have been parsed.
+    // [tag:required_fields_present]
+    if declaration.fields.iter().any(|field| match field.rule {
+        schema::Rule::Asymmetric | schema::Rule::Optional => false,
+        schema::Rule::Required => true,
+    }) {
+        write_indentation(buffer, indentation + 2)?;
+        write!(buffer, "if ")?;
+        let mut first = true;
+        for field in &declaration.fields {
+            match field.rule {
+                schema::Rule::Asymmetric | schema::Rule::Optional => {}
+                schema::Rule::Required => {
+                    if first {
+                        first = false;
+                    } else {
+                        writeln!(buffer)?;
+                        write_indentation(buffer, indentation + 3)?;
+                        write!(buffer, "|| ")?;
                     }
-                    write_indentation(buffer, indentation + 3)?;
-                    write_supers(buffer, indentation)?;
-                    write!(
+                    write!(buffer, "_")?;
+                    write_identifier(buffer, &field.name, Snake, None)?;
+                    write!(buffer, ".is_none()")?;
+                }
+            }
+        }
+        writeln!(buffer, " {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(
+            buffer,
+            "return Err({}::Error::new(",
+            flavor.io_module(indentation)
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(
+            buffer,
+            "{}::ErrorKind::InvalidData,",
+            flavor.io_module(indentation)
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "MISSING_FIELDS_ERROR_MESSAGE,")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "));")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        writeln!(buffer)?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    write!(buffer, "Ok(")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+    writeln!(buffer, " {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 3)?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        write!(buffer, ": _")?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Optional => {}
+            schema::Rule::Required => {
+                // This `unwrap` is safe due to [ref:required_fields_present].
+                write!(buffer, ".unwrap()")?;
+            }
+        }
+        writeln!(buffer, ",")?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "_unknown: unknown_fields,")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}})")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+// Write the function that serializes a choice using a precomputed atlas.
+#[allow(clippy::too_many_lines)]
+fn write_choice_serialize_with_atlas_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "pub {}fn serialize_with_atlas{}<T: {}>(",
+        flavor.qualifier(),
+        flavor.suffix(),
+        flavor.writer_bound(indentation),
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "&self,")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "writer: &mut T,")?;
+    write_indentation(buffer, indentation + 2)?;
+    write!(buffer, "atlas: &")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+    writeln!(buffer, ",")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        ") -> {}::Result<()> {{",
+        flavor.io_module(indentation)
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "match (self, atlas) {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "(")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &field.name, Pascal, None)?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Optional => {
+                if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                    write!(buffer, "(fallback), ")?;
+                } else {
+                    write!(buffer, "(payload, fallback), ")?;
+                }
+            }
+            schema::Rule::Required => {
+                if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                    write!(buffer, ", ")?;
+                } else {
+                    write!(buffer, "(payload), ")?;
+                }
+            }
+        }
+        write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &field.name, Pascal, None)?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Optional => {
+                writeln!(buffer, "(_, payload_atlas, fallback_atlas)) => {{")?;
+            }
+            schema::Rule::Required => {
+                writeln!(buffer, "(_, payload_atlas)) => {{")?;
+            }
+        }
+        write_type_override_rebinding(buffer, indentation + 4, indentation, field, type_overrides)?;
+        write_indentation(buffer, indentation + 4)?;
+        write_supers(buffer, indentation)?;
+        write!(
+            buffer,
+            "serialize_field_header{}(writer, {}_u64, ",
+            flavor.suffix(),
+            field.index,
+        )?;
+        write_atlas_lookup(buffer, &field.r#type.variant)?;
+        writeln!(
+            buffer,
+            ", {}){}?;",
+            integer_encoded(&field.r#type),
+            flavor.postfix(),
+        )?;
+        write_serialization_invocation(
+            buffer,
+            indentation + 4,
+            indentation,
+            &field.r#type.variant,
+            true,
+            flavor,
+        )?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Optional => {
+                write_indentation(buffer, indentation + 4)?;
+                match flavor {
+                    // The fallback has the same type as the message, and recursive asynchronous
+                    // functions require the recursive invocation to be boxed.
+                    Async => writeln!(
                         buffer,
-                        "serialize_field_header(writer, {}_u64, ",
-                        field.index,
-                    )?;
-                    write_atlas_lookup(buffer, &field.r#type.variant)?;
-                    writeln!(buffer, ", {})?;", integer_encoded(&field.r#type))?;
-                    write_serialization_invocation(
+                        "Box::pin(fallback.serialize_with_atlas_async(writer, fallback_atlas))\
+                         .await",
+                    )?,
+                    Blocking | NoStd | Slice => writeln!(
                         buffer,
-                        indentation + 3,
-                        indentation,
-                        &field.r#type.variant,
-                        true,
-                    )?;
-                    write_indentation(buffer, indentation + 2)?;
-                    writeln!(buffer, "}}")?;
-                    writeln!(buffer)?;
+                        "fallback.serialize_with_atlas(writer, fallback_atlas)",
+                    )?,
                 }
-                write_indentation(buffer, indentation + 2)?;
+            }
+            schema::Rule::Required => {
+                write_indentation(buffer, indentation + 4)?;
                 writeln!(buffer, "Ok(())")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+            }
+        }
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "(")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        write!(buffer, " {{ index, payload }}, ")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        writeln!(buffer, "(_)) => {{")?;
+        write_indentation(buffer, indentation + 4)?;
+        write_supers(buffer, indentation)?;
+        write!(
+            buffer,
+            "serialize_field_header{}(writer, *index, payload.len(), ",
+            flavor.suffix(),
+        )?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "payload_integer_encoded(payload)){}?;",
+            flavor.postfix(),
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        write_write_all_invocation(buffer, flavor, "payload")?;
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "(_, _) => panic!(),")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
 
-                writeln!(buffer)?;
+    Ok(())
+}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(
-                    buffer,
-                    "pub fn deserialize_from_reader_ref<T: ::std::io::BufRead>(",
-                )?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "reader: &mut T,")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, ") -> ::std::io::Result<Self> {{")?;
-                if !&declaration.fields.is_empty() {
-                    for field in &declaration.fields {
-                        write_indentation(buffer, indentation + 2)?;
-                        write!(buffer, "let mut _")?;
-                        write_identifier(buffer, &field.name, Snake, None)?;
-                        write!(buffer, ": Option<")?;
-                        write_type(buffer, &imports, namespace, &field.r#type.variant, In)?;
-                        writeln!(buffer, "> = None;")?;
-                    }
-                    writeln!(buffer)?;
-                }
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "loop {{")?;
-                write_indentation(buffer, indentation + 3)?;
-                write!(buffer, "let (index, payload_size) = match ")?;
-                write_supers(buffer, indentation)?;
-                writeln!(buffer, "deserialize_field_header(&mut *reader) {{")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "Ok(header) => header,")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "Err(err) => {{")?;
+// Write the function that deserializes a choice from a reader.
+#[allow(clippy::too_many_lines)]
+fn write_choice_deserialize_from_reader_ref_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "{}{}fn deserialize_from_reader_ref{}<T: {}>(",
+        flavor.visibility(),
+        flavor.qualifier(),
+        flavor.suffix(),
+        flavor.reader_bound(indentation),
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "reader: &mut T,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        ") -> {}::Result<Self> {{",
+        flavor.io_module(indentation)
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "loop {{")?;
+    write_indentation(buffer, indentation + 3)?;
+    write!(buffer, "let (index, payload_size) = ")?;
+    write_supers(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "deserialize_field_header{}(&mut *reader){}?;",
+        flavor.suffix(),
+        flavor.postfix(),
+    )?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(
+        buffer,
+        "let mut sub_reader = {}::take(&mut *reader, payload_size as u64);",
+        flavor.read_trait(indentation),
+    )?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "match index {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "{} => {{", field.index)?;
+        write_deserialization_invocation(
+            buffer,
+            indentation + 5,
+            indentation,
+            imports,
+            namespace,
+            &field.r#type.variant,
+            true,
+            flavor,
+        )?;
+        write_type_override_conversion(
+            buffer,
+            indentation + 5,
+            indentation,
+            field,
+            type_overrides,
+        )?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Required => {
                 write_indentation(buffer, indentation + 5)?;
+                write_supers(buffer, indentation)?;
                 writeln!(
                     buffer,
-                    "if let std::io::ErrorKind::UnexpectedEof = err.kind() {{",
+                    "finish{}(&mut *reader){}?;",
+                    flavor.suffix(),
+                    flavor.postfix(),
                 )?;
-                write_indentation(buffer, indentation + 6)?;
-                writeln!(buffer, "break;")?;
-                write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "}}")?;
-                writeln!(buffer)?;
                 write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "return Err(err);")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}};")?;
-                writeln!(buffer)?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(
-                    buffer,
-                    "let mut sub_reader = ::std::io::Read::take(&mut *reader, \
-                        payload_size as u64);",
-                )?;
-                writeln!(buffer)?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "match index {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 4)?;
-                    writeln!(buffer, "{} => {{", field.index)?;
-                    write_deserialization_invocation(
-                        buffer,
-                        indentation + 5,
-                        indentation,
-                        &imports,
-                        namespace,
-                        &field.r#type.variant,
-                        true,
-                    )?;
-                    write_indentation(buffer, indentation + 5)?;
-                    write!(buffer, "_")?;
-                    write_identifier(buffer, &field.name, Snake, None)?;
-                    writeln!(buffer, ".get_or_insert(payload);")?;
-                    write_indentation(buffer, indentation + 4)?;
-                    writeln!(buffer, "}}")?;
+                write!(buffer, "return Ok(")?;
+                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                write!(buffer, "::")?;
+                write_identifier(buffer, &field.name, Pascal, None)?;
+                if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                    writeln!(buffer, ");")?;
+                } else {
+                    writeln!(buffer, "(payload));")?;
                 }
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "_ => {{")?;
+            }
+            schema::Rule::Optional => {
+                write_finish_sub_reader(buffer, indentation + 5, indentation, flavor)?;
                 write_indentation(buffer, indentation + 5)?;
-                write_supers(buffer, indentation)?;
-                writeln!(buffer, "skip(&mut sub_reader, payload_size)?;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                writeln!(buffer)?;
-                // The logic below ensures that all required fields have been parsed.
-                // [tag:required_fields_present]
-                if declaration.fields.iter().any(|field| match field.rule {
-                    schema::Rule::Asymmetric | schema::Rule::Optional => false,
-                    schema::Rule::Required => true,
-                }) {
-                    write_indentation(buffer, indentation + 2)?;
-                    write!(buffer, "if ")?;
-                    first = true;
-                    for field in &declaration.fields {
-                        match field.rule {
-                            schema::Rule::Asymmetric | schema::Rule::Optional => {}
-                            schema::Rule::Required => {
-                                if first {
-                                    first = false;
-                                } else {
-                                    writeln!(buffer)?;
-                                    write_indentation(buffer, indentation + 3)?;
-                                    write!(buffer, "|| ")?;
-                                }
-                                write!(buffer, "_")?;
-                                write_identifier(buffer, &field.name, Snake, None)?;
-                                write!(buffer, ".is_none()")?;
-                            }
-                        }
+                write!(buffer, "let fallback = Box::new(")?;
+                match flavor {
+                    // The fallback has the same type as the message, and recursive asynchronous
+                    // functions require the recursive invocation to be boxed.
+                    Async => {
+                        write!(buffer, "Box::pin(")?;
+                        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                        writeln!(
+                            buffer,
+                            "::deserialize_from_reader_ref_async(&mut *reader)).await?);",
+                        )?;
+                    }
+                    Blocking | NoStd | Slice => {
+                        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+                        writeln!(
+                            buffer,
+                            "::deserialize_from_reader_ref{}(&mut *reader)?);",
+                            flavor.suffix(),
+                        )?;
                     }
-                    writeln!(buffer, " {{")?;
-                    write_indentation(buffer, indentation + 3)?;
-                    writeln!(buffer, "return Err(::std::io::Error::new(")?;
-                    write_indentation(buffer, indentation + 4)?;
-                    writeln!(buffer, "::std::io::ErrorKind::InvalidData,")?;
-                    write_indentation(buffer, indentation + 4)?;
-                    write_supers(buffer, indentation)?;
-                    writeln!(buffer, "MISSING_FIELDS_ERROR_MESSAGE,")?;
-                    write_indentation(buffer, indentation + 3)?;
-                    writeln!(buffer, "));")?;
-                    write_indentation(buffer, indentation + 2)?;
-                    writeln!(buffer, "}}")?;
-                    writeln!(buffer)?;
                 }
-                write_indentation(buffer, indentation + 2)?;
-                write!(buffer, "Ok(")?;
+                write_indentation(buffer, indentation + 5)?;
+                write!(buffer, "return Ok(")?;
                 write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 3)?;
-                    write_identifier(buffer, &field.name, Snake, None)?;
-                    write!(buffer, ": _")?;
-                    write_identifier(buffer, &field.name, Snake, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {}
-                        schema::Rule::Required => {
-                            // This `unwrap` is safe due to [ref:required_fields_present].
-                            write!(buffer, ".unwrap()")?;
-                        }
-                    }
-                    writeln!(buffer, ",")?;
+                write!(buffer, "::")?;
+                write_identifier(buffer, &field.name, Pascal, None)?;
+                if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
+                    writeln!(buffer, "(fallback));")?;
+                } else {
+                    writeln!(buffer, "(payload, fallback));")?;
                 }
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}})")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+            }
+        }
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "_ => {{")?;
+    if declaration.open {
+        // If the message ends with an unrecognized field, that field is preserved.
+        // Earlier unrecognized fields, if any, must have been optional or asymmetric,
+        // so they can be discarded in favor of their fallbacks.
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(buffer, "let mut payload = vec![];")?;
+        write_bounded_reservation(buffer, indentation + 5, indentation, 1, flavor)?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(
+            buffer,
+            "{}::read_to_end(&mut sub_reader, &mut payload){}?;",
+            flavor.read_trait(indentation),
+            flavor.postfix(),
+        )?;
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(
+            buffer,
+            "if {}::fill_buf(&mut *reader){}?.is_empty() {{",
+            flavor.buf_read_trait(indentation),
+            flavor.postfix(),
+        )?;
+        write_indentation(buffer, indentation + 6)?;
+        write!(buffer, "return Ok(")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        writeln!(buffer, " {{ index, payload }});")?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(buffer, "}}")?;
+    } else {
+        write_indentation(buffer, indentation + 5)?;
+        write_supers(buffer, indentation)?;
+        writeln!(
+            buffer,
+            "skip{}(&mut sub_reader, payload_size){}?;",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+    }
+    write_indentation(buffer, indentation + 4)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
 
-                writeln!(buffer)?;
+    Ok(())
+}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "pub fn size(&self) -> usize {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "self._size")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
-            }
-            schema::DeclarationVariant::Choice => {
-                write_choice(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    Atlas,
-                )?;
+// Write the server trait, dispatcher, and client stub for a service, including a trailing line
+// break.
+#[allow(clippy::too_many_lines)]
+fn write_service<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    service: &schema::Service,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    let server_name = service.name.join(&"Server".into());
+    let client_name = service.name.join(&"Client".into());
 
-                writeln!(buffer)?;
+    // Write the server trait.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub trait ")?;
+    write_identifier(buffer, &server_name, Pascal, None)?;
+    writeln!(buffer, " {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "fn ")?;
+        write_identifier(buffer, &method.name, Snake, None)?;
+        write!(buffer, "(&mut self, request: ")?;
+        write_type(buffer, imports, namespace, &method.request.variant, In)?;
+        write!(buffer, ") -> {}::Result<", flavor.io_module(indentation))?;
+        write_type(buffer, imports, namespace, &method.response.variant, Out)?;
+        writeln!(buffer, ">;")?;
+    }
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
 
-                write_choice(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    Out,
-                )?;
+    writeln!(buffer)?;
+
+    // Write the dispatcher, which routes a request to the appropriate method based on its index.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub fn ")?;
+    write_identifier(
+        buffer,
+        &Identifier::from("dispatch").join(&service.name),
+        Snake,
+        None,
+    )?;
+    write!(buffer, "<T: ")?;
+    write_identifier(buffer, &server_name, Pascal, None)?;
+    writeln!(buffer, ">(")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "server: &mut T,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "method_index: u64,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "request: &[u8],")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        ") -> {}::Result<Vec<u8>> {{",
+        flavor.io_module(indentation)
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "match method_index {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "{} => {{", method.index)?;
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "let request = <")?;
+        write_type(buffer, imports, namespace, &method.request.variant, In)?;
+        write!(buffer, " as ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "Deserialize>::deserialize_from_slice(request)?;")?;
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "let response = server.")?;
+        write_identifier(buffer, &method.name, Snake, None)?;
+        writeln!(buffer, "(request)?;")?;
+        write_indentation(buffer, indentation + 3)?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "Serialize::serialize_to_vec(&response)")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "_ => Err({}::Error::new(",
+        flavor.io_module(indentation)
+    )?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(
+        buffer,
+        "{}::ErrorKind::InvalidInput,",
+        flavor.io_module(indentation)
+    )?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "\"Unknown method index.\",")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, ")),")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
 
-                writeln!(buffer)?;
+    writeln!(buffer)?;
 
-                write_choice(
-                    buffer,
-                    indentation,
-                    &imports,
-                    namespace,
-                    &declaration.name,
-                    &declaration.fields,
-                    In,
-                )?;
+    // Write the client stub, which sends requests through a user-provided transport.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub struct ")?;
+    write_identifier(buffer, &client_name, Pascal, None)?;
+    write!(buffer, "<T: ")?;
+    write_supers(buffer, indentation)?;
+    writeln!(buffer, "Transport> {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "pub transport: T,")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
 
-                writeln!(buffer)?;
+    writeln!(buffer)?;
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_supers(buffer, indentation)?;
-                write!(buffer, "Serialize for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, " {{")?;
-                write_size_function(buffer, indentation + 1)?;
-                writeln!(buffer)?;
-                write_serialize_function(buffer, indentation + 1)?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "impl<T: ")?;
+    write_supers(buffer, indentation)?;
+    write!(buffer, "Transport> ")?;
+    write_identifier(buffer, &client_name, Pascal, None)?;
+    writeln!(buffer, "<T> {{")?;
+    for (i, method) in service.methods.iter().enumerate() {
+        if i != 0 {
+            writeln!(buffer)?;
+        }
 
-                writeln!(buffer)?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "pub fn ")?;
+        write_identifier(buffer, &method.name, Snake, None)?;
+        write!(buffer, "(&mut self, request: &")?;
+        write_type(buffer, imports, namespace, &method.request.variant, Out)?;
+        write!(buffer, ") -> {}::Result<", flavor.io_module(indentation))?;
+        write_type(buffer, imports, namespace, &method.response.variant, In)?;
+        writeln!(buffer, "> {{")?;
+        write_indentation(buffer, indentation + 2)?;
+        write!(buffer, "let request = ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "Serialize::serialize_to_vec(request)?;")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(
+            buffer,
+            "let response = self.transport.call({}, request)?;",
+            method.index,
+        )?;
+        write_indentation(buffer, indentation + 2)?;
+        write!(buffer, "<")?;
+        write_type(buffer, imports, namespace, &method.response.variant, In)?;
+        write!(buffer, " as ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "Deserialize>::deserialize_from_slice(&response)")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_supers(buffer, indentation)?;
-                write!(buffer, "Deserialize for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_deserialize_function(buffer, indentation + 1)?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+// Write a struct, including a trailing line break.
+#[allow(clippy::too_many_arguments)]
+fn write_struct<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+    fields: &[schema::Field],
+    open: bool,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    direction: Direction,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "#[derive({})]", TRAITS_TO_DERIVE.join(", "))?;
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub struct ")?;
+    write_identifier(buffer, name, Pascal, Some(direction))?;
+    writeln!(buffer, " {{")?;
 
-                writeln!(buffer)?;
+    match direction {
+        Direction::Atlas => {
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "pub _size: usize,")?;
+        }
+        Direction::In | Direction::Out => {}
+    }
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl From<")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                write!(buffer, "> for ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                write!(buffer, "fn from(message: ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, ") -> Self {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "match message {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 3)?;
-                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                    write!(buffer, "::")?;
-                    write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                write!(buffer, "(fallback) => ")?;
-                            } else {
-                                write!(buffer, "(payload, fallback) => ")?;
-                            }
-                        }
-                        schema::Rule::Required => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                write!(buffer, " => ")?;
-                            } else {
-                                write!(buffer, "(payload) => ")?;
-                            }
-                        }
-                    }
-                    write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                    write!(buffer, "::")?;
-                    write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Required => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, ",")?;
-                            } else {
-                                write!(buffer, "(payload")?;
-                                write_into_invocation(buffer, &field.r#type.variant)?;
-                                writeln!(buffer, "),")?;
-                            }
-                        }
-                        schema::Rule::Optional => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, "(Box::new((*fallback).into())),")?;
-                            } else {
-                                write!(buffer, "(payload")?;
-                                write_into_invocation(buffer, &field.r#type.variant)?;
-                                writeln!(buffer, ", Box::new((*fallback).into())),")?;
-                            }
-                        }
-                    }
+    for field in fields {
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "pub ")?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        write!(buffer, ": ")?;
+        match field.rule {
+            schema::Rule::Asymmetric => match direction {
+                Direction::Atlas | Direction::Out => {}
+                Direction::In => {
+                    write!(buffer, "Option<")?;
                 }
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
-
-                writeln!(buffer)?;
-
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                write!(buffer, "pub fn atlas(&self) -> ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                // [tag:empty_enum_ref_match] We match on `self*` instead of `self` due to
-                // https://github.com/rust-lang/rust/issues/78123.
-                writeln!(buffer, "match *self {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 3)?;
-                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                    write!(buffer, "::")?;
-                    write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, "(ref fallback) => {{")?;
-                                write_indentation(buffer, indentation + 4)?;
-                                writeln!(buffer, "let payload = &();")?;
-                            } else {
-                                writeln!(buffer, "(ref payload, ref fallback) => {{")?;
-                            }
-                        }
-                        schema::Rule::Required => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, " => {{")?;
-                                write_indentation(buffer, indentation + 4)?;
-                                writeln!(buffer, "let payload = &();")?;
-                            } else {
-                                writeln!(buffer, "(ref payload) => {{")?;
-                            }
-                        }
-                    }
-                    write_indentation(buffer, indentation + 4)?;
-                    write!(buffer, "let payload_atlas = ")?;
-                    write_atlas_calculation(buffer, indentation, &field.r#type.variant, true)?;
-                    writeln!(buffer, ";")?;
-                    write_indentation(buffer, indentation + 4)?;
-                    write!(
-                        buffer,
-                        "let payload_size = {{ let payload_atlas = &payload_atlas; ",
-                    )?;
-                    write_atlas_lookup(buffer, &field.r#type.variant)?;
-                    writeln!(buffer, " }};")?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            write_indentation(buffer, indentation + 4)?;
-                            writeln!(buffer, "let fallback_atlas = fallback.atlas();")?;
-                            write_indentation(buffer, indentation + 4)?;
-                            write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                            write!(buffer, "::")?;
-                            write_identifier(buffer, &field.name, Pascal, None)?;
-                            write!(buffer, "(")?;
-                            write_supers(buffer, indentation)?;
-                            writeln!(
-                                buffer,
-                                "field_header_size({}_u64, payload_size, {}) + \
-                                    payload_size + fallback_atlas.size(), \
-                                    payload_atlas, Box::new(fallback_atlas))",
-                                field.index,
-                                integer_encoded(&field.r#type),
-                            )?;
-                        }
-                        schema::Rule::Required => {
-                            write_indentation(buffer, indentation + 4)?;
-                            write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                            write!(buffer, "::")?;
-                            write_identifier(buffer, &field.name, Pascal, None)?;
-                            write!(buffer, "(")?;
-                            write_supers(buffer, indentation)?;
-                            writeln!(
-                                buffer,
-                                "field_header_size({}_u64, payload_size, {}) + payload_size, \
-                                    payload_atlas)",
-                                field.index,
-                                integer_encoded(&field.r#type),
-                            )?;
-                        }
-                    }
-                    write_indentation(buffer, indentation + 3)?;
-                    writeln!(buffer, "}}")?;
+            },
+            schema::Rule::Optional => {
+                write!(buffer, "Option<")?;
+            }
+            schema::Rule::Required => {}
+        }
+        write_field_type(buffer, imports, namespace, field, type_overrides, direction)?;
+        match field.rule {
+            schema::Rule::Asymmetric => match direction {
+                Direction::Atlas | Direction::Out => {}
+                Direction::In => {
+                    write!(buffer, ">")?;
                 }
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
+            },
+            schema::Rule::Optional => {
+                write!(buffer, ">")?;
+            }
+            schema::Rule::Required => {}
+        }
+        writeln!(buffer, ",")?;
+    }
+
+    if open {
+        match direction {
+            Direction::Atlas => {}
+            Direction::In | Direction::Out => {
                 write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                writeln!(buffer)?;
+                write!(buffer, "pub _unknown: Vec<")?;
+                write_supers(buffer, indentation)?;
+                writeln!(buffer, "UnknownField>,")?;
+            }
+        }
+    }
+
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+// Write a typestate builder for the output type of a struct, including a trailing line break. The
+// builder has a type parameter for each required or asymmetric field which is `()` until the field
+// is set and a one-element tuple containing the value afterward, so `build` is only available once
+// all such fields are set. Optional fields have `with_*` setters instead.
+#[allow(clippy::too_many_lines)]
+fn write_struct_builder<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+) -> Result<(), fmt::Error> {
+    let builder_name = declaration.name.join(&"Builder".into());
+
+    // Find the fields which must be set before the message can be built, along with the index of
+    // the type parameter which tracks each of them.
+    let mut parameters = BTreeMap::new();
+    for field in &declaration.fields {
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Required => {
+                let parameter = parameters.len();
+                parameters.insert(field.index, parameter);
+            }
+            schema::Rule::Optional => {}
+        }
+    }
+
+    // Write the builder type with the given type arguments, one per entry in `parameters`.
+    let write_builder_type = |buffer: &mut T,
+                              argument: &dyn Fn(&mut T, usize) -> Result<(), fmt::Error>|
+     -> Result<(), fmt::Error> {
+        write_identifier(buffer, &builder_name, Pascal, None)?;
+        if !parameters.is_empty() {
+            write!(buffer, "<")?;
+            for parameter in 0..parameters.len() {
+                if parameter != 0 {
+                    write!(buffer, ", ")?;
+                }
+                argument(buffer, parameter)?;
+            }
+            write!(buffer, ">")?;
+        }
+        Ok(())
+    };
+
+    // Write the fields of a builder, taking each one from the given source unless it's the one
+    // being set.
+    let write_builder_fields =
+        |buffer: &mut T,
+         indentation: usize,
+         source: &dyn Fn(&mut T, &schema::Field) -> Result<(), fmt::Error>|
+         -> Result<(), fmt::Error> {
+            write_identifier(buffer, &builder_name, Pascal, None)?;
+            writeln!(buffer, " {{")?;
+            for field in &declaration.fields {
                 write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "pub fn serialize_with_atlas<T: ::std::io::Write>(")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "&self,")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "writer: &mut T,")?;
-                write_indentation(buffer, indentation + 2)?;
-                write!(buffer, "atlas: &")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                write_identifier(buffer, &field.name, Snake, None)?;
+                write!(buffer, ": ")?;
+                source(buffer, field)?;
                 writeln!(buffer, ",")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, ") -> ::std::io::Result<()> {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "match (self, atlas) {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 3)?;
+            }
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "}}")
+        };
+
+    // Write the builder struct.
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "#[derive({})]", TRAITS_TO_DERIVE.join(", "))?;
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "pub struct ")?;
+    write_builder_type(buffer, &|buffer, parameter| write!(buffer, "T{parameter}"))?;
+    writeln!(buffer, " {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 1)?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        write!(buffer, ": ")?;
+        if let Some(parameter) = parameters.get(&field.index) {
+            write!(buffer, "T{parameter}")?;
+        } else {
+            write!(buffer, "Option<")?;
+            write_field_type(buffer, imports, namespace, field, type_overrides, Out)?;
+            write!(buffer, ">")?;
+        }
+        writeln!(buffer, ",")?;
+    }
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    // Write the function which creates an empty builder.
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "impl ")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    write!(buffer, "pub fn builder() -> ")?;
+    write_builder_type(buffer, &|buffer, _| write!(buffer, "()"))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    write_builder_fields(buffer, indentation + 2, &|buffer, field| {
+        if parameters.contains_key(&field.index) {
+            write!(buffer, "()")
+        } else {
+            write!(buffer, "None")
+        }
+    })?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    // Write the setters for the fields which must be set. Each one is only available while its
+    // field is unset.
+    for field in &declaration.fields {
+        if let Some(&parameter) = parameters.get(&field.index) {
+            let write_argument = |buffer: &mut T, other: usize, set: bool| {
+                if other != parameter {
+                    write!(buffer, "T{other}")
+                } else if set {
                     write!(buffer, "(")?;
-                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
-                    write!(buffer, "::")?;
-                    write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                write!(buffer, "(fallback), ")?;
-                            } else {
-                                write!(buffer, "(payload, fallback), ")?;
-                            }
-                        }
-                        schema::Rule::Required => {
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                write!(buffer, ", ")?;
-                            } else {
-                                write!(buffer, "(payload), ")?;
-                            }
-                        }
-                    }
-                    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                    write!(buffer, "::")?;
-                    write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            writeln!(buffer, "(_, payload_atlas, fallback_atlas)) => {{")?;
-                        }
-                        schema::Rule::Required => {
-                            writeln!(buffer, "(_, payload_atlas)) => {{")?;
-                        }
-                    }
-                    write_indentation(buffer, indentation + 4)?;
-                    write_supers(buffer, indentation)?;
-                    write!(
-                        buffer,
-                        "serialize_field_header(writer, {}_u64, ",
-                        field.index,
-                    )?;
-                    write_atlas_lookup(buffer, &field.r#type.variant)?;
-                    writeln!(buffer, ", {})?;", integer_encoded(&field.r#type))?;
-                    write_serialization_invocation(
-                        buffer,
-                        indentation + 4,
-                        indentation,
-                        &field.r#type.variant,
-                        true,
-                    )?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            write_indentation(buffer, indentation + 4)?;
-                            writeln!(
-                                buffer,
-                                "fallback.serialize_with_atlas(writer, fallback_atlas)",
-                            )?;
-                        }
-                        schema::Rule::Required => {
-                            write_indentation(buffer, indentation + 4)?;
-                            writeln!(buffer, "Ok(())")?;
-                        }
+                    write_field_type(buffer, imports, namespace, field, type_overrides, Out)?;
+                    write!(buffer, ",)")
+                } else {
+                    write!(buffer, "()")
+                }
+            };
+
+            writeln!(buffer)?;
+            write_indentation(buffer, indentation)?;
+            write!(buffer, "impl")?;
+            if parameters.len() > 1 {
+                write!(buffer, "<")?;
+                let mut first = true;
+                for other in (0..parameters.len()).filter(|other| *other != parameter) {
+                    if first {
+                        first = false;
+                    } else {
+                        write!(buffer, ", ")?;
                     }
-                    write_indentation(buffer, indentation + 3)?;
-                    writeln!(buffer, "}}")?;
+                    write!(buffer, "T{other}")?;
                 }
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "(_, _) => panic!(),")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+                write!(buffer, ">")?;
+            }
+            write!(buffer, " ")?;
+            write_builder_type(buffer, &|buffer, other| {
+                write_argument(buffer, other, false)
+            })?;
+            writeln!(buffer, " {{")?;
+            write_indentation(buffer, indentation + 1)?;
+            write!(buffer, "pub fn ")?;
+            write_identifier(buffer, &field.name, Snake, None)?;
+            write!(buffer, "(self, payload: ")?;
+            write_field_type(buffer, imports, namespace, field, type_overrides, Out)?;
+            write!(buffer, ") -> ")?;
+            write_builder_type(buffer, &|buffer, other| write_argument(buffer, other, true))?;
+            writeln!(buffer, " {{")?;
+            write_indentation(buffer, indentation + 2)?;
+            write_builder_fields(buffer, indentation + 2, &|buffer, other| {
+                if other.index == field.index {
+                    write!(buffer, "(payload,)")
+                } else {
+                    write!(buffer, "self.")?;
+                    write_identifier(buffer, &other.name, Snake, None)
+                }
+            })?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "}}")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "}}")?;
+        }
+    }
 
-                writeln!(buffer)?;
+    // Write the setters for the optional fields, which are available in every state.
+    if parameters.len() < declaration.fields.len() {
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation)?;
+        write!(buffer, "impl")?;
+        if !parameters.is_empty() {
+            write!(buffer, "<")?;
+            for parameter in 0..parameters.len() {
+                if parameter != 0 {
+                    write!(buffer, ", ")?;
+                }
+                write!(buffer, "T{parameter}")?;
+            }
+            write!(buffer, ">")?;
+        }
+        write!(buffer, " ")?;
+        write_builder_type(buffer, &|buffer, parameter| write!(buffer, "T{parameter}"))?;
+        writeln!(buffer, " {{")?;
+        let mut first = true;
+        for field in &declaration.fields {
+            if parameters.contains_key(&field.index) {
+                continue;
+            }
 
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(
-                    buffer,
-                    "pub fn deserialize_from_reader_ref<T: ::std::io::BufRead>(",
-                )?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "reader: &mut T,")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, ") -> ::std::io::Result<Self> {{")?;
-                write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "loop {{")?;
-                write_indentation(buffer, indentation + 3)?;
-                write!(buffer, "let (index, payload_size) = ")?;
-                write_supers(buffer, indentation)?;
-                writeln!(buffer, "deserialize_field_header(&mut *reader)?;")?;
-                writeln!(buffer)?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(
-                    buffer,
-                    "let mut sub_reader = ::std::io::Read::take(&mut *reader, \
-                        payload_size as u64);",
-                )?;
+            if first {
+                first = false;
+            } else {
                 writeln!(buffer)?;
+            }
+
+            write_indentation(buffer, indentation + 1)?;
+            write!(buffer, "pub fn ")?;
+            write_identifier(
+                buffer,
+                &Identifier::from("with").join(&field.name),
+                Snake,
+                None,
+            )?;
+            write!(buffer, "(mut self, payload: ")?;
+            write_field_type(buffer, imports, namespace, field, type_overrides, Out)?;
+            writeln!(buffer, ") -> Self {{")?;
+            write_indentation(buffer, indentation + 2)?;
+            write!(buffer, "self.")?;
+            write_identifier(buffer, &field.name, Snake, None)?;
+            writeln!(buffer, " = Some(payload);")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "self")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "}}")?;
+        }
+        write_indentation(buffer, indentation)?;
+        writeln!(buffer, "}}")?;
+    }
+
+    // Write the function which builds the message once all the required and asymmetric fields are
+    // set.
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "impl ")?;
+    write_builder_type(buffer, &|buffer, parameter| {
+        // The `unwrap` is safe because every parameter corresponds to a field.
+        let field = declaration
+            .fields
+            .iter()
+            .find(|field| parameters.get(&field.index) == Some(&parameter))
+            .unwrap();
+        write!(buffer, "(")?;
+        write_field_type(buffer, imports, namespace, field, type_overrides, Out)?;
+        write!(buffer, ",)")
+    })?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    write!(buffer, "pub fn build(self) -> ")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    for field in &declaration.fields {
+        write_indentation(buffer, indentation + 3)?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        write!(buffer, ": self.")?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        if parameters.contains_key(&field.index) {
+            write!(buffer, ".0")?;
+        }
+        writeln!(buffer, ",")?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "_unknown: Vec::new(),")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+// Write the implementation of `arbitrary::Arbitrary` for the output type of a struct or choice,
+// including a trailing line break. Unknown fields are never generated, since they might collide
+// with known ones.
+#[allow(clippy::too_many_lines)]
+fn write_arbitrary_impls<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "impl<'a> ::arbitrary::Arbitrary<'a> for ")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {{",
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    write!(buffer, "Self::arbitrary_with_depth(u, ")?;
+    write_supers(buffer, indentation)?;
+    writeln!(buffer, "ARBITRARY_DEPTH)")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    writeln!(buffer)?;
+
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "impl ")?;
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "pub fn arbitrary_with_depth(")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "u: &mut ::arbitrary::Unstructured<'_>,")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "depth: usize,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, ") -> ::arbitrary::Result<Self> {{")?;
+
+    match declaration.variant {
+        schema::DeclarationVariant::Struct => {
+            write_indentation(buffer, indentation + 2)?;
+            write!(buffer, "Ok(")?;
+            write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+            writeln!(buffer, " {{")?;
+            for field in &declaration.fields {
                 write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "match index {{")?;
-                for field in &declaration.fields {
-                    write_indentation(buffer, indentation + 4)?;
-                    writeln!(buffer, "{} => {{", field.index)?;
-                    write_deserialization_invocation(
-                        buffer,
-                        indentation + 5,
-                        indentation,
-                        &imports,
-                        namespace,
-                        &field.r#type.variant,
-                        true,
-                    )?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Required => {
-                            write_indentation(buffer, indentation + 5)?;
-                            write_supers(buffer, indentation)?;
-                            writeln!(buffer, "finish(&mut *reader)?;")?;
-                            write_indentation(buffer, indentation + 5)?;
-                            write!(buffer, "return Ok(")?;
-                            write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                            write!(buffer, "::")?;
-                            write_identifier(buffer, &field.name, Pascal, None)?;
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, ");")?;
-                            } else {
-                                writeln!(buffer, "(payload));")?;
-                            }
-                        }
-                        schema::Rule::Optional => {
-                            write_indentation(buffer, indentation + 5)?;
-                            write!(buffer, "let fallback = Box::new(")?;
-                            write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                            writeln!(buffer, "::deserialize_from_reader_ref(&mut *reader)?);")?;
-                            write_indentation(buffer, indentation + 5)?;
-                            write!(buffer, "return Ok(")?;
-                            write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
-                            write!(buffer, "::")?;
-                            write_identifier(buffer, &field.name, Pascal, None)?;
-                            if matches!(field.r#type.variant, schema::TypeVariant::Unit) {
-                                writeln!(buffer, "(fallback));")?;
-                            } else {
-                                writeln!(buffer, "(payload, fallback));")?;
-                            }
-                        }
+                write_identifier(buffer, &field.name, Snake, None)?;
+                write!(buffer, ": ")?;
+                match field.rule {
+                    schema::Rule::Asymmetric | schema::Rule::Required => {
+                        write_arbitrary_field_value(
+                            buffer,
+                            indentation,
+                            imports,
+                            namespace,
+                            field,
+                            type_overrides,
+                        )?;
+                    }
+                    schema::Rule::Optional => {
+                        write!(buffer, "if u.arbitrary()? {{ Some(")?;
+                        write_arbitrary_field_value(
+                            buffer,
+                            indentation,
+                            imports,
+                            namespace,
+                            field,
+                            type_overrides,
+                        )?;
+                        write!(buffer, ") }} else {{ None }}")?;
                     }
-                    write_indentation(buffer, indentation + 4)?;
-                    writeln!(buffer, "}}")?;
                 }
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "_ => {{")?;
-                write_indentation(buffer, indentation + 5)?;
-                write_supers(buffer, indentation)?;
-                writeln!(buffer, "skip(&mut sub_reader, payload_size)?;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, ",")?;
+            }
+            if declaration.open {
                 write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, "_unknown: Vec::new(),")?;
+            }
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "}})")?;
+        }
+        schema::DeclarationVariant::Choice => {
+            // The fields which need a fallback come last, so they can be excluded once the maximum
+            // depth is reached.
+            let (required, fallible): (Vec<_>, Vec<_>) = declaration
+                .fields
+                .iter()
+                .partition(|field| matches!(field.rule, schema::Rule::Required));
+
+            if declaration.fields.is_empty() {
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
-
+                writeln!(buffer, "Err(::arbitrary::Error::EmptyChoose)")?;
+            } else {
+                write_indentation(buffer, indentation + 2)?;
+                if fallible.is_empty() {
+                    writeln!(buffer, "let choices = {};", required.len())?;
+                } else {
+                    writeln!(
+                        buffer,
+                        "let choices = if depth == 0 {{ {} }} else {{ {} }};",
+                        required.len(),
+                        declaration.fields.len(),
+                    )?;
+                }
                 writeln!(buffer)?;
-
-                write_indentation(buffer, indentation)?;
-                write!(buffer, "impl ")?;
-                write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
-                writeln!(buffer, " {{")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "pub fn size(&self) -> usize {{")?;
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "match *self {{")?; // [ref:empty_enum_ref_match]
-                for field in &declaration.fields {
+                writeln!(buffer, "Ok(match u.choose_index(choices)? {{")?;
+                for (i, field) in required.iter().chain(fallible.iter()).enumerate() {
+                    let payload = !matches!(field.r#type.variant, schema::TypeVariant::Unit);
+                    let fallback = !matches!(field.rule, schema::Rule::Required);
+
                     write_indentation(buffer, indentation + 3)?;
-                    write_identifier(buffer, &declaration.name, Pascal, Some(Atlas))?;
+                    write!(buffer, "{i} => ")?;
+                    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
                     write!(buffer, "::")?;
                     write_identifier(buffer, &field.name, Pascal, None)?;
-                    match field.rule {
-                        schema::Rule::Asymmetric | schema::Rule::Optional => {
-                            writeln!(buffer, "(ref size, _, _) => *size,")?;
-                        }
-                        schema::Rule::Required => {
-                            writeln!(buffer, "(ref size, _) => *size,")?;
+                    if payload || fallback {
+                        write!(buffer, "(")?;
+                    }
+                    if payload {
+                        write_arbitrary_field_value(
+                            buffer,
+                            indentation,
+                            imports,
+                            namespace,
+                            field,
+                            type_overrides,
+                        )?;
+                        if fallback {
+                            write!(buffer, ", ")?;
                         }
                     }
+                    if fallback {
+                        write!(
+                            buffer,
+                            "Box::new(Self::arbitrary_with_depth(u, depth - 1)?)"
+                        )?;
+                    }
+                    if payload || fallback {
+                        write!(buffer, ")")?;
+                    }
+                    writeln!(buffer, ",")?;
                 }
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(buffer, "_ => unreachable!(),")?;
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, "}})")?;
             }
         }
-
-        if iter.peek().is_some() {
-            writeln!(buffer)?;
-        }
     }
 
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
     Ok(())
 }
 
-// Write a struct, including a trailing line break.
-fn write_struct<T: Write>(
+// Write an expression which generates an arbitrary value for a field, with errors propagated.
+fn write_arbitrary_field_value<T: Write>(
     buffer: &mut T,
     indentation: usize,
     imports: &BTreeMap<Identifier, schema::Namespace>,
     namespace: &schema::Namespace,
-    name: &Identifier,
-    fields: &[schema::Field],
-    direction: Direction,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
 ) -> Result<(), fmt::Error> {
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "#[derive({})]", TRAITS_TO_DERIVE.join(", "))?;
-    write_indentation(buffer, indentation)?;
-    write!(buffer, "pub struct ")?;
-    write_identifier(buffer, name, Pascal, Some(direction))?;
-    writeln!(buffer, " {{")?;
+    let mut value = String::new();
+    write_arbitrary_expression(
+        &mut value,
+        indentation,
+        imports,
+        namespace,
+        &field.r#type.variant,
+    )?;
+    value.push('?');
+    write_type_override_construction(buffer, indentation, field, type_overrides, &value)
+}
 
-    match direction {
-        Direction::Atlas => {
-            write_indentation(buffer, indentation + 1)?;
-            writeln!(buffer, "pub _size: usize,")?;
+// Write an expression which generates an arbitrary value of the given type. The expression
+// evaluates to an `arbitrary::Result` and refers to variables named `u` and `depth`.
+fn write_arbitrary_expression<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+) -> Result<(), fmt::Error> {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            write_supers(buffer, indentation)?;
+            write!(buffer, "arbitrary_array(u, depth, |u, ")?;
+            match inner_type.variant {
+                schema::TypeVariant::Array(_) | schema::TypeVariant::Custom(_, _) => {
+                    write!(buffer, "depth")?;
+                }
+                schema::TypeVariant::Bool
+                | schema::TypeVariant::Bytes
+                | schema::TypeVariant::F64
+                | schema::TypeVariant::S64
+                | schema::TypeVariant::String
+                | schema::TypeVariant::U64
+                | schema::TypeVariant::Unit => {
+                    write!(buffer, "_")?;
+                }
+            }
+            write!(buffer, "| ")?;
+            write_arbitrary_expression(
+                buffer,
+                indentation,
+                imports,
+                namespace,
+                &inner_type.variant,
+            )?;
+            write!(buffer, ")")
         }
-        Direction::In | Direction::Out => {}
+        schema::TypeVariant::Custom(_, _) => {
+            write_type(buffer, imports, namespace, type_variant, Out)?;
+            write!(buffer, "::arbitrary_with_depth(u, depth)")
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => write!(buffer, "u.arbitrary()"),
     }
+}
 
-    for field in fields {
+// Write a test module with a test for each declaration in a schema, including a trailing line
+// break. Each test checks that arbitrary messages survive a round trip.
+fn write_arbitrary_round_trip_tests<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    schema: &schema::Schema,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "#[cfg(test)]")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "mod arbitrary_round_trips {{")?;
+
+    let mut iter = schema.declarations.iter().peekable();
+    while let Some(declaration) = iter.next() {
         write_indentation(buffer, indentation + 1)?;
-        write!(buffer, "pub ")?;
-        write_identifier(buffer, &field.name, Snake, None)?;
-        write!(buffer, ": ")?;
-        match field.rule {
-            schema::Rule::Asymmetric => match direction {
-                Direction::Atlas | Direction::Out => {}
-                Direction::In => {
-                    write!(buffer, "Option<")?;
-                }
-            },
-            schema::Rule::Optional => {
-                write!(buffer, "Option<")?;
-            }
-            schema::Rule::Required => {}
-        }
-        write_type(buffer, imports, namespace, &field.r#type.variant, direction)?;
-        match field.rule {
-            schema::Rule::Asymmetric => match direction {
-                Direction::Atlas | Direction::Out => {}
-                Direction::In => {
-                    write!(buffer, ">")?;
-                }
-            },
-            schema::Rule::Optional => {
-                write!(buffer, ">")?;
-            }
-            schema::Rule::Required => {}
+        writeln!(buffer, "#[test]")?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "fn ")?;
+        write_identifier(buffer, &declaration.name, Snake, None)?;
+        writeln!(buffer, "() {{")?;
+        write_indentation(buffer, indentation + 2)?;
+        write_supers(buffer, indentation + 1)?;
+        write!(buffer, "assert_arbitrary_round_trips::<super::")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+        write!(buffer, ", super::")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+        writeln!(buffer, ">();")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "}}")?;
+
+        if iter.peek().is_some() {
+            writeln!(buffer)?;
         }
-        writeln!(buffer, ",")?;
     }
 
     write_indentation(buffer, indentation)?;
@@ -1478,6 +4857,7 @@ fn write_struct<T: Write>(
 }
 
 // Write a choice, including a trailing line break.
+#[allow(clippy::too_many_arguments)]
 fn write_choice<T: Write>(
     buffer: &mut T,
     indentation: usize,
@@ -1485,6 +4865,8 @@ fn write_choice<T: Write>(
     namespace: &schema::Namespace,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    type_overrides: &BTreeMap<Identifier, &str>,
     direction: Direction,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1532,7 +4914,7 @@ fn write_choice<T: Write>(
         }
 
         if payload {
-            write_type(buffer, imports, namespace, &field.r#type.variant, direction)?;
+            write_field_type(buffer, imports, namespace, field, type_overrides, direction)?;
 
             if fallback {
                 write!(buffer, ", ")?;
@@ -1549,54 +4931,269 @@ fn write_choice<T: Write>(
             write!(buffer, ")")?;
         }
 
-        writeln!(buffer, ",")?;
+        writeln!(buffer, ",")?;
+    }
+
+    // Open choices have an extra case for preserving unrecognized fields.
+    if open {
+        write_indentation(buffer, indentation + 1)?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        match direction {
+            Direction::Atlas => {
+                writeln!(buffer, "(usize),")?;
+            }
+            Direction::In | Direction::Out => {
+                writeln!(buffer, " {{ index: u64, payload: Vec<u8> }},")?;
+            }
+        }
+    }
+
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    Ok(())
+}
+
+fn write_size_function<T: Write>(buffer: &mut T, indentation: usize) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "fn size(&self) -> usize {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "self.atlas().size()")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the function to serialize a message.
+fn write_serialize_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "fn serialize<T: {}>(&self, mut writer: T) -> {}::Result<()> {{",
+        flavor.writer_bound(supers),
+        flavor.io_module(supers),
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "let atlas = self.atlas();")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "self.serialize_with_atlas(&mut writer, &atlas)")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the functions to serialize a message into a byte slice or a new vector. The atlas is
+// computed once, and the size of the destination is only checked once up front.
+fn write_serialize_into_functions<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "fn serialize_into(&self, buffer: &mut [u8]) -> {}::Result<usize> {{",
+        flavor.io_module(supers),
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "let atlas = self.atlas();")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "let size = atlas.size();")?;
+    write_indentation(buffer, indentation + 1)?;
+    write!(buffer, "let mut writer = ")?;
+    write_supers(buffer, supers)?;
+    writeln!(buffer, "sized_buffer(buffer, size)?;")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "self.serialize_with_atlas(&mut writer, &atlas)?;")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "Ok(size)")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "fn serialize_to_vec(&self) -> {}::Result<Vec<u8>> {{",
+        flavor.io_module(supers),
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "let atlas = self.atlas();")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "let mut buffer = vec![0; atlas.size()];")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "self.serialize_with_atlas(&mut buffer.as_mut_slice(), &atlas)?;",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "Ok(buffer)")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the function to deserialize a message.
+fn write_deserialize_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "fn deserialize<T: {}>(mut reader: T) -> {}::Result<Self> {{",
+        flavor.reader_bound(supers),
+        flavor.io_module(supers),
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "Self::deserialize_from_reader_ref(&mut reader)")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the function to deserialize a message directly from a byte slice.
+fn write_deserialize_from_slice_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "fn deserialize_from_slice(mut bytes: &[u8]) -> {}::Result<Self> {{",
+        Slice.io_module(supers),
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "Self::deserialize_from_reader_ref_slice(&mut bytes)"
+    )?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the implementations of the `SchemaType` trait for the output and input types of a message,
+// including a trailing line break.
+fn write_schema_type_impls<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    name: &Identifier,
+    embedded_declaration: &container::EmbeddedDeclaration,
+) -> Result<(), fmt::Error> {
+    for (i, direction) in [Out, In].into_iter().enumerate() {
+        if i != 0 {
+            writeln!(buffer)?;
+        }
+
+        write_indentation(buffer, indentation)?;
+        write!(buffer, "impl ")?;
+        write_supers(buffer, indentation)?;
+        write!(buffer, "SchemaType for ")?;
+        write_identifier(buffer, name, Pascal, Some(direction))?;
+        writeln!(buffer, " {{")?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "const FINGERPRINT: u64 = ")?;
+        write_fingerprint(buffer, embedded_declaration.fingerprint)?;
+        writeln!(buffer, ";")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(
+            buffer,
+            "const NORMAL_FORM: &'static str = {:?};",
+            embedded_declaration.normal_form,
+        )?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(
+            buffer,
+            "const NAME: &'static str = \"{}\";",
+            embedded_declaration.name.pascal_case(),
+        )?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "const SCHEMA: &'static str = ")?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "SCHEMA;")?;
+        write_indentation(buffer, indentation)?;
+        writeln!(buffer, "}}")?;
     }
 
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "}}")?;
-
     Ok(())
 }
 
-fn write_size_function<T: Write>(buffer: &mut T, indentation: usize) -> Result<(), fmt::Error> {
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "fn size(&self) -> usize {{")?;
-    write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "self.atlas().size()")?;
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "}}")
+// Write a fingerprint as a hexadecimal literal with its digits grouped for readability.
+fn write_fingerprint<T: Write>(buffer: &mut T, fingerprint: u64) -> Result<(), fmt::Error> {
+    write!(
+        buffer,
+        "0x{:04x}_{:04x}_{:04x}_{:04x}",
+        fingerprint >> 48_u32,
+        (fingerprint >> 32_u32) & 0xffff,
+        (fingerprint >> 16_u32) & 0xffff,
+        fingerprint & 0xffff,
+    )
 }
 
-// Write the function to serialize a message.
-fn write_serialize_function<T: Write>(
+// Write the implementations of the asynchronous serialization and deserialization traits for a
+// message, including a trailing line break.
+fn write_async_trait_impls<T: Write>(
     buffer: &mut T,
     indentation: usize,
+    name: &Identifier,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
+    write!(buffer, "impl ")?;
+    write_supers(buffer, indentation)?;
+    write!(buffer, "SerializeAsync for ")?;
+    write_identifier(buffer, name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
     writeln!(
         buffer,
-        "fn serialize<T: ::std::io::Write>(&self, mut writer: T) -> ::std::io::Result<()> {{",
+        "async fn serialize_async<T: ::tokio::io::AsyncWrite + Send + Unpin>(",
     )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "&self,")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "mut writer: T,")?;
     write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, ") -> ::std::io::Result<()> {{")?;
+    write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "let atlas = self.atlas();")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "self.serialize_with_atlas_async(&mut writer, &atlas).await",
+    )?;
     write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "self.serialize_with_atlas(&mut writer, &atlas)")?;
+    writeln!(buffer, "}}")?;
     write_indentation(buffer, indentation)?;
-    writeln!(buffer, "}}")
-}
+    writeln!(buffer, "}}")?;
+
+    writeln!(buffer)?;
 
-// Write the function to deserialize a message.
-fn write_deserialize_function<T: Write>(
-    buffer: &mut T,
-    indentation: usize,
-) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
+    write!(buffer, "impl ")?;
+    write_supers(buffer, indentation)?;
+    write!(buffer, "DeserializeAsync for ")?;
+    write_identifier(buffer, name, Pascal, Some(In))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
     writeln!(
         buffer,
-        "fn deserialize<T: ::std::io::BufRead>(mut reader: T) -> ::std::io::Result<Self> {{",
+        "async fn deserialize_async<T: ::tokio::io::AsyncBufRead + Send + Unpin>(",
     )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "mut reader: T,")?;
     write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "Self::deserialize_from_reader_ref(&mut reader)")?;
+    writeln!(buffer, ") -> ::std::io::Result<Self> {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "Self::deserialize_from_reader_ref_async(&mut reader).await",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
     write_indentation(buffer, indentation)?;
     writeln!(buffer, "}}")
 }
@@ -1698,21 +5295,664 @@ fn write_type<T: Write>(
             Direction::Atlas => {
                 write!(buffer, "usize")?;
             }
-            Direction::In | Direction::Out => {
-                write!(buffer, "u64")?;
+            Direction::In | Direction::Out => {
+                write!(buffer, "u64")?;
+            }
+        },
+        schema::TypeVariant::Unit => match direction {
+            Direction::Atlas => {
+                write!(buffer, "usize")?;
+            }
+            Direction::In | Direction::Out => {
+                write!(buffer, "()")?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+// Find the Rust type, if any, which replaces the default representation of a field. An override
+// for the field itself takes precedence over one for all the fields of its type, and a later
+// override takes precedence over an earlier one. Only the types of fields with type `Bytes`,
+// `String`, or an array type can be overridden [tag:type_override_variant].
+fn type_override<'a>(
+    type_overrides: &'a [TypeOverride],
+    namespace: &schema::Namespace,
+    declaration_name: &Identifier,
+    field: &schema::Field,
+) -> Option<&'a str> {
+    if !matches!(
+        field.r#type.variant,
+        schema::TypeVariant::Array(_) | schema::TypeVariant::Bytes | schema::TypeVariant::String,
+    ) {
+        return None;
+    }
+
+    type_overrides
+        .iter()
+        .rev()
+        .find(|type_override| match &type_override.target {
+            TypeOverrideTarget::Field(field_namespace, field_declaration_name, field_name) => {
+                field_namespace == namespace
+                    && field_declaration_name == declaration_name
+                    && *field_name == field.name
+            }
+            TypeOverrideTarget::Array | TypeOverrideTarget::Bytes | TypeOverrideTarget::String => {
+                false
+            }
+        })
+        .or_else(|| {
+            type_overrides.iter().rev().find(|type_override| {
+                matches!(
+                    (&type_override.target, &field.r#type.variant),
+                    (TypeOverrideTarget::Array, schema::TypeVariant::Array(_))
+                        | (TypeOverrideTarget::Bytes, schema::TypeVariant::Bytes)
+                        | (TypeOverrideTarget::String, schema::TypeVariant::String),
+                )
+            })
+        })
+        .map(|type_override| type_override.rust_type.as_str())
+}
+
+// Find the Rust types which replace the default representations of the fields of a declaration.
+fn declaration_type_overrides<'a>(
+    options: &'a Options,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+) -> BTreeMap<Identifier, &'a str> {
+    declaration
+        .fields
+        .iter()
+        .filter_map(|field| {
+            type_override(&options.type_overrides, namespace, &declaration.name, field)
+                .map(|rust_type| (field.name.clone(), rust_type))
+        })
+        .collect()
+}
+
+// Return the trait which converts a type override to and from the default representation of the
+// given type, followed by the names of the functions which construct and borrow it.
+fn type_override_trait(
+    type_variant: &schema::TypeVariant,
+) -> (&'static str, &'static str, &'static str) {
+    match type_variant {
+        schema::TypeVariant::Array(_) => ("ArrayType", "from_vec", "as_slice"),
+        schema::TypeVariant::Bytes => ("BytesType", "from_vec", "as_slice"),
+        schema::TypeVariant::String => ("StringType", "from_string", "as_str"),
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Custom(_, _)
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {
+            // Impossible due to [ref:type_override_variant].
+            panic!()
+        }
+    }
+}
+
+// Write the type of a field, taking its type override (if any) into account.
+fn write_field_type<T: Write>(
+    buffer: &mut T,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    direction: Direction,
+) -> Result<(), fmt::Error> {
+    match (type_overrides.get(&field.name), direction) {
+        (Some(rust_type), In | Out) => {
+            if let schema::TypeVariant::Array(inner_type) = &field.r#type.variant {
+                let mut parts = rust_type.split("{}");
+
+                // The `unwrap` is safe because `split` always produces at least one part.
+                write!(buffer, "{}", parts.next().unwrap())?;
+
+                for part in parts {
+                    write_type(buffer, imports, namespace, &inner_type.variant, direction)?;
+                    write!(buffer, "{part}")?;
+                }
+
+                Ok(())
+            } else {
+                write!(buffer, "{rust_type}")
+            }
+        }
+        (None, _) | (_, Atlas) => {
+            write_type(buffer, imports, namespace, &field.r#type.variant, direction)
+        }
+    }
+}
+
+// Write an expression which borrows the value of a field as its default representation, given an
+// expression which borrows the value itself.
+fn write_type_override_borrow<T: Write>(
+    buffer: &mut T,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    expression: &str,
+) -> Result<(), fmt::Error> {
+    if type_overrides.contains_key(&field.name) {
+        let (trait_name, _, borrow) = type_override_trait(&field.r#type.variant);
+        write_supers(buffer, supers)?;
+        write!(buffer, "{trait_name}::{borrow}({expression})")
+    } else {
+        write!(buffer, "{expression}")
+    }
+}
+
+// Write a statement which borrows the value of a field as its default representation, if its type
+// is overridden.
+//
+// Context variables:
+// - `payload` (in, shadowed)
+fn write_type_override_rebinding<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+) -> Result<(), fmt::Error> {
+    if type_overrides.contains_key(&field.name) {
+        write_indentation(buffer, indentation)?;
+        write!(buffer, "let payload = ")?;
+        write_type_override_borrow(buffer, supers, field, type_overrides, "payload")?;
+        writeln!(buffer, ";")?;
+    }
+
+    Ok(())
+}
+
+// Write an expression which converts a value from the default representation of a field to its
+// type override, if it has one.
+fn write_type_override_construction<T: Write>(
+    buffer: &mut T,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    expression: &str,
+) -> Result<(), fmt::Error> {
+    if type_overrides.contains_key(&field.name) {
+        let (trait_name, construct, _) = type_override_trait(&field.r#type.variant);
+        write_supers(buffer, supers)?;
+        write!(buffer, "{trait_name}::{construct}({expression})")
+    } else {
+        write!(buffer, "{expression}")
+    }
+}
+
+// Write a statement which converts the value of a field from its default representation to its
+// type override, if it has one.
+//
+// Context variables:
+// - `payload` (in, shadowed)
+fn write_type_override_conversion<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+) -> Result<(), fmt::Error> {
+    if type_overrides.contains_key(&field.name) {
+        write_indentation(buffer, indentation)?;
+        write!(buffer, "let payload = ")?;
+        write_type_override_construction(buffer, supers, field, type_overrides, "payload")?;
+        writeln!(buffer, ";")?;
+    }
+
+    Ok(())
+}
+
+// Write an expression which converts the value of a field from the output type to the input type.
+fn write_field_into_expression<T: Write>(
+    buffer: &mut T,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    expression: &str,
+) -> Result<(), fmt::Error> {
+    // An overridden array has to be converted to a vector for the elements to be converted, unless
+    // the input and output types of the elements are the same.
+    if type_overrides.contains_key(&field.name) && contains_custom_type(&field.r#type.variant) {
+        write_supers(buffer, supers)?;
+        write!(buffer, "ArrayType::from_vec(")?;
+        write_supers(buffer, supers)?;
+        write!(buffer, "ArrayType::into_vec({expression})")?;
+        write_into_invocation(buffer, &field.r#type.variant)?;
+        write!(buffer, ")")
+    } else {
+        write!(buffer, "{expression}")?;
+        write_into_invocation(buffer, &field.r#type.variant)
+    }
+}
+
+// Determine which declarations can't always be converted from their input versions to their output
+// versions. That's the case for a struct with an asymmetric field, which is optional in the input
+// version, and for a choice with an asymmetric field, whose fallback the input version doesn't
+// retain. It's also the case for any declaration with a field which refers to such a declaration.
+fn fallible_conversions(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+) -> BTreeSet<(schema::Namespace, Identifier)> {
+    let mut fallible_conversions = BTreeSet::new();
+
+    // Keep marking declarations until there are no more to mark.
+    loop {
+        let mut changed = false;
+
+        for (namespace, (schema, _, _)) in schemas {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let imports = schema
+                .imports
+                .iter()
+                .map(|(name, import)| (name.clone(), import.namespace.clone().unwrap()))
+                .collect::<BTreeMap<_, _>>();
+
+            for declaration in &schema.declarations {
+                let qualified_name = (namespace.clone(), declaration.name.clone());
+
+                if !fallible_conversions.contains(&qualified_name)
+                    && declaration.fields.iter().any(|field| {
+                        matches!(field.rule, schema::Rule::Asymmetric)
+                            || conversion_fallible(
+                                &imports,
+                                namespace,
+                                &field.r#type.variant,
+                                &fallible_conversions,
+                            )
+                    })
+                {
+                    fallible_conversions.insert(qualified_name);
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            return fallible_conversions;
+        }
+    }
+}
+
+// Determine whether converting a value of the given type from its input version to its output
+// version can fail.
+fn conversion_fallible(
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    type_variant: &schema::TypeVariant,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> bool {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => conversion_fallible(
+            imports,
+            namespace,
+            &inner_type.variant,
+            fallible_conversions,
+        ),
+        schema::TypeVariant::Custom(import, name) => fallible_conversions.contains(&(
+            import
+                .as_ref()
+                .map_or_else(|| namespace.clone(), |import| imports[import].clone()),
+            name.clone(),
+        )),
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => false,
+    }
+}
+
+// Write the header of the `From` or `TryFrom` implementation which converts the input version of a
+// declaration to the output version, up to and including the opening brace of the function body.
+fn write_out_conversion_header<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    name: &Identifier,
+    flavor: Flavor,
+    fallible: bool,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    write!(
+        buffer,
+        "impl {}<",
+        if fallible { "TryFrom" } else { "From" }
+    )?;
+    write_identifier(buffer, name, Pascal, Some(In))?;
+    write!(buffer, "> for ")?;
+    write_identifier(buffer, name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    if fallible {
+        writeln!(
+            buffer,
+            "type Error = {}::Error;",
+            flavor.io_module(indentation),
+        )?;
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "fn try_from(message: ")?;
+        write_identifier(buffer, name, Pascal, Some(In))?;
+        writeln!(buffer, ") -> Result<Self, Self::Error> {{")
+    } else {
+        write!(buffer, "fn from(message: ")?;
+        write_identifier(buffer, name, Pascal, Some(In))?;
+        writeln!(buffer, ") -> Self {{")
+    }
+}
+
+// Write the `From` or `TryFrom` implementation which converts the input version of a struct to the
+// output version, including a trailing line break. Unrecognized fields are carried along.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn write_struct_out_conversion<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    let fallible = fallible_conversions.contains(&(namespace.clone(), declaration.name.clone()));
+    write_out_conversion_header(buffer, indentation, &declaration.name, flavor, fallible)?;
+
+    // The output version requires the asymmetric fields [tag:asymmetric_fields_present].
+    let asymmetric_fields = declaration
+        .fields
+        .iter()
+        .filter(|field| matches!(field.rule, schema::Rule::Asymmetric))
+        .collect::<Vec<_>>();
+    if !asymmetric_fields.is_empty() {
+        write_indentation(buffer, indentation + 2)?;
+        write!(buffer, "if ")?;
+        for (i, field) in asymmetric_fields.iter().enumerate() {
+            if i != 0 {
+                write!(buffer, " || ")?;
+            }
+            write!(buffer, "message.")?;
+            write_identifier(buffer, &field.name, Snake, None)?;
+            write!(buffer, ".is_none()")?;
+        }
+        writeln!(buffer, " {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(
+            buffer,
+            "return Err({}::Error::new(",
+            flavor.io_module(indentation),
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(
+            buffer,
+            "{}::ErrorKind::InvalidData,",
+            flavor.io_module(indentation),
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        write_supers(buffer, indentation)?;
+        writeln!(buffer, "MISSING_FIELDS_ERROR_MESSAGE,")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "));")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        writeln!(buffer)?;
+    }
+
+    write_indentation(buffer, indentation + 2)?;
+    if fallible {
+        write!(buffer, "Ok(")?;
+    }
+    write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+    writeln!(buffer, " {{")?;
+    for field in &declaration.fields {
+        let field_fallible = conversion_fallible(
+            imports,
+            namespace,
+            &field.r#type.variant,
+            fallible_conversions,
+        );
+        let mut value = "message.".to_owned();
+        write_identifier(&mut value, &field.name, Snake, None)?;
+
+        write_indentation(buffer, indentation + 3)?;
+        write_identifier(buffer, &field.name, Snake, None)?;
+        write!(buffer, ": ")?;
+        match field.rule {
+            schema::Rule::Asymmetric | schema::Rule::Required => {
+                if let schema::Rule::Asymmetric = field.rule {
+                    // This `unwrap` is safe due to [ref:asymmetric_fields_present].
+                    value.push_str(".unwrap()");
+                }
+                if field_fallible {
+                    write_field_try_into_expression(
+                        buffer,
+                        indentation,
+                        field,
+                        type_overrides,
+                        &value,
+                    )?;
+                } else {
+                    write_field_into_expression(
+                        buffer,
+                        indentation,
+                        field,
+                        type_overrides,
+                        &value,
+                    )?;
+                }
+            }
+            schema::Rule::Optional => {
+                if field_fallible {
+                    write!(buffer, "match {value} {{ Some(payload) => Some(")?;
+                    write_field_try_into_expression(
+                        buffer,
+                        indentation,
+                        field,
+                        type_overrides,
+                        "payload",
+                    )?;
+                    write!(buffer, "), None => None }}")?;
+                } else {
+                    write!(buffer, "{value}.map(|payload| ")?;
+                    write_field_into_expression(
+                        buffer,
+                        indentation,
+                        field,
+                        type_overrides,
+                        "payload",
+                    )?;
+                    write!(buffer, ")")?;
+                }
+            }
+        }
+        writeln!(buffer, ",")?;
+    }
+    if declaration.open {
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "_unknown: message._unknown,")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}{}", if fallible { ")" } else { "" })?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the `From` or `TryFrom` implementation which converts the input version of a choice to the
+// output version, including a trailing line break. Unrecognized fields are carried along.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn write_choice_out_conversion<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    imports: &BTreeMap<Identifier, schema::Namespace>,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    flavor: Flavor,
+    fallible_conversions: &BTreeSet<(schema::Namespace, Identifier)>,
+) -> Result<(), fmt::Error> {
+    let fallible = fallible_conversions.contains(&(namespace.clone(), declaration.name.clone()));
+    write_out_conversion_header(buffer, indentation, &declaration.name, flavor, fallible)?;
+
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "{}match message {{",
+        if fallible { "Ok(" } else { "" }
+    )?;
+    for field in &declaration.fields {
+        let payload = !matches!(field.r#type.variant, schema::TypeVariant::Unit);
+        let fallback = matches!(field.rule, schema::Rule::Optional);
+
+        write_indentation(buffer, indentation + 3)?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &field.name, Pascal, None)?;
+
+        // The input version doesn't retain the fallback of an asymmetric field.
+        if let schema::Rule::Asymmetric = field.rule {
+            if payload {
+                write!(buffer, "(_)")?;
+            }
+            writeln!(
+                buffer,
+                " => return Err({}::Error::new(",
+                flavor.io_module(indentation),
+            )?;
+            write_indentation(buffer, indentation + 4)?;
+            writeln!(
+                buffer,
+                "{}::ErrorKind::InvalidData,",
+                flavor.io_module(indentation),
+            )?;
+            write_indentation(buffer, indentation + 4)?;
+            writeln!(
+                buffer,
+                "\"Choice missing the fallback for an asymmetric field.\","
+            )?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, ")),")?;
+            continue;
+        }
+
+        if payload && fallback {
+            write!(buffer, "(payload, fallback)")?;
+        } else if payload {
+            write!(buffer, "(payload)")?;
+        } else if fallback {
+            write!(buffer, "(fallback)")?;
+        }
+        write!(buffer, " => ")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &field.name, Pascal, None)?;
+        if payload || fallback {
+            write!(buffer, "(")?;
+        }
+        if payload {
+            if conversion_fallible(
+                imports,
+                namespace,
+                &field.r#type.variant,
+                fallible_conversions,
+            ) {
+                write_field_try_into_expression(
+                    buffer,
+                    indentation,
+                    field,
+                    type_overrides,
+                    "payload",
+                )?;
+            } else {
+                write_field_into_expression(buffer, indentation, field, type_overrides, "payload")?;
             }
-        },
-        schema::TypeVariant::Unit => match direction {
-            Direction::Atlas => {
-                write!(buffer, "usize")?;
+            if fallback {
+                write!(buffer, ", ")?;
             }
-            Direction::In | Direction::Out => {
-                write!(buffer, "()")?;
+        }
+        if fallback {
+            if fallible {
+                write!(buffer, "Box::new((*fallback).try_into()?)")?;
+            } else {
+                write!(buffer, "Box::new((*fallback).into())")?;
             }
-        },
+        }
+        if payload || fallback {
+            write!(buffer, ")")?;
+        }
+        writeln!(buffer, ",")?;
     }
+    if declaration.open {
+        write_indentation(buffer, indentation + 3)?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(In))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        write!(buffer, " {{ index, payload }} => ")?;
+        write_identifier(buffer, &declaration.name, Pascal, Some(Out))?;
+        write!(buffer, "::")?;
+        write_identifier(buffer, &UNKNOWN_FIELD_NAME.into(), Pascal, None)?;
+        writeln!(buffer, " {{ index, payload }},")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "}}{}", if fallible { ")" } else { "" })?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
 
-    Ok(())
+// Write an expression which converts the given expression from the input version of the type of a
+// field to the output version, returning an error from the enclosing function if that isn't
+// possible. The type must refer to a declaration, since otherwise the conversion can't fail.
+fn write_field_try_into_expression<T: Write>(
+    buffer: &mut T,
+    supers: usize,
+    field: &schema::Field,
+    type_overrides: &BTreeMap<Identifier, &str>,
+    expression: &str,
+) -> Result<(), fmt::Error> {
+    if type_overrides.contains_key(&field.name) {
+        write_supers(buffer, supers)?;
+        write!(buffer, "ArrayType::from_vec(")?;
+        write_supers(buffer, supers)?;
+        write!(buffer, "ArrayType::into_vec({expression})")?;
+        write_try_into_invocation(buffer, &field.r#type.variant)?;
+        write!(buffer, ")")
+    } else {
+        write!(buffer, "{expression}")?;
+        write_try_into_invocation(buffer, &field.r#type.variant)
+    }
+}
+
+// Write the suffix which converts a value of the given type from its input version to its output
+// version, returning an error from the enclosing function if that isn't possible.
+fn write_try_into_invocation<T: Write>(
+    buffer: &mut T,
+    type_variant: &schema::TypeVariant,
+) -> Result<(), fmt::Error> {
+    let mut layers = 0_usize;
+    let mut layer = type_variant;
+    while let schema::TypeVariant::Array(inner_type) = layer {
+        layers += 1;
+        layer = &inner_type.variant;
+    }
+
+    if layers == 0 {
+        write!(buffer, ".try_into()?")
+    } else {
+        write!(buffer, ".into_iter().map(|x| ")?;
+        for _ in 1..layers {
+            write!(buffer, "x.into_iter().map(|x| ")?;
+        }
+        write!(buffer, "x.try_into()")?;
+        for _ in 0..layers {
+            write!(buffer, ").collect::<Result<Vec<_>, _>>()")?;
+        }
+        write!(buffer, "?")
+    }
 }
 
 // Write an identifier with an optional direction suffix in a way that Rust will be happy with.
@@ -1762,6 +6002,60 @@ fn write_indentation<T: Write>(buffer: &mut T, indentation: usize) -> Result<(),
     Ok(())
 }
 
+// Write the logic to skip whatever is left of a field after its value has been decoded, so the
+// rest of a field which is larger than its value isn't mistaken for the next field. The slice
+// flavor doesn't need this, since it splits off the whole field before decoding the value.
+//
+// Context variables:
+// - `sub_reader` (in and out)
+fn write_finish_sub_reader<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    if let Slice = flavor {
+        return Ok(());
+    }
+
+    write_indentation(buffer, indentation)?;
+    write_supers(buffer, supers)?;
+    writeln!(
+        buffer,
+        "finish{}(&mut sub_reader){}?;",
+        flavor.suffix(),
+        flavor.postfix(),
+    )
+}
+
+// Write a statement which preallocates room in `payload` for the rest of the field. The field size
+// comes from the message, so it's capped at the number of bytes which are already available to
+// avoid allocating a huge buffer for a field which turns out to be truncated.
+//
+// Context variables:
+// - `payload_size` (in)
+// - `sub_reader` (in and out)
+// - `payload` (in and out)
+fn write_bounded_reservation<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    supers: usize,
+    element_size: usize,
+    flavor: Flavor,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    write!(
+        buffer,
+        "payload.reserve_exact(payload_size.min({}::fill_buf(&mut sub_reader){}?.len())",
+        flavor.buf_read_trait(supers),
+        flavor.postfix(),
+    )?;
+    if element_size > 1 {
+        write!(buffer, " / {element_size}_usize")?;
+    }
+    writeln!(buffer, ");")
+}
+
 // Write a series of `super::super::...`.
 fn write_supers<T: Write>(buffer: &mut T, count: usize) -> Result<(), fmt::Error> {
     for _ in 0..count {
@@ -1957,6 +6251,7 @@ fn write_serialization_invocation<T: Write>(
     supers: usize,
     type_variant: &schema::TypeVariant,
     is_field: bool,
+    flavor: Flavor,
 ) -> Result<(), fmt::Error> {
     match type_variant {
         schema::TypeVariant::Array(inner_type) => match &inner_type.variant {
@@ -1972,15 +6267,16 @@ fn write_serialization_invocation<T: Write>(
                 )?;
                 write_indentation(buffer, indentation + 1)?;
                 write_supers(buffer, supers)?;
-                write!(buffer, "serialize_varint(")?;
+                write!(buffer, "serialize_varint{}(", flavor.suffix())?;
                 write_atlas_lookup(buffer, &inner_type.variant)?;
-                writeln!(buffer, " as u64, writer)?;")?;
+                writeln!(buffer, " as u64, writer){}?;", flavor.postfix())?;
                 write_serialization_invocation(
                     buffer,
                     indentation + 1,
                     supers,
                     &inner_type.variant,
                     false,
+                    flavor,
                 )?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "}}")
@@ -1997,6 +6293,7 @@ fn write_serialization_invocation<T: Write>(
                     supers,
                     &inner_type.variant,
                     false,
+                    flavor,
                 )?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "}}")
@@ -2006,7 +6303,13 @@ fn write_serialization_invocation<T: Write>(
                 writeln!(buffer, "{{")?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "let varint = payload.len() as u64;")?;
-                write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field)?;
+                write_u64_serialization_invocation(
+                    buffer,
+                    indentation + 1,
+                    supers,
+                    is_field,
+                    flavor,
+                )?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "}}")
             }
@@ -2016,19 +6319,22 @@ fn write_serialization_invocation<T: Write>(
             writeln!(buffer, "{{")?;
             write_indentation(buffer, indentation + 1)?;
             writeln!(buffer, "let varint = u64::from(*payload);")?;
-            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field)?;
+            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field, flavor)?;
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "}}")
         }
         schema::TypeVariant::Bytes => {
             write_indentation(buffer, indentation)?;
-            writeln!(buffer, "writer.write_all(payload)?;")
+            write_write_all_invocation(buffer, flavor, "payload")?;
+            writeln!(buffer, "?;")
         }
         schema::TypeVariant::Custom(_, _) => {
             write_indentation(buffer, indentation)?;
             writeln!(
                 buffer,
-                "payload.serialize_with_atlas(writer, payload_atlas)?;",
+                "payload.serialize_with_atlas{}(writer, payload_atlas){}?;",
+                flavor.suffix(),
+                flavor.postfix(),
             )
         }
         schema::TypeVariant::F64 => {
@@ -2036,11 +6342,13 @@ fn write_serialization_invocation<T: Write>(
             if is_field {
                 writeln!(buffer, "if payload.to_bits() != 0_u64 {{")?;
                 write_indentation(buffer, indentation + 1)?;
-                writeln!(buffer, "writer.write_all(&payload.to_le_bytes())?;")?;
+                write_write_all_invocation(buffer, flavor, "&payload.to_le_bytes()")?;
+                writeln!(buffer, "?;")?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "}}")
             } else {
-                writeln!(buffer, "writer.write_all(&payload.to_le_bytes())?;")
+                write_write_all_invocation(buffer, flavor, "&payload.to_le_bytes()")?;
+                writeln!(buffer, "?;")
             }
         }
         schema::TypeVariant::S64 => {
@@ -2050,20 +6358,21 @@ fn write_serialization_invocation<T: Write>(
             write!(buffer, "let varint = ")?;
             write_supers(buffer, supers)?;
             writeln!(buffer, "zigzag_encode(*payload);")?;
-            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field)?;
+            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field, flavor)?;
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "}}")
         }
         schema::TypeVariant::String => {
             write_indentation(buffer, indentation)?;
-            writeln!(buffer, "writer.write_all(payload.as_bytes())?;")
+            write_write_all_invocation(buffer, flavor, "payload.as_bytes()")?;
+            writeln!(buffer, "?;")
         }
         schema::TypeVariant::U64 => {
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "{{")?;
             write_indentation(buffer, indentation + 1)?;
             writeln!(buffer, "let varint = *payload;")?;
-            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field)?;
+            write_u64_serialization_invocation(buffer, indentation + 1, supers, is_field, flavor)?;
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "}}")
         }
@@ -2071,6 +6380,22 @@ fn write_serialization_invocation<T: Write>(
     }
 }
 
+// Write an invocation of `write_all` on `writer` with the given argument, without a trailing line
+// break or error propagation.
+fn write_write_all_invocation<T: Write>(
+    buffer: &mut T,
+    flavor: Flavor,
+    argument: &str,
+) -> Result<(), fmt::Error> {
+    match flavor {
+        Async => write!(
+            buffer,
+            "::tokio::io::AsyncWriteExt::write_all(writer, {argument}).await",
+        ),
+        Blocking | NoStd | Slice => write!(buffer, "writer.write_all({argument})"),
+    }
+}
+
 // Write the logic to invoke the serialization logic for a varint, including a trailing line break.
 //
 // Context variables:
@@ -2081,6 +6406,7 @@ fn write_u64_serialization_invocation<T: Write>(
     indentation: usize,
     supers: usize,
     is_field: bool,
+    flavor: Flavor,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
     if is_field {
@@ -2090,18 +6416,29 @@ fn write_u64_serialization_invocation<T: Write>(
         write_indentation(buffer, indentation + 1)?;
         write!(buffer, "1_u64..=567_382_630_219_903_u64 => ")?;
         write_supers(buffer, supers)?;
-        writeln!(buffer, "serialize_varint(varint, writer)?,")?;
-        write_indentation(buffer, indentation + 1)?;
         writeln!(
             buffer,
-            "567_382_630_219_904_u64..=18_446_744_073_709_551_615_u64 => \
-                writer.write_all(&varint.to_le_bytes())?,",
+            "serialize_varint{}(varint, writer){}?,",
+            flavor.suffix(),
+            flavor.postfix(),
+        )?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(
+            buffer,
+            "567_382_630_219_904_u64..=18_446_744_073_709_551_615_u64 => ",
         )?;
+        write_write_all_invocation(buffer, flavor, "&varint.to_le_bytes()")?;
+        writeln!(buffer, "?,")?;
         write_indentation(buffer, indentation)?;
         writeln!(buffer, "}}")
     } else {
         write_supers(buffer, supers)?;
-        writeln!(buffer, "serialize_varint(varint, writer)?;")
+        writeln!(
+            buffer,
+            "serialize_varint{}(varint, writer){}?;",
+            flavor.suffix(),
+            flavor.postfix(),
+        )
     }
 }
 
@@ -2117,7 +6454,7 @@ fn write_u64_serialization_invocation<T: Write>(
 //   formed, then `sub_reader` is consumed to the end.
 // - If `type_variant` is `Array` and the element type is `Bool`, `S64`, or `U64`, then
 //   `payload_size` is never read.
-#[allow(clippy::too_many_lines)]
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 fn write_deserialization_invocation<T: Write>(
     buffer: &mut T,
     indentation: usize,
@@ -2126,6 +6463,7 @@ fn write_deserialization_invocation<T: Write>(
     namespace: &schema::Namespace,
     type_variant: &schema::TypeVariant,
     is_field: bool,
+    flavor: Flavor,
 ) -> Result<(), fmt::Error> {
     match type_variant {
         schema::TypeVariant::Array(inner_type) => match &inner_type.variant {
@@ -2140,7 +6478,12 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 write!(buffer, "let payload_size = match ")?;
                 write_supers(buffer, supers)?;
-                writeln!(buffer, "deserialize_varint(&mut sub_reader) {{")?;
+                writeln!(
+                    buffer,
+                    "deserialize_varint{}(&mut sub_reader){} {{",
+                    flavor.suffix(),
+                    flavor.postfix(),
+                )?;
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "Ok(payload_size) => payload_size as usize,")?;
                 write_indentation(buffer, indentation + 2)?;
@@ -2148,7 +6491,8 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 3)?;
                 writeln!(
                     buffer,
-                    "if let std::io::ErrorKind::UnexpectedEof = err.kind() {{",
+                    "if let {}::ErrorKind::UnexpectedEof = err.kind() {{",
+                    flavor.io_module(supers),
                 )?;
                 write_indentation(buffer, indentation + 4)?;
                 writeln!(buffer, "break;")?;
@@ -2163,8 +6507,8 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(
                     buffer,
-                    "let mut sub_reader = ::std::io::Read::take(\
-                            &mut sub_reader, payload_size as u64);",
+                    "let mut sub_reader = {}::take(&mut sub_reader, payload_size as u64);",
+                    flavor.read_trait(supers),
                 )?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "payload.push({{")?;
@@ -2176,7 +6520,9 @@ fn write_deserialization_invocation<T: Write>(
                     namespace,
                     &inner_type.variant,
                     false,
+                    flavor,
                 )?;
+                write_finish_sub_reader(buffer, indentation + 2, supers, flavor)?;
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "payload")?;
                 write_indentation(buffer, indentation + 1)?;
@@ -2191,8 +6537,11 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation)?;
                 write!(
                     buffer,
-                    "fn deserialize_element<T: ::std::io::BufRead>(mut sub_reader: &mut T) -> \
-                            ::std::io::Result<",
+                    "{}fn deserialize_element<T: {}>(mut sub_reader: &mut T) -> \
+                            {}::Result<",
+                    flavor.qualifier(),
+                    flavor.reader_bound(supers),
+                    flavor.io_module(supers),
                 )?;
                 write_type(buffer, imports, namespace, &inner_type.variant, In)?;
                 writeln!(buffer, "> {{")?;
@@ -2204,6 +6553,7 @@ fn write_deserialization_invocation<T: Write>(
                     namespace,
                     &inner_type.variant,
                     false,
+                    flavor,
                 )?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "Ok(payload)")?;
@@ -2212,15 +6562,15 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "let mut payload = Vec::new();")?;
                 if matches!(inner_type.variant, schema::TypeVariant::F64) {
-                    write_indentation(buffer, indentation)?;
-                    writeln!(buffer, "payload.reserve_exact(payload_size);")?;
+                    write_bounded_reservation(buffer, indentation, supers, 8, flavor)?;
                 }
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "loop {{")?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(
                     buffer,
-                    "payload.push(match deserialize_element(&mut sub_reader) {{",
+                    "payload.push(match deserialize_element(&mut sub_reader){} {{",
+                    flavor.postfix(),
                 )?;
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "Ok(element) => element,")?;
@@ -2229,7 +6579,8 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 3)?;
                 writeln!(
                     buffer,
-                    "if let std::io::ErrorKind::UnexpectedEof = err.kind() {{",
+                    "if let {}::ErrorKind::UnexpectedEof = err.kind() {{",
+                    flavor.io_module(supers),
                 )?;
                 write_indentation(buffer, indentation + 4)?;
                 writeln!(buffer, "break;")?;
@@ -2253,7 +6604,31 @@ fn write_deserialization_invocation<T: Write>(
                     namespace,
                     &schema::TypeVariant::U64,
                     is_field,
+                    flavor,
+                )?;
+                // Building the vector takes time proportional to its length, so a tiny message
+                // could otherwise claim an arbitrarily long array. The limit matches the longest
+                // array JavaScript supports.
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "if payload > u64::from(u32::MAX) {{")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(
+                    buffer,
+                    "return Err({}::Error::new(",
+                    flavor.io_module(supers),
+                )?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(
+                    buffer,
+                    "{}::ErrorKind::InvalidData,",
+                    flavor.io_module(supers),
                 )?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "\"Array of units is too long.\",")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "));")?;
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "}}")?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "let payload = vec![(); payload as usize];")
             }
@@ -2267,6 +6642,7 @@ fn write_deserialization_invocation<T: Write>(
                 namespace,
                 &schema::TypeVariant::U64,
                 is_field,
+                flavor,
             )?;
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "let payload = payload != 0_u64;")
@@ -2274,19 +6650,25 @@ fn write_deserialization_invocation<T: Write>(
         schema::TypeVariant::Bytes => {
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "let mut payload = vec![];")?;
-            write_indentation(buffer, indentation)?;
-            writeln!(buffer, "payload.reserve_exact(payload_size);")?;
+            write_bounded_reservation(buffer, indentation, supers, 1, flavor)?;
             write_indentation(buffer, indentation)?;
             writeln!(
                 buffer,
-                "::std::io::Read::read_to_end(&mut sub_reader, &mut payload)?;",
+                "{}::read_to_end(&mut sub_reader, &mut payload){}?;",
+                flavor.read_trait(supers),
+                flavor.postfix(),
             )
         }
         schema::TypeVariant::Custom(_, _) => {
             write_indentation(buffer, indentation)?;
             write!(buffer, "let payload = ")?;
             write_type(buffer, imports, namespace, type_variant, In)?;
-            writeln!(buffer, "::deserialize_from_reader_ref(&mut sub_reader)?;")
+            writeln!(
+                buffer,
+                "::deserialize_from_reader_ref{}(&mut sub_reader){}?;",
+                flavor.suffix(),
+                flavor.postfix(),
+            )
         }
         schema::TypeVariant::F64 => {
             write_indentation(buffer, indentation)?;
@@ -2301,7 +6683,9 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(
                     buffer,
-                    "::std::io::Read::read_exact(&mut sub_reader, &mut buffer)?;",
+                    "{}::read_exact(&mut sub_reader, &mut buffer){}?;",
+                    flavor.read_trait(supers),
+                    flavor.postfix(),
                 )?;
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "f64::from_le_bytes(buffer)")?;
@@ -2312,7 +6696,9 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation)?;
                 writeln!(
                     buffer,
-                    "::std::io::Read::read_exact(&mut sub_reader, &mut buffer)?;",
+                    "{}::read_exact(&mut sub_reader, &mut buffer){}?;",
+                    flavor.read_trait(supers),
+                    flavor.postfix(),
                 )?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "let payload = f64::from_le_bytes(buffer);")
@@ -2327,6 +6713,7 @@ fn write_deserialization_invocation<T: Write>(
                 namespace,
                 &schema::TypeVariant::U64,
                 is_field,
+                flavor,
             )?;
             write_indentation(buffer, indentation)?;
             write!(buffer, "let payload = ")?;
@@ -2336,12 +6723,13 @@ fn write_deserialization_invocation<T: Write>(
         schema::TypeVariant::String => {
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "let mut payload = String::new();")?;
-            write_indentation(buffer, indentation)?;
-            writeln!(buffer, "payload.reserve_exact(payload_size);")?;
+            write_bounded_reservation(buffer, indentation, supers, 1, flavor)?;
             write_indentation(buffer, indentation)?;
             writeln!(
                 buffer,
-                "::std::io::Read::read_to_string(&mut sub_reader, &mut payload)?;",
+                "{}::read_to_string(&mut sub_reader, &mut payload){}?;",
+                flavor.read_trait(supers),
+                flavor.postfix(),
             )
         }
         schema::TypeVariant::U64 => {
@@ -2357,7 +6745,9 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(
                     buffer,
-                    "::std::io::Read::read_exact(&mut sub_reader, &mut buffer[..])?;",
+                    "{}::read_exact(&mut sub_reader, &mut buffer[..]){}?;",
+                    flavor.read_trait(supers),
+                    flavor.postfix(),
                 )?;
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "u64::from_le_bytes(buffer)")?;
@@ -2366,13 +6756,23 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 write!(buffer, "_ => ")?;
                 write_supers(buffer, supers)?;
-                writeln!(buffer, "deserialize_varint(&mut sub_reader)?,")?;
+                writeln!(
+                    buffer,
+                    "deserialize_varint{}(&mut sub_reader){}?,",
+                    flavor.suffix(),
+                    flavor.postfix(),
+                )?;
                 write_indentation(buffer, indentation)?;
                 writeln!(buffer, "}};")
             } else {
                 write!(buffer, "let payload = ")?;
                 write_supers(buffer, supers)?;
-                writeln!(buffer, "deserialize_varint(&mut sub_reader)?;")
+                writeln!(
+                    buffer,
+                    "deserialize_varint{}(&mut sub_reader){}?;",
+                    flavor.suffix(),
+                    flavor.postfix(),
+                )
             }
         }
         schema::TypeVariant::Unit => {
@@ -2397,17 +6797,338 @@ fn integer_encoded(r#type: &schema::Type) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::{generate_rust::generate, schema_loader::load_schemas, validator::validate};
-    use std::{fs::read_to_string, path::Path};
+    use crate::{
+        desugar::desugar,
+        generate_rust::{
+            Options, TypeOverride, check_builder_setters, check_type_overrides, generate,
+            generate_files, generate_fuzz_crate,
+        },
+        parser::parse,
+        schema,
+        schema_loader::load_schemas,
+        tokenizer::tokenize,
+        validator::validate,
+    };
+    use std::{collections::BTreeMap, fs::read_to_string, path::Path};
 
     #[test]
     fn generate_example() {
-        let schemas = load_schemas(Path::new("integration_tests/types/types.t")).unwrap();
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        assert_eq!(
+            generate("0.0.0", &schemas, &Options::default()),
+            read_to_string("test_data/types.rs").unwrap(),
+        );
+    }
+
+    #[test]
+    fn generate_example_files() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let files = generate_files("0.0.0", &schemas, &Options::default());
+
+        assert!(files[Path::new("mod.rs")].contains(
+            "include!(\"common.rs\");\n\npub mod circular_dependency;\npub mod comprehensive;\n",
+        ));
+        assert!(
+            generate("0.0.0", &schemas, &Options::default())
+                .starts_with(files[Path::new("common.rs")].strip_suffix('\n').unwrap())
+        );
+        assert_eq!(
+            files[Path::new("circular_dependency/mod.rs")],
+            "// This file was automatically generated by Typical 0.0.0.\n\
+             // Visit https://github.com/stepchowfun/typical for more information.\n\
+             \n\
+             pub mod dependency;\n\
+             pub mod types;\n",
+        );
+        assert!(
+            files[Path::new("degenerate/types.rs")]
+                .contains("\nimpl super::super::Serialize for EmptyStructOut {\n")
+        );
+        assert!(
+            files[Path::new("circular_dependency/dependency/types.rs")]
+                .contains("\n    pub x: super::super::types::StructFromAboveIn,\n")
+        );
+        assert!(generate_files("0.0.0", &BTreeMap::new(), &Options::default()).is_empty());
+    }
+
+    #[test]
+    fn generate_no_std() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                no_std: true,
+                ..Options::default()
+            },
+        );
+
+        assert!(!code.contains("std::"));
+    }
+
+    #[test]
+    fn generate_runtime() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                runtime: true,
+                ..Options::default()
+            },
+        );
+
+        assert!(code.contains("typical_runtime::check_version(\"0.0.0\")"));
+        assert!(!code.contains("pub trait Serialize"));
+        assert!(!code.contains("fn zigzag_encode"));
+    }
+
+    #[test]
+    fn generate_builders() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                builders: true,
+                ..Options::default()
+            },
+        );
+
+        assert!(code.contains("pub fn builder() -> EmptyStructBuilder {"));
+        assert!(code.contains("pub fn builder() -> OldStructBuilder<()> {"));
+        assert!(
+            code.contains("pub fn known(self, payload: String) -> OldStructBuilder<(String,)> {",)
+        );
+        assert!(code.contains("impl OldStructBuilder<(String,)> {"));
+        assert!(code.contains("pub fn with_number(mut self, payload: f64) -> Self {"));
+        assert!(!generate("0.0.0", &schemas, &Options::default()).contains("Builder"));
+    }
+
+    #[test]
+    fn generate_arbitrary() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                arbitrary: true,
+                ..Options::default()
+            },
+        );
+
+        assert!(code.contains("impl<'a> ::arbitrary::Arbitrary<'a> for EmptyStructOut {"));
+        assert!(code.contains("Err(::arbitrary::Error::EmptyChoose)"));
+        assert!(code.contains("let choices = if depth == 0 { 2 } else { 3 };"));
+        assert!(code.contains(
+            "assert_arbitrary_round_trips::<super::EmptyStructOut, super::EmptyStructIn>();",
+        ));
+        assert!(!generate("0.0.0", &schemas, &Options::default()).contains("arbitrary"));
+    }
+
+    #[test]
+    fn generate_containers() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                containers: true,
+                ..Options::default()
+            },
+        );
+
+        assert!(code.contains("pub const SCHEMA: &str = r\""));
+        assert!(code.contains("pub fn read_container<M: Deserialize + SchemaType, T: BufRead>("));
+        assert!(code.contains("impl super::super::SchemaType for BarOut {"));
+        assert!(code.contains("const SCHEMA: &'static str = super::super::SCHEMA;"));
+        assert!(code.contains("const NORMAL_FORM: &'static str = \"struct\\n\";"));
+
+        let code = generate("0.0.0", &schemas, &Options::default());
+        assert!(!code.contains("SCHEMA"));
+        assert!(!code.contains("framed"));
+    }
+
+    #[test]
+    fn generate_type_overrides() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let type_overrides = [
+            "bytes=::bytes::Bytes",
+            "array=Box<[{}]>",
+            "unknown_fields::types::NewStruct.text=Box<str>",
+        ]
+        .iter()
+        .map(|type_override| type_override.parse().unwrap())
+        .collect::<Vec<_>>();
+        check_type_overrides(&schemas, &type_overrides).unwrap();
+
+        let code = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                type_overrides,
+                ..Options::default()
+            },
+        );
+
+        assert!(code.contains("FRequired(::bytes::Bytes),"));
+        assert!(code.contains("XRequired(Box<[Vec<Vec<u8>>]>),"));
+        assert!(code.contains("pub known: String,"));
+        assert!(code.contains("pub text: Box<str>,"));
+        assert!(code.contains("let payload = super::super::StringType::from_string(payload);"));
+        assert!(code.contains("pub trait BytesType: Sized {"));
+        assert!(!generate("0.0.0", &schemas, &Options::default()).contains("BytesType"));
+    }
+
+    #[test]
+    fn parse_type_overrides() {
+        assert_eq!(
+            "string=Box<str>".parse::<TypeOverride>().unwrap().rust_type,
+            "Box<str>",
+        );
+        assert_eq!(
+            "foo::bar::Baz.qux=a=b"
+                .parse::<TypeOverride>()
+                .unwrap()
+                .target
+                .to_string(),
+            "foo::bar::Baz.qux",
+        );
+        assert!("string".parse::<TypeOverride>().is_err());
+        assert!("string=".parse::<TypeOverride>().is_err());
+        assert!("foo=Box<str>".parse::<TypeOverride>().is_err());
+        assert!("foo::Baz.=Box<str>".parse::<TypeOverride>().is_err());
+    }
+
+    #[test]
+    fn check_type_overrides_invalid() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let type_overrides = [
+            "unknown_fields::types::NewStruct.unrecognized=u8",
+            "unknown_fields::types::NewStruct.missing=u8",
+            "missing::types::NewStruct.text=u8",
+        ]
+        .iter()
+        .map(|type_override| type_override.parse().unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            check_type_overrides(&schemas, &type_overrides)
+                .unwrap_err()
+                .len(),
+            3,
+        );
+    }
+
+    #[test]
+    fn check_builder_setters_collision() {
+        let namespace = schema::Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                with_x: String = 0
+                optional x: String = 1
+            }
+
+            struct Bar {
+                x: String = 0
+                optional with_x: String = 1
+            }
+
+            choice Baz {
+                with_x: String = 0
+                optional x: String = 1
+            }
+        "
+        .to_owned();
+
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let errors = check_builder_setters(&schemas).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("with_x"));
+    }
+
+    #[test]
+    fn check_builder_setters_ok() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        check_builder_setters(&schemas).unwrap();
+    }
+
+    #[test]
+    fn generate_fuzz_targets() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
         validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let files = generate_fuzz_crate("0.0.0", &schemas);
 
         assert_eq!(
-            generate("0.0.0", &schemas),
+            files[Path::new("src/types.rs")],
             read_to_string("test_data/types.rs").unwrap(),
         );
+        assert!(files[Path::new("Cargo.toml")].contains(
+            "[[bin]]\nname = \"degenerate_types_empty_struct\"\n\
+             path = \"fuzz_targets/degenerate_types_empty_struct.rs\"\n",
+        ));
+        assert!(
+            files[Path::new("fuzz_targets/degenerate_types_empty_struct.rs")].contains(
+                "fuzz::round_trip::<fuzz::types::degenerate::types::EmptyStructIn, \
+                 fuzz::types::degenerate::types::EmptyStructOut>(data);",
+            ),
+        );
+        assert!(
+            files[Path::new("src/lib.rs")]
+                .contains("pub fn round_trip<T: Clone + Debug + Deserialize, U: Serialize + ")
+        );
+    }
+
+    #[test]
+    fn runtime_version_matches() {
+        // The generated code checks that it's compatible with the runtime crate, so the two are
+        // released together with the same version.
+        assert!(
+            include_str!("../runtime/rust/Cargo.toml")
+                .lines()
+                .any(|line| line == format!("version = \"{}\"", env!("CARGO_PKG_VERSION"))),
+        );
     }
 }
@@ -1,8 +1,16 @@
-use crate::{identifier::Identifier, schema};
+use crate::{
+    container,
+    error::{Error, throw},
+    format::CodeStr,
+    identifier::Identifier,
+    schema::{self, UNKNOWN_FIELD_NAME},
+};
 use std::{
-    collections::BTreeMap,
-    fmt::{self, Write},
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Display, Write},
+    iter::once,
     path::PathBuf,
+    str::FromStr,
 };
 
 // The name of the shared library file, without the `.ts` suffix.
@@ -123,6 +131,9 @@ struct DeclarationFunctionNames {
     deserialize_unsafe: String,
     serialize: String,
     deserialize: String,
+    check_out: String,
+    is_out: String,
+    assert_out: String,
 }
 
 impl DeclarationFunctionNames {
@@ -134,21 +145,267 @@ impl DeclarationFunctionNames {
             deserialize_unsafe: helper_name(identifier, "deserialize_unsafe"),
             serialize: helper_name(identifier, "serialize"),
             deserialize: helper_name(identifier, "deserialize"),
+            check_out: helper_name(identifier, "check_out"),
+            is_out: format_identifier(&Identifier::from("is").join(identifier), Camel, Some(Out)),
+            assert_out: format_identifier(
+                &Identifier::from("assert").join(identifier),
+                Camel,
+                Some(Out),
+            ),
+        }
+    }
+}
+
+// This struct represents the options which control the TypeScript code generator.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    // The `U64` and `S64` fields which are represented as `number`s rather than `bigint`s
+    pub number_fields: Vec<NumberSelector>,
+
+    // What the deserializers do with an integer which can't be represented exactly as a `number`
+    pub number_overflow: NumberOverflow,
+
+    // Whether to generate the helpers for framed messages and containers, along with an embedded
+    // copy of the schema and the fingerprint of each message
+    pub containers: bool,
+}
+
+// This enum represents the fields which are represented as `number`s: every `U64` or `S64` field
+// (or array thereof) in a schema, or one such field.
+#[derive(Clone, Debug)]
+pub enum NumberSelector {
+    Schema(schema::Namespace),
+    Field(schema::Namespace, Identifier, Identifier),
+}
+
+impl Display for NumberSelector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (namespace, field) = match self {
+            NumberSelector::Schema(namespace) => (namespace, None),
+            NumberSelector::Field(namespace, declaration_name, field_name) => {
+                (namespace, Some((declaration_name, field_name)))
+            }
+        };
+
+        let components = namespace
+            .components
+            .iter()
+            .map(Identifier::original)
+            .collect::<Vec<_>>();
+        write!(f, "{}", components.join("::"))?;
+
+        if let Some((declaration_name, field_name)) = field {
+            write!(
+                f,
+                "::{}.{}",
+                declaration_name.original(),
+                field_name.original(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// Number selectors are given on the command line as a schema such as `foo::types` or a field such
+// as `foo::types::Bar.baz`.
+impl FromStr for NumberSelector {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let selector = string.trim();
+
+        let (path, field_name) = match selector.rsplit_once('.') {
+            Some((path, field_name)) => (path, Some(field_name)),
+            None => (selector, None),
+        };
+        let mut components = path.split("::").chain(field_name).collect::<Vec<_>>();
+
+        if components.len() < field_name.map_or(1, |_| 3)
+            || components.iter().any(|component| component.is_empty())
+        {
+            return Err(format!(
+                "Expected a schema such as foo::types or a field such as foo::types::Bar.baz, but \
+                    got {selector}.",
+            ));
+        }
+
+        Ok(if field_name.is_some() {
+            // The `unwrap`s are safe because there are at least three components.
+            let field_name = components.pop().unwrap().into();
+            let declaration_name = components.pop().unwrap().into();
+
+            NumberSelector::Field(
+                schema::Namespace {
+                    components: components.into_iter().map(Identifier::from).collect(),
+                },
+                declaration_name,
+                field_name,
+            )
+        } else {
+            NumberSelector::Schema(schema::Namespace {
+                components: components.into_iter().map(Identifier::from).collect(),
+            })
+        })
+    }
+}
+
+// This enum represents what the deserializers do with an integer which is represented as a
+// `number` but exceeds `Number.MAX_SAFE_INTEGER` in magnitude.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NumberOverflow {
+    #[default]
+    Throw,
+    Clamp,
+}
+
+impl NumberOverflow {
+    // The name of the function in the shared library which converts a `bigint` to a `number`
+    fn conversion_function(self) -> &'static str {
+        match self {
+            NumberOverflow::Throw => "safeNumberFromBigint",
+            NumberOverflow::Clamp => "clampedNumberFromBigint",
+        }
+    }
+}
+
+impl FromStr for NumberOverflow {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.trim() {
+            "throw" => Ok(NumberOverflow::Throw),
+            "clamp" => Ok(NumberOverflow::Clamp),
+            overflow => Err(format!("Expected throw or clamp, but got {overflow}.")),
+        }
+    }
+}
+
+// This struct records which fields of a declaration are represented as `number`s, along with how
+// they are converted from `bigint`s when deserializing.
+struct NumberFields {
+    names: BTreeSet<Identifier>,
+    overflow: NumberOverflow,
+}
+
+impl NumberFields {
+    fn contains(&self, field: &schema::Field) -> bool {
+        self.names.contains(&field.name)
+    }
+}
+
+// Check that every number selector refers to a schema which exists or to a field which exists and
+// has a type that can be represented as a `number`.
+pub fn check_number_fields(
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    number_fields: &[NumberSelector],
+) -> Result<(), Vec<Error>> {
+    let mut errors = vec![];
+
+    for selector in number_fields {
+        match selector {
+            NumberSelector::Schema(namespace) => {
+                if !schemas.contains_key(namespace) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "There is no schema {} to represent the integers of as numbers.",
+                            selector.to_string().code_str(),
+                        ),
+                        None,
+                        None,
+                        None,
+                    ));
+                }
+            }
+            NumberSelector::Field(namespace, declaration_name, field_name) => {
+                let field = schemas.get(namespace).and_then(|(schema, _, _)| {
+                    schema
+                        .declarations
+                        .iter()
+                        .find(|declaration| declaration.name == *declaration_name)
+                        .and_then(|declaration| {
+                            declaration
+                                .fields
+                                .iter()
+                                .find(|field| field.name == *field_name)
+                        })
+                });
+
+                match field {
+                    None => {
+                        errors.push(throw::<Error>(
+                            &format!(
+                                "There is no field {} to represent as a number.",
+                                selector.to_string().code_str(),
+                            ),
+                            None,
+                            None,
+                            None,
+                        ));
+                    }
+                    Some(field) => {
+                        if !integer_elements(&field.r#type.variant) {
+                            errors.push(throw::<Error>(
+                                &format!(
+                                    "The type of {} is {}, but only fields of type {}, {}, or \
+                                        arrays thereof can be represented as numbers.",
+                                    selector.to_string().code_str(),
+                                    field.r#type.to_string().code_str(),
+                                    "U64".code_str(),
+                                    "S64".code_str(),
+                                ),
+                                None,
+                                None,
+                                None,
+                            ));
+                        }
+                    }
+                }
+            }
         }
     }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
 }
 
 // Generate TypeScript files from a schema and its transitive dependencies. The paths are relative
 // to the configured output directory.
+//
+// The generated code only uses TypeScript syntax which can be erased to obtain JavaScript, so it
+// has no enums, namespaces, decorators, parameter properties, or overloads. The JavaScript code
+// generator relies on this [tag:typescript_erasable_syntax].
 pub fn generate(
     typical_version: &str,
     schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    options: &Options,
+) -> BTreeMap<PathBuf, String> {
+    generate_with_import_extension(typical_version, schemas, options, "")
+}
+
+// Generate TypeScript files like `generate`, but with the given extension (e.g., `.js`) appended to
+// the specifiers of the modules imported by the generated files.
+pub fn generate_with_import_extension(
+    typical_version: &str,
+    schemas: &BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>,
+    options: &Options,
+    import_extension: &str,
 ) -> BTreeMap<PathBuf, String> {
     let mut files = BTreeMap::new();
+    let embedded_schema = container::embedded_schema(schemas);
 
     let mut common_buffer = String::new();
     // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
-    write_common_file(&mut common_buffer, typical_version).unwrap();
+    write_common_file(
+        &mut common_buffer,
+        typical_version,
+        options,
+        &embedded_schema,
+    )
+    .unwrap();
     files.insert(
         PathBuf::from(COMMON_FILE_STEM).with_extension("ts"),
         common_buffer,
@@ -157,7 +414,16 @@ pub fn generate(
     for (namespace, (schema, _, _)) in schemas {
         let mut buffer = String::new();
         // The `unwrap` is safe because the `std::fmt::Write` impl for `String` is infallible.
-        write_schema_file(&mut buffer, typical_version, namespace, schema).unwrap();
+        write_schema_file(
+            &mut buffer,
+            typical_version,
+            namespace,
+            schema,
+            options,
+            import_extension,
+            &embedded_schema,
+        )
+        .unwrap();
         let mut path = PathBuf::new();
         for component in &namespace.components {
             path.push(component.snake_case());
@@ -188,7 +454,12 @@ fn write_generated_file_header<T: Write>(
 
 // Write the shared library file.
 #[allow(clippy::too_many_lines)]
-fn write_common_file<T: Write>(buffer: &mut T, typical_version: &str) -> Result<(), fmt::Error> {
+fn write_common_file<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+) -> Result<(), fmt::Error> {
     write_generated_file_header(buffer, typical_version)?;
     writeln!(buffer)?;
     writeln!(
@@ -209,6 +480,10 @@ export type Deserializable =
   | BigInt64Array
   | BigUint64Array;
 
+export type Transport = (methodIndex: bigint, request: ArrayBuffer) => Promise<ArrayBuffer>;
+
+export type UnknownField = {{ index: bigint; sizeMode: number; payload: ArrayBuffer }};
+
 export function unreachable(x: never): never {{
   return x;
 }}
@@ -359,76 +634,108 @@ export function serializeVarint(
 }}
 
 function varintSizeFromFirstByte(firstByte: number): number {{
-  let trailingZeros = 0;
-
-  while (trailingZeros < 8 && (firstByte & 1) !== 1) {{
-    trailingZeros += 1;
-    firstByte >>= 1;
-  }}
-
-  return trailingZeros + 1;
+  // The size is one more than the number of trailing zeros, which `Math.clz32` can count once the
+  // lowest set bit is isolated.
+  return firstByte === 0 ? 9 : 32 - Math.clz32(firstByte & -firstByte);
 }}
 
-export function deserializeVarint(
+// This decodes a varint as a `number` rather than a `bigint`, which is much faster. The result is
+// exact for varints of at most 7 bytes, which covers every value up to 567,382,630,219,903 (about
+// 2^49, so the arithmetic never exceeds `Number.MAX_SAFE_INTEGER`). Longer varints are decoded as
+// a `bigint` and then rounded.
+export function deserializeVarintNumber(
   dataView: DataView,
   offset: number,
-): [number, bigint] {{
+): [number, number] {{
   const firstByte = dataView.getUint8(offset);
-  const sizeMinusOne = varintSizeFromFirstByte(firstByte) - 1;
-
-  const offsetPlusOne = offset + 1;
-  dataView64.setBigUint64(0, 0n, true);
-  for (let i = 0; i < sizeMinusOne; i += 1) {{
-    dataView64.setUint8(i, dataView.getUint8(offsetPlusOne + i));
-  }}
-  const remainingBytesValue = dataView64.getBigUint64(0, true);
 
-  switch (sizeMinusOne) {{
-    case 0:
-      return [offset + 1, BigInt(firstByte >> 1)];
+  switch (varintSizeFromFirstByte(firstByte)) {{
     case 1:
+      return [offset + 1, firstByte >> 1];
+    case 2:
       return [
         offset + 2,
-        128n + BigInt(firstByte >> 2) + (remainingBytesValue << 6n),
+        128 + (firstByte >> 2) + dataView.getUint8(offset + 1) * 64,
       ];
-    case 2:
+    case 3:
       return [
         offset + 3,
-        16_512n + BigInt(firstByte >> 3) + (remainingBytesValue << 5n),
+        16_512 + (firstByte >> 3) + dataView.getUint16(offset + 1, true) * 32,
       ];
-    case 3:
+    case 4:
       return [
         offset + 4,
-        2_113_664n + BigInt(firstByte >> 4) + (remainingBytesValue << 4n),
+        2_113_664 +
+          (firstByte >> 4) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint16(offset + 2, true) * 256) *
+            16,
       ];
-    case 4:
+    case 5:
       return [
         offset + 5,
-        270_549_120n + BigInt(firstByte >> 5) + (remainingBytesValue << 3n),
+        270_549_120 +
+          (firstByte >> 5) +
+          dataView.getUint32(offset + 1, true) * 8,
       ];
-    case 5:
+    case 6:
       return [
         offset + 6,
-        34_630_287_488n + BigInt(firstByte >> 6) + (remainingBytesValue << 2n),
+        34_630_287_488 +
+          (firstByte >> 6) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint32(offset + 2, true) * 256) *
+            4,
       ];
-    case 6:
+    case 7:
       return [
         offset + 7,
-        4_432_676_798_592n +
-          BigInt(firstByte >> 7) +
-          (remainingBytesValue << 1n),
+        4_432_676_798_592 +
+          (firstByte >> 7) +
+          (dataView.getUint16(offset + 1, true) +
+            dataView.getUint32(offset + 3, true) * 65_536) *
+            2,
       ];
-    case 7:
-      return [offset + 8, 567_382_630_219_904n + remainingBytesValue];
-    default:
+    default: {{
+      const [newOffset, value] = deserializeVarint(dataView, offset);
+      return [newOffset, Number(value)];
+    }}
+  }}
+}}
+
+export function deserializeVarint(
+  dataView: DataView,
+  offset: number,
+): [number, bigint] {{
+  switch (varintSizeFromFirstByte(dataView.getUint8(offset))) {{
+    case 8:
+      return [
+        offset + 8,
+        567_382_630_219_904n +
+          BigInt(dataView.getUint32(offset + 1, true)) +
+          (BigInt(dataView.getUint16(offset + 5, true)) << 32n) +
+          (BigInt(dataView.getUint8(offset + 7)) << 48n),
+      ];
+    case 9:
       return [
         offset + 9,
-        (72_624_976_668_147_840n + remainingBytesValue) %
+        (72_624_976_668_147_840n + dataView.getBigUint64(offset + 1, true)) %
           18_446_744_073_709_551_616n,
       ];
+    default: {{
+      const [newOffset, value] = deserializeVarintNumber(dataView, offset);
+      return [newOffset, BigInt(value)];
+    }}
   }}
 }}
 
+export function payloadIntegerEncoded(payload: ArrayBuffer): boolean {{
+  return (
+    payload.byteLength > 0 &&
+    varintSizeFromFirstByte(new Uint8Array(payload)[0]) === payload.byteLength
+  );
+}}
+
 export function fieldHeaderSize(
   index: bigint,
   payloadSize: number,
@@ -477,184 +784,828 @@ export function serializeFieldHeader(
 export function deserializeFieldHeader(
   dataView: DataView,
   offset: number,
-): [number, bigint, number] {{
-  const [newOffset, tag] = deserializeVarint(dataView, offset);
-
-  const index = tag >> 2n;
-
-  switch (tag & 3n) {{
-    case 0n:
-      return [newOffset, index, 0];
-    case 1n:
-      return [newOffset, index, 8];
-    case 2n:
-      return [newOffset, index, varintSizeFromFirstByte(dataView.getUint8(newOffset))];
+): [number, bigint, number, number] {{
+  let newOffset: number;
+  let index: bigint;
+  let sizeMode: number;
+
+  if (varintSizeFromFirstByte(dataView.getUint8(offset)) < 8) {{
+    // The tag fits in a `number`, so only the index needs to be converted to a `bigint`.
+    let tag: number;
+    [newOffset, tag] = deserializeVarintNumber(dataView, offset);
+    sizeMode = tag % 4;
+    index = BigInt((tag - sizeMode) / 4);
+  }} else {{
+    let tag: bigint;
+    [newOffset, tag] = deserializeVarint(dataView, offset);
+    sizeMode = Number(tag & 3n);
+    index = tag >> 2n;
+  }}
+
+  switch (sizeMode) {{
+    case 0:
+      return [newOffset, index, 0, sizeMode];
+    case 1:
+      return [newOffset, index, 8, sizeMode];
+    case 2:
+      return [
+        newOffset,
+        index,
+        varintSizeFromFirstByte(dataView.getUint8(newOffset)),
+        sizeMode,
+      ];
     default: {{
-      const [newNewOffset, sizeValue] = deserializeVarint(dataView, newOffset);
-      return [newNewOffset, index, Number(sizeValue)];
+      const [newNewOffset, payloadSize] = deserializeVarintNumber(
+        dataView,
+        newOffset,
+      );
+      return [newNewOffset, index, payloadSize, sizeMode];
     }}
   }}
 }}
 
-const dataView64 = new DataView(new ArrayBuffer(8));
-export const missingFieldsErrorMessage = 'Struct missing one or more required field(s).';
-export const textEncoder = new TextEncoder();
-export const textDecoder = new TextDecoder('utf-8', {{ fatal: true, ignoreBOM: true }});",
-    )
-}
+export function unknownFieldSize(field: UnknownField): number {{
+  const sizeMode = field.sizeMode & 3;
+  const tagSize = varintSizeFromValue((field.index << 2n) | BigInt(sizeMode));
 
-// Write a file with the code generated from an individual schema.
-fn write_schema_file<T: Write>(
-    buffer: &mut T,
-    typical_version: &str,
-    namespace: &schema::Namespace,
-    schema: &schema::Schema,
-) -> Result<(), fmt::Error> {
-    write_generated_file_header(buffer, typical_version)?;
+  if (sizeMode === 3) {{
+    return (
+      tagSize +
+      varintSizeFromValue(BigInt(field.payload.byteLength)) +
+      field.payload.byteLength
+    );
+  }}
 
-    if schema.declarations.is_empty() {
-        return Ok(());
-    }
+  return tagSize + field.payload.byteLength;
+}}
 
-    writeln!(buffer)?;
-    write_common_import(buffer, namespace)?;
+export function serializeUnknownField(
+  dataView: DataView,
+  offset: number,
+  field: UnknownField,
+): number {{
+  const sizeMode = field.sizeMode & 3;
+  offset = serializeVarint(dataView, offset, (field.index << 2n) | BigInt(sizeMode));
 
-    if !schema.imports.is_empty() {
-        writeln!(buffer)?;
+  if (sizeMode === 3) {{
+    offset = serializeVarint(dataView, offset, BigInt(field.payload.byteLength));
+  }}
 
-        for (name, import) in &schema.imports {
-            // The `unwrap` is safe due to [ref:namespace_populated].
-            let import_namespace = import.namespace.clone().unwrap();
-            let binding_name = import_binding_name(name);
-            let specifier = relative_module_specifier(
-                &namespace_parent_components(namespace),
-                &import_namespace
-                    .components
-                    .iter()
-                    .map(Identifier::snake_case)
-                    .collect::<Vec<_>>(),
-            );
-            writeln!(buffer, "import * as {binding_name} from '{specifier}';")?;
-        }
-    }
+  const sourceBuffer = new Uint8Array(field.payload);
+  const targetBuffer = new Uint8Array(
+    dataView.buffer as ArrayBuffer,
+    dataView.byteOffset,
+    dataView.byteLength,
+  );
+  targetBuffer.set(sourceBuffer, offset);
+  return offset + sourceBuffer.byteLength;
+}}
 
-    writeln!(buffer)?;
-    write_schema(buffer, 0, schema)?;
+export function checkUnknownField(
+  value: unknown,
+  hasSizeMode: boolean,
+): string | undefined {{
+  if (typeof value !== 'object' || value === null) {{
+    return ' must be an object';
+  }}
 
-    Ok(())
-}
+  const field = value as any;
 
-// Emit code to import the shared library.
-fn write_common_import<T: Write>(
-    buffer: &mut T,
-    namespace: &schema::Namespace,
-) -> Result<(), fmt::Error> {
-    let specifier = relative_module_specifier(
-        &namespace_parent_components(namespace),
-        &[COMMON_FILE_STEM.to_owned()],
-    );
+  if (typeof field.index !== 'bigint' || field.index < 0n) {{
+    return '.index must be a non-negative bigint';
+  }}
 
-    writeln!(
-        buffer,
-        "import {{
-  dataViewFromDeserializable,
-  deserializeFieldHeader,
-  deserializeVarint,
-  fieldHeaderSize,
-  missingFieldsErrorMessage,
-  serializeFieldHeader,
-  serializeVarint,
-  textDecoder,
-  textEncoder,
-  unreachable,
-  varintSizeFromValue,
-  zigzagDecode,
-  zigzagEncode,
-}} from '{specifier}';
-import type {{ Deserializable }} from '{specifier}';",
-    )
-}
+  if (hasSizeMode && ![0, 1, 2, 3].includes(field.sizeMode)) {{
+    return '.sizeMode must be 0, 1, 2, or 3';
+  }}
 
-// Write a schema, including a trailing line break if there was anything to render.
-#[allow(clippy::too_many_lines)]
-fn write_schema<T: Write>(
-    buffer: &mut T,
-    indentation: usize,
-    schema: &schema::Schema,
-) -> Result<(), fmt::Error> {
-    // Write the declarations.
-    let mut iter = schema.declarations.iter().peekable();
-    while let Some(declaration) = iter.next() {
-        let function_names = DeclarationFunctionNames::new(&declaration.name);
+  if (!(field.payload instanceof ArrayBuffer)) {{
+    return '.payload must be an ArrayBuffer';
+  }}
 
-        match &declaration.variant {
-            schema::DeclarationVariant::Struct => {
-                write_struct(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    Atlas,
-                )?;
+  return undefined;
+}}
 
-                writeln!(buffer)?;
+export function safeNumberFromBigint(value: bigint): number {{
+  if (value > 9_007_199_254_740_991n || value < -9_007_199_254_740_991n) {{
+    throw new Error('Integer cannot be represented exactly as a number.');
+  }}
 
-                write_struct(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    Out,
-                )?;
+  return Number(value);
+}}
 
-                writeln!(buffer)?;
+export function clampedNumberFromBigint(value: bigint): number {{
+  if (value > 9_007_199_254_740_991n) {{
+    return Number.MAX_SAFE_INTEGER;
+  }}
 
-                write_struct(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    In,
-                )?;
+  if (value < -9_007_199_254_740_991n) {{
+    return Number.MIN_SAFE_INTEGER;
+  }}
 
-                writeln!(buffer)?;
+  return Number(value);
+}}
 
-                write_struct_atlas_function(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    &function_names.atlas,
-                )?;
+export const missingFieldsErrorMessage = 'Struct missing one or more required field(s).';
+export const textEncoder = new TextEncoder();
+export const textDecoder = new TextDecoder('utf-8', {{ fatal: true, ignoreBOM: true }});",
+    )?;
 
-                writeln!(buffer)?;
+    if options.containers {
+        writeln!(
+            buffer,
+            "
+function makeCrc32Table(): Uint32Array {{
+  const table = new Uint32Array(256);
 
-                write_size_function(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    &function_names.size,
-                    &function_names.atlas,
-                )?;
+  for (let index = 0; index < 256; index += 1) {{
+    let value = index;
 
-                writeln!(buffer)?;
+    for (let bit = 0; bit < 8; bit += 1) {{
+      value = (value & 1) === 0 ? value >>> 1 : (value >>> 1) ^ 0xedb8_8320;
+    }}
 
-                write_struct_serialize_with_atlas_unsafe_function(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
-                    &function_names.serialize_with_atlas_unsafe,
-                )?;
+    table[index] = value;
+  }}
 
-                writeln!(buffer)?;
+  return table;
+}}
 
-                write_struct_deserialize_unsafe_function(
-                    buffer,
-                    indentation,
-                    &declaration.name,
-                    &declaration.fields,
+const crc32Table = makeCrc32Table();
+
+function crc32(bytes: Uint8Array): number {{
+  let crc = 0xffff_ffff;
+
+  for (let i = 0; i < bytes.byteLength; i += 1) {{
+    crc = crc32Table[(crc ^ bytes[i]) & 0xff] ^ (crc >>> 8);
+  }}
+
+  return (crc ^ 0xffff_ffff) >>> 0;
+}}
+
+// The size of a frame with the given payload
+function frameSize(payload: Uint8Array, checksum: boolean): number {{
+  return (
+    varintSizeFromValue(BigInt(payload.byteLength)) + payload.byteLength + (checksum ? 4 : 0)
+  );
+}}
+
+// Write a frame with the given payload, and return the offset after it.
+function serializeFrame(
+  dataView: DataView,
+  offset: number,
+  payload: Uint8Array,
+  checksum: boolean,
+): number {{
+  const payloadStart = serializeVarint(dataView, offset, BigInt(payload.byteLength));
+  const payloadEnd = payloadStart + payload.byteLength;
+  new Uint8Array(dataView.buffer as ArrayBuffer).set(payload, payloadStart);
+
+  if (!checksum) {{
+    return payloadEnd;
+  }}
+
+  dataView.setUint32(payloadEnd, crc32(payload), true);
+  return payloadEnd + 4;
+}}
+
+export function serializeFramed<T>(
+  type: {{ serialize: (message: T) => ArrayBuffer }},
+  message: T,
+  checksum: boolean,
+): ArrayBuffer {{
+  const payload = new Uint8Array(type.serialize(message));
+  const arrayBuffer = new ArrayBuffer(frameSize(payload, checksum));
+  serializeFrame(new DataView(arrayBuffer), 0, payload, checksum);
+  return arrayBuffer;
+}}
+
+// The bytes which have been received from a stream but not yet decoded are the ones from `start`
+// to `end`.
+type StreamBuffer = {{
+  reader: ReadableStreamDefaultReader<Uint8Array>;
+  bytes: Uint8Array;
+  start: number;
+  end: number;
+}};
+
+// Receive chunks until at least `size` bytes are buffered, and return whether that succeeded
+// before the stream ended.
+async function fillStreamBuffer(streamBuffer: StreamBuffer, size: number): Promise<boolean> {{
+  while (streamBuffer.end - streamBuffer.start < size) {{
+    const result = await streamBuffer.reader.read();
+
+    if (result.done) {{
+      return false;
+    }}
+
+    const chunk = result.value;
+
+    // Make room for the chunk by discarding the bytes which have been decoded, and grow the
+    // buffer if that isn't enough.
+    if (streamBuffer.end + chunk.byteLength > streamBuffer.bytes.byteLength) {{
+      const bufferedSize = streamBuffer.end - streamBuffer.start;
+
+      if (bufferedSize + chunk.byteLength > streamBuffer.bytes.byteLength) {{
+        const newBytes = new Uint8Array(
+          Math.max(streamBuffer.bytes.byteLength * 2, bufferedSize + chunk.byteLength),
+        );
+        newBytes.set(streamBuffer.bytes.subarray(streamBuffer.start, streamBuffer.end));
+        streamBuffer.bytes = newBytes;
+      }} else {{
+        streamBuffer.bytes.copyWithin(0, streamBuffer.start, streamBuffer.end);
+      }}
+
+      streamBuffer.start = 0;
+      streamBuffer.end = bufferedSize;
+    }}
+
+    streamBuffer.bytes.set(chunk, streamBuffer.end);
+    streamBuffer.end += chunk.byteLength;
+  }}
+
+  return true;
+}}
+
+// Decode the next frame and return its payload, or `undefined` if the stream ended before it. The
+// payload is only valid until the stream buffer is used again.
+async function deserializeFrame(
+  streamBuffer: StreamBuffer,
+  checksum: boolean,
+): Promise<Uint8Array | undefined> {{
+  if (!(await fillStreamBuffer(streamBuffer, 1))) {{
+    return undefined;
+  }}
+
+  const sizeSize = varintSizeFromFirstByte(streamBuffer.bytes[streamBuffer.start]);
+
+  if (!(await fillStreamBuffer(streamBuffer, sizeSize))) {{
+    throw new Error('Stream ended in the middle of a frame.');
+  }}
+
+  const [, payloadSize] = deserializeVarintNumber(
+    new DataView(streamBuffer.bytes.buffer),
+    streamBuffer.start,
+  );
+  const checksumSize = checksum ? 4 : 0;
+
+  if (!(await fillStreamBuffer(streamBuffer, sizeSize + payloadSize + checksumSize))) {{
+    throw new Error('Stream ended in the middle of a frame.');
+  }}
+
+  const payloadStart = streamBuffer.start + sizeSize;
+  const payloadEnd = payloadStart + payloadSize;
+  const payload = streamBuffer.bytes.subarray(payloadStart, payloadEnd);
+
+  if (
+    checksumSize > 0 &&
+    crc32(payload) !== new DataView(streamBuffer.bytes.buffer).getUint32(payloadEnd, true)
+  ) {{
+    throw new Error('Frame checksum mismatch.');
+  }}
+
+  streamBuffer.start = payloadEnd + checksumSize;
+  return payload;
+}}
+
+// Decode the remaining frames in a stream as messages.
+async function* deserializeFrames<T>(
+  type: {{ deserialize: (bytes: Deserializable) => T | Error }},
+  streamBuffer: StreamBuffer,
+  checksum: boolean,
+): AsyncGenerator<T, void, undefined> {{
+  while (true) {{
+    const payload = await deserializeFrame(streamBuffer, checksum);
+
+    if (payload === undefined) {{
+      return;
+    }}
+
+    const message = type.deserialize(payload);
+
+    if (message instanceof Error) {{
+      throw message;
+    }}
+
+    yield message;
+  }}
+}}
+
+export async function* deserializeFramed<T>(
+  type: {{ deserialize: (bytes: Deserializable) => T | Error }},
+  stream: ReadableStream<Uint8Array>,
+  checksum: boolean,
+): AsyncGenerator<T, void, undefined> {{
+  const streamBuffer = {{
+    reader: stream.getReader(),
+    bytes: new Uint8Array(1024),
+    start: 0,
+    end: 0,
+  }};
+
+  try {{
+    yield* deserializeFrames(type, streamBuffer, checksum);
+  }} finally {{
+    streamBuffer.reader.releaseLock();
+  }}
+}}
+
+const containerMagic = new Uint8Array([0x89, 0x54, 0x59, 0x50, 0x49, 0x43, 0x41, 0x4c]);
+const containerVersion = 1;
+const containerChecksums = 0b01;
+const containerSchema = 0b10;
+const containerHeaderSize = 18;
+
+export function serializeContainerHeader(
+  type: {{ fingerprint: bigint; normalForm: string; schemaName: string }},
+  checksum: boolean,
+  embedSchema: boolean,
+): ArrayBuffer {{
+  const frames = [textEncoder.encode(type.normalForm)];
+  if (embedSchema) {{
+    frames.push(textEncoder.encode(schema), textEncoder.encode(type.schemaName));
+  }}
+  let size = containerHeaderSize;
+  for (const frame of frames) {{
+    size += frameSize(frame, checksum);
+  }}
+
+  const arrayBuffer = new ArrayBuffer(size);
+  const dataView = new DataView(arrayBuffer);
+  new Uint8Array(arrayBuffer).set(containerMagic);
+  dataView.setUint8(8, containerVersion);
+  dataView.setUint8(
+    9,
+    (checksum ? containerChecksums : 0) | (embedSchema ? containerSchema : 0),
+  );
+  dataView.setBigUint64(10, type.fingerprint, true);
+
+  let offset = containerHeaderSize;
+  for (const frame of frames) {{
+    offset = serializeFrame(dataView, offset, frame, checksum);
+  }}
+
+  return arrayBuffer;
+}}
+
+export async function* deserializeContainer<T>(
+  type: {{
+    deserialize: (bytes: Deserializable) => T | Error;
+    fingerprint: bigint;
+    normalForm: string;
+  }},
+  stream: ReadableStream<Uint8Array>,
+): AsyncGenerator<T, void, undefined> {{
+  const streamBuffer = {{
+    reader: stream.getReader(),
+    bytes: new Uint8Array(1024),
+    start: 0,
+    end: 0,
+  }};
+
+  try {{
+    if (!(await fillStreamBuffer(streamBuffer, containerHeaderSize))) {{
+      throw new Error('Invalid container header.');
+    }}
+
+    const header = streamBuffer.bytes.subarray(
+      streamBuffer.start,
+      streamBuffer.start + containerHeaderSize,
+    );
+    const dataView = new DataView(header.buffer, header.byteOffset, header.byteLength);
+    const flags = header[9];
+
+    if (
+      containerMagic.some((byte, index) => header[index] !== byte) ||
+      header[8] !== containerVersion ||
+      (flags & ~(containerChecksums | containerSchema)) !== 0
+    ) {{
+      throw new Error('Invalid container header.');
+    }}
+
+    const fingerprint = dataView.getBigUint64(10, true);
+    streamBuffer.start += containerHeaderSize;
+    const checksum = (flags & containerChecksums) !== 0;
+    const normalForm = await deserializeFrame(streamBuffer, checksum);
+
+    if (normalForm === undefined) {{
+      throw new Error('Stream ended in the middle of a frame.');
+    }}
+
+    if (
+      fingerprint !== type.fingerprint &&
+      !readable(textDecoder.decode(normalForm), type.normalForm)
+    ) {{
+      throw new Error('Container holds messages of an incompatible type.');
+    }}
+
+    // Skip the embedded schema and the name of the root type.
+    if ((flags & containerSchema) !== 0) {{
+      for (let i = 0; i < 2; i += 1) {{
+        if ((await deserializeFrame(streamBuffer, checksum)) === undefined) {{
+          throw new Error('Stream ended in the middle of a frame.');
+        }}
+      }}
+    }}
+
+    yield* deserializeFrames(type, streamBuffer, checksum);
+  }} finally {{
+    streamBuffer.reader.releaseLock();
+  }}
+}}
+
+// A declaration in a normal form. Each field is a list of its index, rule, and type.
+type NormalDeclaration = {{
+  choice: boolean;
+  open: boolean;
+  fields: string[][];
+}};
+
+// Parse a normal form, which has one line per declaration. Each line consists of `struct` or
+// `choice`, optionally `open`, and then the fields as `index:rule:type`.
+function parseNormalForm(normalForm: string): NormalDeclaration[] | undefined {{
+  const lines = normalForm.split('\\n');
+  if (lines[lines.length - 1] === '') {{
+    lines.pop();
+  }}
+
+  const declarations = [];
+  for (const line of lines) {{
+    const words = line.split(' ');
+    if (words[0] !== 'struct' && words[0] !== 'choice') {{
+      return undefined;
+    }}
+
+    const open = words[1] === 'open';
+    const fields = [];
+    for (const word of words.slice(open ? 2 : 1)) {{
+      const [index, rule, ...type] = word.split(':');
+      if (rule === undefined || type.length === 0) {{
+        return undefined;
+      }}
+
+      fields.push([index, rule, type.join(':')]);
+    }}
+
+    declarations.push({{ choice: words[0] === 'choice', open, fields }});
+  }}
+
+  return declarations;
+}}
+
+// Determine whether messages written with the normal form `writer` can be read with the normal
+// form `reader`.
+function readable(writer: string, reader: string): boolean {{
+  const writers = parseNormalForm(writer);
+  const readers = parseNormalForm(reader);
+
+  return (
+    writers !== undefined &&
+    readers !== undefined &&
+    declarationReadable(writers, readers, 0, 0, new Set())
+  );
+}}
+
+// Determine whether messages of a declaration in one normal form can be read as a declaration in
+// another. Pairs which are already being compared are assumed to be readable, so recursive types
+// don't lead to infinite recursion.
+function declarationReadable(
+  writers: NormalDeclaration[],
+  readers: NormalDeclaration[],
+  writerNumber: number,
+  readerNumber: number,
+  visited: Set<string>,
+): boolean {{
+  const key = `${{writerNumber}} ${{readerNumber}}`;
+  if (visited.has(key)) {{
+    return true;
+  }}
+
+  visited.add(key);
+
+  const writer = writers[writerNumber];
+  const reader = readers[readerNumber];
+  if (writer === undefined || reader === undefined) {{
+    return false;
+  }}
+
+  if (writer.choice !== reader.choice) {{
+    // A struct with a single required field is encoded in the same way as a choice with just
+    // that field.
+    const [writerField] = writer.fields;
+    const [readerField] = reader.fields;
+    return (
+      writer.fields.length === 1 &&
+      reader.fields.length === 1 &&
+      writerField[0] === readerField[0] &&
+      writerField[1] === 'required' &&
+      readerField[1] === 'required' &&
+      typeReadable(writers, readers, writerField[2], readerField[2], visited)
+    );
+  }}
+
+  if (reader.choice) {{
+    // The reader must recognize every field the writer might write, unless the writer provides a
+    // fallback or the reader preserves unknown fields.
+    return writer.fields.every(([index, writerRule, writerType]) => {{
+      const readerField = reader.fields.find((field) => field[0] === index);
+      return readerField === undefined
+        ? reader.open || writerRule !== 'required'
+        : typeReadable(writers, readers, writerType, readerField[2], visited);
+    }});
+  }}
+
+  // The writer must write every field which the reader requires.
+  return reader.fields.every(([index, readerRule, readerType]) => {{
+    const writerField = writer.fields.find((field) => field[0] === index);
+    return writerField === undefined
+      ? readerRule !== 'required'
+      : (readerRule !== 'required' || writerField[1] !== 'optional') &&
+          typeReadable(writers, readers, writerField[2], readerType, visited);
+  }});
+}}
+
+// Determine whether values of a type in one normal form can be read as a type in another.
+function typeReadable(
+  writers: NormalDeclaration[],
+  readers: NormalDeclaration[],
+  writer: string,
+  reader: string,
+  visited: Set<string>,
+): boolean {{
+  function isArray(type: string): boolean {{
+    return type.length > 1 && type.startsWith('[') && type.endsWith(']');
+  }}
+
+  function isReference(type: string): boolean {{
+    return type.length > 1 && type.startsWith('#') && !Number.isNaN(Number(type.slice(1)));
+  }}
+
+  if (isArray(writer) && isArray(reader)) {{
+    return typeReadable(writers, readers, writer.slice(1, -1), reader.slice(1, -1), visited);
+  }}
+
+  if (isReference(writer) && isReference(reader)) {{
+    return declarationReadable(
+      writers,
+      readers,
+      Number(writer.slice(1)),
+      Number(reader.slice(1)),
+      visited,
+    );
+  }}
+
+  return writer === reader;
+}}",
+        )?;
+
+        writeln!(buffer)?;
+        write_embedded_schema(buffer, embedded_schema)?;
+    }
+
+    Ok(())
+}
+
+// Write the schema which is embedded in containers. It's a template literal, so the characters
+// which are special in template literals are escaped.
+fn write_embedded_schema<T: Write>(
+    buffer: &mut T,
+    embedded_schema: &container::EmbeddedSchema,
+) -> Result<(), fmt::Error> {
+    write!(buffer, "export const schema = `")?;
+    for c in embedded_schema.text.chars() {
+        if matches!(c, '\\' | '`' | '$') {
+            write!(buffer, "\\")?;
+        }
+        write!(buffer, "{c}")?;
+    }
+    writeln!(buffer, "`;")
+}
+
+// Write a file with the code generated from an individual schema.
+fn write_schema_file<T: Write>(
+    buffer: &mut T,
+    typical_version: &str,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    options: &Options,
+    import_extension: &str,
+    embedded_schema: &container::EmbeddedSchema,
+) -> Result<(), fmt::Error> {
+    write_generated_file_header(buffer, typical_version)?;
+
+    if schema.declarations.is_empty() && schema.services.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer)?;
+    write_common_import(
+        buffer,
+        namespace,
+        import_extension,
+        !schema.services.is_empty(),
+        schema.declarations.iter().any(|declaration| {
+            declaration.open && matches!(declaration.variant, schema::DeclarationVariant::Struct)
+        }),
+        schema
+            .declarations
+            .iter()
+            .any(|declaration| declaration.open),
+        schema
+            .declarations
+            .iter()
+            .any(|declaration| {
+                !declaration_number_fields(options, namespace, declaration)
+                    .names
+                    .is_empty()
+            })
+            .then_some(options.number_overflow),
+    )?;
+
+    if !schema.imports.is_empty() {
+        writeln!(buffer)?;
+
+        for (name, import) in &schema.imports {
+            // The `unwrap` is safe due to [ref:namespace_populated].
+            let import_namespace = import.namespace.clone().unwrap();
+            let binding_name = import_binding_name(name);
+            let specifier = relative_module_specifier(
+                &namespace_parent_components(namespace),
+                &import_namespace
+                    .components
+                    .iter()
+                    .map(Identifier::snake_case)
+                    .collect::<Vec<_>>(),
+            );
+            writeln!(
+                buffer,
+                "import * as {binding_name} from '{specifier}{import_extension}';",
+            )?;
+        }
+    }
+
+    writeln!(buffer)?;
+    write_schema(buffer, 0, namespace, schema, options, embedded_schema)?;
+
+    Ok(())
+}
+
+// Emit code to import the shared library.
+fn write_common_import<T: Write>(
+    buffer: &mut T,
+    namespace: &schema::Namespace,
+    import_extension: &str,
+    import_transport: bool,
+    import_unknown_field: bool,
+    import_check_unknown_field: bool,
+    number_overflow: Option<NumberOverflow>,
+) -> Result<(), fmt::Error> {
+    let specifier = relative_module_specifier(
+        &namespace_parent_components(namespace),
+        &[COMMON_FILE_STEM.to_owned()],
+    ) + import_extension;
+
+    let mut names = vec![
+        "dataViewFromDeserializable",
+        "deserializeFieldHeader",
+        "deserializeVarint",
+        "deserializeVarintNumber",
+        "fieldHeaderSize",
+        "missingFieldsErrorMessage",
+        "payloadIntegerEncoded",
+        "serializeFieldHeader",
+        "serializeUnknownField",
+        "serializeVarint",
+        "textDecoder",
+        "textEncoder",
+        "unknownFieldSize",
+        "unreachable",
+        "varintSizeFromValue",
+        "zigzagDecode",
+        "zigzagEncode",
+    ];
+    if import_check_unknown_field {
+        names.push("checkUnknownField");
+    }
+    if let Some(number_overflow) = number_overflow {
+        names.push(number_overflow.conversion_function());
+    }
+    names.sort_unstable();
+
+    writeln!(buffer, "import {{")?;
+    for name in names {
+        writeln!(buffer, "{INDENTATION}{name},")?;
+    }
+    writeln!(
+        buffer,
+        "}} from '{specifier}';
+import type {{ Deserializable{}{} }} from '{specifier}';",
+        if import_transport { ", Transport" } else { "" },
+        if import_unknown_field {
+            ", UnknownField"
+        } else {
+            ""
+        },
+    )
+}
+
+// Write a schema, including a trailing line break if there was anything to render.
+#[allow(clippy::too_many_lines)]
+fn write_schema<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    options: &Options,
+    embedded_schema: &container::EmbeddedSchema,
+) -> Result<(), fmt::Error> {
+    // Write the declarations.
+    let mut iter = schema.declarations.iter().peekable();
+    while let Some(declaration) = iter.next() {
+        let function_names = DeclarationFunctionNames::new(&declaration.name);
+        let number_fields = declaration_number_fields(options, namespace, declaration);
+
+        match &declaration.variant {
+            schema::DeclarationVariant::Struct => {
+                write_struct(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    Atlas,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    Out,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    In,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct_atlas_function(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    &function_names.atlas,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_size_function(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &function_names.size,
+                    &function_names.atlas,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct_serialize_with_atlas_unsafe_function(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    &function_names.serialize_with_atlas_unsafe,
+                )?;
+
+                writeln!(buffer)?;
+
+                write_struct_deserialize_unsafe_function(
+                    buffer,
+                    indentation,
+                    &declaration.name,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     &function_names.deserialize_unsafe,
                 )?;
 
@@ -666,6 +1617,7 @@ fn write_schema<T: Write>(
                     &declaration.variant,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
                     &function_names,
                 )?;
 
@@ -678,6 +1630,18 @@ fn write_schema<T: Write>(
                     &function_names.deserialize,
                     &function_names.deserialize_unsafe,
                 )?;
+
+                writeln!(buffer)?;
+
+                write_check_out_function(
+                    buffer,
+                    indentation,
+                    &declaration.variant,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    &function_names.check_out,
+                )?;
             }
             schema::DeclarationVariant::Choice => {
                 write_choice(
@@ -685,6 +1649,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     Atlas,
                 )?;
 
@@ -695,6 +1661,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     Out,
                 )?;
 
@@ -705,6 +1673,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     In,
                 )?;
 
@@ -715,6 +1685,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     &function_names.atlas,
                 )?;
 
@@ -725,6 +1697,7 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
                     &function_names.size,
                     &function_names.atlas,
                 )?;
@@ -736,6 +1709,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     &function_names.serialize_with_atlas_unsafe,
                 )?;
 
@@ -746,6 +1721,8 @@ fn write_schema<T: Write>(
                     indentation,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
+                    &number_fields,
                     &function_names.deserialize_unsafe,
                 )?;
 
@@ -757,6 +1734,7 @@ fn write_schema<T: Write>(
                     &declaration.variant,
                     &declaration.name,
                     &declaration.fields,
+                    declaration.open,
                     &function_names,
                 )?;
 
@@ -769,6 +1747,18 @@ fn write_schema<T: Write>(
                     &function_names.deserialize,
                     &function_names.deserialize_unsafe,
                 )?;
+
+                writeln!(buffer)?;
+
+                write_check_out_function(
+                    buffer,
+                    indentation,
+                    &declaration.variant,
+                    &declaration.fields,
+                    declaration.open,
+                    &number_fields,
+                    &function_names.check_out,
+                )?;
             }
         }
 
@@ -797,9 +1787,44 @@ fn write_schema<T: Write>(
         writeln!(buffer, "serialize: {},", function_names.serialize)?;
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "deserialize: {},", function_names.deserialize)?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "checkOut: {},", function_names.check_out)?;
+        if options.containers {
+            let embedded_declaration =
+                &embedded_schema.declarations[&(namespace.clone(), declaration.name.clone())];
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(
+                buffer,
+                "fingerprint: {:#x}n,",
+                embedded_declaration.fingerprint,
+            )?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(
+                buffer,
+                "normalForm: '{}',",
+                embedded_declaration.normal_form.replace('\n', "\\n"),
+            )?;
+            write_indentation(buffer, indentation + 1)?;
+            write!(buffer, "schemaName: '")?;
+            write_identifier(buffer, &embedded_declaration.name, Pascal, None)?;
+            writeln!(buffer, "',")?;
+        }
         write_indentation(buffer, indentation)?;
         writeln!(buffer, "}};")?;
 
+        writeln!(buffer)?;
+        write_guard_functions(buffer, indentation, &declaration.name, &function_names)?;
+
+        if iter.peek().is_some() || !schema.services.is_empty() {
+            writeln!(buffer)?;
+        }
+    }
+
+    // Write the services.
+    let mut iter = schema.services.iter().peekable();
+    while let Some(service) = iter.next() {
+        write_service(buffer, indentation, service)?;
+
         if iter.peek().is_some() {
             writeln!(buffer)?;
         }
@@ -808,6 +1833,170 @@ fn write_schema<T: Write>(
     Ok(())
 }
 
+// Write the server interface, dispatcher, and client stub for a service.
+#[allow(clippy::too_many_lines)]
+fn write_service<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    service: &schema::Service,
+) -> Result<(), fmt::Error> {
+    let server_name = service.name.join(&"Server".into());
+    let client_name = service.name.join(&"Client".into());
+
+    // Write the server interface. Handlers may respond synchronously or asynchronously.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "export interface ")?;
+    write_identifier(buffer, &server_name, Pascal, None)?;
+    writeln!(buffer, " {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 1)?;
+        write_identifier(buffer, &method.name, Camel, None)?;
+        write!(buffer, "(request: ")?;
+        write_type(buffer, &method.request.variant, In)?;
+        write!(buffer, "): ")?;
+        write_type(buffer, &method.response.variant, Out)?;
+        write!(buffer, " | Promise<")?;
+        write_type(buffer, &method.response.variant, Out)?;
+        writeln!(buffer, ">;")?;
+    }
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    writeln!(buffer)?;
+
+    // Write the dispatcher, which routes a request to the appropriate method based on its index.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "export async function ")?;
+    write_identifier(
+        buffer,
+        &Identifier::from("dispatch").join(&service.name),
+        Camel,
+        None,
+    )?;
+    writeln!(buffer, "(")?;
+    write_indentation(buffer, indentation + 1)?;
+    write!(buffer, "server: ")?;
+    write_identifier(buffer, &server_name, Pascal, None)?;
+    writeln!(buffer, ",")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "methodIndex: bigint,")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "request: Deserializable,")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "): Promise<ArrayBuffer> {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "switch (methodIndex) {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "case {}n: {{", method.index)?;
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "const message = ")?;
+        write_method_type_object(buffer, &method.request)?;
+        writeln!(buffer, ".deserialize(request);")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "if (message instanceof Error) {{")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "throw message;")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "return ")?;
+        write_method_type_object(buffer, &method.response)?;
+        write!(buffer, ".serialize(await server.")?;
+        write_identifier(buffer, &method.name, Camel, None)?;
+        writeln!(buffer, "(message));")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "default:")?;
+    write_indentation(buffer, indentation + 3)?;
+    writeln!(buffer, "throw new Error('Unknown method index.');")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    writeln!(buffer)?;
+
+    // Write the client interface.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "export interface ")?;
+    write_identifier(buffer, &client_name, Pascal, None)?;
+    writeln!(buffer, " {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 1)?;
+        write_identifier(buffer, &method.name, Camel, None)?;
+        write!(buffer, "(request: ")?;
+        write_type(buffer, &method.request.variant, Out)?;
+        write!(buffer, "): Promise<")?;
+        write_type(buffer, &method.response.variant, In)?;
+        writeln!(buffer, ">;")?;
+    }
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+
+    writeln!(buffer)?;
+
+    // Write the client stub, which sends requests through a user-provided transport.
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "export function ")?;
+    write_identifier(buffer, &client_name, Camel, None)?;
+    write!(buffer, "(transport: Transport): ")?;
+    write_identifier(buffer, &client_name, Pascal, None)?;
+    writeln!(buffer, " {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "return {{")?;
+    for method in &service.methods {
+        write_indentation(buffer, indentation + 2)?;
+        write!(buffer, "async ")?;
+        write_identifier(buffer, &method.name, Camel, None)?;
+        write!(buffer, "(request: ")?;
+        write_type(buffer, &method.request.variant, Out)?;
+        write!(buffer, "): Promise<")?;
+        write_type(buffer, &method.response.variant, In)?;
+        writeln!(buffer, "> {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        write!(buffer, "const response = ")?;
+        write_method_type_object(buffer, &method.response)?;
+        writeln!(buffer, ".deserialize(")?;
+        write_indentation(buffer, indentation + 4)?;
+        write!(buffer, "await transport({}n, ", method.index)?;
+        write_method_type_object(buffer, &method.request)?;
+        writeln!(buffer, ".serialize(request)),")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, ");")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "if (response instanceof Error) {{")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "throw response;")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "return response;")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}},")?;
+    }
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}};")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the name of the exported object holding the functions for the type of a method request or
+// response. The validator guarantees that such types refer to declarations.
+fn write_method_type_object<T: Write>(
+    buffer: &mut T,
+    r#type: &schema::Type,
+) -> Result<(), fmt::Error> {
+    if let schema::TypeVariant::Custom(import, name) = &r#type.variant {
+        write_custom_type(buffer, import.as_ref(), name, None)
+    } else {
+        // Impossible since the schema was validated.
+        panic!()
+    }
+}
+
 // Write the function that computes a struct's encoded size atlas.
 #[allow(clippy::too_many_lines)]
 fn write_struct_atlas_function<T: Write>(
@@ -815,6 +2004,8 @@ fn write_struct_atlas_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -847,10 +2038,13 @@ fn write_struct_atlas_function<T: Write>(
         write!(buffer, "let payloadAtlas: ")?;
         write_type(buffer, &field.r#type.variant, Atlas)?;
         writeln!(buffer, ";")?;
-        write_indentation(buffer, indentation + 2)?;
-        write!(buffer, "const payload = message.")?;
-        write_identifier(buffer, &field.name, Camel, None)?;
-        writeln!(buffer, ";")?;
+        write_payload_from_message(
+            buffer,
+            indentation + 2,
+            field,
+            number_fields,
+            matches!(field.rule, schema::Rule::Optional),
+        )?;
         let conditional_indentation = match field.rule {
             schema::Rule::Asymmetric | schema::Rule::Required => indentation + 2,
             schema::Rule::Optional => {
@@ -882,6 +2076,19 @@ fn write_struct_atlas_function<T: Write>(
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "}}")?;
     }
+    if open {
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "if (message.$unknown !== undefined) {{")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "for (const unknownField of message.$unknown) {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "size += unknownFieldSize(unknownField);")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "}}")?;
+    }
     writeln!(buffer)?;
     write_indentation(buffer, indentation + 1)?;
     writeln!(buffer, "return {{")?;
@@ -907,6 +2114,8 @@ fn write_struct_serialize_with_atlas_unsafe_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -928,10 +2137,13 @@ fn write_struct_serialize_with_atlas_unsafe_function<T: Write>(
     for field in fields {
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "{{")?;
-        write_indentation(buffer, indentation + 2)?;
-        write!(buffer, "const payload = message.")?;
-        write_identifier(buffer, &field.name, Camel, None)?;
-        writeln!(buffer, ";")?;
+        write_payload_from_message(
+            buffer,
+            indentation + 2,
+            field,
+            number_fields,
+            matches!(field.rule, schema::Rule::Optional),
+        )?;
         write_indentation(buffer, indentation + 2)?;
         write!(buffer, "const payloadAtlas = atlas.")?;
         write_identifier(buffer, &field.name, Camel, None)?;
@@ -972,6 +2184,22 @@ fn write_struct_serialize_with_atlas_unsafe_function<T: Write>(
         writeln!(buffer, "}}")?;
         writeln!(buffer)?;
     }
+    if open {
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "if (message.$unknown !== undefined) {{")?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "for (const unknownField of message.$unknown) {{")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(
+            buffer,
+            "offset = serializeUnknownField(dataView, offset, unknownField);",
+        )?;
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "}}")?;
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "}}")?;
+        writeln!(buffer)?;
+    }
     write_indentation(buffer, indentation + 1)?;
     writeln!(buffer, "return offset;")?;
     write_indentation(buffer, indentation)?;
@@ -985,6 +2213,8 @@ fn write_struct_deserialize_unsafe_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1003,78 +2233,98 @@ fn write_struct_deserialize_unsafe_function<T: Write>(
             write!(buffer, "let $")?;
             write_identifier(buffer, &field.name, Camel, None)?;
             write!(buffer, ": ")?;
-            write_type(buffer, &field.r#type.variant, In)?;
+            write_field_type(
+                buffer,
+                &field.r#type.variant,
+                In,
+                number_fields.contains(field),
+            )?;
             write!(buffer, " | undefined")?;
             writeln!(buffer, ";")?;
         }
         writeln!(buffer)?;
     }
+    if open {
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "const unknownFields: UnknownField[] = [];")?;
+        writeln!(buffer)?;
+    }
     write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "while (true) {{")?;
+    writeln!(buffer, "while (offset < dataViewAlias.byteLength) {{")?;
     write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "let index: bigint;")?;
     write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "let payloadSize: number;")?;
+    if open {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "let sizeMode: number;")?;
+    }
     writeln!(buffer)?;
-    write_indentation(buffer, indentation + 2)?;
-    writeln!(buffer, "try {{")?;
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(
-        buffer,
-        "[offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);",
-    )?;
-    write_indentation(buffer, indentation + 2)?;
-    writeln!(buffer, "}} catch (e) {{")?;
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(buffer, "if (e instanceof RangeError) {{")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "break;")?;
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(buffer, "}} else {{")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "throw e;")?;
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(buffer, "}}")?;
-    write_indentation(buffer, indentation + 2)?;
-    writeln!(buffer, "}}")?;
+    write_try_truncated(buffer, indentation + 2, |buffer| {
+        write_indentation(buffer, indentation + 3)?;
+        if open {
+            writeln!(
+                buffer,
+                "[offset, index, payloadSize, sizeMode] = deserializeFieldHeader(dataViewAlias, \
+                    offset);",
+            )
+        } else {
+            writeln!(
+                buffer,
+                "[offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);",
+            )
+        }
+    })?;
     writeln!(buffer)?;
     write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "switch (index) {{")?;
     for field in fields {
         write_indentation(buffer, indentation + 3)?;
         writeln!(buffer, "case {}n: {{", field.index)?;
+        write_field_deserialization(buffer, indentation + 4, &field.r#type.variant, true)?;
         write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "const dataView = new DataView(")?;
-        write_indentation(buffer, indentation + 5)?;
-        writeln!(buffer, "dataViewAlias.buffer as ArrayBuffer,")?;
+        write!(buffer, "$")?;
+        write_identifier(buffer, &field.name, Camel, None)?;
+        write!(buffer, " = ")?;
+        write_payload_for_message(buffer, field, number_fields)?;
+        writeln!(buffer, ";")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "break;")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 3)?;
+    if open {
+        writeln!(buffer, "default: {{")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(
+            buffer,
+            "const payload = (dataViewAlias.buffer as ArrayBuffer).slice(",
+        )?;
         write_indentation(buffer, indentation + 5)?;
         writeln!(buffer, "dataViewAlias.byteOffset + offset,")?;
         write_indentation(buffer, indentation + 5)?;
-        writeln!(buffer, "payloadSize,")?;
+        writeln!(buffer, "dataViewAlias.byteOffset + offset + payloadSize,")?;
         write_indentation(buffer, indentation + 4)?;
         writeln!(buffer, ");")?;
         write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "const oldOffset = offset;")?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "offset = 0;")?;
-        write_deserialization_invocation(buffer, indentation + 4, &field.r#type.variant, true)?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "offset += oldOffset;")?;
+        writeln!(
+            buffer,
+            "unknownFields.push({{ index, sizeMode, payload }});"
+        )?;
         write_indentation(buffer, indentation + 4)?;
-        write!(buffer, "$")?;
-        write_identifier(buffer, &field.name, Camel, None)?;
-        writeln!(buffer, " = payload;")?;
+        writeln!(buffer, "offset += payloadSize;")?;
         write_indentation(buffer, indentation + 4)?;
         writeln!(buffer, "break;")?;
         write_indentation(buffer, indentation + 3)?;
         writeln!(buffer, "}}")?;
+    } else {
+        writeln!(buffer, "default:")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "offset += payloadSize;")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "break;")?;
     }
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(buffer, "default:")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "offset += payloadSize;")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "break;")?;
     write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "}}")?;
     write_indentation(buffer, indentation + 1)?;
@@ -1114,6 +2364,10 @@ fn write_struct_deserialize_unsafe_function<T: Write>(
         write_identifier(buffer, &field.name, Camel, None)?;
         writeln!(buffer, ",")?;
     }
+    if open {
+        write_indentation(buffer, indentation + 2)?;
+        writeln!(buffer, "$unknown: unknownFields,")?;
+    }
     write_indentation(buffer, indentation + 1)?;
     writeln!(buffer, "}};")?;
     write_indentation(buffer, indentation)?;
@@ -1127,6 +2381,8 @@ fn write_choice_atlas_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1135,7 +2391,7 @@ fn write_choice_atlas_function<T: Write>(
     write!(buffer, "): ")?;
     write_identifier(buffer, name, Pascal, Some(Atlas))?;
     writeln!(buffer, " {{")?;
-    if fields.is_empty() {
+    if fields.is_empty() && !open {
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return unreachable(message);")?;
     } else {
@@ -1148,10 +2404,7 @@ fn write_choice_atlas_function<T: Write>(
             write!(buffer, "let payloadAtlas: ")?;
             write_type(buffer, &field.r#type.variant, Atlas)?;
             writeln!(buffer, ";")?;
-            write_indentation(buffer, indentation + 2)?;
-            write!(buffer, "const payload = message.")?;
-            write_identifier(buffer, &field.name, Camel, None)?;
-            writeln!(buffer, ";")?;
+            write_payload_from_message(buffer, indentation + 2, field, number_fields, false)?;
             write_atlas_calculation(buffer, indentation + 2, &field.r#type.variant, true)?;
             write_indentation(buffer, indentation + 2)?;
             write!(buffer, "const payloadSize = ")?;
@@ -1196,6 +2449,31 @@ fn write_choice_atlas_function<T: Write>(
             writeln!(buffer, "}}")?;
             writeln!(buffer)?;
         }
+        if open {
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "if ('{UNKNOWN_FIELD_NAME}' in message) {{")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(
+                buffer,
+                "const {{ index, payload }} = message.{UNKNOWN_FIELD_NAME};"
+            )?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "return {{")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "$size:")?;
+            write_indentation(buffer, indentation + 4)?;
+            writeln!(
+                buffer,
+                "fieldHeaderSize(index, payload.byteLength, payloadIntegerEncoded(payload)) +",
+            )?;
+            write_indentation(buffer, indentation + 4)?;
+            writeln!(buffer, "payload.byteLength,")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "}};")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "}}")?;
+            writeln!(buffer)?;
+        }
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return unreachable(message);")?;
     }
@@ -1210,6 +2488,8 @@ fn write_choice_serialize_with_atlas_unsafe_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1228,7 +2508,7 @@ fn write_choice_serialize_with_atlas_unsafe_function<T: Write>(
     writeln!(buffer, ",")?;
     write_indentation(buffer, indentation)?;
     writeln!(buffer, "): number {{")?;
-    if fields.is_empty() {
+    if fields.is_empty() && !open {
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return unreachable(message);")?;
     } else {
@@ -1237,10 +2517,7 @@ fn write_choice_serialize_with_atlas_unsafe_function<T: Write>(
             write!(buffer, "if ('")?;
             write_identifier(buffer, &field.name, Camel, None)?;
             writeln!(buffer, "' in message) {{")?;
-            write_indentation(buffer, indentation + 2)?;
-            write!(buffer, "const payload = message.")?;
-            write_identifier(buffer, &field.name, Camel, None)?;
-            writeln!(buffer, ";")?;
+            write_payload_from_message(buffer, indentation + 2, field, number_fields, false)?;
             write_indentation(buffer, indentation + 2)?;
             write!(buffer, "const payloadAtlas = (atlas as any).")?;
             write_identifier(buffer, &field.name, Camel, None)?;
@@ -1275,6 +2552,48 @@ fn write_choice_serialize_with_atlas_unsafe_function<T: Write>(
             writeln!(buffer, "}}")?;
             writeln!(buffer)?;
         }
+        if open {
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "if ('{UNKNOWN_FIELD_NAME}' in message) {{")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(
+                buffer,
+                "const {{ index, payload }} = message.{UNKNOWN_FIELD_NAME};"
+            )?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "offset = serializeFieldHeader(")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "dataView,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "offset,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "index,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "payload.byteLength,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "payloadIntegerEncoded(payload),")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, ");")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "const sourceBuffer = new Uint8Array(payload);")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "const targetBuffer = new Uint8Array(")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "dataView.buffer as ArrayBuffer,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "dataView.byteOffset,")?;
+            write_indentation(buffer, indentation + 3)?;
+            writeln!(buffer, "dataView.byteLength,")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, ");")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "targetBuffer.set(sourceBuffer, offset);")?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "return offset + sourceBuffer.byteLength;")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "}}")?;
+            writeln!(buffer)?;
+        }
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return unreachable(message);")?;
     }
@@ -1289,6 +2608,8 @@ fn write_choice_deserialize_unsafe_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     function_name: &str,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1316,24 +2637,9 @@ fn write_choice_deserialize_unsafe_function<T: Write>(
     for field in fields {
         write_indentation(buffer, indentation + 3)?;
         writeln!(buffer, "case {}n: {{", field.index)?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "const dataView = new DataView(")?;
-        write_indentation(buffer, indentation + 5)?;
-        writeln!(buffer, "dataViewAlias.buffer as ArrayBuffer,")?;
-        write_indentation(buffer, indentation + 5)?;
-        writeln!(buffer, "dataViewAlias.byteOffset + offset,")?;
-        write_indentation(buffer, indentation + 5)?;
-        writeln!(buffer, "payloadSize,")?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, ");")?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "const oldOffset = offset;")?;
-        write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "offset = 0;")?;
-        write_deserialization_invocation(buffer, indentation + 4, &field.r#type.variant, true)?;
-        if let schema::Rule::Optional = field.rule {
-            write_indentation(buffer, indentation + 4)?;
-            writeln!(buffer, "offset += oldOffset;")?;
+        let has_fallback = matches!(field.rule, schema::Rule::Optional);
+        write_field_deserialization(buffer, indentation + 4, &field.r#type.variant, has_fallback)?;
+        if has_fallback {
             write_indentation(buffer, indentation + 4)?;
             writeln!(buffer, "const $fallback = {function_name}(")?;
             write_indentation(buffer, indentation + 5)?;
@@ -1357,22 +2663,59 @@ fn write_choice_deserialize_unsafe_function<T: Write>(
         writeln!(buffer, "',")?;
         write_indentation(buffer, indentation + 5)?;
         write_identifier(buffer, &field.name, Camel, None)?;
-        writeln!(buffer, ": payload,")?;
+        write!(buffer, ": ")?;
+        write_payload_for_message(buffer, field, number_fields)?;
+        writeln!(buffer, ",")?;
         if let schema::Rule::Optional = field.rule {
             write_indentation(buffer, indentation + 5)?;
             writeln!(buffer, "$fallback,")?;
         }
         write_indentation(buffer, indentation + 4)?;
-        writeln!(buffer, "}};")?;
+        writeln!(buffer, "}};")?;
+        write_indentation(buffer, indentation + 3)?;
+        writeln!(buffer, "}}")?;
+    }
+    write_indentation(buffer, indentation + 3)?;
+    if open {
+        // If the message ends with an unrecognized field, that field is preserved. Earlier
+        // unrecognized fields, if any, must have been optional or asymmetric, so they can be
+        // discarded in favor of their fallbacks.
+        writeln!(buffer, "default: {{")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(
+            buffer,
+            "const payload = (dataViewAlias.buffer as ArrayBuffer).slice(",
+        )?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(buffer, "dataViewAlias.byteOffset + offset,")?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(buffer, "dataViewAlias.byteOffset + offset + payloadSize,")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, ");")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "offset += payloadSize;")?;
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "if (offset >= dataViewAlias.byteLength) {{")?;
+        write_indentation(buffer, indentation + 5)?;
+        writeln!(
+            buffer,
+            "return {{ $field: '{UNKNOWN_FIELD_NAME}', {UNKNOWN_FIELD_NAME}: \
+             {{ index, payload }} }};",
+        )?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "}}")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "break;")?;
         write_indentation(buffer, indentation + 3)?;
         writeln!(buffer, "}}")?;
+    } else {
+        writeln!(buffer, "default:")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "offset += payloadSize;")?;
+        write_indentation(buffer, indentation + 4)?;
+        writeln!(buffer, "break;")?;
     }
-    write_indentation(buffer, indentation + 3)?;
-    writeln!(buffer, "default:")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "offset += payloadSize;")?;
-    write_indentation(buffer, indentation + 4)?;
-    writeln!(buffer, "break;")?;
     write_indentation(buffer, indentation + 2)?;
     writeln!(buffer, "}}")?;
     write_indentation(buffer, indentation + 1)?;
@@ -1387,6 +2730,8 @@ fn write_struct<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     direction: Direction,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1406,7 +2751,12 @@ fn write_struct<T: Write>(
         write_indentation(buffer, indentation + 1)?;
         write_identifier(buffer, &field.name, Camel, None)?;
         write!(buffer, ": ")?;
-        write_type(buffer, &field.r#type.variant, direction)?;
+        write_field_type(
+            buffer,
+            &field.r#type.variant,
+            direction,
+            number_fields.contains(field),
+        )?;
         match field.rule {
             schema::Rule::Asymmetric => match direction {
                 Direction::Atlas | Direction::Out => {}
@@ -1422,6 +2772,21 @@ fn write_struct<T: Write>(
         writeln!(buffer, ";")?;
     }
 
+    // Open structs carry any unrecognized fields along so they can be reserialized.
+    if open {
+        match direction {
+            Direction::Atlas => {}
+            Direction::In => {
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "$unknown: UnknownField[];")?;
+            }
+            Direction::Out => {
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "$unknown?: UnknownField[];")?;
+            }
+        }
+    }
+
     write_indentation(buffer, indentation)?;
     writeln!(buffer, "}};")?;
 
@@ -1434,6 +2799,8 @@ fn write_choice<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
     direction: Direction,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
@@ -1459,12 +2826,22 @@ fn write_choice<T: Write>(
                 write!(buffer, "'; ")?;
                 write_identifier(buffer, &field.name, Camel, None)?;
                 write!(buffer, ": ")?;
-                write_type(buffer, &field.r#type.variant, direction)?;
+                write_field_type(
+                    buffer,
+                    &field.r#type.variant,
+                    direction,
+                    number_fields.contains(field),
+                )?;
             }
             Direction::Out => {
                 write_identifier(buffer, &field.name, Camel, None)?;
                 write!(buffer, ": ")?;
-                write_type(buffer, &field.r#type.variant, direction)?;
+                write_field_type(
+                    buffer,
+                    &field.r#type.variant,
+                    direction,
+                    number_fields.contains(field),
+                )?;
             }
         }
 
@@ -1483,12 +2860,40 @@ fn write_choice<T: Write>(
         write!(buffer, " }}")?;
     }
 
+    // Open choices have an extra case for preserving unrecognized fields.
+    if open {
+        writeln!(buffer)?;
+        write_indentation(buffer, indentation + 1)?;
+        write!(buffer, "| {{ ")?;
+
+        match direction {
+            Direction::Atlas => {
+                write!(buffer, "$size: number")?;
+            }
+            Direction::In => {
+                write!(
+                    buffer,
+                    "$field: '{UNKNOWN_FIELD_NAME}'; \
+                        {UNKNOWN_FIELD_NAME}: {{ index: bigint; payload: ArrayBuffer }}",
+                )?;
+            }
+            Direction::Out => {
+                write!(
+                    buffer,
+                    "{UNKNOWN_FIELD_NAME}: {{ index: bigint; payload: ArrayBuffer }}",
+                )?;
+            }
+        }
+
+        write!(buffer, " }}")?;
+    }
+
     match direction {
         Direction::Atlas | Direction::Out => {}
         Direction::In => {
             // See https://github.com/microsoft/TypeScript/issues/46978#issuecomment-984093435 for
             // an explanation of this extra case.
-            if fields.len() == 1 {
+            if fields.len() + usize::from(open) == 1 {
                 writeln!(buffer)?;
                 write_indentation(buffer, indentation + 1)?;
                 write!(buffer, "| {{ $field: never }}")?;
@@ -1496,7 +2901,7 @@ fn write_choice<T: Write>(
         }
     }
 
-    if fields.is_empty() {
+    if fields.is_empty() && !open {
         write!(buffer, " never")?;
     }
 
@@ -1511,6 +2916,7 @@ fn write_size_function<T: Write>(
     indentation: usize,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
     function_name: &str,
     atlas_function_name: &str,
 ) -> Result<(), fmt::Error> {
@@ -1519,7 +2925,7 @@ fn write_size_function<T: Write>(
     write_identifier(buffer, name, Pascal, Some(Out))?;
     writeln!(buffer, "): number {{")?;
     write_indentation(buffer, indentation + 1)?;
-    if fields.is_empty() {
+    if fields.is_empty() && !open {
         writeln!(buffer, "return 0;")?;
     } else {
         writeln!(buffer, "return {atlas_function_name}(message).$size;")?;
@@ -1535,13 +2941,16 @@ fn write_serialize_function<T: Write>(
     declaration_variant: &schema::DeclarationVariant,
     name: &Identifier,
     fields: &[schema::Field],
+    open: bool,
     function_names: &DeclarationFunctionNames,
 ) -> Result<(), fmt::Error> {
     write_indentation(buffer, indentation)?;
     write!(buffer, "function {}(message: ", function_names.serialize)?;
     write_identifier(buffer, name, Pascal, Some(Out))?;
     writeln!(buffer, "): ArrayBuffer {{")?;
-    if let (schema::DeclarationVariant::Choice, true) = (declaration_variant, fields.is_empty()) {
+    if let (schema::DeclarationVariant::Choice, true) =
+        (declaration_variant, fields.is_empty() && !open)
+    {
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return unreachable(message);")?;
     } else {
@@ -1567,35 +2976,451 @@ fn write_serialize_function<T: Write>(
         write_indentation(buffer, indentation + 1)?;
         writeln!(buffer, "return arrayBuffer;")?;
     }
-    write_indentation(buffer, indentation)?;
-    writeln!(buffer, "}}")
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the function to deserialize a message.
+fn write_deserialize_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    name: &Identifier,
+    function_name: &str,
+    deserialize_unsafe_function_name: &str,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "function {function_name}(bytes: Deserializable): ")?;
+    write_identifier(buffer, name, Pascal, Some(In))?;
+    writeln!(buffer, " | Error {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "try {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "return {deserialize_unsafe_function_name}(dataViewFromDeserializable(bytes));",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}} catch (e) {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "return e as Error;")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the function which checks whether a value has the shape of a struct's or choice's output
+// type. The function returns `undefined` if so. Otherwise, it returns a description of the first
+// problem it found, starting with the path to the offending value (e.g., `.foo[2] must be a
+// string`). Unrecognized properties are ignored, as they are by the serialization functions.
+#[allow(clippy::too_many_lines)]
+fn write_check_out_function<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    declaration_variant: &schema::DeclarationVariant,
+    fields: &[schema::Field],
+    open: bool,
+    number_fields: &NumberFields,
+    function_name: &str,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "function {function_name}(value: unknown): string | undefined {{",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "if (typeof value !== 'object' || value === null) {{",
+    )?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "return ' must be an object';")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    writeln!(buffer)?;
+    if !fields.is_empty() || open {
+        write_indentation(buffer, indentation + 1)?;
+        writeln!(buffer, "const message = value as any;")?;
+        writeln!(buffer)?;
+    }
+
+    match declaration_variant {
+        schema::DeclarationVariant::Struct => {
+            for field in fields {
+                let field_name = format_identifier(&field.name, Camel, None);
+                let path = [CheckPathPiece::Literal(format!(".{field_name}"))];
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "{{")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "const payload = message.{field_name};")?;
+                if let schema::Rule::Optional = field.rule {
+                    write_indentation(buffer, indentation + 2)?;
+                    writeln!(buffer, "if (payload !== undefined) {{")?;
+                    write_check(
+                        buffer,
+                        indentation + 3,
+                        &field.r#type.variant,
+                        number_fields.contains(field),
+                        &path,
+                        0,
+                    )?;
+                    write_indentation(buffer, indentation + 2)?;
+                    writeln!(buffer, "}}")?;
+                } else {
+                    write_check(
+                        buffer,
+                        indentation + 2,
+                        &field.r#type.variant,
+                        number_fields.contains(field),
+                        &path,
+                        0,
+                    )?;
+                }
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+            }
+
+            if open {
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "if (message.$unknown !== undefined) {{")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "if (!Array.isArray(message.$unknown)) {{")?;
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(buffer, "return '.$unknown must be an array';")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(
+                    buffer,
+                    "for (let i0 = 0; i0 < message.$unknown.length; i0 += 1) {{",
+                )?;
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(
+                    buffer,
+                    "const error = checkUnknownField(message.$unknown[i0], true);",
+                )?;
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(buffer, "if (error !== undefined) {{")?;
+                write_indentation(buffer, indentation + 4)?;
+                writeln!(buffer, "return '.$unknown[' + i0 + ']' + error;")?;
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+            }
+
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "return undefined;")?;
+        }
+        schema::DeclarationVariant::Choice => {
+            // Like the serialization functions, the check uses the first field which is present.
+            for field in fields {
+                let field_name = format_identifier(&field.name, Camel, None);
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "if ('{field_name}' in message) {{")?;
+                write_field_discriminant_check(buffer, indentation + 2, &field_name)?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "const payload = message.{field_name};")?;
+                write_check(
+                    buffer,
+                    indentation + 2,
+                    &field.r#type.variant,
+                    number_fields.contains(field),
+                    &[CheckPathPiece::Literal(format!(".{field_name}"))],
+                    0,
+                )?;
+                if matches!(
+                    field.rule,
+                    schema::Rule::Asymmetric | schema::Rule::Optional,
+                ) {
+                    write_indentation(buffer, indentation + 2)?;
+                    writeln!(
+                        buffer,
+                        "const fallbackError = {function_name}(message.$fallback);",
+                    )?;
+                    write_indentation(buffer, indentation + 2)?;
+                    writeln!(buffer, "if (fallbackError !== undefined) {{")?;
+                    write_indentation(buffer, indentation + 3)?;
+                    writeln!(buffer, "return '.$fallback' + fallbackError;")?;
+                    write_indentation(buffer, indentation + 2)?;
+                    writeln!(buffer, "}}")?;
+                }
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "return undefined;")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+            }
+
+            if open {
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "if ('{UNKNOWN_FIELD_NAME}' in message) {{")?;
+                write_field_discriminant_check(buffer, indentation + 2, UNKNOWN_FIELD_NAME)?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(
+                    buffer,
+                    "const error = checkUnknownField(message.{UNKNOWN_FIELD_NAME}, false);",
+                )?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "if (error !== undefined) {{")?;
+                write_indentation(buffer, indentation + 3)?;
+                writeln!(buffer, "return '.{UNKNOWN_FIELD_NAME}' + error;")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "}}")?;
+                write_indentation(buffer, indentation + 2)?;
+                writeln!(buffer, "return undefined;")?;
+                write_indentation(buffer, indentation + 1)?;
+                writeln!(buffer, "}}")?;
+                writeln!(buffer)?;
+            }
+
+            let field_names = fields
+                .iter()
+                .map(|field| format_identifier(&field.name, Camel, None))
+                .chain(open.then(|| UNKNOWN_FIELD_NAME.to_owned()))
+                .collect::<Vec<_>>();
+            write_indentation(buffer, indentation + 1)?;
+            if field_names.is_empty() {
+                writeln!(
+                    buffer,
+                    "return ' must not exist, since the choice has no fields';"
+                )?;
+            } else {
+                writeln!(
+                    buffer,
+                    "return ' must have one of the fields {}';",
+                    field_names.join(", "),
+                )?;
+            }
+        }
+    }
+
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the code which checks that the `$field` property of a choice, if any, matches the field
+// which is present.
+fn write_field_discriminant_check<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    field_name: &str,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "if ('$field' in message && message.$field !== '{field_name}') {{",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "return '.$field must be \"{field_name}\"';")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the exported type guard and assertion function for the output type of a struct or choice.
+fn write_guard_functions<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    name: &Identifier,
+    function_names: &DeclarationFunctionNames,
+) -> Result<(), fmt::Error> {
+    let type_name = format_identifier(name, Pascal, Some(Out));
+
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "export function {}(value: unknown): value is {type_name} {{",
+        function_names.is_out,
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(
+        buffer,
+        "return {}(value) === undefined;",
+        function_names.check_out,
+    )?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")?;
+    writeln!(buffer)?;
+    write_indentation(buffer, indentation)?;
+    writeln!(
+        buffer,
+        "export function {}(value: unknown): asserts value is {type_name} {{",
+        function_names.assert_out,
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "const error = {}(value);", function_names.check_out)?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "if (error !== undefined) {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(
+        buffer,
+        "throw new TypeError('Invalid {type_name}: value' + error + '.');",
+    )?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// This enum represents a piece of the path to a value being checked, which is reported when the
+// value doesn't have the expected shape.
+#[derive(Clone)]
+enum CheckPathPiece {
+    Literal(String),
+    Variable(String),
+}
+
+// Write an expression which concatenates the pieces of a path, followed by a problem description.
+fn write_check_path<T: Write>(
+    buffer: &mut T,
+    path: &[CheckPathPiece],
+    problem: &str,
+) -> Result<(), fmt::Error> {
+    let mut operands: Vec<String> = vec![];
+    let mut literal = String::new();
+
+    for piece in path
+        .iter()
+        .cloned()
+        .chain(once(CheckPathPiece::Literal(problem.to_owned())))
+    {
+        match piece {
+            CheckPathPiece::Literal(string) => literal.push_str(&string),
+            CheckPathPiece::Variable(variable) => {
+                if !literal.is_empty() {
+                    operands.push(format!("'{literal}'"));
+                    literal.clear();
+                }
+                operands.push(variable);
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        operands.push(format!("'{literal}'"));
+    }
+
+    write!(buffer, "{}", operands.join(" + "))
 }
 
-// Write the function to deserialize a message.
-fn write_deserialize_function<T: Write>(
+// Write the code which checks the value in `payload`, returning a description of the problem if it
+// doesn't have the given type.
+#[allow(clippy::too_many_lines)]
+fn write_check<T: Write>(
     buffer: &mut T,
     indentation: usize,
-    name: &Identifier,
-    function_name: &str,
-    deserialize_unsafe_function_name: &str,
+    type_variant: &schema::TypeVariant,
+    number: bool,
+    path: &[CheckPathPiece],
+    depth: usize,
 ) -> Result<(), fmt::Error> {
+    let (condition, problem) = match type_variant {
+        schema::TypeVariant::Array(inner_type) => {
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "if (!Array.isArray(payload)) {{")?;
+            write_indentation(buffer, indentation + 1)?;
+            write!(buffer, "return ")?;
+            write_check_path(buffer, path, " must be an array")?;
+            writeln!(buffer, ";")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "}}")?;
+
+            let index = format!("i{depth}");
+            let mut inner_path = path.to_vec();
+            inner_path.push(CheckPathPiece::Literal("[".to_owned()));
+            inner_path.push(CheckPathPiece::Variable(index.clone()));
+            inner_path.push(CheckPathPiece::Literal("]".to_owned()));
+
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "{{")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "const oldPayload = payload;")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(
+                buffer,
+                "for (let {index} = 0; {index} < oldPayload.length; {index} += 1) {{",
+            )?;
+            write_indentation(buffer, indentation + 2)?;
+            writeln!(buffer, "const payload = oldPayload[{index}];")?;
+            write_check(
+                buffer,
+                indentation + 2,
+                &inner_type.variant,
+                number,
+                &inner_path,
+                depth + 1,
+            )?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "}}")?;
+            write_indentation(buffer, indentation)?;
+            return writeln!(buffer, "}}");
+        }
+        schema::TypeVariant::Custom(import, name) => {
+            write_indentation(buffer, indentation)?;
+            write!(buffer, "const error = ")?;
+            write_custom_type(buffer, import.as_ref(), name, None)?;
+            writeln!(buffer, ".checkOut(payload);")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "if (error !== undefined) {{")?;
+            write_indentation(buffer, indentation + 1)?;
+            write!(buffer, "return ")?;
+            let mut error_path = path.to_vec();
+            error_path.push(CheckPathPiece::Variable("error".to_owned()));
+            write_check_path(buffer, &error_path, "")?;
+            writeln!(buffer, ";")?;
+            write_indentation(buffer, indentation)?;
+            return writeln!(buffer, "}}");
+        }
+        schema::TypeVariant::Bool => ("typeof payload !== 'boolean'", " must be a boolean"),
+        schema::TypeVariant::Bytes => (
+            "!(payload instanceof ArrayBuffer)",
+            " must be an ArrayBuffer",
+        ),
+        schema::TypeVariant::F64 => ("typeof payload !== 'number'", " must be a number"),
+        schema::TypeVariant::S64 => {
+            if number {
+                (
+                    "!Number.isInteger(payload) || payload < -9_223_372_036_854_775_808 || \
+                        payload >= 9_223_372_036_854_775_808",
+                    " must be an integer from -9223372036854775808 to 9223372036854775807",
+                )
+            } else {
+                (
+                    "typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || \
+                        payload > 9_223_372_036_854_775_807n",
+                    " must be a bigint from -9223372036854775808 to 9223372036854775807",
+                )
+            }
+        }
+        schema::TypeVariant::String => ("typeof payload !== 'string'", " must be a string"),
+        schema::TypeVariant::U64 => {
+            if number {
+                (
+                    "!Number.isInteger(payload) || payload < 0 || \
+                        payload >= 18_446_744_073_709_551_616",
+                    " must be an integer from 0 to 18446744073709551615",
+                )
+            } else {
+                (
+                    "typeof payload !== 'bigint' || payload < 0n || \
+                        payload > 18_446_744_073_709_551_615n",
+                    " must be a bigint from 0 to 18446744073709551615",
+                )
+            }
+        }
+        schema::TypeVariant::Unit => ("payload !== null", " must be null"),
+    };
+
     write_indentation(buffer, indentation)?;
-    write!(buffer, "function {function_name}(bytes: Deserializable): ")?;
-    write_identifier(buffer, name, Pascal, Some(In))?;
-    writeln!(buffer, " | Error {{")?;
-    write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "try {{")?;
-    write_indentation(buffer, indentation + 2)?;
-    writeln!(
-        buffer,
-        "return {deserialize_unsafe_function_name}(dataViewFromDeserializable(bytes));",
-    )?;
+    writeln!(buffer, "if ({condition}) {{")?;
     write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "}} catch (e) {{")?;
-    write_indentation(buffer, indentation + 2)?;
-    writeln!(buffer, "return e as Error;")?;
-    write_indentation(buffer, indentation + 1)?;
-    writeln!(buffer, "}}")?;
+    write!(buffer, "return ")?;
+    write_check_path(buffer, path, problem)?;
+    writeln!(buffer, ";")?;
     write_indentation(buffer, indentation)?;
     writeln!(buffer, "}}")
 }
@@ -2216,30 +4041,16 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "{{")?;
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "while (true) {{")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "let payloadSizeBig: bigint;")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "try {{")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(
-                    buffer,
-                    "[offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);",
-                )?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}} catch (e) {{")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "if (e instanceof RangeError) {{")?;
-                write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "break;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}} else {{")?;
-                write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "throw e;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, "while (offset < dataViewAlias.byteLength) {{")?;
                 write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, "let payloadSize: number;")?;
+                write_try_truncated(buffer, indentation + 3, |buffer| {
+                    write_indentation(buffer, indentation + 4)?;
+                    writeln!(
+                        buffer,
+                        "[offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);",
+                    )
+                })?;
                 write_indentation(buffer, indentation + 3)?;
                 writeln!(buffer, "const dataView = new DataView(")?;
                 write_indentation(buffer, indentation + 4)?;
@@ -2247,7 +4058,7 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 4)?;
                 writeln!(buffer, "dataViewAlias.byteOffset + offset,")?;
                 write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "Number(payloadSizeBig),")?;
+                writeln!(buffer, "payloadSize,")?;
                 write_indentation(buffer, indentation + 3)?;
                 writeln!(buffer, ");")?;
                 write_indentation(buffer, indentation + 3)?;
@@ -2286,31 +4097,17 @@ fn write_deserialization_invocation<T: Write>(
                 write_indentation(buffer, indentation + 1)?;
                 writeln!(buffer, "{{")?;
                 write_indentation(buffer, indentation + 2)?;
-                writeln!(buffer, "while (true) {{")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "try {{")?;
-                write_deserialization_invocation(
-                    buffer,
-                    indentation + 4,
-                    &inner_type.variant,
-                    false,
-                )?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "payloadAlias.push(payload);")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}} catch (e) {{")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "if (e instanceof RangeError) {{")?;
-                write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "break;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}} else {{")?;
-                write_indentation(buffer, indentation + 5)?;
-                writeln!(buffer, "throw e;")?;
-                write_indentation(buffer, indentation + 4)?;
-                writeln!(buffer, "}}")?;
-                write_indentation(buffer, indentation + 3)?;
-                writeln!(buffer, "}}")?;
+                writeln!(buffer, "while (offset < dataView.byteLength) {{")?;
+                write_try_truncated(buffer, indentation + 3, |buffer| {
+                    write_deserialization_invocation(
+                        buffer,
+                        indentation + 4,
+                        &inner_type.variant,
+                        false,
+                    )?;
+                    write_indentation(buffer, indentation + 4)?;
+                    writeln!(buffer, "payloadAlias.push(payload);")
+                })?;
                 write_indentation(buffer, indentation + 2)?;
                 writeln!(buffer, "}}")?;
                 write_indentation(buffer, indentation + 1)?;
@@ -2436,20 +4233,9 @@ fn write_deserialization_invocation<T: Write>(
             writeln!(buffer, "payload = zigzagDecode(payload);")
         }
         schema::TypeVariant::String => {
+            // The payload spans the whole `DataView` [ref:typescript_payload_view].
             write_indentation(buffer, indentation)?;
-            writeln!(buffer, "let payload = textDecoder.decode(")?;
-            write_indentation(buffer, indentation + 1)?;
-            writeln!(buffer, "new DataView(")?;
-            write_indentation(buffer, indentation + 2)?;
-            writeln!(buffer, "dataView.buffer as ArrayBuffer,")?;
-            write_indentation(buffer, indentation + 2)?;
-            writeln!(buffer, "dataView.byteOffset + offset,")?;
-            write_indentation(buffer, indentation + 2)?;
-            writeln!(buffer, "dataView.byteLength - offset,")?;
-            write_indentation(buffer, indentation + 1)?;
-            writeln!(buffer, "),")?;
-            write_indentation(buffer, indentation)?;
-            writeln!(buffer, ");")?;
+            writeln!(buffer, "let payload = textDecoder.decode(dataView);")?;
             write_indentation(buffer, indentation)?;
             writeln!(buffer, "offset = dataView.byteLength;")
         }
@@ -2502,6 +4288,82 @@ fn write_deserialization_invocation<T: Write>(
     }
 }
 
+// Write a `try` statement which ends the enclosing loop if the given code runs out of data. The
+// loop condition stops at the end of the data, so this only happens when the last field header
+// or array element is incomplete. Such data are ignored, as in the generated Rust code.
+fn write_try_truncated<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    body: impl FnOnce(&mut T) -> Result<(), fmt::Error>,
+) -> Result<(), fmt::Error> {
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "try {{")?;
+    body(buffer)?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}} catch (e) {{")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "if (e instanceof RangeError) {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "break;")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}} else {{")?;
+    write_indentation(buffer, indentation + 2)?;
+    writeln!(buffer, "throw e;")?;
+    write_indentation(buffer, indentation + 1)?;
+    writeln!(buffer, "}}")?;
+    write_indentation(buffer, indentation)?;
+    writeln!(buffer, "}}")
+}
+
+// Write the code to deserialize the payload of a field into a variable called `payload`. The
+// field header has already been read, so `offset` points to the payload and `payloadSize` is its
+// size. Afterward, `offset` points to the end of the payload if `advance_offset` is set.
+fn write_field_deserialization<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    type_variant: &schema::TypeVariant,
+    advance_offset: bool,
+) -> Result<(), fmt::Error> {
+    match type_variant {
+        // These payloads are read in place, so they don't need their own `DataView`.
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {
+            write_deserialization_invocation(buffer, indentation, type_variant, true)
+        }
+
+        // These payloads are delimited by the end of a `DataView` which starts at the payload
+        // [tag:typescript_payload_view].
+        schema::TypeVariant::Array(_)
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::Custom(_, _)
+        | schema::TypeVariant::String => {
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "const dataView = new DataView(")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "dataViewAlias.buffer as ArrayBuffer,")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "dataViewAlias.byteOffset + offset,")?;
+            write_indentation(buffer, indentation + 1)?;
+            writeln!(buffer, "payloadSize,")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, ");")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "const oldOffset = offset;")?;
+            write_indentation(buffer, indentation)?;
+            writeln!(buffer, "offset = 0;")?;
+            write_deserialization_invocation(buffer, indentation, type_variant, true)?;
+            if advance_offset {
+                write_indentation(buffer, indentation)?;
+                writeln!(buffer, "offset += oldOffset;")?;
+            }
+            Ok(())
+        }
+    }
+}
+
 // Determine whether a type is encoded as a varint.
 fn integer_encoded(r#type: &schema::Type) -> bool {
     match &r#type.variant {
@@ -2515,6 +4377,143 @@ fn integer_encoded(r#type: &schema::Type) -> bool {
     }
 }
 
+// Determine whether a type is `U64`, `S64`, or an array thereof (possibly nested).
+fn integer_elements(type_variant: &schema::TypeVariant) -> bool {
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => integer_elements(&inner_type.variant),
+        schema::TypeVariant::S64 | schema::TypeVariant::U64 => true,
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::Custom(_, _)
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::Unit => false,
+    }
+}
+
+// Find the fields of a declaration which are represented as `number`s.
+fn declaration_number_fields(
+    options: &Options,
+    namespace: &schema::Namespace,
+    declaration: &schema::Declaration,
+) -> NumberFields {
+    NumberFields {
+        names: declaration
+            .fields
+            .iter()
+            .filter(|field| {
+                integer_elements(&field.r#type.variant)
+                    && options.number_fields.iter().any(|selector| match selector {
+                        NumberSelector::Schema(selected_namespace) => {
+                            selected_namespace == namespace
+                        }
+                        NumberSelector::Field(selected_namespace, declaration_name, field_name) => {
+                            selected_namespace == namespace
+                                && *declaration_name == declaration.name
+                                && *field_name == field.name
+                        }
+                    })
+            })
+            .map(|field| field.name.clone())
+            .collect(),
+        overflow: options.number_overflow,
+    }
+}
+
+// Write the type of a field, taking into account whether it's represented as a `number`.
+fn write_field_type<T: Write>(
+    buffer: &mut T,
+    type_variant: &schema::TypeVariant,
+    direction: Direction,
+    number: bool,
+) -> Result<(), fmt::Error> {
+    match (type_variant, direction, number) {
+        (schema::TypeVariant::Array(inner_type), Direction::In | Direction::Out, true) => {
+            write_field_type(buffer, &inner_type.variant, direction, number)?;
+            write!(buffer, "[]")
+        }
+        (
+            schema::TypeVariant::S64 | schema::TypeVariant::U64,
+            Direction::In | Direction::Out,
+            true,
+        ) => write!(buffer, "number"),
+        _ => write_type(buffer, type_variant, direction),
+    }
+}
+
+// Write an expression which applies a conversion function to an integer or to each of the
+// integers in a (possibly nested) array, given as another expression.
+fn write_number_conversion<T: Write>(
+    buffer: &mut T,
+    type_variant: &schema::TypeVariant,
+    function: &str,
+    expression: &str,
+) -> Result<(), fmt::Error> {
+    // Compute the function which converts the elements of an array, if applicable.
+    fn element_conversion(type_variant: &schema::TypeVariant, function: &str) -> String {
+        match type_variant {
+            schema::TypeVariant::Array(inner_type) => format!(
+                "(x) => x.map({})",
+                element_conversion(&inner_type.variant, function),
+            ),
+            _ => function.to_owned(),
+        }
+    }
+
+    match type_variant {
+        schema::TypeVariant::Array(inner_type) => write!(
+            buffer,
+            "{expression}.map({})",
+            element_conversion(&inner_type.variant, function),
+        ),
+        _ => write!(buffer, "{function}({expression})"),
+    }
+}
+
+// Write the statement which reads a field of `message` into `payload`, converting it to `bigint`s
+// if it's represented as a `number`.
+fn write_payload_from_message<T: Write>(
+    buffer: &mut T,
+    indentation: usize,
+    field: &schema::Field,
+    number_fields: &NumberFields,
+    optional: bool,
+) -> Result<(), fmt::Error> {
+    let mut expression = "message.".to_owned();
+    write_identifier(&mut expression, &field.name, Camel, None)?;
+
+    write_indentation(buffer, indentation)?;
+    write!(buffer, "const payload = ")?;
+    if number_fields.contains(field) {
+        if optional {
+            write!(buffer, "{expression} === undefined ? undefined : ")?;
+        }
+        write_number_conversion(buffer, &field.r#type.variant, "BigInt", &expression)?;
+    } else {
+        write!(buffer, "{expression}")?;
+    }
+    writeln!(buffer, ";")
+}
+
+// Write an expression for the deserialized `payload` of a field, converting it from `bigint`s if
+// it's represented as a `number`.
+fn write_payload_for_message<T: Write>(
+    buffer: &mut T,
+    field: &schema::Field,
+    number_fields: &NumberFields,
+) -> Result<(), fmt::Error> {
+    if number_fields.contains(field) {
+        write_number_conversion(
+            buffer,
+            &field.r#type.variant,
+            number_fields.overflow.conversion_function(),
+            "payload",
+        )
+    } else {
+        write!(buffer, "payload")
+    }
+}
+
 // Format an identifier for TypeScript, including any requested direction suffix.
 fn format_identifier(
     identifier: &Identifier,
@@ -2606,8 +4605,12 @@ fn import_binding_name(import: &Identifier) -> String {
 #[cfg(test)]
 mod tests {
     use crate::{
+        desugar::desugar,
         error::SourceRange,
-        generate_typescript::{COMMON_FILE_STEM, generate},
+        generate_typescript::{
+            COMMON_FILE_STEM, NumberOverflow, NumberSelector, Options, check_number_fields,
+            generate,
+        },
         schema::{self, Namespace},
         schema_loader::load_schemas,
         validator::validate,
@@ -2621,8 +4624,9 @@ mod tests {
     // Check that TypeScript generation matches the golden fixture tree.
     #[test]
     fn generate_example() {
-        let schemas = load_schemas(Path::new("integration_tests/types/types.t")).unwrap();
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
         validate(&schemas).unwrap();
+        desugar(&mut schemas);
 
         let fixture_root = Path::new("test_data/typescript");
         let mut expected = BTreeMap::new();
@@ -2642,7 +4646,7 @@ mod tests {
             expected.insert(path, contents);
         }
 
-        assert_eq!(generate("0.0.0", &schemas), expected);
+        assert_eq!(generate("0.0.0", &schemas, &Options::default()), expected);
     }
 
     // Check that TypeScript file paths and import specifiers use `snake_case`.
@@ -2662,6 +4666,7 @@ mod tests {
                 source_range,
                 path: PathBuf::from("../Foo/FirstSchema.t"),
                 namespace: Some(dependency_namespace.clone()),
+                names: vec![],
             },
         );
         let mut schemas = BTreeMap::new();
@@ -2672,6 +4677,7 @@ mod tests {
                     comment: vec![],
                     imports: BTreeMap::new(),
                     declarations: vec![],
+                    services: vec![],
                 },
                 PathBuf::from("Foo/FirstSchema.t"),
                 String::new(),
@@ -2687,17 +4693,20 @@ mod tests {
                         source_range,
                         comment: vec![],
                         variant: schema::DeclarationVariant::Struct,
+                        open: false,
                         name: "SomeStruct".into(),
+                        includes: vec![],
                         fields: vec![],
                         deleted: BTreeSet::new(),
                     }],
+                    services: vec![],
                 },
                 PathBuf::from("Bar/SecondSchema.t"),
                 String::new(),
             ),
         );
 
-        let generated = generate("0.0.0", &schemas);
+        let generated = generate("0.0.0", &schemas, &Options::default());
 
         assert!(generated.contains_key(Path::new("foo/first_schema.ts")));
         assert!(generated.contains_key(Path::new("bar/second_schema.ts")));
@@ -2708,4 +4717,122 @@ mod tests {
                 .contains("import * as _FirstSchema from '../foo/first_schema';"),
         );
     }
+
+    // Check that the selected integer fields are represented as numbers.
+    #[test]
+    fn generate_number_fields() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let number_fields = ["comprehensive::types", "degenerate::types"]
+            .iter()
+            .map(|selector| selector.parse().unwrap())
+            .collect::<Vec<_>>();
+        check_number_fields(&schemas, &number_fields).unwrap();
+
+        let generated = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                number_fields,
+                number_overflow: NumberOverflow::Clamp,
+                ..Options::default()
+            },
+        );
+        let code = &generated[Path::new("comprehensive/types.ts")];
+
+        assert!(code.contains("  cRequired: number;\n"));
+        assert!(code.contains("  vOptional: number[][] | undefined;\n"));
+        assert!(code.contains("const payload = BigInt(message.cRequired);"));
+        assert!(code.contains(
+            "const payload = message.uOptional === undefined ? undefined : message.uOptional.map(\
+             (x) => x.map(BigInt));",
+        ));
+        assert!(code.contains("$cRequired = clampedNumberFromBigint(payload);"));
+        assert!(code.contains("mRequired: payload.map(clampedNumberFromBigint),"));
+        assert!(code.contains("  clampedNumberFromBigint,\n"));
+        assert!(
+            !generated[Path::new("unknown_fields/types.ts")].contains("clampedNumberFromBigint"),
+        );
+    }
+
+    // Check that the container helpers and the embedded schema are only generated on request.
+    #[test]
+    fn generate_containers() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let generated = generate(
+            "0.0.0",
+            &schemas,
+            &Options {
+                containers: true,
+                ..Options::default()
+            },
+        );
+        let common = &generated[&PathBuf::from(COMMON_FILE_STEM).with_extension("ts")];
+
+        assert!(common.contains("export async function* deserializeContainer<T>("));
+        assert!(common.contains("export const schema = `"));
+        assert!(generated[Path::new("comprehensive/types.ts")].contains("  schemaName: 'Bar',\n"));
+        assert!(
+            generated[Path::new("degenerate/types.ts")].contains("  normalForm: 'struct\\n',\n")
+        );
+
+        let generated = generate("0.0.0", &schemas, &Options::default());
+        let common = &generated[&PathBuf::from(COMMON_FILE_STEM).with_extension("ts")];
+
+        assert!(!common.contains("Framed"));
+        assert!(!common.contains("schema"));
+        assert!(!generated[Path::new("comprehensive/types.ts")].contains("schemaName"));
+    }
+
+    #[test]
+    fn parse_number_selectors() {
+        assert_eq!(
+            "foo::types".parse::<NumberSelector>().unwrap().to_string(),
+            "foo::types",
+        );
+        assert_eq!(
+            "foo::types::Bar.baz"
+                .parse::<NumberSelector>()
+                .unwrap()
+                .to_string(),
+            "foo::types::Bar.baz",
+        );
+        assert!("".parse::<NumberSelector>().is_err());
+        assert!("foo::".parse::<NumberSelector>().is_err());
+        assert!("Bar.baz".parse::<NumberSelector>().is_err());
+        assert!("foo::Bar.".parse::<NumberSelector>().is_err());
+        assert_eq!(
+            "clamp".parse::<NumberOverflow>().unwrap(),
+            NumberOverflow::Clamp,
+        );
+        assert!("wrap".parse::<NumberOverflow>().is_err());
+    }
+
+    #[test]
+    fn check_number_fields_invalid() {
+        let mut schemas = load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+        validate(&schemas).unwrap();
+        desugar(&mut schemas);
+
+        let number_fields = [
+            "unknown_fields::types::NewStruct.text",
+            "unknown_fields::types::NewStruct.missing",
+            "missing::types",
+        ]
+        .iter()
+        .map(|selector| selector.parse().unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            check_number_fields(&schemas, &number_fields)
+                .unwrap_err()
+                .len(),
+            3,
+        );
+    }
 }
@@ -1,8 +1,15 @@
 mod assertions;
+mod container;
 mod count;
+mod decode;
+mod desugar;
 mod error;
 mod error_merger;
+mod fingerprint;
 mod format;
+mod generate_graph;
+mod generate_javascript;
+mod generate_markdown;
 mod generate_rust;
 mod generate_typescript;
 mod identifier;
@@ -15,8 +22,11 @@ mod validator;
 
 use crate::{
     count::count,
+    decode::decode,
+    desugar::desugar,
     error::{Error, throw},
     error_merger::merge_errors,
+    fingerprint::{fingerprint, wire_fingerprint},
     format::CodeStr,
     schema_loader::load_schemas,
     validator::validate,
@@ -24,6 +34,7 @@ use crate::{
 use clap::{ArgAction, Args, CommandFactory, Parser, Subcommand as ClapSubcommand};
 use clap_complete::{Shell, generate};
 use std::{
+    collections::BTreeMap,
     fs::{create_dir_all, write},
     io::stdout,
     path::{Path, PathBuf},
@@ -56,11 +67,28 @@ struct Cli {
     command: TypicalCommand,
 }
 
+// The option for finding imports, which every subcommand that loads schemas accepts
 #[derive(Args)]
+struct ImportPathArgs {
+    #[arg(
+        short = 'I',
+        long = "import-path",
+        value_name = "PATH",
+        help = "Add a directory in which to search for imports which can't be found relative to \
+                the importing schema (can be given multiple times)"
+    )]
+    import_paths: Vec<PathBuf>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
 struct GenerateArgs {
     #[arg(value_name = "SCHEMA_PATH", help = "Set the path to the schema")]
     path: PathBuf,
 
+    #[command(flatten)]
+    imports: ImportPathArgs,
+
     #[arg(
         long,
         help = "List the schemas imported by the given schema (and the given schema itself)"
@@ -74,12 +102,119 @@ struct GenerateArgs {
     )]
     rust_file: Option<PathBuf>,
 
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "rust_file",
+        help = "Set the directory in which to emit the Rust code as a tree of files, with one file \
+                per module (starting with mod.rs)"
+    )]
+    rust_dir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Also generate asynchronous serialization and deserialization functions for Rust \
+                (requires the tokio crate with the io-util feature)"
+    )]
+    rust_async: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "rust_async",
+        help = "Generate Rust code which only depends on the core and alloc crates rather than the \
+                standard library"
+    )]
+    rust_no_std: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "rust_no_std",
+        help = "Import the traits and helpers for Rust from the typical-runtime crate rather than \
+                defining them in the generated code"
+    )]
+    rust_runtime: bool,
+
+    #[arg(
+        long,
+        help = "Generate a builder for the output type of each struct in Rust, which can only \
+                build the message once all the required and asymmetric fields are set"
+    )]
+    rust_builders: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "rust_no_std",
+        help = "Implement the Arbitrary trait from the arbitrary crate for the output types in \
+                Rust, and generate tests which check that arbitrary messages survive a round trip"
+    )]
+    rust_arbitrary: bool,
+
+    #[arg(
+        long,
+        help = "Generate helpers for Rust which read and write streams of framed messages and \
+                container files, and embed the schema in the generated code so containers can \
+                identify their type"
+    )]
+    rust_containers: bool,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Set the directory in which to emit a cargo-fuzz crate with a fuzz target for each \
+                type, which checks that decoded messages survive a round trip"
+    )]
+    rust_fuzz_dir: Option<PathBuf>,
+
+    #[arg(
+        long = "rust-type",
+        value_name = "SELECTOR=TYPE",
+        help = "Use a custom Rust type for all the fields of type Bytes, String, or an array, or \
+                for one field, as in bytes=::bytes::Bytes or foo::types::Bar.baz=Box<str> (can \
+                be given multiple times)"
+    )]
+    rust_type_overrides: Vec<generate_rust::TypeOverride>,
+
     #[arg(
         long,
         value_name = "PATH",
         help = "Set the directory in which the TypeScript files will be emitted"
     )]
     typescript_dir: Option<PathBuf>,
+
+    #[arg(
+        long = "javascript-dir",
+        value_name = "PATH",
+        help = "Set the directory in which the JavaScript files and their TypeScript declarations \
+                will be emitted"
+    )]
+    javascript_dir: Option<PathBuf>,
+
+    #[arg(
+        long = "typescript-number",
+        value_name = "SELECTOR",
+        help = "Represent the U64 and S64 fields of a schema, or one such field, as numbers rather \
+                than bigints in TypeScript and JavaScript, as in foo::types or foo::types::Bar.baz \
+                (can be given multiple times)"
+    )]
+    typescript_number_fields: Vec<generate_typescript::NumberSelector>,
+
+    #[arg(
+        long,
+        value_name = "throw|clamp",
+        default_value = "throw",
+        help = "Set whether the TypeScript and JavaScript deserializers throw an error or clamp \
+                when an integer represented as a number exceeds Number.MAX_SAFE_INTEGER in \
+                magnitude"
+    )]
+    typescript_number_overflow: generate_typescript::NumberOverflow,
+
+    #[arg(
+        long,
+        help = "Generate helpers for TypeScript and JavaScript which read and write streams of \
+                framed messages and container files, and embed the schema in the generated code \
+                so containers can identify their type"
+    )]
+    typescript_containers: bool,
 }
 
 #[derive(Args)]
@@ -87,10 +222,74 @@ struct FormatArgs {
     #[arg(value_name = "SCHEMA_PATH", help = "Set the path to the schema")]
     path: PathBuf,
 
+    #[command(flatten)]
+    imports: ImportPathArgs,
+
     #[arg(long, help = "Check the formatting rather than actually doing it")]
     check: bool,
 }
 
+#[derive(Args)]
+struct FingerprintArgs {
+    #[arg(value_name = "SCHEMA_PATH", help = "Set the path to the schema")]
+    path: PathBuf,
+
+    #[command(flatten)]
+    imports: ImportPathArgs,
+
+    #[arg(
+        long,
+        help = "Print the wire fingerprints instead, which ignore the differences that don't \
+                affect whether two versions of a type can exchange messages"
+    )]
+    wire: bool,
+}
+
+#[derive(Args)]
+struct DocsArgs {
+    #[arg(value_name = "SCHEMA_PATH", help = "Set the path to the schema")]
+    path: PathBuf,
+
+    #[command(flatten)]
+    imports: ImportPathArgs,
+
+    #[arg(
+        long = "out",
+        value_name = "PATH",
+        help = "Set the directory in which the Markdown files will be emitted"
+    )]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+struct GraphArgs {
+    #[arg(value_name = "SCHEMA_PATH", help = "Set the path to the schema")]
+    path: PathBuf,
+
+    #[command(flatten)]
+    imports: ImportPathArgs,
+
+    #[arg(
+        long,
+        value_name = "dot|mermaid",
+        default_value = "dot",
+        help = "Set the language in which the graph will be described"
+    )]
+    format: generate_graph::Format,
+
+    #[arg(
+        long,
+        help = "Draw a node for each namespace rather than for each type"
+    )]
+    collapse_namespaces: bool,
+}
+
+#[derive(Args)]
+struct DecodeArgs {
+    #[arg(value_name = "CONTAINER_PATH", help = "Set the path to the container")]
+    path: PathBuf,
+}
+
 #[derive(Args)]
 struct ShellCompletionArgs {
     #[arg(value_enum, ignore_case = true)]
@@ -105,6 +304,29 @@ enum TypicalCommand {
     #[command(about = "Format a schema and its transitive dependencies")]
     Format(FormatArgs),
 
+    #[command(
+        about = "Print the fingerprint of each type in a schema and its transitive dependencies, \
+                 which only depends on the structure of the type"
+    )]
+    Fingerprint(FingerprintArgs),
+
+    #[command(
+        about = "Generate Markdown documentation for a schema and its transitive dependencies"
+    )]
+    Docs(DocsArgs),
+
+    #[command(
+        about = "Print the dependency graph of the types in a schema and its transitive \
+                 dependencies"
+    )]
+    Graph(GraphArgs),
+
+    #[command(
+        about = "Print the messages in a container as JSON, one per line, using the schema \
+                 embedded in the container"
+    )]
+    Decode(DecodeArgs),
+
     #[command(
         name = "shell-completion",
         about = "Print a shell completion script. Supports Bash, Fish, Zsh, PowerShell, and Elvish."
@@ -113,21 +335,32 @@ enum TypicalCommand {
 }
 
 // Generate code for a schema and its transitive dependencies.
+#[allow(clippy::too_many_arguments)]
 fn generate_code(
     schema_path: &Path,
+    import_paths: &[PathBuf],
     list_schemas: bool,
     rust_file: Option<&Path>,
+    rust_directory: Option<&Path>,
+    rust_options: &generate_rust::Options,
+    rust_fuzz_directory: Option<&Path>,
     typescript_directory: Option<&Path>,
+    javascript_directory: Option<&Path>,
+    typescript_options: &generate_typescript::Options,
 ) -> Result<(), Error> {
     // Load the schema and its transitive dependencies.
     eprintln!("Loading schemas\u{2026}");
-    let schemas = load_schemas(schema_path).map_err(|errors| merge_errors(&errors))?;
+    let mut schemas =
+        load_schemas(schema_path, import_paths).map_err(|errors| merge_errors(&errors))?;
     eprintln!("{} loaded.", count(schemas.len(), "schema"));
 
     // Validate the schemas.
     eprintln!("Validating schemas\u{2026}");
     validate(&schemas).map_err(|errors| merge_errors(&errors))?;
 
+    // Rewrite any syntactic conveniences for the code generators.
+    desugar(&mut schemas);
+
     // Print the schema paths, if applicable.
     if list_schemas {
         eprintln!("Listing schemas\u{2026}");
@@ -136,10 +369,41 @@ fn generate_code(
         let directory = schema_path.parent().unwrap();
 
         for (_, source_path, _) in schemas.values() {
-            println!("{}", directory.join(source_path).to_string_lossy());
+            let full_source_path = directory.join(source_path);
+            let resolved_source_path = full_source_path.canonicalize().map_err(|error| {
+                throw(
+                    &format!(
+                        "Unable to resolve {}.",
+                        full_source_path.to_string_lossy().code_str(),
+                    ),
+                    None,
+                    None,
+                    Some(error),
+                )
+            })?;
+            println!("{}", resolved_source_path.to_string_lossy());
         }
     }
 
+    // Make sure the type overrides refer to fields which can be overridden.
+    if rust_file.is_some() || rust_directory.is_some() {
+        generate_rust::check_type_overrides(&schemas, &rust_options.type_overrides)
+            .map_err(|errors| merge_errors(&errors))?;
+
+        // Make sure the builder setters don't collide.
+        if rust_options.builders {
+            generate_rust::check_builder_setters(&schemas)
+                .map_err(|errors| merge_errors(&errors))?;
+        }
+    }
+
+    // Make sure the number selectors refer to schemas or fields which can be represented as
+    // numbers.
+    if typescript_directory.is_some() || javascript_directory.is_some() {
+        generate_typescript::check_number_fields(&schemas, &typescript_options.number_fields)
+            .map_err(|errors| merge_errors(&errors))?;
+    }
+
     // Generate Rust code, if applicable.
     if let Some(rust_file) = rust_file {
         eprintln!("Generating Rust\u{2026}");
@@ -158,7 +422,11 @@ fn generate_code(
 
         // Generate the code and write it to the file.
         eprintln!("Writing {}\u{2026}", rust_file.to_string_lossy().code_str());
-        write(rust_file, generate_rust::generate(VERSION, &schemas)).map_err(|error| {
+        write(
+            rust_file,
+            generate_rust::generate(VERSION, &schemas, rust_options),
+        )
+        .map_err(|error| {
             throw(
                 &format!(
                     "Unable to write {}.",
@@ -171,54 +439,98 @@ fn generate_code(
         })?;
     }
 
+    // Generate Rust files, if applicable.
+    if let Some(rust_directory) = rust_directory {
+        eprintln!("Generating Rust\u{2026}");
+
+        // Generate the code and write it to the files.
+        write_files(
+            rust_directory,
+            generate_rust::generate_files(VERSION, &schemas, rust_options),
+        )?;
+    }
+
+    // Generate the fuzz crate for the Rust code, if applicable.
+    if let Some(rust_fuzz_directory) = rust_fuzz_directory {
+        eprintln!("Generating Rust fuzz targets\u{2026}");
+
+        // Generate the crate and write it to the files.
+        write_files(
+            rust_fuzz_directory,
+            generate_rust::generate_fuzz_crate(VERSION, &schemas),
+        )?;
+    }
+
     // Generate TypeScript code, if applicable.
     if let Some(typescript_directory) = typescript_directory {
         eprintln!("Generating TypeScript\u{2026}");
 
         // Generate the code and write it to the files.
-        for (relative_path, contents) in generate_typescript::generate(VERSION, &schemas) {
-            let output_file_path = typescript_directory.join(&relative_path);
-
-            // Create any missing ancestor directories.
-            if let Some(parent) = output_file_path.parent() {
-                create_dir_all(parent).map_err(|error| {
-                    throw(
-                        &format!("Unable to create {}.", parent.to_string_lossy().code_str()),
-                        None,
-                        None,
-                        Some(error),
-                    )
-                })?;
-            }
+        write_files(
+            typescript_directory,
+            generate_typescript::generate(VERSION, &schemas, typescript_options),
+        )?;
+    }
 
-            // Write the file.
-            eprintln!(
-                "Writing {}\u{2026}",
-                output_file_path.to_string_lossy().code_str(),
-            );
-            write(&output_file_path, contents).map_err(|error| {
+    // Generate JavaScript code, if applicable.
+    if let Some(javascript_directory) = javascript_directory {
+        eprintln!("Generating JavaScript\u{2026}");
+
+        // Generate the code and write it to the files.
+        write_files(
+            javascript_directory,
+            generate_javascript::generate(VERSION, &schemas, typescript_options),
+        )?;
+    }
+
+    eprintln!("Done.");
+    Ok(())
+}
+
+// Write files to the given directory, creating any missing ancestor directories.
+fn write_files(directory: &Path, files: BTreeMap<PathBuf, String>) -> Result<(), Error> {
+    for (relative_path, contents) in files {
+        let output_file_path = directory.join(&relative_path);
+
+        // Create any missing ancestor directories.
+        if let Some(parent) = output_file_path.parent() {
+            create_dir_all(parent).map_err(|error| {
                 throw(
-                    &format!(
-                        "Unable to write {}.",
-                        output_file_path.to_string_lossy().code_str(),
-                    ),
+                    &format!("Unable to create {}.", parent.to_string_lossy().code_str()),
                     None,
                     None,
                     Some(error),
                 )
             })?;
         }
+
+        // Write the file.
+        eprintln!(
+            "Writing {}\u{2026}",
+            output_file_path.to_string_lossy().code_str(),
+        );
+        write(&output_file_path, contents).map_err(|error| {
+            throw(
+                &format!(
+                    "Unable to write {}.",
+                    output_file_path.to_string_lossy().code_str(),
+                ),
+                None,
+                None,
+                Some(error),
+            )
+        })?;
     }
 
-    eprintln!("Done.");
     Ok(())
 }
 
 // Format a schema and its transitive dependencies.
-fn format_schema(schema_path: &Path, check: bool) -> Result<(), Error> {
+fn format_schema(schema_path: &Path, import_paths: &[PathBuf], check: bool) -> Result<(), Error> {
     // Load the schema and its transitive dependencies.
     eprintln!("Loading schemas\u{2026}");
-    let schemas = load_schemas(schema_path).map_err(|errors| merge_errors(&errors))?;
+    let schemas =
+        load_schemas(schema_path, import_paths).map_err(|errors| merge_errors(&errors))?;
     eprintln!("{} loaded.", count(schemas.len(), "schema"));
 
     // This flag will be set if any changes were made to any of the schemas.
@@ -261,11 +573,14 @@ fn format_schema(schema_path: &Path, check: bool) -> Result<(), Error> {
     // If the user only wants to check the formatting, fail if any of the schemas need to be
     // formatted.
     if check && any_schema_updated {
+        let mut command = format!("typical format {}", schema_path.to_string_lossy());
+        for import_path in import_paths {
+            command.push_str(" -I ");
+            command.push_str(&import_path.to_string_lossy());
+        }
+
         return Err(throw::<Error>(
-            &format!(
-                "Formatting mismatch. Please run {}.",
-                format!("typical format {}", schema_path.to_string_lossy()).code_str(),
-            ),
+            &format!("Formatting mismatch. Please run {}.", command.code_str()),
             None,
             None,
             None,
@@ -276,6 +591,115 @@ fn format_schema(schema_path: &Path, check: bool) -> Result<(), Error> {
     Ok(())
 }
 
+// Print the fingerprint (or wire fingerprint) of each declaration in a schema and its transitive
+// dependencies.
+fn print_fingerprints(
+    schema_path: &Path,
+    import_paths: &[PathBuf],
+    wire: bool,
+) -> Result<(), Error> {
+    // Load the schema and its transitive dependencies.
+    eprintln!("Loading schemas\u{2026}");
+    let mut schemas =
+        load_schemas(schema_path, import_paths).map_err(|errors| merge_errors(&errors))?;
+    eprintln!("{} loaded.", count(schemas.len(), "schema"));
+
+    // Validate the schemas.
+    eprintln!("Validating schemas\u{2026}");
+    validate(&schemas).map_err(|errors| merge_errors(&errors))?;
+
+    // The fingerprints are computed from the desugared schemas [ref:fingerprint]
+    // [ref:wire_fingerprint].
+    desugar(&mut schemas);
+
+    // Print the fingerprints, one declaration per line.
+    eprintln!("Computing fingerprints\u{2026}");
+    for (namespace, (schema, _, _)) in &schemas {
+        for declaration in &schema.declarations {
+            let mut path = String::new();
+            for component in &namespace.components {
+                path.push_str(component.original());
+                path.push_str("::");
+            }
+            path.push_str(declaration.name.original());
+
+            println!(
+                "{:016x} {path}",
+                if wire {
+                    wire_fingerprint(&schemas, namespace, &declaration.name)
+                } else {
+                    fingerprint(&schemas, namespace, &declaration.name)
+                },
+            );
+        }
+    }
+
+    eprintln!("Done.");
+    Ok(())
+}
+
+// Generate Markdown documentation for a schema and its transitive dependencies.
+fn generate_docs(
+    schema_path: &Path,
+    import_paths: &[PathBuf],
+    output_directory: &Path,
+) -> Result<(), Error> {
+    // Load the schema and its transitive dependencies.
+    eprintln!("Loading schemas\u{2026}");
+    let mut schemas =
+        load_schemas(schema_path, import_paths).map_err(|errors| merge_errors(&errors))?;
+    eprintln!("{} loaded.", count(schemas.len(), "schema"));
+
+    // Validate the schemas.
+    eprintln!("Validating schemas\u{2026}");
+    validate(&schemas).map_err(|errors| merge_errors(&errors))?;
+    generate_markdown::check_schema_paths(&schemas).map_err(|errors| merge_errors(&errors))?;
+
+    // Rewrite any syntactic conveniences for the documentation generator.
+    desugar(&mut schemas);
+
+    // Generate the documentation and write it to the files.
+    eprintln!("Generating documentation\u{2026}");
+    write_files(
+        output_directory,
+        generate_markdown::generate(VERSION, &schemas),
+    )?;
+
+    eprintln!("Done.");
+    Ok(())
+}
+
+// Print the dependency graph of the types in a schema and its transitive dependencies.
+fn print_graph(
+    schema_path: &Path,
+    import_paths: &[PathBuf],
+    format: generate_graph::Format,
+    collapse_namespaces: bool,
+) -> Result<(), Error> {
+    // Load the schema and its transitive dependencies.
+    eprintln!("Loading schemas\u{2026}");
+    let mut schemas =
+        load_schemas(schema_path, import_paths).map_err(|errors| merge_errors(&errors))?;
+    eprintln!("{} loaded.", count(schemas.len(), "schema"));
+
+    // Validate the schemas.
+    eprintln!("Validating schemas\u{2026}");
+    validate(&schemas).map_err(|errors| merge_errors(&errors))?;
+
+    // Rewrite any syntactic conveniences, e.g., so included fields are drawn too.
+    desugar(&mut schemas);
+
+    // Print the graph.
+    eprintln!("Generating graph\u{2026}");
+    print!(
+        "{}",
+        generate_graph::generate(&schemas, format, collapse_namespaces),
+    );
+
+    eprintln!("Done.");
+    Ok(())
+}
+
 // Print a shell completion script to STDOUT.
 fn shell_completion(shell: Shell) {
     // Write the script to STDOUT.
@@ -294,14 +718,53 @@ fn entry() -> Result<(), Error> {
             // Generate code for the schema and its transitive dependencies.
             generate_code(
                 &args.path,
+                &args.imports.import_paths,
                 args.list_schemas,
                 args.rust_file.as_deref(),
+                args.rust_dir.as_deref(),
+                &generate_rust::Options {
+                    asynchronous: args.rust_async,
+                    no_std: args.rust_no_std,
+                    runtime: args.rust_runtime,
+                    builders: args.rust_builders,
+                    arbitrary: args.rust_arbitrary,
+                    containers: args.rust_containers,
+                    type_overrides: args.rust_type_overrides,
+                },
+                args.rust_fuzz_dir.as_deref(),
                 args.typescript_dir.as_deref(),
+                args.javascript_dir.as_deref(),
+                &generate_typescript::Options {
+                    number_fields: args.typescript_number_fields,
+                    number_overflow: args.typescript_number_overflow,
+                    containers: args.typescript_containers,
+                },
             )?;
         }
         TypicalCommand::Format(args) => {
             // Format the schema and its transitive dependencies.
-            format_schema(&args.path, args.check)?;
+            format_schema(&args.path, &args.imports.import_paths, args.check)?;
+        }
+        TypicalCommand::Fingerprint(args) => {
+            // Print the fingerprints of the types in the schema and its transitive dependencies.
+            print_fingerprints(&args.path, &args.imports.import_paths, args.wire)?;
+        }
+        TypicalCommand::Docs(args) => {
+            // Generate documentation for the schema and its transitive dependencies.
+            generate_docs(&args.path, &args.imports.import_paths, &args.out)?;
+        }
+        TypicalCommand::Graph(args) => {
+            // Print the dependency graph for the schema and its transitive dependencies.
+            print_graph(
+                &args.path,
+                &args.imports.import_paths,
+                args.format,
+                args.collapse_namespaces,
+            )?;
+        }
+        TypicalCommand::Decode(args) => {
+            // Decode the messages in the container.
+            decode(&args.path)?;
         }
         TypicalCommand::ShellCompletion(args) => {
             // Generate the shell completion script.
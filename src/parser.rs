@@ -240,6 +240,7 @@ fn parse_schema(
     let mut comment = vec![];
     let mut imports = BTreeMap::new();
     let mut declarations = vec![];
+    let mut services = vec![];
 
     // Parse the comment, if it exists.
     if *position < tokens.len()
@@ -247,7 +248,10 @@ fn parse_schema(
     {
         if *position + 1 < tokens.len() {
             match tokens[*position + 1].variant {
-                token::Variant::Struct | token::Variant::Choice => {
+                token::Variant::Struct
+                | token::Variant::Choice
+                | token::Variant::Open
+                | token::Variant::Service => {
                     if tokens[*position].source_range.end + 1
                         < tokens[*position + 1].source_range.start
                     {
@@ -308,25 +312,64 @@ fn parse_schema(
             vec![]
         };
 
+        // Parse a service, if applicable.
+        if *position < tokens.len()
+            && let token::Variant::Service = tokens[*position].variant
+        {
+            if let Some(service) = parse_service(
+                source_path,
+                source_contents,
+                tokens,
+                start,
+                declaration_comment,
+                position,
+                errors,
+            ) {
+                services.push(service);
+
+                continue;
+            }
+
+            return schema::Schema {
+                comment,
+                imports,
+                declarations,
+                services,
+            };
+        }
+
+        // Parse the `open` modifier, if it exists.
+        let open = if *position < tokens.len()
+            && let token::Variant::Open = tokens[*position].variant
+        {
+            *position += 1;
+
+            true
+        } else {
+            false
+        };
+
         // Parse the keyword [tag:keyword_is_struct_or_choice].
-        let keyword =
-            if let token::Variant::Struct | token::Variant::Choice = tokens[*position].variant {
-                tokens[*position].variant.clone()
-            } else {
-                errors.push(unexpected_token(
-                    source_path,
-                    source_contents,
-                    tokens,
-                    *position,
-                    "a declaration",
-                ));
-
-                return schema::Schema {
-                    comment,
-                    imports,
-                    declarations,
-                };
+        let keyword = if *position < tokens.len()
+            && let token::Variant::Struct | token::Variant::Choice = tokens[*position].variant
+        {
+            tokens[*position].variant.clone()
+        } else {
+            errors.push(unexpected_token(
+                source_path,
+                source_contents,
+                tokens,
+                *position,
+                "a declaration",
+            ));
+
+            return schema::Schema {
+                comment,
+                imports,
+                declarations,
+                services,
             };
+        };
         *position += 1;
 
         // Parse the name.
@@ -352,6 +395,7 @@ fn parse_schema(
                 comment,
                 imports,
                 declarations,
+                services,
             },
         );
 
@@ -367,10 +411,12 @@ fn parse_schema(
                 comment,
                 imports,
                 declarations,
+                services,
             },
         );
 
-        // Parse the fields.
+        // Parse the includes and fields.
+        let mut includes = vec![];
         let mut fields = vec![];
         while *position < tokens.len() {
             match tokens[*position].variant {
@@ -378,56 +424,50 @@ fn parse_schema(
                 _ => {}
             }
 
-            if let Some(field) = parse_field(source_path, source_contents, tokens, position, errors)
+            // Determine whether the next item (after any comment) is an include.
+            let is_include = matches!(tokens[*position].variant, token::Variant::Include)
+                || (matches!(tokens[*position].variant, token::Variant::Comment(_))
+                    && matches!(
+                        tokens.get(*position + 1).map(|token| &token.variant),
+                        Some(token::Variant::Include),
+                    ));
+
+            if is_include {
+                if let Some(include) =
+                    parse_include(source_path, source_contents, tokens, position, errors)
+                {
+                    // In this case, [ref:parse_include_some_advance] guarantees that we will not
+                    // loop forever.
+                    includes.push(include);
+
+                    continue;
+                }
+            } else if let Some(field) =
+                parse_field(source_path, source_contents, tokens, position, errors)
             {
                 // In this case, [ref:parse_field_some_advance] guarantees that we will not
                 // loop forever.
                 fields.push(field.clone());
-            } else {
-                // Jump to the closing curly brace, if it exists. Otherwise, jump to the
-                // end of the source.
-                while *position < tokens.len() {
-                    if let token::Variant::RightCurly = tokens[*position].variant {
-                        break;
-                    }
 
-                    *position += 1;
-                }
-
-                break;
+                continue;
             }
-        }
-
-        // Parse the set of deleted fields.
-        let mut deleted = BTreeSet::new();
-        if *position != tokens.len() && matches!(tokens[*position].variant, token::Variant::Deleted)
-        {
-            *position += 1;
-
-            while *position != tokens.len() {
-                if let index_token @ token::Variant::Integer(index) = &tokens[*position].variant {
-                    if !deleted.insert(*index) {
-                        errors.push(throw::<Error>(
-                            &format!(
-                                "Index {} is already marked as deleted.",
-                                index_token.to_string().code_str(),
-                            ),
-                            Some(source_path),
-                            Some(&listing(
-                                source_contents,
-                                token_source_range(tokens, *position),
-                            )),
-                            None,
-                        ));
-                    }
 
-                    *position += 1;
-                } else {
+            // Jump to the closing curly brace, if it exists. Otherwise, jump to the end of the
+            // source.
+            while *position < tokens.len() {
+                if let token::Variant::RightCurly = tokens[*position].variant {
                     break;
                 }
+
+                *position += 1;
             }
+
+            break;
         }
 
+        // Parse the set of deleted fields.
+        let deleted = parse_deleted(source_path, source_contents, tokens, position, errors);
+
         // Consume the `}`.
         consume_token_0!(
             source_path,
@@ -440,6 +480,7 @@ fn parse_schema(
                 comment,
                 imports,
                 declarations,
+                services,
             },
         );
 
@@ -456,7 +497,9 @@ fn parse_schema(
                     panic!()
                 }
             },
+            open,
             name,
+            includes,
             fields,
             deleted,
         });
@@ -467,12 +510,248 @@ fn parse_schema(
         comment,
         imports,
         declarations,
+        services,
+    }
+}
+
+// Parse the optional `deleted` clause at the end of a declaration or service.
+fn parse_deleted(
+    source_path: &Path,
+    source_contents: &str,
+    tokens: &[token::Token],
+    position: &mut usize,
+    errors: &mut Vec<Error>,
+) -> BTreeSet<usize> {
+    let mut deleted = BTreeSet::new();
+
+    if *position != tokens.len() && matches!(tokens[*position].variant, token::Variant::Deleted) {
+        *position += 1;
+
+        while *position != tokens.len() {
+            if let index_token @ token::Variant::Integer(index) = &tokens[*position].variant {
+                if !deleted.insert(*index) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "Index {} is already marked as deleted.",
+                            index_token.to_string().code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(
+                            source_contents,
+                            token_source_range(tokens, *position),
+                        )),
+                        None,
+                    ));
+                }
+
+                *position += 1;
+            } else {
+                break;
+            }
+        }
     }
+
+    deleted
+}
+
+// Parse a service, starting at the `service` keyword. The `start` position and `comment` refer to
+// the comment preceding the keyword, if any. If this function returns `None`, then at least one
+// error was added to `errors`.
+fn parse_service(
+    source_path: &Path,
+    source_contents: &str,
+    tokens: &[token::Token],
+    start: usize,
+    comment: Vec<String>,
+    position: &mut usize,
+    errors: &mut Vec<Error>,
+) -> Option<schema::Service> {
+    // Consume the `service` keyword.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Service,
+        None,
+    );
+
+    // Parse the name.
+    let name = consume_token_1!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Identifier,
+        "a name for the service",
+        None,
+    );
+
+    // Consume the `{`.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        LeftCurly,
+        None,
+    );
+
+    // Parse the methods.
+    let mut methods = vec![];
+    while *position < tokens.len() {
+        match tokens[*position].variant {
+            token::Variant::Deleted | token::Variant::RightCurly => break,
+            _ => {}
+        }
+
+        if let Some(method) = parse_method(source_path, source_contents, tokens, position, errors) {
+            // In this case, [ref:parse_method_some_advance] guarantees that we will not loop
+            // forever.
+            methods.push(method);
+        } else {
+            // Jump to the closing curly brace, if it exists. Otherwise, jump to the end of the
+            // source.
+            while *position < tokens.len() {
+                if let token::Variant::RightCurly = tokens[*position].variant {
+                    break;
+                }
+
+                *position += 1;
+            }
+
+            break;
+        }
+    }
+
+    // Parse the set of deleted methods.
+    let deleted = parse_deleted(source_path, source_contents, tokens, position, errors);
+
+    // Consume the `}`.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        RightCurly,
+        None,
+    );
+
+    // Construct and return the service.
+    Some(schema::Service {
+        source_range: span_tokens(tokens, start, *position),
+        comment,
+        name,
+        methods,
+        deleted,
+    })
+}
+
+// Parse a service method. If this function returns `None`, then at least one error was added to
+// `errors`. Otherwise, the `position` is guaranteed to have advanced
+// [tag:parse_method_some_advance].
+fn parse_method(
+    source_path: &Path,
+    source_contents: &str,
+    tokens: &[token::Token],
+    position: &mut usize,
+    errors: &mut Vec<Error>,
+) -> Option<schema::Method> {
+    let start = *position;
+
+    // Parse the comment, if it exists.
+    let comment = if *position == tokens.len() {
+        vec![]
+    } else if let token::Variant::Comment(paragraphs) = &tokens[*position].variant {
+        *position += 1;
+
+        paragraphs.clone()
+    } else {
+        vec![]
+    };
+
+    // Parse the name.
+    let name = consume_token_1!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Identifier,
+        "a method",
+        None,
+    );
+
+    // Consume the colon.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Colon,
+        None,
+    );
+
+    // Parse the request type.
+    let request = parse_type(source_path, source_contents, tokens, position, errors)?;
+
+    // Consume the arrow.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Arrow,
+        None,
+    );
+
+    // Parse the response type.
+    let response = parse_type(source_path, source_contents, tokens, position, errors)?;
+
+    // Consume the equals sign.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Equals,
+        None,
+    );
+
+    // Parse the index.
+    let index = consume_token_1!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Integer,
+        "an index for the method",
+        None,
+    );
+
+    // Return the method.
+    Some(schema::Method {
+        source_range: span_tokens(tokens, start, *position),
+        comment,
+        name,
+        request,
+        response,
+        index,
+    })
 }
 
 // Parse an import. If this function returns `None`, then at least one error was added to `errors`.
 // If the starting token is the `import` keyword, then this function is guaranteed to advance the
 // `position` [tag:parse_import_keyword_chomp].
+#[allow(clippy::too_many_lines)]
 fn parse_import(
     source_path: &Path,
     source_contents: &str,
@@ -554,6 +833,58 @@ fn parse_import(
         REPLACEMENT_CHARACTER.to_string().as_str().into()
     };
 
+    // Parse the list of names to import without qualification, if it exists.
+    let mut names = vec![];
+    if *position < tokens.len()
+        && let token::Variant::LeftCurly = tokens[*position].variant
+    {
+        *position += 1;
+
+        while *position < tokens.len() {
+            if let token::Variant::RightCurly = tokens[*position].variant {
+                break;
+            }
+
+            // This is guaranteed to advance the token position due to
+            // [ref:parse_imported_name_some_advance].
+            names.push(parse_imported_name(
+                source_path,
+                source_contents,
+                tokens,
+                position,
+                errors,
+            )?);
+
+            // Consume the `,`, unless this is the last name.
+            if *position < tokens.len()
+                && let token::Variant::RightCurly = tokens[*position].variant
+            {
+                break;
+            }
+
+            consume_token_0!(
+                source_path,
+                source_contents,
+                tokens,
+                &mut *position,
+                errors,
+                Comma,
+                None,
+            );
+        }
+
+        // Consume the `}`.
+        consume_token_0!(
+            source_path,
+            source_contents,
+            tokens,
+            &mut *position,
+            errors,
+            RightCurly,
+            None,
+        );
+    }
+
     // Construct and return the import.
     Some((
         name,
@@ -561,10 +892,132 @@ fn parse_import(
             source_range: span_tokens(tokens, start, *position),
             path,
             namespace: None,
+            names,
         },
     ))
 }
 
+// Parse a name in the list of names of an import. If this function returns `None`, then at least
+// one error was added to `errors`. Otherwise, the `position` is guaranteed to have advanced
+// [tag:parse_imported_name_some_advance].
+fn parse_imported_name(
+    source_path: &Path,
+    source_contents: &str,
+    tokens: &[token::Token],
+    position: &mut usize,
+    errors: &mut Vec<Error>,
+) -> Option<schema::ImportedName> {
+    let start = *position;
+
+    // Parse the name.
+    let name = consume_token_1!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Identifier,
+        "the name of a type to import",
+        None,
+    );
+
+    // Parse the alias, if it exists.
+    let alias = if *position < tokens.len()
+        && let token::Variant::As = tokens[*position].variant
+    {
+        *position += 1;
+
+        consume_token_1!(
+            source_path,
+            source_contents,
+            tokens,
+            &mut *position,
+            errors,
+            Identifier,
+            "an alias for the imported type",
+            None,
+        )
+    } else {
+        name.clone()
+    };
+
+    // Return the name.
+    Some(schema::ImportedName {
+        source_range: span_tokens(tokens, start, *position),
+        name,
+        alias,
+    })
+}
+
+// Parse an include. If this function returns `None`, then at least one error was added to
+// `errors`. Otherwise, the `position` is guaranteed to have advanced
+// [tag:parse_include_some_advance].
+fn parse_include(
+    source_path: &Path,
+    source_contents: &str,
+    tokens: &[token::Token],
+    position: &mut usize,
+    errors: &mut Vec<Error>,
+) -> Option<schema::Include> {
+    let start = *position;
+
+    // Parse the comment, if it exists.
+    let comment = if *position == tokens.len() {
+        vec![]
+    } else if let token::Variant::Comment(paragraphs) = &tokens[*position].variant {
+        *position += 1;
+
+        paragraphs.clone()
+    } else {
+        vec![]
+    };
+
+    // Consume the `include` keyword.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Include,
+        None,
+    );
+
+    // Parse the type [ref:parse_type_some_advance].
+    let r#type = parse_type(source_path, source_contents, tokens, position, errors)?;
+
+    // Consume the `at` keyword.
+    consume_token_0!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        At,
+        None,
+    );
+
+    // Parse the offset.
+    let offset = consume_token_1!(
+        source_path,
+        source_contents,
+        tokens,
+        &mut *position,
+        errors,
+        Integer,
+        "an offset for the included fields",
+        None,
+    );
+
+    // Return the include.
+    Some(schema::Include {
+        source_range: span_tokens(tokens, start, *position),
+        comment,
+        r#type,
+        offset,
+    })
+}
+
 // Parse a field. If this function returns `None`, then at least one error was added to `errors`.
 // Otherwise, the `position` is guaranteed to have advanced [tag:parse_field_some_advance].
 #[allow(clippy::too_many_lines)]
@@ -882,6 +1335,7 @@ mod tests {
                 comment: vec![],
                 imports: BTreeMap::new(),
                 declarations: vec![],
+                services: vec![],
             }),
         );
     }
@@ -928,6 +1382,7 @@ mod tests {
                 source_range: SourceRange { start: 55, end: 69 },
                 path: Path::new("baz.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -940,6 +1395,7 @@ mod tests {
                 },
                 path: Path::new("qux.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -951,7 +1407,9 @@ mod tests {
                 },
                 comment: vec!["This is a struct.".to_owned()],
                 variant: schema::DeclarationVariant::Struct,
+                open: false,
                 name: "Foo".into(),
+                includes: vec![],
                 fields: vec![
                     schema::Field {
                         source_range: SourceRange {
@@ -1037,7 +1495,9 @@ mod tests {
                 },
                 comment: vec!["This is a choice.".to_owned()],
                 variant: schema::DeclarationVariant::Choice,
+                open: false,
                 name: "Bar".into(),
+                includes: vec![],
                 fields: vec![
                     schema::Field {
                         source_range: SourceRange {
@@ -1127,6 +1587,7 @@ mod tests {
                 comment: vec!["This is an example schema.".to_owned()],
                 imports,
                 declarations,
+                services: vec![],
             }),
         );
     }
@@ -1146,6 +1607,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_imported_names() {
+        let source_path = Path::new("foo.t");
+        let source = "import 'bar.t' { Bar, Baz as Qux, }";
+        let tokens = tokenize(source_path, source).unwrap();
+        let schema = parse(source_path, source, &tokens[..]).unwrap();
+
+        assert_same!(
+            schema.imports[&"bar".into()].names.clone(),
+            vec![
+                schema::ImportedName {
+                    source_range: SourceRange { start: 17, end: 20 },
+                    name: "Bar".into(),
+                    alias: "Bar".into(),
+                },
+                schema::ImportedName {
+                    source_range: SourceRange { start: 22, end: 32 },
+                    name: "Baz".into(),
+                    alias: "Qux".into(),
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn parse_imported_names_missing_comma() {
+        let source_path = Path::new("foo.t");
+        let source = "import 'bar.t' { Bar Baz }";
+        let tokens = tokenize(source_path, source).unwrap();
+
+        assert_fails!(
+            parse(source_path, source, &tokens[..]),
+            "Expected `,`, but encountered `Baz`.",
+        );
+    }
+
+    #[test]
+    fn parse_include() {
+        let source_path = Path::new("foo.t");
+        let source = "struct Foo { include bar.Bar at 100 }";
+        let tokens = tokenize(source_path, source).unwrap();
+        let schema = parse(source_path, source, &tokens[..]).unwrap();
+
+        assert_same!(
+            schema.declarations[0].includes.clone(),
+            vec![schema::Include {
+                source_range: SourceRange { start: 13, end: 35 },
+                comment: vec![],
+                r#type: schema::Type {
+                    source_range: SourceRange { start: 21, end: 28 },
+                    variant: schema::TypeVariant::Custom(Some("bar".into()), "Bar".into()),
+                },
+                offset: 100,
+            }],
+        );
+    }
+
+    #[test]
+    fn parse_include_missing_offset() {
+        let source_path = Path::new("foo.t");
+        let source = "struct Foo { include Bar }";
+        let tokens = tokenize(source_path, source).unwrap();
+
+        assert_fails!(
+            parse(source_path, source, &tokens[..]),
+            "Expected `at`, but encountered `}`.",
+        );
+    }
+
+    #[test]
+    fn parse_open() {
+        let source_path = Path::new("foo.t");
+        let source = "open choice Foo { x = 0 }";
+        let tokens = tokenize(source_path, source).unwrap();
+        let schema = parse(source_path, source, &tokens[..]).unwrap();
+
+        assert!(schema.declarations[0].open);
+        assert_same!(
+            schema.declarations[0].source_range,
+            SourceRange { start: 0, end: 25 },
+        );
+    }
+
+    #[test]
+    fn parse_open_missing_keyword() {
+        let source_path = Path::new("foo.t");
+        let source = "open Foo { x = 0 }";
+        let tokens = tokenize(source_path, source).unwrap();
+
+        assert_fails!(
+            parse(source_path, source, &tokens[..]),
+            "Expected a declaration, but encountered `Foo`.",
+        );
+    }
+
     #[test]
     fn parse_duplicate_deleted_index() {
         let source_path = Path::new("foo.t");
@@ -1161,4 +1717,57 @@ mod tests {
             "Index `2` is already marked as deleted.",
         );
     }
+
+    #[test]
+    fn parse_service() {
+        let source_path = Path::new("foo.t");
+        let source =
+            "# This is a service.\nservice Foo {\n  x: Bar -> baz.Qux = 0\n  deleted 1\n}\n";
+        let tokens = tokenize(source_path, source).unwrap();
+
+        assert_same!(
+            parse(source_path, source, &tokens[..]),
+            Ok(schema::Schema {
+                comment: vec![],
+                imports: BTreeMap::new(),
+                declarations: vec![],
+                services: vec![schema::Service {
+                    source_range: SourceRange { start: 0, end: 72 },
+                    comment: vec!["This is a service.".to_owned()],
+                    name: "Foo".into(),
+                    methods: vec![schema::Method {
+                        source_range: SourceRange { start: 37, end: 58 },
+                        comment: vec![],
+                        name: "x".into(),
+                        request: schema::Type {
+                            source_range: SourceRange { start: 40, end: 43 },
+                            variant: schema::TypeVariant::Custom(None, "Bar".into()),
+                        },
+                        response: schema::Type {
+                            source_range: SourceRange { start: 47, end: 54 },
+                            variant: schema::TypeVariant::Custom(Some("baz".into()), "Qux".into()),
+                        },
+                        index: 0,
+                    }],
+                    deleted: BTreeSet::from_iter(vec![1]),
+                }],
+            }),
+        );
+    }
+
+    #[test]
+    fn parse_service_missing_arrow() {
+        let source_path = Path::new("foo.t");
+        let source = "
+            service Foo {
+                x: Bar = 0
+            }
+        ";
+        let tokens = tokenize(source_path, source).unwrap();
+
+        assert_fails!(
+            parse(source_path, source, &tokens[..]),
+            "Expected `->`, but encountered `=`.",
+        );
+    }
 }
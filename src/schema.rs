@@ -2,9 +2,10 @@ use crate::{
     error::SourceRange,
     identifier::Identifier,
     token::{
-        AS_KEYWORD, ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
-        DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD,
-        STRING_KEYWORD, STRUCT_KEYWORD, U64_KEYWORD, UNIT_KEYWORD,
+        AS_KEYWORD, ASYMMETRIC_KEYWORD, AT_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
+        DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, INCLUDE_KEYWORD, OPEN_KEYWORD,
+        OPTIONAL_KEYWORD, S64_KEYWORD, SERVICE_KEYWORD, STRING_KEYWORD, STRUCT_KEYWORD,
+        U64_KEYWORD, UNIT_KEYWORD,
     },
 };
 use std::{
@@ -15,11 +16,15 @@ use std::{
 
 const MAX_COLUMNS: usize = 79;
 
+// The name of the case which represents unrecognized fields in the generated code for open choices
+pub const UNKNOWN_FIELD_NAME: &str = "unknown";
+
 #[derive(Clone, Debug)]
 pub struct Schema {
     pub comment: Vec<String>,
     pub imports: BTreeMap<Identifier, Import>,
     pub declarations: Vec<Declaration>,
+    pub services: Vec<Service>,
 }
 
 #[derive(Clone, Debug)]
@@ -27,6 +32,14 @@ pub struct Import {
     pub source_range: SourceRange,
     pub path: PathBuf, // The literal path as it appears in the source file
     pub namespace: Option<Namespace>, // A normalized form of the path
+    pub names: Vec<ImportedName>, // Declarations which can be referenced without qualification
+}
+
+#[derive(Clone, Debug)]
+pub struct ImportedName {
+    pub source_range: SourceRange,
+    pub name: Identifier,  // The name of the declaration in the imported file
+    pub alias: Identifier, // The name by which the declaration is referenced in this file
 }
 
 #[derive(Clone, Debug)]
@@ -34,7 +47,9 @@ pub struct Declaration {
     pub source_range: SourceRange,
     pub comment: Vec<String>,
     pub variant: DeclarationVariant,
+    pub open: bool, // Whether unrecognized fields are preserved rather than discarded
     pub name: Identifier,
+    pub includes: Vec<Include>,
     pub fields: Vec<Field>,
     pub deleted: BTreeSet<usize>,
 }
@@ -55,6 +70,14 @@ pub struct Field {
     pub index: usize,
 }
 
+#[derive(Clone, Debug)]
+pub struct Include {
+    pub source_range: SourceRange,
+    pub comment: Vec<String>,
+    pub r#type: Type,
+    pub offset: usize, // Added to the indices of the fields of the included struct
+}
+
 #[derive(Clone, Debug)]
 pub enum Rule {
     Asymmetric,
@@ -62,6 +85,25 @@ pub enum Rule {
     Required,
 }
 
+#[derive(Clone, Debug)]
+pub struct Service {
+    pub source_range: SourceRange,
+    pub comment: Vec<String>,
+    pub name: Identifier,
+    pub methods: Vec<Method>,
+    pub deleted: BTreeSet<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Method {
+    pub source_range: SourceRange,
+    pub comment: Vec<String>,
+    pub name: Identifier,
+    pub request: Type,
+    pub response: Type,
+    pub index: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct Type {
     pub source_range: SourceRange,
@@ -134,10 +176,35 @@ fn write_comment<W: Write>(indentation: &str, paragraphs: &[String], f: &mut W)
 }
 
 impl Schema {
+    // Determine which declaration a reference to a user-defined type refers to. Unqualified
+    // references to declarations imported by name are resolved to the corresponding import and the
+    // original name of the declaration. Other references are returned unchanged.
+    pub fn qualify(
+        &self,
+        import: Option<&Identifier>,
+        name: &Identifier,
+    ) -> (Option<Identifier>, Identifier) {
+        if import.is_none() {
+            for (import_name, import) in &self.imports {
+                for imported_name in &import.names {
+                    if imported_name.alias == *name {
+                        return (Some(import_name.clone()), imported_name.name.clone());
+                    }
+                }
+            }
+        }
+
+        (import.cloned(), name.clone())
+    }
+
     fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
         write_comment("", &self.comment, f)?;
 
-        if !self.comment.is_empty() && (!self.imports.is_empty() || !self.declarations.is_empty()) {
+        if !self.comment.is_empty()
+            && (!self.imports.is_empty()
+                || !self.declarations.is_empty()
+                || !self.services.is_empty())
+        {
             writeln!(f)?;
         }
 
@@ -157,6 +224,16 @@ impl Schema {
             declaration.write(f)?;
         }
 
+        for service in &self.services {
+            if skip_blank_line {
+                skip_blank_line = false;
+            } else {
+                writeln!(f)?;
+            }
+
+            service.write(f)?;
+        }
+
         Ok(())
     }
 }
@@ -170,17 +247,48 @@ impl Import {
             .as_ref()
             == Some(name)
         {
-            writeln!(f, "{} '{}'", IMPORT_KEYWORD, self.path.display())
+            write!(f, "{} '{}'", IMPORT_KEYWORD, self.path.display())?;
         } else {
-            writeln!(
+            write!(
                 f,
                 "{} '{}' {} {}",
                 IMPORT_KEYWORD,
                 self.path.display(),
                 AS_KEYWORD,
                 name.snake_case(),
-            )
+            )?;
+        }
+
+        if !self.names.is_empty() {
+            let mut names = self.names.iter().collect::<Vec<_>>();
+            names.sort_by(|x, y| (&x.name, &x.alias).cmp(&(&y.name, &y.alias)));
+
+            write!(f, " {{ ")?;
+
+            for (i, imported_name) in names.iter().enumerate() {
+                if i != 0 {
+                    write!(f, ", ")?;
+                }
+
+                imported_name.write(f)?;
+            }
+
+            write!(f, " }}")?;
         }
+
+        writeln!(f)
+    }
+}
+
+impl ImportedName {
+    fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write!(f, "{}", self.name.pascal_case())?;
+
+        if self.alias != self.name {
+            write!(f, " {} {}", AS_KEYWORD, self.alias.pascal_case())?;
+        }
+
+        Ok(())
     }
 }
 
@@ -188,23 +296,40 @@ impl Declaration {
     fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
         write_comment("", &self.comment, f)?;
 
+        if self.open {
+            write!(f, "{OPEN_KEYWORD} ")?;
+        }
+
         self.variant.write(f)?;
 
         writeln!(f, " {} {{", self.name.pascal_case())?;
 
-        let mut previous_field_has_comment = false;
+        let mut previous_item_has_comment = false;
+
+        for (i, include) in self.includes.iter().enumerate() {
+            if (previous_item_has_comment || !include.comment.is_empty()) && i != 0 {
+                writeln!(f)?;
+            }
+
+            previous_item_has_comment = !include.comment.is_empty();
+
+            include.write(f)?;
+        }
 
         for (i, field) in self.fields.iter().enumerate() {
-            if (previous_field_has_comment || !field.comment.is_empty()) && i != 0 {
+            // The includes are separated from the fields by a blank line.
+            if (i == 0 && !self.includes.is_empty())
+                || (i != 0 && (previous_item_has_comment || !field.comment.is_empty()))
+            {
                 writeln!(f)?;
             }
 
-            previous_field_has_comment = !field.comment.is_empty();
+            previous_item_has_comment = !field.comment.is_empty();
 
             field.write(f)?;
         }
 
-        if !self.fields.is_empty() && !self.deleted.is_empty() {
+        if (!self.includes.is_empty() || !self.fields.is_empty()) && !self.deleted.is_empty() {
             writeln!(f)?;
         }
 
@@ -231,6 +356,16 @@ impl DeclarationVariant {
     }
 }
 
+impl Include {
+    fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_comment("    ", &self.comment, f)?;
+
+        write!(f, "    {INCLUDE_KEYWORD} ")?;
+        self.r#type.write(f)?;
+        writeln!(f, " {} {}", AT_KEYWORD, self.offset)
+    }
+}
+
 impl Field {
     fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
         write_comment("    ", &self.comment, f)?;
@@ -259,6 +394,57 @@ impl Field {
     }
 }
 
+impl Service {
+    fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_comment("", &self.comment, f)?;
+
+        writeln!(f, "{} {} {{", SERVICE_KEYWORD, self.name.pascal_case())?;
+
+        let mut previous_method_has_comment = false;
+
+        for (i, method) in self.methods.iter().enumerate() {
+            if (previous_method_has_comment || !method.comment.is_empty()) && i != 0 {
+                writeln!(f)?;
+            }
+
+            previous_method_has_comment = !method.comment.is_empty();
+
+            method.write(f)?;
+        }
+
+        if !self.methods.is_empty() && !self.deleted.is_empty() {
+            writeln!(f)?;
+        }
+
+        if !self.deleted.is_empty() {
+            write!(f, "    {DELETED_KEYWORD}")?;
+
+            for deleted_index in &self.deleted {
+                write!(f, " {deleted_index}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Method {
+    fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
+        write_comment("    ", &self.comment, f)?;
+
+        writeln!(
+            f,
+            "    {}: {} -> {} = {}",
+            self.name.snake_case(),
+            self.request,
+            self.response,
+            self.index,
+        )
+    }
+}
+
 impl Type {
     fn write<W: Write>(&self, f: &mut W) -> fmt::Result {
         self.variant.write(f)
@@ -343,8 +529,8 @@ mod tests {
         assert_same,
         error::SourceRange,
         schema::{
-            Declaration, DeclarationVariant, Field, Import, Namespace, Rule, Schema, Type,
-            TypeVariant, relativize_namespace,
+            Declaration, DeclarationVariant, Field, Import, ImportedName, Include, Method,
+            Namespace, Rule, Schema, Service, Type, TypeVariant, relativize_namespace,
         },
     };
     use std::{
@@ -490,6 +676,7 @@ mod tests {
             comment: vec![],
             imports: BTreeMap::new(),
             declarations: vec![],
+            services: vec![],
         };
 
         let expected = "";
@@ -497,6 +684,45 @@ mod tests {
         assert_eq!(schema.to_string(), expected);
     }
 
+    #[test]
+    fn schema_imported_names_display() {
+        let mut imports = BTreeMap::new();
+
+        imports.insert(
+            "foo".into(),
+            Import {
+                source_range: SourceRange { start: 0, end: 0 },
+                path: Path::new("foo.t").to_owned(),
+                namespace: None,
+                names: vec![
+                    ImportedName {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        name: "qux".into(),
+                        alias: "Corge".into(),
+                    },
+                    ImportedName {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        name: "bar".into(),
+                        alias: "bar".into(),
+                    },
+                ],
+            },
+        );
+
+        let schema = Schema {
+            comment: vec![],
+            imports,
+            declarations: vec![],
+            services: vec![],
+        };
+
+        let expected = "\
+            import 'foo.t' { Bar, Qux as Corge }\n\
+        ";
+
+        assert_eq!(schema.to_string(), expected);
+    }
+
     #[test]
     fn schema_imports_only_display() {
         let mut imports = BTreeMap::new();
@@ -507,6 +733,7 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 path: Path::new("foo.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -516,6 +743,7 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 path: Path::new("bar.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -523,6 +751,7 @@ mod tests {
             comment: vec![],
             imports,
             declarations: vec![],
+            services: vec![],
         };
 
         let expected = "\
@@ -540,7 +769,9 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 comment: vec![],
                 variant: DeclarationVariant::Struct,
+                open: false,
                 name: "foo".into(),
+                includes: vec![],
                 fields: vec![
                     Field {
                         source_range: SourceRange { start: 0, end: 0 },
@@ -571,7 +802,9 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 comment: vec![],
                 variant: DeclarationVariant::Choice,
+                open: false,
                 name: "bar".into(),
+                includes: vec![],
                 fields: vec![
                     Field {
                         source_range: SourceRange { start: 0, end: 0 },
@@ -604,6 +837,7 @@ mod tests {
             comment: vec![],
             imports: BTreeMap::new(),
             declarations,
+            services: vec![],
         };
 
         let expected = "\
@@ -632,6 +866,7 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 path: Path::new("foo.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -641,6 +876,7 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 path: Path::new("bar.t").to_owned(),
                 namespace: None,
+                names: vec![],
             },
         );
 
@@ -649,7 +885,9 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 comment: vec!["This is a struct.".to_owned()],
                 variant: DeclarationVariant::Struct,
+                open: false,
                 name: "foo".into(),
+                includes: vec![],
                 fields: vec![
                     Field {
                         source_range: SourceRange { start: 0, end: 0 },
@@ -680,7 +918,9 @@ mod tests {
                 source_range: SourceRange { start: 0, end: 0 },
                 comment: vec!["This is a choice.".to_owned()],
                 variant: DeclarationVariant::Choice,
+                open: false,
                 name: "bar".into(),
+                includes: vec![],
                 fields: vec![
                     Field {
                         source_range: SourceRange { start: 0, end: 0 },
@@ -720,6 +960,7 @@ mod tests {
             ],
             imports,
             declarations,
+            services: vec![],
         };
 
         let expected = "\
@@ -757,6 +998,186 @@ mod tests {
         assert_eq!(schema.to_string(), expected);
     }
 
+    #[test]
+    fn schema_includes_display() {
+        let declarations = vec![Declaration {
+            source_range: SourceRange { start: 0, end: 0 },
+            comment: vec![],
+            variant: DeclarationVariant::Struct,
+            open: false,
+            name: "foo".into(),
+            includes: vec![
+                Include {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    comment: vec![],
+                    r#type: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(Some("bar".into()), "Bar".into()),
+                    },
+                    offset: 100,
+                },
+                Include {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    comment: vec!["This is an include.".to_owned()],
+                    r#type: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(None, "Baz".into()),
+                    },
+                    offset: 200,
+                },
+            ],
+            fields: vec![Field {
+                source_range: SourceRange { start: 0, end: 0 },
+                comment: vec![],
+                rule: Rule::Required,
+                name: "x".into(),
+                r#type: Type {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    variant: TypeVariant::Bool,
+                },
+                index: 0,
+            }],
+            deleted: BTreeSet::from_iter(vec![1]),
+        }];
+
+        let schema = Schema {
+            comment: vec![],
+            imports: BTreeMap::new(),
+            declarations,
+            services: vec![],
+        };
+
+        let expected = "\
+            struct Foo {\n\
+            \x20   include bar.Bar at 100\n\
+            \n\
+            \x20   # This is an include.\n\
+            \x20   include Baz at 200\n\
+            \n\
+            \x20   x: Bool = 0\n\
+            \n\
+            \x20   deleted 1\n\
+            }\n\
+        ";
+
+        assert_eq!(schema.to_string(), expected);
+    }
+
+    #[test]
+    fn schema_open_display() {
+        let declarations = vec![Declaration {
+            source_range: SourceRange { start: 0, end: 0 },
+            comment: vec!["This is an open choice.".to_owned()],
+            variant: DeclarationVariant::Choice,
+            open: true,
+            name: "foo".into(),
+            includes: vec![],
+            fields: vec![Field {
+                source_range: SourceRange { start: 0, end: 0 },
+                comment: vec![],
+                rule: Rule::Required,
+                name: "x".into(),
+                r#type: Type {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    variant: TypeVariant::Bool,
+                },
+                index: 0,
+            }],
+            deleted: BTreeSet::new(),
+        }];
+
+        let schema = Schema {
+            comment: vec![],
+            imports: BTreeMap::new(),
+            declarations,
+            services: vec![],
+        };
+
+        let expected = "\
+            # This is an open choice.\n\
+            open choice Foo {\n\
+            \x20   x: Bool = 0\n\
+            }\n\
+        ";
+
+        assert_eq!(schema.to_string(), expected);
+    }
+
+    #[test]
+    fn schema_services_display() {
+        let declarations = vec![Declaration {
+            source_range: SourceRange { start: 0, end: 0 },
+            comment: vec![],
+            variant: DeclarationVariant::Struct,
+            open: false,
+            name: "foo".into(),
+            includes: vec![],
+            fields: vec![],
+            deleted: BTreeSet::new(),
+        }];
+
+        let services = vec![Service {
+            source_range: SourceRange { start: 0, end: 0 },
+            comment: vec!["This is a service.".to_owned()],
+            name: "bar".into(),
+            methods: vec![
+                Method {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    comment: vec![],
+                    name: "X".into(),
+                    request: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(None, "foo".into()),
+                    },
+                    response: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(None, "foo".into()),
+                    },
+                    index: 0,
+                },
+                Method {
+                    source_range: SourceRange { start: 0, end: 0 },
+                    comment: vec!["This is a method.".to_owned()],
+                    name: "Y".into(),
+                    request: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(None, "foo".into()),
+                    },
+                    response: Type {
+                        source_range: SourceRange { start: 0, end: 0 },
+                        variant: TypeVariant::Custom(Some("qux".into()), "baz".into()),
+                    },
+                    index: 1,
+                },
+            ],
+            deleted: BTreeSet::from_iter(vec![2]),
+        }];
+
+        let schema = Schema {
+            comment: vec![],
+            imports: BTreeMap::new(),
+            declarations,
+            services,
+        };
+
+        let expected = "\
+            struct Foo {\n\
+            }\n\
+            \n\
+            # This is a service.\n\
+            service Bar {\n\
+            \x20   x: Foo -> Foo = 0\n\
+            \n\
+            \x20   # This is a method.\n\
+            \x20   y: Foo -> qux.Baz = 1\n\
+            \n\
+            \x20   deleted 2\n\
+            }\n\
+        ";
+
+        assert_eq!(schema.to_string(), expected);
+    }
+
     #[test]
     fn type_display_array() {
         let r#type = Type {
@@ -7,9 +7,10 @@ use crate::{
 };
 use std::{
     borrow::ToOwned,
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, HashMap},
     fs::read_to_string,
     io::{self, ErrorKind},
+    iter::once,
     path::{Component, Path, PathBuf},
 };
 
@@ -33,12 +34,14 @@ fn path_to_namespace(path: &Path) -> schema::Namespace {
     }
 }
 
-// Load a schema and its transitive dependencies. The imports in the returned schemas are guaranteed
-// to resolve.
+// Load a schema and its transitive dependencies. Imports are resolved relative to the importing
+// schema first, and then relative to each of the given import paths in order. The imports in the
+// returned schemas are guaranteed to resolve.
 #[allow(clippy::too_many_lines)]
 #[allow(clippy::type_complexity)]
 pub fn load_schemas(
     schema_path: &Path,
+    import_paths: &[PathBuf],
 ) -> Result<BTreeMap<schema::Namespace, (schema::Schema, PathBuf, String)>, Vec<Error>> {
     // The schema and all its transitive dependencies will end up here.
     let mut schemas = BTreeMap::new();
@@ -92,6 +95,43 @@ pub fn load_schemas(
         }
     };
 
+    // Canonicalize the import paths. Like the base directory, these are used to calculate
+    // namespaces below.
+    let mut canonical_import_paths = vec![];
+    for import_path in import_paths {
+        match import_path.canonicalize() {
+            Ok(canonical_import_path) if canonical_import_path.is_dir() => {
+                canonical_import_paths.push(canonical_import_path);
+            }
+            Ok(_) => {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "{} is not a directory.",
+                        import_path.to_string_lossy().code_str(),
+                    ),
+                    None,
+                    None,
+                    None,
+                ));
+            }
+            Err(error) => {
+                errors.push(throw(
+                    &format!(
+                        "{} is not a directory.",
+                        import_path.to_string_lossy().code_str(),
+                    ),
+                    None,
+                    None,
+                    Some(error),
+                ));
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
     // Relative to the base directory, the path to the schema is the name of the schema file
     // [tag:based_schema_path_is_file_name].
     let based_schema_path = if let Some(based_schema_path) = schema_path.file_name() {
@@ -114,15 +154,26 @@ pub fn load_schemas(
     // [ref:based_schema_path_is_file_name].
     let schema_namespace = path_to_namespace(based_schema_path);
 
-    // Initialize the "frontier" with the given path. Paths in the frontier are relative to
-    // `base_path` [tag:frontier_paths_based].
+    // Initialize the "frontier" with the given path. Paths in the frontier are either relative to
+    // `base_path` or absolute [tag:frontier_paths_based].
     let mut schemas_to_load = vec![(
         schema_namespace.clone(),
         based_schema_path.to_owned(),
         None as Option<(PathBuf, String)>,
     )];
-    let mut visited_namespaces = HashSet::new();
-    visited_namespaces.insert(schema_namespace);
+
+    // These track which file corresponds to each namespace and vice versa, so we can detect when a
+    // file is reachable under more than one namespace or when two files map to the same namespace.
+    let mut namespaces_by_path = HashMap::new();
+    let mut paths_by_namespace = HashMap::new();
+    namespaces_by_path.insert(
+        canonical_base_path.join(based_schema_path),
+        schema_namespace.clone(),
+    );
+    paths_by_namespace.insert(
+        schema_namespace,
+        canonical_base_path.join(based_schema_path),
+    );
 
     // Perform a depth-first traversal of the transitive dependencies.
     while let Some((namespace, path, origin)) = schemas_to_load.pop() {
@@ -176,40 +227,113 @@ pub fn load_schemas(
             // Compute the source listing for this import for error reporting.
             let origin_listing = listing(&contents, import.source_range);
 
-            // Compute the import path.
+            // Compute the import path relative to the importing schema.
             let non_canonical_import_path = base_path.join(parent_path.join(&import.path));
 
-            // Canonicalize the import path.
-            let canonical_import_path = match non_canonical_import_path.canonicalize() {
-                Ok(canonical_import_path) => canonical_import_path,
+            // Resolve the import. If the path relative to the importing schema exists, the
+            // namespace is computed relative to the first root directory (i.e., the base directory
+            // followed by the import paths) which contains it. Otherwise, the namespace is computed
+            // relative to the first import path under which the path exists.
+            let resolution = match non_canonical_import_path.canonicalize() {
+                Ok(canonical_import_path) => {
+                    if let Some(resolution) = once(&canonical_base_path)
+                        .chain(&canonical_import_paths)
+                        .find_map(|root| resolve(root, &canonical_import_path))
+                    {
+                        resolution
+                    } else {
+                        errors.push(throw::<Error>(
+                            &if canonical_import_paths.is_empty() {
+                                format!(
+                                    "{} is not a descendant of {}, which is the base directory \
+                                        for this run.",
+                                    canonical_import_path.to_string_lossy().code_str(),
+                                    canonical_base_path.to_string_lossy().code_str(),
+                                )
+                            } else {
+                                format!(
+                                    "{} is not a descendant of {}, which is the base directory \
+                                        for this run, or of any of the import paths.",
+                                    canonical_import_path.to_string_lossy().code_str(),
+                                    canonical_base_path.to_string_lossy().code_str(),
+                                )
+                            },
+                            Some(&path),
+                            Some(&origin_listing),
+                            None,
+                        ));
+
+                        continue;
+                    }
+                }
                 Err(error) => {
-                    errors.push(throw(
+                    if let Some(resolution) = canonical_import_paths.iter().find_map(|root| {
+                        root.join(&import.path)
+                            .canonicalize()
+                            .ok()
+                            .and_then(|canonical_import_path| resolve(root, &canonical_import_path))
+                    }) {
+                        resolution
+                    } else {
+                        errors.push(throw(
+                            &if canonical_import_paths.is_empty() {
+                                format!(
+                                    "Unable to load {}.",
+                                    non_canonical_import_path.to_string_lossy().code_str(),
+                                )
+                            } else {
+                                format!(
+                                    "Unable to load {}, and {} was not found in any of the \
+                                        import paths.",
+                                    non_canonical_import_path.to_string_lossy().code_str(),
+                                    import.path.to_string_lossy().code_str(),
+                                )
+                            },
+                            Some(&path),
+                            Some(&origin_listing),
+                            Some(error),
+                        ));
+
+                        continue;
+                    }
+                }
+            };
+
+            // Populate the namespace of the import [tag:namespace_populated]. The
+            // path-to-namespace conversion is safe due to
+            // [ref:rooted_import_path_only_has_normal_components].
+            let import_namespace = path_to_namespace(&resolution.rooted_path);
+            import.namespace = Some(import_namespace.clone());
+
+            // Make sure the file isn't also reachable under a different namespace.
+            if let Some(existing_namespace) = namespaces_by_path.get(&resolution.canonical_path) {
+                if *existing_namespace != import_namespace {
+                    errors.push(throw::<Error>(
                         &format!(
-                            "Unable to load {}.",
-                            non_canonical_import_path.to_string_lossy().code_str(),
+                            "{} is reachable under two namespaces: {} and {}.",
+                            resolution.canonical_path.to_string_lossy().code_str(),
+                            existing_namespace.to_string().code_str(),
+                            import_namespace.to_string().code_str(),
                         ),
                         Some(&path),
                         Some(&origin_listing),
-                        Some(error),
+                        None,
                     ));
-
-                    continue;
                 }
-            };
 
-            // Strip the base path from the schema path. Since this is computed from two canonical
-            // paths, its guaranteed to contain only normal components
-            // [tag:based_import_path_only_has_normal_components].
-            let based_import_path = if let Ok(based_import_path) =
-                canonical_import_path.strip_prefix(&canonical_base_path)
-            {
-                based_import_path.to_owned()
-            } else {
+                continue;
+            }
+
+            // Make sure no other file corresponds to the same namespace.
+            if let Some(existing_path) = paths_by_namespace.get(&import_namespace) {
                 errors.push(throw::<Error>(
                     &format!(
-                        "{} is not a descendant of {}, which is the base directory for this run.",
-                        canonical_import_path.to_string_lossy().code_str(),
-                        canonical_base_path.to_string_lossy().code_str(),
+                        "{} conflicts with {}, since both correspond to the same namespace {}.",
+                        resolution.canonical_path.to_string_lossy().code_str(),
+                        AsRef::<Path>::as_ref(existing_path)
+                            .to_string_lossy()
+                            .code_str(),
+                        import_namespace.to_string().code_str(),
                     ),
                     Some(&path),
                     Some(&origin_listing),
@@ -217,23 +341,21 @@ pub fn load_schemas(
                 ));
 
                 continue;
-            };
-
-            // Populate the namespace of the import [tag:namespace_populated]. The
-            // path-to-namespace conversion is safe due to
-            // [ref:based_import_path_only_has_normal_components].
-            let import_namespace = path_to_namespace(&based_import_path);
-            import.namespace = Some(import_namespace.clone());
-
-            // Visit this import if it hasn't been visited already.
-            if !visited_namespaces.contains(&import_namespace) {
-                visited_namespaces.insert(import_namespace.clone());
-                schemas_to_load.push((
-                    import_namespace,
-                    based_import_path,
-                    Some((path.clone(), origin_listing)),
-                ));
             }
+
+            // Visit this import. Schemas found in the base directory are referred to by their path
+            // relative to it, and other schemas are referred to by their absolute path.
+            namespaces_by_path.insert(resolution.canonical_path.clone(), import_namespace.clone());
+            paths_by_namespace.insert(import_namespace.clone(), resolution.canonical_path.clone());
+            schemas_to_load.push((
+                import_namespace,
+                if resolution.root == canonical_base_path {
+                    resolution.rooted_path
+                } else {
+                    resolution.canonical_path
+                },
+                Some((path.clone(), origin_listing)),
+            ));
         }
 
         // Store the schema.
@@ -261,13 +383,45 @@ pub fn load_schemas(
     }
 }
 
+// This struct describes where an imported schema was found.
+struct Resolution {
+    // The root directory (either the base directory or an import path) containing the schema
+    root: PathBuf,
+
+    // The canonical path to the schema
+    canonical_path: PathBuf,
+
+    // The path to the schema relative to `root`
+    rooted_path: PathBuf,
+}
+
+// Resolve a canonical path to a schema against a canonical root directory. This returns `None` if
+// the schema isn't a descendant of the root.
+fn resolve(root: &Path, canonical_path: &Path) -> Option<Resolution> {
+    // Strip the root from the schema path. Since this is computed from two canonical paths, it's
+    // guaranteed to contain only normal components
+    // [tag:rooted_import_path_only_has_normal_components].
+    canonical_path
+        .strip_prefix(root)
+        .ok()
+        .map(|rooted_path| Resolution {
+            root: root.to_owned(),
+            canonical_path: canonical_path.to_owned(),
+            rooted_path: rooted_path.to_owned(),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
+        assert_fails,
         schema::Namespace,
         schema_loader::{load_schemas, path_to_namespace},
     };
-    use std::path::Path;
+    use std::{
+        fmt::Write,
+        path::{Path, PathBuf},
+    };
 
     #[test]
     fn path_to_namespace_empty() {
@@ -310,6 +464,62 @@ mod tests {
     // This test doesn't work on Windows, for some reason.
     #[test]
     fn load_schemas_example() {
-        load_schemas(Path::new("integration_tests/types/types.t")).unwrap();
+        load_schemas(Path::new("integration_tests/types/types.t"), &[]).unwrap();
+    }
+
+    #[test]
+    fn load_schemas_import_path() {
+        let schemas = load_schemas(
+            Path::new("test_data/import_paths/app/main.t"),
+            &[PathBuf::from("test_data/import_paths/shared")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            schemas.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                Namespace {
+                    components: vec!["common".into(), "money".into()],
+                },
+                Namespace {
+                    components: vec!["main".into()],
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn load_schemas_missing_import_path() {
+        assert_fails!(
+            load_schemas(Path::new("test_data/import_paths/app/main.t"), &[]),
+            "Unable to load",
+        );
+    }
+
+    #[test]
+    fn load_schemas_import_path_not_a_directory() {
+        assert_fails!(
+            load_schemas(
+                Path::new("test_data/import_paths/app/main.t"),
+                &[PathBuf::from(
+                    "test_data/import_paths/shared/common/money.t"
+                )],
+            ),
+            "is not a directory.",
+        );
+    }
+
+    #[test]
+    fn load_schemas_reachable_under_two_namespaces() {
+        assert_fails!(
+            load_schemas(
+                Path::new("test_data/import_paths/app/ambiguous.t"),
+                &[
+                    PathBuf::from("test_data/import_paths/shared"),
+                    PathBuf::from("test_data/import_paths/shared/common"),
+                ],
+            ),
+            "is reachable under two namespaces: `common.money` and `money`.",
+        );
     }
 }
@@ -7,14 +7,18 @@ use std::{
 // Keywords
 pub const ASYMMETRIC_KEYWORD: &str = "asymmetric";
 pub const AS_KEYWORD: &str = "as";
+pub const AT_KEYWORD: &str = "at";
 pub const BOOL_KEYWORD: &str = "Bool";
 pub const BYTES_KEYWORD: &str = "Bytes";
 pub const CHOICE_KEYWORD: &str = "choice";
 pub const DELETED_KEYWORD: &str = "deleted";
 pub const F64_KEYWORD: &str = "F64";
 pub const IMPORT_KEYWORD: &str = "import";
+pub const INCLUDE_KEYWORD: &str = "include";
+pub const OPEN_KEYWORD: &str = "open";
 pub const OPTIONAL_KEYWORD: &str = "optional";
 pub const S64_KEYWORD: &str = "S64";
+pub const SERVICE_KEYWORD: &str = "service";
 pub const STRING_KEYWORD: &str = "String";
 pub const STRUCT_KEYWORD: &str = "struct";
 pub const U64_KEYWORD: &str = "U64";
@@ -31,12 +35,15 @@ pub struct Token {
 // We assign each token a "variant" describing what kind of token it is.
 #[derive(Clone, Debug)]
 pub enum Variant {
+    Arrow,
     As,
     Asymmetric,
+    At,
     Bool,
     Bytes,
     Choice,
     Colon,
+    Comma,
     Comment(Vec<String>),
     Deleted,
     Dot,
@@ -44,14 +51,17 @@ pub enum Variant {
     F64,
     Identifier(Identifier),
     Import,
+    Include,
     Integer(usize),
     LeftCurly,
     LeftSquare,
+    Open,
     Optional,
     Path(PathBuf),
     RightCurly,
     RightSquare,
     S64,
+    Service,
     String,
     Struct,
     U64,
@@ -67,12 +77,15 @@ impl Display for Token {
 impl Display for Variant {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
+            Self::Arrow => write!(f, "->"),
             Self::As => write!(f, "{AS_KEYWORD}"),
             Self::Asymmetric => write!(f, "{ASYMMETRIC_KEYWORD}"),
+            Self::At => write!(f, "{AT_KEYWORD}"),
             Self::Bool => write!(f, "{BOOL_KEYWORD}"),
             Self::Bytes => write!(f, "{BYTES_KEYWORD}"),
             Self::Choice => write!(f, "{CHOICE_KEYWORD}"),
             Self::Colon => write!(f, ":"),
+            Self::Comma => write!(f, ","),
             Self::Comment(paragraphs) => {
                 for (i, paragraph) in paragraphs.iter().enumerate() {
                     if i != 0 {
@@ -90,14 +103,17 @@ impl Display for Variant {
             Self::F64 => write!(f, "{F64_KEYWORD}"),
             Self::Identifier(name) => write!(f, "{}", name.original()),
             Self::Import => write!(f, "{IMPORT_KEYWORD}"),
+            Self::Include => write!(f, "{INCLUDE_KEYWORD}"),
             Self::Integer(integer) => write!(f, "{integer}"),
             Self::LeftCurly => write!(f, "{{"),
             Self::LeftSquare => write!(f, "["),
+            Self::Open => write!(f, "{OPEN_KEYWORD}"),
             Self::Optional => write!(f, "{OPTIONAL_KEYWORD}"),
             Self::Path(path) => write!(f, "'{}'", path.display()),
             Self::RightCurly => write!(f, "}}"),
             Self::RightSquare => write!(f, "]"),
             Self::S64 => write!(f, "{S64_KEYWORD}"),
+            Self::Service => write!(f, "{SERVICE_KEYWORD}"),
             Self::String => write!(f, "{STRING_KEYWORD}"),
             Self::Struct => write!(f, "{STRUCT_KEYWORD}"),
             Self::U64 => write!(f, "{U64_KEYWORD}"),
@@ -111,9 +127,10 @@ mod tests {
     use crate::{
         error::SourceRange,
         token::{
-            AS_KEYWORD, ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
-            DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD,
-            STRING_KEYWORD, STRUCT_KEYWORD, Token, U64_KEYWORD, UNIT_KEYWORD, Variant,
+            AS_KEYWORD, ASYMMETRIC_KEYWORD, AT_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD,
+            CHOICE_KEYWORD, DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, INCLUDE_KEYWORD,
+            OPEN_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD, SERVICE_KEYWORD, STRING_KEYWORD,
+            STRUCT_KEYWORD, Token, U64_KEYWORD, UNIT_KEYWORD, Variant,
         },
     };
     use std::path::Path;
@@ -132,6 +149,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn variant_arrow_display() {
+        assert_eq!(format!("{}", Variant::Arrow), "->");
+    }
+
     #[test]
     fn variant_as_display() {
         assert_eq!(format!("{}", Variant::As), AS_KEYWORD);
@@ -142,6 +164,11 @@ mod tests {
         assert_eq!(format!("{}", Variant::Asymmetric), ASYMMETRIC_KEYWORD);
     }
 
+    #[test]
+    fn variant_at_display() {
+        assert_eq!(format!("{}", Variant::At), AT_KEYWORD);
+    }
+
     #[test]
     fn variant_bool_display() {
         assert_eq!(format!("{}", Variant::Bool), BOOL_KEYWORD);
@@ -162,6 +189,11 @@ mod tests {
         assert_eq!(format!("{}", Variant::Colon), ":");
     }
 
+    #[test]
+    fn variant_comma_display() {
+        assert_eq!(format!("{}", Variant::Comma), ",");
+    }
+
     #[test]
     fn variant_comment_display() {
         assert_eq!(
@@ -203,6 +235,11 @@ mod tests {
         assert_eq!(format!("{}", Variant::Import), IMPORT_KEYWORD);
     }
 
+    #[test]
+    fn variant_include_display() {
+        assert_eq!(format!("{}", Variant::Include), INCLUDE_KEYWORD);
+    }
+
     #[test]
     fn variant_integer_literal_display() {
         assert_eq!(format!("{}", Variant::Integer(42)), "42");
@@ -218,6 +255,11 @@ mod tests {
         assert_eq!(format!("{}", Variant::LeftSquare), "[");
     }
 
+    #[test]
+    fn variant_open_display() {
+        assert_eq!(format!("{}", Variant::Open), OPEN_KEYWORD);
+    }
+
     #[test]
     fn variant_optional_display() {
         assert_eq!(format!("{}", Variant::Optional), OPTIONAL_KEYWORD);
@@ -246,6 +288,11 @@ mod tests {
         assert_eq!(format!("{}", Variant::S64), S64_KEYWORD);
     }
 
+    #[test]
+    fn variant_service_display() {
+        assert_eq!(format!("{}", Variant::Service), SERVICE_KEYWORD);
+    }
+
     #[test]
     fn variant_string_display() {
         assert_eq!(format!("{}", Variant::String), STRING_KEYWORD);
@@ -2,9 +2,10 @@ use crate::{
     error::{Error, SourceRange, listing, throw},
     format::CodeStr,
     token::{
-        AS_KEYWORD, ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
-        DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD,
-        STRING_KEYWORD, STRUCT_KEYWORD, Token, U64_KEYWORD, UNIT_KEYWORD, Variant,
+        AS_KEYWORD, ASYMMETRIC_KEYWORD, AT_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
+        DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, INCLUDE_KEYWORD, OPEN_KEYWORD,
+        OPTIONAL_KEYWORD, S64_KEYWORD, SERVICE_KEYWORD, STRING_KEYWORD, STRUCT_KEYWORD, Token,
+        U64_KEYWORD, UNIT_KEYWORD, Variant,
     },
 };
 use std::path::Path;
@@ -41,6 +42,26 @@ pub fn tokenize(schema_path: &Path, schema_contents: &str) -> Result<Vec<Token>,
                     variant: Variant::Colon,
                 });
             }
+            ',' => {
+                tokens.push(Token {
+                    source_range: SourceRange {
+                        start: i,
+                        end: i + 1,
+                    },
+                    variant: Variant::Comma,
+                });
+            }
+            '-' if matches!(iter.peek(), Some((_, '>'))) => {
+                iter.next();
+
+                tokens.push(Token {
+                    source_range: SourceRange {
+                        start: i,
+                        end: i + 2,
+                    },
+                    variant: Variant::Arrow,
+                });
+            }
             '.' => {
                 tokens.push(Token {
                     source_range: SourceRange {
@@ -121,6 +142,11 @@ pub fn tokenize(schema_path: &Path, schema_contents: &str) -> Result<Vec<Token>,
                         source_range: SourceRange { start: i, end },
                         variant: Variant::Asymmetric,
                     });
+                } else if &schema_contents[i..end] == AT_KEYWORD {
+                    tokens.push(Token {
+                        source_range: SourceRange { start: i, end },
+                        variant: Variant::At,
+                    });
                 } else if &schema_contents[i..end] == BOOL_KEYWORD {
                     tokens.push(Token {
                         source_range: SourceRange { start: i, end },
@@ -151,6 +177,16 @@ pub fn tokenize(schema_path: &Path, schema_contents: &str) -> Result<Vec<Token>,
                         source_range: SourceRange { start: i, end },
                         variant: Variant::Import,
                     });
+                } else if &schema_contents[i..end] == INCLUDE_KEYWORD {
+                    tokens.push(Token {
+                        source_range: SourceRange { start: i, end },
+                        variant: Variant::Include,
+                    });
+                } else if &schema_contents[i..end] == OPEN_KEYWORD {
+                    tokens.push(Token {
+                        source_range: SourceRange { start: i, end },
+                        variant: Variant::Open,
+                    });
                 } else if &schema_contents[i..end] == OPTIONAL_KEYWORD {
                     tokens.push(Token {
                         source_range: SourceRange { start: i, end },
@@ -161,6 +197,11 @@ pub fn tokenize(schema_path: &Path, schema_contents: &str) -> Result<Vec<Token>,
                         source_range: SourceRange { start: i, end },
                         variant: Variant::S64,
                     });
+                } else if &schema_contents[i..end] == SERVICE_KEYWORD {
+                    tokens.push(Token {
+                        source_range: SourceRange { start: i, end },
+                        variant: Variant::Service,
+                    });
                 } else if &schema_contents[i..end] == STRING_KEYWORD {
                     tokens.push(Token {
                         source_range: SourceRange { start: i, end },
@@ -392,9 +433,10 @@ mod tests {
         assert_fails, assert_same,
         error::SourceRange,
         token::{
-            AS_KEYWORD, ASYMMETRIC_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD, CHOICE_KEYWORD,
-            DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD,
-            STRING_KEYWORD, STRUCT_KEYWORD, Token, U64_KEYWORD, UNIT_KEYWORD, Variant,
+            AS_KEYWORD, ASYMMETRIC_KEYWORD, AT_KEYWORD, BOOL_KEYWORD, BYTES_KEYWORD,
+            CHOICE_KEYWORD, DELETED_KEYWORD, F64_KEYWORD, IMPORT_KEYWORD, INCLUDE_KEYWORD,
+            OPEN_KEYWORD, OPTIONAL_KEYWORD, S64_KEYWORD, SERVICE_KEYWORD, STRING_KEYWORD,
+            STRUCT_KEYWORD, Token, U64_KEYWORD, UNIT_KEYWORD, Variant,
         },
         tokenizer::{RAW_IDENTIFIER_SIGIL, tokenize},
     };
@@ -484,6 +526,17 @@ mod tests {
         assert_same!(tokenize(Path::new("foo.t"), " \t\n").unwrap(), vec![]);
     }
 
+    #[test]
+    fn tokenize_arrow() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), "->").unwrap(),
+            vec![Token {
+                source_range: SourceRange { start: 0, end: 2 },
+                variant: Variant::Arrow,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_as() {
         assert_same!(
@@ -512,6 +565,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_at() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), AT_KEYWORD).unwrap(),
+            vec![Token {
+                source_range: SourceRange {
+                    start: 0,
+                    end: AT_KEYWORD.len(),
+                },
+                variant: Variant::At,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_bool() {
         assert_same!(
@@ -565,6 +632,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_comma() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), ",").unwrap(),
+            vec![Token {
+                source_range: SourceRange { start: 0, end: 1 },
+                variant: Variant::Comma,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_comment_simple() {
         assert_same!(
@@ -703,6 +781,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_include() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), INCLUDE_KEYWORD).unwrap(),
+            vec![Token {
+                source_range: SourceRange {
+                    start: 0,
+                    end: INCLUDE_KEYWORD.len(),
+                },
+                variant: Variant::Include,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_integer_literal_valid() {
         assert_same!(
@@ -744,6 +836,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_open() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), OPEN_KEYWORD).unwrap(),
+            vec![Token {
+                source_range: SourceRange {
+                    start: 0,
+                    end: OPEN_KEYWORD.len(),
+                },
+                variant: Variant::Open,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_optional() {
         assert_same!(
@@ -824,6 +930,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_service() {
+        assert_same!(
+            tokenize(Path::new("foo.t"), SERVICE_KEYWORD).unwrap(),
+            vec![Token {
+                source_range: SourceRange {
+                    start: 0,
+                    end: SERVICE_KEYWORD.len(),
+                },
+                variant: Variant::Service,
+            }],
+        );
+    }
+
     #[test]
     fn tokenize_string() {
         assert_same!(
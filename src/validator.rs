@@ -3,10 +3,11 @@ use crate::{
     format::CodeStr,
     generate_typescript::COMMON_FILE_STEM,
     identifier::Identifier,
-    schema,
+    schema::{self, UNKNOWN_FIELD_NAME},
 };
 use std::{
-    collections::{BTreeMap, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    iter::once,
     path::{Path, PathBuf},
 };
 
@@ -15,9 +16,9 @@ use std::{
 const MAX_FIELD_INDEX: usize = (1 << 62) - 1;
 
 // The error message shown when there's a collision with a reserved module name.
-const TYPESCRIPT_RESERVED_MODULE_NAME_ERROR: &str = concat!(
+const RESERVED_MODULE_NAME_ERROR: &str = concat!(
     "The name of this file conflicts with a reserved module name used by the generated ",
-    "TypeScript code.",
+    "TypeScript, JavaScript, and Rust code.",
 );
 
 // This function validates a schema and its transitive dependencies.
@@ -29,14 +30,15 @@ pub fn validate(
     let mut errors: Vec<Error> = vec![];
 
     // [tag:typescript_common_file_collision_rejected] Check that no schema collides with the
-    // shared TypeScript library file.
+    // shared TypeScript (or JavaScript) library file, or with the file for the Rust helpers when
+    // the Rust code is generated as a tree of files.
     let common_namespace = schema::Namespace {
         components: vec![COMMON_FILE_STEM.into()],
     };
     for (namespace, (_, source_path, _)) in schemas {
         if namespace == &common_namespace {
             errors.push(throw::<Error>(
-                TYPESCRIPT_RESERVED_MODULE_NAME_ERROR,
+                RESERVED_MODULE_NAME_ERROR,
                 Some(source_path),
                 None,
                 None,
@@ -58,6 +60,15 @@ pub fn validate(
 
     // Validate each file.
     for (namespace, (schema, source_path, source_contents)) in schemas {
+        // Validate the names imported without qualification.
+        validate_imported_names(
+            &all_types,
+            &mut errors,
+            schema,
+            source_path,
+            source_contents,
+        );
+
         // Validate the declarations in the file.
         let mut declaration_names = HashSet::new();
 
@@ -75,6 +86,29 @@ pub fn validate(
                 ));
             }
 
+            // Check that open choices leave room for the case which represents unrecognized fields.
+            // Open structs keep unrecognized fields out of band, so they need no such check.
+            if let (true, schema::DeclarationVariant::Choice) =
+                (declaration.open, &declaration.variant)
+            {
+                let unknown = Identifier::from(UNKNOWN_FIELD_NAME);
+
+                for field in &declaration.fields {
+                    if field.name == unknown {
+                        errors.push(throw::<Error>(
+                            &format!(
+                                "An open choice can't have a field named {}, since that name is \
+                                    reserved for unrecognized fields.",
+                                field.name.code_str(),
+                            ),
+                            Some(source_path),
+                            Some(&listing(source_contents, field.source_range)),
+                            None,
+                        ));
+                    }
+                }
+            }
+
             // Validate the fields in the declaration.
             let mut field_names = HashSet::new();
             let mut field_indices = HashSet::new();
@@ -145,9 +179,38 @@ pub fn validate(
                 );
             }
 
-            // Check that all index gaps are marked as deleted.
-            for index in 0..(field_indices.len() + declaration.deleted.len()) {
-                if !field_indices.contains(&index) && !declaration.deleted.contains(&index) {
+            // Validate the includes in the declaration.
+            let (included_indices, included_deleted) = validate_includes(
+                &all_types,
+                &mut errors,
+                namespace,
+                schema,
+                source_path,
+                source_contents,
+                declaration,
+                &mut field_names,
+                &field_indices,
+            );
+
+            // Check that all index gaps below the largest index of the declaration are marked as
+            // deleted. Included fields can fill gaps, but they don't extend the range which is
+            // checked, since they are placed at an arbitrary offset. The range is also bounded by
+            // the number of indices in use, which still finds a gap if there is one.
+            let used_indices = field_indices
+                .iter()
+                .chain(&declaration.deleted)
+                .chain(&included_indices)
+                .chain(&included_deleted)
+                .copied()
+                .collect::<HashSet<_>>();
+            let end = field_indices
+                .iter()
+                .chain(&declaration.deleted)
+                .max()
+                .map_or(0, |max| max.saturating_add(1))
+                .min(used_indices.len() + 1);
+            for index in 0..end {
+                if !used_indices.contains(&index) {
                     errors.push(throw::<Error>(
                         &format!(
                             "Field index gap found. Unused index {} is not marked as deleted.",
@@ -160,6 +223,122 @@ pub fn validate(
                 }
             }
         }
+
+        // Validate the services in the file. Services share a namespace with declarations.
+        for service in &schema.services {
+            // Check that the name of the service is unique within the file.
+            if !declaration_names.insert(service.name.clone()) {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "A declaration or service named {} already exists in this file.",
+                        service.name.code_str(),
+                    ),
+                    Some(source_path),
+                    Some(&listing(source_contents, service.source_range)),
+                    None,
+                ));
+            }
+
+            // Validate the methods in the service.
+            let mut method_names = HashSet::new();
+            let mut method_indices = HashSet::new();
+
+            for method in &service.methods {
+                // Check that the name of the method is unique within the service.
+                if !method_names.insert(method.name.clone()) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "A method named {} already exists in this service.",
+                            method.name.code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, method.source_range)),
+                        None,
+                    ));
+                }
+
+                // Check that the index of the method is unique within the service.
+                if !method_indices.insert(method.index) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "A method with index {} already exists in this service.",
+                            method.index.to_string().code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, method.source_range)),
+                        None,
+                    ));
+                }
+
+                // Check that the index of the method isn't marked as deleted.
+                if service.deleted.contains(&method.index) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "Method index {} is marked as deleted in this service.",
+                            method.index.to_string().code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, method.source_range)),
+                        None,
+                    ));
+                }
+
+                // Check that the index isn't too big.
+                if method.index > MAX_FIELD_INDEX {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "Method index {} is too large. The maximum method index is {}.",
+                            method.index.to_string().code_str(),
+                            MAX_FIELD_INDEX.to_string().code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, method.source_range)),
+                        None,
+                    ));
+                }
+
+                // Validate the request and response types. They must refer to declarations.
+                for r#type in [&method.request, &method.response] {
+                    if let schema::TypeVariant::Custom(_, _) = r#type.variant {
+                        validate_type(
+                            &all_types,
+                            &mut errors,
+                            namespace,
+                            schema,
+                            source_path,
+                            source_contents,
+                            r#type,
+                        );
+                    } else {
+                        errors.push(throw::<Error>(
+                            &format!(
+                                "The requests and responses of methods must be structs or \
+                                    choices, but {} is not.",
+                                r#type.to_string().code_str(),
+                            ),
+                            Some(source_path),
+                            Some(&listing(source_contents, r#type.source_range)),
+                            None,
+                        ));
+                    }
+                }
+            }
+
+            // Check that all index gaps are marked as deleted.
+            for index in 0..(method_indices.len() + service.deleted.len()) {
+                if !method_indices.contains(&index) && !service.deleted.contains(&index) {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "Method index gap found. Unused index {} is not marked as deleted.",
+                            index.to_string().code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, service.source_range)),
+                        None,
+                    ));
+                }
+            }
+        }
     }
 
     // Check for cycles if the schemas are otherwise valid
@@ -192,176 +371,474 @@ pub fn validate(
     }
 }
 
-// This function validates an individual type.
-fn validate_type(
+// This function validates the includes in a declaration. The names of the included fields are
+// added to `field_names`, and their offset indices are returned along with the offset indices
+// which the included structs mark as deleted.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
+fn validate_includes(
     all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
     errors: &mut Vec<Error>,
     namespace: &schema::Namespace,
     schema: &schema::Schema,
     source_path: &Path,
     source_contents: &str,
-    r#type: &schema::Type,
-) {
-    match &r#type.variant {
-        schema::TypeVariant::Array(inner_type) => {
-            validate_type(
-                all_types,
-                errors,
-                namespace,
-                schema,
-                source_path,
-                source_contents,
-                inner_type,
-            );
+    declaration: &schema::Declaration,
+    field_names: &mut HashSet<Identifier>,
+    field_indices: &HashSet<usize>,
+) -> (HashSet<usize>, BTreeSet<usize>) {
+    let mut included_indices = HashSet::new();
+    let mut included_deleted = BTreeSet::new();
+
+    // Only structs can include other structs.
+    if let schema::DeclarationVariant::Choice = declaration.variant {
+        for include in &declaration.includes {
+            errors.push(throw::<Error>(
+                "Only structs can include other structs.",
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
         }
-        schema::TypeVariant::Bool
-        | schema::TypeVariant::Bytes
-        | schema::TypeVariant::F64
-        | schema::TypeVariant::S64
-        | schema::TypeVariant::String
-        | schema::TypeVariant::U64
-        | schema::TypeVariant::Unit => {}
-        schema::TypeVariant::Custom(import, name) => {
-            // Determine which file the type is from.
-            let type_namespace = if let Some(import) = import {
-                if let Some(import) = schema.imports.get(import) {
-                    // The `unwrap` is safe due to [ref:namespace_populated].
-                    import.namespace.clone().unwrap()
-                } else {
-                    errors.push(throw::<Error>(
-                        &format!(
-                            "There is no import named {} in this file.",
-                            import.code_str(),
-                        ),
-                        Some(source_path),
-                        Some(&listing(source_contents, r#type.source_range)),
-                        None,
-                    ));
 
-                    return;
-                }
-            } else {
-                namespace.clone()
-            };
+        return (included_indices, included_deleted);
+    }
 
-            // Check that the type exists in that file.
-            if !all_types.contains_key(&(type_namespace, name.clone())) {
+    // Gather the fields and deleted indices from all the includes.
+    let mut included_fields = vec![];
+
+    for include in &declaration.includes {
+        // Check that the included type exists.
+        validate_type(
+            all_types,
+            errors,
+            namespace,
+            schema,
+            source_path,
+            source_contents,
+            &include.r#type,
+        );
+
+        // Check that the included type is a struct.
+        let Some((included_namespace, included_name)) =
+            resolve_type(all_types, namespace, schema, &include.r#type)
+        else {
+            if !matches!(include.r#type.variant, schema::TypeVariant::Custom(_, _)) {
                 errors.push(throw::<Error>(
-                    &if let Some(import) = import {
-                        format!(
-                            "There is no type named {} in import {}.",
-                            name.code_str(),
-                            import.code_str(),
-                        )
-                    } else {
-                        format!("There is no type named {} in this file.", name.code_str())
-                    },
+                    &format!(
+                        "Only structs can be included, but {} is not a struct.",
+                        include.r#type.to_string().code_str(),
+                    ),
                     Some(source_path),
-                    Some(&listing(source_contents, r#type.source_range)),
+                    Some(&listing(source_contents, include.source_range)),
                     None,
                 ));
             }
-        }
-    }
-}
 
-// This function checks that declarations have no cycles.
-fn check_declaration_for_cycles(
-    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
-    types_checked: &mut HashSet<(schema::Namespace, Identifier)>,
-    types_visited_set: &mut HashSet<(schema::Namespace, Identifier)>,
-    types_visited_vec: &mut Vec<(schema::Namespace, Identifier)>,
-    errors: &mut Vec<Error>,
-    namespace: &schema::Namespace,
-    name: &Identifier,
-) {
-    // Compute this once here so we don't have to compute it in multiple places below.
-    let qualified_type = (namespace.clone(), name.clone());
+            continue;
+        };
 
-    // Stop if we've already checked this type.
-    if types_checked.contains(&qualified_type) {
-        return;
+        let (_, included_declaration) =
+            all_types[&(included_namespace.clone(), included_name.clone())];
+        if let schema::DeclarationVariant::Choice = included_declaration.variant {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Only structs can be included, but {} is a choice.",
+                    include.r#type.to_string().code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
+
+            continue;
+        }
+
+        // Collect the fields of the included struct, including those from nested includes.
+        let mut visited = vec![(namespace.clone(), declaration.name.clone())];
+        let mut fields = vec![];
+        if collect_included_fields(
+            all_types,
+            errors,
+            &mut visited,
+            &mut fields,
+            &mut included_deleted,
+            &included_namespace,
+            &included_name,
+            include.offset,
+        ) {
+            included_fields.extend(fields.into_iter().map(|field| (include, field)));
+        }
     }
 
-    // Visit this type or report a cycle if the type has already been visited.
-    types_visited_vec.push(qualified_type.clone());
-    if !types_visited_set.insert(qualified_type.clone()) {
-        errors.push(throw::<Error>(
-            &format!(
-                "Cycle detected: {}.",
-                types_visited_vec
-                    .iter()
-                    .map(|(namespace, name)| {
-                        format!("{}.{}", namespace, name.pascal_case())
-                            .code_str()
-                            .to_string()
-                    })
-                    .collect::<Vec<_>>()
-                    .join(" \u{2192} "),
-            ),
-            None,
-            None,
-            None,
-        ));
+    // Check the included fields against the fields of the declaration and each other.
+    for (include, (name, index)) in &included_fields {
+        // Check that the name of the field is unique within the declaration.
+        if !field_names.insert(name.clone()) {
+            errors.push(throw::<Error>(
+                &format!(
+                    "A field named {} already exists in this declaration.",
+                    name.code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
+        }
 
-        // Un-visit this type.
-        types_visited_vec.pop();
+        // Check that the index of the field is unique within the declaration.
+        if field_indices.contains(index) || !included_indices.insert(*index) {
+            errors.push(throw::<Error>(
+                &format!(
+                    "A field with index {} already exists in this declaration.",
+                    index.to_string().code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
+        }
 
-        // Record that the type has been checked to avoid reporting this cycle multiple times. Note
-        // that we haven't necessarily checked all the fields of this type (and the fields of the
-        // types of those fields, etc.), but we know they are being checked by a call higher in the
-        // stack (since this is the second time we've seen this type in this call stack).
-        types_checked.insert(qualified_type);
+        // Check that the index of the field isn't marked as deleted.
+        if declaration.deleted.contains(index) || included_deleted.contains(index) {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Field index {} is marked as deleted in this declaration.",
+                    index.to_string().code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
+        }
 
-        return;
+        // Check that the index isn't too big.
+        if *index > MAX_FIELD_INDEX {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Field index {} is too large. The maximum field index is {}.",
+                    index.to_string().code_str(),
+                    MAX_FIELD_INDEX.to_string().code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, include.source_range)),
+                None,
+            ));
+        }
     }
 
-    // Check the type of each field. The `unwrap` is safe due to
-    // [ref:schemas_valid_except_possible_cycles].
-    let (schema, declaration) = all_types.get(&qualified_type).unwrap();
+    // Check that the fields of the declaration don't use indices reserved by included structs.
     for field in &declaration.fields {
-        check_type_for_cycles(
-            all_types,
-            types_checked,
-            types_visited_set,
-            types_visited_vec,
-            errors,
-            namespace,
-            schema,
-            &field.r#type,
-        );
+        if included_deleted.contains(&field.index) {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Field index {} is marked as deleted in an included struct.",
+                    field.index.to_string().code_str(),
+                ),
+                Some(source_path),
+                Some(&listing(source_contents, field.source_range)),
+                None,
+            ));
+        }
     }
 
-    // Un-visit this type.
-    types_visited_set.remove(&qualified_type);
-    types_visited_vec.pop();
-
-    // Record that the type has been checked.
-    types_checked.insert(qualified_type);
+    (included_indices, included_deleted)
 }
 
-// This function checks that types have no cycles.
+// This function collects the names and offset indices of the fields of a struct, including those
+// from nested includes, along with the offset indices marked as deleted. It returns `false` if the
+// includes couldn't be resolved, which is reported elsewhere unless it's due to a cycle through the
+// first element of `visited`.
 #[allow(clippy::too_many_arguments)]
-fn check_type_for_cycles(
+fn collect_included_fields(
     all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
-    types_checked: &mut HashSet<(schema::Namespace, Identifier)>,
-    types_visited_set: &mut HashSet<(schema::Namespace, Identifier)>,
-    types_visited_vec: &mut Vec<(schema::Namespace, Identifier)>,
     errors: &mut Vec<Error>,
+    visited: &mut Vec<(schema::Namespace, Identifier)>,
+    fields: &mut Vec<(Identifier, usize)>,
+    deleted: &mut BTreeSet<usize>,
     namespace: &schema::Namespace,
-    schema: &schema::Schema,
-    r#type: &schema::Type,
-) {
-    match &r#type.variant {
-        schema::TypeVariant::Array(inner_type) => {
-            check_type_for_cycles(
-                all_types,
-                types_checked,
-                types_visited_set,
-                types_visited_vec,
+    name: &Identifier,
+    offset: usize,
+) -> bool {
+    // Check for a cycle.
+    let qualified_type = (namespace.clone(), name.clone());
+    if let Some(position) = visited
+        .iter()
+        .position(|visited| *visited == qualified_type)
+    {
+        if position == 0 {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Include cycle detected: {}.",
+                    visited
+                        .iter()
+                        .chain(once(&qualified_type))
+                        .map(|(namespace, name)| {
+                            format!("{}.{}", namespace, name.pascal_case())
+                                .code_str()
+                                .to_string()
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" \u{2192} "),
+                ),
+                None,
+                None,
+                None,
+            ));
+        }
+
+        return false;
+    }
+
+    // Make sure the type is a struct.
+    let Some((schema, declaration)) = all_types.get(&qualified_type) else {
+        return false;
+    };
+    if let schema::DeclarationVariant::Choice = declaration.variant {
+        return false;
+    }
+
+    // Collect the fields and deleted indices of the struct itself [tag:included_indices_fit].
+    let mut success = true;
+    for (index, field_name) in declaration
+        .fields
+        .iter()
+        .map(|field| (field.index, Some(&field.name)))
+        .chain(declaration.deleted.iter().map(|index| (*index, None)))
+    {
+        if let Some(offset_index) = index.checked_add(offset) {
+            if let Some(field_name) = field_name {
+                fields.push((field_name.clone(), offset_index));
+            } else {
+                deleted.insert(offset_index);
+            }
+        } else {
+            errors.push(throw::<Error>(
+                &format!(
+                    "Field index {} of {} overflows when offset by {}.",
+                    index.to_string().code_str(),
+                    format!("{}.{}", namespace, name.pascal_case()).code_str(),
+                    offset.to_string().code_str(),
+                ),
+                None,
+                None,
+                None,
+            ));
+
+            success = false;
+        }
+    }
+
+    // Collect the fields from any nested includes.
+    visited.push(qualified_type);
+    for include in &declaration.includes {
+        let Some(nested_offset) = offset.checked_add(include.offset) else {
+            errors.push(throw::<Error>(
+                &format!(
+                    "The offset of the include of {} in {} overflows when offset by {}.",
+                    include.r#type.to_string().code_str(),
+                    format!("{}.{}", namespace, name.pascal_case()).code_str(),
+                    offset.to_string().code_str(),
+                ),
+                None,
+                None,
+                None,
+            ));
+
+            success = false;
+            continue;
+        };
+
+        success = if let Some((included_namespace, included_name)) =
+            resolve_type(all_types, namespace, schema, &include.r#type)
+        {
+            collect_included_fields(
+                all_types,
+                errors,
+                visited,
+                fields,
+                deleted,
+                &included_namespace,
+                &included_name,
+                nested_offset,
+            ) && success
+        } else {
+            false
+        };
+    }
+    visited.pop();
+
+    success
+}
+
+// This function determines which declaration a type refers to, if any.
+fn resolve_type(
+    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    r#type: &schema::Type,
+) -> Option<(schema::Namespace, Identifier)> {
+    let schema::TypeVariant::Custom(import, name) = &r#type.variant else {
+        return None;
+    };
+
+    // Resolve any name imported without qualification.
+    let (import, name) = schema.qualify(import.as_ref(), name);
+
+    // Determine which file the type is from. The `unwrap` is safe due to
+    // [ref:namespace_populated].
+    let type_namespace = if let Some(import) = import {
+        schema.imports.get(&import)?.namespace.clone().unwrap()
+    } else {
+        namespace.clone()
+    };
+
+    // Check that the type exists in that file.
+    let qualified_type = (type_namespace, name);
+    if all_types.contains_key(&qualified_type) {
+        Some(qualified_type)
+    } else {
+        None
+    }
+}
+
+// This function validates the names imported without qualification in a file.
+fn validate_imported_names(
+    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
+    errors: &mut Vec<Error>,
+    schema: &schema::Schema,
+    source_path: &Path,
+    source_contents: &str,
+) {
+    // Determine which unqualified type names are used in this file.
+    let mut used_names = HashSet::new();
+    for declaration in &schema.declarations {
+        for field in &declaration.fields {
+            collect_unqualified_type_names(&mut used_names, &field.r#type);
+        }
+        for include in &declaration.includes {
+            collect_unqualified_type_names(&mut used_names, &include.r#type);
+        }
+    }
+    for service in &schema.services {
+        for method in &service.methods {
+            collect_unqualified_type_names(&mut used_names, &method.request);
+            collect_unqualified_type_names(&mut used_names, &method.response);
+        }
+    }
+
+    // Validate each imported name.
+    let mut aliases = HashSet::new();
+    for (import_name, import) in &schema.imports {
+        for imported_name in &import.names {
+            // Check that the type exists in the imported file. The `unwrap` is safe due to
+            // [ref:namespace_populated].
+            if !all_types.contains_key(&(
+                import.namespace.clone().unwrap(),
+                imported_name.name.clone(),
+            )) {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "There is no type named {} in import {}.",
+                        imported_name.name.code_str(),
+                        import_name.code_str(),
+                    ),
+                    Some(source_path),
+                    Some(&listing(source_contents, imported_name.source_range)),
+                    None,
+                ));
+            }
+
+            // Check that the name is only imported once.
+            if !aliases.insert(imported_name.alias.clone()) {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "A type named {} has already been imported into this file.",
+                        imported_name.alias.code_str(),
+                    ),
+                    Some(source_path),
+                    Some(&listing(source_contents, imported_name.source_range)),
+                    None,
+                ));
+            }
+
+            // Check that the name doesn't collide with a declaration or service in this file.
+            if schema
+                .declarations
+                .iter()
+                .map(|declaration| &declaration.name)
+                .chain(schema.services.iter().map(|service| &service.name))
+                .any(|name| *name == imported_name.alias)
+            {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "The imported type {} conflicts with a declaration or service of the same \
+                            name in this file.",
+                        imported_name.alias.code_str(),
+                    ),
+                    Some(source_path),
+                    Some(&listing(source_contents, imported_name.source_range)),
+                    None,
+                ));
+            }
+
+            // Check that the name is used.
+            if !used_names.contains(&imported_name.alias) {
+                errors.push(throw::<Error>(
+                    &format!(
+                        "The imported type {} is never used.",
+                        imported_name.alias.code_str(),
+                    ),
+                    Some(source_path),
+                    Some(&listing(source_contents, imported_name.source_range)),
+                    None,
+                ));
+            }
+        }
+    }
+}
+
+// This function collects the names of the user-defined types referenced without qualification by
+// a type.
+fn collect_unqualified_type_names(names: &mut HashSet<Identifier>, r#type: &schema::Type) {
+    match &r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            collect_unqualified_type_names(names, inner_type);
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {}
+        schema::TypeVariant::Custom(import, name) => {
+            if import.is_none() {
+                names.insert(name.clone());
+            }
+        }
+    }
+}
+
+// This function validates an individual type.
+fn validate_type(
+    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
+    errors: &mut Vec<Error>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    source_path: &Path,
+    source_contents: &str,
+    r#type: &schema::Type,
+) {
+    match &r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            validate_type(
+                all_types,
                 errors,
                 namespace,
                 schema,
+                source_path,
+                source_contents,
                 inner_type,
             );
         }
@@ -373,107 +850,1042 @@ fn check_type_for_cycles(
         | schema::TypeVariant::U64
         | schema::TypeVariant::Unit => {}
         schema::TypeVariant::Custom(import, name) => {
-            let type_namespace = import.as_ref().map_or_else(
-                || namespace.clone(),
-                |import|
-                    // The first `unwrap` is safe due to
-                    // [ref:schemas_valid_except_possible_cycles]. The second `unwrap`
-                    // is safe due to [ref:namespace_populated].
-                    schema
-                    .imports
-                    .get(import)
-                    .unwrap()
-                    .namespace
-                    .clone()
-                    .unwrap(),
-            );
+            // Resolve any name imported without qualification.
+            let (import, name) = schema.qualify(import.as_ref(), name);
+
+            // Determine which file the type is from.
+            let type_namespace = if let Some(import) = &import {
+                if let Some(import) = schema.imports.get(import) {
+                    // The `unwrap` is safe due to [ref:namespace_populated].
+                    import.namespace.clone().unwrap()
+                } else {
+                    errors.push(throw::<Error>(
+                        &format!(
+                            "There is no import named {} in this file.",
+                            import.code_str(),
+                        ),
+                        Some(source_path),
+                        Some(&listing(source_contents, r#type.source_range)),
+                        None,
+                    ));
+
+                    return;
+                }
+            } else {
+                namespace.clone()
+            };
+
+            // Check that the type exists in that file.
+            if !all_types.contains_key(&(type_namespace, name.clone())) {
+                errors.push(throw::<Error>(
+                    &if let Some(import) = &import {
+                        format!(
+                            "There is no type named {} in import {}.",
+                            name.code_str(),
+                            import.code_str(),
+                        )
+                    } else {
+                        format!("There is no type named {} in this file.", name.code_str())
+                    },
+                    Some(source_path),
+                    Some(&listing(source_contents, r#type.source_range)),
+                    None,
+                ));
+            }
+        }
+    }
+}
+
+// This function checks that declarations have no cycles.
+fn check_declaration_for_cycles(
+    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
+    types_checked: &mut HashSet<(schema::Namespace, Identifier)>,
+    types_visited_set: &mut HashSet<(schema::Namespace, Identifier)>,
+    types_visited_vec: &mut Vec<(schema::Namespace, Identifier)>,
+    errors: &mut Vec<Error>,
+    namespace: &schema::Namespace,
+    name: &Identifier,
+) {
+    // Compute this once here so we don't have to compute it in multiple places below.
+    let qualified_type = (namespace.clone(), name.clone());
+
+    // Stop if we've already checked this type.
+    if types_checked.contains(&qualified_type) {
+        return;
+    }
+
+    // Visit this type or report a cycle if the type has already been visited.
+    types_visited_vec.push(qualified_type.clone());
+    if !types_visited_set.insert(qualified_type.clone()) {
+        errors.push(throw::<Error>(
+            &format!(
+                "Cycle detected: {}.",
+                types_visited_vec
+                    .iter()
+                    .map(|(namespace, name)| {
+                        format!("{}.{}", namespace, name.pascal_case())
+                            .code_str()
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" \u{2192} "),
+            ),
+            None,
+            None,
+            None,
+        ));
+
+        // Un-visit this type.
+        types_visited_vec.pop();
+
+        // Record that the type has been checked to avoid reporting this cycle multiple times. Note
+        // that we haven't necessarily checked all the fields of this type (and the fields of the
+        // types of those fields, etc.), but we know they are being checked by a call higher in the
+        // stack (since this is the second time we've seen this type in this call stack).
+        types_checked.insert(qualified_type);
+
+        return;
+    }
+
+    // Check the type of each field. The `unwrap` is safe due to
+    // [ref:schemas_valid_except_possible_cycles].
+    let (schema, declaration) = all_types.get(&qualified_type).unwrap();
+    for r#type in declaration
+        .includes
+        .iter()
+        .map(|include| &include.r#type)
+        .chain(declaration.fields.iter().map(|field| &field.r#type))
+    {
+        check_type_for_cycles(
+            all_types,
+            types_checked,
+            types_visited_set,
+            types_visited_vec,
+            errors,
+            namespace,
+            schema,
+            r#type,
+        );
+    }
+
+    // Un-visit this type.
+    types_visited_set.remove(&qualified_type);
+    types_visited_vec.pop();
+
+    // Record that the type has been checked.
+    types_checked.insert(qualified_type);
+}
+
+// This function checks that types have no cycles.
+#[allow(clippy::too_many_arguments)]
+fn check_type_for_cycles(
+    all_types: &HashMap<(schema::Namespace, Identifier), (&schema::Schema, &schema::Declaration)>,
+    types_checked: &mut HashSet<(schema::Namespace, Identifier)>,
+    types_visited_set: &mut HashSet<(schema::Namespace, Identifier)>,
+    types_visited_vec: &mut Vec<(schema::Namespace, Identifier)>,
+    errors: &mut Vec<Error>,
+    namespace: &schema::Namespace,
+    schema: &schema::Schema,
+    r#type: &schema::Type,
+) {
+    match &r#type.variant {
+        schema::TypeVariant::Array(inner_type) => {
+            // Arrays are followed like any other reference, so they don't break cycles
+            // [tag:cycles_through_arrays].
+            check_type_for_cycles(
+                all_types,
+                types_checked,
+                types_visited_set,
+                types_visited_vec,
+                errors,
+                namespace,
+                schema,
+                inner_type,
+            );
+        }
+        schema::TypeVariant::Bool
+        | schema::TypeVariant::Bytes
+        | schema::TypeVariant::F64
+        | schema::TypeVariant::S64
+        | schema::TypeVariant::String
+        | schema::TypeVariant::U64
+        | schema::TypeVariant::Unit => {}
+        schema::TypeVariant::Custom(import, name) => {
+            // Resolve any name imported without qualification.
+            let (import, name) = schema.qualify(import.as_ref(), name);
+
+            let type_namespace = import.as_ref().map_or_else(
+                || namespace.clone(),
+                |import|
+                    // The first `unwrap` is safe due to
+                    // [ref:schemas_valid_except_possible_cycles]. The second `unwrap`
+                    // is safe due to [ref:namespace_populated].
+                    schema
+                    .imports
+                    .get(import)
+                    .unwrap()
+                    .namespace
+                    .clone()
+                    .unwrap(),
+            );
+
+            check_declaration_for_cycles(
+                all_types,
+                types_checked,
+                types_visited_set,
+                types_visited_vec,
+                errors,
+                &type_namespace,
+                &name,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RESERVED_MODULE_NAME_ERROR;
+    use crate::{
+        assert_fails, assert_same, parser::parse, schema::Namespace, tokenizer::tokenize,
+        validator::validate,
+    };
+    use std::{collections::BTreeMap, fmt::Write, path::Path};
+
+    #[test]
+    fn validate_typescript_common_namespace_collision() {
+        let namespace = Namespace {
+            components: vec!["common".into()],
+        };
+        let path = Path::new("common.t").to_owned();
+        let contents = String::new();
+
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(validate(&schemas), RESERVED_MODULE_NAME_ERROR);
+    }
+
+    #[test]
+    fn validate_typescript_common_namespace_collision_after_snake_case() {
+        let namespace = Namespace {
+            components: vec!["Common".into()],
+        };
+        let path = Path::new("Common.t").to_owned();
+        let contents = String::new();
+
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(validate(&schemas), RESERVED_MODULE_NAME_ERROR);
+    }
+
+    #[test]
+    fn validate_empty() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = String::new();
+
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_same!(validate(&schemas), Ok(()));
+    }
+
+    #[test]
+    fn validate_example() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                x: bar.Bar = 0
+                y: [bar.Bar] = 1
+                asymmetric z: String = 2
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            choice Bar {
+                x: Bool = 0
+                y: F64 = 1
+                optional z: U64 = 2
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_same!(validate(&schemas), Ok(()));
+    }
+
+    #[test]
+    fn validate_duplicate_declaration_names() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct foo {
+            }
+
+            struct Foo {
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A declaration named `Foo` already exists in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_struct_field_names() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                X: Bool = 0
+                x: F64 = 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A field named `x` already exists in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_struct_field_indices() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: Bool = 0
+                y: F64 = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A field with index `0` already exists in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_deleted_struct_field_index() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: Bool = 0
+
+                deleted 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Field index `0` is marked as deleted in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_struct_field_index_gap() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: Bool = 0
+                y: Bool = 3
+
+                deleted 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Field index gap found. Unused index `2` is not marked as deleted.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_choice_field_names() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            choice Foo {
+                X: Bool = 0
+                x: F64 = 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A field named `x` already exists in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_choice_field_indices() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            choice Foo {
+                x: Bool = 0
+                y: F64 = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A field with index `0` already exists in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_deleted_choice_field_index() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            choice Foo {
+                x: Bool = 0
+
+                deleted 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Field index `0` is marked as deleted in this declaration.",
+        );
+    }
+
+    #[test]
+    fn validate_choice_field_index_gap() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            choice Foo {
+                x: Bool = 0
+                y: Bool = 3
+
+                deleted 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Field index gap found. Unused index `2` is not marked as deleted.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_import() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: bar.Bar = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no import named `bar` in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_import_in_array() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: [bar.Bar] = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no import named `bar` in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_type_same_file() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: Bar = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Bar` in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_type_in_array_same_file() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+                x: [Bar] = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Bar` in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_type_different_file() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                x: bar.Bar = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Qux {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Bar` in import `bar`.",
+        );
+    }
+
+    #[test]
+    fn validate_non_existent_field_type_in_array_different_file() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                x: [bar.Bar] = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Qux {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Bar` in import `bar`.",
+        );
+    }
+
+    #[test]
+    fn validate_cycle() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                x: bar.Bar = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            import 'foo.t'
+
+            choice Bar {
+                x: foo.Foo = 0
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let mut bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+        bar_schema.imports.get_mut(&"foo".into()).unwrap().namespace = Some(foo_namespace.clone());
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Cycle detected: `bar.Bar` \u{2192} `foo.Foo` \u{2192} `bar.Bar`.",
+        );
+    }
+
+    #[test]
+    fn validate_cycle_in_array() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t'
+
+            struct Foo {
+                x: [bar.Bar] = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            import 'foo.t'
+
+            choice Bar {
+                x: foo.Foo = 0
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let mut bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+        bar_schema.imports.get_mut(&"foo".into()).unwrap().namespace = Some(foo_namespace.clone());
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "Cycle detected: `bar.Bar` \u{2192} `foo.Foo` \u{2192} `bar.Bar`.",
+        );
+    }
+
+    #[test]
+    fn validate_service() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Request {
+            }
+
+            choice Response {
+            }
+
+            service Foo {
+                x: Request -> Response = 0
+                y: Request -> Request = 2
+
+                deleted 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_same!(validate(&schemas), Ok(()));
+    }
+
+    #[test]
+    fn validate_service_name_collides_with_declaration() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Foo {
+            }
+
+            service foo {
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A declaration or service named `foo` already exists in this file.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_method_names() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Bar {
+            }
+
+            service Foo {
+                X: Bar -> Bar = 0
+                x: Bar -> Bar = 1
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A method named `x` already exists in this service.",
+        );
+    }
+
+    #[test]
+    fn validate_duplicate_method_indices() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Bar {
+            }
+
+            service Foo {
+                x: Bar -> Bar = 0
+                y: Bar -> Bar = 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "A method with index `0` already exists in this service.",
+        );
+    }
+
+    #[test]
+    fn validate_deleted_method_index() {
+        let namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Bar {
+            }
+
+            service Foo {
+                x: Bar -> Bar = 0
+
+                deleted 0
+            }
+        "
+        .to_owned();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(namespace, (schema, path, contents));
 
-            check_declaration_for_cycles(
-                all_types,
-                types_checked,
-                types_visited_set,
-                types_visited_vec,
-                errors,
-                &type_namespace,
-                name,
-            );
-        }
+        assert_fails!(
+            validate(&schemas),
+            "Method index `0` is marked as deleted in this service.",
+        );
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::TYPESCRIPT_RESERVED_MODULE_NAME_ERROR;
-    use crate::{
-        assert_fails, assert_same, parser::parse, schema::Namespace, tokenizer::tokenize,
-        validator::validate,
-    };
-    use std::{collections::BTreeMap, fmt::Write, path::Path};
 
     #[test]
-    fn validate_typescript_common_namespace_collision() {
+    fn validate_method_index_gap() {
         let namespace = Namespace {
-            components: vec!["common".into()],
+            components: vec!["foo".into()],
         };
-        let path = Path::new("common.t").to_owned();
-        let contents = String::new();
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Bar {
+            }
 
+            service Foo {
+                x: Bar -> Bar = 0
+                y: Bar -> Bar = 2
+            }
+        "
+        .to_owned();
         let tokens = tokenize(&path, &contents).unwrap();
         let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
         schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(validate(&schemas), TYPESCRIPT_RESERVED_MODULE_NAME_ERROR);
+        assert_fails!(
+            validate(&schemas),
+            "Method index gap found. Unused index `1` is not marked as deleted.",
+        );
     }
 
     #[test]
-    fn validate_typescript_common_namespace_collision_after_snake_case() {
+    fn validate_method_non_declaration_request() {
         let namespace = Namespace {
-            components: vec!["Common".into()],
+            components: vec!["foo".into()],
         };
-        let path = Path::new("Common.t").to_owned();
-        let contents = String::new();
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            struct Bar {
+            }
 
+            service Foo {
+                x: [Bar] -> Bar = 0
+            }
+        "
+        .to_owned();
         let tokens = tokenize(&path, &contents).unwrap();
         let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
         schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(validate(&schemas), TYPESCRIPT_RESERVED_MODULE_NAME_ERROR);
+        assert_fails!(
+            validate(&schemas),
+            "The requests and responses of methods must be structs or choices, but `[Bar]` is \
+                not.",
+        );
     }
 
     #[test]
-    fn validate_empty() {
+    fn validate_method_non_existent_response() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
-        let contents = String::new();
+        let contents = "
+            struct Bar {
+            }
 
+            service Foo {
+                x: Bar -> Baz = 0
+            }
+        "
+        .to_owned();
         let tokens = tokenize(&path, &contents).unwrap();
         let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
         schemas.insert(namespace, (schema, path, contents));
 
-        assert_same!(validate(&schemas), Ok(()));
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Baz` in this file.",
+        );
     }
 
     #[test]
-    fn validate_example() {
+    fn validate_imported_names() {
         let foo_namespace = Namespace {
             components: vec!["foo".into()],
         };
         let foo_path = Path::new("foo.t").to_owned();
         let foo_contents = "
-            import 'bar.t'
+            import 'bar.t' { Bar, Baz as Qux }
 
             struct Foo {
-                x: bar.Bar = 0
-                y: [bar.Bar] = 1
-                asymmetric z: String = 2
+                x: Bar = 0
+                y: [Qux] = 1
             }
         "
         .to_owned();
@@ -483,10 +1895,10 @@ mod tests {
         };
         let bar_path = Path::new("bar.t").to_owned();
         let bar_contents = "
-            choice Bar {
-                x: Bool = 0
-                y: F64 = 1
-                optional z: U64 = 2
+            struct Bar {
+            }
+
+            struct Baz {
             }
         "
         .to_owned();
@@ -502,45 +1914,250 @@ mod tests {
         schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
         schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
 
-        assert_same!(validate(&schemas), Ok(()));
+        validate(&schemas).unwrap();
     }
 
     #[test]
-    fn validate_duplicate_declaration_names() {
-        let namespace = Namespace {
+    fn validate_imported_name_non_existent() {
+        let foo_namespace = Namespace {
             components: vec!["foo".into()],
         };
-        let path = Path::new("foo.t").to_owned();
-        let contents = "
-            struct foo {
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Qux }
+
+            struct Foo {
+                x: Qux = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
             }
 
+            struct Baz {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "There is no type named `Qux` in import `bar`.",
+        );
+    }
+
+    #[test]
+    fn validate_imported_name_duplicate() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Bar, Baz as Bar }
+
             struct Foo {
+                x: Bar = 0
             }
         "
         .to_owned();
-        let tokens = tokenize(&path, &contents).unwrap();
-        let schema = parse(&path, &contents, &tokens).unwrap();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
+            }
+
+            struct Baz {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
-        schemas.insert(namespace, (schema, path, contents));
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
 
         assert_fails!(
             validate(&schemas),
-            "A declaration named `Foo` already exists in this file.",
+            "A type named `Bar` has already been imported into this file.",
+        );
+    }
+
+    #[test]
+    fn validate_imported_name_collides_with_declaration() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Bar }
+
+            struct Bar {
+            }
+
+            struct Foo {
+                x: Bar = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
+            }
+
+            struct Baz {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(
+            validate(&schemas),
+            "The imported type `Bar` conflicts with a declaration or service of the same name in \
+                this file.",
         );
     }
 
     #[test]
-    fn validate_duplicate_struct_field_names() {
+    fn validate_imported_name_unused() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Bar }
+
+            struct Foo {
+                x: bar.Bar = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
+            }
+
+            struct Baz {
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        assert_fails!(validate(&schemas), "The imported type `Bar` is never used.");
+    }
+
+    #[test]
+    fn validate_imported_name_used_by_include() {
+        let foo_namespace = Namespace {
+            components: vec!["foo".into()],
+        };
+        let foo_path = Path::new("foo.t").to_owned();
+        let foo_contents = "
+            import 'bar.t' { Bar }
+
+            struct Foo {
+                include Bar at 1
+
+                x: Bool = 0
+            }
+        "
+        .to_owned();
+
+        let bar_namespace = Namespace {
+            components: vec!["bar".into()],
+        };
+        let bar_path = Path::new("bar.t").to_owned();
+        let bar_contents = "
+            struct Bar {
+                y: Bool = 0
+            }
+        "
+        .to_owned();
+
+        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
+        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
+        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
+
+        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
+        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+
+        let mut schemas = BTreeMap::new();
+        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
+        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+
+        validate(&schemas).unwrap();
+    }
+
+    #[test]
+    fn validate_include() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                x: Bool = 0
+                y: Bool = 2
+
+                deleted 1
+            }
+
             struct Foo {
-                X: Bool = 0
-                x: F64 = 1
+                include Header at 10
+
+                z: Bool = 0
             }
         "
         .to_owned();
@@ -550,22 +2167,26 @@ mod tests {
         let mut schemas = BTreeMap::new();
         schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(
-            validate(&schemas),
-            "A field named `x` already exists in this declaration.",
-        );
+        validate(&schemas).unwrap();
     }
 
     #[test]
-    fn validate_duplicate_struct_field_indices() {
+    fn validate_include_fills_index_gap() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                y: Bool = 0
+                z: Bool = 1
+            }
+
             struct Foo {
+                include Header at 1
+
                 x: Bool = 0
-                y: F64 = 0
+                w: Bool = 3
             }
         "
         .to_owned();
@@ -575,23 +2196,26 @@ mod tests {
         let mut schemas = BTreeMap::new();
         schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(
-            validate(&schemas),
-            "A field with index `0` already exists in this declaration.",
-        );
+        validate(&schemas).unwrap();
     }
 
     #[test]
-    fn validate_deleted_struct_field_index() {
+    fn validate_include_index_gap() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                y: Bool = 0
+                z: Bool = 1
+            }
+
             struct Foo {
-                x: Bool = 0
+                include Header at 1
 
-                deleted 0
+                x: Bool = 0
+                w: Bool = 4
             }
         "
         .to_owned();
@@ -603,23 +2227,29 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "Field index `0` is marked as deleted in this declaration.",
+            "Field index gap found. Unused index `3` is not marked as deleted.",
         );
     }
 
     #[test]
-    fn validate_struct_field_index_gap() {
+    fn validate_include_in_choice() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
-            struct Foo {
+            struct Header {
                 x: Bool = 0
-                y: Bool = 3
+                y: Bool = 2
 
                 deleted 1
             }
+
+            choice Foo {
+                include Header at 10
+
+                z: Bool = 0
+            }
         "
         .to_owned();
         let tokens = tokenize(&path, &contents).unwrap();
@@ -630,20 +2260,23 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "Field index gap found. Unused index `2` is not marked as deleted.",
+            "Only structs can include other structs.",
         );
     }
 
     #[test]
-    fn validate_duplicate_choice_field_names() {
+    fn validate_include_choice() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
-            choice Foo {
-                X: Bool = 0
-                x: F64 = 1
+            choice Header {
+                x: Bool = 0
+            }
+
+            struct Foo {
+                include Header at 10
             }
         "
         .to_owned();
@@ -655,20 +2288,19 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "A field named `x` already exists in this declaration.",
+            "Only structs can be included, but `Header` is a choice.",
         );
     }
 
     #[test]
-    fn validate_duplicate_choice_field_indices() {
+    fn validate_include_non_struct() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
-            choice Foo {
-                x: Bool = 0
-                y: F64 = 0
+            struct Foo {
+                include String at 10
             }
         "
         .to_owned();
@@ -680,21 +2312,28 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "A field with index `0` already exists in this declaration.",
+            "Only structs can be included, but `String` is not a struct.",
         );
     }
 
     #[test]
-    fn validate_deleted_choice_field_index() {
+    fn validate_include_duplicate_field_names() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
-            choice Foo {
+            struct Header {
                 x: Bool = 0
+                y: Bool = 2
 
-                deleted 0
+                deleted 1
+            }
+
+            struct Foo {
+                include Header at 10
+
+                x: Bool = 0
             }
         "
         .to_owned();
@@ -706,23 +2345,30 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "Field index `0` is marked as deleted in this declaration.",
+            "A field named `x` already exists in this declaration.",
         );
     }
 
     #[test]
-    fn validate_choice_field_index_gap() {
+    fn validate_include_duplicate_field_indices() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
-            choice Foo {
+            struct Header {
                 x: Bool = 0
-                y: Bool = 3
+                y: Bool = 2
 
                 deleted 1
             }
+
+            struct Foo {
+                include Header at 1
+
+                z: Bool = 0
+                w: Bool = 1
+            }
         "
         .to_owned();
         let tokens = tokenize(&path, &contents).unwrap();
@@ -733,19 +2379,30 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "Field index gap found. Unused index `2` is not marked as deleted.",
+            "A field with index `1` already exists in this declaration.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_import() {
+    fn validate_include_deleted_field_index() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                x: Bool = 0
+                y: Bool = 2
+
+                deleted 1
+            }
+
             struct Foo {
-                x: bar.Bar = 0
+                include Header at 10
+
+                z: Bool = 0
+
+                deleted 12
             }
         "
         .to_owned();
@@ -757,19 +2414,29 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "There is no import named `bar` in this file.",
+            "Field index `12` is marked as deleted in this declaration.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_import_in_array() {
+    fn validate_include_reserves_deleted_indices() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                x: Bool = 0
+                y: Bool = 2
+
+                deleted 1
+            }
+
             struct Foo {
-                x: [bar.Bar] = 0
+                include Header at 1
+
+                z: Bool = 0
+                w: Bool = 2
             }
         "
         .to_owned();
@@ -781,19 +2448,23 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "There is no import named `bar` in this file.",
+            "Field index `2` is marked as deleted in an included struct.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_type_same_file() {
+    fn validate_include_index_overflow() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                x: Bool = 1
+            }
+
             struct Foo {
-                x: Bar = 0
+                include Header at 18446744073709551615
             }
         "
         .to_owned();
@@ -805,19 +2476,27 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "There is no type named `Bar` in this file.",
+            "Field index `1` of `foo.Header` overflows when offset by `18446744073709551615`.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_type_in_array_same_file() {
+    fn validate_include_offset_overflow() {
         let namespace = Namespace {
             components: vec!["foo".into()],
         };
         let path = Path::new("foo.t").to_owned();
         let contents = "
+            struct Header {
+                x: Bool = 0
+            }
+
+            struct Middle {
+                include Header at 10000000000000000000
+            }
+
             struct Foo {
-                x: [Bar] = 0
+                include Middle at 10000000000000000000
             }
         "
         .to_owned();
@@ -829,183 +2508,106 @@ mod tests {
 
         assert_fails!(
             validate(&schemas),
-            "There is no type named `Bar` in this file.",
+            "The offset of the include of `Header` in `foo.Middle` overflows when offset by \
+             `10000000000000000000`.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_type_different_file() {
-        let foo_namespace = Namespace {
+    fn validate_include_cycle() {
+        let namespace = Namespace {
             components: vec!["foo".into()],
         };
-        let foo_path = Path::new("foo.t").to_owned();
-        let foo_contents = "
-            import 'bar.t'
-
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
             struct Foo {
-                x: bar.Bar = 0
+                include Bar at 10
             }
-        "
-        .to_owned();
 
-        let bar_namespace = Namespace {
-            components: vec!["bar".into()],
-        };
-        let bar_path = Path::new("bar.t").to_owned();
-        let bar_contents = "
-            struct Qux {
+            struct Bar {
+                include Foo at 10
             }
         "
         .to_owned();
-
-        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
-        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
-        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
-
-        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
-        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
-        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
-        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+        schemas.insert(namespace, (schema, path, contents));
 
         assert_fails!(
             validate(&schemas),
-            "There is no type named `Bar` in import `bar`.",
+            "Include cycle detected: `foo.Foo` \u{2192} `foo.Bar` \u{2192} `foo.Foo`.",
         );
     }
 
     #[test]
-    fn validate_non_existent_field_type_in_array_different_file() {
-        let foo_namespace = Namespace {
+    fn validate_open_choice() {
+        let namespace = Namespace {
             components: vec!["foo".into()],
         };
-        let foo_path = Path::new("foo.t").to_owned();
-        let foo_contents = "
-            import 'bar.t'
-
-            struct Foo {
-                x: [bar.Bar] = 0
-            }
-        "
-        .to_owned();
-
-        let bar_namespace = Namespace {
-            components: vec!["bar".into()],
-        };
-        let bar_path = Path::new("bar.t").to_owned();
-        let bar_contents = "
-            struct Qux {
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            open choice Foo {
+                x: Bool = 0
+                optional y: String = 1
             }
         "
         .to_owned();
-
-        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
-        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
-        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
-
-        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
-        let bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
-        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
-        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+        schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(
-            validate(&schemas),
-            "There is no type named `Bar` in import `bar`.",
-        );
+        validate(&schemas).unwrap();
     }
 
     #[test]
-    fn validate_cycle() {
-        let foo_namespace = Namespace {
+    fn validate_open_struct() {
+        let namespace = Namespace {
             components: vec!["foo".into()],
         };
-        let foo_path = Path::new("foo.t").to_owned();
-        let foo_contents = "
-            import 'bar.t'
-
-            struct Foo {
-                x: bar.Bar = 0
-            }
-        "
-        .to_owned();
-
-        let bar_namespace = Namespace {
-            components: vec!["bar".into()],
-        };
-        let bar_path = Path::new("bar.t").to_owned();
-        let bar_contents = "
-            import 'foo.t'
-
-            choice Bar {
-                x: foo.Foo = 0
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            open struct Foo {
+                x: Bool = 0
+                unknown: String = 1
             }
         "
         .to_owned();
-
-        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
-        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
-        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
-
-        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
-        let mut bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
-        bar_schema.imports.get_mut(&"foo".into()).unwrap().namespace = Some(foo_namespace.clone());
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
-        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
-        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+        schemas.insert(namespace, (schema, path, contents));
 
-        assert_fails!(
-            validate(&schemas),
-            "Cycle detected: `bar.Bar` \u{2192} `foo.Foo` \u{2192} `bar.Bar`.",
-        );
+        validate(&schemas).unwrap();
     }
 
     #[test]
-    fn validate_cycle_in_array() {
-        let foo_namespace = Namespace {
+    fn validate_open_choice_unknown_field() {
+        let namespace = Namespace {
             components: vec!["foo".into()],
         };
-        let foo_path = Path::new("foo.t").to_owned();
-        let foo_contents = "
-            import 'bar.t'
-
-            struct Foo {
-                x: [bar.Bar] = 0
-            }
-        "
-        .to_owned();
-
-        let bar_namespace = Namespace {
-            components: vec!["bar".into()],
-        };
-        let bar_path = Path::new("bar.t").to_owned();
-        let bar_contents = "
-            import 'foo.t'
-
-            choice Bar {
-                x: foo.Foo = 0
+        let path = Path::new("foo.t").to_owned();
+        let contents = "
+            open choice Foo {
+                x: Bool = 0
+                Unknown: String = 1
             }
         "
         .to_owned();
-
-        let foo_tokens = tokenize(&foo_path, &foo_contents).unwrap();
-        let mut foo_schema = parse(&foo_path, &foo_contents, &foo_tokens).unwrap();
-        foo_schema.imports.get_mut(&"bar".into()).unwrap().namespace = Some(bar_namespace.clone());
-
-        let bar_tokens = tokenize(&bar_path, &bar_contents).unwrap();
-        let mut bar_schema = parse(&bar_path, &bar_contents, &bar_tokens).unwrap();
-        bar_schema.imports.get_mut(&"foo".into()).unwrap().namespace = Some(foo_namespace.clone());
+        let tokens = tokenize(&path, &contents).unwrap();
+        let schema = parse(&path, &contents, &tokens).unwrap();
 
         let mut schemas = BTreeMap::new();
-        schemas.insert(foo_namespace, (foo_schema, foo_path, foo_contents));
-        schemas.insert(bar_namespace, (bar_schema, bar_path, bar_contents));
+        schemas.insert(namespace, (schema, path, contents));
 
         assert_fails!(
             validate(&schemas),
-            "Cycle detected: `bar.Bar` \u{2192} `foo.Foo` \u{2192} `bar.Bar`.",
+            "An open choice can't have a field named `Unknown`, since that name is reserved for \
+                unrecognized fields.",
         );
     }
 }
@@ -0,0 +1,7 @@
+import 'common/money.t'
+import 'money.t' as other_money
+
+struct Invoice {
+    total: money.Money = 0
+    subtotal: other_money.Money = 1
+}
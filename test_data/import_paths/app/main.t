@@ -0,0 +1,5 @@
+import 'common/money.t'
+
+struct Invoice {
+    total: money.Money = 0
+}
@@ -0,0 +1,4 @@
+struct Money {
+    currency: String = 0
+    cents: S64 = 1
+}
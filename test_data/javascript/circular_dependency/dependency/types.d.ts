@@ -0,0 +1,73 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../../common.js';
+import type { Deserializable } from '../../common.js';
+
+import * as _Types from '../types.js';
+
+export type StructFromBelowAtlas = {
+  $size: number;
+  x: _Types.StructFromAboveAtlas;
+};
+
+export type StructFromBelowOut = {
+  x: _Types.StructFromAboveOut;
+};
+
+export type StructFromBelowIn = {
+  x: _Types.StructFromAboveIn;
+};
+
+declare function structFromBelowAtlas(message: StructFromBelowOut): StructFromBelowAtlas;
+
+declare function structFromBelowSize(message: StructFromBelowOut): number;
+
+declare function structFromBelowSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: StructFromBelowOut,
+  atlas: StructFromBelowAtlas,
+): number;
+
+declare function structFromBelowDeserializeUnsafe(dataView: DataView): StructFromBelowIn;
+
+declare function structFromBelowSerialize(message: StructFromBelowOut): ArrayBuffer;
+
+declare function structFromBelowDeserialize(bytes: Deserializable): StructFromBelowIn | Error;
+
+declare function structFromBelowCheckOut(value: unknown): string | undefined;
+
+export declare const StructFromBelow: {
+  atlas: typeof structFromBelowAtlas;
+  size: typeof structFromBelowSize;
+  serializeWithAtlasUnsafe: typeof structFromBelowSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof structFromBelowDeserializeUnsafe;
+  serialize: typeof structFromBelowSerialize;
+  deserialize: typeof structFromBelowDeserialize;
+  checkOut: typeof structFromBelowCheckOut;
+};
+
+export declare function isStructFromBelowOut(value: unknown): value is StructFromBelowOut;
+
+export declare function assertStructFromBelowOut(value: unknown): asserts value is StructFromBelowOut;
@@ -0,0 +1,173 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../../common.js';
+
+import * as _Types from '../types.js';
+
+function structFromBelowAtlas(message) {
+  let size = 0;
+
+  let $x;
+
+  {
+    let payloadAtlas;
+    const payload = message.x;
+    payloadAtlas = _Types.StructFromAbove.atlas(payload);
+    $x = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    x: $x,
+  };
+}
+
+function structFromBelowSize(message) {
+  return structFromBelowAtlas(message).$size;
+}
+
+function structFromBelowSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.x;
+    const payloadAtlas = atlas.x;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    offset = _Types.StructFromAbove.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  return offset;
+}
+
+function structFromBelowDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $x;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.StructFromAbove.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $x = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($x === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    x: $x,
+  };
+}
+
+function structFromBelowSerialize(message) {
+  const atlasValue = structFromBelowAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  structFromBelowSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function structFromBelowDeserialize(bytes) {
+  try {
+    return structFromBelowDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function structFromBelowCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.x;
+    const error = _Types.StructFromAbove.checkOut(payload);
+    if (error !== undefined) {
+      return '.x' + error;
+    }
+  }
+
+  return undefined;
+}
+
+export const StructFromBelow = {
+  atlas: structFromBelowAtlas,
+  size: structFromBelowSize,
+  serializeWithAtlasUnsafe: structFromBelowSerializeWithAtlasUnsafe,
+  deserializeUnsafe: structFromBelowDeserializeUnsafe,
+  serialize: structFromBelowSerialize,
+  deserialize: structFromBelowDeserialize,
+  checkOut: structFromBelowCheckOut,
+};
+
+export function isStructFromBelowOut(value) {
+  return structFromBelowCheckOut(value) === undefined;
+}
+
+export function assertStructFromBelowOut(value) {
+  const error = structFromBelowCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid StructFromBelowOut: value' + error + '.');
+  }
+}
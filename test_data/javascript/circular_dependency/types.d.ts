@@ -0,0 +1,82 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+import * as _Types from './dependency/types.js';
+
+export type StructFromAboveAtlas = {
+  $size: number;
+  field: Uint8Array;
+  size: Uint8Array;
+  elements: Uint8Array;
+  fallback: Uint8Array;
+};
+
+export type StructFromAboveOut = {
+  field: string;
+  size: string;
+  elements: string;
+  fallback: string;
+};
+
+export type StructFromAboveIn = {
+  field: string;
+  size: string;
+  elements: string;
+  fallback: string;
+};
+
+declare function structFromAboveAtlas(message: StructFromAboveOut): StructFromAboveAtlas;
+
+declare function structFromAboveSize(message: StructFromAboveOut): number;
+
+declare function structFromAboveSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: StructFromAboveOut,
+  atlas: StructFromAboveAtlas,
+): number;
+
+declare function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn;
+
+declare function structFromAboveSerialize(message: StructFromAboveOut): ArrayBuffer;
+
+declare function structFromAboveDeserialize(bytes: Deserializable): StructFromAboveIn | Error;
+
+declare function structFromAboveCheckOut(value: unknown): string | undefined;
+
+export declare const StructFromAbove: {
+  atlas: typeof structFromAboveAtlas;
+  size: typeof structFromAboveSize;
+  serializeWithAtlasUnsafe: typeof structFromAboveSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof structFromAboveDeserializeUnsafe;
+  serialize: typeof structFromAboveSerialize;
+  deserialize: typeof structFromAboveDeserialize;
+  checkOut: typeof structFromAboveCheckOut;
+};
+
+export declare function isStructFromAboveOut(value: unknown): value is StructFromAboveOut;
+
+export declare function assertStructFromAboveOut(value: unknown): asserts value is StructFromAboveOut;
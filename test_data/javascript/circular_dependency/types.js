@@ -0,0 +1,333 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _Types from './dependency/types.js';
+
+function structFromAboveAtlas(message) {
+  let size = 0;
+
+  let $field;
+  let $size;
+  let $elements;
+  let $fallback;
+
+  {
+    let payloadAtlas;
+    const payload = message.field;
+    payloadAtlas = textEncoder.encode(payload);
+    $field = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.size;
+    payloadAtlas = textEncoder.encode(payload);
+    $size = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.elements;
+    payloadAtlas = textEncoder.encode(payload);
+    $elements = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(2n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.fallback;
+    payloadAtlas = textEncoder.encode(payload);
+    $fallback = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(3n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    field: $field,
+    size: $size,
+    elements: $elements,
+    fallback: $fallback,
+  };
+}
+
+function structFromAboveSize(message) {
+  return structFromAboveAtlas(message).$size;
+}
+
+function structFromAboveSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.field;
+    const payloadAtlas = atlas.field;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.size;
+    const payloadAtlas = atlas.size;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.elements;
+    const payloadAtlas = atlas.elements;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.fallback;
+    const payloadAtlas = atlas.fallback;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function structFromAboveDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $field;
+  let $size;
+  let $elements;
+  let $fallback;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $field = payload;
+        break;
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $size = payload;
+        break;
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $elements = payload;
+        break;
+      }
+      case 3n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $fallback = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($field === undefined
+    || $size === undefined
+    || $elements === undefined
+    || $fallback === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    field: $field,
+    size: $size,
+    elements: $elements,
+    fallback: $fallback,
+  };
+}
+
+function structFromAboveSerialize(message) {
+  const atlasValue = structFromAboveAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  structFromAboveSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function structFromAboveDeserialize(bytes) {
+  try {
+    return structFromAboveDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function structFromAboveCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.field;
+    if (typeof payload !== 'string') {
+      return '.field must be a string';
+    }
+  }
+
+  {
+    const payload = message.size;
+    if (typeof payload !== 'string') {
+      return '.size must be a string';
+    }
+  }
+
+  {
+    const payload = message.elements;
+    if (typeof payload !== 'string') {
+      return '.elements must be a string';
+    }
+  }
+
+  {
+    const payload = message.fallback;
+    if (typeof payload !== 'string') {
+      return '.fallback must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const StructFromAbove = {
+  atlas: structFromAboveAtlas,
+  size: structFromAboveSize,
+  serializeWithAtlasUnsafe: structFromAboveSerializeWithAtlasUnsafe,
+  deserializeUnsafe: structFromAboveDeserializeUnsafe,
+  serialize: structFromAboveSerialize,
+  deserialize: structFromAboveDeserialize,
+  checkOut: structFromAboveCheckOut,
+};
+
+export function isStructFromAboveOut(value) {
+  return structFromAboveCheckOut(value) === undefined;
+}
+
+export function assertStructFromAboveOut(value) {
+  const error = structFromAboveCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid StructFromAboveOut: value' + error + '.');
+  }
+}
@@ -0,0 +1,97 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+export type Deserializable =
+  | ArrayBuffer
+  | DataView
+  | Int8Array
+  | Uint8Array
+  | Uint8ClampedArray
+  | Int16Array
+  | Uint16Array
+  | Int32Array
+  | Uint32Array
+  | Float32Array
+  | Float64Array
+  | BigInt64Array
+  | BigUint64Array;
+
+export type Transport = (methodIndex: bigint, request: ArrayBuffer) => Promise<ArrayBuffer>;
+
+export type UnknownField = { index: bigint; sizeMode: number; payload: ArrayBuffer };
+
+export declare function unreachable(x: never): never;
+
+export declare function dataViewFromDeserializable(bytes: Deserializable): DataView;
+
+export declare function zigzagEncode(value: bigint): bigint;
+
+export declare function zigzagDecode(value: bigint): bigint;
+
+export declare function varintSizeFromValue(value: bigint): number;
+
+export declare function serializeVarint(
+  dataView: DataView,
+  offset: number,
+  value: bigint,
+): number;
+
+declare function varintSizeFromFirstByte(firstByte: number): number;
+
+// This decodes a varint as a `number` rather than a `bigint`, which is much faster. The result is
+// exact for varints of at most 7 bytes, which covers every value up to 567,382,630,219,903 (about
+// 2^49, so the arithmetic never exceeds `Number.MAX_SAFE_INTEGER`). Longer varints are decoded as
+// a `bigint` and then rounded.
+export declare function deserializeVarintNumber(
+  dataView: DataView,
+  offset: number,
+): [number, number];
+
+export declare function deserializeVarint(
+  dataView: DataView,
+  offset: number,
+): [number, bigint];
+
+export declare function payloadIntegerEncoded(payload: ArrayBuffer): boolean;
+
+export declare function fieldHeaderSize(
+  index: bigint,
+  payloadSize: number,
+  integerEncoded: boolean,
+): number;
+
+export declare function serializeFieldHeader(
+  dataView: DataView,
+  offset: number,
+  index: bigint,
+  payloadSize: number,
+  integerEncoded: boolean,
+): number;
+
+export declare function deserializeFieldHeader(
+  dataView: DataView,
+  offset: number,
+): [number, bigint, number, number];
+
+export declare function unknownFieldSize(field: UnknownField): number;
+
+export declare function serializeUnknownField(
+  dataView: DataView,
+  offset: number,
+  field: UnknownField,
+): number;
+
+export declare function checkUnknownField(
+  value: unknown,
+  hasSizeMode: boolean,
+): string | undefined;
+
+export declare function safeNumberFromBigint(value: bigint): number;
+
+export declare function clampedNumberFromBigint(value: bigint): number;
+
+export declare const missingFieldsErrorMessage: string;
+export declare const textEncoder: TextEncoder;
+export declare const textDecoder: TextDecoder;
@@ -0,0 +1,430 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+export function unreachable(x) {
+  return x;
+}
+
+export function dataViewFromDeserializable(bytes) {
+  if (bytes instanceof ArrayBuffer) {
+    return new DataView(bytes);
+  }
+
+  const buffer = bytes.buffer;
+  if (!(buffer instanceof ArrayBuffer)) {
+    throw new Error('Expected ArrayBuffer or ArrayBuffer-backed view.');
+  }
+
+  return new DataView(buffer, bytes.byteOffset, bytes.byteLength);
+}
+
+export function zigzagEncode(value) {
+  const twice = value << 1n;
+  return value < 0n ? -1n - twice : twice;
+}
+
+export function zigzagDecode(value) {
+  const half = (value + 1n) >> 1n;
+  return (value & 1n) === 0n ? half : -half;
+}
+
+export function varintSizeFromValue(value) {
+  if (value < 128n) {
+    return 1;
+  }
+
+  if (value < 16_512n) {
+    return 2;
+  }
+
+  if (value < 2_113_664n) {
+    return 3;
+  }
+
+  if (value < 270_549_120n) {
+    return 4;
+  }
+
+  if (value < 34_630_287_488n) {
+    return 5;
+  }
+
+  if (value < 4_432_676_798_592n) {
+    return 6;
+  }
+
+  if (value < 567_382_630_219_904n) {
+    return 7;
+  }
+
+  if (value < 72_624_976_668_147_840n) {
+    return 8;
+  }
+
+  return 9;
+}
+
+export function serializeVarint(
+  dataView,
+  offset,
+  value,
+) {
+  if (value < 128n) {
+    dataView.setUint8(offset, Number(value << 1n) | 0b0000_0001);
+    return offset + 1;
+  }
+
+  if (value < 16_512n) {
+    value -= 128n;
+    dataView.setUint8(offset, Number((value << 2n) % 256n) | 0b0000_0010);
+    dataView.setUint8(offset + 1, Number(value >> 6n));
+    return offset + 2;
+  }
+
+  if (value < 2_113_664n) {
+    value -= 16_512n;
+    dataView.setUint8(offset, Number((value << 3n) % 256n) | 0b0000_0100);
+    dataView.setUint16(offset + 1, Number((value >> 5n) % 65_536n), true);
+    return offset + 3;
+  }
+
+  if (value < 270_549_120n) {
+    value -= 2_113_664n;
+    dataView.setUint8(offset, Number((value << 4n) % 256n) | 0b0000_1000);
+    dataView.setUint8(offset + 1, Number((value >> 4n) % 256n));
+    dataView.setUint16(offset + 2, Number((value >> 12n) % 65_536n), true);
+    return offset + 4;
+  }
+
+  if (value < 34_630_287_488n) {
+    value -= 270_549_120n;
+    dataView.setUint8(offset, Number((value << 5n) % 256n) | 0b0001_0000);
+    dataView.setUint32(
+      offset + 1,
+      Number((value >> 3n) % 4_294_967_296n),
+      true,
+    );
+    return offset + 5;
+  }
+
+  if (value < 4_432_676_798_592n) {
+    value -= 34_630_287_488n;
+    dataView.setUint8(offset, Number((value << 6n) % 256n) | 0b0010_0000);
+    dataView.setUint8(offset + 1, Number((value >> 2n) % 256n));
+    dataView.setUint32(
+      offset + 2,
+      Number((value >> 10n) % 4_294_967_296n),
+      true,
+    );
+    return offset + 6;
+  }
+
+  if (value < 567_382_630_219_904n) {
+    value -= 4_432_676_798_592n;
+    dataView.setUint8(offset, Number((value << 7n) % 256n) | 0b0100_0000);
+    dataView.setUint16(offset + 1, Number((value >> 1n) % 65_536n), true);
+    dataView.setUint32(
+      offset + 3,
+      Number((value >> 17n) % 4_294_967_296n),
+      true,
+    );
+    return offset + 7;
+  }
+
+  if (value < 72_624_976_668_147_840n) {
+    value -= 567_382_630_219_904n;
+    dataView.setUint8(offset, 0b1000_0000);
+    dataView.setUint8(offset + 1, Number(value % 256n));
+    dataView.setUint16(offset + 2, Number((value >> 8n) % 65_536n), true);
+    dataView.setUint32(
+      offset + 4,
+      Number((value >> 24n) % 4_294_967_296n),
+      true,
+    );
+    return offset + 8;
+  }
+
+  value -= 72_624_976_668_147_840n;
+  dataView.setUint8(offset, 0b0000_0000);
+  dataView.setBigUint64(offset + 1, value, true);
+  return offset + 9;
+}
+
+function varintSizeFromFirstByte(firstByte) {
+  // The size is one more than the number of trailing zeros, which `Math.clz32` can count once the
+  // lowest set bit is isolated.
+  return firstByte === 0 ? 9 : 32 - Math.clz32(firstByte & -firstByte);
+}
+
+// This decodes a varint as a `number` rather than a `bigint`, which is much faster. The result is
+// exact for varints of at most 7 bytes, which covers every value up to 567,382,630,219,903 (about
+// 2^49, so the arithmetic never exceeds `Number.MAX_SAFE_INTEGER`). Longer varints are decoded as
+// a `bigint` and then rounded.
+export function deserializeVarintNumber(
+  dataView,
+  offset,
+) {
+  const firstByte = dataView.getUint8(offset);
+
+  switch (varintSizeFromFirstByte(firstByte)) {
+    case 1:
+      return [offset + 1, firstByte >> 1];
+    case 2:
+      return [
+        offset + 2,
+        128 + (firstByte >> 2) + dataView.getUint8(offset + 1) * 64,
+      ];
+    case 3:
+      return [
+        offset + 3,
+        16_512 + (firstByte >> 3) + dataView.getUint16(offset + 1, true) * 32,
+      ];
+    case 4:
+      return [
+        offset + 4,
+        2_113_664 +
+          (firstByte >> 4) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint16(offset + 2, true) * 256) *
+            16,
+      ];
+    case 5:
+      return [
+        offset + 5,
+        270_549_120 +
+          (firstByte >> 5) +
+          dataView.getUint32(offset + 1, true) * 8,
+      ];
+    case 6:
+      return [
+        offset + 6,
+        34_630_287_488 +
+          (firstByte >> 6) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint32(offset + 2, true) * 256) *
+            4,
+      ];
+    case 7:
+      return [
+        offset + 7,
+        4_432_676_798_592 +
+          (firstByte >> 7) +
+          (dataView.getUint16(offset + 1, true) +
+            dataView.getUint32(offset + 3, true) * 65_536) *
+            2,
+      ];
+    default: {
+      const [newOffset, value] = deserializeVarint(dataView, offset);
+      return [newOffset, Number(value)];
+    }
+  }
+}
+
+export function deserializeVarint(
+  dataView,
+  offset,
+) {
+  switch (varintSizeFromFirstByte(dataView.getUint8(offset))) {
+    case 8:
+      return [
+        offset + 8,
+        567_382_630_219_904n +
+          BigInt(dataView.getUint32(offset + 1, true)) +
+          (BigInt(dataView.getUint16(offset + 5, true)) << 32n) +
+          (BigInt(dataView.getUint8(offset + 7)) << 48n),
+      ];
+    case 9:
+      return [
+        offset + 9,
+        (72_624_976_668_147_840n + dataView.getBigUint64(offset + 1, true)) %
+          18_446_744_073_709_551_616n,
+      ];
+    default: {
+      const [newOffset, value] = deserializeVarintNumber(dataView, offset);
+      return [newOffset, BigInt(value)];
+    }
+  }
+}
+
+export function payloadIntegerEncoded(payload) {
+  return (
+    payload.byteLength > 0 &&
+    varintSizeFromFirstByte(new Uint8Array(payload)[0]) === payload.byteLength
+  );
+}
+
+export function fieldHeaderSize(
+  index,
+  payloadSize,
+  integerEncoded,
+) {
+  switch (payloadSize) {
+    case 0:
+      return varintSizeFromValue(index << 2n);
+    case 8:
+      return varintSizeFromValue((index << 2n) | 1n);
+    default:
+      if (integerEncoded) {
+        return varintSizeFromValue((index << 2n) | 2n);
+      }
+
+      return (
+        varintSizeFromValue((index << 2n) | 3n) +
+        varintSizeFromValue(BigInt(payloadSize))
+      );
+  }
+}
+
+export function serializeFieldHeader(
+  dataView,
+  offset,
+  index,
+  payloadSize,
+  integerEncoded,
+) {
+  switch (payloadSize) {
+    case 0:
+      return serializeVarint(dataView, offset, index << 2n);
+    case 8:
+      return serializeVarint(dataView, offset, (index << 2n) | 1n);
+    default:
+      if (integerEncoded) {
+        return serializeVarint(dataView, offset, (index << 2n) | 2n);
+      }
+
+      offset = serializeVarint(dataView, offset, (index << 2n) | 3n);
+
+      return serializeVarint(dataView, offset, BigInt(payloadSize));
+  }
+}
+
+export function deserializeFieldHeader(
+  dataView,
+  offset,
+) {
+  let newOffset;
+  let index;
+  let sizeMode;
+
+  if (varintSizeFromFirstByte(dataView.getUint8(offset)) < 8) {
+    // The tag fits in a `number`, so only the index needs to be converted to a `bigint`.
+    let tag;
+    [newOffset, tag] = deserializeVarintNumber(dataView, offset);
+    sizeMode = tag % 4;
+    index = BigInt((tag - sizeMode) / 4);
+  } else {
+    let tag;
+    [newOffset, tag] = deserializeVarint(dataView, offset);
+    sizeMode = Number(tag & 3n);
+    index = tag >> 2n;
+  }
+
+  switch (sizeMode) {
+    case 0:
+      return [newOffset, index, 0, sizeMode];
+    case 1:
+      return [newOffset, index, 8, sizeMode];
+    case 2:
+      return [
+        newOffset,
+        index,
+        varintSizeFromFirstByte(dataView.getUint8(newOffset)),
+        sizeMode,
+      ];
+    default: {
+      const [newNewOffset, payloadSize] = deserializeVarintNumber(
+        dataView,
+        newOffset,
+      );
+      return [newNewOffset, index, payloadSize, sizeMode];
+    }
+  }
+}
+
+export function unknownFieldSize(field) {
+  const sizeMode = field.sizeMode & 3;
+  const tagSize = varintSizeFromValue((field.index << 2n) | BigInt(sizeMode));
+
+  if (sizeMode === 3) {
+    return (
+      tagSize +
+      varintSizeFromValue(BigInt(field.payload.byteLength)) +
+      field.payload.byteLength
+    );
+  }
+
+  return tagSize + field.payload.byteLength;
+}
+
+export function serializeUnknownField(
+  dataView,
+  offset,
+  field,
+) {
+  const sizeMode = field.sizeMode & 3;
+  offset = serializeVarint(dataView, offset, (field.index << 2n) | BigInt(sizeMode));
+
+  if (sizeMode === 3) {
+    offset = serializeVarint(dataView, offset, BigInt(field.payload.byteLength));
+  }
+
+  const sourceBuffer = new Uint8Array(field.payload);
+  const targetBuffer = new Uint8Array(
+    dataView.buffer,
+    dataView.byteOffset,
+    dataView.byteLength,
+  );
+  targetBuffer.set(sourceBuffer, offset);
+  return offset + sourceBuffer.byteLength;
+}
+
+export function checkUnknownField(
+  value,
+  hasSizeMode,
+) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const field = value;
+
+  if (typeof field.index !== 'bigint' || field.index < 0n) {
+    return '.index must be a non-negative bigint';
+  }
+
+  if (hasSizeMode && ![0, 1, 2, 3].includes(field.sizeMode)) {
+    return '.sizeMode must be 0, 1, 2, or 3';
+  }
+
+  if (!(field.payload instanceof ArrayBuffer)) {
+    return '.payload must be an ArrayBuffer';
+  }
+
+  return undefined;
+}
+
+export function safeNumberFromBigint(value) {
+  if (value > 9_007_199_254_740_991n || value < -9_007_199_254_740_991n) {
+    throw new Error('Integer cannot be represented exactly as a number.');
+  }
+
+  return Number(value);
+}
+
+export function clampedNumberFromBigint(value) {
+  if (value > 9_007_199_254_740_991n) {
+    return Number.MAX_SAFE_INTEGER;
+  }
+
+  if (value < -9_007_199_254_740_991n) {
+    return Number.MIN_SAFE_INTEGER;
+  }
+
+  return Number(value);
+}
+
+export const missingFieldsErrorMessage = 'Struct missing one or more required field(s).';
+export const textEncoder = new TextEncoder();
+export const textDecoder = new TextDecoder('utf-8', { fatal: true, ignoreBOM: true });
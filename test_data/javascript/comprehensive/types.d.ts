@@ -0,0 +1,638 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+export type LocalStructAtlas = {
+  $size: number;
+};
+
+export type LocalStructOut = {
+};
+
+export type LocalStructIn = {
+};
+
+declare function localStructAtlas(message: LocalStructOut): LocalStructAtlas;
+
+declare function localStructSize(message: LocalStructOut): number;
+
+declare function localStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: LocalStructOut,
+  atlas: LocalStructAtlas,
+): number;
+
+declare function localStructDeserializeUnsafe(dataView: DataView): LocalStructIn;
+
+declare function localStructSerialize(message: LocalStructOut): ArrayBuffer;
+
+declare function localStructDeserialize(bytes: Deserializable): LocalStructIn | Error;
+
+declare function localStructCheckOut(value: unknown): string | undefined;
+
+export declare const LocalStruct: {
+  atlas: typeof localStructAtlas;
+  size: typeof localStructSize;
+  serializeWithAtlasUnsafe: typeof localStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof localStructDeserializeUnsafe;
+  serialize: typeof localStructSerialize;
+  deserialize: typeof localStructDeserialize;
+  checkOut: typeof localStructCheckOut;
+};
+
+export declare function isLocalStructOut(value: unknown): value is LocalStructOut;
+
+export declare function assertLocalStructOut(value: unknown): asserts value is LocalStructOut;
+
+export type FooAtlas = {
+  $size: number;
+  aRequired: number;
+  bRequired: number;
+  cRequired: number;
+  dRequired: number;
+  eRequired: number;
+  fRequired: number;
+  gRequired: Uint8Array;
+  hRequired: LocalStructAtlas;
+  iRequired: _Types.EmptyStructAtlas;
+  jRequired: number;
+  kRequired: number;
+  lRequired: number;
+  mRequired: number;
+  nRequired: number;
+  oRequired: { $size: number; $elements: number[] };
+  pRequired: { $size: number; $elements: Uint8Array[] };
+  qRequired: { $size: number; $elements: LocalStructAtlas[] };
+  rRequired: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+  sRequired: { $size: number; $elements: number[] };
+  tRequired: { $size: number; $elements: number[] };
+  uRequired: { $size: number; $elements: number[] };
+  vRequired: { $size: number; $elements: number[] };
+  wRequired: { $size: number; $elements: number[] };
+  xRequired: { $size: number; $elements: { $size: number; $elements: number[] }[] };
+  yRequired: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+  zRequired: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+  aaRequired: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+  aAsymmetric: number;
+  bAsymmetric: number;
+  cAsymmetric: number;
+  dAsymmetric: number;
+  eAsymmetric: number;
+  fAsymmetric: number;
+  gAsymmetric: Uint8Array;
+  hAsymmetric: LocalStructAtlas;
+  iAsymmetric: _Types.EmptyStructAtlas;
+  jAsymmetric: number;
+  kAsymmetric: number;
+  lAsymmetric: number;
+  mAsymmetric: number;
+  nAsymmetric: number;
+  oAsymmetric: { $size: number; $elements: number[] };
+  pAsymmetric: { $size: number; $elements: Uint8Array[] };
+  qAsymmetric: { $size: number; $elements: LocalStructAtlas[] };
+  rAsymmetric: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+  sAsymmetric: { $size: number; $elements: number[] };
+  tAsymmetric: { $size: number; $elements: number[] };
+  uAsymmetric: { $size: number; $elements: number[] };
+  vAsymmetric: { $size: number; $elements: number[] };
+  wAsymmetric: { $size: number; $elements: number[] };
+  xAsymmetric: { $size: number; $elements: { $size: number; $elements: number[] }[] };
+  yAsymmetric: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+  zAsymmetric: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+  aaAsymmetric: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+  aOptional: number | undefined;
+  bOptional: number | undefined;
+  cOptional: number | undefined;
+  dOptional: number | undefined;
+  eOptional: number | undefined;
+  fOptional: number | undefined;
+  gOptional: Uint8Array | undefined;
+  hOptional: LocalStructAtlas | undefined;
+  iOptional: _Types.EmptyStructAtlas | undefined;
+  jOptional: number | undefined;
+  kOptional: number | undefined;
+  lOptional: number | undefined;
+  mOptional: number | undefined;
+  nOptional: number | undefined;
+  oOptional: { $size: number; $elements: number[] } | undefined;
+  pOptional: { $size: number; $elements: Uint8Array[] } | undefined;
+  qOptional: { $size: number; $elements: LocalStructAtlas[] } | undefined;
+  rOptional: { $size: number; $elements: _Types.EmptyStructAtlas[] } | undefined;
+  sOptional: { $size: number; $elements: number[] } | undefined;
+  tOptional: { $size: number; $elements: number[] } | undefined;
+  uOptional: { $size: number; $elements: number[] } | undefined;
+  vOptional: { $size: number; $elements: number[] } | undefined;
+  wOptional: { $size: number; $elements: number[] } | undefined;
+  xOptional: { $size: number; $elements: { $size: number; $elements: number[] }[] } | undefined;
+  yOptional: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] } | undefined;
+  zOptional: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] } | undefined;
+  aaOptional: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] } | undefined;
+};
+
+export type FooOut = {
+  aRequired: null;
+  bRequired: number;
+  cRequired: bigint;
+  dRequired: bigint;
+  eRequired: boolean;
+  fRequired: ArrayBuffer;
+  gRequired: string;
+  hRequired: LocalStructOut;
+  iRequired: _Types.EmptyStructOut;
+  jRequired: null[];
+  kRequired: number[];
+  lRequired: bigint[];
+  mRequired: bigint[];
+  nRequired: boolean[];
+  oRequired: ArrayBuffer[];
+  pRequired: string[];
+  qRequired: LocalStructOut[];
+  rRequired: _Types.EmptyStructOut[];
+  sRequired: null[][];
+  tRequired: number[][];
+  uRequired: bigint[][];
+  vRequired: bigint[][];
+  wRequired: boolean[][];
+  xRequired: ArrayBuffer[][];
+  yRequired: string[][];
+  zRequired: LocalStructOut[][];
+  aaRequired: _Types.EmptyStructOut[][];
+  aAsymmetric: null;
+  bAsymmetric: number;
+  cAsymmetric: bigint;
+  dAsymmetric: bigint;
+  eAsymmetric: boolean;
+  fAsymmetric: ArrayBuffer;
+  gAsymmetric: string;
+  hAsymmetric: LocalStructOut;
+  iAsymmetric: _Types.EmptyStructOut;
+  jAsymmetric: null[];
+  kAsymmetric: number[];
+  lAsymmetric: bigint[];
+  mAsymmetric: bigint[];
+  nAsymmetric: boolean[];
+  oAsymmetric: ArrayBuffer[];
+  pAsymmetric: string[];
+  qAsymmetric: LocalStructOut[];
+  rAsymmetric: _Types.EmptyStructOut[];
+  sAsymmetric: null[][];
+  tAsymmetric: number[][];
+  uAsymmetric: bigint[][];
+  vAsymmetric: bigint[][];
+  wAsymmetric: boolean[][];
+  xAsymmetric: ArrayBuffer[][];
+  yAsymmetric: string[][];
+  zAsymmetric: LocalStructOut[][];
+  aaAsymmetric: _Types.EmptyStructOut[][];
+  aOptional: null | undefined;
+  bOptional: number | undefined;
+  cOptional: bigint | undefined;
+  dOptional: bigint | undefined;
+  eOptional: boolean | undefined;
+  fOptional: ArrayBuffer | undefined;
+  gOptional: string | undefined;
+  hOptional: LocalStructOut | undefined;
+  iOptional: _Types.EmptyStructOut | undefined;
+  jOptional: null[] | undefined;
+  kOptional: number[] | undefined;
+  lOptional: bigint[] | undefined;
+  mOptional: bigint[] | undefined;
+  nOptional: boolean[] | undefined;
+  oOptional: ArrayBuffer[] | undefined;
+  pOptional: string[] | undefined;
+  qOptional: LocalStructOut[] | undefined;
+  rOptional: _Types.EmptyStructOut[] | undefined;
+  sOptional: null[][] | undefined;
+  tOptional: number[][] | undefined;
+  uOptional: bigint[][] | undefined;
+  vOptional: bigint[][] | undefined;
+  wOptional: boolean[][] | undefined;
+  xOptional: ArrayBuffer[][] | undefined;
+  yOptional: string[][] | undefined;
+  zOptional: LocalStructOut[][] | undefined;
+  aaOptional: _Types.EmptyStructOut[][] | undefined;
+};
+
+export type FooIn = {
+  aRequired: null;
+  bRequired: number;
+  cRequired: bigint;
+  dRequired: bigint;
+  eRequired: boolean;
+  fRequired: ArrayBuffer;
+  gRequired: string;
+  hRequired: LocalStructIn;
+  iRequired: _Types.EmptyStructIn;
+  jRequired: null[];
+  kRequired: number[];
+  lRequired: bigint[];
+  mRequired: bigint[];
+  nRequired: boolean[];
+  oRequired: ArrayBuffer[];
+  pRequired: string[];
+  qRequired: LocalStructIn[];
+  rRequired: _Types.EmptyStructIn[];
+  sRequired: null[][];
+  tRequired: number[][];
+  uRequired: bigint[][];
+  vRequired: bigint[][];
+  wRequired: boolean[][];
+  xRequired: ArrayBuffer[][];
+  yRequired: string[][];
+  zRequired: LocalStructIn[][];
+  aaRequired: _Types.EmptyStructIn[][];
+  aAsymmetric: null | undefined;
+  bAsymmetric: number | undefined;
+  cAsymmetric: bigint | undefined;
+  dAsymmetric: bigint | undefined;
+  eAsymmetric: boolean | undefined;
+  fAsymmetric: ArrayBuffer | undefined;
+  gAsymmetric: string | undefined;
+  hAsymmetric: LocalStructIn | undefined;
+  iAsymmetric: _Types.EmptyStructIn | undefined;
+  jAsymmetric: null[] | undefined;
+  kAsymmetric: number[] | undefined;
+  lAsymmetric: bigint[] | undefined;
+  mAsymmetric: bigint[] | undefined;
+  nAsymmetric: boolean[] | undefined;
+  oAsymmetric: ArrayBuffer[] | undefined;
+  pAsymmetric: string[] | undefined;
+  qAsymmetric: LocalStructIn[] | undefined;
+  rAsymmetric: _Types.EmptyStructIn[] | undefined;
+  sAsymmetric: null[][] | undefined;
+  tAsymmetric: number[][] | undefined;
+  uAsymmetric: bigint[][] | undefined;
+  vAsymmetric: bigint[][] | undefined;
+  wAsymmetric: boolean[][] | undefined;
+  xAsymmetric: ArrayBuffer[][] | undefined;
+  yAsymmetric: string[][] | undefined;
+  zAsymmetric: LocalStructIn[][] | undefined;
+  aaAsymmetric: _Types.EmptyStructIn[][] | undefined;
+  aOptional: null | undefined;
+  bOptional: number | undefined;
+  cOptional: bigint | undefined;
+  dOptional: bigint | undefined;
+  eOptional: boolean | undefined;
+  fOptional: ArrayBuffer | undefined;
+  gOptional: string | undefined;
+  hOptional: LocalStructIn | undefined;
+  iOptional: _Types.EmptyStructIn | undefined;
+  jOptional: null[] | undefined;
+  kOptional: number[] | undefined;
+  lOptional: bigint[] | undefined;
+  mOptional: bigint[] | undefined;
+  nOptional: boolean[] | undefined;
+  oOptional: ArrayBuffer[] | undefined;
+  pOptional: string[] | undefined;
+  qOptional: LocalStructIn[] | undefined;
+  rOptional: _Types.EmptyStructIn[] | undefined;
+  sOptional: null[][] | undefined;
+  tOptional: number[][] | undefined;
+  uOptional: bigint[][] | undefined;
+  vOptional: bigint[][] | undefined;
+  wOptional: boolean[][] | undefined;
+  xOptional: ArrayBuffer[][] | undefined;
+  yOptional: string[][] | undefined;
+  zOptional: LocalStructIn[][] | undefined;
+  aaOptional: _Types.EmptyStructIn[][] | undefined;
+};
+
+declare function fooAtlas(message: FooOut): FooAtlas;
+
+declare function fooSize(message: FooOut): number;
+
+declare function fooSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: FooOut,
+  atlas: FooAtlas,
+): number;
+
+declare function fooDeserializeUnsafe(dataView: DataView): FooIn;
+
+declare function fooSerialize(message: FooOut): ArrayBuffer;
+
+declare function fooDeserialize(bytes: Deserializable): FooIn | Error;
+
+declare function fooCheckOut(value: unknown): string | undefined;
+
+export declare const Foo: {
+  atlas: typeof fooAtlas;
+  size: typeof fooSize;
+  serializeWithAtlasUnsafe: typeof fooSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof fooDeserializeUnsafe;
+  serialize: typeof fooSerialize;
+  deserialize: typeof fooDeserialize;
+  checkOut: typeof fooCheckOut;
+};
+
+export declare function isFooOut(value: unknown): value is FooOut;
+
+export declare function assertFooOut(value: unknown): asserts value is FooOut;
+
+export type BarAtlas =
+  | { $size: number; aRequired: number }
+  | { $size: number; bRequired: number }
+  | { $size: number; cRequired: number }
+  | { $size: number; dRequired: number }
+  | { $size: number; eRequired: number }
+  | { $size: number; fRequired: number }
+  | { $size: number; gRequired: Uint8Array }
+  | { $size: number; hRequired: LocalStructAtlas }
+  | { $size: number; iRequired: _Types.EmptyStructAtlas }
+  | { $size: number; jRequired: number }
+  | { $size: number; kRequired: number }
+  | { $size: number; lRequired: number }
+  | { $size: number; mRequired: number }
+  | { $size: number; nRequired: number }
+  | { $size: number; oRequired: { $size: number; $elements: number[] } }
+  | { $size: number; pRequired: { $size: number; $elements: Uint8Array[] } }
+  | { $size: number; qRequired: { $size: number; $elements: LocalStructAtlas[] } }
+  | { $size: number; rRequired: { $size: number; $elements: _Types.EmptyStructAtlas[] } }
+  | { $size: number; sRequired: { $size: number; $elements: number[] } }
+  | { $size: number; tRequired: { $size: number; $elements: number[] } }
+  | { $size: number; uRequired: { $size: number; $elements: number[] } }
+  | { $size: number; vRequired: { $size: number; $elements: number[] } }
+  | { $size: number; wRequired: { $size: number; $elements: number[] } }
+  | { $size: number; xRequired: { $size: number; $elements: { $size: number; $elements: number[] }[] } }
+  | { $size: number; yRequired: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] } }
+  | { $size: number; zRequired: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] } }
+  | { $size: number; aaRequired: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] } }
+  | { $size: number; aAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; bAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; cAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; dAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; eAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; fAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; gAsymmetric: Uint8Array; $fallback: BarAtlas }
+  | { $size: number; hAsymmetric: LocalStructAtlas; $fallback: BarAtlas }
+  | { $size: number; iAsymmetric: _Types.EmptyStructAtlas; $fallback: BarAtlas }
+  | { $size: number; jAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; kAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; lAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; mAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; nAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; oAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; pAsymmetric: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
+  | { $size: number; qAsymmetric: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; rAsymmetric: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; sAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; tAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; uAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; vAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; wAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; xAsymmetric: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
+  | { $size: number; yAsymmetric: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
+  | { $size: number; zAsymmetric: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aaAsymmetric: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aOptional: number; $fallback: BarAtlas }
+  | { $size: number; bOptional: number; $fallback: BarAtlas }
+  | { $size: number; cOptional: number; $fallback: BarAtlas }
+  | { $size: number; dOptional: number; $fallback: BarAtlas }
+  | { $size: number; eOptional: number; $fallback: BarAtlas }
+  | { $size: number; fOptional: number; $fallback: BarAtlas }
+  | { $size: number; gOptional: Uint8Array; $fallback: BarAtlas }
+  | { $size: number; hOptional: LocalStructAtlas; $fallback: BarAtlas }
+  | { $size: number; iOptional: _Types.EmptyStructAtlas; $fallback: BarAtlas }
+  | { $size: number; jOptional: number; $fallback: BarAtlas }
+  | { $size: number; kOptional: number; $fallback: BarAtlas }
+  | { $size: number; lOptional: number; $fallback: BarAtlas }
+  | { $size: number; mOptional: number; $fallback: BarAtlas }
+  | { $size: number; nOptional: number; $fallback: BarAtlas }
+  | { $size: number; oOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; pOptional: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
+  | { $size: number; qOptional: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; rOptional: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; sOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; tOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; uOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; vOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; wOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; xOptional: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
+  | { $size: number; yOptional: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
+  | { $size: number; zOptional: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aaOptional: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas };
+
+export type BarOut =
+  | { aRequired: null }
+  | { bRequired: number }
+  | { cRequired: bigint }
+  | { dRequired: bigint }
+  | { eRequired: boolean }
+  | { fRequired: ArrayBuffer }
+  | { gRequired: string }
+  | { hRequired: LocalStructOut }
+  | { iRequired: _Types.EmptyStructOut }
+  | { jRequired: null[] }
+  | { kRequired: number[] }
+  | { lRequired: bigint[] }
+  | { mRequired: bigint[] }
+  | { nRequired: boolean[] }
+  | { oRequired: ArrayBuffer[] }
+  | { pRequired: string[] }
+  | { qRequired: LocalStructOut[] }
+  | { rRequired: _Types.EmptyStructOut[] }
+  | { sRequired: null[][] }
+  | { tRequired: number[][] }
+  | { uRequired: bigint[][] }
+  | { vRequired: bigint[][] }
+  | { wRequired: boolean[][] }
+  | { xRequired: ArrayBuffer[][] }
+  | { yRequired: string[][] }
+  | { zRequired: LocalStructOut[][] }
+  | { aaRequired: _Types.EmptyStructOut[][] }
+  | { aAsymmetric: null; $fallback: BarOut }
+  | { bAsymmetric: number; $fallback: BarOut }
+  | { cAsymmetric: bigint; $fallback: BarOut }
+  | { dAsymmetric: bigint; $fallback: BarOut }
+  | { eAsymmetric: boolean; $fallback: BarOut }
+  | { fAsymmetric: ArrayBuffer; $fallback: BarOut }
+  | { gAsymmetric: string; $fallback: BarOut }
+  | { hAsymmetric: LocalStructOut; $fallback: BarOut }
+  | { iAsymmetric: _Types.EmptyStructOut; $fallback: BarOut }
+  | { jAsymmetric: null[]; $fallback: BarOut }
+  | { kAsymmetric: number[]; $fallback: BarOut }
+  | { lAsymmetric: bigint[]; $fallback: BarOut }
+  | { mAsymmetric: bigint[]; $fallback: BarOut }
+  | { nAsymmetric: boolean[]; $fallback: BarOut }
+  | { oAsymmetric: ArrayBuffer[]; $fallback: BarOut }
+  | { pAsymmetric: string[]; $fallback: BarOut }
+  | { qAsymmetric: LocalStructOut[]; $fallback: BarOut }
+  | { rAsymmetric: _Types.EmptyStructOut[]; $fallback: BarOut }
+  | { sAsymmetric: null[][]; $fallback: BarOut }
+  | { tAsymmetric: number[][]; $fallback: BarOut }
+  | { uAsymmetric: bigint[][]; $fallback: BarOut }
+  | { vAsymmetric: bigint[][]; $fallback: BarOut }
+  | { wAsymmetric: boolean[][]; $fallback: BarOut }
+  | { xAsymmetric: ArrayBuffer[][]; $fallback: BarOut }
+  | { yAsymmetric: string[][]; $fallback: BarOut }
+  | { zAsymmetric: LocalStructOut[][]; $fallback: BarOut }
+  | { aaAsymmetric: _Types.EmptyStructOut[][]; $fallback: BarOut }
+  | { aOptional: null; $fallback: BarOut }
+  | { bOptional: number; $fallback: BarOut }
+  | { cOptional: bigint; $fallback: BarOut }
+  | { dOptional: bigint; $fallback: BarOut }
+  | { eOptional: boolean; $fallback: BarOut }
+  | { fOptional: ArrayBuffer; $fallback: BarOut }
+  | { gOptional: string; $fallback: BarOut }
+  | { hOptional: LocalStructOut; $fallback: BarOut }
+  | { iOptional: _Types.EmptyStructOut; $fallback: BarOut }
+  | { jOptional: null[]; $fallback: BarOut }
+  | { kOptional: number[]; $fallback: BarOut }
+  | { lOptional: bigint[]; $fallback: BarOut }
+  | { mOptional: bigint[]; $fallback: BarOut }
+  | { nOptional: boolean[]; $fallback: BarOut }
+  | { oOptional: ArrayBuffer[]; $fallback: BarOut }
+  | { pOptional: string[]; $fallback: BarOut }
+  | { qOptional: LocalStructOut[]; $fallback: BarOut }
+  | { rOptional: _Types.EmptyStructOut[]; $fallback: BarOut }
+  | { sOptional: null[][]; $fallback: BarOut }
+  | { tOptional: number[][]; $fallback: BarOut }
+  | { uOptional: bigint[][]; $fallback: BarOut }
+  | { vOptional: bigint[][]; $fallback: BarOut }
+  | { wOptional: boolean[][]; $fallback: BarOut }
+  | { xOptional: ArrayBuffer[][]; $fallback: BarOut }
+  | { yOptional: string[][]; $fallback: BarOut }
+  | { zOptional: LocalStructOut[][]; $fallback: BarOut }
+  | { aaOptional: _Types.EmptyStructOut[][]; $fallback: BarOut };
+
+export type BarIn =
+  | { $field: 'aRequired'; aRequired: null }
+  | { $field: 'bRequired'; bRequired: number }
+  | { $field: 'cRequired'; cRequired: bigint }
+  | { $field: 'dRequired'; dRequired: bigint }
+  | { $field: 'eRequired'; eRequired: boolean }
+  | { $field: 'fRequired'; fRequired: ArrayBuffer }
+  | { $field: 'gRequired'; gRequired: string }
+  | { $field: 'hRequired'; hRequired: LocalStructIn }
+  | { $field: 'iRequired'; iRequired: _Types.EmptyStructIn }
+  | { $field: 'jRequired'; jRequired: null[] }
+  | { $field: 'kRequired'; kRequired: number[] }
+  | { $field: 'lRequired'; lRequired: bigint[] }
+  | { $field: 'mRequired'; mRequired: bigint[] }
+  | { $field: 'nRequired'; nRequired: boolean[] }
+  | { $field: 'oRequired'; oRequired: ArrayBuffer[] }
+  | { $field: 'pRequired'; pRequired: string[] }
+  | { $field: 'qRequired'; qRequired: LocalStructIn[] }
+  | { $field: 'rRequired'; rRequired: _Types.EmptyStructIn[] }
+  | { $field: 'sRequired'; sRequired: null[][] }
+  | { $field: 'tRequired'; tRequired: number[][] }
+  | { $field: 'uRequired'; uRequired: bigint[][] }
+  | { $field: 'vRequired'; vRequired: bigint[][] }
+  | { $field: 'wRequired'; wRequired: boolean[][] }
+  | { $field: 'xRequired'; xRequired: ArrayBuffer[][] }
+  | { $field: 'yRequired'; yRequired: string[][] }
+  | { $field: 'zRequired'; zRequired: LocalStructIn[][] }
+  | { $field: 'aaRequired'; aaRequired: _Types.EmptyStructIn[][] }
+  | { $field: 'aAsymmetric'; aAsymmetric: null }
+  | { $field: 'bAsymmetric'; bAsymmetric: number }
+  | { $field: 'cAsymmetric'; cAsymmetric: bigint }
+  | { $field: 'dAsymmetric'; dAsymmetric: bigint }
+  | { $field: 'eAsymmetric'; eAsymmetric: boolean }
+  | { $field: 'fAsymmetric'; fAsymmetric: ArrayBuffer }
+  | { $field: 'gAsymmetric'; gAsymmetric: string }
+  | { $field: 'hAsymmetric'; hAsymmetric: LocalStructIn }
+  | { $field: 'iAsymmetric'; iAsymmetric: _Types.EmptyStructIn }
+  | { $field: 'jAsymmetric'; jAsymmetric: null[] }
+  | { $field: 'kAsymmetric'; kAsymmetric: number[] }
+  | { $field: 'lAsymmetric'; lAsymmetric: bigint[] }
+  | { $field: 'mAsymmetric'; mAsymmetric: bigint[] }
+  | { $field: 'nAsymmetric'; nAsymmetric: boolean[] }
+  | { $field: 'oAsymmetric'; oAsymmetric: ArrayBuffer[] }
+  | { $field: 'pAsymmetric'; pAsymmetric: string[] }
+  | { $field: 'qAsymmetric'; qAsymmetric: LocalStructIn[] }
+  | { $field: 'rAsymmetric'; rAsymmetric: _Types.EmptyStructIn[] }
+  | { $field: 'sAsymmetric'; sAsymmetric: null[][] }
+  | { $field: 'tAsymmetric'; tAsymmetric: number[][] }
+  | { $field: 'uAsymmetric'; uAsymmetric: bigint[][] }
+  | { $field: 'vAsymmetric'; vAsymmetric: bigint[][] }
+  | { $field: 'wAsymmetric'; wAsymmetric: boolean[][] }
+  | { $field: 'xAsymmetric'; xAsymmetric: ArrayBuffer[][] }
+  | { $field: 'yAsymmetric'; yAsymmetric: string[][] }
+  | { $field: 'zAsymmetric'; zAsymmetric: LocalStructIn[][] }
+  | { $field: 'aaAsymmetric'; aaAsymmetric: _Types.EmptyStructIn[][] }
+  | { $field: 'aOptional'; aOptional: null; $fallback: BarIn }
+  | { $field: 'bOptional'; bOptional: number; $fallback: BarIn }
+  | { $field: 'cOptional'; cOptional: bigint; $fallback: BarIn }
+  | { $field: 'dOptional'; dOptional: bigint; $fallback: BarIn }
+  | { $field: 'eOptional'; eOptional: boolean; $fallback: BarIn }
+  | { $field: 'fOptional'; fOptional: ArrayBuffer; $fallback: BarIn }
+  | { $field: 'gOptional'; gOptional: string; $fallback: BarIn }
+  | { $field: 'hOptional'; hOptional: LocalStructIn; $fallback: BarIn }
+  | { $field: 'iOptional'; iOptional: _Types.EmptyStructIn; $fallback: BarIn }
+  | { $field: 'jOptional'; jOptional: null[]; $fallback: BarIn }
+  | { $field: 'kOptional'; kOptional: number[]; $fallback: BarIn }
+  | { $field: 'lOptional'; lOptional: bigint[]; $fallback: BarIn }
+  | { $field: 'mOptional'; mOptional: bigint[]; $fallback: BarIn }
+  | { $field: 'nOptional'; nOptional: boolean[]; $fallback: BarIn }
+  | { $field: 'oOptional'; oOptional: ArrayBuffer[]; $fallback: BarIn }
+  | { $field: 'pOptional'; pOptional: string[]; $fallback: BarIn }
+  | { $field: 'qOptional'; qOptional: LocalStructIn[]; $fallback: BarIn }
+  | { $field: 'rOptional'; rOptional: _Types.EmptyStructIn[]; $fallback: BarIn }
+  | { $field: 'sOptional'; sOptional: null[][]; $fallback: BarIn }
+  | { $field: 'tOptional'; tOptional: number[][]; $fallback: BarIn }
+  | { $field: 'uOptional'; uOptional: bigint[][]; $fallback: BarIn }
+  | { $field: 'vOptional'; vOptional: bigint[][]; $fallback: BarIn }
+  | { $field: 'wOptional'; wOptional: boolean[][]; $fallback: BarIn }
+  | { $field: 'xOptional'; xOptional: ArrayBuffer[][]; $fallback: BarIn }
+  | { $field: 'yOptional'; yOptional: string[][]; $fallback: BarIn }
+  | { $field: 'zOptional'; zOptional: LocalStructIn[][]; $fallback: BarIn }
+  | { $field: 'aaOptional'; aaOptional: _Types.EmptyStructIn[][]; $fallback: BarIn };
+
+declare function barAtlas(message: BarOut): BarAtlas;
+
+declare function barSize(message: BarOut): number;
+
+declare function barSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: BarOut,
+  atlas: BarAtlas,
+): number;
+
+declare function barDeserializeUnsafe(dataView: DataView): BarIn;
+
+declare function barSerialize(message: BarOut): ArrayBuffer;
+
+declare function barDeserialize(bytes: Deserializable): BarIn | Error;
+
+declare function barCheckOut(value: unknown): string | undefined;
+
+export declare const Bar: {
+  atlas: typeof barAtlas;
+  size: typeof barSize;
+  serializeWithAtlasUnsafe: typeof barSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof barDeserializeUnsafe;
+  serialize: typeof barSerialize;
+  deserialize: typeof barDeserialize;
+  checkOut: typeof barCheckOut;
+};
+
+export declare function isBarOut(value: unknown): value is BarOut;
+
+export declare function assertBarOut(value: unknown): asserts value is BarOut;
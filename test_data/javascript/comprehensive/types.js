@@ -0,0 +1,17347 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+function localStructAtlas(message) {
+  let size = 0;
+
+  return {
+    $size: size,
+  };
+}
+
+function localStructSize(message) {
+  return 0;
+}
+
+function localStructSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  return offset;
+}
+
+function localStructDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  return {
+  };
+}
+
+function localStructSerialize(message) {
+  const atlasValue = localStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  localStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function localStructDeserialize(bytes) {
+  try {
+    return localStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function localStructCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return undefined;
+}
+
+export const LocalStruct = {
+  atlas: localStructAtlas,
+  size: localStructSize,
+  serializeWithAtlasUnsafe: localStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: localStructDeserializeUnsafe,
+  serialize: localStructSerialize,
+  deserialize: localStructDeserialize,
+  checkOut: localStructCheckOut,
+};
+
+export function isLocalStructOut(value) {
+  return localStructCheckOut(value) === undefined;
+}
+
+export function assertLocalStructOut(value) {
+  const error = localStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid LocalStructOut: value' + error + '.');
+  }
+}
+
+function fooAtlas(message) {
+  let size = 0;
+
+  let $aRequired;
+  let $bRequired;
+  let $cRequired;
+  let $dRequired;
+  let $eRequired;
+  let $fRequired;
+  let $gRequired;
+  let $hRequired;
+  let $iRequired;
+  let $jRequired;
+  let $kRequired;
+  let $lRequired;
+  let $mRequired;
+  let $nRequired;
+  let $oRequired;
+  let $pRequired;
+  let $qRequired;
+  let $rRequired;
+  let $sRequired;
+  let $tRequired;
+  let $uRequired;
+  let $vRequired;
+  let $wRequired;
+  let $xRequired;
+  let $yRequired;
+  let $zRequired;
+  let $aaRequired;
+  let $aAsymmetric;
+  let $bAsymmetric;
+  let $cAsymmetric;
+  let $dAsymmetric;
+  let $eAsymmetric;
+  let $fAsymmetric;
+  let $gAsymmetric;
+  let $hAsymmetric;
+  let $iAsymmetric;
+  let $jAsymmetric;
+  let $kAsymmetric;
+  let $lAsymmetric;
+  let $mAsymmetric;
+  let $nAsymmetric;
+  let $oAsymmetric;
+  let $pAsymmetric;
+  let $qAsymmetric;
+  let $rAsymmetric;
+  let $sAsymmetric;
+  let $tAsymmetric;
+  let $uAsymmetric;
+  let $vAsymmetric;
+  let $wAsymmetric;
+  let $xAsymmetric;
+  let $yAsymmetric;
+  let $zAsymmetric;
+  let $aaAsymmetric;
+  let $aOptional;
+  let $bOptional;
+  let $cOptional;
+  let $dOptional;
+  let $eOptional;
+  let $fOptional;
+  let $gOptional;
+  let $hOptional;
+  let $iOptional;
+  let $jOptional;
+  let $kOptional;
+  let $lOptional;
+  let $mOptional;
+  let $nOptional;
+  let $oOptional;
+  let $pOptional;
+  let $qOptional;
+  let $rOptional;
+  let $sOptional;
+  let $tOptional;
+  let $uOptional;
+  let $vOptional;
+  let $wOptional;
+  let $xOptional;
+  let $yOptional;
+  let $zOptional;
+  let $aaOptional;
+
+  {
+    let payloadAtlas;
+    const payload = message.aRequired;
+    payloadAtlas = 0;
+    $aRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.bRequired;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
+    }
+    $bRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.cRequired;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    $cRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(2n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.dRequired;
+    {
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
+      }
+    }
+    $dRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(3n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.eRequired;
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
+    }
+    $eRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(4n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.fRequired;
+    payloadAtlas = payload.byteLength;
+    $fRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(5n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.gRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    $gRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(6n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.hRequired;
+    payloadAtlas = LocalStruct.atlas(payload);
+    $hRequired = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(7n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.iRequired;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
+    $iRequired = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(8n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.jRequired;
+    {
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+    }
+    $jRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(9n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.kRequired;
+    payloadAtlas = 8 * payload.length;
+    $kRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(10n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.lRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $lRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(11n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.mRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $mRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(12n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.nRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $nRequired = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(13n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.oRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $oRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(14n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.pRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $pRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(15n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.qRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $qRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(16n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.rRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $rRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(17n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.sRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $sRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(18n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.tRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $tRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(19n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.uRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $uRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(20n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.vRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $vRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(21n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.wRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $wRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(22n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.xRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $xRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(23n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.yRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $yRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(24n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.zRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $zRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(25n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.aaRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $aaRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(26n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.aAsymmetric;
+    payloadAtlas = 0;
+    $aAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(28n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.bAsymmetric;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
+    }
+    $bAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(29n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.cAsymmetric;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    $cAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(30n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.dAsymmetric;
+    {
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
+      }
+    }
+    $dAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(31n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.eAsymmetric;
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
+    }
+    $eAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(32n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.fAsymmetric;
+    payloadAtlas = payload.byteLength;
+    $fAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(33n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.gAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    $gAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(34n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.hAsymmetric;
+    payloadAtlas = LocalStruct.atlas(payload);
+    $hAsymmetric = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(35n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.iAsymmetric;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
+    $iAsymmetric = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(36n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.jAsymmetric;
+    {
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+    }
+    $jAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(37n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.kAsymmetric;
+    payloadAtlas = 8 * payload.length;
+    $kAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(38n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.lAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $lAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(39n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.mAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $mAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(40n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.nAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    $nAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(41n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.oAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $oAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(42n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.pAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $pAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(43n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.qAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $qAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(44n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.rAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $rAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(45n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.sAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $sAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(46n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.tAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $tAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(47n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.uAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $uAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(48n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.vAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $vAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(49n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.wAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $wAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(50n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.xAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $xAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(51n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.yAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $yAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(52n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.zAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $zAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(53n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.aaAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    $aaAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.$size;
+    size += fieldHeaderSize(54n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.aOptional;
+    if (payload !== undefined) {
+      payloadAtlas = 0;
+      $aOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(56n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.bOptional;
+    if (payload !== undefined) {
+      if (Object.is(payload, 0)) {
+        payloadAtlas = 0;
+      } else {
+        payloadAtlas = 8;
+      }
+      $bOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(57n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.cOptional;
+    if (payload !== undefined) {
+      if (payload === 0n) {
+        payloadAtlas = 0;
+      } else if (payload < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(payload);
+      } else {
+        payloadAtlas = 8;
+      }
+      $cOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(58n, payloadSize, true) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.dOptional;
+    if (payload !== undefined) {
+      {
+        const zigzag = zigzagEncode(payload);
+        if (zigzag === 0n) {
+          payloadAtlas = 0;
+        } else if (zigzag < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(zigzag);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+      $dOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(59n, payloadSize, true) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.eOptional;
+    if (payload !== undefined) {
+      if (payload) {
+        payloadAtlas = 1;
+      } else {
+        payloadAtlas = 0;
+      }
+      $eOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(60n, payloadSize, true) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.fOptional;
+    if (payload !== undefined) {
+      payloadAtlas = payload.byteLength;
+      $fOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(61n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.gOptional;
+    if (payload !== undefined) {
+      payloadAtlas = textEncoder.encode(payload);
+      $gOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.byteLength;
+      size += fieldHeaderSize(62n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.hOptional;
+    if (payload !== undefined) {
+      payloadAtlas = LocalStruct.atlas(payload);
+      $hOptional = payloadAtlas;
+      const payloadSize = (payloadAtlas).$size;
+      size += fieldHeaderSize(63n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.iOptional;
+    if (payload !== undefined) {
+      payloadAtlas = _Types.EmptyStruct.atlas(payload);
+      $iOptional = payloadAtlas;
+      const payloadSize = (payloadAtlas).$size;
+      size += fieldHeaderSize(64n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.jOptional;
+    if (payload !== undefined) {
+      {
+        const oldPayload = payload;
+        {
+          const payload = BigInt(oldPayload.length);
+          if (payload === 0n) {
+            payloadAtlas = 0;
+          } else if (payload < 567_382_630_219_904n) {
+            payloadAtlas = varintSizeFromValue(payload);
+          } else {
+            payloadAtlas = 8;
+          }
+        }
+      }
+      $jOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(65n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.kOptional;
+    if (payload !== undefined) {
+      payloadAtlas = 8 * payload.length;
+      $kOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(66n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.lOptional;
+    if (payload !== undefined) {
+      {
+        let arraySize = 0;
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas = 0;
+          payloadAtlas = varintSizeFromValue(payload);
+          arraySize += payloadAtlas;
+        }
+        payloadAtlas = arraySize;
+      }
+      $lOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(67n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.mOptional;
+    if (payload !== undefined) {
+      {
+        let arraySize = 0;
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas = 0;
+          payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+          arraySize += payloadAtlas;
+        }
+        payloadAtlas = arraySize;
+      }
+      $mOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(68n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.nOptional;
+    if (payload !== undefined) {
+      {
+        let arraySize = 0;
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas = 0;
+          payloadAtlas = 1;
+          arraySize += payloadAtlas;
+        }
+        payloadAtlas = arraySize;
+      }
+      $nOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(69n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.oOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          payloadAtlas = payload.byteLength;
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $oOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(70n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.pOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          payloadAtlas = textEncoder.encode(payload);
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas.byteLength;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $pOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(71n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.qOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          payloadAtlas = LocalStruct.atlas(payload);
+          elements.push(payloadAtlas);
+          const payloadSize = (payloadAtlas).$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $qOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(72n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.rOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          payloadAtlas = _Types.EmptyStruct.atlas(payload);
+          elements.push(payloadAtlas);
+          const payloadSize = (payloadAtlas).$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $rOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(73n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.sOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            const oldPayload = payload;
+            {
+              const payload = BigInt(oldPayload.length);
+              payloadAtlas = varintSizeFromValue(payload);
+            }
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $sOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(74n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.tOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          payloadAtlas = 8 * payload.length;
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $tOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(75n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.uOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let arraySize = 0;
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas = 0;
+              payloadAtlas = varintSizeFromValue(payload);
+              arraySize += payloadAtlas;
+            }
+            payloadAtlas = arraySize;
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $uOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(76n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.vOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let arraySize = 0;
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas = 0;
+              payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+              arraySize += payloadAtlas;
+            }
+            payloadAtlas = arraySize;
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $vOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(77n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.wOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let arraySize = 0;
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas = 0;
+              payloadAtlas = 1;
+              arraySize += payloadAtlas;
+            }
+            payloadAtlas = arraySize;
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $wOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(78n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.xOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let size = 0;
+            let elements = [];
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas;
+              payloadAtlas = payload.byteLength;
+              elements.push(payloadAtlas);
+              const payloadSize = payloadAtlas;
+              size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+            }
+            payloadAtlas = { $size: size, $elements: elements };
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas.$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $xOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(79n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.yOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let size = 0;
+            let elements = [];
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas;
+              payloadAtlas = textEncoder.encode(payload);
+              elements.push(payloadAtlas);
+              const payloadSize = payloadAtlas.byteLength;
+              size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+            }
+            payloadAtlas = { $size: size, $elements: elements };
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas.$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $yOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(80n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.zOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let size = 0;
+            let elements = [];
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas;
+              payloadAtlas = LocalStruct.atlas(payload);
+              elements.push(payloadAtlas);
+              const payloadSize = (payloadAtlas).$size;
+              size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+            }
+            payloadAtlas = { $size: size, $elements: elements };
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas.$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $zOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(81n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.aaOptional;
+    if (payload !== undefined) {
+      {
+        let size = 0;
+        let elements = [];
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          let payloadAtlas;
+          {
+            let size = 0;
+            let elements = [];
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              let payloadAtlas;
+              payloadAtlas = _Types.EmptyStruct.atlas(payload);
+              elements.push(payloadAtlas);
+              const payloadSize = (payloadAtlas).$size;
+              size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+            }
+            payloadAtlas = { $size: size, $elements: elements };
+          }
+          elements.push(payloadAtlas);
+          const payloadSize = payloadAtlas.$size;
+          size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+        }
+        payloadAtlas = { $size: size, $elements: elements };
+      }
+      $aaOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.$size;
+      size += fieldHeaderSize(82n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  return {
+    $size: size,
+    aRequired: $aRequired,
+    bRequired: $bRequired,
+    cRequired: $cRequired,
+    dRequired: $dRequired,
+    eRequired: $eRequired,
+    fRequired: $fRequired,
+    gRequired: $gRequired,
+    hRequired: $hRequired,
+    iRequired: $iRequired,
+    jRequired: $jRequired,
+    kRequired: $kRequired,
+    lRequired: $lRequired,
+    mRequired: $mRequired,
+    nRequired: $nRequired,
+    oRequired: $oRequired,
+    pRequired: $pRequired,
+    qRequired: $qRequired,
+    rRequired: $rRequired,
+    sRequired: $sRequired,
+    tRequired: $tRequired,
+    uRequired: $uRequired,
+    vRequired: $vRequired,
+    wRequired: $wRequired,
+    xRequired: $xRequired,
+    yRequired: $yRequired,
+    zRequired: $zRequired,
+    aaRequired: $aaRequired,
+    aAsymmetric: $aAsymmetric,
+    bAsymmetric: $bAsymmetric,
+    cAsymmetric: $cAsymmetric,
+    dAsymmetric: $dAsymmetric,
+    eAsymmetric: $eAsymmetric,
+    fAsymmetric: $fAsymmetric,
+    gAsymmetric: $gAsymmetric,
+    hAsymmetric: $hAsymmetric,
+    iAsymmetric: $iAsymmetric,
+    jAsymmetric: $jAsymmetric,
+    kAsymmetric: $kAsymmetric,
+    lAsymmetric: $lAsymmetric,
+    mAsymmetric: $mAsymmetric,
+    nAsymmetric: $nAsymmetric,
+    oAsymmetric: $oAsymmetric,
+    pAsymmetric: $pAsymmetric,
+    qAsymmetric: $qAsymmetric,
+    rAsymmetric: $rAsymmetric,
+    sAsymmetric: $sAsymmetric,
+    tAsymmetric: $tAsymmetric,
+    uAsymmetric: $uAsymmetric,
+    vAsymmetric: $vAsymmetric,
+    wAsymmetric: $wAsymmetric,
+    xAsymmetric: $xAsymmetric,
+    yAsymmetric: $yAsymmetric,
+    zAsymmetric: $zAsymmetric,
+    aaAsymmetric: $aaAsymmetric,
+    aOptional: $aOptional,
+    bOptional: $bOptional,
+    cOptional: $cOptional,
+    dOptional: $dOptional,
+    eOptional: $eOptional,
+    fOptional: $fOptional,
+    gOptional: $gOptional,
+    hOptional: $hOptional,
+    iOptional: $iOptional,
+    jOptional: $jOptional,
+    kOptional: $kOptional,
+    lOptional: $lOptional,
+    mOptional: $mOptional,
+    nOptional: $nOptional,
+    oOptional: $oOptional,
+    pOptional: $pOptional,
+    qOptional: $qOptional,
+    rOptional: $rOptional,
+    sOptional: $sOptional,
+    tOptional: $tOptional,
+    uOptional: $uOptional,
+    vOptional: $vOptional,
+    wOptional: $wOptional,
+    xOptional: $xOptional,
+    yOptional: $yOptional,
+    zOptional: $zOptional,
+    aaOptional: $aaOptional,
+  };
+}
+
+function fooSize(message) {
+  return fooAtlas(message).$size;
+}
+
+function fooSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.aRequired;
+    const payloadAtlas = atlas.aRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+  }
+
+  {
+    const payload = message.bRequired;
+    const payloadAtlas = atlas.bRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+  }
+
+  {
+    const payload = message.cRequired;
+    const payloadAtlas = atlas.cRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.dRequired;
+    const payloadAtlas = atlas.dRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.eRequired;
+    const payloadAtlas = atlas.eRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.fRequired;
+    const payloadAtlas = atlas.fRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+  }
+
+  {
+    const payload = message.gRequired;
+    const payloadAtlas = atlas.gRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.hRequired;
+    const payloadAtlas = atlas.hRequired;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 7n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.iRequired;
+    const payloadAtlas = atlas.iRequired;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.jRequired;
+    const payloadAtlas = atlas.jRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.kRequired;
+    const payloadAtlas = atlas.kRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+  }
+
+  {
+    const payload = message.lRequired;
+    const payloadAtlas = atlas.lRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 11n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mRequired;
+    const payloadAtlas = atlas.mRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 12n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nRequired;
+    const payloadAtlas = atlas.nRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oRequired;
+    const payloadAtlas = atlas.oRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pRequired;
+    const payloadAtlas = atlas.pRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 15n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qRequired;
+    const payloadAtlas = atlas.qRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 16n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+  }
+
+  {
+    const payload = message.rRequired;
+    const payloadAtlas = atlas.rRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 17n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+  }
+
+  {
+    const payload = message.sRequired;
+    const payloadAtlas = atlas.sRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 18n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tRequired;
+    const payloadAtlas = atlas.tRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 19n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uRequired;
+    const payloadAtlas = atlas.uRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 20n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vRequired;
+    const payloadAtlas = atlas.vRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 21n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wRequired;
+    const payloadAtlas = atlas.wRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 22n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xRequired;
+    const payloadAtlas = atlas.xRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 23n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yRequired;
+    const payloadAtlas = atlas.yRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 24n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zRequired;
+    const payloadAtlas = atlas.zRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 25n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaRequired;
+    const payloadAtlas = atlas.aaRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 26n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aAsymmetric;
+    const payloadAtlas = atlas.aAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 28n, payloadSize, false);
+  }
+
+  {
+    const payload = message.bAsymmetric;
+    const payloadAtlas = atlas.bAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 29n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+  }
+
+  {
+    const payload = message.cAsymmetric;
+    const payloadAtlas = atlas.cAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 30n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.dAsymmetric;
+    const payloadAtlas = atlas.dAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 31n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.eAsymmetric;
+    const payloadAtlas = atlas.eAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 32n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.fAsymmetric;
+    const payloadAtlas = atlas.fAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 33n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+  }
+
+  {
+    const payload = message.gAsymmetric;
+    const payloadAtlas = atlas.gAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 34n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.hAsymmetric;
+    const payloadAtlas = atlas.hAsymmetric;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 35n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.iAsymmetric;
+    const payloadAtlas = atlas.iAsymmetric;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 36n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.jAsymmetric;
+    const payloadAtlas = atlas.jAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 37n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.kAsymmetric;
+    const payloadAtlas = atlas.kAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 38n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+  }
+
+  {
+    const payload = message.lAsymmetric;
+    const payloadAtlas = atlas.lAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 39n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mAsymmetric;
+    const payloadAtlas = atlas.mAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 40n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nAsymmetric;
+    const payloadAtlas = atlas.nAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 41n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oAsymmetric;
+    const payloadAtlas = atlas.oAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 42n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pAsymmetric;
+    const payloadAtlas = atlas.pAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 43n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qAsymmetric;
+    const payloadAtlas = atlas.qAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 44n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+  }
+
+  {
+    const payload = message.rAsymmetric;
+    const payloadAtlas = atlas.rAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 45n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+  }
+
+  {
+    const payload = message.sAsymmetric;
+    const payloadAtlas = atlas.sAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 46n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tAsymmetric;
+    const payloadAtlas = atlas.tAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 47n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uAsymmetric;
+    const payloadAtlas = atlas.uAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 48n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vAsymmetric;
+    const payloadAtlas = atlas.vAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 49n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wAsymmetric;
+    const payloadAtlas = atlas.wAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 50n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xAsymmetric;
+    const payloadAtlas = atlas.xAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 51n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yAsymmetric;
+    const payloadAtlas = atlas.yAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 52n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zAsymmetric;
+    const payloadAtlas = atlas.zAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 53n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaAsymmetric;
+    const payloadAtlas = atlas.aaAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 54n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aOptional;
+    const payloadAtlas = atlas.aOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 56n, payloadSize, false);
+    }
+  }
+
+  {
+    const payload = message.bOptional;
+    const payloadAtlas = atlas.bOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 57n, payloadSize, false);
+      if (payloadAtlas !== 0) {
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+  }
+
+  {
+    const payload = message.cOptional;
+    const payloadAtlas = atlas.cOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 58n, payloadSize, true);
+      {
+        const varint = payload;
+        if (varint > 567_382_630_219_903n) {
+          dataView.setBigUint64(offset, varint, true);
+          offset += 8;
+        } else if (varint !== 0n) {
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.dOptional;
+    const payloadAtlas = atlas.dOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 59n, payloadSize, true);
+      {
+        const varint = zigzagEncode(payload);
+        if (varint > 567_382_630_219_903n) {
+          dataView.setBigUint64(offset, varint, true);
+          offset += 8;
+        } else if (varint !== 0n) {
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.eOptional;
+    const payloadAtlas = atlas.eOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 60n, payloadSize, true);
+      {
+        const varint = payload ? 1n : 0n;
+        if (varint > 567_382_630_219_903n) {
+          dataView.setBigUint64(offset, varint, true);
+          offset += 8;
+        } else if (varint !== 0n) {
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.fOptional;
+    const payloadAtlas = atlas.fOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 61n, payloadSize, false);
+      {
+        const sourceBuffer = new Uint8Array(payload);
+        const targetBuffer = new Uint8Array(
+          dataView.buffer,
+          dataView.byteOffset,
+          dataView.byteLength,
+        );
+        targetBuffer.set(sourceBuffer, offset);
+        offset += sourceBuffer.byteLength;
+      }
+    }
+  }
+
+  {
+    const payload = message.gOptional;
+    const payloadAtlas = atlas.gOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.byteLength;
+      offset = serializeFieldHeader(dataView, offset, 62n, payloadSize, false);
+      {
+        const targetBuffer = new Uint8Array(
+          dataView.buffer,
+          dataView.byteOffset,
+          dataView.byteLength,
+        );
+        targetBuffer.set(payloadAtlas, offset);
+        offset += payloadAtlas.byteLength;
+      }
+    }
+  }
+
+  {
+    const payload = message.hOptional;
+    const payloadAtlas = atlas.hOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas).$size;
+      offset = serializeFieldHeader(dataView, offset, 63n, payloadSize, false);
+      offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  {
+    const payload = message.iOptional;
+    const payloadAtlas = atlas.iOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas).$size;
+      offset = serializeFieldHeader(dataView, offset, 64n, payloadSize, false);
+      offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  {
+    const payload = message.jOptional;
+    const payloadAtlas = atlas.jOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 65n, payloadSize, false);
+      {
+        const varint = BigInt(payload.length);
+        if (varint > 567_382_630_219_903n) {
+          dataView.setBigUint64(offset, varint, true);
+          offset += 8;
+        } else if (varint !== 0n) {
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.kOptional;
+    const payloadAtlas = atlas.kOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 66n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          dataView.setFloat64(offset, payload, true);
+          offset += 8;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.lOptional;
+    const payloadAtlas = atlas.lOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 67n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          {
+            const varint = payload;
+            offset = serializeVarint(dataView, offset, varint);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mOptional;
+    const payloadAtlas = atlas.mOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 68n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          {
+            const varint = zigzagEncode(payload);
+            offset = serializeVarint(dataView, offset, varint);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nOptional;
+    const payloadAtlas = atlas.nOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 69n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          {
+            const varint = payload ? 1n : 0n;
+            offset = serializeVarint(dataView, offset, varint);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oOptional;
+    const payloadAtlas = atlas.oOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 70n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const sourceBuffer = new Uint8Array(payload);
+            const targetBuffer = new Uint8Array(
+              dataView.buffer,
+              dataView.byteOffset,
+              dataView.byteLength,
+            );
+            targetBuffer.set(sourceBuffer, offset);
+            offset += sourceBuffer.byteLength;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pOptional;
+    const payloadAtlas = atlas.pOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 71n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+          {
+            const targetBuffer = new Uint8Array(
+              dataView.buffer,
+              dataView.byteOffset,
+              dataView.byteLength,
+            );
+            targetBuffer.set(payloadAtlas, offset);
+            offset += payloadAtlas.byteLength;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qOptional;
+    const payloadAtlas = atlas.qOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 72n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+          offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.rOptional;
+    const payloadAtlas = atlas.rOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 73n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+          offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.sOptional;
+    const payloadAtlas = atlas.sOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 74n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const varint = BigInt(payload.length);
+            offset = serializeVarint(dataView, offset, varint);
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tOptional;
+    const payloadAtlas = atlas.tOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 75n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              dataView.setFloat64(offset, payload, true);
+              offset += 8;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uOptional;
+    const payloadAtlas = atlas.uOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 76n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              {
+                const varint = payload;
+                offset = serializeVarint(dataView, offset, varint);
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vOptional;
+    const payloadAtlas = atlas.vOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 77n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              {
+                const varint = zigzagEncode(payload);
+                offset = serializeVarint(dataView, offset, varint);
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wOptional;
+    const payloadAtlas = atlas.wOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 78n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+          {
+            const oldPayload = payload;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              {
+                const varint = payload ? 1n : 0n;
+                offset = serializeVarint(dataView, offset, varint);
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xOptional;
+    const payloadAtlas = atlas.xOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 79n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+          {
+            const oldPayload = payload;
+            const oldPayloadAtlas = payloadAtlas;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              const payloadAtlas = oldPayloadAtlas.$elements[i];
+              offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+              {
+                const sourceBuffer = new Uint8Array(payload);
+                const targetBuffer = new Uint8Array(
+                  dataView.buffer,
+                  dataView.byteOffset,
+                  dataView.byteLength,
+                );
+                targetBuffer.set(sourceBuffer, offset);
+                offset += sourceBuffer.byteLength;
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yOptional;
+    const payloadAtlas = atlas.yOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 80n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+          {
+            const oldPayload = payload;
+            const oldPayloadAtlas = payloadAtlas;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              const payloadAtlas = oldPayloadAtlas.$elements[i];
+              offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+              {
+                const targetBuffer = new Uint8Array(
+                  dataView.buffer,
+                  dataView.byteOffset,
+                  dataView.byteLength,
+                );
+                targetBuffer.set(payloadAtlas, offset);
+                offset += payloadAtlas.byteLength;
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zOptional;
+    const payloadAtlas = atlas.zOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 81n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+          {
+            const oldPayload = payload;
+            const oldPayloadAtlas = payloadAtlas;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              const payloadAtlas = oldPayloadAtlas.$elements[i];
+              offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+              offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaOptional;
+    const payloadAtlas = atlas.aaOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.$size;
+      offset = serializeFieldHeader(dataView, offset, 82n, payloadSize, false);
+      {
+        const oldPayload = payload;
+        const oldPayloadAtlas = payloadAtlas;
+        for (let i = 0; i < oldPayload.length; i += 1) {
+          const payload = oldPayload[i];
+          const payloadAtlas = oldPayloadAtlas.$elements[i];
+          offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+          {
+            const oldPayload = payload;
+            const oldPayloadAtlas = payloadAtlas;
+            for (let i = 0; i < oldPayload.length; i += 1) {
+              const payload = oldPayload[i];
+              const payloadAtlas = oldPayloadAtlas.$elements[i];
+              offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+              offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            }
+          }
+        }
+      }
+    }
+  }
+
+  return offset;
+}
+
+function fooDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $aRequired;
+  let $bRequired;
+  let $cRequired;
+  let $dRequired;
+  let $eRequired;
+  let $fRequired;
+  let $gRequired;
+  let $hRequired;
+  let $iRequired;
+  let $jRequired;
+  let $kRequired;
+  let $lRequired;
+  let $mRequired;
+  let $nRequired;
+  let $oRequired;
+  let $pRequired;
+  let $qRequired;
+  let $rRequired;
+  let $sRequired;
+  let $tRequired;
+  let $uRequired;
+  let $vRequired;
+  let $wRequired;
+  let $xRequired;
+  let $yRequired;
+  let $zRequired;
+  let $aaRequired;
+  let $aAsymmetric;
+  let $bAsymmetric;
+  let $cAsymmetric;
+  let $dAsymmetric;
+  let $eAsymmetric;
+  let $fAsymmetric;
+  let $gAsymmetric;
+  let $hAsymmetric;
+  let $iAsymmetric;
+  let $jAsymmetric;
+  let $kAsymmetric;
+  let $lAsymmetric;
+  let $mAsymmetric;
+  let $nAsymmetric;
+  let $oAsymmetric;
+  let $pAsymmetric;
+  let $qAsymmetric;
+  let $rAsymmetric;
+  let $sAsymmetric;
+  let $tAsymmetric;
+  let $uAsymmetric;
+  let $vAsymmetric;
+  let $wAsymmetric;
+  let $xAsymmetric;
+  let $yAsymmetric;
+  let $zAsymmetric;
+  let $aaAsymmetric;
+  let $aOptional;
+  let $bOptional;
+  let $cOptional;
+  let $dOptional;
+  let $eOptional;
+  let $fOptional;
+  let $gOptional;
+  let $hOptional;
+  let $iOptional;
+  let $jOptional;
+  let $kOptional;
+  let $lOptional;
+  let $mOptional;
+  let $nOptional;
+  let $oOptional;
+  let $pOptional;
+  let $qOptional;
+  let $rOptional;
+  let $sOptional;
+  let $tOptional;
+  let $uOptional;
+  let $vOptional;
+  let $wOptional;
+  let $xOptional;
+  let $yOptional;
+  let $zOptional;
+  let $aaOptional;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        let payload = null;
+        $aRequired = payload;
+        break;
+      }
+      case 1n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        $bRequired = payload;
+        break;
+      }
+      case 2n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        $cRequired = payload;
+        break;
+      }
+      case 3n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        $dRequired = payload;
+        break;
+      }
+      case 4n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        $eRequired = payload;
+        break;
+      }
+      case 5n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $fRequired = payload;
+        break;
+      }
+      case 6n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $gRequired = payload;
+        break;
+      }
+      case 7n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $hRequired = payload;
+        break;
+      }
+      case 8n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $iRequired = payload;
+        break;
+      }
+      case 9n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        offset += oldOffset;
+        $jRequired = payload;
+        break;
+      }
+      case 10n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $kRequired = payload;
+        break;
+      }
+      case 11n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $lRequired = payload;
+        break;
+      }
+      case 12n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $mRequired = payload;
+        break;
+      }
+      case 13n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $nRequired = payload;
+        break;
+      }
+      case 14n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $oRequired = payload;
+        break;
+      }
+      case 15n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $pRequired = payload;
+        break;
+      }
+      case 16n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $qRequired = payload;
+        break;
+      }
+      case 17n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $rRequired = payload;
+        break;
+      }
+      case 18n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $sRequired = payload;
+        break;
+      }
+      case 19n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $tRequired = payload;
+        break;
+      }
+      case 20n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $uRequired = payload;
+        break;
+      }
+      case 21n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $vRequired = payload;
+        break;
+      }
+      case 22n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $wRequired = payload;
+        break;
+      }
+      case 23n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $xRequired = payload;
+        break;
+      }
+      case 24n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $yRequired = payload;
+        break;
+      }
+      case 25n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $zRequired = payload;
+        break;
+      }
+      case 26n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $aaRequired = payload;
+        break;
+      }
+      case 28n: {
+        let payload = null;
+        $aAsymmetric = payload;
+        break;
+      }
+      case 29n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        $bAsymmetric = payload;
+        break;
+      }
+      case 30n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        $cAsymmetric = payload;
+        break;
+      }
+      case 31n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        $dAsymmetric = payload;
+        break;
+      }
+      case 32n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        $eAsymmetric = payload;
+        break;
+      }
+      case 33n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $fAsymmetric = payload;
+        break;
+      }
+      case 34n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $gAsymmetric = payload;
+        break;
+      }
+      case 35n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $hAsymmetric = payload;
+        break;
+      }
+      case 36n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $iAsymmetric = payload;
+        break;
+      }
+      case 37n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        offset += oldOffset;
+        $jAsymmetric = payload;
+        break;
+      }
+      case 38n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $kAsymmetric = payload;
+        break;
+      }
+      case 39n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $lAsymmetric = payload;
+        break;
+      }
+      case 40n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $mAsymmetric = payload;
+        break;
+      }
+      case 41n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $nAsymmetric = payload;
+        break;
+      }
+      case 42n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $oAsymmetric = payload;
+        break;
+      }
+      case 43n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $pAsymmetric = payload;
+        break;
+      }
+      case 44n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $qAsymmetric = payload;
+        break;
+      }
+      case 45n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $rAsymmetric = payload;
+        break;
+      }
+      case 46n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $sAsymmetric = payload;
+        break;
+      }
+      case 47n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $tAsymmetric = payload;
+        break;
+      }
+      case 48n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $uAsymmetric = payload;
+        break;
+      }
+      case 49n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $vAsymmetric = payload;
+        break;
+      }
+      case 50n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $wAsymmetric = payload;
+        break;
+      }
+      case 51n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $xAsymmetric = payload;
+        break;
+      }
+      case 52n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $yAsymmetric = payload;
+        break;
+      }
+      case 53n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $zAsymmetric = payload;
+        break;
+      }
+      case 54n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $aaAsymmetric = payload;
+        break;
+      }
+      case 56n: {
+        let payload = null;
+        $aOptional = payload;
+        break;
+      }
+      case 57n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        $bOptional = payload;
+        break;
+      }
+      case 58n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        $cOptional = payload;
+        break;
+      }
+      case 59n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        $dOptional = payload;
+        break;
+      }
+      case 60n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        $eOptional = payload;
+        break;
+      }
+      case 61n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $fOptional = payload;
+        break;
+      }
+      case 62n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $gOptional = payload;
+        break;
+      }
+      case 63n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $hOptional = payload;
+        break;
+      }
+      case 64n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $iOptional = payload;
+        break;
+      }
+      case 65n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        offset += oldOffset;
+        $jOptional = payload;
+        break;
+      }
+      case 66n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $kOptional = payload;
+        break;
+      }
+      case 67n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $lOptional = payload;
+        break;
+      }
+      case 68n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $mOptional = payload;
+        break;
+      }
+      case 69n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        $nOptional = payload;
+        break;
+      }
+      case 70n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $oOptional = payload;
+        break;
+      }
+      case 71n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $pOptional = payload;
+        break;
+      }
+      case 72n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $qOptional = payload;
+        break;
+      }
+      case 73n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $rOptional = payload;
+        break;
+      }
+      case 74n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $sOptional = payload;
+        break;
+      }
+      case 75n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $tOptional = payload;
+        break;
+      }
+      case 76n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $uOptional = payload;
+        break;
+      }
+      case 77n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $vOptional = payload;
+        break;
+      }
+      case 78n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $wOptional = payload;
+        break;
+      }
+      case 79n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $xOptional = payload;
+        break;
+      }
+      case 80n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $yOptional = payload;
+        break;
+      }
+      case 81n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $zOptional = payload;
+        break;
+      }
+      case 82n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        $aaOptional = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($aRequired === undefined
+    || $bRequired === undefined
+    || $cRequired === undefined
+    || $dRequired === undefined
+    || $eRequired === undefined
+    || $fRequired === undefined
+    || $gRequired === undefined
+    || $hRequired === undefined
+    || $iRequired === undefined
+    || $jRequired === undefined
+    || $kRequired === undefined
+    || $lRequired === undefined
+    || $mRequired === undefined
+    || $nRequired === undefined
+    || $oRequired === undefined
+    || $pRequired === undefined
+    || $qRequired === undefined
+    || $rRequired === undefined
+    || $sRequired === undefined
+    || $tRequired === undefined
+    || $uRequired === undefined
+    || $vRequired === undefined
+    || $wRequired === undefined
+    || $xRequired === undefined
+    || $yRequired === undefined
+    || $zRequired === undefined
+    || $aaRequired === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    aRequired: $aRequired,
+    bRequired: $bRequired,
+    cRequired: $cRequired,
+    dRequired: $dRequired,
+    eRequired: $eRequired,
+    fRequired: $fRequired,
+    gRequired: $gRequired,
+    hRequired: $hRequired,
+    iRequired: $iRequired,
+    jRequired: $jRequired,
+    kRequired: $kRequired,
+    lRequired: $lRequired,
+    mRequired: $mRequired,
+    nRequired: $nRequired,
+    oRequired: $oRequired,
+    pRequired: $pRequired,
+    qRequired: $qRequired,
+    rRequired: $rRequired,
+    sRequired: $sRequired,
+    tRequired: $tRequired,
+    uRequired: $uRequired,
+    vRequired: $vRequired,
+    wRequired: $wRequired,
+    xRequired: $xRequired,
+    yRequired: $yRequired,
+    zRequired: $zRequired,
+    aaRequired: $aaRequired,
+    aAsymmetric: $aAsymmetric,
+    bAsymmetric: $bAsymmetric,
+    cAsymmetric: $cAsymmetric,
+    dAsymmetric: $dAsymmetric,
+    eAsymmetric: $eAsymmetric,
+    fAsymmetric: $fAsymmetric,
+    gAsymmetric: $gAsymmetric,
+    hAsymmetric: $hAsymmetric,
+    iAsymmetric: $iAsymmetric,
+    jAsymmetric: $jAsymmetric,
+    kAsymmetric: $kAsymmetric,
+    lAsymmetric: $lAsymmetric,
+    mAsymmetric: $mAsymmetric,
+    nAsymmetric: $nAsymmetric,
+    oAsymmetric: $oAsymmetric,
+    pAsymmetric: $pAsymmetric,
+    qAsymmetric: $qAsymmetric,
+    rAsymmetric: $rAsymmetric,
+    sAsymmetric: $sAsymmetric,
+    tAsymmetric: $tAsymmetric,
+    uAsymmetric: $uAsymmetric,
+    vAsymmetric: $vAsymmetric,
+    wAsymmetric: $wAsymmetric,
+    xAsymmetric: $xAsymmetric,
+    yAsymmetric: $yAsymmetric,
+    zAsymmetric: $zAsymmetric,
+    aaAsymmetric: $aaAsymmetric,
+    aOptional: $aOptional,
+    bOptional: $bOptional,
+    cOptional: $cOptional,
+    dOptional: $dOptional,
+    eOptional: $eOptional,
+    fOptional: $fOptional,
+    gOptional: $gOptional,
+    hOptional: $hOptional,
+    iOptional: $iOptional,
+    jOptional: $jOptional,
+    kOptional: $kOptional,
+    lOptional: $lOptional,
+    mOptional: $mOptional,
+    nOptional: $nOptional,
+    oOptional: $oOptional,
+    pOptional: $pOptional,
+    qOptional: $qOptional,
+    rOptional: $rOptional,
+    sOptional: $sOptional,
+    tOptional: $tOptional,
+    uOptional: $uOptional,
+    vOptional: $vOptional,
+    wOptional: $wOptional,
+    xOptional: $xOptional,
+    yOptional: $yOptional,
+    zOptional: $zOptional,
+    aaOptional: $aaOptional,
+  };
+}
+
+function fooSerialize(message) {
+  const atlasValue = fooAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  fooSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function fooDeserialize(bytes) {
+  try {
+    return fooDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function fooCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.aRequired;
+    if (payload !== null) {
+      return '.aRequired must be null';
+    }
+  }
+
+  {
+    const payload = message.bRequired;
+    if (typeof payload !== 'number') {
+      return '.bRequired must be a number';
+    }
+  }
+
+  {
+    const payload = message.cRequired;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cRequired must be a bigint from 0 to 18446744073709551615';
+    }
+  }
+
+  {
+    const payload = message.dRequired;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dRequired must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+  }
+
+  {
+    const payload = message.eRequired;
+    if (typeof payload !== 'boolean') {
+      return '.eRequired must be a boolean';
+    }
+  }
+
+  {
+    const payload = message.fRequired;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fRequired must be an ArrayBuffer';
+    }
+  }
+
+  {
+    const payload = message.gRequired;
+    if (typeof payload !== 'string') {
+      return '.gRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.hRequired;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hRequired' + error;
+    }
+  }
+
+  {
+    const payload = message.iRequired;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iRequired' + error;
+    }
+  }
+
+  {
+    const payload = message.jRequired;
+    if (!Array.isArray(payload)) {
+      return '.jRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jRequired[' + i0 + '] must be null';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.kRequired;
+    if (!Array.isArray(payload)) {
+      return '.kRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kRequired[' + i0 + '] must be a number';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.lRequired;
+    if (!Array.isArray(payload)) {
+      return '.lRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lRequired[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mRequired;
+    if (!Array.isArray(payload)) {
+      return '.mRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mRequired[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nRequired;
+    if (!Array.isArray(payload)) {
+      return '.nRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nRequired[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oRequired;
+    if (!Array.isArray(payload)) {
+      return '.oRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oRequired[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pRequired;
+    if (!Array.isArray(payload)) {
+      return '.pRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pRequired[' + i0 + '] must be a string';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qRequired;
+    if (!Array.isArray(payload)) {
+      return '.qRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.rRequired;
+    if (!Array.isArray(payload)) {
+      return '.rRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.sRequired;
+    if (!Array.isArray(payload)) {
+      return '.sRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sRequired[' + i0 + '][' + i1 + '] must be null';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tRequired;
+    if (!Array.isArray(payload)) {
+      return '.tRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tRequired[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uRequired;
+    if (!Array.isArray(payload)) {
+      return '.uRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uRequired[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vRequired;
+    if (!Array.isArray(payload)) {
+      return '.vRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vRequired[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wRequired;
+    if (!Array.isArray(payload)) {
+      return '.wRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wRequired[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xRequired;
+    if (!Array.isArray(payload)) {
+      return '.xRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xRequired[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yRequired;
+    if (!Array.isArray(payload)) {
+      return '.yRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yRequired[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zRequired;
+    if (!Array.isArray(payload)) {
+      return '.zRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zRequired[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaRequired;
+    if (!Array.isArray(payload)) {
+      return '.aaRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaRequired[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aAsymmetric;
+    if (payload !== null) {
+      return '.aAsymmetric must be null';
+    }
+  }
+
+  {
+    const payload = message.bAsymmetric;
+    if (typeof payload !== 'number') {
+      return '.bAsymmetric must be a number';
+    }
+  }
+
+  {
+    const payload = message.cAsymmetric;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cAsymmetric must be a bigint from 0 to 18446744073709551615';
+    }
+  }
+
+  {
+    const payload = message.dAsymmetric;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dAsymmetric must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+  }
+
+  {
+    const payload = message.eAsymmetric;
+    if (typeof payload !== 'boolean') {
+      return '.eAsymmetric must be a boolean';
+    }
+  }
+
+  {
+    const payload = message.fAsymmetric;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fAsymmetric must be an ArrayBuffer';
+    }
+  }
+
+  {
+    const payload = message.gAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.gAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.hAsymmetric;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hAsymmetric' + error;
+    }
+  }
+
+  {
+    const payload = message.iAsymmetric;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iAsymmetric' + error;
+    }
+  }
+
+  {
+    const payload = message.jAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.jAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jAsymmetric[' + i0 + '] must be null';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.kAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.kAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kAsymmetric[' + i0 + '] must be a number';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.lAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.lAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lAsymmetric[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.mAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mAsymmetric[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.nAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nAsymmetric[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.oAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oAsymmetric[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.pAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pAsymmetric[' + i0 + '] must be a string';
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.qAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.rAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.rAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.sAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.sAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sAsymmetric[' + i0 + '][' + i1 + '] must be null';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.tAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tAsymmetric[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.uAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.vAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.wAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wAsymmetric[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.xAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xAsymmetric[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.yAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yAsymmetric[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.zAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.aaAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aOptional;
+    if (payload !== undefined) {
+      if (payload !== null) {
+        return '.aOptional must be null';
+      }
+    }
+  }
+
+  {
+    const payload = message.bOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'number') {
+        return '.bOptional must be a number';
+      }
+    }
+  }
+
+  {
+    const payload = message.cOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+        return '.cOptional must be a bigint from 0 to 18446744073709551615';
+      }
+    }
+  }
+
+  {
+    const payload = message.dOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+        return '.dOptional must be a bigint from -9223372036854775808 to 9223372036854775807';
+      }
+    }
+  }
+
+  {
+    const payload = message.eOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'boolean') {
+        return '.eOptional must be a boolean';
+      }
+    }
+  }
+
+  {
+    const payload = message.fOptional;
+    if (payload !== undefined) {
+      if (!(payload instanceof ArrayBuffer)) {
+        return '.fOptional must be an ArrayBuffer';
+      }
+    }
+  }
+
+  {
+    const payload = message.gOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.gOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.hOptional;
+    if (payload !== undefined) {
+      const error = LocalStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.hOptional' + error;
+      }
+    }
+  }
+
+  {
+    const payload = message.iOptional;
+    if (payload !== undefined) {
+      const error = _Types.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.iOptional' + error;
+      }
+    }
+  }
+
+  {
+    const payload = message.jOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.jOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (payload !== null) {
+            return '.jOptional[' + i0 + '] must be null';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.kOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.kOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'number') {
+            return '.kOptional[' + i0 + '] must be a number';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.lOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.lOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+            return '.lOptional[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.mOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.mOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+            return '.mOptional[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.nOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.nOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'boolean') {
+            return '.nOptional[' + i0 + '] must be a boolean';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.oOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.oOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!(payload instanceof ArrayBuffer)) {
+            return '.oOptional[' + i0 + '] must be an ArrayBuffer';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.pOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.pOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'string') {
+            return '.pOptional[' + i0 + '] must be a string';
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.qOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.qOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          const error = LocalStruct.checkOut(payload);
+          if (error !== undefined) {
+            return '.qOptional[' + i0 + ']' + error;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.rOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.rOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          const error = _Types.EmptyStruct.checkOut(payload);
+          if (error !== undefined) {
+            return '.rOptional[' + i0 + ']' + error;
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.sOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.sOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.sOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (payload !== null) {
+                return '.sOptional[' + i0 + '][' + i1 + '] must be null';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.tOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.tOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.tOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'number') {
+                return '.tOptional[' + i0 + '][' + i1 + '] must be a number';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.uOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.uOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.uOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+                return '.uOptional[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.vOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.vOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.vOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+                return '.vOptional[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.wOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.wOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.wOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'boolean') {
+                return '.wOptional[' + i0 + '][' + i1 + '] must be a boolean';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.xOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.xOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.xOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (!(payload instanceof ArrayBuffer)) {
+                return '.xOptional[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.yOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.yOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.yOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'string') {
+                return '.yOptional[' + i0 + '][' + i1 + '] must be a string';
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.zOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.zOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.zOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              const error = LocalStruct.checkOut(payload);
+              if (error !== undefined) {
+                return '.zOptional[' + i0 + '][' + i1 + ']' + error;
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  {
+    const payload = message.aaOptional;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.aaOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.aaOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              const error = _Types.EmptyStruct.checkOut(payload);
+              if (error !== undefined) {
+                return '.aaOptional[' + i0 + '][' + i1 + ']' + error;
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const Foo = {
+  atlas: fooAtlas,
+  size: fooSize,
+  serializeWithAtlasUnsafe: fooSerializeWithAtlasUnsafe,
+  deserializeUnsafe: fooDeserializeUnsafe,
+  serialize: fooSerialize,
+  deserialize: fooDeserialize,
+  checkOut: fooCheckOut,
+};
+
+export function isFooOut(value) {
+  return fooCheckOut(value) === undefined;
+}
+
+export function assertFooOut(value) {
+  const error = fooCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid FooOut: value' + error + '.');
+  }
+}
+
+function barAtlas(message) {
+  if ('aRequired' in message) {
+    let payloadAtlas;
+    const payload = message.aRequired;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, aRequired: payloadAtlas };
+  }
+
+  if ('bRequired' in message) {
+    let payloadAtlas;
+    const payload = message.bRequired;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize, bRequired: payloadAtlas };
+  }
+
+  if ('cRequired' in message) {
+    let payloadAtlas;
+    const payload = message.cRequired;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(2n, payloadSize, true) + payloadSize, cRequired: payloadAtlas };
+  }
+
+  if ('dRequired' in message) {
+    let payloadAtlas;
+    const payload = message.dRequired;
+    {
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
+      }
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(3n, payloadSize, true) + payloadSize, dRequired: payloadAtlas };
+  }
+
+  if ('eRequired' in message) {
+    let payloadAtlas;
+    const payload = message.eRequired;
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(4n, payloadSize, true) + payloadSize, eRequired: payloadAtlas };
+  }
+
+  if ('fRequired' in message) {
+    let payloadAtlas;
+    const payload = message.fRequired;
+    payloadAtlas = payload.byteLength;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(5n, payloadSize, false) + payloadSize, fRequired: payloadAtlas };
+  }
+
+  if ('gRequired' in message) {
+    let payloadAtlas;
+    const payload = message.gRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(6n, payloadSize, false) + payloadSize, gRequired: payloadAtlas };
+  }
+
+  if ('hRequired' in message) {
+    let payloadAtlas;
+    const payload = message.hRequired;
+    payloadAtlas = LocalStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    return { $size: fieldHeaderSize(7n, payloadSize, false) + payloadSize, hRequired: payloadAtlas };
+  }
+
+  if ('iRequired' in message) {
+    let payloadAtlas;
+    const payload = message.iRequired;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    return { $size: fieldHeaderSize(8n, payloadSize, false) + payloadSize, iRequired: payloadAtlas };
+  }
+
+  if ('jRequired' in message) {
+    let payloadAtlas;
+    const payload = message.jRequired;
+    {
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(9n, payloadSize, false) + payloadSize, jRequired: payloadAtlas };
+  }
+
+  if ('kRequired' in message) {
+    let payloadAtlas;
+    const payload = message.kRequired;
+    payloadAtlas = 8 * payload.length;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(10n, payloadSize, false) + payloadSize, kRequired: payloadAtlas };
+  }
+
+  if ('lRequired' in message) {
+    let payloadAtlas;
+    const payload = message.lRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(11n, payloadSize, false) + payloadSize, lRequired: payloadAtlas };
+  }
+
+  if ('mRequired' in message) {
+    let payloadAtlas;
+    const payload = message.mRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(12n, payloadSize, false) + payloadSize, mRequired: payloadAtlas };
+  }
+
+  if ('nRequired' in message) {
+    let payloadAtlas;
+    const payload = message.nRequired;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(13n, payloadSize, false) + payloadSize, nRequired: payloadAtlas };
+  }
+
+  if ('oRequired' in message) {
+    let payloadAtlas;
+    const payload = message.oRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(14n, payloadSize, false) + payloadSize, oRequired: payloadAtlas };
+  }
+
+  if ('pRequired' in message) {
+    let payloadAtlas;
+    const payload = message.pRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(15n, payloadSize, false) + payloadSize, pRequired: payloadAtlas };
+  }
+
+  if ('qRequired' in message) {
+    let payloadAtlas;
+    const payload = message.qRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(16n, payloadSize, false) + payloadSize, qRequired: payloadAtlas };
+  }
+
+  if ('rRequired' in message) {
+    let payloadAtlas;
+    const payload = message.rRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(17n, payloadSize, false) + payloadSize, rRequired: payloadAtlas };
+  }
+
+  if ('sRequired' in message) {
+    let payloadAtlas;
+    const payload = message.sRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(18n, payloadSize, false) + payloadSize, sRequired: payloadAtlas };
+  }
+
+  if ('tRequired' in message) {
+    let payloadAtlas;
+    const payload = message.tRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(19n, payloadSize, false) + payloadSize, tRequired: payloadAtlas };
+  }
+
+  if ('uRequired' in message) {
+    let payloadAtlas;
+    const payload = message.uRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(20n, payloadSize, false) + payloadSize, uRequired: payloadAtlas };
+  }
+
+  if ('vRequired' in message) {
+    let payloadAtlas;
+    const payload = message.vRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(21n, payloadSize, false) + payloadSize, vRequired: payloadAtlas };
+  }
+
+  if ('wRequired' in message) {
+    let payloadAtlas;
+    const payload = message.wRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(22n, payloadSize, false) + payloadSize, wRequired: payloadAtlas };
+  }
+
+  if ('xRequired' in message) {
+    let payloadAtlas;
+    const payload = message.xRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(23n, payloadSize, false) + payloadSize, xRequired: payloadAtlas };
+  }
+
+  if ('yRequired' in message) {
+    let payloadAtlas;
+    const payload = message.yRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(24n, payloadSize, false) + payloadSize, yRequired: payloadAtlas };
+  }
+
+  if ('zRequired' in message) {
+    let payloadAtlas;
+    const payload = message.zRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(25n, payloadSize, false) + payloadSize, zRequired: payloadAtlas };
+  }
+
+  if ('aaRequired' in message) {
+    let payloadAtlas;
+    const payload = message.aaRequired;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(26n, payloadSize, false) + payloadSize, aaRequired: payloadAtlas };
+  }
+
+  if ('aAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.aAsymmetric;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(28n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('bAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.bAsymmetric;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(29n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('cAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.cAsymmetric;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(30n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('dAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.dAsymmetric;
+    {
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
+      }
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(31n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('eAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.eAsymmetric;
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(32n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('fAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.fAsymmetric;
+    payloadAtlas = payload.byteLength;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(33n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('gAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.gAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(34n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('hAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.hAsymmetric;
+    payloadAtlas = LocalStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(35n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('iAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.iAsymmetric;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(36n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('jAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.jAsymmetric;
+    {
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(37n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('kAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.kAsymmetric;
+    payloadAtlas = 8 * payload.length;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(38n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('lAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.lAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(39n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('mAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.mAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(40n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('nAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.nAsymmetric;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(41n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('oAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.oAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(42n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('pAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.pAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(43n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('qAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.qAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(44n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('rAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.rAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(45n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('sAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.sAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(46n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('tAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.tAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(47n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('uAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.uAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(48n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('vAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.vAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(49n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('wAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.wAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(50n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('xAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.xAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(51n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('yAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.yAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(52n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('zAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.zAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(53n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('aaAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.aaAsymmetric;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(54n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('aOptional' in message) {
+    let payloadAtlas;
+    const payload = message.aOptional;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(56n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('bOptional' in message) {
+    let payloadAtlas;
+    const payload = message.bOptional;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(57n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('cOptional' in message) {
+    let payloadAtlas;
+    const payload = message.cOptional;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(58n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('dOptional' in message) {
+    let payloadAtlas;
+    const payload = message.dOptional;
+    {
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
+      }
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(59n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('eOptional' in message) {
+    let payloadAtlas;
+    const payload = message.eOptional;
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(60n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('fOptional' in message) {
+    let payloadAtlas;
+    const payload = message.fOptional;
+    payloadAtlas = payload.byteLength;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(61n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('gOptional' in message) {
+    let payloadAtlas;
+    const payload = message.gOptional;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(62n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('hOptional' in message) {
+    let payloadAtlas;
+    const payload = message.hOptional;
+    payloadAtlas = LocalStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(63n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('iOptional' in message) {
+    let payloadAtlas;
+    const payload = message.iOptional;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
+    const payloadSize = (payloadAtlas).$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(64n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('jOptional' in message) {
+    let payloadAtlas;
+    const payload = message.jOptional;
+    {
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
+      }
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(65n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('kOptional' in message) {
+    let payloadAtlas;
+    const payload = message.kOptional;
+    payloadAtlas = 8 * payload.length;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(66n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('lOptional' in message) {
+    let payloadAtlas;
+    const payload = message.lOptional;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(67n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('mOptional' in message) {
+    let payloadAtlas;
+    const payload = message.mOptional;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(68n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('nOptional' in message) {
+    let payloadAtlas;
+    const payload = message.nOptional;
+    {
+      let arraySize = 0;
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
+      }
+      payloadAtlas = arraySize;
+    }
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(69n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('oOptional' in message) {
+    let payloadAtlas;
+    const payload = message.oOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(70n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('pOptional' in message) {
+    let payloadAtlas;
+    const payload = message.pOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(71n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('qOptional' in message) {
+    let payloadAtlas;
+    const payload = message.qOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(72n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('rOptional' in message) {
+    let payloadAtlas;
+    const payload = message.rOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(73n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('sOptional' in message) {
+    let payloadAtlas;
+    const payload = message.sOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(74n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('tOptional' in message) {
+    let payloadAtlas;
+    const payload = message.tOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(75n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('uOptional' in message) {
+    let payloadAtlas;
+    const payload = message.uOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(76n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('vOptional' in message) {
+    let payloadAtlas;
+    const payload = message.vOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(77n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('wOptional' in message) {
+    let payloadAtlas;
+    const payload = message.wOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let arraySize = 0;
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
+          }
+          payloadAtlas = arraySize;
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(78n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('xOptional' in message) {
+    let payloadAtlas;
+    const payload = message.xOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(79n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('yOptional' in message) {
+    let payloadAtlas;
+    const payload = message.yOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(80n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('zOptional' in message) {
+    let payloadAtlas;
+    const payload = message.zOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(81n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('aaOptional' in message) {
+    let payloadAtlas;
+    const payload = message.aaOptional;
+    {
+      let size = 0;
+      let elements = [];
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        let payloadAtlas;
+        {
+          let size = 0;
+          let elements = [];
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            let payloadAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          }
+          payloadAtlas = { $size: size, $elements: elements };
+        }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(82n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function barSize(message) {
+  return barAtlas(message).$size;
+}
+
+function barSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  if ('aRequired' in message) {
+    const payload = message.aRequired;
+    const payloadAtlas = (atlas).aRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    return offset;
+  }
+
+  if ('bRequired' in message) {
+    const payload = message.bRequired;
+    const payloadAtlas = (atlas).bRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    return offset;
+  }
+
+  if ('cRequired' in message) {
+    const payload = message.cRequired;
+    const payloadAtlas = (atlas).cRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('dRequired' in message) {
+    const payload = message.dRequired;
+    const payloadAtlas = (atlas).dRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('eRequired' in message) {
+    const payload = message.eRequired;
+    const payloadAtlas = (atlas).eRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('fRequired' in message) {
+    const payload = message.fRequired;
+    const payloadAtlas = (atlas).fRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    return offset;
+  }
+
+  if ('gRequired' in message) {
+    const payload = message.gRequired;
+    const payloadAtlas = (atlas).gRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('hRequired' in message) {
+    const payload = message.hRequired;
+    const payloadAtlas = (atlas).hRequired;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 7n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    return offset;
+  }
+
+  if ('iRequired' in message) {
+    const payload = message.iRequired;
+    const payloadAtlas = (atlas).iRequired;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    return offset;
+  }
+
+  if ('jRequired' in message) {
+    const payload = message.jRequired;
+    const payloadAtlas = (atlas).jRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('kRequired' in message) {
+    const payload = message.kRequired;
+    const payloadAtlas = (atlas).kRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    return offset;
+  }
+
+  if ('lRequired' in message) {
+    const payload = message.lRequired;
+    const payloadAtlas = (atlas).lRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 11n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('mRequired' in message) {
+    const payload = message.mRequired;
+    const payloadAtlas = (atlas).mRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 12n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('nRequired' in message) {
+    const payload = message.nRequired;
+    const payloadAtlas = (atlas).nRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('oRequired' in message) {
+    const payload = message.oRequired;
+    const payloadAtlas = (atlas).oRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('pRequired' in message) {
+    const payload = message.pRequired;
+    const payloadAtlas = (atlas).pRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 15n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('qRequired' in message) {
+    const payload = message.qRequired;
+    const payloadAtlas = (atlas).qRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 16n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    return offset;
+  }
+
+  if ('rRequired' in message) {
+    const payload = message.rRequired;
+    const payloadAtlas = (atlas).rRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 17n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    return offset;
+  }
+
+  if ('sRequired' in message) {
+    const payload = message.sRequired;
+    const payloadAtlas = (atlas).sRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 18n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('tRequired' in message) {
+    const payload = message.tRequired;
+    const payloadAtlas = (atlas).tRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 19n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('uRequired' in message) {
+    const payload = message.uRequired;
+    const payloadAtlas = (atlas).uRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 20n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('vRequired' in message) {
+    const payload = message.vRequired;
+    const payloadAtlas = (atlas).vRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 21n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('wRequired' in message) {
+    const payload = message.wRequired;
+    const payloadAtlas = (atlas).wRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 22n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('xRequired' in message) {
+    const payload = message.xRequired;
+    const payloadAtlas = (atlas).xRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 23n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('yRequired' in message) {
+    const payload = message.yRequired;
+    const payloadAtlas = (atlas).yRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 24n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('zRequired' in message) {
+    const payload = message.zRequired;
+    const payloadAtlas = (atlas).zRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 25n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('aaRequired' in message) {
+    const payload = message.aaRequired;
+    const payloadAtlas = (atlas).aaRequired;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 26n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    return offset;
+  }
+
+  if ('aAsymmetric' in message) {
+    const payload = message.aAsymmetric;
+    const payloadAtlas = (atlas).aAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 28n, payloadSize, false);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('bAsymmetric' in message) {
+    const payload = message.bAsymmetric;
+    const payloadAtlas = (atlas).bAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 29n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('cAsymmetric' in message) {
+    const payload = message.cAsymmetric;
+    const payloadAtlas = (atlas).cAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 30n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('dAsymmetric' in message) {
+    const payload = message.dAsymmetric;
+    const payloadAtlas = (atlas).dAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 31n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('eAsymmetric' in message) {
+    const payload = message.eAsymmetric;
+    const payloadAtlas = (atlas).eAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 32n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('fAsymmetric' in message) {
+    const payload = message.fAsymmetric;
+    const payloadAtlas = (atlas).fAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 33n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('gAsymmetric' in message) {
+    const payload = message.gAsymmetric;
+    const payloadAtlas = (atlas).gAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 34n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('hAsymmetric' in message) {
+    const payload = message.hAsymmetric;
+    const payloadAtlas = (atlas).hAsymmetric;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 35n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('iAsymmetric' in message) {
+    const payload = message.iAsymmetric;
+    const payloadAtlas = (atlas).iAsymmetric;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 36n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('jAsymmetric' in message) {
+    const payload = message.jAsymmetric;
+    const payloadAtlas = (atlas).jAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 37n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('kAsymmetric' in message) {
+    const payload = message.kAsymmetric;
+    const payloadAtlas = (atlas).kAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 38n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('lAsymmetric' in message) {
+    const payload = message.lAsymmetric;
+    const payloadAtlas = (atlas).lAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 39n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('mAsymmetric' in message) {
+    const payload = message.mAsymmetric;
+    const payloadAtlas = (atlas).mAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 40n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('nAsymmetric' in message) {
+    const payload = message.nAsymmetric;
+    const payloadAtlas = (atlas).nAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 41n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('oAsymmetric' in message) {
+    const payload = message.oAsymmetric;
+    const payloadAtlas = (atlas).oAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 42n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('pAsymmetric' in message) {
+    const payload = message.pAsymmetric;
+    const payloadAtlas = (atlas).pAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 43n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('qAsymmetric' in message) {
+    const payload = message.qAsymmetric;
+    const payloadAtlas = (atlas).qAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 44n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('rAsymmetric' in message) {
+    const payload = message.rAsymmetric;
+    const payloadAtlas = (atlas).rAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 45n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('sAsymmetric' in message) {
+    const payload = message.sAsymmetric;
+    const payloadAtlas = (atlas).sAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 46n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('tAsymmetric' in message) {
+    const payload = message.tAsymmetric;
+    const payloadAtlas = (atlas).tAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 47n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('uAsymmetric' in message) {
+    const payload = message.uAsymmetric;
+    const payloadAtlas = (atlas).uAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 48n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('vAsymmetric' in message) {
+    const payload = message.vAsymmetric;
+    const payloadAtlas = (atlas).vAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 49n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('wAsymmetric' in message) {
+    const payload = message.wAsymmetric;
+    const payloadAtlas = (atlas).wAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 50n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('xAsymmetric' in message) {
+    const payload = message.xAsymmetric;
+    const payloadAtlas = (atlas).xAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 51n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('yAsymmetric' in message) {
+    const payload = message.yAsymmetric;
+    const payloadAtlas = (atlas).yAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 52n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('zAsymmetric' in message) {
+    const payload = message.zAsymmetric;
+    const payloadAtlas = (atlas).zAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 53n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('aaAsymmetric' in message) {
+    const payload = message.aaAsymmetric;
+    const payloadAtlas = (atlas).aaAsymmetric;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 54n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('aOptional' in message) {
+    const payload = message.aOptional;
+    const payloadAtlas = (atlas).aOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 56n, payloadSize, false);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('bOptional' in message) {
+    const payload = message.bOptional;
+    const payloadAtlas = (atlas).bOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 57n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('cOptional' in message) {
+    const payload = message.cOptional;
+    const payloadAtlas = (atlas).cOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 58n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('dOptional' in message) {
+    const payload = message.dOptional;
+    const payloadAtlas = (atlas).dOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 59n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('eOptional' in message) {
+    const payload = message.eOptional;
+    const payloadAtlas = (atlas).eOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 60n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('fOptional' in message) {
+    const payload = message.fOptional;
+    const payloadAtlas = (atlas).fOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 61n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('gOptional' in message) {
+    const payload = message.gOptional;
+    const payloadAtlas = (atlas).gOptional;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 62n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('hOptional' in message) {
+    const payload = message.hOptional;
+    const payloadAtlas = (atlas).hOptional;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 63n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('iOptional' in message) {
+    const payload = message.iOptional;
+    const payloadAtlas = (atlas).iOptional;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 64n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('jOptional' in message) {
+    const payload = message.jOptional;
+    const payloadAtlas = (atlas).jOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 65n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('kOptional' in message) {
+    const payload = message.kOptional;
+    const payloadAtlas = (atlas).kOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 66n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('lOptional' in message) {
+    const payload = message.lOptional;
+    const payloadAtlas = (atlas).lOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 67n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('mOptional' in message) {
+    const payload = message.mOptional;
+    const payloadAtlas = (atlas).mOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 68n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('nOptional' in message) {
+    const payload = message.nOptional;
+    const payloadAtlas = (atlas).nOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 69n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('oOptional' in message) {
+    const payload = message.oOptional;
+    const payloadAtlas = (atlas).oOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 70n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('pOptional' in message) {
+    const payload = message.pOptional;
+    const payloadAtlas = (atlas).pOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 71n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('qOptional' in message) {
+    const payload = message.qOptional;
+    const payloadAtlas = (atlas).qOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 72n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('rOptional' in message) {
+    const payload = message.rOptional;
+    const payloadAtlas = (atlas).rOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 73n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('sOptional' in message) {
+    const payload = message.sOptional;
+    const payloadAtlas = (atlas).sOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 74n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('tOptional' in message) {
+    const payload = message.tOptional;
+    const payloadAtlas = (atlas).tOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 75n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('uOptional' in message) {
+    const payload = message.uOptional;
+    const payloadAtlas = (atlas).uOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 76n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('vOptional' in message) {
+    const payload = message.vOptional;
+    const payloadAtlas = (atlas).vOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 77n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('wOptional' in message) {
+    const payload = message.wOptional;
+    const payloadAtlas = (atlas).wOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 78n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('xOptional' in message) {
+    const payload = message.xOptional;
+    const payloadAtlas = (atlas).xOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 79n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('yOptional' in message) {
+    const payload = message.yOptional;
+    const payloadAtlas = (atlas).yOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 80n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('zOptional' in message) {
+    const payload = message.zOptional;
+    const payloadAtlas = (atlas).zOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 81n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('aaOptional' in message) {
+    const payload = message.aaOptional;
+    const payloadAtlas = (atlas).aaOptional;
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 82n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function barDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        let payload = null;
+        return {
+          $field: 'aRequired',
+          aRequired: payload,
+        };
+      }
+      case 1n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        return {
+          $field: 'bRequired',
+          bRequired: payload,
+        };
+      }
+      case 2n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        return {
+          $field: 'cRequired',
+          cRequired: payload,
+        };
+      }
+      case 3n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        return {
+          $field: 'dRequired',
+          dRequired: payload,
+        };
+      }
+      case 4n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'eRequired',
+          eRequired: payload,
+        };
+      }
+      case 5n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        return {
+          $field: 'fRequired',
+          fRequired: payload,
+        };
+      }
+      case 6n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'gRequired',
+          gRequired: payload,
+        };
+      }
+      case 7n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'hRequired',
+          hRequired: payload,
+        };
+      }
+      case 8n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'iRequired',
+          iRequired: payload,
+        };
+      }
+      case 9n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'jRequired',
+          jRequired: payload,
+        };
+      }
+      case 10n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'kRequired',
+          kRequired: payload,
+        };
+      }
+      case 11n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'lRequired',
+          lRequired: payload,
+        };
+      }
+      case 12n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'mRequired',
+          mRequired: payload,
+        };
+      }
+      case 13n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'nRequired',
+          nRequired: payload,
+        };
+      }
+      case 14n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'oRequired',
+          oRequired: payload,
+        };
+      }
+      case 15n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'pRequired',
+          pRequired: payload,
+        };
+      }
+      case 16n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'qRequired',
+          qRequired: payload,
+        };
+      }
+      case 17n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'rRequired',
+          rRequired: payload,
+        };
+      }
+      case 18n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'sRequired',
+          sRequired: payload,
+        };
+      }
+      case 19n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'tRequired',
+          tRequired: payload,
+        };
+      }
+      case 20n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'uRequired',
+          uRequired: payload,
+        };
+      }
+      case 21n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'vRequired',
+          vRequired: payload,
+        };
+      }
+      case 22n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'wRequired',
+          wRequired: payload,
+        };
+      }
+      case 23n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'xRequired',
+          xRequired: payload,
+        };
+      }
+      case 24n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'yRequired',
+          yRequired: payload,
+        };
+      }
+      case 25n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'zRequired',
+          zRequired: payload,
+        };
+      }
+      case 26n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'aaRequired',
+          aaRequired: payload,
+        };
+      }
+      case 28n: {
+        let payload = null;
+        return {
+          $field: 'aAsymmetric',
+          aAsymmetric: payload,
+        };
+      }
+      case 29n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        return {
+          $field: 'bAsymmetric',
+          bAsymmetric: payload,
+        };
+      }
+      case 30n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        return {
+          $field: 'cAsymmetric',
+          cAsymmetric: payload,
+        };
+      }
+      case 31n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        return {
+          $field: 'dAsymmetric',
+          dAsymmetric: payload,
+        };
+      }
+      case 32n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'eAsymmetric',
+          eAsymmetric: payload,
+        };
+      }
+      case 33n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        return {
+          $field: 'fAsymmetric',
+          fAsymmetric: payload,
+        };
+      }
+      case 34n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'gAsymmetric',
+          gAsymmetric: payload,
+        };
+      }
+      case 35n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'hAsymmetric',
+          hAsymmetric: payload,
+        };
+      }
+      case 36n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'iAsymmetric',
+          iAsymmetric: payload,
+        };
+      }
+      case 37n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'jAsymmetric',
+          jAsymmetric: payload,
+        };
+      }
+      case 38n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'kAsymmetric',
+          kAsymmetric: payload,
+        };
+      }
+      case 39n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'lAsymmetric',
+          lAsymmetric: payload,
+        };
+      }
+      case 40n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'mAsymmetric',
+          mAsymmetric: payload,
+        };
+      }
+      case 41n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'nAsymmetric',
+          nAsymmetric: payload,
+        };
+      }
+      case 42n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'oAsymmetric',
+          oAsymmetric: payload,
+        };
+      }
+      case 43n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'pAsymmetric',
+          pAsymmetric: payload,
+        };
+      }
+      case 44n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'qAsymmetric',
+          qAsymmetric: payload,
+        };
+      }
+      case 45n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'rAsymmetric',
+          rAsymmetric: payload,
+        };
+      }
+      case 46n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'sAsymmetric',
+          sAsymmetric: payload,
+        };
+      }
+      case 47n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'tAsymmetric',
+          tAsymmetric: payload,
+        };
+      }
+      case 48n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'uAsymmetric',
+          uAsymmetric: payload,
+        };
+      }
+      case 49n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'vAsymmetric',
+          vAsymmetric: payload,
+        };
+      }
+      case 50n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'wAsymmetric',
+          wAsymmetric: payload,
+        };
+      }
+      case 51n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'xAsymmetric',
+          xAsymmetric: payload,
+        };
+      }
+      case 52n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'yAsymmetric',
+          yAsymmetric: payload,
+        };
+      }
+      case 53n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'zAsymmetric',
+          zAsymmetric: payload,
+        };
+      }
+      case 54n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'aaAsymmetric',
+          aaAsymmetric: payload,
+        };
+      }
+      case 56n: {
+        let payload = null;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'aOptional',
+          aOptional: payload,
+          $fallback,
+        };
+      }
+      case 57n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'bOptional',
+          bOptional: payload,
+          $fallback,
+        };
+      }
+      case 58n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'cOptional',
+          cOptional: payload,
+          $fallback,
+        };
+      }
+      case 59n: {
+        let payload;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'dOptional',
+          dOptional: payload,
+          $fallback,
+        };
+      }
+      case 60n: {
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'eOptional',
+          eOptional: payload,
+          $fallback,
+        };
+      }
+      case 61n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'fOptional',
+          fOptional: payload,
+          $fallback,
+        };
+      }
+      case 62n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'gOptional',
+          gOptional: payload,
+          $fallback,
+        };
+      }
+      case 63n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'hOptional',
+          hOptional: payload,
+          $fallback,
+        };
+      }
+      case 64n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'iOptional',
+          iOptional: payload,
+          $fallback,
+        };
+      }
+      case 65n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload;
+        {
+          let newPayload;
+          {
+            let payload;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null);
+          }
+          payload = newPayload;
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'jOptional',
+          jOptional: payload,
+          $fallback,
+        };
+      }
+      case 66n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'kOptional',
+          kOptional: payload,
+          $fallback,
+        };
+      }
+      case 67n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'lOptional',
+          lOptional: payload,
+          $fallback,
+        };
+      }
+      case 68n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'mOptional',
+          mOptional: payload,
+          $fallback,
+        };
+      }
+      case 69n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload;
+                {
+                  let newPayload;
+                  {
+                    let payload;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'nOptional',
+          nOptional: payload,
+          $fallback,
+        };
+      }
+      case 70n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'oOptional',
+          oOptional: payload,
+          $fallback,
+        };
+      }
+      case 71n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'pOptional',
+          pOptional: payload,
+          $fallback,
+        };
+      }
+      case 72n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'qOptional',
+          qOptional: payload,
+          $fallback,
+        };
+      }
+      case 73n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'rOptional',
+          rOptional: payload,
+          $fallback,
+        };
+      }
+      case 74n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload;
+              {
+                let newPayload;
+                {
+                  let payload;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null);
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'sOptional',
+          sOptional: payload,
+          $fallback,
+        };
+      }
+      case 75n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'tOptional',
+          tOptional: payload,
+          $fallback,
+        };
+      }
+      case 76n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'uOptional',
+          uOptional: payload,
+          $fallback,
+        };
+      }
+      case 77n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'vOptional',
+          vOptional: payload,
+          $fallback,
+        };
+      }
+      case 78n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload;
+                      {
+                        let newPayload;
+                        {
+                          let payload;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'wOptional',
+          wOptional: payload,
+          $fallback,
+        };
+      }
+      case 79n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'xOptional',
+          xOptional: payload,
+          $fallback,
+        };
+      }
+      case 80n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'yOptional',
+          yOptional: payload,
+          $fallback,
+        };
+      }
+      case 81n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'zOptional',
+          zOptional: payload,
+          $fallback,
+        };
+      }
+      case 82n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'aaOptional',
+          aaOptional: payload,
+          $fallback,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function barSerialize(message) {
+  const atlasValue = barAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  barSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function barDeserialize(bytes) {
+  try {
+    return barDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function barCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  if ('aRequired' in message) {
+    if ('$field' in message && message.$field !== 'aRequired') {
+      return '.$field must be "aRequired"';
+    }
+    const payload = message.aRequired;
+    if (payload !== null) {
+      return '.aRequired must be null';
+    }
+    return undefined;
+  }
+
+  if ('bRequired' in message) {
+    if ('$field' in message && message.$field !== 'bRequired') {
+      return '.$field must be "bRequired"';
+    }
+    const payload = message.bRequired;
+    if (typeof payload !== 'number') {
+      return '.bRequired must be a number';
+    }
+    return undefined;
+  }
+
+  if ('cRequired' in message) {
+    if ('$field' in message && message.$field !== 'cRequired') {
+      return '.$field must be "cRequired"';
+    }
+    const payload = message.cRequired;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cRequired must be a bigint from 0 to 18446744073709551615';
+    }
+    return undefined;
+  }
+
+  if ('dRequired' in message) {
+    if ('$field' in message && message.$field !== 'dRequired') {
+      return '.$field must be "dRequired"';
+    }
+    const payload = message.dRequired;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dRequired must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    return undefined;
+  }
+
+  if ('eRequired' in message) {
+    if ('$field' in message && message.$field !== 'eRequired') {
+      return '.$field must be "eRequired"';
+    }
+    const payload = message.eRequired;
+    if (typeof payload !== 'boolean') {
+      return '.eRequired must be a boolean';
+    }
+    return undefined;
+  }
+
+  if ('fRequired' in message) {
+    if ('$field' in message && message.$field !== 'fRequired') {
+      return '.$field must be "fRequired"';
+    }
+    const payload = message.fRequired;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fRequired must be an ArrayBuffer';
+    }
+    return undefined;
+  }
+
+  if ('gRequired' in message) {
+    if ('$field' in message && message.$field !== 'gRequired') {
+      return '.$field must be "gRequired"';
+    }
+    const payload = message.gRequired;
+    if (typeof payload !== 'string') {
+      return '.gRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('hRequired' in message) {
+    if ('$field' in message && message.$field !== 'hRequired') {
+      return '.$field must be "hRequired"';
+    }
+    const payload = message.hRequired;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hRequired' + error;
+    }
+    return undefined;
+  }
+
+  if ('iRequired' in message) {
+    if ('$field' in message && message.$field !== 'iRequired') {
+      return '.$field must be "iRequired"';
+    }
+    const payload = message.iRequired;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iRequired' + error;
+    }
+    return undefined;
+  }
+
+  if ('jRequired' in message) {
+    if ('$field' in message && message.$field !== 'jRequired') {
+      return '.$field must be "jRequired"';
+    }
+    const payload = message.jRequired;
+    if (!Array.isArray(payload)) {
+      return '.jRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jRequired[' + i0 + '] must be null';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('kRequired' in message) {
+    if ('$field' in message && message.$field !== 'kRequired') {
+      return '.$field must be "kRequired"';
+    }
+    const payload = message.kRequired;
+    if (!Array.isArray(payload)) {
+      return '.kRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kRequired[' + i0 + '] must be a number';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('lRequired' in message) {
+    if ('$field' in message && message.$field !== 'lRequired') {
+      return '.$field must be "lRequired"';
+    }
+    const payload = message.lRequired;
+    if (!Array.isArray(payload)) {
+      return '.lRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lRequired[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('mRequired' in message) {
+    if ('$field' in message && message.$field !== 'mRequired') {
+      return '.$field must be "mRequired"';
+    }
+    const payload = message.mRequired;
+    if (!Array.isArray(payload)) {
+      return '.mRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mRequired[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('nRequired' in message) {
+    if ('$field' in message && message.$field !== 'nRequired') {
+      return '.$field must be "nRequired"';
+    }
+    const payload = message.nRequired;
+    if (!Array.isArray(payload)) {
+      return '.nRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nRequired[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('oRequired' in message) {
+    if ('$field' in message && message.$field !== 'oRequired') {
+      return '.$field must be "oRequired"';
+    }
+    const payload = message.oRequired;
+    if (!Array.isArray(payload)) {
+      return '.oRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oRequired[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('pRequired' in message) {
+    if ('$field' in message && message.$field !== 'pRequired') {
+      return '.$field must be "pRequired"';
+    }
+    const payload = message.pRequired;
+    if (!Array.isArray(payload)) {
+      return '.pRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pRequired[' + i0 + '] must be a string';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('qRequired' in message) {
+    if ('$field' in message && message.$field !== 'qRequired') {
+      return '.$field must be "qRequired"';
+    }
+    const payload = message.qRequired;
+    if (!Array.isArray(payload)) {
+      return '.qRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('rRequired' in message) {
+    if ('$field' in message && message.$field !== 'rRequired') {
+      return '.$field must be "rRequired"';
+    }
+    const payload = message.rRequired;
+    if (!Array.isArray(payload)) {
+      return '.rRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('sRequired' in message) {
+    if ('$field' in message && message.$field !== 'sRequired') {
+      return '.$field must be "sRequired"';
+    }
+    const payload = message.sRequired;
+    if (!Array.isArray(payload)) {
+      return '.sRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sRequired[' + i0 + '][' + i1 + '] must be null';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('tRequired' in message) {
+    if ('$field' in message && message.$field !== 'tRequired') {
+      return '.$field must be "tRequired"';
+    }
+    const payload = message.tRequired;
+    if (!Array.isArray(payload)) {
+      return '.tRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tRequired[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('uRequired' in message) {
+    if ('$field' in message && message.$field !== 'uRequired') {
+      return '.$field must be "uRequired"';
+    }
+    const payload = message.uRequired;
+    if (!Array.isArray(payload)) {
+      return '.uRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uRequired[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('vRequired' in message) {
+    if ('$field' in message && message.$field !== 'vRequired') {
+      return '.$field must be "vRequired"';
+    }
+    const payload = message.vRequired;
+    if (!Array.isArray(payload)) {
+      return '.vRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vRequired[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('wRequired' in message) {
+    if ('$field' in message && message.$field !== 'wRequired') {
+      return '.$field must be "wRequired"';
+    }
+    const payload = message.wRequired;
+    if (!Array.isArray(payload)) {
+      return '.wRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wRequired[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('xRequired' in message) {
+    if ('$field' in message && message.$field !== 'xRequired') {
+      return '.$field must be "xRequired"';
+    }
+    const payload = message.xRequired;
+    if (!Array.isArray(payload)) {
+      return '.xRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xRequired[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('yRequired' in message) {
+    if ('$field' in message && message.$field !== 'yRequired') {
+      return '.$field must be "yRequired"';
+    }
+    const payload = message.yRequired;
+    if (!Array.isArray(payload)) {
+      return '.yRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yRequired[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('zRequired' in message) {
+    if ('$field' in message && message.$field !== 'zRequired') {
+      return '.$field must be "zRequired"';
+    }
+    const payload = message.zRequired;
+    if (!Array.isArray(payload)) {
+      return '.zRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zRequired[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('aaRequired' in message) {
+    if ('$field' in message && message.$field !== 'aaRequired') {
+      return '.$field must be "aaRequired"';
+    }
+    const payload = message.aaRequired;
+    if (!Array.isArray(payload)) {
+      return '.aaRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaRequired[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('aAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'aAsymmetric') {
+      return '.$field must be "aAsymmetric"';
+    }
+    const payload = message.aAsymmetric;
+    if (payload !== null) {
+      return '.aAsymmetric must be null';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('bAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'bAsymmetric') {
+      return '.$field must be "bAsymmetric"';
+    }
+    const payload = message.bAsymmetric;
+    if (typeof payload !== 'number') {
+      return '.bAsymmetric must be a number';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('cAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'cAsymmetric') {
+      return '.$field must be "cAsymmetric"';
+    }
+    const payload = message.cAsymmetric;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cAsymmetric must be a bigint from 0 to 18446744073709551615';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('dAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'dAsymmetric') {
+      return '.$field must be "dAsymmetric"';
+    }
+    const payload = message.dAsymmetric;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dAsymmetric must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('eAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'eAsymmetric') {
+      return '.$field must be "eAsymmetric"';
+    }
+    const payload = message.eAsymmetric;
+    if (typeof payload !== 'boolean') {
+      return '.eAsymmetric must be a boolean';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('fAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'fAsymmetric') {
+      return '.$field must be "fAsymmetric"';
+    }
+    const payload = message.fAsymmetric;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fAsymmetric must be an ArrayBuffer';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('gAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'gAsymmetric') {
+      return '.$field must be "gAsymmetric"';
+    }
+    const payload = message.gAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.gAsymmetric must be a string';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('hAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'hAsymmetric') {
+      return '.$field must be "hAsymmetric"';
+    }
+    const payload = message.hAsymmetric;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hAsymmetric' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('iAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'iAsymmetric') {
+      return '.$field must be "iAsymmetric"';
+    }
+    const payload = message.iAsymmetric;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iAsymmetric' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('jAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'jAsymmetric') {
+      return '.$field must be "jAsymmetric"';
+    }
+    const payload = message.jAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.jAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jAsymmetric[' + i0 + '] must be null';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('kAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'kAsymmetric') {
+      return '.$field must be "kAsymmetric"';
+    }
+    const payload = message.kAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.kAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kAsymmetric[' + i0 + '] must be a number';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('lAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'lAsymmetric') {
+      return '.$field must be "lAsymmetric"';
+    }
+    const payload = message.lAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.lAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lAsymmetric[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('mAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'mAsymmetric') {
+      return '.$field must be "mAsymmetric"';
+    }
+    const payload = message.mAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.mAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mAsymmetric[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'nAsymmetric') {
+      return '.$field must be "nAsymmetric"';
+    }
+    const payload = message.nAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.nAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nAsymmetric[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('oAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'oAsymmetric') {
+      return '.$field must be "oAsymmetric"';
+    }
+    const payload = message.oAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.oAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oAsymmetric[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('pAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'pAsymmetric') {
+      return '.$field must be "pAsymmetric"';
+    }
+    const payload = message.pAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.pAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pAsymmetric[' + i0 + '] must be a string';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('qAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'qAsymmetric') {
+      return '.$field must be "qAsymmetric"';
+    }
+    const payload = message.qAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.qAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('rAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'rAsymmetric') {
+      return '.$field must be "rAsymmetric"';
+    }
+    const payload = message.rAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.rAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('sAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'sAsymmetric') {
+      return '.$field must be "sAsymmetric"';
+    }
+    const payload = message.sAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.sAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sAsymmetric[' + i0 + '][' + i1 + '] must be null';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('tAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'tAsymmetric') {
+      return '.$field must be "tAsymmetric"';
+    }
+    const payload = message.tAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.tAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tAsymmetric[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('uAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'uAsymmetric') {
+      return '.$field must be "uAsymmetric"';
+    }
+    const payload = message.uAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.uAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('vAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'vAsymmetric') {
+      return '.$field must be "vAsymmetric"';
+    }
+    const payload = message.vAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.vAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('wAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'wAsymmetric') {
+      return '.$field must be "wAsymmetric"';
+    }
+    const payload = message.wAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.wAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wAsymmetric[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('xAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'xAsymmetric') {
+      return '.$field must be "xAsymmetric"';
+    }
+    const payload = message.xAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.xAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xAsymmetric[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('yAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'yAsymmetric') {
+      return '.$field must be "yAsymmetric"';
+    }
+    const payload = message.yAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.yAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yAsymmetric[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('zAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'zAsymmetric') {
+      return '.$field must be "zAsymmetric"';
+    }
+    const payload = message.zAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.zAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aaAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'aaAsymmetric') {
+      return '.$field must be "aaAsymmetric"';
+    }
+    const payload = message.aaAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.aaAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aOptional' in message) {
+    if ('$field' in message && message.$field !== 'aOptional') {
+      return '.$field must be "aOptional"';
+    }
+    const payload = message.aOptional;
+    if (payload !== null) {
+      return '.aOptional must be null';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('bOptional' in message) {
+    if ('$field' in message && message.$field !== 'bOptional') {
+      return '.$field must be "bOptional"';
+    }
+    const payload = message.bOptional;
+    if (typeof payload !== 'number') {
+      return '.bOptional must be a number';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('cOptional' in message) {
+    if ('$field' in message && message.$field !== 'cOptional') {
+      return '.$field must be "cOptional"';
+    }
+    const payload = message.cOptional;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cOptional must be a bigint from 0 to 18446744073709551615';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('dOptional' in message) {
+    if ('$field' in message && message.$field !== 'dOptional') {
+      return '.$field must be "dOptional"';
+    }
+    const payload = message.dOptional;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dOptional must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('eOptional' in message) {
+    if ('$field' in message && message.$field !== 'eOptional') {
+      return '.$field must be "eOptional"';
+    }
+    const payload = message.eOptional;
+    if (typeof payload !== 'boolean') {
+      return '.eOptional must be a boolean';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('fOptional' in message) {
+    if ('$field' in message && message.$field !== 'fOptional') {
+      return '.$field must be "fOptional"';
+    }
+    const payload = message.fOptional;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fOptional must be an ArrayBuffer';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('gOptional' in message) {
+    if ('$field' in message && message.$field !== 'gOptional') {
+      return '.$field must be "gOptional"';
+    }
+    const payload = message.gOptional;
+    if (typeof payload !== 'string') {
+      return '.gOptional must be a string';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('hOptional' in message) {
+    if ('$field' in message && message.$field !== 'hOptional') {
+      return '.$field must be "hOptional"';
+    }
+    const payload = message.hOptional;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hOptional' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('iOptional' in message) {
+    if ('$field' in message && message.$field !== 'iOptional') {
+      return '.$field must be "iOptional"';
+    }
+    const payload = message.iOptional;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iOptional' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('jOptional' in message) {
+    if ('$field' in message && message.$field !== 'jOptional') {
+      return '.$field must be "jOptional"';
+    }
+    const payload = message.jOptional;
+    if (!Array.isArray(payload)) {
+      return '.jOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jOptional[' + i0 + '] must be null';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('kOptional' in message) {
+    if ('$field' in message && message.$field !== 'kOptional') {
+      return '.$field must be "kOptional"';
+    }
+    const payload = message.kOptional;
+    if (!Array.isArray(payload)) {
+      return '.kOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kOptional[' + i0 + '] must be a number';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('lOptional' in message) {
+    if ('$field' in message && message.$field !== 'lOptional') {
+      return '.$field must be "lOptional"';
+    }
+    const payload = message.lOptional;
+    if (!Array.isArray(payload)) {
+      return '.lOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lOptional[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('mOptional' in message) {
+    if ('$field' in message && message.$field !== 'mOptional') {
+      return '.$field must be "mOptional"';
+    }
+    const payload = message.mOptional;
+    if (!Array.isArray(payload)) {
+      return '.mOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mOptional[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nOptional' in message) {
+    if ('$field' in message && message.$field !== 'nOptional') {
+      return '.$field must be "nOptional"';
+    }
+    const payload = message.nOptional;
+    if (!Array.isArray(payload)) {
+      return '.nOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nOptional[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('oOptional' in message) {
+    if ('$field' in message && message.$field !== 'oOptional') {
+      return '.$field must be "oOptional"';
+    }
+    const payload = message.oOptional;
+    if (!Array.isArray(payload)) {
+      return '.oOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oOptional[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('pOptional' in message) {
+    if ('$field' in message && message.$field !== 'pOptional') {
+      return '.$field must be "pOptional"';
+    }
+    const payload = message.pOptional;
+    if (!Array.isArray(payload)) {
+      return '.pOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pOptional[' + i0 + '] must be a string';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('qOptional' in message) {
+    if ('$field' in message && message.$field !== 'qOptional') {
+      return '.$field must be "qOptional"';
+    }
+    const payload = message.qOptional;
+    if (!Array.isArray(payload)) {
+      return '.qOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qOptional[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('rOptional' in message) {
+    if ('$field' in message && message.$field !== 'rOptional') {
+      return '.$field must be "rOptional"';
+    }
+    const payload = message.rOptional;
+    if (!Array.isArray(payload)) {
+      return '.rOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rOptional[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('sOptional' in message) {
+    if ('$field' in message && message.$field !== 'sOptional') {
+      return '.$field must be "sOptional"';
+    }
+    const payload = message.sOptional;
+    if (!Array.isArray(payload)) {
+      return '.sOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sOptional[' + i0 + '][' + i1 + '] must be null';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('tOptional' in message) {
+    if ('$field' in message && message.$field !== 'tOptional') {
+      return '.$field must be "tOptional"';
+    }
+    const payload = message.tOptional;
+    if (!Array.isArray(payload)) {
+      return '.tOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tOptional[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('uOptional' in message) {
+    if ('$field' in message && message.$field !== 'uOptional') {
+      return '.$field must be "uOptional"';
+    }
+    const payload = message.uOptional;
+    if (!Array.isArray(payload)) {
+      return '.uOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uOptional[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('vOptional' in message) {
+    if ('$field' in message && message.$field !== 'vOptional') {
+      return '.$field must be "vOptional"';
+    }
+    const payload = message.vOptional;
+    if (!Array.isArray(payload)) {
+      return '.vOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vOptional[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('wOptional' in message) {
+    if ('$field' in message && message.$field !== 'wOptional') {
+      return '.$field must be "wOptional"';
+    }
+    const payload = message.wOptional;
+    if (!Array.isArray(payload)) {
+      return '.wOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wOptional[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('xOptional' in message) {
+    if ('$field' in message && message.$field !== 'xOptional') {
+      return '.$field must be "xOptional"';
+    }
+    const payload = message.xOptional;
+    if (!Array.isArray(payload)) {
+      return '.xOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xOptional[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('yOptional' in message) {
+    if ('$field' in message && message.$field !== 'yOptional') {
+      return '.$field must be "yOptional"';
+    }
+    const payload = message.yOptional;
+    if (!Array.isArray(payload)) {
+      return '.yOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yOptional[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('zOptional' in message) {
+    if ('$field' in message && message.$field !== 'zOptional') {
+      return '.$field must be "zOptional"';
+    }
+    const payload = message.zOptional;
+    if (!Array.isArray(payload)) {
+      return '.zOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zOptional[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aaOptional' in message) {
+    if ('$field' in message && message.$field !== 'aaOptional') {
+      return '.$field must be "aaOptional"';
+    }
+    const payload = message.aaOptional;
+    if (!Array.isArray(payload)) {
+      return '.aaOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaOptional[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields aRequired, bRequired, cRequired, dRequired, eRequired, fRequired, gRequired, hRequired, iRequired, jRequired, kRequired, lRequired, mRequired, nRequired, oRequired, pRequired, qRequired, rRequired, sRequired, tRequired, uRequired, vRequired, wRequired, xRequired, yRequired, zRequired, aaRequired, aAsymmetric, bAsymmetric, cAsymmetric, dAsymmetric, eAsymmetric, fAsymmetric, gAsymmetric, hAsymmetric, iAsymmetric, jAsymmetric, kAsymmetric, lAsymmetric, mAsymmetric, nAsymmetric, oAsymmetric, pAsymmetric, qAsymmetric, rAsymmetric, sAsymmetric, tAsymmetric, uAsymmetric, vAsymmetric, wAsymmetric, xAsymmetric, yAsymmetric, zAsymmetric, aaAsymmetric, aOptional, bOptional, cOptional, dOptional, eOptional, fOptional, gOptional, hOptional, iOptional, jOptional, kOptional, lOptional, mOptional, nOptional, oOptional, pOptional, qOptional, rOptional, sOptional, tOptional, uOptional, vOptional, wOptional, xOptional, yOptional, zOptional, aaOptional';
+}
+
+export const Bar = {
+  atlas: barAtlas,
+  size: barSize,
+  serializeWithAtlasUnsafe: barSerializeWithAtlasUnsafe,
+  deserializeUnsafe: barDeserializeUnsafe,
+  serialize: barSerialize,
+  deserialize: barDeserialize,
+  checkOut: barCheckOut,
+};
+
+export function isBarOut(value) {
+  return barCheckOut(value) === undefined;
+}
+
+export function assertBarOut(value) {
+  const error = barCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid BarOut: value' + error + '.');
+  }
+}
@@ -0,0 +1,107 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+export type EmptyStructAtlas = {
+  $size: number;
+};
+
+export type EmptyStructOut = {
+};
+
+export type EmptyStructIn = {
+};
+
+declare function emptyStructAtlas(message: EmptyStructOut): EmptyStructAtlas;
+
+declare function emptyStructSize(message: EmptyStructOut): number;
+
+declare function emptyStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyStructOut,
+  atlas: EmptyStructAtlas,
+): number;
+
+declare function emptyStructDeserializeUnsafe(dataView: DataView): EmptyStructIn;
+
+declare function emptyStructSerialize(message: EmptyStructOut): ArrayBuffer;
+
+declare function emptyStructDeserialize(bytes: Deserializable): EmptyStructIn | Error;
+
+declare function emptyStructCheckOut(value: unknown): string | undefined;
+
+export declare const EmptyStruct: {
+  atlas: typeof emptyStructAtlas;
+  size: typeof emptyStructSize;
+  serializeWithAtlasUnsafe: typeof emptyStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof emptyStructDeserializeUnsafe;
+  serialize: typeof emptyStructSerialize;
+  deserialize: typeof emptyStructDeserialize;
+  checkOut: typeof emptyStructCheckOut;
+};
+
+export declare function isEmptyStructOut(value: unknown): value is EmptyStructOut;
+
+export declare function assertEmptyStructOut(value: unknown): asserts value is EmptyStructOut;
+
+export type EmptyChoiceAtlas = never;
+
+export type EmptyChoiceOut = never;
+
+export type EmptyChoiceIn = never;
+
+declare function emptyChoiceAtlas(message: EmptyChoiceOut): EmptyChoiceAtlas;
+
+declare function emptyChoiceSize(message: EmptyChoiceOut): number;
+
+declare function emptyChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyChoiceOut,
+  atlas: EmptyChoiceAtlas,
+): number;
+
+declare function emptyChoiceDeserializeUnsafe(dataView: DataView): EmptyChoiceIn;
+
+declare function emptyChoiceSerialize(message: EmptyChoiceOut): ArrayBuffer;
+
+declare function emptyChoiceDeserialize(bytes: Deserializable): EmptyChoiceIn | Error;
+
+declare function emptyChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const EmptyChoice: {
+  atlas: typeof emptyChoiceAtlas;
+  size: typeof emptyChoiceSize;
+  serializeWithAtlasUnsafe: typeof emptyChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof emptyChoiceDeserializeUnsafe;
+  serialize: typeof emptyChoiceSerialize;
+  deserialize: typeof emptyChoiceDeserialize;
+  checkOut: typeof emptyChoiceCheckOut;
+};
+
+export declare function isEmptyChoiceOut(value: unknown): value is EmptyChoiceOut;
+
+export declare function assertEmptyChoiceOut(value: unknown): asserts value is EmptyChoiceOut;
@@ -0,0 +1,195 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+function emptyStructAtlas(message) {
+  let size = 0;
+
+  return {
+    $size: size,
+  };
+}
+
+function emptyStructSize(message) {
+  return 0;
+}
+
+function emptyStructSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  return offset;
+}
+
+function emptyStructDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  return {
+  };
+}
+
+function emptyStructSerialize(message) {
+  const atlasValue = emptyStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  emptyStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function emptyStructDeserialize(bytes) {
+  try {
+    return emptyStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function emptyStructCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return undefined;
+}
+
+export const EmptyStruct = {
+  atlas: emptyStructAtlas,
+  size: emptyStructSize,
+  serializeWithAtlasUnsafe: emptyStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: emptyStructDeserializeUnsafe,
+  serialize: emptyStructSerialize,
+  deserialize: emptyStructDeserialize,
+  checkOut: emptyStructCheckOut,
+};
+
+export function isEmptyStructOut(value) {
+  return emptyStructCheckOut(value) === undefined;
+}
+
+export function assertEmptyStructOut(value) {
+  const error = emptyStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyStructOut: value' + error + '.');
+  }
+}
+
+function emptyChoiceAtlas(message) {
+  return unreachable(message);
+}
+
+function emptyChoiceSize(message) {
+  return 0;
+}
+
+function emptyChoiceSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  return unreachable(message);
+}
+
+function emptyChoiceDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function emptyChoiceSerialize(message) {
+  return unreachable(message);
+}
+
+function emptyChoiceDeserialize(bytes) {
+  try {
+    return emptyChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function emptyChoiceCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return ' must not exist, since the choice has no fields';
+}
+
+export const EmptyChoice = {
+  atlas: emptyChoiceAtlas,
+  size: emptyChoiceSize,
+  serializeWithAtlasUnsafe: emptyChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: emptyChoiceDeserializeUnsafe,
+  serialize: emptyChoiceSerialize,
+  deserialize: emptyChoiceDeserialize,
+  checkOut: emptyChoiceCheckOut,
+};
+
+export function isEmptyChoiceOut(value) {
+  return emptyChoiceCheckOut(value) === undefined;
+}
+
+export function assertEmptyChoiceOut(value) {
+  const error = emptyChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyChoiceOut: value' + error + '.');
+  }
+}
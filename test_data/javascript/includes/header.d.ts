@@ -0,0 +1,125 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+export type TenantAtlas = {
+  $size: number;
+  name: Uint8Array;
+};
+
+export type TenantOut = {
+  name: string;
+};
+
+export type TenantIn = {
+  name: string;
+};
+
+declare function tenantAtlas(message: TenantOut): TenantAtlas;
+
+declare function tenantSize(message: TenantOut): number;
+
+declare function tenantSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: TenantOut,
+  atlas: TenantAtlas,
+): number;
+
+declare function tenantDeserializeUnsafe(dataView: DataView): TenantIn;
+
+declare function tenantSerialize(message: TenantOut): ArrayBuffer;
+
+declare function tenantDeserialize(bytes: Deserializable): TenantIn | Error;
+
+declare function tenantCheckOut(value: unknown): string | undefined;
+
+export declare const Tenant: {
+  atlas: typeof tenantAtlas;
+  size: typeof tenantSize;
+  serializeWithAtlasUnsafe: typeof tenantSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof tenantDeserializeUnsafe;
+  serialize: typeof tenantSerialize;
+  deserialize: typeof tenantDeserialize;
+  checkOut: typeof tenantCheckOut;
+};
+
+export declare function isTenantOut(value: unknown): value is TenantOut;
+
+export declare function assertTenantOut(value: unknown): asserts value is TenantOut;
+
+export type HeaderAtlas = {
+  $size: number;
+  requestId: Uint8Array;
+  tenant: TenantAtlas;
+  marker: _Types.EmptyStructAtlas | undefined;
+};
+
+export type HeaderOut = {
+  requestId: string;
+  tenant: TenantOut;
+  marker: _Types.EmptyStructOut | undefined;
+};
+
+export type HeaderIn = {
+  requestId: string;
+  tenant: TenantIn;
+  marker: _Types.EmptyStructIn | undefined;
+};
+
+declare function headerAtlas(message: HeaderOut): HeaderAtlas;
+
+declare function headerSize(message: HeaderOut): number;
+
+declare function headerSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: HeaderOut,
+  atlas: HeaderAtlas,
+): number;
+
+declare function headerDeserializeUnsafe(dataView: DataView): HeaderIn;
+
+declare function headerSerialize(message: HeaderOut): ArrayBuffer;
+
+declare function headerDeserialize(bytes: Deserializable): HeaderIn | Error;
+
+declare function headerCheckOut(value: unknown): string | undefined;
+
+export declare const Header: {
+  atlas: typeof headerAtlas;
+  size: typeof headerSize;
+  serializeWithAtlasUnsafe: typeof headerSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof headerDeserializeUnsafe;
+  serialize: typeof headerSerialize;
+  deserialize: typeof headerDeserialize;
+  checkOut: typeof headerCheckOut;
+};
+
+export declare function isHeaderOut(value: unknown): value is HeaderOut;
+
+export declare function assertHeaderOut(value: unknown): asserts value is HeaderOut;
@@ -0,0 +1,427 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+function tenantAtlas(message) {
+  let size = 0;
+
+  let $name;
+
+  {
+    let payloadAtlas;
+    const payload = message.name;
+    payloadAtlas = textEncoder.encode(payload);
+    $name = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    name: $name,
+  };
+}
+
+function tenantSize(message) {
+  return tenantAtlas(message).$size;
+}
+
+function tenantSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.name;
+    const payloadAtlas = atlas.name;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function tenantDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $name;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $name = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($name === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    name: $name,
+  };
+}
+
+function tenantSerialize(message) {
+  const atlasValue = tenantAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  tenantSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function tenantDeserialize(bytes) {
+  try {
+    return tenantDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function tenantCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.name;
+    if (typeof payload !== 'string') {
+      return '.name must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const Tenant = {
+  atlas: tenantAtlas,
+  size: tenantSize,
+  serializeWithAtlasUnsafe: tenantSerializeWithAtlasUnsafe,
+  deserializeUnsafe: tenantDeserializeUnsafe,
+  serialize: tenantSerialize,
+  deserialize: tenantDeserialize,
+  checkOut: tenantCheckOut,
+};
+
+export function isTenantOut(value) {
+  return tenantCheckOut(value) === undefined;
+}
+
+export function assertTenantOut(value) {
+  const error = tenantCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid TenantOut: value' + error + '.');
+  }
+}
+
+function headerAtlas(message) {
+  let size = 0;
+
+  let $requestId;
+  let $tenant;
+  let $marker;
+
+  {
+    let payloadAtlas;
+    const payload = message.requestId;
+    payloadAtlas = textEncoder.encode(payload);
+    $requestId = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.tenant;
+    payloadAtlas = Tenant.atlas(payload);
+    $tenant = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.marker;
+    if (payload !== undefined) {
+      payloadAtlas = _Types.EmptyStruct.atlas(payload);
+      $marker = payloadAtlas;
+      const payloadSize = (payloadAtlas).$size;
+      size += fieldHeaderSize(3n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  return {
+    $size: size,
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+  };
+}
+
+function headerSize(message) {
+  return headerAtlas(message).$size;
+}
+
+function headerSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.requestId;
+    const payloadAtlas = atlas.requestId;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const payloadAtlas = atlas.tenant;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    offset = Tenant.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.marker;
+    const payloadAtlas = atlas.marker;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas).$size;
+      offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, false);
+      offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  return offset;
+}
+
+function headerDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $requestId;
+  let $tenant;
+  let $marker;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requestId = payload;
+        break;
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = Tenant.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $tenant = payload;
+        break;
+      }
+      case 3n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $marker = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($requestId === undefined
+    || $tenant === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+  };
+}
+
+function headerSerialize(message) {
+  const atlasValue = headerAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  headerSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function headerDeserialize(bytes) {
+  try {
+    return headerDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function headerCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.requestId;
+    if (typeof payload !== 'string') {
+      return '.requestId must be a string';
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const error = Tenant.checkOut(payload);
+    if (error !== undefined) {
+      return '.tenant' + error;
+    }
+  }
+
+  {
+    const payload = message.marker;
+    if (payload !== undefined) {
+      const error = _Types.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.marker' + error;
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const Header = {
+  atlas: headerAtlas,
+  size: headerSize,
+  serializeWithAtlasUnsafe: headerSerializeWithAtlasUnsafe,
+  deserializeUnsafe: headerDeserializeUnsafe,
+  serialize: headerSerialize,
+  deserialize: headerDeserialize,
+  checkOut: headerCheckOut,
+};
+
+export function isHeaderOut(value) {
+  return headerCheckOut(value) === undefined;
+}
+
+export function assertHeaderOut(value) {
+  const error = headerCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid HeaderOut: value' + error + '.');
+  }
+}
@@ -0,0 +1,83 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+import * as _Header from './header.js';
+import * as _Included0 from '../degenerate/types.js';
+
+export type RequestAtlas = {
+  $size: number;
+  requestId: Uint8Array;
+  tenant: _Header.TenantAtlas;
+  marker: _Included0.EmptyStructAtlas | undefined;
+  body: Uint8Array;
+};
+
+export type RequestOut = {
+  requestId: string;
+  tenant: _Header.TenantOut;
+  marker: _Included0.EmptyStructOut | undefined;
+  body: string;
+};
+
+export type RequestIn = {
+  requestId: string;
+  tenant: _Header.TenantIn;
+  marker: _Included0.EmptyStructIn | undefined;
+  body: string;
+};
+
+declare function requestAtlas(message: RequestOut): RequestAtlas;
+
+declare function requestSize(message: RequestOut): number;
+
+declare function requestSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: RequestOut,
+  atlas: RequestAtlas,
+): number;
+
+declare function requestDeserializeUnsafe(dataView: DataView): RequestIn;
+
+declare function requestSerialize(message: RequestOut): ArrayBuffer;
+
+declare function requestDeserialize(bytes: Deserializable): RequestIn | Error;
+
+declare function requestCheckOut(value: unknown): string | undefined;
+
+export declare const Request: {
+  atlas: typeof requestAtlas;
+  size: typeof requestSize;
+  serializeWithAtlasUnsafe: typeof requestSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof requestDeserializeUnsafe;
+  serialize: typeof requestSerialize;
+  deserialize: typeof requestDeserialize;
+  checkOut: typeof requestCheckOut;
+};
+
+export declare function isRequestOut(value: unknown): value is RequestOut;
+
+export declare function assertRequestOut(value: unknown): asserts value is RequestOut;
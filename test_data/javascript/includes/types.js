@@ -0,0 +1,325 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _Header from './header.js';
+import * as _Included0 from '../degenerate/types.js';
+
+function requestAtlas(message) {
+  let size = 0;
+
+  let $requestId;
+  let $tenant;
+  let $marker;
+  let $body;
+
+  {
+    let payloadAtlas;
+    const payload = message.requestId;
+    payloadAtlas = textEncoder.encode(payload);
+    $requestId = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.tenant;
+    payloadAtlas = _Header.Tenant.atlas(payload);
+    $tenant = payloadAtlas;
+    const payloadSize = (payloadAtlas).$size;
+    size += fieldHeaderSize(2n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.marker;
+    if (payload !== undefined) {
+      payloadAtlas = _Included0.EmptyStruct.atlas(payload);
+      $marker = payloadAtlas;
+      const payloadSize = (payloadAtlas).$size;
+      size += fieldHeaderSize(4n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.body;
+    payloadAtlas = textEncoder.encode(payload);
+    $body = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+    body: $body,
+  };
+}
+
+function requestSize(message) {
+  return requestAtlas(message).$size;
+}
+
+function requestSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.requestId;
+    const payloadAtlas = atlas.requestId;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const payloadAtlas = atlas.tenant;
+    const payloadSize = (payloadAtlas).$size;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+    offset = _Header.Tenant.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.marker;
+    const payloadAtlas = atlas.marker;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas).$size;
+      offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, false);
+      offset = _Included0.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  {
+    const payload = message.body;
+    const payloadAtlas = atlas.body;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function requestDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $requestId;
+  let $tenant;
+  let $marker;
+  let $body;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requestId = payload;
+        break;
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Header.Tenant.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $tenant = payload;
+        break;
+      }
+      case 4n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Included0.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $marker = payload;
+        break;
+      }
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $body = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($requestId === undefined
+    || $tenant === undefined
+    || $body === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+    body: $body,
+  };
+}
+
+function requestSerialize(message) {
+  const atlasValue = requestAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  requestSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function requestDeserialize(bytes) {
+  try {
+    return requestDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function requestCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.requestId;
+    if (typeof payload !== 'string') {
+      return '.requestId must be a string';
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const error = _Header.Tenant.checkOut(payload);
+    if (error !== undefined) {
+      return '.tenant' + error;
+    }
+  }
+
+  {
+    const payload = message.marker;
+    if (payload !== undefined) {
+      const error = _Included0.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.marker' + error;
+      }
+    }
+  }
+
+  {
+    const payload = message.body;
+    if (typeof payload !== 'string') {
+      return '.body must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const Request = {
+  atlas: requestAtlas,
+  size: requestSize,
+  serializeWithAtlasUnsafe: requestSerializeWithAtlasUnsafe,
+  deserializeUnsafe: requestDeserializeUnsafe,
+  serialize: requestSerialize,
+  deserialize: requestDeserialize,
+  checkOut: requestCheckOut,
+};
+
+export function isRequestOut(value) {
+  return requestCheckOut(value) === undefined;
+}
+
+export function assertRequestOut(value) {
+  const error = requestCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid RequestOut: value' + error + '.');
+  }
+}
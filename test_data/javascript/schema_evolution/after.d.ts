@@ -0,0 +1,173 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+export type ExampleStructAtlas = {
+  $size: number;
+  requiredToRequired: Uint8Array;
+  requiredToAsymmetric: Uint8Array;
+  requiredToOptional: Uint8Array | undefined;
+  asymmetricToRequired: Uint8Array;
+  asymmetricToAsymmetric: Uint8Array;
+  asymmetricToOptional: Uint8Array | undefined;
+  optionalToRequired: Uint8Array;
+  optionalToAsymmetric: Uint8Array;
+  optionalToOptional: Uint8Array | undefined;
+  nonexistentToAsymmetric: number;
+  nonexistentToOptional: number | undefined;
+};
+
+export type ExampleStructOut = {
+  requiredToRequired: string;
+  requiredToAsymmetric: string;
+  requiredToOptional: string | undefined;
+  asymmetricToRequired: string;
+  asymmetricToAsymmetric: string;
+  asymmetricToOptional: string | undefined;
+  optionalToRequired: string;
+  optionalToAsymmetric: string;
+  optionalToOptional: string | undefined;
+  nonexistentToAsymmetric: null;
+  nonexistentToOptional: null | undefined;
+};
+
+export type ExampleStructIn = {
+  requiredToRequired: string;
+  requiredToAsymmetric: string | undefined;
+  requiredToOptional: string | undefined;
+  asymmetricToRequired: string;
+  asymmetricToAsymmetric: string | undefined;
+  asymmetricToOptional: string | undefined;
+  optionalToRequired: string;
+  optionalToAsymmetric: string | undefined;
+  optionalToOptional: string | undefined;
+  nonexistentToAsymmetric: null | undefined;
+  nonexistentToOptional: null | undefined;
+};
+
+declare function exampleStructAtlas(message: ExampleStructOut): ExampleStructAtlas;
+
+declare function exampleStructSize(message: ExampleStructOut): number;
+
+declare function exampleStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: ExampleStructOut,
+  atlas: ExampleStructAtlas,
+): number;
+
+declare function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn;
+
+declare function exampleStructSerialize(message: ExampleStructOut): ArrayBuffer;
+
+declare function exampleStructDeserialize(bytes: Deserializable): ExampleStructIn | Error;
+
+declare function exampleStructCheckOut(value: unknown): string | undefined;
+
+export declare const ExampleStruct: {
+  atlas: typeof exampleStructAtlas;
+  size: typeof exampleStructSize;
+  serializeWithAtlasUnsafe: typeof exampleStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof exampleStructDeserializeUnsafe;
+  serialize: typeof exampleStructSerialize;
+  deserialize: typeof exampleStructDeserialize;
+  checkOut: typeof exampleStructCheckOut;
+};
+
+export declare function isExampleStructOut(value: unknown): value is ExampleStructOut;
+
+export declare function assertExampleStructOut(value: unknown): asserts value is ExampleStructOut;
+
+export type ExampleChoiceAtlas =
+  | { $size: number; requiredToRequired: Uint8Array }
+  | { $size: number; requiredToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; asymmetricToRequired: Uint8Array }
+  | { $size: number; asymmetricToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; asymmetricToOptional: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToRequired: Uint8Array }
+  | { $size: number; optionalToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToOptional: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; nonexistentToRequired: number }
+  | { $size: number; nonexistentToAsymmetric: number; $fallback: ExampleChoiceAtlas }
+  | { $size: number; nonexistentToOptional: number; $fallback: ExampleChoiceAtlas };
+
+export type ExampleChoiceOut =
+  | { requiredToRequired: string }
+  | { requiredToAsymmetric: string; $fallback: ExampleChoiceOut }
+  | { asymmetricToRequired: string }
+  | { asymmetricToAsymmetric: string; $fallback: ExampleChoiceOut }
+  | { asymmetricToOptional: string; $fallback: ExampleChoiceOut }
+  | { optionalToRequired: string }
+  | { optionalToAsymmetric: string; $fallback: ExampleChoiceOut }
+  | { optionalToOptional: string; $fallback: ExampleChoiceOut }
+  | { nonexistentToRequired: null }
+  | { nonexistentToAsymmetric: null; $fallback: ExampleChoiceOut }
+  | { nonexistentToOptional: null; $fallback: ExampleChoiceOut };
+
+export type ExampleChoiceIn =
+  | { $field: 'requiredToRequired'; requiredToRequired: string }
+  | { $field: 'requiredToAsymmetric'; requiredToAsymmetric: string }
+  | { $field: 'asymmetricToRequired'; asymmetricToRequired: string }
+  | { $field: 'asymmetricToAsymmetric'; asymmetricToAsymmetric: string }
+  | { $field: 'asymmetricToOptional'; asymmetricToOptional: string; $fallback: ExampleChoiceIn }
+  | { $field: 'optionalToRequired'; optionalToRequired: string }
+  | { $field: 'optionalToAsymmetric'; optionalToAsymmetric: string }
+  | { $field: 'optionalToOptional'; optionalToOptional: string; $fallback: ExampleChoiceIn }
+  | { $field: 'nonexistentToRequired'; nonexistentToRequired: null }
+  | { $field: 'nonexistentToAsymmetric'; nonexistentToAsymmetric: null }
+  | { $field: 'nonexistentToOptional'; nonexistentToOptional: null; $fallback: ExampleChoiceIn };
+
+declare function exampleChoiceAtlas(message: ExampleChoiceOut): ExampleChoiceAtlas;
+
+declare function exampleChoiceSize(message: ExampleChoiceOut): number;
+
+declare function exampleChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: ExampleChoiceOut,
+  atlas: ExampleChoiceAtlas,
+): number;
+
+declare function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn;
+
+declare function exampleChoiceSerialize(message: ExampleChoiceOut): ArrayBuffer;
+
+declare function exampleChoiceDeserialize(bytes: Deserializable): ExampleChoiceIn | Error;
+
+declare function exampleChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const ExampleChoice: {
+  atlas: typeof exampleChoiceAtlas;
+  size: typeof exampleChoiceSize;
+  serializeWithAtlasUnsafe: typeof exampleChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof exampleChoiceDeserializeUnsafe;
+  serialize: typeof exampleChoiceSerialize;
+  deserialize: typeof exampleChoiceDeserialize;
+  checkOut: typeof exampleChoiceCheckOut;
+};
+
+export declare function isExampleChoiceOut(value: unknown): value is ExampleChoiceOut;
+
+export declare function assertExampleChoiceOut(value: unknown): asserts value is ExampleChoiceOut;
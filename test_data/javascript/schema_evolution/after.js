@@ -0,0 +1,1327 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+function exampleStructAtlas(message) {
+  let size = 0;
+
+  let $requiredToRequired;
+  let $requiredToAsymmetric;
+  let $requiredToOptional;
+  let $asymmetricToRequired;
+  let $asymmetricToAsymmetric;
+  let $asymmetricToOptional;
+  let $optionalToRequired;
+  let $optionalToAsymmetric;
+  let $optionalToOptional;
+  let $nonexistentToAsymmetric;
+  let $nonexistentToOptional;
+
+  {
+    let payloadAtlas;
+    const payload = message.requiredToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    $requiredToRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.requiredToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    $requiredToAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.requiredToOptional;
+    if (payload !== undefined) {
+      payloadAtlas = textEncoder.encode(payload);
+      $requiredToOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.byteLength;
+      size += fieldHeaderSize(2n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.asymmetricToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    $asymmetricToRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(4n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.asymmetricToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    $asymmetricToAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(5n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.asymmetricToOptional;
+    if (payload !== undefined) {
+      payloadAtlas = textEncoder.encode(payload);
+      $asymmetricToOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.byteLength;
+      size += fieldHeaderSize(6n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.optionalToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    $optionalToRequired = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(8n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.optionalToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    $optionalToAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(9n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.optionalToOptional;
+    if (payload !== undefined) {
+      payloadAtlas = textEncoder.encode(payload);
+      $optionalToOptional = payloadAtlas;
+      const payloadSize = payloadAtlas.byteLength;
+      size += fieldHeaderSize(10n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.nonexistentToAsymmetric;
+    payloadAtlas = 0;
+    $nonexistentToAsymmetric = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(13n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas;
+    const payload = message.nonexistentToOptional;
+    if (payload !== undefined) {
+      payloadAtlas = 0;
+      $nonexistentToOptional = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(14n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  return {
+    $size: size,
+    requiredToRequired: $requiredToRequired,
+    requiredToAsymmetric: $requiredToAsymmetric,
+    requiredToOptional: $requiredToOptional,
+    asymmetricToRequired: $asymmetricToRequired,
+    asymmetricToAsymmetric: $asymmetricToAsymmetric,
+    asymmetricToOptional: $asymmetricToOptional,
+    optionalToRequired: $optionalToRequired,
+    optionalToAsymmetric: $optionalToAsymmetric,
+    optionalToOptional: $optionalToOptional,
+    nonexistentToAsymmetric: $nonexistentToAsymmetric,
+    nonexistentToOptional: $nonexistentToOptional,
+  };
+}
+
+function exampleStructSize(message) {
+  return exampleStructAtlas(message).$size;
+}
+
+function exampleStructSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.requiredToRequired;
+    const payloadAtlas = atlas.requiredToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.requiredToAsymmetric;
+    const payloadAtlas = atlas.requiredToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.requiredToOptional;
+    const payloadAtlas = atlas.requiredToOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.byteLength;
+      offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+      {
+        const targetBuffer = new Uint8Array(
+          dataView.buffer,
+          dataView.byteOffset,
+          dataView.byteLength,
+        );
+        targetBuffer.set(payloadAtlas, offset);
+        offset += payloadAtlas.byteLength;
+      }
+    }
+  }
+
+  {
+    const payload = message.asymmetricToRequired;
+    const payloadAtlas = atlas.asymmetricToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.asymmetricToAsymmetric;
+    const payloadAtlas = atlas.asymmetricToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.asymmetricToOptional;
+    const payloadAtlas = atlas.asymmetricToOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.byteLength;
+      offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
+      {
+        const targetBuffer = new Uint8Array(
+          dataView.buffer,
+          dataView.byteOffset,
+          dataView.byteLength,
+        );
+        targetBuffer.set(payloadAtlas, offset);
+        offset += payloadAtlas.byteLength;
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToRequired;
+    const payloadAtlas = atlas.optionalToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.optionalToAsymmetric;
+    const payloadAtlas = atlas.optionalToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.optionalToOptional;
+    const payloadAtlas = atlas.optionalToOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas.byteLength;
+      offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
+      {
+        const targetBuffer = new Uint8Array(
+          dataView.buffer,
+          dataView.byteOffset,
+          dataView.byteLength,
+        );
+        targetBuffer.set(payloadAtlas, offset);
+        offset += payloadAtlas.byteLength;
+      }
+    }
+  }
+
+  {
+    const payload = message.nonexistentToAsymmetric;
+    const payloadAtlas = atlas.nonexistentToAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
+  }
+
+  {
+    const payload = message.nonexistentToOptional;
+    const payloadAtlas = atlas.nonexistentToOptional;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
+    }
+  }
+
+  return offset;
+}
+
+function exampleStructDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $requiredToRequired;
+  let $requiredToAsymmetric;
+  let $requiredToOptional;
+  let $asymmetricToRequired;
+  let $asymmetricToAsymmetric;
+  let $asymmetricToOptional;
+  let $optionalToRequired;
+  let $optionalToAsymmetric;
+  let $optionalToOptional;
+  let $nonexistentToAsymmetric;
+  let $nonexistentToOptional;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requiredToRequired = payload;
+        break;
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requiredToAsymmetric = payload;
+        break;
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requiredToOptional = payload;
+        break;
+      }
+      case 4n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $asymmetricToRequired = payload;
+        break;
+      }
+      case 5n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $asymmetricToAsymmetric = payload;
+        break;
+      }
+      case 6n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $asymmetricToOptional = payload;
+        break;
+      }
+      case 8n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $optionalToRequired = payload;
+        break;
+      }
+      case 9n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $optionalToAsymmetric = payload;
+        break;
+      }
+      case 10n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $optionalToOptional = payload;
+        break;
+      }
+      case 13n: {
+        let payload = null;
+        $nonexistentToAsymmetric = payload;
+        break;
+      }
+      case 14n: {
+        let payload = null;
+        $nonexistentToOptional = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($requiredToRequired === undefined
+    || $asymmetricToRequired === undefined
+    || $optionalToRequired === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    requiredToRequired: $requiredToRequired,
+    requiredToAsymmetric: $requiredToAsymmetric,
+    requiredToOptional: $requiredToOptional,
+    asymmetricToRequired: $asymmetricToRequired,
+    asymmetricToAsymmetric: $asymmetricToAsymmetric,
+    asymmetricToOptional: $asymmetricToOptional,
+    optionalToRequired: $optionalToRequired,
+    optionalToAsymmetric: $optionalToAsymmetric,
+    optionalToOptional: $optionalToOptional,
+    nonexistentToAsymmetric: $nonexistentToAsymmetric,
+    nonexistentToOptional: $nonexistentToOptional,
+  };
+}
+
+function exampleStructSerialize(message) {
+  const atlasValue = exampleStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  exampleStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function exampleStructDeserialize(bytes) {
+  try {
+    return exampleStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function exampleStructCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.requiredToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.asymmetricToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToRequired;
+    if (typeof payload !== 'string') {
+      return '.optionalToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.optionalToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.optionalToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.optionalToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.nonexistentToAsymmetric;
+    if (payload !== null) {
+      return '.nonexistentToAsymmetric must be null';
+    }
+  }
+
+  {
+    const payload = message.nonexistentToOptional;
+    if (payload !== undefined) {
+      if (payload !== null) {
+        return '.nonexistentToOptional must be null';
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const ExampleStruct = {
+  atlas: exampleStructAtlas,
+  size: exampleStructSize,
+  serializeWithAtlasUnsafe: exampleStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: exampleStructDeserializeUnsafe,
+  serialize: exampleStructSerialize,
+  deserialize: exampleStructDeserialize,
+  checkOut: exampleStructCheckOut,
+};
+
+export function isExampleStructOut(value) {
+  return exampleStructCheckOut(value) === undefined;
+}
+
+export function assertExampleStructOut(value) {
+  const error = exampleStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleStructOut: value' + error + '.');
+  }
+}
+
+function exampleChoiceAtlas(message) {
+  if ('requiredToRequired' in message) {
+    let payloadAtlas;
+    const payload = message.requiredToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, requiredToRequired: payloadAtlas };
+  }
+
+  if ('requiredToAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.requiredToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize + fallbackAtlas.$size, requiredToAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('asymmetricToRequired' in message) {
+    let payloadAtlas;
+    const payload = message.asymmetricToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(4n, payloadSize, false) + payloadSize, asymmetricToRequired: payloadAtlas };
+  }
+
+  if ('asymmetricToAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.asymmetricToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(5n, payloadSize, false) + payloadSize + fallbackAtlas.$size, asymmetricToAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('asymmetricToOptional' in message) {
+    let payloadAtlas;
+    const payload = message.asymmetricToOptional;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(6n, payloadSize, false) + payloadSize + fallbackAtlas.$size, asymmetricToOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('optionalToRequired' in message) {
+    let payloadAtlas;
+    const payload = message.optionalToRequired;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(8n, payloadSize, false) + payloadSize, optionalToRequired: payloadAtlas };
+  }
+
+  if ('optionalToAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.optionalToAsymmetric;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(9n, payloadSize, false) + payloadSize + fallbackAtlas.$size, optionalToAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('optionalToOptional' in message) {
+    let payloadAtlas;
+    const payload = message.optionalToOptional;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(10n, payloadSize, false) + payloadSize + fallbackAtlas.$size, optionalToOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('nonexistentToRequired' in message) {
+    let payloadAtlas;
+    const payload = message.nonexistentToRequired;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(12n, payloadSize, false) + payloadSize, nonexistentToRequired: payloadAtlas };
+  }
+
+  if ('nonexistentToAsymmetric' in message) {
+    let payloadAtlas;
+    const payload = message.nonexistentToAsymmetric;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(13n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nonexistentToAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('nonexistentToOptional' in message) {
+    let payloadAtlas;
+    const payload = message.nonexistentToOptional;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = exampleChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(14n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nonexistentToOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function exampleChoiceSize(message) {
+  return exampleChoiceAtlas(message).$size;
+}
+
+function exampleChoiceSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  if ('requiredToRequired' in message) {
+    const payload = message.requiredToRequired;
+    const payloadAtlas = (atlas).requiredToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('requiredToAsymmetric' in message) {
+    const payload = message.requiredToAsymmetric;
+    const payloadAtlas = (atlas).requiredToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('asymmetricToRequired' in message) {
+    const payload = message.asymmetricToRequired;
+    const payloadAtlas = (atlas).asymmetricToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('asymmetricToAsymmetric' in message) {
+    const payload = message.asymmetricToAsymmetric;
+    const payloadAtlas = (atlas).asymmetricToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('asymmetricToOptional' in message) {
+    const payload = message.asymmetricToOptional;
+    const payloadAtlas = (atlas).asymmetricToOptional;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('optionalToRequired' in message) {
+    const payload = message.optionalToRequired;
+    const payloadAtlas = (atlas).optionalToRequired;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('optionalToAsymmetric' in message) {
+    const payload = message.optionalToAsymmetric;
+    const payloadAtlas = (atlas).optionalToAsymmetric;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('optionalToOptional' in message) {
+    const payload = message.optionalToOptional;
+    const payloadAtlas = (atlas).optionalToOptional;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('nonexistentToRequired' in message) {
+    const payload = message.nonexistentToRequired;
+    const payloadAtlas = (atlas).nonexistentToRequired;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 12n, payloadSize, false);
+    return offset;
+  }
+
+  if ('nonexistentToAsymmetric' in message) {
+    const payload = message.nonexistentToAsymmetric;
+    const payloadAtlas = (atlas).nonexistentToAsymmetric;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  if ('nonexistentToOptional' in message) {
+    const payload = message.nonexistentToOptional;
+    const payloadAtlas = (atlas).nonexistentToOptional;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
+    offset = exampleChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas).$fallback);
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function exampleChoiceDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'requiredToRequired',
+          requiredToRequired: payload,
+        };
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'requiredToAsymmetric',
+          requiredToAsymmetric: payload,
+        };
+      }
+      case 4n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'asymmetricToRequired',
+          asymmetricToRequired: payload,
+        };
+      }
+      case 5n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'asymmetricToAsymmetric',
+          asymmetricToAsymmetric: payload,
+        };
+      }
+      case 6n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = exampleChoiceDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'asymmetricToOptional',
+          asymmetricToOptional: payload,
+          $fallback,
+        };
+      }
+      case 8n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'optionalToRequired',
+          optionalToRequired: payload,
+        };
+      }
+      case 9n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'optionalToAsymmetric',
+          optionalToAsymmetric: payload,
+        };
+      }
+      case 10n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = exampleChoiceDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'optionalToOptional',
+          optionalToOptional: payload,
+          $fallback,
+        };
+      }
+      case 12n: {
+        let payload = null;
+        return {
+          $field: 'nonexistentToRequired',
+          nonexistentToRequired: payload,
+        };
+      }
+      case 13n: {
+        let payload = null;
+        return {
+          $field: 'nonexistentToAsymmetric',
+          nonexistentToAsymmetric: payload,
+        };
+      }
+      case 14n: {
+        let payload = null;
+        const $fallback = exampleChoiceDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'nonexistentToOptional',
+          nonexistentToOptional: payload,
+          $fallback,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function exampleChoiceSerialize(message) {
+  const atlasValue = exampleChoiceAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  exampleChoiceSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function exampleChoiceDeserialize(bytes) {
+  try {
+    return exampleChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function exampleChoiceCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  if ('requiredToRequired' in message) {
+    if ('$field' in message && message.$field !== 'requiredToRequired') {
+      return '.$field must be "requiredToRequired"';
+    }
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('requiredToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'requiredToAsymmetric') {
+      return '.$field must be "requiredToAsymmetric"';
+    }
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToRequired' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToRequired') {
+      return '.$field must be "asymmetricToRequired"';
+    }
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToAsymmetric') {
+      return '.$field must be "asymmetricToAsymmetric"';
+    }
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToOptional' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToOptional') {
+      return '.$field must be "asymmetricToOptional"';
+    }
+    const payload = message.asymmetricToOptional;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToRequired' in message) {
+    if ('$field' in message && message.$field !== 'optionalToRequired') {
+      return '.$field must be "optionalToRequired"';
+    }
+    const payload = message.optionalToRequired;
+    if (typeof payload !== 'string') {
+      return '.optionalToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('optionalToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'optionalToAsymmetric') {
+      return '.$field must be "optionalToAsymmetric"';
+    }
+    const payload = message.optionalToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.optionalToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToOptional' in message) {
+    if ('$field' in message && message.$field !== 'optionalToOptional') {
+      return '.$field must be "optionalToOptional"';
+    }
+    const payload = message.optionalToOptional;
+    if (typeof payload !== 'string') {
+      return '.optionalToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToRequired' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToRequired') {
+      return '.$field must be "nonexistentToRequired"';
+    }
+    const payload = message.nonexistentToRequired;
+    if (payload !== null) {
+      return '.nonexistentToRequired must be null';
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToAsymmetric') {
+      return '.$field must be "nonexistentToAsymmetric"';
+    }
+    const payload = message.nonexistentToAsymmetric;
+    if (payload !== null) {
+      return '.nonexistentToAsymmetric must be null';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToOptional' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToOptional') {
+      return '.$field must be "nonexistentToOptional"';
+    }
+    const payload = message.nonexistentToOptional;
+    if (payload !== null) {
+      return '.nonexistentToOptional must be null';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields requiredToRequired, requiredToAsymmetric, asymmetricToRequired, asymmetricToAsymmetric, asymmetricToOptional, optionalToRequired, optionalToAsymmetric, optionalToOptional, nonexistentToRequired, nonexistentToAsymmetric, nonexistentToOptional';
+}
+
+export const ExampleChoice = {
+  atlas: exampleChoiceAtlas,
+  size: exampleChoiceSize,
+  serializeWithAtlasUnsafe: exampleChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: exampleChoiceDeserializeUnsafe,
+  serialize: exampleChoiceSerialize,
+  deserialize: exampleChoiceDeserialize,
+  checkOut: exampleChoiceCheckOut,
+};
+
+export function isExampleChoiceOut(value) {
+  return exampleChoiceCheckOut(value) === undefined;
+}
+
+export function assertExampleChoiceOut(value) {
+  const error = exampleChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleChoiceOut: value' + error + '.');
+  }
+}
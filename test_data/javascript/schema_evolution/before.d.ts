@@ -0,0 +1,173 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+export type ExampleStructAtlas = {
+  $size: number;
+  requiredToRequired: Uint8Array;
+  requiredToAsymmetric: Uint8Array;
+  requiredToOptional: Uint8Array;
+  requiredToNonexistent: Uint8Array;
+  asymmetricToRequired: Uint8Array;
+  asymmetricToAsymmetric: Uint8Array;
+  asymmetricToOptional: Uint8Array;
+  asymmetricToNonexistent: Uint8Array;
+  optionalToRequired: Uint8Array | undefined;
+  optionalToAsymmetric: Uint8Array | undefined;
+  optionalToOptional: Uint8Array | undefined;
+  optionalToNonexistent: Uint8Array | undefined;
+};
+
+export type ExampleStructOut = {
+  requiredToRequired: string;
+  requiredToAsymmetric: string;
+  requiredToOptional: string;
+  requiredToNonexistent: string;
+  asymmetricToRequired: string;
+  asymmetricToAsymmetric: string;
+  asymmetricToOptional: string;
+  asymmetricToNonexistent: string;
+  optionalToRequired: string | undefined;
+  optionalToAsymmetric: string | undefined;
+  optionalToOptional: string | undefined;
+  optionalToNonexistent: string | undefined;
+};
+
+export type ExampleStructIn = {
+  requiredToRequired: string;
+  requiredToAsymmetric: string;
+  requiredToOptional: string;
+  requiredToNonexistent: string;
+  asymmetricToRequired: string | undefined;
+  asymmetricToAsymmetric: string | undefined;
+  asymmetricToOptional: string | undefined;
+  asymmetricToNonexistent: string | undefined;
+  optionalToRequired: string | undefined;
+  optionalToAsymmetric: string | undefined;
+  optionalToOptional: string | undefined;
+  optionalToNonexistent: string | undefined;
+};
+
+declare function exampleStructAtlas(message: ExampleStructOut): ExampleStructAtlas;
+
+declare function exampleStructSize(message: ExampleStructOut): number;
+
+declare function exampleStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: ExampleStructOut,
+  atlas: ExampleStructAtlas,
+): number;
+
+declare function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn;
+
+declare function exampleStructSerialize(message: ExampleStructOut): ArrayBuffer;
+
+declare function exampleStructDeserialize(bytes: Deserializable): ExampleStructIn | Error;
+
+declare function exampleStructCheckOut(value: unknown): string | undefined;
+
+export declare const ExampleStruct: {
+  atlas: typeof exampleStructAtlas;
+  size: typeof exampleStructSize;
+  serializeWithAtlasUnsafe: typeof exampleStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof exampleStructDeserializeUnsafe;
+  serialize: typeof exampleStructSerialize;
+  deserialize: typeof exampleStructDeserialize;
+  checkOut: typeof exampleStructCheckOut;
+};
+
+export declare function isExampleStructOut(value: unknown): value is ExampleStructOut;
+
+export declare function assertExampleStructOut(value: unknown): asserts value is ExampleStructOut;
+
+export type ExampleChoiceAtlas =
+  | { $size: number; requiredToRequired: Uint8Array }
+  | { $size: number; requiredToAsymmetric: Uint8Array }
+  | { $size: number; asymmetricToRequired: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; asymmetricToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; asymmetricToOptional: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; asymmetricToNonexistent: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToRequired: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToOptional: Uint8Array; $fallback: ExampleChoiceAtlas }
+  | { $size: number; optionalToNonexistent: Uint8Array; $fallback: ExampleChoiceAtlas };
+
+export type ExampleChoiceOut =
+  | { requiredToRequired: string }
+  | { requiredToAsymmetric: string }
+  | { asymmetricToRequired: string; $fallback: ExampleChoiceOut }
+  | { asymmetricToAsymmetric: string; $fallback: ExampleChoiceOut }
+  | { asymmetricToOptional: string; $fallback: ExampleChoiceOut }
+  | { asymmetricToNonexistent: string; $fallback: ExampleChoiceOut }
+  | { optionalToRequired: string; $fallback: ExampleChoiceOut }
+  | { optionalToAsymmetric: string; $fallback: ExampleChoiceOut }
+  | { optionalToOptional: string; $fallback: ExampleChoiceOut }
+  | { optionalToNonexistent: string; $fallback: ExampleChoiceOut };
+
+export type ExampleChoiceIn =
+  | { $field: 'requiredToRequired'; requiredToRequired: string }
+  | { $field: 'requiredToAsymmetric'; requiredToAsymmetric: string }
+  | { $field: 'asymmetricToRequired'; asymmetricToRequired: string }
+  | { $field: 'asymmetricToAsymmetric'; asymmetricToAsymmetric: string }
+  | { $field: 'asymmetricToOptional'; asymmetricToOptional: string }
+  | { $field: 'asymmetricToNonexistent'; asymmetricToNonexistent: string }
+  | { $field: 'optionalToRequired'; optionalToRequired: string; $fallback: ExampleChoiceIn }
+  | { $field: 'optionalToAsymmetric'; optionalToAsymmetric: string; $fallback: ExampleChoiceIn }
+  | { $field: 'optionalToOptional'; optionalToOptional: string; $fallback: ExampleChoiceIn }
+  | { $field: 'optionalToNonexistent'; optionalToNonexistent: string; $fallback: ExampleChoiceIn };
+
+declare function exampleChoiceAtlas(message: ExampleChoiceOut): ExampleChoiceAtlas;
+
+declare function exampleChoiceSize(message: ExampleChoiceOut): number;
+
+declare function exampleChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: ExampleChoiceOut,
+  atlas: ExampleChoiceAtlas,
+): number;
+
+declare function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn;
+
+declare function exampleChoiceSerialize(message: ExampleChoiceOut): ArrayBuffer;
+
+declare function exampleChoiceDeserialize(bytes: Deserializable): ExampleChoiceIn | Error;
+
+declare function exampleChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const ExampleChoice: {
+  atlas: typeof exampleChoiceAtlas;
+  size: typeof exampleChoiceSize;
+  serializeWithAtlasUnsafe: typeof exampleChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof exampleChoiceDeserializeUnsafe;
+  serialize: typeof exampleChoiceSerialize;
+  deserialize: typeof exampleChoiceDeserialize;
+  checkOut: typeof exampleChoiceCheckOut;
+};
+
+export declare function isExampleChoiceOut(value: unknown): value is ExampleChoiceOut;
+
+export declare function assertExampleChoiceOut(value: unknown): asserts value is ExampleChoiceOut;
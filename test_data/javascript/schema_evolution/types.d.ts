@@ -0,0 +1,117 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable } from '../common.js';
+
+import * as _After from './after.js';
+import * as _Before from './before.js';
+
+export type SingletonStructAtlas = {
+  $size: number;
+  x: Uint8Array;
+};
+
+export type SingletonStructOut = {
+  x: string;
+};
+
+export type SingletonStructIn = {
+  x: string;
+};
+
+declare function singletonStructAtlas(message: SingletonStructOut): SingletonStructAtlas;
+
+declare function singletonStructSize(message: SingletonStructOut): number;
+
+declare function singletonStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: SingletonStructOut,
+  atlas: SingletonStructAtlas,
+): number;
+
+declare function singletonStructDeserializeUnsafe(dataView: DataView): SingletonStructIn;
+
+declare function singletonStructSerialize(message: SingletonStructOut): ArrayBuffer;
+
+declare function singletonStructDeserialize(bytes: Deserializable): SingletonStructIn | Error;
+
+declare function singletonStructCheckOut(value: unknown): string | undefined;
+
+export declare const SingletonStruct: {
+  atlas: typeof singletonStructAtlas;
+  size: typeof singletonStructSize;
+  serializeWithAtlasUnsafe: typeof singletonStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof singletonStructDeserializeUnsafe;
+  serialize: typeof singletonStructSerialize;
+  deserialize: typeof singletonStructDeserialize;
+  checkOut: typeof singletonStructCheckOut;
+};
+
+export declare function isSingletonStructOut(value: unknown): value is SingletonStructOut;
+
+export declare function assertSingletonStructOut(value: unknown): asserts value is SingletonStructOut;
+
+export type SingletonChoiceAtlas =
+  | { $size: number; x: Uint8Array };
+
+export type SingletonChoiceOut =
+  | { x: string };
+
+export type SingletonChoiceIn =
+  | { $field: 'x'; x: string }
+  | { $field: never };
+
+declare function singletonChoiceAtlas(message: SingletonChoiceOut): SingletonChoiceAtlas;
+
+declare function singletonChoiceSize(message: SingletonChoiceOut): number;
+
+declare function singletonChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: SingletonChoiceOut,
+  atlas: SingletonChoiceAtlas,
+): number;
+
+declare function singletonChoiceDeserializeUnsafe(dataView: DataView): SingletonChoiceIn;
+
+declare function singletonChoiceSerialize(message: SingletonChoiceOut): ArrayBuffer;
+
+declare function singletonChoiceDeserialize(bytes: Deserializable): SingletonChoiceIn | Error;
+
+declare function singletonChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const SingletonChoice: {
+  atlas: typeof singletonChoiceAtlas;
+  size: typeof singletonChoiceSize;
+  serializeWithAtlasUnsafe: typeof singletonChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof singletonChoiceDeserializeUnsafe;
+  serialize: typeof singletonChoiceSerialize;
+  deserialize: typeof singletonChoiceDeserialize;
+  checkOut: typeof singletonChoiceCheckOut;
+};
+
+export declare function isSingletonChoiceOut(value: unknown): value is SingletonChoiceOut;
+
+export declare function assertSingletonChoiceOut(value: unknown): asserts value is SingletonChoiceOut;
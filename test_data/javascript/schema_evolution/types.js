@@ -0,0 +1,313 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _After from './after.js';
+import * as _Before from './before.js';
+
+function singletonStructAtlas(message) {
+  let size = 0;
+
+  let $x;
+
+  {
+    let payloadAtlas;
+    const payload = message.x;
+    payloadAtlas = textEncoder.encode(payload);
+    $x = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    x: $x,
+  };
+}
+
+function singletonStructSize(message) {
+  return singletonStructAtlas(message).$size;
+}
+
+function singletonStructSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.x;
+    const payloadAtlas = atlas.x;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function singletonStructDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $x;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $x = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($x === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    x: $x,
+  };
+}
+
+function singletonStructSerialize(message) {
+  const atlasValue = singletonStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  singletonStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function singletonStructDeserialize(bytes) {
+  try {
+    return singletonStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function singletonStructCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.x;
+    if (typeof payload !== 'string') {
+      return '.x must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const SingletonStruct = {
+  atlas: singletonStructAtlas,
+  size: singletonStructSize,
+  serializeWithAtlasUnsafe: singletonStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: singletonStructDeserializeUnsafe,
+  serialize: singletonStructSerialize,
+  deserialize: singletonStructDeserialize,
+  checkOut: singletonStructCheckOut,
+};
+
+export function isSingletonStructOut(value) {
+  return singletonStructCheckOut(value) === undefined;
+}
+
+export function assertSingletonStructOut(value) {
+  const error = singletonStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid SingletonStructOut: value' + error + '.');
+  }
+}
+
+function singletonChoiceAtlas(message) {
+  if ('x' in message) {
+    let payloadAtlas;
+    const payload = message.x;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, x: payloadAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function singletonChoiceSize(message) {
+  return singletonChoiceAtlas(message).$size;
+}
+
+function singletonChoiceSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  if ('x' in message) {
+    const payload = message.x;
+    const payloadAtlas = (atlas).x;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function singletonChoiceDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'x',
+          x: payload,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function singletonChoiceSerialize(message) {
+  const atlasValue = singletonChoiceAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  singletonChoiceSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function singletonChoiceDeserialize(bytes) {
+  try {
+    return singletonChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function singletonChoiceCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  if ('x' in message) {
+    if ('$field' in message && message.$field !== 'x') {
+      return '.$field must be "x"';
+    }
+    const payload = message.x;
+    if (typeof payload !== 'string') {
+      return '.x must be a string';
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields x';
+}
+
+export const SingletonChoice = {
+  atlas: singletonChoiceAtlas,
+  size: singletonChoiceSize,
+  serializeWithAtlasUnsafe: singletonChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: singletonChoiceDeserializeUnsafe,
+  serialize: singletonChoiceSerialize,
+  deserialize: singletonChoiceDeserialize,
+  checkOut: singletonChoiceCheckOut,
+};
+
+export function isSingletonChoiceOut(value) {
+  return singletonChoiceCheckOut(value) === undefined;
+}
+
+export function assertSingletonChoiceOut(value) {
+  const error = singletonChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid SingletonChoiceOut: value' + error + '.');
+  }
+}
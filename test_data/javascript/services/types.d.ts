@@ -0,0 +1,136 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable, Transport } from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+export type GreetRequestAtlas = {
+  $size: number;
+  name: Uint8Array;
+};
+
+export type GreetRequestOut = {
+  name: string;
+};
+
+export type GreetRequestIn = {
+  name: string;
+};
+
+declare function greetRequestAtlas(message: GreetRequestOut): GreetRequestAtlas;
+
+declare function greetRequestSize(message: GreetRequestOut): number;
+
+declare function greetRequestSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: GreetRequestOut,
+  atlas: GreetRequestAtlas,
+): number;
+
+declare function greetRequestDeserializeUnsafe(dataView: DataView): GreetRequestIn;
+
+declare function greetRequestSerialize(message: GreetRequestOut): ArrayBuffer;
+
+declare function greetRequestDeserialize(bytes: Deserializable): GreetRequestIn | Error;
+
+declare function greetRequestCheckOut(value: unknown): string | undefined;
+
+export declare const GreetRequest: {
+  atlas: typeof greetRequestAtlas;
+  size: typeof greetRequestSize;
+  serializeWithAtlasUnsafe: typeof greetRequestSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof greetRequestDeserializeUnsafe;
+  serialize: typeof greetRequestSerialize;
+  deserialize: typeof greetRequestDeserialize;
+  checkOut: typeof greetRequestCheckOut;
+};
+
+export declare function isGreetRequestOut(value: unknown): value is GreetRequestOut;
+
+export declare function assertGreetRequestOut(value: unknown): asserts value is GreetRequestOut;
+
+export type GreetResponseAtlas =
+  | { $size: number; greeting: Uint8Array }
+  | { $size: number; refusal: Uint8Array };
+
+export type GreetResponseOut =
+  | { greeting: string }
+  | { refusal: string };
+
+export type GreetResponseIn =
+  | { $field: 'greeting'; greeting: string }
+  | { $field: 'refusal'; refusal: string };
+
+declare function greetResponseAtlas(message: GreetResponseOut): GreetResponseAtlas;
+
+declare function greetResponseSize(message: GreetResponseOut): number;
+
+declare function greetResponseSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: GreetResponseOut,
+  atlas: GreetResponseAtlas,
+): number;
+
+declare function greetResponseDeserializeUnsafe(dataView: DataView): GreetResponseIn;
+
+declare function greetResponseSerialize(message: GreetResponseOut): ArrayBuffer;
+
+declare function greetResponseDeserialize(bytes: Deserializable): GreetResponseIn | Error;
+
+declare function greetResponseCheckOut(value: unknown): string | undefined;
+
+export declare const GreetResponse: {
+  atlas: typeof greetResponseAtlas;
+  size: typeof greetResponseSize;
+  serializeWithAtlasUnsafe: typeof greetResponseSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof greetResponseDeserializeUnsafe;
+  serialize: typeof greetResponseSerialize;
+  deserialize: typeof greetResponseDeserialize;
+  checkOut: typeof greetResponseCheckOut;
+};
+
+export declare function isGreetResponseOut(value: unknown): value is GreetResponseOut;
+
+export declare function assertGreetResponseOut(value: unknown): asserts value is GreetResponseOut;
+
+export interface GreeterServer {
+  greet(request: GreetRequestIn): GreetResponseOut | Promise<GreetResponseOut>;
+  ping(request: _Types.EmptyStructIn): _Types.EmptyStructOut | Promise<_Types.EmptyStructOut>;
+}
+
+export declare function dispatchGreeter(
+  server: GreeterServer,
+  methodIndex: bigint,
+  request: Deserializable,
+): Promise<ArrayBuffer>;
+
+export interface GreeterClient {
+  greet(request: GreetRequestOut): Promise<GreetResponseIn>;
+  ping(request: _Types.EmptyStructOut): Promise<_Types.EmptyStructIn>;
+}
+
+export declare function greeterClient(transport: Transport): GreeterClient;
@@ -0,0 +1,411 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+
+import * as _Types from '../degenerate/types.js';
+
+function greetRequestAtlas(message) {
+  let size = 0;
+
+  let $name;
+
+  {
+    let payloadAtlas;
+    const payload = message.name;
+    payloadAtlas = textEncoder.encode(payload);
+    $name = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    name: $name,
+  };
+}
+
+function greetRequestSize(message) {
+  return greetRequestAtlas(message).$size;
+}
+
+function greetRequestSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  {
+    const payload = message.name;
+    const payloadAtlas = atlas.name;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function greetRequestDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $name;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index;
+    let payloadSize;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $name = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($name === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    name: $name,
+  };
+}
+
+function greetRequestSerialize(message) {
+  const atlasValue = greetRequestAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  greetRequestSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function greetRequestDeserialize(bytes) {
+  try {
+    return greetRequestDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function greetRequestCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  {
+    const payload = message.name;
+    if (typeof payload !== 'string') {
+      return '.name must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const GreetRequest = {
+  atlas: greetRequestAtlas,
+  size: greetRequestSize,
+  serializeWithAtlasUnsafe: greetRequestSerializeWithAtlasUnsafe,
+  deserializeUnsafe: greetRequestDeserializeUnsafe,
+  serialize: greetRequestSerialize,
+  deserialize: greetRequestDeserialize,
+  checkOut: greetRequestCheckOut,
+};
+
+export function isGreetRequestOut(value) {
+  return greetRequestCheckOut(value) === undefined;
+}
+
+export function assertGreetRequestOut(value) {
+  const error = greetRequestCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid GreetRequestOut: value' + error + '.');
+  }
+}
+
+function greetResponseAtlas(message) {
+  if ('greeting' in message) {
+    let payloadAtlas;
+    const payload = message.greeting;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, greeting: payloadAtlas };
+  }
+
+  if ('refusal' in message) {
+    let payloadAtlas;
+    const payload = message.refusal;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize, refusal: payloadAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function greetResponseSize(message) {
+  return greetResponseAtlas(message).$size;
+}
+
+function greetResponseSerializeWithAtlasUnsafe(
+  dataView,
+  offset,
+  message,
+  atlas,
+) {
+  if ('greeting' in message) {
+    const payload = message.greeting;
+    const payloadAtlas = (atlas).greeting;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('refusal' in message) {
+    const payload = message.refusal;
+    const payloadAtlas = (atlas).refusal;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function greetResponseDeserializeUnsafe(dataView) {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'greeting',
+          greeting: payload,
+        };
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'refusal',
+          refusal: payload,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function greetResponseSerialize(message) {
+  const atlasValue = greetResponseAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  greetResponseSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function greetResponseDeserialize(bytes) {
+  try {
+    return greetResponseDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e;
+  }
+}
+
+function greetResponseCheckOut(value) {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value;
+
+  if ('greeting' in message) {
+    if ('$field' in message && message.$field !== 'greeting') {
+      return '.$field must be "greeting"';
+    }
+    const payload = message.greeting;
+    if (typeof payload !== 'string') {
+      return '.greeting must be a string';
+    }
+    return undefined;
+  }
+
+  if ('refusal' in message) {
+    if ('$field' in message && message.$field !== 'refusal') {
+      return '.$field must be "refusal"';
+    }
+    const payload = message.refusal;
+    if (typeof payload !== 'string') {
+      return '.refusal must be a string';
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields greeting, refusal';
+}
+
+export const GreetResponse = {
+  atlas: greetResponseAtlas,
+  size: greetResponseSize,
+  serializeWithAtlasUnsafe: greetResponseSerializeWithAtlasUnsafe,
+  deserializeUnsafe: greetResponseDeserializeUnsafe,
+  serialize: greetResponseSerialize,
+  deserialize: greetResponseDeserialize,
+  checkOut: greetResponseCheckOut,
+};
+
+export function isGreetResponseOut(value) {
+  return greetResponseCheckOut(value) === undefined;
+}
+
+export function assertGreetResponseOut(value) {
+  const error = greetResponseCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid GreetResponseOut: value' + error + '.');
+  }
+}
+
+export async function dispatchGreeter(
+  server,
+  methodIndex,
+  request,
+) {
+  switch (methodIndex) {
+    case 0n: {
+      const message = GreetRequest.deserialize(request);
+      if (message instanceof Error) {
+        throw message;
+      }
+      return GreetResponse.serialize(await server.greet(message));
+    }
+    case 2n: {
+      const message = _Types.EmptyStruct.deserialize(request);
+      if (message instanceof Error) {
+        throw message;
+      }
+      return _Types.EmptyStruct.serialize(await server.ping(message));
+    }
+    default:
+      throw new Error('Unknown method index.');
+  }
+}
+
+export function greeterClient(transport) {
+  return {
+    async greet(request) {
+      const response = GreetResponse.deserialize(
+        await transport(0n, GreetRequest.serialize(request)),
+      );
+      if (response instanceof Error) {
+        throw response;
+      }
+      return response;
+    },
+    async ping(request) {
+      const response = _Types.EmptyStruct.deserialize(
+        await transport(2n, _Types.EmptyStruct.serialize(request)),
+      );
+      if (response instanceof Error) {
+        throw response;
+      }
+      return response;
+    },
+  };
+}
@@ -0,0 +1,313 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  checkUnknownField,
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common.js';
+import type { Deserializable, UnknownField } from '../common.js';
+
+export type NewChoiceAtlas =
+  | { $size: number; known: Uint8Array }
+  | { $size: number; unrecognized: number }
+  | { $size: number; preferred: number; $fallback: NewChoiceAtlas };
+
+export type NewChoiceOut =
+  | { known: string }
+  | { unrecognized: bigint }
+  | { preferred: ArrayBuffer; $fallback: NewChoiceOut };
+
+export type NewChoiceIn =
+  | { $field: 'known'; known: string }
+  | { $field: 'unrecognized'; unrecognized: bigint }
+  | { $field: 'preferred'; preferred: ArrayBuffer; $fallback: NewChoiceIn };
+
+declare function newChoiceAtlas(message: NewChoiceOut): NewChoiceAtlas;
+
+declare function newChoiceSize(message: NewChoiceOut): number;
+
+declare function newChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: NewChoiceOut,
+  atlas: NewChoiceAtlas,
+): number;
+
+declare function newChoiceDeserializeUnsafe(dataView: DataView): NewChoiceIn;
+
+declare function newChoiceSerialize(message: NewChoiceOut): ArrayBuffer;
+
+declare function newChoiceDeserialize(bytes: Deserializable): NewChoiceIn | Error;
+
+declare function newChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const NewChoice: {
+  atlas: typeof newChoiceAtlas;
+  size: typeof newChoiceSize;
+  serializeWithAtlasUnsafe: typeof newChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof newChoiceDeserializeUnsafe;
+  serialize: typeof newChoiceSerialize;
+  deserialize: typeof newChoiceDeserialize;
+  checkOut: typeof newChoiceCheckOut;
+};
+
+export declare function isNewChoiceOut(value: unknown): value is NewChoiceOut;
+
+export declare function assertNewChoiceOut(value: unknown): asserts value is NewChoiceOut;
+
+export type OldChoiceAtlas =
+  | { $size: number; known: Uint8Array }
+  | { $size: number };
+
+export type OldChoiceOut =
+  | { known: string }
+  | { unknown: { index: bigint; payload: ArrayBuffer } };
+
+export type OldChoiceIn =
+  | { $field: 'known'; known: string }
+  | { $field: 'unknown'; unknown: { index: bigint; payload: ArrayBuffer } };
+
+declare function oldChoiceAtlas(message: OldChoiceOut): OldChoiceAtlas;
+
+declare function oldChoiceSize(message: OldChoiceOut): number;
+
+declare function oldChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: OldChoiceOut,
+  atlas: OldChoiceAtlas,
+): number;
+
+declare function oldChoiceDeserializeUnsafe(dataView: DataView): OldChoiceIn;
+
+declare function oldChoiceSerialize(message: OldChoiceOut): ArrayBuffer;
+
+declare function oldChoiceDeserialize(bytes: Deserializable): OldChoiceIn | Error;
+
+declare function oldChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const OldChoice: {
+  atlas: typeof oldChoiceAtlas;
+  size: typeof oldChoiceSize;
+  serializeWithAtlasUnsafe: typeof oldChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof oldChoiceDeserializeUnsafe;
+  serialize: typeof oldChoiceSerialize;
+  deserialize: typeof oldChoiceDeserialize;
+  checkOut: typeof oldChoiceCheckOut;
+};
+
+export declare function isOldChoiceOut(value: unknown): value is OldChoiceOut;
+
+export declare function assertOldChoiceOut(value: unknown): asserts value is OldChoiceOut;
+
+export type EmptyOpenChoiceAtlas =
+  | { $size: number };
+
+export type EmptyOpenChoiceOut =
+  | { unknown: { index: bigint; payload: ArrayBuffer } };
+
+export type EmptyOpenChoiceIn =
+  | { $field: 'unknown'; unknown: { index: bigint; payload: ArrayBuffer } }
+  | { $field: never };
+
+declare function emptyOpenChoiceAtlas(message: EmptyOpenChoiceOut): EmptyOpenChoiceAtlas;
+
+declare function emptyOpenChoiceSize(message: EmptyOpenChoiceOut): number;
+
+declare function emptyOpenChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyOpenChoiceOut,
+  atlas: EmptyOpenChoiceAtlas,
+): number;
+
+declare function emptyOpenChoiceDeserializeUnsafe(dataView: DataView): EmptyOpenChoiceIn;
+
+declare function emptyOpenChoiceSerialize(message: EmptyOpenChoiceOut): ArrayBuffer;
+
+declare function emptyOpenChoiceDeserialize(bytes: Deserializable): EmptyOpenChoiceIn | Error;
+
+declare function emptyOpenChoiceCheckOut(value: unknown): string | undefined;
+
+export declare const EmptyOpenChoice: {
+  atlas: typeof emptyOpenChoiceAtlas;
+  size: typeof emptyOpenChoiceSize;
+  serializeWithAtlasUnsafe: typeof emptyOpenChoiceSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof emptyOpenChoiceDeserializeUnsafe;
+  serialize: typeof emptyOpenChoiceSerialize;
+  deserialize: typeof emptyOpenChoiceDeserialize;
+  checkOut: typeof emptyOpenChoiceCheckOut;
+};
+
+export declare function isEmptyOpenChoiceOut(value: unknown): value is EmptyOpenChoiceOut;
+
+export declare function assertEmptyOpenChoiceOut(value: unknown): asserts value is EmptyOpenChoiceOut;
+
+export type NewStructAtlas = {
+  $size: number;
+  known: Uint8Array;
+  unrecognized: number;
+  text: Uint8Array;
+  nothing: number | undefined;
+  _number: number | undefined;
+};
+
+export type NewStructOut = {
+  known: string;
+  unrecognized: bigint;
+  text: string;
+  nothing: null | undefined;
+  _number: number | undefined;
+};
+
+export type NewStructIn = {
+  known: string;
+  unrecognized: bigint;
+  text: string;
+  nothing: null | undefined;
+  _number: number | undefined;
+};
+
+declare function newStructAtlas(message: NewStructOut): NewStructAtlas;
+
+declare function newStructSize(message: NewStructOut): number;
+
+declare function newStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: NewStructOut,
+  atlas: NewStructAtlas,
+): number;
+
+declare function newStructDeserializeUnsafe(dataView: DataView): NewStructIn;
+
+declare function newStructSerialize(message: NewStructOut): ArrayBuffer;
+
+declare function newStructDeserialize(bytes: Deserializable): NewStructIn | Error;
+
+declare function newStructCheckOut(value: unknown): string | undefined;
+
+export declare const NewStruct: {
+  atlas: typeof newStructAtlas;
+  size: typeof newStructSize;
+  serializeWithAtlasUnsafe: typeof newStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof newStructDeserializeUnsafe;
+  serialize: typeof newStructSerialize;
+  deserialize: typeof newStructDeserialize;
+  checkOut: typeof newStructCheckOut;
+};
+
+export declare function isNewStructOut(value: unknown): value is NewStructOut;
+
+export declare function assertNewStructOut(value: unknown): asserts value is NewStructOut;
+
+export type OldStructAtlas = {
+  $size: number;
+  known: Uint8Array;
+};
+
+export type OldStructOut = {
+  known: string;
+  $unknown?: UnknownField[];
+};
+
+export type OldStructIn = {
+  known: string;
+  $unknown: UnknownField[];
+};
+
+declare function oldStructAtlas(message: OldStructOut): OldStructAtlas;
+
+declare function oldStructSize(message: OldStructOut): number;
+
+declare function oldStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: OldStructOut,
+  atlas: OldStructAtlas,
+): number;
+
+declare function oldStructDeserializeUnsafe(dataView: DataView): OldStructIn;
+
+declare function oldStructSerialize(message: OldStructOut): ArrayBuffer;
+
+declare function oldStructDeserialize(bytes: Deserializable): OldStructIn | Error;
+
+declare function oldStructCheckOut(value: unknown): string | undefined;
+
+export declare const OldStruct: {
+  atlas: typeof oldStructAtlas;
+  size: typeof oldStructSize;
+  serializeWithAtlasUnsafe: typeof oldStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof oldStructDeserializeUnsafe;
+  serialize: typeof oldStructSerialize;
+  deserialize: typeof oldStructDeserialize;
+  checkOut: typeof oldStructCheckOut;
+};
+
+export declare function isOldStructOut(value: unknown): value is OldStructOut;
+
+export declare function assertOldStructOut(value: unknown): asserts value is OldStructOut;
+
+export type EmptyOpenStructAtlas = {
+  $size: number;
+};
+
+export type EmptyOpenStructOut = {
+  $unknown?: UnknownField[];
+};
+
+export type EmptyOpenStructIn = {
+  $unknown: UnknownField[];
+};
+
+declare function emptyOpenStructAtlas(message: EmptyOpenStructOut): EmptyOpenStructAtlas;
+
+declare function emptyOpenStructSize(message: EmptyOpenStructOut): number;
+
+declare function emptyOpenStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyOpenStructOut,
+  atlas: EmptyOpenStructAtlas,
+): number;
+
+declare function emptyOpenStructDeserializeUnsafe(dataView: DataView): EmptyOpenStructIn;
+
+declare function emptyOpenStructSerialize(message: EmptyOpenStructOut): ArrayBuffer;
+
+declare function emptyOpenStructDeserialize(bytes: Deserializable): EmptyOpenStructIn | Error;
+
+declare function emptyOpenStructCheckOut(value: unknown): string | undefined;
+
+export declare const EmptyOpenStruct: {
+  atlas: typeof emptyOpenStructAtlas;
+  size: typeof emptyOpenStructSize;
+  serializeWithAtlasUnsafe: typeof emptyOpenStructSerializeWithAtlasUnsafe;
+  deserializeUnsafe: typeof emptyOpenStructDeserializeUnsafe;
+  serialize: typeof emptyOpenStructSerialize;
+  deserialize: typeof emptyOpenStructDeserialize;
+  checkOut: typeof emptyOpenStructCheckOut;
+};
+
+export declare function isEmptyOpenStructOut(value: unknown): value is EmptyOpenStructOut;
+
+export declare function assertEmptyOpenStructOut(value: unknown): asserts value is EmptyOpenStructOut;
@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -83,7 +87,7 @@ function structFromBelowDeserializeUnsafe(dataView: DataView): StructFromBelowIn
 
   let $x: _Types.StructFromAboveIn | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -143,6 +147,24 @@ function structFromBelowDeserialize(bytes: Deserializable): StructFromBelowIn |
   }
 }
 
+function structFromBelowCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.x;
+    const error = _Types.StructFromAbove.checkOut(payload);
+    if (error !== undefined) {
+      return '.x' + error;
+    }
+  }
+
+  return undefined;
+}
+
 export const StructFromBelow = {
   atlas: structFromBelowAtlas,
   size: structFromBelowSize,
@@ -150,4 +172,16 @@ export const StructFromBelow = {
   deserializeUnsafe: structFromBelowDeserializeUnsafe,
   serialize: structFromBelowSerialize,
   deserialize: structFromBelowDeserialize,
+  checkOut: structFromBelowCheckOut,
 };
+
+export function isStructFromBelowOut(value: unknown): value is StructFromBelowOut {
+  return structFromBelowCheckOut(value) === undefined;
+}
+
+export function assertStructFromBelowOut(value: unknown): asserts value is StructFromBelowOut {
+  const error = structFromBelowCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid StructFromBelowOut: value' + error + '.');
+  }
+}
@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -184,7 +188,7 @@ function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn
   let $elements: string | undefined;
   let $fallback: string | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -207,13 +211,7 @@ function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $field = payload;
@@ -227,13 +225,7 @@ function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $size = payload;
@@ -247,13 +239,7 @@ function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $elements = payload;
@@ -267,13 +253,7 @@ function structFromAboveDeserializeUnsafe(dataView: DataView): StructFromAboveIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $fallback = payload;
@@ -316,6 +296,44 @@ function structFromAboveDeserialize(bytes: Deserializable): StructFromAboveIn |
   }
 }
 
+function structFromAboveCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.field;
+    if (typeof payload !== 'string') {
+      return '.field must be a string';
+    }
+  }
+
+  {
+    const payload = message.size;
+    if (typeof payload !== 'string') {
+      return '.size must be a string';
+    }
+  }
+
+  {
+    const payload = message.elements;
+    if (typeof payload !== 'string') {
+      return '.elements must be a string';
+    }
+  }
+
+  {
+    const payload = message.fallback;
+    if (typeof payload !== 'string') {
+      return '.fallback must be a string';
+    }
+  }
+
+  return undefined;
+}
+
 export const StructFromAbove = {
   atlas: structFromAboveAtlas,
   size: structFromAboveSize,
@@ -323,4 +341,16 @@ export const StructFromAbove = {
   deserializeUnsafe: structFromAboveDeserializeUnsafe,
   serialize: structFromAboveSerialize,
   deserialize: structFromAboveDeserialize,
+  checkOut: structFromAboveCheckOut,
 };
+
+export function isStructFromAboveOut(value: unknown): value is StructFromAboveOut {
+  return structFromAboveCheckOut(value) === undefined;
+}
+
+export function assertStructFromAboveOut(value: unknown): asserts value is StructFromAboveOut {
+  const error = structFromAboveCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid StructFromAboveOut: value' + error + '.');
+  }
+}
@@ -18,6 +18,10 @@ export type Deserializable =
   | BigInt64Array
   | BigUint64Array;
 
+export type Transport = (methodIndex: bigint, request: ArrayBuffer) => Promise<ArrayBuffer>;
+
+export type UnknownField = { index: bigint; sizeMode: number; payload: ArrayBuffer };
+
 export function unreachable(x: never): never {
   return x;
 }
@@ -168,76 +172,108 @@ export function serializeVarint(
 }
 
 function varintSizeFromFirstByte(firstByte: number): number {
-  let trailingZeros = 0;
-
-  while (trailingZeros < 8 && (firstByte & 1) !== 1) {
-    trailingZeros += 1;
-    firstByte >>= 1;
-  }
-
-  return trailingZeros + 1;
+  // The size is one more than the number of trailing zeros, which `Math.clz32` can count once the
+  // lowest set bit is isolated.
+  return firstByte === 0 ? 9 : 32 - Math.clz32(firstByte & -firstByte);
 }
 
-export function deserializeVarint(
+// This decodes a varint as a `number` rather than a `bigint`, which is much faster. The result is
+// exact for varints of at most 7 bytes, which covers every value up to 567,382,630,219,903 (about
+// 2^49, so the arithmetic never exceeds `Number.MAX_SAFE_INTEGER`). Longer varints are decoded as
+// a `bigint` and then rounded.
+export function deserializeVarintNumber(
   dataView: DataView,
   offset: number,
-): [number, bigint] {
+): [number, number] {
   const firstByte = dataView.getUint8(offset);
-  const sizeMinusOne = varintSizeFromFirstByte(firstByte) - 1;
-
-  const offsetPlusOne = offset + 1;
-  dataView64.setBigUint64(0, 0n, true);
-  for (let i = 0; i < sizeMinusOne; i += 1) {
-    dataView64.setUint8(i, dataView.getUint8(offsetPlusOne + i));
-  }
-  const remainingBytesValue = dataView64.getBigUint64(0, true);
 
-  switch (sizeMinusOne) {
-    case 0:
-      return [offset + 1, BigInt(firstByte >> 1)];
+  switch (varintSizeFromFirstByte(firstByte)) {
     case 1:
+      return [offset + 1, firstByte >> 1];
+    case 2:
       return [
         offset + 2,
-        128n + BigInt(firstByte >> 2) + (remainingBytesValue << 6n),
+        128 + (firstByte >> 2) + dataView.getUint8(offset + 1) * 64,
       ];
-    case 2:
+    case 3:
       return [
         offset + 3,
-        16_512n + BigInt(firstByte >> 3) + (remainingBytesValue << 5n),
+        16_512 + (firstByte >> 3) + dataView.getUint16(offset + 1, true) * 32,
       ];
-    case 3:
+    case 4:
       return [
         offset + 4,
-        2_113_664n + BigInt(firstByte >> 4) + (remainingBytesValue << 4n),
+        2_113_664 +
+          (firstByte >> 4) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint16(offset + 2, true) * 256) *
+            16,
       ];
-    case 4:
+    case 5:
       return [
         offset + 5,
-        270_549_120n + BigInt(firstByte >> 5) + (remainingBytesValue << 3n),
+        270_549_120 +
+          (firstByte >> 5) +
+          dataView.getUint32(offset + 1, true) * 8,
       ];
-    case 5:
+    case 6:
       return [
         offset + 6,
-        34_630_287_488n + BigInt(firstByte >> 6) + (remainingBytesValue << 2n),
+        34_630_287_488 +
+          (firstByte >> 6) +
+          (dataView.getUint8(offset + 1) +
+            dataView.getUint32(offset + 2, true) * 256) *
+            4,
       ];
-    case 6:
+    case 7:
       return [
         offset + 7,
-        4_432_676_798_592n +
-          BigInt(firstByte >> 7) +
-          (remainingBytesValue << 1n),
+        4_432_676_798_592 +
+          (firstByte >> 7) +
+          (dataView.getUint16(offset + 1, true) +
+            dataView.getUint32(offset + 3, true) * 65_536) *
+            2,
       ];
-    case 7:
-      return [offset + 8, 567_382_630_219_904n + remainingBytesValue];
-    default:
+    default: {
+      const [newOffset, value] = deserializeVarint(dataView, offset);
+      return [newOffset, Number(value)];
+    }
+  }
+}
+
+export function deserializeVarint(
+  dataView: DataView,
+  offset: number,
+): [number, bigint] {
+  switch (varintSizeFromFirstByte(dataView.getUint8(offset))) {
+    case 8:
+      return [
+        offset + 8,
+        567_382_630_219_904n +
+          BigInt(dataView.getUint32(offset + 1, true)) +
+          (BigInt(dataView.getUint16(offset + 5, true)) << 32n) +
+          (BigInt(dataView.getUint8(offset + 7)) << 48n),
+      ];
+    case 9:
       return [
         offset + 9,
-        (72_624_976_668_147_840n + remainingBytesValue) %
+        (72_624_976_668_147_840n + dataView.getBigUint64(offset + 1, true)) %
           18_446_744_073_709_551_616n,
       ];
+    default: {
+      const [newOffset, value] = deserializeVarintNumber(dataView, offset);
+      return [newOffset, BigInt(value)];
+    }
   }
 }
 
+export function payloadIntegerEncoded(payload: ArrayBuffer): boolean {
+  return (
+    payload.byteLength > 0 &&
+    varintSizeFromFirstByte(new Uint8Array(payload)[0]) === payload.byteLength
+  );
+}
+
 export function fieldHeaderSize(
   index: bigint,
   payloadSize: number,
@@ -286,26 +322,128 @@ export function serializeFieldHeader(
 export function deserializeFieldHeader(
   dataView: DataView,
   offset: number,
-): [number, bigint, number] {
-  const [newOffset, tag] = deserializeVarint(dataView, offset);
-
-  const index = tag >> 2n;
-
-  switch (tag & 3n) {
-    case 0n:
-      return [newOffset, index, 0];
-    case 1n:
-      return [newOffset, index, 8];
-    case 2n:
-      return [newOffset, index, varintSizeFromFirstByte(dataView.getUint8(newOffset))];
+): [number, bigint, number, number] {
+  let newOffset: number;
+  let index: bigint;
+  let sizeMode: number;
+
+  if (varintSizeFromFirstByte(dataView.getUint8(offset)) < 8) {
+    // The tag fits in a `number`, so only the index needs to be converted to a `bigint`.
+    let tag: number;
+    [newOffset, tag] = deserializeVarintNumber(dataView, offset);
+    sizeMode = tag % 4;
+    index = BigInt((tag - sizeMode) / 4);
+  } else {
+    let tag: bigint;
+    [newOffset, tag] = deserializeVarint(dataView, offset);
+    sizeMode = Number(tag & 3n);
+    index = tag >> 2n;
+  }
+
+  switch (sizeMode) {
+    case 0:
+      return [newOffset, index, 0, sizeMode];
+    case 1:
+      return [newOffset, index, 8, sizeMode];
+    case 2:
+      return [
+        newOffset,
+        index,
+        varintSizeFromFirstByte(dataView.getUint8(newOffset)),
+        sizeMode,
+      ];
     default: {
-      const [newNewOffset, sizeValue] = deserializeVarint(dataView, newOffset);
-      return [newNewOffset, index, Number(sizeValue)];
+      const [newNewOffset, payloadSize] = deserializeVarintNumber(
+        dataView,
+        newOffset,
+      );
+      return [newNewOffset, index, payloadSize, sizeMode];
     }
   }
 }
 
-const dataView64 = new DataView(new ArrayBuffer(8));
+export function unknownFieldSize(field: UnknownField): number {
+  const sizeMode = field.sizeMode & 3;
+  const tagSize = varintSizeFromValue((field.index << 2n) | BigInt(sizeMode));
+
+  if (sizeMode === 3) {
+    return (
+      tagSize +
+      varintSizeFromValue(BigInt(field.payload.byteLength)) +
+      field.payload.byteLength
+    );
+  }
+
+  return tagSize + field.payload.byteLength;
+}
+
+export function serializeUnknownField(
+  dataView: DataView,
+  offset: number,
+  field: UnknownField,
+): number {
+  const sizeMode = field.sizeMode & 3;
+  offset = serializeVarint(dataView, offset, (field.index << 2n) | BigInt(sizeMode));
+
+  if (sizeMode === 3) {
+    offset = serializeVarint(dataView, offset, BigInt(field.payload.byteLength));
+  }
+
+  const sourceBuffer = new Uint8Array(field.payload);
+  const targetBuffer = new Uint8Array(
+    dataView.buffer as ArrayBuffer,
+    dataView.byteOffset,
+    dataView.byteLength,
+  );
+  targetBuffer.set(sourceBuffer, offset);
+  return offset + sourceBuffer.byteLength;
+}
+
+export function checkUnknownField(
+  value: unknown,
+  hasSizeMode: boolean,
+): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const field = value as any;
+
+  if (typeof field.index !== 'bigint' || field.index < 0n) {
+    return '.index must be a non-negative bigint';
+  }
+
+  if (hasSizeMode && ![0, 1, 2, 3].includes(field.sizeMode)) {
+    return '.sizeMode must be 0, 1, 2, or 3';
+  }
+
+  if (!(field.payload instanceof ArrayBuffer)) {
+    return '.payload must be an ArrayBuffer';
+  }
+
+  return undefined;
+}
+
+export function safeNumberFromBigint(value: bigint): number {
+  if (value > 9_007_199_254_740_991n || value < -9_007_199_254_740_991n) {
+    throw new Error('Integer cannot be represented exactly as a number.');
+  }
+
+  return Number(value);
+}
+
+export function clampedNumberFromBigint(value: bigint): number {
+  if (value > 9_007_199_254_740_991n) {
+    return Number.MAX_SAFE_INTEGER;
+  }
+
+  if (value < -9_007_199_254_740_991n) {
+    return Number.MIN_SAFE_INTEGER;
+  }
+
+  return Number(value);
+}
+
 export const missingFieldsErrorMessage = 'Struct missing one or more required field(s).';
 export const textEncoder = new TextEncoder();
 export const textDecoder = new TextDecoder('utf-8', { fatal: true, ignoreBOM: true });
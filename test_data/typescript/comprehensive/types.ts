@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -58,7 +62,7 @@ function localStructDeserializeUnsafe(dataView: DataView): LocalStructIn {
 
   let offset = 0;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -99,6 +103,14 @@ function localStructDeserialize(bytes: Deserializable): LocalStructIn | Error {
   }
 }
 
+function localStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return undefined;
+}
+
 export const LocalStruct = {
   atlas: localStructAtlas,
   size: localStructSize,
@@ -106,8 +118,20 @@ export const LocalStruct = {
   deserializeUnsafe: localStructDeserializeUnsafe,
   serialize: localStructSerialize,
   deserialize: localStructDeserialize,
+  checkOut: localStructCheckOut,
 };
 
+export function isLocalStructOut(value: unknown): value is LocalStructOut {
+  return localStructCheckOut(value) === undefined;
+}
+
+export function assertLocalStructOut(value: unknown): asserts value is LocalStructOut {
+  const error = localStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid LocalStructOut: value' + error + '.');
+  }
+}
+
 export type FooAtlas = {
   $size: number;
   aRequired: number;
@@ -4010,7 +4034,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
   let $zOptional: LocalStructIn[][] | undefined;
   let $aaOptional: _Types.EmptyStructIn[][] | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -4026,26 +4050,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
 
     switch (index) {
       case 0n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         $aRequired = payload;
         break;
       }
       case 1n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: number;
         {
           switch (payloadSize) {
@@ -4058,18 +4067,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $bRequired = payload;
         break;
       }
       case 2n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -4085,18 +4086,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $cRequired = payload;
         break;
       }
       case 3n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -4113,18 +4106,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
         }
         payload = zigzagDecode(payload);
-        offset += oldOffset;
         $dRequired = payload;
         break;
       }
       case 4n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: boolean;
         {
           let newPayload: boolean;
@@ -4148,7 +4133,6 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
           payload = newPayload;
         }
-        offset += oldOffset;
         $eRequired = payload;
         break;
       }
@@ -4177,13 +4161,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $gRequired = payload;
@@ -4264,7 +4242,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload = dataView.getFloat64(offset, true);
                 offset += 8;
@@ -4295,7 +4273,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -4326,7 +4304,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -4358,7 +4336,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: boolean;
                 {
@@ -4398,10 +4376,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4412,7 +4390,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4443,10 +4421,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4457,17 +4435,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
-              );
+              let payload = textDecoder.decode(dataView);
               offset = dataView.byteLength;
               offset += oldOffset;
               payloadAlias.push(payload);
@@ -4491,10 +4463,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4505,7 +4477,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4533,10 +4505,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4547,7 +4519,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4575,10 +4547,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4589,7 +4561,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4625,10 +4597,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4639,7 +4611,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4647,7 +4619,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload = dataView.getFloat64(offset, true);
                       offset += 8;
@@ -4684,10 +4656,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4698,7 +4670,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4706,7 +4678,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -4743,10 +4715,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4757,7 +4729,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4765,7 +4737,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -4803,10 +4775,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4817,7 +4789,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4825,7 +4797,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: boolean;
                       {
@@ -4870,10 +4842,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4884,7 +4856,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4893,10 +4865,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -4907,7 +4879,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -4943,10 +4915,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -4957,7 +4929,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -4966,10 +4938,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -4980,17 +4952,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
+                    let payload = textDecoder.decode(dataView);
                     offset = dataView.byteLength;
                     offset += oldOffset;
                     payloadAlias.push(payload);
@@ -5019,10 +4985,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5033,7 +4999,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5042,10 +5008,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -5056,7 +5022,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -5089,10 +5055,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5103,7 +5069,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5112,10 +5078,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -5126,7 +5092,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -5147,26 +5113,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         break;
       }
       case 28n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         $aAsymmetric = payload;
         break;
       }
       case 29n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: number;
         {
           switch (payloadSize) {
@@ -5179,18 +5130,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $bAsymmetric = payload;
         break;
       }
       case 30n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -5206,18 +5149,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $cAsymmetric = payload;
         break;
       }
       case 31n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -5234,18 +5169,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
         }
         payload = zigzagDecode(payload);
-        offset += oldOffset;
         $dAsymmetric = payload;
         break;
       }
       case 32n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: boolean;
         {
           let newPayload: boolean;
@@ -5269,7 +5196,6 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
           payload = newPayload;
         }
-        offset += oldOffset;
         $eAsymmetric = payload;
         break;
       }
@@ -5298,13 +5224,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $gAsymmetric = payload;
@@ -5385,7 +5305,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload = dataView.getFloat64(offset, true);
                 offset += 8;
@@ -5416,7 +5336,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -5447,7 +5367,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -5479,7 +5399,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: boolean;
                 {
@@ -5519,10 +5439,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5533,7 +5453,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5564,10 +5484,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5578,17 +5498,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
-              );
+              let payload = textDecoder.decode(dataView);
               offset = dataView.byteLength;
               offset += oldOffset;
               payloadAlias.push(payload);
@@ -5612,10 +5526,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5626,7 +5540,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5654,10 +5568,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5668,7 +5582,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5696,10 +5610,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5710,7 +5624,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5746,10 +5660,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5760,7 +5674,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5768,7 +5682,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload = dataView.getFloat64(offset, true);
                       offset += 8;
@@ -5805,10 +5719,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5819,7 +5733,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5827,7 +5741,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -5864,10 +5778,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5878,7 +5792,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5886,7 +5800,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -5924,10 +5838,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -5938,7 +5852,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -5946,7 +5860,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: boolean;
                       {
@@ -5991,10 +5905,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6005,7 +5919,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6014,10 +5928,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -6028,7 +5942,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -6064,10 +5978,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6078,7 +5992,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6087,10 +6001,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -6101,17 +6015,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
+                    let payload = textDecoder.decode(dataView);
                     offset = dataView.byteLength;
                     offset += oldOffset;
                     payloadAlias.push(payload);
@@ -6140,10 +6048,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6154,7 +6062,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6163,10 +6071,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -6177,7 +6085,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -6210,10 +6118,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6224,7 +6132,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6233,10 +6141,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -6247,7 +6155,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -6268,26 +6176,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         break;
       }
       case 56n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         $aOptional = payload;
         break;
       }
       case 57n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: number;
         {
           switch (payloadSize) {
@@ -6300,18 +6193,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $bOptional = payload;
         break;
       }
       case 58n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -6327,18 +6212,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               break;
           }
         }
-        offset += oldOffset;
         $cOptional = payload;
         break;
       }
       case 59n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -6355,18 +6232,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
         }
         payload = zigzagDecode(payload);
-        offset += oldOffset;
         $dOptional = payload;
         break;
       }
       case 60n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload: boolean;
         {
           let newPayload: boolean;
@@ -6390,7 +6259,6 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           }
           payload = newPayload;
         }
-        offset += oldOffset;
         $eOptional = payload;
         break;
       }
@@ -6419,13 +6287,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $gOptional = payload;
@@ -6506,7 +6368,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload = dataView.getFloat64(offset, true);
                 offset += 8;
@@ -6537,7 +6399,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -6568,7 +6430,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -6600,7 +6462,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: boolean;
                 {
@@ -6640,10 +6502,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6654,7 +6516,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6685,10 +6547,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6699,17 +6561,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
-              );
+              let payload = textDecoder.decode(dataView);
               offset = dataView.byteLength;
               offset += oldOffset;
               payloadAlias.push(payload);
@@ -6733,10 +6589,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6747,7 +6603,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6775,10 +6631,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6789,7 +6645,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6817,10 +6673,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6831,7 +6687,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6867,10 +6723,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6881,7 +6737,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6889,7 +6745,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload = dataView.getFloat64(offset, true);
                       offset += 8;
@@ -6926,10 +6782,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6940,7 +6796,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -6948,7 +6804,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -6985,10 +6841,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -6999,7 +6855,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7007,7 +6863,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -7045,10 +6901,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -7059,7 +6915,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7067,7 +6923,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: boolean;
                       {
@@ -7112,10 +6968,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -7126,7 +6982,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7135,10 +6991,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -7149,7 +7005,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -7185,10 +7041,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -7199,7 +7055,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7208,10 +7064,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -7222,17 +7078,11 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
+                    let payload = textDecoder.decode(dataView);
                     offset = dataView.byteLength;
                     offset += oldOffset;
                     payloadAlias.push(payload);
@@ -7261,10 +7111,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -7275,7 +7125,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7284,10 +7134,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -7298,7 +7148,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -7331,10 +7181,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -7345,7 +7195,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -7354,10 +7204,10 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -7368,7 +7218,7 @@ function fooDeserializeUnsafe(dataView: DataView): FooIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -7525,4363 +7375,6801 @@ function fooDeserialize(bytes: Deserializable): FooIn | Error {
   }
 }
 
-export const Foo = {
-  atlas: fooAtlas,
-  size: fooSize,
-  serializeWithAtlasUnsafe: fooSerializeWithAtlasUnsafe,
-  deserializeUnsafe: fooDeserializeUnsafe,
-  serialize: fooSerialize,
-  deserialize: fooDeserialize,
-};
+function fooCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
 
-export type BarAtlas =
-  | { $size: number; aRequired: number }
-  | { $size: number; bRequired: number }
-  | { $size: number; cRequired: number }
-  | { $size: number; dRequired: number }
-  | { $size: number; eRequired: number }
-  | { $size: number; fRequired: number }
-  | { $size: number; gRequired: Uint8Array }
-  | { $size: number; hRequired: LocalStructAtlas }
-  | { $size: number; iRequired: _Types.EmptyStructAtlas }
-  | { $size: number; jRequired: number }
-  | { $size: number; kRequired: number }
-  | { $size: number; lRequired: number }
-  | { $size: number; mRequired: number }
-  | { $size: number; nRequired: number }
-  | { $size: number; oRequired: { $size: number; $elements: number[] } }
-  | { $size: number; pRequired: { $size: number; $elements: Uint8Array[] } }
-  | { $size: number; qRequired: { $size: number; $elements: LocalStructAtlas[] } }
-  | { $size: number; rRequired: { $size: number; $elements: _Types.EmptyStructAtlas[] } }
-  | { $size: number; sRequired: { $size: number; $elements: number[] } }
-  | { $size: number; tRequired: { $size: number; $elements: number[] } }
-  | { $size: number; uRequired: { $size: number; $elements: number[] } }
-  | { $size: number; vRequired: { $size: number; $elements: number[] } }
-  | { $size: number; wRequired: { $size: number; $elements: number[] } }
-  | { $size: number; xRequired: { $size: number; $elements: { $size: number; $elements: number[] }[] } }
-  | { $size: number; yRequired: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] } }
-  | { $size: number; zRequired: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] } }
-  | { $size: number; aaRequired: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] } }
-  | { $size: number; aAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; bAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; cAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; dAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; eAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; fAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; gAsymmetric: Uint8Array; $fallback: BarAtlas }
-  | { $size: number; hAsymmetric: LocalStructAtlas; $fallback: BarAtlas }
-  | { $size: number; iAsymmetric: _Types.EmptyStructAtlas; $fallback: BarAtlas }
-  | { $size: number; jAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; kAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; lAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; mAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; nAsymmetric: number; $fallback: BarAtlas }
-  | { $size: number; oAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; pAsymmetric: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
-  | { $size: number; qAsymmetric: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
-  | { $size: number; rAsymmetric: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
-  | { $size: number; sAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; tAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; uAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; vAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; wAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; xAsymmetric: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
-  | { $size: number; yAsymmetric: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
-  | { $size: number; zAsymmetric: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
-  | { $size: number; aaAsymmetric: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas }
-  | { $size: number; aOptional: number; $fallback: BarAtlas }
-  | { $size: number; bOptional: number; $fallback: BarAtlas }
-  | { $size: number; cOptional: number; $fallback: BarAtlas }
-  | { $size: number; dOptional: number; $fallback: BarAtlas }
-  | { $size: number; eOptional: number; $fallback: BarAtlas }
-  | { $size: number; fOptional: number; $fallback: BarAtlas }
-  | { $size: number; gOptional: Uint8Array; $fallback: BarAtlas }
-  | { $size: number; hOptional: LocalStructAtlas; $fallback: BarAtlas }
-  | { $size: number; iOptional: _Types.EmptyStructAtlas; $fallback: BarAtlas }
-  | { $size: number; jOptional: number; $fallback: BarAtlas }
-  | { $size: number; kOptional: number; $fallback: BarAtlas }
-  | { $size: number; lOptional: number; $fallback: BarAtlas }
-  | { $size: number; mOptional: number; $fallback: BarAtlas }
-  | { $size: number; nOptional: number; $fallback: BarAtlas }
-  | { $size: number; oOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; pOptional: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
-  | { $size: number; qOptional: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
-  | { $size: number; rOptional: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
-  | { $size: number; sOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; tOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; uOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; vOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; wOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
-  | { $size: number; xOptional: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
-  | { $size: number; yOptional: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
-  | { $size: number; zOptional: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
-  | { $size: number; aaOptional: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas };
+  const message = value as any;
 
-export type BarOut =
-  | { aRequired: null }
-  | { bRequired: number }
-  | { cRequired: bigint }
-  | { dRequired: bigint }
-  | { eRequired: boolean }
-  | { fRequired: ArrayBuffer }
-  | { gRequired: string }
-  | { hRequired: LocalStructOut }
-  | { iRequired: _Types.EmptyStructOut }
-  | { jRequired: null[] }
-  | { kRequired: number[] }
-  | { lRequired: bigint[] }
-  | { mRequired: bigint[] }
-  | { nRequired: boolean[] }
-  | { oRequired: ArrayBuffer[] }
-  | { pRequired: string[] }
-  | { qRequired: LocalStructOut[] }
-  | { rRequired: _Types.EmptyStructOut[] }
-  | { sRequired: null[][] }
-  | { tRequired: number[][] }
-  | { uRequired: bigint[][] }
-  | { vRequired: bigint[][] }
-  | { wRequired: boolean[][] }
-  | { xRequired: ArrayBuffer[][] }
-  | { yRequired: string[][] }
-  | { zRequired: LocalStructOut[][] }
-  | { aaRequired: _Types.EmptyStructOut[][] }
-  | { aAsymmetric: null; $fallback: BarOut }
-  | { bAsymmetric: number; $fallback: BarOut }
-  | { cAsymmetric: bigint; $fallback: BarOut }
-  | { dAsymmetric: bigint; $fallback: BarOut }
-  | { eAsymmetric: boolean; $fallback: BarOut }
-  | { fAsymmetric: ArrayBuffer; $fallback: BarOut }
-  | { gAsymmetric: string; $fallback: BarOut }
-  | { hAsymmetric: LocalStructOut; $fallback: BarOut }
-  | { iAsymmetric: _Types.EmptyStructOut; $fallback: BarOut }
-  | { jAsymmetric: null[]; $fallback: BarOut }
-  | { kAsymmetric: number[]; $fallback: BarOut }
-  | { lAsymmetric: bigint[]; $fallback: BarOut }
-  | { mAsymmetric: bigint[]; $fallback: BarOut }
-  | { nAsymmetric: boolean[]; $fallback: BarOut }
-  | { oAsymmetric: ArrayBuffer[]; $fallback: BarOut }
-  | { pAsymmetric: string[]; $fallback: BarOut }
-  | { qAsymmetric: LocalStructOut[]; $fallback: BarOut }
-  | { rAsymmetric: _Types.EmptyStructOut[]; $fallback: BarOut }
-  | { sAsymmetric: null[][]; $fallback: BarOut }
-  | { tAsymmetric: number[][]; $fallback: BarOut }
-  | { uAsymmetric: bigint[][]; $fallback: BarOut }
-  | { vAsymmetric: bigint[][]; $fallback: BarOut }
-  | { wAsymmetric: boolean[][]; $fallback: BarOut }
-  | { xAsymmetric: ArrayBuffer[][]; $fallback: BarOut }
-  | { yAsymmetric: string[][]; $fallback: BarOut }
-  | { zAsymmetric: LocalStructOut[][]; $fallback: BarOut }
-  | { aaAsymmetric: _Types.EmptyStructOut[][]; $fallback: BarOut }
-  | { aOptional: null; $fallback: BarOut }
-  | { bOptional: number; $fallback: BarOut }
-  | { cOptional: bigint; $fallback: BarOut }
-  | { dOptional: bigint; $fallback: BarOut }
-  | { eOptional: boolean; $fallback: BarOut }
-  | { fOptional: ArrayBuffer; $fallback: BarOut }
-  | { gOptional: string; $fallback: BarOut }
-  | { hOptional: LocalStructOut; $fallback: BarOut }
-  | { iOptional: _Types.EmptyStructOut; $fallback: BarOut }
-  | { jOptional: null[]; $fallback: BarOut }
-  | { kOptional: number[]; $fallback: BarOut }
-  | { lOptional: bigint[]; $fallback: BarOut }
-  | { mOptional: bigint[]; $fallback: BarOut }
-  | { nOptional: boolean[]; $fallback: BarOut }
-  | { oOptional: ArrayBuffer[]; $fallback: BarOut }
-  | { pOptional: string[]; $fallback: BarOut }
-  | { qOptional: LocalStructOut[]; $fallback: BarOut }
-  | { rOptional: _Types.EmptyStructOut[]; $fallback: BarOut }
-  | { sOptional: null[][]; $fallback: BarOut }
-  | { tOptional: number[][]; $fallback: BarOut }
-  | { uOptional: bigint[][]; $fallback: BarOut }
-  | { vOptional: bigint[][]; $fallback: BarOut }
-  | { wOptional: boolean[][]; $fallback: BarOut }
-  | { xOptional: ArrayBuffer[][]; $fallback: BarOut }
-  | { yOptional: string[][]; $fallback: BarOut }
-  | { zOptional: LocalStructOut[][]; $fallback: BarOut }
-  | { aaOptional: _Types.EmptyStructOut[][]; $fallback: BarOut };
-
-export type BarIn =
-  | { $field: 'aRequired'; aRequired: null }
-  | { $field: 'bRequired'; bRequired: number }
-  | { $field: 'cRequired'; cRequired: bigint }
-  | { $field: 'dRequired'; dRequired: bigint }
-  | { $field: 'eRequired'; eRequired: boolean }
-  | { $field: 'fRequired'; fRequired: ArrayBuffer }
-  | { $field: 'gRequired'; gRequired: string }
-  | { $field: 'hRequired'; hRequired: LocalStructIn }
-  | { $field: 'iRequired'; iRequired: _Types.EmptyStructIn }
-  | { $field: 'jRequired'; jRequired: null[] }
-  | { $field: 'kRequired'; kRequired: number[] }
-  | { $field: 'lRequired'; lRequired: bigint[] }
-  | { $field: 'mRequired'; mRequired: bigint[] }
-  | { $field: 'nRequired'; nRequired: boolean[] }
-  | { $field: 'oRequired'; oRequired: ArrayBuffer[] }
-  | { $field: 'pRequired'; pRequired: string[] }
-  | { $field: 'qRequired'; qRequired: LocalStructIn[] }
-  | { $field: 'rRequired'; rRequired: _Types.EmptyStructIn[] }
-  | { $field: 'sRequired'; sRequired: null[][] }
-  | { $field: 'tRequired'; tRequired: number[][] }
-  | { $field: 'uRequired'; uRequired: bigint[][] }
-  | { $field: 'vRequired'; vRequired: bigint[][] }
-  | { $field: 'wRequired'; wRequired: boolean[][] }
-  | { $field: 'xRequired'; xRequired: ArrayBuffer[][] }
-  | { $field: 'yRequired'; yRequired: string[][] }
-  | { $field: 'zRequired'; zRequired: LocalStructIn[][] }
-  | { $field: 'aaRequired'; aaRequired: _Types.EmptyStructIn[][] }
-  | { $field: 'aAsymmetric'; aAsymmetric: null }
-  | { $field: 'bAsymmetric'; bAsymmetric: number }
-  | { $field: 'cAsymmetric'; cAsymmetric: bigint }
-  | { $field: 'dAsymmetric'; dAsymmetric: bigint }
-  | { $field: 'eAsymmetric'; eAsymmetric: boolean }
-  | { $field: 'fAsymmetric'; fAsymmetric: ArrayBuffer }
-  | { $field: 'gAsymmetric'; gAsymmetric: string }
-  | { $field: 'hAsymmetric'; hAsymmetric: LocalStructIn }
-  | { $field: 'iAsymmetric'; iAsymmetric: _Types.EmptyStructIn }
-  | { $field: 'jAsymmetric'; jAsymmetric: null[] }
-  | { $field: 'kAsymmetric'; kAsymmetric: number[] }
-  | { $field: 'lAsymmetric'; lAsymmetric: bigint[] }
-  | { $field: 'mAsymmetric'; mAsymmetric: bigint[] }
-  | { $field: 'nAsymmetric'; nAsymmetric: boolean[] }
-  | { $field: 'oAsymmetric'; oAsymmetric: ArrayBuffer[] }
-  | { $field: 'pAsymmetric'; pAsymmetric: string[] }
-  | { $field: 'qAsymmetric'; qAsymmetric: LocalStructIn[] }
-  | { $field: 'rAsymmetric'; rAsymmetric: _Types.EmptyStructIn[] }
-  | { $field: 'sAsymmetric'; sAsymmetric: null[][] }
-  | { $field: 'tAsymmetric'; tAsymmetric: number[][] }
-  | { $field: 'uAsymmetric'; uAsymmetric: bigint[][] }
-  | { $field: 'vAsymmetric'; vAsymmetric: bigint[][] }
-  | { $field: 'wAsymmetric'; wAsymmetric: boolean[][] }
-  | { $field: 'xAsymmetric'; xAsymmetric: ArrayBuffer[][] }
-  | { $field: 'yAsymmetric'; yAsymmetric: string[][] }
-  | { $field: 'zAsymmetric'; zAsymmetric: LocalStructIn[][] }
-  | { $field: 'aaAsymmetric'; aaAsymmetric: _Types.EmptyStructIn[][] }
-  | { $field: 'aOptional'; aOptional: null; $fallback: BarIn }
-  | { $field: 'bOptional'; bOptional: number; $fallback: BarIn }
-  | { $field: 'cOptional'; cOptional: bigint; $fallback: BarIn }
-  | { $field: 'dOptional'; dOptional: bigint; $fallback: BarIn }
-  | { $field: 'eOptional'; eOptional: boolean; $fallback: BarIn }
-  | { $field: 'fOptional'; fOptional: ArrayBuffer; $fallback: BarIn }
-  | { $field: 'gOptional'; gOptional: string; $fallback: BarIn }
-  | { $field: 'hOptional'; hOptional: LocalStructIn; $fallback: BarIn }
-  | { $field: 'iOptional'; iOptional: _Types.EmptyStructIn; $fallback: BarIn }
-  | { $field: 'jOptional'; jOptional: null[]; $fallback: BarIn }
-  | { $field: 'kOptional'; kOptional: number[]; $fallback: BarIn }
-  | { $field: 'lOptional'; lOptional: bigint[]; $fallback: BarIn }
-  | { $field: 'mOptional'; mOptional: bigint[]; $fallback: BarIn }
-  | { $field: 'nOptional'; nOptional: boolean[]; $fallback: BarIn }
-  | { $field: 'oOptional'; oOptional: ArrayBuffer[]; $fallback: BarIn }
-  | { $field: 'pOptional'; pOptional: string[]; $fallback: BarIn }
-  | { $field: 'qOptional'; qOptional: LocalStructIn[]; $fallback: BarIn }
-  | { $field: 'rOptional'; rOptional: _Types.EmptyStructIn[]; $fallback: BarIn }
-  | { $field: 'sOptional'; sOptional: null[][]; $fallback: BarIn }
-  | { $field: 'tOptional'; tOptional: number[][]; $fallback: BarIn }
-  | { $field: 'uOptional'; uOptional: bigint[][]; $fallback: BarIn }
-  | { $field: 'vOptional'; vOptional: bigint[][]; $fallback: BarIn }
-  | { $field: 'wOptional'; wOptional: boolean[][]; $fallback: BarIn }
-  | { $field: 'xOptional'; xOptional: ArrayBuffer[][]; $fallback: BarIn }
-  | { $field: 'yOptional'; yOptional: string[][]; $fallback: BarIn }
-  | { $field: 'zOptional'; zOptional: LocalStructIn[][]; $fallback: BarIn }
-  | { $field: 'aaOptional'; aaOptional: _Types.EmptyStructIn[][]; $fallback: BarIn };
-
-function barAtlas(message: BarOut): BarAtlas {
-  if ('aRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.aRequired;
-    payloadAtlas = 0;
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, aRequired: payloadAtlas };
+    if (payload !== null) {
+      return '.aRequired must be null';
+    }
   }
 
-  if ('bRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.bRequired;
-    if (Object.is(payload, 0)) {
-      payloadAtlas = 0;
-    } else {
-      payloadAtlas = 8;
+    if (typeof payload !== 'number') {
+      return '.bRequired must be a number';
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize, bRequired: payloadAtlas };
   }
 
-  if ('cRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.cRequired;
-    if (payload === 0n) {
-      payloadAtlas = 0;
-    } else if (payload < 567_382_630_219_904n) {
-      payloadAtlas = varintSizeFromValue(payload);
-    } else {
-      payloadAtlas = 8;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cRequired must be a bigint from 0 to 18446744073709551615';
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(2n, payloadSize, true) + payloadSize, cRequired: payloadAtlas };
   }
 
-  if ('dRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.dRequired;
-    {
-      const zigzag = zigzagEncode(payload);
-      if (zigzag === 0n) {
-        payloadAtlas = 0;
-      } else if (zigzag < 567_382_630_219_904n) {
-        payloadAtlas = varintSizeFromValue(zigzag);
-      } else {
-        payloadAtlas = 8;
-      }
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dRequired must be a bigint from -9223372036854775808 to 9223372036854775807';
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(3n, payloadSize, true) + payloadSize, dRequired: payloadAtlas };
   }
 
-  if ('eRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.eRequired;
-    if (payload) {
-      payloadAtlas = 1;
-    } else {
-      payloadAtlas = 0;
+    if (typeof payload !== 'boolean') {
+      return '.eRequired must be a boolean';
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(4n, payloadSize, true) + payloadSize, eRequired: payloadAtlas };
   }
 
-  if ('fRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.fRequired;
-    payloadAtlas = payload.byteLength;
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(5n, payloadSize, false) + payloadSize, fRequired: payloadAtlas };
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fRequired must be an ArrayBuffer';
+    }
   }
 
-  if ('gRequired' in message) {
-    let payloadAtlas: Uint8Array;
+  {
     const payload = message.gRequired;
-    payloadAtlas = textEncoder.encode(payload);
-    const payloadSize = payloadAtlas.byteLength;
-    return { $size: fieldHeaderSize(6n, payloadSize, false) + payloadSize, gRequired: payloadAtlas };
+    if (typeof payload !== 'string') {
+      return '.gRequired must be a string';
+    }
   }
 
-  if ('hRequired' in message) {
-    let payloadAtlas: LocalStructAtlas;
+  {
     const payload = message.hRequired;
-    payloadAtlas = LocalStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    return { $size: fieldHeaderSize(7n, payloadSize, false) + payloadSize, hRequired: payloadAtlas };
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hRequired' + error;
+    }
   }
 
-  if ('iRequired' in message) {
-    let payloadAtlas: _Types.EmptyStructAtlas;
+  {
     const payload = message.iRequired;
-    payloadAtlas = _Types.EmptyStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    return { $size: fieldHeaderSize(8n, payloadSize, false) + payloadSize, iRequired: payloadAtlas };
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iRequired' + error;
+    }
   }
 
-  if ('jRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.jRequired;
+    if (!Array.isArray(payload)) {
+      return '.jRequired must be an array';
+    }
     {
       const oldPayload = payload;
-      {
-        const payload = BigInt(oldPayload.length);
-        if (payload === 0n) {
-          payloadAtlas = 0;
-        } else if (payload < 567_382_630_219_904n) {
-          payloadAtlas = varintSizeFromValue(payload);
-        } else {
-          payloadAtlas = 8;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jRequired[' + i0 + '] must be null';
         }
       }
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(9n, payloadSize, false) + payloadSize, jRequired: payloadAtlas };
   }
 
-  if ('kRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.kRequired;
-    payloadAtlas = 8 * payload.length;
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(10n, payloadSize, false) + payloadSize, kRequired: payloadAtlas };
-  }
-
-  if ('lRequired' in message) {
-    let payloadAtlas: number;
+    if (!Array.isArray(payload)) {
+      return '.kRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kRequired[' + i0 + '] must be a number';
+        }
+      }
+    }
+  }
+
+  {
     const payload = message.lRequired;
+    if (!Array.isArray(payload)) {
+      return '.lRequired must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(payload);
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lRequired[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(11n, payloadSize, false) + payloadSize, lRequired: payloadAtlas };
   }
 
-  if ('mRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.mRequired;
+    if (!Array.isArray(payload)) {
+      return '.mRequired must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mRequired[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(12n, payloadSize, false) + payloadSize, mRequired: payloadAtlas };
   }
 
-  if ('nRequired' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.nRequired;
+    if (!Array.isArray(payload)) {
+      return '.nRequired must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = 1;
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nRequired[' + i0 + '] must be a boolean';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    return { $size: fieldHeaderSize(13n, payloadSize, false) + payloadSize, nRequired: payloadAtlas };
   }
 
-  if ('oRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.oRequired;
+    if (!Array.isArray(payload)) {
+      return '.oRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = payload.byteLength;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oRequired[' + i0 + '] must be an ArrayBuffer';
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(14n, payloadSize, false) + payloadSize, oRequired: payloadAtlas };
   }
 
-  if ('pRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
+  {
     const payload = message.pRequired;
+    if (!Array.isArray(payload)) {
+      return '.pRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: Uint8Array[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: Uint8Array;
-        payloadAtlas = textEncoder.encode(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.byteLength;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pRequired[' + i0 + '] must be a string';
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(15n, payloadSize, false) + payloadSize, pRequired: payloadAtlas };
   }
 
-  if ('qRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
+  {
     const payload = message.qRequired;
+    if (!Array.isArray(payload)) {
+      return '.qRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: LocalStructAtlas[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: LocalStructAtlas;
-        payloadAtlas = LocalStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qRequired[' + i0 + ']' + error;
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(16n, payloadSize, false) + payloadSize, qRequired: payloadAtlas };
   }
 
-  if ('rRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+  {
     const payload = message.rRequired;
+    if (!Array.isArray(payload)) {
+      return '.rRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: _Types.EmptyStructAtlas[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: _Types.EmptyStructAtlas;
-        payloadAtlas = _Types.EmptyStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rRequired[' + i0 + ']' + error;
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(17n, payloadSize, false) + payloadSize, rRequired: payloadAtlas };
   }
 
-  if ('sRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.sRequired;
+    if (!Array.isArray(payload)) {
+      return '.sRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sRequired[' + i0 + '] must be an array';
+        }
         {
           const oldPayload = payload;
-          {
-            const payload = BigInt(oldPayload.length);
-            payloadAtlas = varintSizeFromValue(payload);
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sRequired[' + i0 + '][' + i1 + '] must be null';
+            }
           }
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(18n, payloadSize, false) + payloadSize, sRequired: payloadAtlas };
   }
 
-  if ('tRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.tRequired;
+    if (!Array.isArray(payload)) {
+      return '.tRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = 8 * payload.length;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tRequired[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(19n, payloadSize, false) + payloadSize, tRequired: payloadAtlas };
   }
 
-  if ('uRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.uRequired;
+    if (!Array.isArray(payload)) {
+      return '.uRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uRequired[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(payload);
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uRequired[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(20n, payloadSize, false) + payloadSize, uRequired: payloadAtlas };
   }
 
-  if ('vRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.vRequired;
+    if (!Array.isArray(payload)) {
+      return '.vRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vRequired[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vRequired[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(21n, payloadSize, false) + payloadSize, vRequired: payloadAtlas };
   }
 
-  if ('wRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.wRequired;
+    if (!Array.isArray(payload)) {
+      return '.wRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wRequired[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = 1;
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wRequired[' + i0 + '][' + i1 + '] must be a boolean';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(22n, payloadSize, false) + payloadSize, wRequired: payloadAtlas };
   }
 
-  if ('xRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
+  {
     const payload = message.xRequired;
+    if (!Array.isArray(payload)) {
+      return '.xRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: number[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: number[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xRequired[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: number[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: number;
-            payloadAtlas = payload.byteLength;
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xRequired[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(23n, payloadSize, false) + payloadSize, xRequired: payloadAtlas };
   }
 
-  if ('yRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+  {
     const payload = message.yRequired;
+    if (!Array.isArray(payload)) {
+      return '.yRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yRequired[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: Uint8Array[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: Uint8Array;
-            payloadAtlas = textEncoder.encode(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas.byteLength;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yRequired[' + i0 + '][' + i1 + '] must be a string';
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(24n, payloadSize, false) + payloadSize, yRequired: payloadAtlas };
   }
 
-  if ('zRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+  {
     const payload = message.zRequired;
+    if (!Array.isArray(payload)) {
+      return '.zRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zRequired[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: LocalStructAtlas[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: LocalStructAtlas;
-            payloadAtlas = LocalStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zRequired[' + i0 + '][' + i1 + ']' + error;
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(25n, payloadSize, false) + payloadSize, zRequired: payloadAtlas };
   }
 
-  if ('aaRequired' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+  {
     const payload = message.aaRequired;
+    if (!Array.isArray(payload)) {
+      return '.aaRequired must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaRequired[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: _Types.EmptyStructAtlas[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: _Types.EmptyStructAtlas;
-            payloadAtlas = _Types.EmptyStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaRequired[' + i0 + '][' + i1 + ']' + error;
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    return { $size: fieldHeaderSize(26n, payloadSize, false) + payloadSize, aaRequired: payloadAtlas };
   }
 
-  if ('aAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.aAsymmetric;
-    payloadAtlas = 0;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(28n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== null) {
+      return '.aAsymmetric must be null';
+    }
   }
 
-  if ('bAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.bAsymmetric;
-    if (Object.is(payload, 0)) {
-      payloadAtlas = 0;
-    } else {
-      payloadAtlas = 8;
+    if (typeof payload !== 'number') {
+      return '.bAsymmetric must be a number';
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(29n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('cAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.cAsymmetric;
-    if (payload === 0n) {
-      payloadAtlas = 0;
-    } else if (payload < 567_382_630_219_904n) {
-      payloadAtlas = varintSizeFromValue(payload);
-    } else {
-      payloadAtlas = 8;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cAsymmetric must be a bigint from 0 to 18446744073709551615';
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(30n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('dAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.dAsymmetric;
-    {
-      const zigzag = zigzagEncode(payload);
-      if (zigzag === 0n) {
-        payloadAtlas = 0;
-      } else if (zigzag < 567_382_630_219_904n) {
-        payloadAtlas = varintSizeFromValue(zigzag);
-      } else {
-        payloadAtlas = 8;
-      }
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dAsymmetric must be a bigint from -9223372036854775808 to 9223372036854775807';
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(31n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('eAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.eAsymmetric;
-    if (payload) {
-      payloadAtlas = 1;
-    } else {
-      payloadAtlas = 0;
+    if (typeof payload !== 'boolean') {
+      return '.eAsymmetric must be a boolean';
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(32n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('fAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.fAsymmetric;
-    payloadAtlas = payload.byteLength;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(33n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fAsymmetric must be an ArrayBuffer';
+    }
   }
 
-  if ('gAsymmetric' in message) {
-    let payloadAtlas: Uint8Array;
+  {
     const payload = message.gAsymmetric;
-    payloadAtlas = textEncoder.encode(payload);
-    const payloadSize = payloadAtlas.byteLength;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(34n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    if (typeof payload !== 'string') {
+      return '.gAsymmetric must be a string';
+    }
   }
 
-  if ('hAsymmetric' in message) {
-    let payloadAtlas: LocalStructAtlas;
+  {
     const payload = message.hAsymmetric;
-    payloadAtlas = LocalStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(35n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hAsymmetric' + error;
+    }
   }
 
-  if ('iAsymmetric' in message) {
-    let payloadAtlas: _Types.EmptyStructAtlas;
+  {
     const payload = message.iAsymmetric;
-    payloadAtlas = _Types.EmptyStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(36n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iAsymmetric' + error;
+    }
   }
 
-  if ('jAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.jAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.jAsymmetric must be an array';
+    }
     {
       const oldPayload = payload;
-      {
-        const payload = BigInt(oldPayload.length);
-        if (payload === 0n) {
-          payloadAtlas = 0;
-        } else if (payload < 567_382_630_219_904n) {
-          payloadAtlas = varintSizeFromValue(payload);
-        } else {
-          payloadAtlas = 8;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jAsymmetric[' + i0 + '] must be null';
         }
       }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(37n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('kAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.kAsymmetric;
-    payloadAtlas = 8 * payload.length;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(38n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
+    if (!Array.isArray(payload)) {
+      return '.kAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kAsymmetric[' + i0 + '] must be a number';
+        }
+      }
+    }
   }
 
-  if ('lAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.lAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.lAsymmetric must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(payload);
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lAsymmetric[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(39n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('mAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.mAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.mAsymmetric must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mAsymmetric[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(40n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('nAsymmetric' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.nAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.nAsymmetric must be an array';
+    }
     {
-      let arraySize = 0;
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = 1;
-        arraySize += payloadAtlas;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nAsymmetric[' + i0 + '] must be a boolean';
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(41n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('oAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.oAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.oAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = payload.byteLength;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oAsymmetric[' + i0 + '] must be an ArrayBuffer';
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(42n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('pAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
+  {
     const payload = message.pAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.pAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: Uint8Array[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: Uint8Array;
-        payloadAtlas = textEncoder.encode(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.byteLength;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pAsymmetric[' + i0 + '] must be a string';
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(43n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('qAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
+  {
     const payload = message.qAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.qAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: LocalStructAtlas[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: LocalStructAtlas;
-        payloadAtlas = LocalStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qAsymmetric[' + i0 + ']' + error;
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(44n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('rAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+  {
     const payload = message.rAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.rAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: _Types.EmptyStructAtlas[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: _Types.EmptyStructAtlas;
-        payloadAtlas = _Types.EmptyStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rAsymmetric[' + i0 + ']' + error;
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(45n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('sAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.sAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.sAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sAsymmetric[' + i0 + '] must be an array';
+        }
         {
           const oldPayload = payload;
-          {
-            const payload = BigInt(oldPayload.length);
-            payloadAtlas = varintSizeFromValue(payload);
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sAsymmetric[' + i0 + '][' + i1 + '] must be null';
+            }
           }
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(46n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('tAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.tAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.tAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = 8 * payload.length;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tAsymmetric[' + i0 + '][' + i1 + '] must be a number';
+            }
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(47n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('uAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.uAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.uAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(payload);
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(48n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('vAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.vAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.vAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(49n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('wAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.wAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.wAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: number[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let arraySize = 0;
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = 1;
-            arraySize += payloadAtlas;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wAsymmetric[' + i0 + '][' + i1 + '] must be a boolean';
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(50n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('xAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
+  {
     const payload = message.xAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.xAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: number[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: number[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: number[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: number;
-            payloadAtlas = payload.byteLength;
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xAsymmetric[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(51n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('yAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+  {
     const payload = message.yAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.yAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: Uint8Array[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: Uint8Array;
-            payloadAtlas = textEncoder.encode(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas.byteLength;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yAsymmetric[' + i0 + '][' + i1 + '] must be a string';
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(52n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('zAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+  {
     const payload = message.zAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.zAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: LocalStructAtlas[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: LocalStructAtlas;
-            payloadAtlas = LocalStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(53n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('aaAsymmetric' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+  {
     const payload = message.aaAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.aaAsymmetric must be an array';
+    }
     {
-      let size = 0;
-      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
       const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          let size = 0;
-          let elements: _Types.EmptyStructAtlas[] = [];
           const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: _Types.EmptyStructAtlas;
-            payloadAtlas = _Types.EmptyStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(54n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('aOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.aOptional;
-    payloadAtlas = 0;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(56n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      if (payload !== null) {
+        return '.aOptional must be null';
+      }
+    }
   }
 
-  if ('bOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.bOptional;
-    if (Object.is(payload, 0)) {
-      payloadAtlas = 0;
-    } else {
-      payloadAtlas = 8;
+    if (payload !== undefined) {
+      if (typeof payload !== 'number') {
+        return '.bOptional must be a number';
+      }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(57n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('cOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.cOptional;
-    if (payload === 0n) {
-      payloadAtlas = 0;
-    } else if (payload < 567_382_630_219_904n) {
-      payloadAtlas = varintSizeFromValue(payload);
-    } else {
-      payloadAtlas = 8;
+    if (payload !== undefined) {
+      if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+        return '.cOptional must be a bigint from 0 to 18446744073709551615';
+      }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(58n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('dOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.dOptional;
-    {
-      const zigzag = zigzagEncode(payload);
-      if (zigzag === 0n) {
-        payloadAtlas = 0;
-      } else if (zigzag < 567_382_630_219_904n) {
-        payloadAtlas = varintSizeFromValue(zigzag);
-      } else {
-        payloadAtlas = 8;
+    if (payload !== undefined) {
+      if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+        return '.dOptional must be a bigint from -9223372036854775808 to 9223372036854775807';
       }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(59n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('eOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.eOptional;
-    if (payload) {
-      payloadAtlas = 1;
-    } else {
-      payloadAtlas = 0;
+    if (payload !== undefined) {
+      if (typeof payload !== 'boolean') {
+        return '.eOptional must be a boolean';
+      }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(60n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('fOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.fOptional;
-    payloadAtlas = payload.byteLength;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(61n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      if (!(payload instanceof ArrayBuffer)) {
+        return '.fOptional must be an ArrayBuffer';
+      }
+    }
   }
 
-  if ('gOptional' in message) {
-    let payloadAtlas: Uint8Array;
+  {
     const payload = message.gOptional;
-    payloadAtlas = textEncoder.encode(payload);
-    const payloadSize = payloadAtlas.byteLength;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(62n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.gOptional must be a string';
+      }
+    }
   }
 
-  if ('hOptional' in message) {
-    let payloadAtlas: LocalStructAtlas;
+  {
     const payload = message.hOptional;
-    payloadAtlas = LocalStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(63n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      const error = LocalStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.hOptional' + error;
+      }
+    }
   }
 
-  if ('iOptional' in message) {
-    let payloadAtlas: _Types.EmptyStructAtlas;
+  {
     const payload = message.iOptional;
-    payloadAtlas = _Types.EmptyStruct.atlas(payload);
-    const payloadSize = (payloadAtlas as { $size: number }).$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(64n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      const error = _Types.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.iOptional' + error;
+      }
+    }
   }
 
-  if ('jOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.jOptional;
-    {
-      const oldPayload = payload;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.jOptional must be an array';
+      }
       {
-        const payload = BigInt(oldPayload.length);
-        if (payload === 0n) {
-          payloadAtlas = 0;
-        } else if (payload < 567_382_630_219_904n) {
-          payloadAtlas = varintSizeFromValue(payload);
-        } else {
-          payloadAtlas = 8;
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (payload !== null) {
+            return '.jOptional[' + i0 + '] must be null';
+          }
         }
       }
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(65n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('kOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.kOptional;
-    payloadAtlas = 8 * payload.length;
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(66n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kOptional: payloadAtlas, $fallback: fallbackAtlas };
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.kOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'number') {
+            return '.kOptional[' + i0 + '] must be a number';
+          }
+        }
+      }
+    }
   }
 
-  if ('lOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.lOptional;
-    {
-      let arraySize = 0;
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(payload);
-        arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.lOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+            return '.lOptional[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+          }
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(67n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('mOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.mOptional;
-    {
-      let arraySize = 0;
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-        arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.mOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+            return '.mOptional[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+          }
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(68n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('nOptional' in message) {
-    let payloadAtlas: number;
+  {
     const payload = message.nOptional;
-    {
-      let arraySize = 0;
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas = 0;
-        payloadAtlas = 1;
-        arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.nOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'boolean') {
+            return '.nOptional[' + i0 + '] must be a boolean';
+          }
+        }
       }
-      payloadAtlas = arraySize;
     }
-    const payloadSize = payloadAtlas;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(69n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('oOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.oOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = payload.byteLength;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.oOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!(payload instanceof ArrayBuffer)) {
+            return '.oOptional[' + i0 + '] must be an ArrayBuffer';
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(70n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('pOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
+  {
     const payload = message.pOptional;
-    {
-      let size = 0;
-      let elements: Uint8Array[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: Uint8Array;
-        payloadAtlas = textEncoder.encode(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.byteLength;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.pOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (typeof payload !== 'string') {
+            return '.pOptional[' + i0 + '] must be a string';
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(71n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('qOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
+  {
     const payload = message.qOptional;
-    {
-      let size = 0;
-      let elements: LocalStructAtlas[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: LocalStructAtlas;
-        payloadAtlas = LocalStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.qOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          const error = LocalStruct.checkOut(payload);
+          if (error !== undefined) {
+            return '.qOptional[' + i0 + ']' + error;
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(72n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('rOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
+  {
     const payload = message.rOptional;
-    {
-      let size = 0;
-      let elements: _Types.EmptyStructAtlas[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: _Types.EmptyStructAtlas;
-        payloadAtlas = _Types.EmptyStruct.atlas(payload);
-        elements.push(payloadAtlas);
-        const payloadSize = (payloadAtlas as { $size: number }).$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.rOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          const error = _Types.EmptyStruct.checkOut(payload);
+          if (error !== undefined) {
+            return '.rOptional[' + i0 + ']' + error;
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(73n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('sOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.sOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        {
-          const oldPayload = payload;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.sOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.sOptional[' + i0 + '] must be an array';
+          }
           {
-            const payload = BigInt(oldPayload.length);
-            payloadAtlas = varintSizeFromValue(payload);
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (payload !== null) {
+                return '.sOptional[' + i0 + '][' + i1 + '] must be null';
+              }
+            }
           }
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(74n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('tOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.tOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        payloadAtlas = 8 * payload.length;
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.tOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.tOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'number') {
+                return '.tOptional[' + i0 + '][' + i1 + '] must be a number';
+              }
+            }
+          }
+        }
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(75n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('uOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.uOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        {
-          let arraySize = 0;
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(payload);
-            arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.uOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.uOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+                return '.uOptional[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+              }
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(76n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('vOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.vOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        {
-          let arraySize = 0;
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
-            arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.vOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.vOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+                return '.vOptional[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+              }
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(77n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('wOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: number[] };
+  {
     const payload = message.wOptional;
-    {
-      let size = 0;
-      let elements: number[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: number;
-        {
-          let arraySize = 0;
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas = 0;
-            payloadAtlas = 1;
-            arraySize += payloadAtlas;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.wOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.wOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'boolean') {
+                return '.wOptional[' + i0 + '][' + i1 + '] must be a boolean';
+              }
+            }
           }
-          payloadAtlas = arraySize;
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(78n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('xOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
+  {
     const payload = message.xOptional;
-    {
-      let size = 0;
-      let elements: { $size: number; $elements: number[] }[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: number[] };
-        {
-          let size = 0;
-          let elements: number[] = [];
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: number;
-            payloadAtlas = payload.byteLength;
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.xOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.xOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (!(payload instanceof ArrayBuffer)) {
+                return '.xOptional[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
+              }
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(79n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('yOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+  {
     const payload = message.yOptional;
-    {
-      let size = 0;
-      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
-        {
-          let size = 0;
-          let elements: Uint8Array[] = [];
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: Uint8Array;
-            payloadAtlas = textEncoder.encode(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = payloadAtlas.byteLength;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.yOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.yOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              if (typeof payload !== 'string') {
+                return '.yOptional[' + i0 + '][' + i1 + '] must be a string';
+              }
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(80n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('zOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+  {
     const payload = message.zOptional;
-    {
-      let size = 0;
-      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
-        {
-          let size = 0;
-          let elements: LocalStructAtlas[] = [];
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: LocalStructAtlas;
-            payloadAtlas = LocalStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.zOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.zOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              const error = LocalStruct.checkOut(payload);
+              if (error !== undefined) {
+                return '.zOptional[' + i0 + '][' + i1 + ']' + error;
+              }
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(81n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  if ('aaOptional' in message) {
-    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+  {
     const payload = message.aaOptional;
-    {
-      let size = 0;
-      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
-        {
-          let size = 0;
-          let elements: _Types.EmptyStructAtlas[] = [];
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            let payloadAtlas: _Types.EmptyStructAtlas;
-            payloadAtlas = _Types.EmptyStruct.atlas(payload);
-            elements.push(payloadAtlas);
-            const payloadSize = (payloadAtlas as { $size: number }).$size;
-            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+    if (payload !== undefined) {
+      if (!Array.isArray(payload)) {
+        return '.aaOptional must be an array';
+      }
+      {
+        const oldPayload = payload;
+        for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+          const payload = oldPayload[i0];
+          if (!Array.isArray(payload)) {
+            return '.aaOptional[' + i0 + '] must be an array';
+          }
+          {
+            const oldPayload = payload;
+            for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+              const payload = oldPayload[i1];
+              const error = _Types.EmptyStruct.checkOut(payload);
+              if (error !== undefined) {
+                return '.aaOptional[' + i0 + '][' + i1 + ']' + error;
+              }
+            }
           }
-          payloadAtlas = { $size: size, $elements: elements };
         }
-        elements.push(payloadAtlas);
-        const payloadSize = payloadAtlas.$size;
-        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
-      payloadAtlas = { $size: size, $elements: elements };
     }
-    const payloadSize = payloadAtlas.$size;
-    const fallbackAtlas = barAtlas(message.$fallback);
-    return { $size: fieldHeaderSize(82n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
-  return unreachable(message);
+  return undefined;
 }
 
-function barSize(message: BarOut): number {
-  return barAtlas(message).$size;
+export const Foo = {
+  atlas: fooAtlas,
+  size: fooSize,
+  serializeWithAtlasUnsafe: fooSerializeWithAtlasUnsafe,
+  deserializeUnsafe: fooDeserializeUnsafe,
+  serialize: fooSerialize,
+  deserialize: fooDeserialize,
+  checkOut: fooCheckOut,
+};
+
+export function isFooOut(value: unknown): value is FooOut {
+  return fooCheckOut(value) === undefined;
 }
 
-function barSerializeWithAtlasUnsafe(
-  dataView: DataView,
-  offset: number,
-  message: BarOut,
-  atlas: BarAtlas,
-): number {
-  if ('aRequired' in message) {
-    const payload = message.aRequired;
-    const payloadAtlas = (atlas as any).aRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
-    return offset;
+export function assertFooOut(value: unknown): asserts value is FooOut {
+  const error = fooCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid FooOut: value' + error + '.');
   }
+}
 
-  if ('bRequired' in message) {
-    const payload = message.bRequired;
-    const payloadAtlas = (atlas as any).bRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
-    if (payloadAtlas !== 0) {
-      dataView.setFloat64(offset, payload, true);
-      offset += 8;
-    }
-    return offset;
-  }
+export type BarAtlas =
+  | { $size: number; aRequired: number }
+  | { $size: number; bRequired: number }
+  | { $size: number; cRequired: number }
+  | { $size: number; dRequired: number }
+  | { $size: number; eRequired: number }
+  | { $size: number; fRequired: number }
+  | { $size: number; gRequired: Uint8Array }
+  | { $size: number; hRequired: LocalStructAtlas }
+  | { $size: number; iRequired: _Types.EmptyStructAtlas }
+  | { $size: number; jRequired: number }
+  | { $size: number; kRequired: number }
+  | { $size: number; lRequired: number }
+  | { $size: number; mRequired: number }
+  | { $size: number; nRequired: number }
+  | { $size: number; oRequired: { $size: number; $elements: number[] } }
+  | { $size: number; pRequired: { $size: number; $elements: Uint8Array[] } }
+  | { $size: number; qRequired: { $size: number; $elements: LocalStructAtlas[] } }
+  | { $size: number; rRequired: { $size: number; $elements: _Types.EmptyStructAtlas[] } }
+  | { $size: number; sRequired: { $size: number; $elements: number[] } }
+  | { $size: number; tRequired: { $size: number; $elements: number[] } }
+  | { $size: number; uRequired: { $size: number; $elements: number[] } }
+  | { $size: number; vRequired: { $size: number; $elements: number[] } }
+  | { $size: number; wRequired: { $size: number; $elements: number[] } }
+  | { $size: number; xRequired: { $size: number; $elements: { $size: number; $elements: number[] }[] } }
+  | { $size: number; yRequired: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] } }
+  | { $size: number; zRequired: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] } }
+  | { $size: number; aaRequired: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] } }
+  | { $size: number; aAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; bAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; cAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; dAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; eAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; fAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; gAsymmetric: Uint8Array; $fallback: BarAtlas }
+  | { $size: number; hAsymmetric: LocalStructAtlas; $fallback: BarAtlas }
+  | { $size: number; iAsymmetric: _Types.EmptyStructAtlas; $fallback: BarAtlas }
+  | { $size: number; jAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; kAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; lAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; mAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; nAsymmetric: number; $fallback: BarAtlas }
+  | { $size: number; oAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; pAsymmetric: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
+  | { $size: number; qAsymmetric: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; rAsymmetric: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; sAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; tAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; uAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; vAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; wAsymmetric: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; xAsymmetric: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
+  | { $size: number; yAsymmetric: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
+  | { $size: number; zAsymmetric: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aaAsymmetric: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aOptional: number; $fallback: BarAtlas }
+  | { $size: number; bOptional: number; $fallback: BarAtlas }
+  | { $size: number; cOptional: number; $fallback: BarAtlas }
+  | { $size: number; dOptional: number; $fallback: BarAtlas }
+  | { $size: number; eOptional: number; $fallback: BarAtlas }
+  | { $size: number; fOptional: number; $fallback: BarAtlas }
+  | { $size: number; gOptional: Uint8Array; $fallback: BarAtlas }
+  | { $size: number; hOptional: LocalStructAtlas; $fallback: BarAtlas }
+  | { $size: number; iOptional: _Types.EmptyStructAtlas; $fallback: BarAtlas }
+  | { $size: number; jOptional: number; $fallback: BarAtlas }
+  | { $size: number; kOptional: number; $fallback: BarAtlas }
+  | { $size: number; lOptional: number; $fallback: BarAtlas }
+  | { $size: number; mOptional: number; $fallback: BarAtlas }
+  | { $size: number; nOptional: number; $fallback: BarAtlas }
+  | { $size: number; oOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; pOptional: { $size: number; $elements: Uint8Array[] }; $fallback: BarAtlas }
+  | { $size: number; qOptional: { $size: number; $elements: LocalStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; rOptional: { $size: number; $elements: _Types.EmptyStructAtlas[] }; $fallback: BarAtlas }
+  | { $size: number; sOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; tOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; uOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; vOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; wOptional: { $size: number; $elements: number[] }; $fallback: BarAtlas }
+  | { $size: number; xOptional: { $size: number; $elements: { $size: number; $elements: number[] }[] }; $fallback: BarAtlas }
+  | { $size: number; yOptional: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] }; $fallback: BarAtlas }
+  | { $size: number; zOptional: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] }; $fallback: BarAtlas }
+  | { $size: number; aaOptional: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] }; $fallback: BarAtlas };
 
-  if ('cRequired' in message) {
-    const payload = message.cRequired;
-    const payloadAtlas = (atlas as any).cRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, true);
-    {
-      const varint = payload;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
+export type BarOut =
+  | { aRequired: null }
+  | { bRequired: number }
+  | { cRequired: bigint }
+  | { dRequired: bigint }
+  | { eRequired: boolean }
+  | { fRequired: ArrayBuffer }
+  | { gRequired: string }
+  | { hRequired: LocalStructOut }
+  | { iRequired: _Types.EmptyStructOut }
+  | { jRequired: null[] }
+  | { kRequired: number[] }
+  | { lRequired: bigint[] }
+  | { mRequired: bigint[] }
+  | { nRequired: boolean[] }
+  | { oRequired: ArrayBuffer[] }
+  | { pRequired: string[] }
+  | { qRequired: LocalStructOut[] }
+  | { rRequired: _Types.EmptyStructOut[] }
+  | { sRequired: null[][] }
+  | { tRequired: number[][] }
+  | { uRequired: bigint[][] }
+  | { vRequired: bigint[][] }
+  | { wRequired: boolean[][] }
+  | { xRequired: ArrayBuffer[][] }
+  | { yRequired: string[][] }
+  | { zRequired: LocalStructOut[][] }
+  | { aaRequired: _Types.EmptyStructOut[][] }
+  | { aAsymmetric: null; $fallback: BarOut }
+  | { bAsymmetric: number; $fallback: BarOut }
+  | { cAsymmetric: bigint; $fallback: BarOut }
+  | { dAsymmetric: bigint; $fallback: BarOut }
+  | { eAsymmetric: boolean; $fallback: BarOut }
+  | { fAsymmetric: ArrayBuffer; $fallback: BarOut }
+  | { gAsymmetric: string; $fallback: BarOut }
+  | { hAsymmetric: LocalStructOut; $fallback: BarOut }
+  | { iAsymmetric: _Types.EmptyStructOut; $fallback: BarOut }
+  | { jAsymmetric: null[]; $fallback: BarOut }
+  | { kAsymmetric: number[]; $fallback: BarOut }
+  | { lAsymmetric: bigint[]; $fallback: BarOut }
+  | { mAsymmetric: bigint[]; $fallback: BarOut }
+  | { nAsymmetric: boolean[]; $fallback: BarOut }
+  | { oAsymmetric: ArrayBuffer[]; $fallback: BarOut }
+  | { pAsymmetric: string[]; $fallback: BarOut }
+  | { qAsymmetric: LocalStructOut[]; $fallback: BarOut }
+  | { rAsymmetric: _Types.EmptyStructOut[]; $fallback: BarOut }
+  | { sAsymmetric: null[][]; $fallback: BarOut }
+  | { tAsymmetric: number[][]; $fallback: BarOut }
+  | { uAsymmetric: bigint[][]; $fallback: BarOut }
+  | { vAsymmetric: bigint[][]; $fallback: BarOut }
+  | { wAsymmetric: boolean[][]; $fallback: BarOut }
+  | { xAsymmetric: ArrayBuffer[][]; $fallback: BarOut }
+  | { yAsymmetric: string[][]; $fallback: BarOut }
+  | { zAsymmetric: LocalStructOut[][]; $fallback: BarOut }
+  | { aaAsymmetric: _Types.EmptyStructOut[][]; $fallback: BarOut }
+  | { aOptional: null; $fallback: BarOut }
+  | { bOptional: number; $fallback: BarOut }
+  | { cOptional: bigint; $fallback: BarOut }
+  | { dOptional: bigint; $fallback: BarOut }
+  | { eOptional: boolean; $fallback: BarOut }
+  | { fOptional: ArrayBuffer; $fallback: BarOut }
+  | { gOptional: string; $fallback: BarOut }
+  | { hOptional: LocalStructOut; $fallback: BarOut }
+  | { iOptional: _Types.EmptyStructOut; $fallback: BarOut }
+  | { jOptional: null[]; $fallback: BarOut }
+  | { kOptional: number[]; $fallback: BarOut }
+  | { lOptional: bigint[]; $fallback: BarOut }
+  | { mOptional: bigint[]; $fallback: BarOut }
+  | { nOptional: boolean[]; $fallback: BarOut }
+  | { oOptional: ArrayBuffer[]; $fallback: BarOut }
+  | { pOptional: string[]; $fallback: BarOut }
+  | { qOptional: LocalStructOut[]; $fallback: BarOut }
+  | { rOptional: _Types.EmptyStructOut[]; $fallback: BarOut }
+  | { sOptional: null[][]; $fallback: BarOut }
+  | { tOptional: number[][]; $fallback: BarOut }
+  | { uOptional: bigint[][]; $fallback: BarOut }
+  | { vOptional: bigint[][]; $fallback: BarOut }
+  | { wOptional: boolean[][]; $fallback: BarOut }
+  | { xOptional: ArrayBuffer[][]; $fallback: BarOut }
+  | { yOptional: string[][]; $fallback: BarOut }
+  | { zOptional: LocalStructOut[][]; $fallback: BarOut }
+  | { aaOptional: _Types.EmptyStructOut[][]; $fallback: BarOut };
+
+export type BarIn =
+  | { $field: 'aRequired'; aRequired: null }
+  | { $field: 'bRequired'; bRequired: number }
+  | { $field: 'cRequired'; cRequired: bigint }
+  | { $field: 'dRequired'; dRequired: bigint }
+  | { $field: 'eRequired'; eRequired: boolean }
+  | { $field: 'fRequired'; fRequired: ArrayBuffer }
+  | { $field: 'gRequired'; gRequired: string }
+  | { $field: 'hRequired'; hRequired: LocalStructIn }
+  | { $field: 'iRequired'; iRequired: _Types.EmptyStructIn }
+  | { $field: 'jRequired'; jRequired: null[] }
+  | { $field: 'kRequired'; kRequired: number[] }
+  | { $field: 'lRequired'; lRequired: bigint[] }
+  | { $field: 'mRequired'; mRequired: bigint[] }
+  | { $field: 'nRequired'; nRequired: boolean[] }
+  | { $field: 'oRequired'; oRequired: ArrayBuffer[] }
+  | { $field: 'pRequired'; pRequired: string[] }
+  | { $field: 'qRequired'; qRequired: LocalStructIn[] }
+  | { $field: 'rRequired'; rRequired: _Types.EmptyStructIn[] }
+  | { $field: 'sRequired'; sRequired: null[][] }
+  | { $field: 'tRequired'; tRequired: number[][] }
+  | { $field: 'uRequired'; uRequired: bigint[][] }
+  | { $field: 'vRequired'; vRequired: bigint[][] }
+  | { $field: 'wRequired'; wRequired: boolean[][] }
+  | { $field: 'xRequired'; xRequired: ArrayBuffer[][] }
+  | { $field: 'yRequired'; yRequired: string[][] }
+  | { $field: 'zRequired'; zRequired: LocalStructIn[][] }
+  | { $field: 'aaRequired'; aaRequired: _Types.EmptyStructIn[][] }
+  | { $field: 'aAsymmetric'; aAsymmetric: null }
+  | { $field: 'bAsymmetric'; bAsymmetric: number }
+  | { $field: 'cAsymmetric'; cAsymmetric: bigint }
+  | { $field: 'dAsymmetric'; dAsymmetric: bigint }
+  | { $field: 'eAsymmetric'; eAsymmetric: boolean }
+  | { $field: 'fAsymmetric'; fAsymmetric: ArrayBuffer }
+  | { $field: 'gAsymmetric'; gAsymmetric: string }
+  | { $field: 'hAsymmetric'; hAsymmetric: LocalStructIn }
+  | { $field: 'iAsymmetric'; iAsymmetric: _Types.EmptyStructIn }
+  | { $field: 'jAsymmetric'; jAsymmetric: null[] }
+  | { $field: 'kAsymmetric'; kAsymmetric: number[] }
+  | { $field: 'lAsymmetric'; lAsymmetric: bigint[] }
+  | { $field: 'mAsymmetric'; mAsymmetric: bigint[] }
+  | { $field: 'nAsymmetric'; nAsymmetric: boolean[] }
+  | { $field: 'oAsymmetric'; oAsymmetric: ArrayBuffer[] }
+  | { $field: 'pAsymmetric'; pAsymmetric: string[] }
+  | { $field: 'qAsymmetric'; qAsymmetric: LocalStructIn[] }
+  | { $field: 'rAsymmetric'; rAsymmetric: _Types.EmptyStructIn[] }
+  | { $field: 'sAsymmetric'; sAsymmetric: null[][] }
+  | { $field: 'tAsymmetric'; tAsymmetric: number[][] }
+  | { $field: 'uAsymmetric'; uAsymmetric: bigint[][] }
+  | { $field: 'vAsymmetric'; vAsymmetric: bigint[][] }
+  | { $field: 'wAsymmetric'; wAsymmetric: boolean[][] }
+  | { $field: 'xAsymmetric'; xAsymmetric: ArrayBuffer[][] }
+  | { $field: 'yAsymmetric'; yAsymmetric: string[][] }
+  | { $field: 'zAsymmetric'; zAsymmetric: LocalStructIn[][] }
+  | { $field: 'aaAsymmetric'; aaAsymmetric: _Types.EmptyStructIn[][] }
+  | { $field: 'aOptional'; aOptional: null; $fallback: BarIn }
+  | { $field: 'bOptional'; bOptional: number; $fallback: BarIn }
+  | { $field: 'cOptional'; cOptional: bigint; $fallback: BarIn }
+  | { $field: 'dOptional'; dOptional: bigint; $fallback: BarIn }
+  | { $field: 'eOptional'; eOptional: boolean; $fallback: BarIn }
+  | { $field: 'fOptional'; fOptional: ArrayBuffer; $fallback: BarIn }
+  | { $field: 'gOptional'; gOptional: string; $fallback: BarIn }
+  | { $field: 'hOptional'; hOptional: LocalStructIn; $fallback: BarIn }
+  | { $field: 'iOptional'; iOptional: _Types.EmptyStructIn; $fallback: BarIn }
+  | { $field: 'jOptional'; jOptional: null[]; $fallback: BarIn }
+  | { $field: 'kOptional'; kOptional: number[]; $fallback: BarIn }
+  | { $field: 'lOptional'; lOptional: bigint[]; $fallback: BarIn }
+  | { $field: 'mOptional'; mOptional: bigint[]; $fallback: BarIn }
+  | { $field: 'nOptional'; nOptional: boolean[]; $fallback: BarIn }
+  | { $field: 'oOptional'; oOptional: ArrayBuffer[]; $fallback: BarIn }
+  | { $field: 'pOptional'; pOptional: string[]; $fallback: BarIn }
+  | { $field: 'qOptional'; qOptional: LocalStructIn[]; $fallback: BarIn }
+  | { $field: 'rOptional'; rOptional: _Types.EmptyStructIn[]; $fallback: BarIn }
+  | { $field: 'sOptional'; sOptional: null[][]; $fallback: BarIn }
+  | { $field: 'tOptional'; tOptional: number[][]; $fallback: BarIn }
+  | { $field: 'uOptional'; uOptional: bigint[][]; $fallback: BarIn }
+  | { $field: 'vOptional'; vOptional: bigint[][]; $fallback: BarIn }
+  | { $field: 'wOptional'; wOptional: boolean[][]; $fallback: BarIn }
+  | { $field: 'xOptional'; xOptional: ArrayBuffer[][]; $fallback: BarIn }
+  | { $field: 'yOptional'; yOptional: string[][]; $fallback: BarIn }
+  | { $field: 'zOptional'; zOptional: LocalStructIn[][]; $fallback: BarIn }
+  | { $field: 'aaOptional'; aaOptional: _Types.EmptyStructIn[][]; $fallback: BarIn };
+
+function barAtlas(message: BarOut): BarAtlas {
+  if ('aRequired' in message) {
+    let payloadAtlas: number;
+    const payload = message.aRequired;
+    payloadAtlas = 0;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, aRequired: payloadAtlas };
+  }
+
+  if ('bRequired' in message) {
+    let payloadAtlas: number;
+    const payload = message.bRequired;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize, bRequired: payloadAtlas };
+  }
+
+  if ('cRequired' in message) {
+    let payloadAtlas: number;
+    const payload = message.cRequired;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(2n, payloadSize, true) + payloadSize, cRequired: payloadAtlas };
   }
 
   if ('dRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.dRequired;
-    const payloadAtlas = (atlas as any).dRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, true);
     {
-      const varint = zigzagEncode(payload);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
       }
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(3n, payloadSize, true) + payloadSize, dRequired: payloadAtlas };
   }
 
   if ('eRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.eRequired;
-    const payloadAtlas = (atlas as any).eRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, true);
-    {
-      const varint = payload ? 1n : 0n;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(4n, payloadSize, true) + payloadSize, eRequired: payloadAtlas };
   }
 
   if ('fRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.fRequired;
-    const payloadAtlas = (atlas as any).fRequired as number;
+    payloadAtlas = payload.byteLength;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
-    {
-      const sourceBuffer = new Uint8Array(payload);
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(sourceBuffer, offset);
-      offset += sourceBuffer.byteLength;
-    }
-    return offset;
+    return { $size: fieldHeaderSize(5n, payloadSize, false) + payloadSize, fRequired: payloadAtlas };
   }
 
   if ('gRequired' in message) {
+    let payloadAtlas: Uint8Array;
     const payload = message.gRequired;
-    const payloadAtlas = (atlas as any).gRequired as Uint8Array;
+    payloadAtlas = textEncoder.encode(payload);
     const payloadSize = payloadAtlas.byteLength;
-    offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
-    {
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(payloadAtlas, offset);
-      offset += payloadAtlas.byteLength;
-    }
-    return offset;
+    return { $size: fieldHeaderSize(6n, payloadSize, false) + payloadSize, gRequired: payloadAtlas };
   }
 
   if ('hRequired' in message) {
+    let payloadAtlas: LocalStructAtlas;
     const payload = message.hRequired;
-    const payloadAtlas = (atlas as any).hRequired as LocalStructAtlas;
+    payloadAtlas = LocalStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 7n, payloadSize, false);
-    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    return offset;
+    return { $size: fieldHeaderSize(7n, payloadSize, false) + payloadSize, hRequired: payloadAtlas };
   }
 
   if ('iRequired' in message) {
+    let payloadAtlas: _Types.EmptyStructAtlas;
     const payload = message.iRequired;
-    const payloadAtlas = (atlas as any).iRequired as _Types.EmptyStructAtlas;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
-    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    return offset;
+    return { $size: fieldHeaderSize(8n, payloadSize, false) + payloadSize, iRequired: payloadAtlas };
   }
 
   if ('jRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.jRequired;
-    const payloadAtlas = (atlas as any).jRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
     {
-      const varint = BigInt(payload.length);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
       }
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(9n, payloadSize, false) + payloadSize, jRequired: payloadAtlas };
   }
 
   if ('kRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.kRequired;
-    const payloadAtlas = (atlas as any).kRequired as number;
+    payloadAtlas = 8 * payload.length;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
-    {
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        dataView.setFloat64(offset, payload, true);
-        offset += 8;
-      }
-    }
-    return offset;
+    return { $size: fieldHeaderSize(10n, payloadSize, false) + payloadSize, kRequired: payloadAtlas };
   }
 
   if ('lRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.lRequired;
-    const payloadAtlas = (atlas as any).lRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 11n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = payload;
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(11n, payloadSize, false) + payloadSize, lRequired: payloadAtlas };
   }
 
   if ('mRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.mRequired;
-    const payloadAtlas = (atlas as any).mRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 12n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = zigzagEncode(payload);
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(12n, payloadSize, false) + payloadSize, mRequired: payloadAtlas };
   }
 
   if ('nRequired' in message) {
+    let payloadAtlas: number;
     const payload = message.nRequired;
-    const payloadAtlas = (atlas as any).nRequired as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = payload ? 1n : 0n;
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    return offset;
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(13n, payloadSize, false) + payloadSize, nRequired: payloadAtlas };
   }
 
   if ('oRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.oRequired;
-    const payloadAtlas = (atlas as any).oRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const sourceBuffer = new Uint8Array(payload);
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(sourceBuffer, offset);
-          offset += sourceBuffer.byteLength;
-        }
+        let payloadAtlas: number;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(14n, payloadSize, false) + payloadSize, oRequired: payloadAtlas };
   }
 
   if ('pRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
     const payload = message.pRequired;
-    const payloadAtlas = (atlas as any).pRequired as { $size: number; $elements: Uint8Array[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 15n, payloadSize, false);
     {
+      let size = 0;
+      let elements: Uint8Array[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-        {
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(payloadAtlas, offset);
-          offset += payloadAtlas.byteLength;
-        }
+        let payloadAtlas: Uint8Array;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(15n, payloadSize, false) + payloadSize, pRequired: payloadAtlas };
   }
 
   if ('qRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
     const payload = message.qRequired;
-    const payloadAtlas = (atlas as any).qRequired as { $size: number; $elements: LocalStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 16n, payloadSize, false);
     {
+      let size = 0;
+      let elements: LocalStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: LocalStructAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(16n, payloadSize, false) + payloadSize, qRequired: payloadAtlas };
   }
 
   if ('rRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
     const payload = message.rRequired;
-    const payloadAtlas = (atlas as any).rRequired as { $size: number; $elements: _Types.EmptyStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 17n, payloadSize, false);
     {
+      let size = 0;
+      let elements: _Types.EmptyStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: _Types.EmptyStructAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(17n, payloadSize, false) + payloadSize, rRequired: payloadAtlas };
   }
 
   if ('sRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.sRequired;
-    const payloadAtlas = (atlas as any).sRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 18n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
-          const varint = BigInt(payload.length);
-          offset = serializeVarint(dataView, offset, varint);
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(18n, payloadSize, false) + payloadSize, sRequired: payloadAtlas };
   }
 
   if ('tRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.tRequired;
-    const payloadAtlas = (atlas as any).tRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 19n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            dataView.setFloat64(offset, payload, true);
-            offset += 8;
-          }
-        }
-      }
-    }
-    return offset;
+        let payloadAtlas: number;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
+      }
+      payloadAtlas = { $size: size, $elements: elements };
+    }
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(19n, payloadSize, false) + payloadSize, tRequired: payloadAtlas };
   }
 
   if ('uRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.uRequired;
-    const payloadAtlas = (atlas as any).uRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 20n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(20n, payloadSize, false) + payloadSize, uRequired: payloadAtlas };
   }
 
   if ('vRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.vRequired;
-    const payloadAtlas = (atlas as any).vRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 21n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = zigzagEncode(payload);
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(21n, payloadSize, false) + payloadSize, vRequired: payloadAtlas };
   }
 
   if ('wRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.wRequired;
-    const payloadAtlas = (atlas as any).wRequired as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 22n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload ? 1n : 0n;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(22n, payloadSize, false) + payloadSize, wRequired: payloadAtlas };
   }
 
   if ('xRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
     const payload = message.xRequired;
-    const payloadAtlas = (atlas as any).xRequired as { $size: number; $elements: { $size: number; $elements: number[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 23n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: number[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: number[] };
         {
+          let size = 0;
+          let elements: number[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-            {
-              const sourceBuffer = new Uint8Array(payload);
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(sourceBuffer, offset);
-              offset += sourceBuffer.byteLength;
-            }
+            let payloadAtlas: number;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(23n, payloadSize, false) + payloadSize, xRequired: payloadAtlas };
   }
 
   if ('yRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
     const payload = message.yRequired;
-    const payloadAtlas = (atlas as any).yRequired as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 24n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
         {
+          let size = 0;
+          let elements: Uint8Array[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-            {
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(payloadAtlas, offset);
-              offset += payloadAtlas.byteLength;
-            }
+            let payloadAtlas: Uint8Array;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(24n, payloadSize, false) + payloadSize, yRequired: payloadAtlas };
   }
 
   if ('zRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
     const payload = message.zRequired;
-    const payloadAtlas = (atlas as any).zRequired as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 25n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
         {
+          let size = 0;
+          let elements: LocalStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: LocalStructAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(25n, payloadSize, false) + payloadSize, zRequired: payloadAtlas };
   }
 
   if ('aaRequired' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
     const payload = message.aaRequired;
-    const payloadAtlas = (atlas as any).aaRequired as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 26n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
         {
+          let size = 0;
+          let elements: _Types.EmptyStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: _Types.EmptyStructAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    return { $size: fieldHeaderSize(26n, payloadSize, false) + payloadSize, aaRequired: payloadAtlas };
   }
 
   if ('aAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.aAsymmetric;
-    const payloadAtlas = (atlas as any).aAsymmetric as number;
+    payloadAtlas = 0;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 28n, payloadSize, false);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(28n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('bAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.bAsymmetric;
-    const payloadAtlas = (atlas as any).bAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 29n, payloadSize, false);
-    if (payloadAtlas !== 0) {
-      dataView.setFloat64(offset, payload, true);
-      offset += 8;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(29n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('cAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.cAsymmetric;
-    const payloadAtlas = (atlas as any).cAsymmetric as number;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 30n, payloadSize, true);
-    {
-      const varint = payload;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(30n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('dAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.dAsymmetric;
-    const payloadAtlas = (atlas as any).dAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 31n, payloadSize, true);
     {
-      const varint = zigzagEncode(payload);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
       }
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(31n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('eAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.eAsymmetric;
-    const payloadAtlas = (atlas as any).eAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 32n, payloadSize, true);
-    {
-      const varint = payload ? 1n : 0n;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(32n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('fAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.fAsymmetric;
-    const payloadAtlas = (atlas as any).fAsymmetric as number;
+    payloadAtlas = payload.byteLength;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 33n, payloadSize, false);
-    {
-      const sourceBuffer = new Uint8Array(payload);
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(sourceBuffer, offset);
-      offset += sourceBuffer.byteLength;
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(33n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('gAsymmetric' in message) {
+    let payloadAtlas: Uint8Array;
     const payload = message.gAsymmetric;
-    const payloadAtlas = (atlas as any).gAsymmetric as Uint8Array;
+    payloadAtlas = textEncoder.encode(payload);
     const payloadSize = payloadAtlas.byteLength;
-    offset = serializeFieldHeader(dataView, offset, 34n, payloadSize, false);
-    {
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(payloadAtlas, offset);
-      offset += payloadAtlas.byteLength;
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(34n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('hAsymmetric' in message) {
+    let payloadAtlas: LocalStructAtlas;
     const payload = message.hAsymmetric;
-    const payloadAtlas = (atlas as any).hAsymmetric as LocalStructAtlas;
+    payloadAtlas = LocalStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 35n, payloadSize, false);
-    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(35n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('iAsymmetric' in message) {
+    let payloadAtlas: _Types.EmptyStructAtlas;
     const payload = message.iAsymmetric;
-    const payloadAtlas = (atlas as any).iAsymmetric as _Types.EmptyStructAtlas;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 36n, payloadSize, false);
-    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(36n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('jAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.jAsymmetric;
-    const payloadAtlas = (atlas as any).jAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 37n, payloadSize, false);
     {
-      const varint = BigInt(payload.length);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
       }
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(37n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('kAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.kAsymmetric;
-    const payloadAtlas = (atlas as any).kAsymmetric as number;
+    payloadAtlas = 8 * payload.length;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 38n, payloadSize, false);
-    {
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        dataView.setFloat64(offset, payload, true);
-        offset += 8;
-      }
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(38n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('lAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.lAsymmetric;
-    const payloadAtlas = (atlas as any).lAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 39n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = payload;
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(39n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('mAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.mAsymmetric;
-    const payloadAtlas = (atlas as any).mAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 40n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = zigzagEncode(payload);
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(40n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('nAsymmetric' in message) {
+    let payloadAtlas: number;
     const payload = message.nAsymmetric;
-    const payloadAtlas = (atlas as any).nAsymmetric as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 41n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = payload ? 1n : 0n;
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(41n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('oAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.oAsymmetric;
-    const payloadAtlas = (atlas as any).oAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 42n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const sourceBuffer = new Uint8Array(payload);
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(sourceBuffer, offset);
-          offset += sourceBuffer.byteLength;
-        }
+        let payloadAtlas: number;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(42n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('pAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
     const payload = message.pAsymmetric;
-    const payloadAtlas = (atlas as any).pAsymmetric as { $size: number; $elements: Uint8Array[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 43n, payloadSize, false);
     {
+      let size = 0;
+      let elements: Uint8Array[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-        {
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(payloadAtlas, offset);
-          offset += payloadAtlas.byteLength;
-        }
+        let payloadAtlas: Uint8Array;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(43n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('qAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
     const payload = message.qAsymmetric;
-    const payloadAtlas = (atlas as any).qAsymmetric as { $size: number; $elements: LocalStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 44n, payloadSize, false);
     {
+      let size = 0;
+      let elements: LocalStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: LocalStructAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(44n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('rAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
     const payload = message.rAsymmetric;
-    const payloadAtlas = (atlas as any).rAsymmetric as { $size: number; $elements: _Types.EmptyStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 45n, payloadSize, false);
     {
+      let size = 0;
+      let elements: _Types.EmptyStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: _Types.EmptyStructAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(45n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('sAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.sAsymmetric;
-    const payloadAtlas = (atlas as any).sAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 46n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
-          const varint = BigInt(payload.length);
-          offset = serializeVarint(dataView, offset, varint);
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(46n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('tAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.tAsymmetric;
-    const payloadAtlas = (atlas as any).tAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 47n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            dataView.setFloat64(offset, payload, true);
-            offset += 8;
-          }
-        }
+        let payloadAtlas: number;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(47n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('uAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.uAsymmetric;
-    const payloadAtlas = (atlas as any).uAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 48n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(48n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('vAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.vAsymmetric;
-    const payloadAtlas = (atlas as any).vAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 49n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = zigzagEncode(payload);
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(49n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('wAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.wAsymmetric;
-    const payloadAtlas = (atlas as any).wAsymmetric as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 50n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload ? 1n : 0n;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(50n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('xAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
     const payload = message.xAsymmetric;
-    const payloadAtlas = (atlas as any).xAsymmetric as { $size: number; $elements: { $size: number; $elements: number[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 51n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: number[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: number[] };
         {
+          let size = 0;
+          let elements: number[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-            {
-              const sourceBuffer = new Uint8Array(payload);
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(sourceBuffer, offset);
-              offset += sourceBuffer.byteLength;
-            }
+            let payloadAtlas: number;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(51n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('yAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
     const payload = message.yAsymmetric;
-    const payloadAtlas = (atlas as any).yAsymmetric as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 52n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
         {
+          let size = 0;
+          let elements: Uint8Array[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-            {
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(payloadAtlas, offset);
-              offset += payloadAtlas.byteLength;
-            }
+            let payloadAtlas: Uint8Array;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(52n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('zAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
     const payload = message.zAsymmetric;
-    const payloadAtlas = (atlas as any).zAsymmetric as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 53n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
         {
+          let size = 0;
+          let elements: LocalStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: LocalStructAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(53n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('aaAsymmetric' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
     const payload = message.aaAsymmetric;
-    const payloadAtlas = (atlas as any).aaAsymmetric as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 54n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
         {
+          let size = 0;
+          let elements: _Types.EmptyStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: _Types.EmptyStructAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(54n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaAsymmetric: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('aOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.aOptional;
-    const payloadAtlas = (atlas as any).aOptional as number;
+    payloadAtlas = 0;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 56n, payloadSize, false);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(56n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('bOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.bOptional;
-    const payloadAtlas = (atlas as any).bOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 57n, payloadSize, false);
-    if (payloadAtlas !== 0) {
-      dataView.setFloat64(offset, payload, true);
-      offset += 8;
+    if (Object.is(payload, 0)) {
+      payloadAtlas = 0;
+    } else {
+      payloadAtlas = 8;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(57n, payloadSize, false) + payloadSize + fallbackAtlas.$size, bOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('cOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.cOptional;
-    const payloadAtlas = (atlas as any).cOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 58n, payloadSize, true);
-    {
-      const varint = payload;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(58n, payloadSize, true) + payloadSize + fallbackAtlas.$size, cOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('dOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.dOptional;
-    const payloadAtlas = (atlas as any).dOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 59n, payloadSize, true);
     {
-      const varint = zigzagEncode(payload);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const zigzag = zigzagEncode(payload);
+      if (zigzag === 0n) {
+        payloadAtlas = 0;
+      } else if (zigzag < 567_382_630_219_904n) {
+        payloadAtlas = varintSizeFromValue(zigzag);
+      } else {
+        payloadAtlas = 8;
       }
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(59n, payloadSize, true) + payloadSize + fallbackAtlas.$size, dOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('eOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.eOptional;
-    const payloadAtlas = (atlas as any).eOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 60n, payloadSize, true);
-    {
-      const varint = payload ? 1n : 0n;
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
-      }
+    if (payload) {
+      payloadAtlas = 1;
+    } else {
+      payloadAtlas = 0;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(60n, payloadSize, true) + payloadSize + fallbackAtlas.$size, eOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('fOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.fOptional;
-    const payloadAtlas = (atlas as any).fOptional as number;
+    payloadAtlas = payload.byteLength;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 61n, payloadSize, false);
-    {
-      const sourceBuffer = new Uint8Array(payload);
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(sourceBuffer, offset);
-      offset += sourceBuffer.byteLength;
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(61n, payloadSize, false) + payloadSize + fallbackAtlas.$size, fOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('gOptional' in message) {
+    let payloadAtlas: Uint8Array;
     const payload = message.gOptional;
-    const payloadAtlas = (atlas as any).gOptional as Uint8Array;
+    payloadAtlas = textEncoder.encode(payload);
     const payloadSize = payloadAtlas.byteLength;
-    offset = serializeFieldHeader(dataView, offset, 62n, payloadSize, false);
-    {
-      const targetBuffer = new Uint8Array(
-        dataView.buffer as ArrayBuffer,
-        dataView.byteOffset,
-        dataView.byteLength,
-      );
-      targetBuffer.set(payloadAtlas, offset);
-      offset += payloadAtlas.byteLength;
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(62n, payloadSize, false) + payloadSize + fallbackAtlas.$size, gOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('hOptional' in message) {
+    let payloadAtlas: LocalStructAtlas;
     const payload = message.hOptional;
-    const payloadAtlas = (atlas as any).hOptional as LocalStructAtlas;
+    payloadAtlas = LocalStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 63n, payloadSize, false);
-    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(63n, payloadSize, false) + payloadSize + fallbackAtlas.$size, hOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('iOptional' in message) {
+    let payloadAtlas: _Types.EmptyStructAtlas;
     const payload = message.iOptional;
-    const payloadAtlas = (atlas as any).iOptional as _Types.EmptyStructAtlas;
+    payloadAtlas = _Types.EmptyStruct.atlas(payload);
     const payloadSize = (payloadAtlas as { $size: number }).$size;
-    offset = serializeFieldHeader(dataView, offset, 64n, payloadSize, false);
-    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(64n, payloadSize, false) + payloadSize + fallbackAtlas.$size, iOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('jOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.jOptional;
-    const payloadAtlas = (atlas as any).jOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 65n, payloadSize, false);
     {
-      const varint = BigInt(payload.length);
-      if (varint > 567_382_630_219_903n) {
-        dataView.setBigUint64(offset, varint, true);
-        offset += 8;
-      } else if (varint !== 0n) {
-        offset = serializeVarint(dataView, offset, varint);
+      const oldPayload = payload;
+      {
+        const payload = BigInt(oldPayload.length);
+        if (payload === 0n) {
+          payloadAtlas = 0;
+        } else if (payload < 567_382_630_219_904n) {
+          payloadAtlas = varintSizeFromValue(payload);
+        } else {
+          payloadAtlas = 8;
+        }
       }
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(65n, payloadSize, false) + payloadSize + fallbackAtlas.$size, jOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('kOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.kOptional;
-    const payloadAtlas = (atlas as any).kOptional as number;
+    payloadAtlas = 8 * payload.length;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 66n, payloadSize, false);
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(66n, payloadSize, false) + payloadSize + fallbackAtlas.$size, kOptional: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  if ('lOptional' in message) {
+    let payloadAtlas: number;
+    const payload = message.lOptional;
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        dataView.setFloat64(offset, payload, true);
-        offset += 8;
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(payload);
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
-  }
-
-  if ('lOptional' in message) {
-    const payload = message.lOptional;
-    const payloadAtlas = (atlas as any).lOptional as number;
     const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 67n, payloadSize, false);
-    {
-      const oldPayload = payload;
-      for (let i = 0; i < oldPayload.length; i += 1) {
-        const payload = oldPayload[i];
-        {
-          const varint = payload;
-          offset = serializeVarint(dataView, offset, varint);
-        }
-      }
-    }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(67n, payloadSize, false) + payloadSize + fallbackAtlas.$size, lOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('mOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.mOptional;
-    const payloadAtlas = (atlas as any).mOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 68n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = zigzagEncode(payload);
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(68n, payloadSize, false) + payloadSize + fallbackAtlas.$size, mOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('nOptional' in message) {
+    let payloadAtlas: number;
     const payload = message.nOptional;
-    const payloadAtlas = (atlas as any).nOptional as number;
-    const payloadSize = payloadAtlas;
-    offset = serializeFieldHeader(dataView, offset, 69n, payloadSize, false);
     {
+      let arraySize = 0;
       const oldPayload = payload;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        {
-          const varint = payload ? 1n : 0n;
-          offset = serializeVarint(dataView, offset, varint);
-        }
+        let payloadAtlas = 0;
+        payloadAtlas = 1;
+        arraySize += payloadAtlas;
       }
+      payloadAtlas = arraySize;
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(69n, payloadSize, false) + payloadSize + fallbackAtlas.$size, nOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('oOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.oOptional;
-    const payloadAtlas = (atlas as any).oOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 70n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const sourceBuffer = new Uint8Array(payload);
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(sourceBuffer, offset);
-          offset += sourceBuffer.byteLength;
-        }
+        let payloadAtlas: number;
+        payloadAtlas = payload.byteLength;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(70n, payloadSize, false) + payloadSize + fallbackAtlas.$size, oOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('pOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: Uint8Array[] };
     const payload = message.pOptional;
-    const payloadAtlas = (atlas as any).pOptional as { $size: number; $elements: Uint8Array[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 71n, payloadSize, false);
     {
+      let size = 0;
+      let elements: Uint8Array[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-        {
-          const targetBuffer = new Uint8Array(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset,
-            dataView.byteLength,
-          );
-          targetBuffer.set(payloadAtlas, offset);
-          offset += payloadAtlas.byteLength;
-        }
+        let payloadAtlas: Uint8Array;
+        payloadAtlas = textEncoder.encode(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.byteLength;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(71n, payloadSize, false) + payloadSize + fallbackAtlas.$size, pOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('qOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
     const payload = message.qOptional;
-    const payloadAtlas = (atlas as any).qOptional as { $size: number; $elements: LocalStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 72n, payloadSize, false);
     {
+      let size = 0;
+      let elements: LocalStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: LocalStructAtlas;
+        payloadAtlas = LocalStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(72n, payloadSize, false) + payloadSize + fallbackAtlas.$size, qOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('rOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
     const payload = message.rOptional;
-    const payloadAtlas = (atlas as any).rOptional as { $size: number; $elements: _Types.EmptyStructAtlas[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 73n, payloadSize, false);
     {
+      let size = 0;
+      let elements: _Types.EmptyStructAtlas[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+        let payloadAtlas: _Types.EmptyStructAtlas;
+        payloadAtlas = _Types.EmptyStruct.atlas(payload);
+        elements.push(payloadAtlas);
+        const payloadSize = (payloadAtlas as { $size: number }).$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(73n, payloadSize, false) + payloadSize + fallbackAtlas.$size, rOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('sOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.sOptional;
-    const payloadAtlas = (atlas as any).sOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 74n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
-          const varint = BigInt(payload.length);
-          offset = serializeVarint(dataView, offset, varint);
+          const oldPayload = payload;
+          {
+            const payload = BigInt(oldPayload.length);
+            payloadAtlas = varintSizeFromValue(payload);
+          }
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(74n, payloadSize, false) + payloadSize + fallbackAtlas.$size, sOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('tOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.tOptional;
-    const payloadAtlas = (atlas as any).tOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 75n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-        {
-          const oldPayload = payload;
-          for (let i = 0; i < oldPayload.length; i += 1) {
-            const payload = oldPayload[i];
-            dataView.setFloat64(offset, payload, true);
-            offset += 8;
-          }
-        }
+        let payloadAtlas: number;
+        payloadAtlas = 8 * payload.length;
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(75n, payloadSize, false) + payloadSize + fallbackAtlas.$size, tOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('uOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.uOptional;
-    const payloadAtlas = (atlas as any).uOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 76n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(payload);
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(76n, payloadSize, false) + payloadSize + fallbackAtlas.$size, uOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('vOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.vOptional;
-    const payloadAtlas = (atlas as any).vOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 77n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = zigzagEncode(payload);
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = varintSizeFromValue(zigzagEncode(payload));
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(77n, payloadSize, false) + payloadSize + fallbackAtlas.$size, vOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('wOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: number[] };
     const payload = message.wOptional;
-    const payloadAtlas = (atlas as any).wOptional as { $size: number; $elements: number[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 78n, payloadSize, false);
     {
+      let size = 0;
+      let elements: number[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        let payloadAtlas: number;
         {
+          let arraySize = 0;
           const oldPayload = payload;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            {
-              const varint = payload ? 1n : 0n;
-              offset = serializeVarint(dataView, offset, varint);
-            }
+            let payloadAtlas = 0;
+            payloadAtlas = 1;
+            arraySize += payloadAtlas;
           }
+          payloadAtlas = arraySize;
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(78n, payloadSize, false) + payloadSize + fallbackAtlas.$size, wOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('xOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: number[] }[] };
     const payload = message.xOptional;
-    const payloadAtlas = (atlas as any).xOptional as { $size: number; $elements: { $size: number; $elements: number[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 79n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: number[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: number[] };
         {
+          let size = 0;
+          let elements: number[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
-            {
-              const sourceBuffer = new Uint8Array(payload);
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(sourceBuffer, offset);
-              offset += sourceBuffer.byteLength;
-            }
+            let payloadAtlas: number;
+            payloadAtlas = payload.byteLength;
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(79n, payloadSize, false) + payloadSize + fallbackAtlas.$size, xOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('yOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
     const payload = message.yOptional;
-    const payloadAtlas = (atlas as any).yOptional as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 80n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: Uint8Array[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: Uint8Array[] };
         {
+          let size = 0;
+          let elements: Uint8Array[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
-            {
-              const targetBuffer = new Uint8Array(
-                dataView.buffer as ArrayBuffer,
-                dataView.byteOffset,
-                dataView.byteLength,
-              );
-              targetBuffer.set(payloadAtlas, offset);
-              offset += payloadAtlas.byteLength;
-            }
+            let payloadAtlas: Uint8Array;
+            payloadAtlas = textEncoder.encode(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = payloadAtlas.byteLength;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(80n, payloadSize, false) + payloadSize + fallbackAtlas.$size, yOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('zOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
     const payload = message.zOptional;
-    const payloadAtlas = (atlas as any).zOptional as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 81n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: LocalStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: LocalStructAtlas[] };
         {
+          let size = 0;
+          let elements: LocalStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: LocalStructAtlas;
+            payloadAtlas = LocalStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(81n, payloadSize, false) + payloadSize + fallbackAtlas.$size, zOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   if ('aaOptional' in message) {
+    let payloadAtlas: { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
     const payload = message.aaOptional;
-    const payloadAtlas = (atlas as any).aaOptional as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
-    const payloadSize = payloadAtlas.$size;
-    offset = serializeFieldHeader(dataView, offset, 82n, payloadSize, false);
     {
+      let size = 0;
+      let elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] = [];
       const oldPayload = payload;
-      const oldPayloadAtlas = payloadAtlas;
       for (let i = 0; i < oldPayload.length; i += 1) {
         const payload = oldPayload[i];
-        const payloadAtlas = oldPayloadAtlas.$elements[i];
-        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        let payloadAtlas: { $size: number; $elements: _Types.EmptyStructAtlas[] };
         {
+          let size = 0;
+          let elements: _Types.EmptyStructAtlas[] = [];
           const oldPayload = payload;
-          const oldPayloadAtlas = payloadAtlas;
           for (let i = 0; i < oldPayload.length; i += 1) {
             const payload = oldPayload[i];
-            const payloadAtlas = oldPayloadAtlas.$elements[i];
-            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
-            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+            let payloadAtlas: _Types.EmptyStructAtlas;
+            payloadAtlas = _Types.EmptyStruct.atlas(payload);
+            elements.push(payloadAtlas);
+            const payloadSize = (payloadAtlas as { $size: number }).$size;
+            size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
           }
+          payloadAtlas = { $size: size, $elements: elements };
         }
+        elements.push(payloadAtlas);
+        const payloadSize = payloadAtlas.$size;
+        size += varintSizeFromValue(BigInt(payloadSize)) + payloadSize;
       }
+      payloadAtlas = { $size: size, $elements: elements };
     }
-    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
-    return offset;
+    const payloadSize = payloadAtlas.$size;
+    const fallbackAtlas = barAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(82n, payloadSize, false) + payloadSize + fallbackAtlas.$size, aaOptional: payloadAtlas, $fallback: fallbackAtlas };
   }
 
   return unreachable(message);
 }
 
-function barDeserializeUnsafe(dataView: DataView): BarIn {
-  const dataViewAlias = dataView;
+function barSize(message: BarOut): number {
+  return barAtlas(message).$size;
+}
 
-  let offset = 0;
+function barSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: BarOut,
+  atlas: BarAtlas,
+): number {
+  if ('aRequired' in message) {
+    const payload = message.aRequired;
+    const payloadAtlas = (atlas as any).aRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    return offset;
+  }
 
-  while (true) {
-    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
-    offset = newOffset;
+  if ('bRequired' in message) {
+    const payload = message.bRequired;
+    const payloadAtlas = (atlas as any).bRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    return offset;
+  }
 
-    switch (index) {
-      case 0n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = null;
-        return {
-          $field: 'aRequired',
-          aRequired: payload,
-        };
+  if ('cRequired' in message) {
+    const payload = message.cRequired;
+    const payloadAtlas = (atlas as any).cRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
       }
-      case 1n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number;
-        {
-          switch (payloadSize) {
-            case 0:
-              payload = 0;
-              break;
-            default:
-              payload = dataView.getFloat64(offset, true);
-              offset += 8;
-              break;
-          }
-        }
-        return {
-          $field: 'bRequired',
-          bRequired: payload,
-        };
+    }
+    return offset;
+  }
+
+  if ('dRequired' in message) {
+    const payload = message.dRequired;
+    const payloadAtlas = (atlas as any).dRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
       }
-      case 2n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint;
+    }
+    return offset;
+  }
+
+  if ('eRequired' in message) {
+    const payload = message.eRequired;
+    const payloadAtlas = (atlas as any).eRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('fRequired' in message) {
+    const payload = message.fRequired;
+    const payloadAtlas = (atlas as any).fRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 5n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    return offset;
+  }
+
+  if ('gRequired' in message) {
+    const payload = message.gRequired;
+    const payloadAtlas = (atlas as any).gRequired as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 6n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('hRequired' in message) {
+    const payload = message.hRequired;
+    const payloadAtlas = (atlas as any).hRequired as LocalStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 7n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    return offset;
+  }
+
+  if ('iRequired' in message) {
+    const payload = message.iRequired;
+    const payloadAtlas = (atlas as any).iRequired as _Types.EmptyStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 8n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    return offset;
+  }
+
+  if ('jRequired' in message) {
+    const payload = message.jRequired;
+    const payloadAtlas = (atlas as any).jRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 9n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('kRequired' in message) {
+    const payload = message.kRequired;
+    const payloadAtlas = (atlas as any).kRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 10n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    return offset;
+  }
+
+  if ('lRequired' in message) {
+    const payload = message.lRequired;
+    const payloadAtlas = (atlas as any).lRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 11n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
         {
-          switch (payloadSize) {
-            case 0:
-              payload = 0n;
-              break;
-            case 8:
-              payload = dataView.getBigUint64(offset, true);
-              offset += 8;
-              break;
-            default:
-              [offset, payload] = deserializeVarint(dataView, offset);
-              break;
-          }
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
         }
-        return {
-          $field: 'cRequired',
-          cRequired: payload,
-        };
       }
-      case 3n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint;
+    }
+    return offset;
+  }
+
+  if ('mRequired' in message) {
+    const payload = message.mRequired;
+    const payloadAtlas = (atlas as any).mRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 12n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
         {
-          switch (payloadSize) {
-            case 0:
-              payload = 0n;
-              break;
-            case 8:
-              payload = dataView.getBigUint64(offset, true);
-              offset += 8;
-              break;
-            default:
-              [offset, payload] = deserializeVarint(dataView, offset);
-              break;
-          }
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
         }
-        payload = zigzagDecode(payload);
-        return {
-          $field: 'dRequired',
-          dRequired: payload,
-        };
       }
-      case 4n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: boolean;
+    }
+    return offset;
+  }
+
+  if ('nRequired' in message) {
+    const payload = message.nRequired;
+    const payloadAtlas = (atlas as any).nRequired as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 13n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
         {
-          let newPayload: boolean;
-          {
-            let payload: bigint;
-            {
-              switch (payloadSize) {
-                case 0:
-                  payload = 0n;
-                  break;
-                case 8:
-                  payload = dataView.getBigUint64(offset, true);
-                  offset += 8;
-                  break;
-                default:
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  break;
-              }
-            }
-            newPayload = payload !== 0n;
-          }
-          payload = newPayload;
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
         }
-        return {
-          $field: 'eRequired',
-          eRequired: payload,
-        };
       }
-      case 5n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = (dataView.buffer as ArrayBuffer).slice(
-          dataView.byteOffset + offset,
-          dataView.byteOffset + dataView.byteLength,
-        );
-        offset = dataView.byteLength;
-        return {
-          $field: 'fRequired',
-          fRequired: payload,
-        };
+    }
+    return offset;
+  }
+
+  if ('oRequired' in message) {
+    const payload = message.oRequired;
+    const payloadAtlas = (atlas as any).oRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 14n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer as ArrayBuffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
       }
-      case 6n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
+    }
+    return offset;
+  }
+
+  if ('pRequired' in message) {
+    const payload = message.pRequired;
+    const payloadAtlas = (atlas as any).pRequired as { $size: number; $elements: Uint8Array[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 15n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
             dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
-        offset = dataView.byteLength;
-        return {
-          $field: 'gRequired',
-          gRequired: payload,
-        };
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
       }
-      case 7n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = LocalStruct.deserializeUnsafe(dataView);
-        offset = dataView.byteLength;
-        return {
-          $field: 'hRequired',
-          hRequired: payload,
-        };
+    }
+    return offset;
+  }
+
+  if ('qRequired' in message) {
+    const payload = message.qRequired;
+    const payloadAtlas = (atlas as any).qRequired as { $size: number; $elements: LocalStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 16n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
       }
-      case 8n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-        offset = dataView.byteLength;
-        return {
-          $field: 'iRequired',
-          iRequired: payload,
-        };
+    }
+    return offset;
+  }
+
+  if ('rRequired' in message) {
+    const payload = message.rRequired;
+    const payloadAtlas = (atlas as any).rRequired as { $size: number; $elements: _Types.EmptyStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 17n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
       }
-      case 9n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: null[];
+    }
+    return offset;
+  }
+
+  if ('sRequired' in message) {
+    const payload = message.sRequired;
+    const payloadAtlas = (atlas as any).sRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 18n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
         {
-          let newPayload: null[];
-          {
-            let payload: bigint;
-            {
-              switch (payloadSize) {
-                case 0:
-                  payload = 0n;
-                  break;
-                case 8:
-                  payload = dataView.getBigUint64(offset, true);
-                  offset += 8;
-                  break;
-                default:
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  break;
-              }
-            }
-            newPayload = Array(Number(payload)).fill(null) as null[];
-          }
-          payload = newPayload;
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
         }
-        return {
-          $field: 'jRequired',
-          jRequired: payload,
-        };
       }
-      case 10n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number[] = [];
+    }
+    return offset;
+  }
+
+  if ('tRequired' in message) {
+    const payload = message.tRequired;
+    const payloadAtlas = (atlas as any).tRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 19n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload = dataView.getFloat64(offset, true);
-                offset += 8;
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-            }
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
           }
         }
-        return {
-          $field: 'kRequired',
-          kRequired: payload,
-        };
       }
-      case 11n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[] = [];
-        {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: bigint;
-                [offset, payload] = deserializeVarint(dataView, offset);
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-            }
-          }
-        }
-        return {
-          $field: 'lRequired',
-          lRequired: payload,
-        };
-      }
-      case 12n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[] = [];
+    }
+    return offset;
+  }
+
+  if ('uRequired' in message) {
+    const payload = message.uRequired;
+    const payloadAtlas = (atlas as any).uRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 20n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: bigint;
-                [offset, payload] = deserializeVarint(dataView, offset);
-                payload = zigzagDecode(payload);
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
             }
           }
         }
-        return {
-          $field: 'mRequired',
-          mRequired: payload,
-        };
       }
-      case 13n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: boolean[] = [];
+    }
+    return offset;
+  }
+
+  if ('vRequired' in message) {
+    const payload = message.vRequired;
+    const payloadAtlas = (atlas as any).vRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 21n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: boolean;
-                {
-                  let newPayload: boolean;
-                  {
-                    let payload: bigint;
-                    [offset, payload] = deserializeVarint(dataView, offset);
-                    newPayload = payload !== 0n;
-                  }
-                  payload = newPayload;
-                }
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
             }
           }
         }
-        return {
-          $field: 'nRequired',
-          nRequired: payload,
-        };
       }
-      case 14n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: ArrayBuffer[] = [];
+    }
+    return offset;
+  }
+
+  if ('wRequired' in message) {
+    const payload = message.wRequired;
+    const payloadAtlas = (atlas as any).wRequired as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 22n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = (dataView.buffer as ArrayBuffer).slice(
-                dataView.byteOffset + offset,
-                dataView.byteOffset + dataView.byteLength,
-              );
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
             }
           }
         }
-        return {
-          $field: 'oRequired',
-          oRequired: payload,
-        };
       }
-      case 15n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: string[] = [];
+    }
+    return offset;
+  }
+
+  if ('xRequired' in message) {
+    const payload = message.xRequired;
+    const payloadAtlas = (atlas as any).xRequired as { $size: number; $elements: { $size: number; $elements: number[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 23n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
               );
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
             }
           }
         }
-        return {
-          $field: 'pRequired',
-          pRequired: payload,
-        };
       }
-      case 16n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: LocalStructIn[] = [];
-        {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = LocalStruct.deserializeUnsafe(dataView);
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
-            }
-          }
-        }
-        return {
-          $field: 'qRequired',
-          qRequired: payload,
-        };
-      }
-      case 17n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: _Types.EmptyStructIn[] = [];
+    }
+    return offset;
+  }
+
+  if ('yRequired' in message) {
+    const payload = message.yRequired;
+    const payloadAtlas = (atlas as any).yRequired as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 24n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
               );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
             }
           }
         }
-        return {
-          $field: 'rRequired',
-          rRequired: payload,
-        };
       }
-      case 18n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: null[][] = [];
+    }
+    return offset;
+  }
+
+  if ('zRequired' in message) {
+    const payload = message.zRequired;
+    const payloadAtlas = (atlas as any).zRequired as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 25n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: null[];
-              {
-                let newPayload: null[];
-                {
-                  let payload: bigint;
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  newPayload = Array(Number(payload)).fill(null) as null[];
-                }
-                payload = newPayload;
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
-            }
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
           }
         }
-        return {
-          $field: 'sRequired',
-          sRequired: payload,
-        };
       }
-      case 19n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number[][] = [];
+    }
+    return offset;
+  }
+
+  if ('aaRequired' in message) {
+    const payload = message.aaRequired;
+    const payloadAtlas = (atlas as any).aaRequired as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 26n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: number[] = [];
-              {
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    try {
-                      let payload = dataView.getFloat64(offset, true);
-                      offset += 8;
-                      payloadAlias.push(payload);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
-            }
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
           }
         }
-        return {
-          $field: 'tRequired',
-          tRequired: payload,
-        };
       }
-      case 20n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[][] = [];
-        {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
+    }
+    return offset;
+  }
+
+  if ('aAsymmetric' in message) {
+    const payload = message.aAsymmetric;
+    const payloadAtlas = (atlas as any).aAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 28n, payloadSize, false);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('bAsymmetric' in message) {
+    const payload = message.bAsymmetric;
+    const payloadAtlas = (atlas as any).bAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 29n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('cAsymmetric' in message) {
+    const payload = message.cAsymmetric;
+    const payloadAtlas = (atlas as any).cAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 30n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('dAsymmetric' in message) {
+    const payload = message.dAsymmetric;
+    const payloadAtlas = (atlas as any).dAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 31n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('eAsymmetric' in message) {
+    const payload = message.eAsymmetric;
+    const payloadAtlas = (atlas as any).eAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 32n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('fAsymmetric' in message) {
+    const payload = message.fAsymmetric;
+    const payloadAtlas = (atlas as any).fAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 33n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('gAsymmetric' in message) {
+    const payload = message.gAsymmetric;
+    const payloadAtlas = (atlas as any).gAsymmetric as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 34n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('hAsymmetric' in message) {
+    const payload = message.hAsymmetric;
+    const payloadAtlas = (atlas as any).hAsymmetric as LocalStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 35n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('iAsymmetric' in message) {
+    const payload = message.iAsymmetric;
+    const payloadAtlas = (atlas as any).iAsymmetric as _Types.EmptyStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 36n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('jAsymmetric' in message) {
+    const payload = message.jAsymmetric;
+    const payloadAtlas = (atlas as any).jAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 37n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('kAsymmetric' in message) {
+    const payload = message.kAsymmetric;
+    const payloadAtlas = (atlas as any).kAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 38n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('lAsymmetric' in message) {
+    const payload = message.lAsymmetric;
+    const payloadAtlas = (atlas as any).lAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 39n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('mAsymmetric' in message) {
+    const payload = message.mAsymmetric;
+    const payloadAtlas = (atlas as any).mAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 40n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('nAsymmetric' in message) {
+    const payload = message.nAsymmetric;
+    const payloadAtlas = (atlas as any).nAsymmetric as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 41n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('oAsymmetric' in message) {
+    const payload = message.oAsymmetric;
+    const payloadAtlas = (atlas as any).oAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 42n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer as ArrayBuffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('pAsymmetric' in message) {
+    const payload = message.pAsymmetric;
+    const payloadAtlas = (atlas as any).pAsymmetric as { $size: number; $elements: Uint8Array[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 43n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer as ArrayBuffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('qAsymmetric' in message) {
+    const payload = message.qAsymmetric;
+    const payloadAtlas = (atlas as any).qAsymmetric as { $size: number; $elements: LocalStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 44n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('rAsymmetric' in message) {
+    const payload = message.rAsymmetric;
+    const payloadAtlas = (atlas as any).rAsymmetric as { $size: number; $elements: _Types.EmptyStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 45n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('sAsymmetric' in message) {
+    const payload = message.sAsymmetric;
+    const payloadAtlas = (atlas as any).sAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 46n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('tAsymmetric' in message) {
+    const payload = message.tAsymmetric;
+    const payloadAtlas = (atlas as any).tAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 47n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('uAsymmetric' in message) {
+    const payload = message.uAsymmetric;
+    const payloadAtlas = (atlas as any).uAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 48n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('vAsymmetric' in message) {
+    const payload = message.vAsymmetric;
+    const payloadAtlas = (atlas as any).vAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 49n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('wAsymmetric' in message) {
+    const payload = message.wAsymmetric;
+    const payloadAtlas = (atlas as any).wAsymmetric as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 50n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('xAsymmetric' in message) {
+    const payload = message.xAsymmetric;
+    const payloadAtlas = (atlas as any).xAsymmetric as { $size: number; $elements: { $size: number; $elements: number[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 51n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('yAsymmetric' in message) {
+    const payload = message.yAsymmetric;
+    const payloadAtlas = (atlas as any).yAsymmetric as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 52n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('zAsymmetric' in message) {
+    const payload = message.zAsymmetric;
+    const payloadAtlas = (atlas as any).zAsymmetric as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 53n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('aaAsymmetric' in message) {
+    const payload = message.aaAsymmetric;
+    const payloadAtlas = (atlas as any).aaAsymmetric as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 54n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('aOptional' in message) {
+    const payload = message.aOptional;
+    const payloadAtlas = (atlas as any).aOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 56n, payloadSize, false);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('bOptional' in message) {
+    const payload = message.bOptional;
+    const payloadAtlas = (atlas as any).bOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 57n, payloadSize, false);
+    if (payloadAtlas !== 0) {
+      dataView.setFloat64(offset, payload, true);
+      offset += 8;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('cOptional' in message) {
+    const payload = message.cOptional;
+    const payloadAtlas = (atlas as any).cOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 58n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('dOptional' in message) {
+    const payload = message.dOptional;
+    const payloadAtlas = (atlas as any).dOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 59n, payloadSize, true);
+    {
+      const varint = zigzagEncode(payload);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('eOptional' in message) {
+    const payload = message.eOptional;
+    const payloadAtlas = (atlas as any).eOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 60n, payloadSize, true);
+    {
+      const varint = payload ? 1n : 0n;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('fOptional' in message) {
+    const payload = message.fOptional;
+    const payloadAtlas = (atlas as any).fOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 61n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('gOptional' in message) {
+    const payload = message.gOptional;
+    const payloadAtlas = (atlas as any).gOptional as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 62n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('hOptional' in message) {
+    const payload = message.hOptional;
+    const payloadAtlas = (atlas as any).hOptional as LocalStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 63n, payloadSize, false);
+    offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('iOptional' in message) {
+    const payload = message.iOptional;
+    const payloadAtlas = (atlas as any).iOptional as _Types.EmptyStructAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 64n, payloadSize, false);
+    offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('jOptional' in message) {
+    const payload = message.jOptional;
+    const payloadAtlas = (atlas as any).jOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 65n, payloadSize, false);
+    {
+      const varint = BigInt(payload.length);
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('kOptional' in message) {
+    const payload = message.kOptional;
+    const payloadAtlas = (atlas as any).kOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 66n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('lOptional' in message) {
+    const payload = message.lOptional;
+    const payloadAtlas = (atlas as any).lOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 67n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('mOptional' in message) {
+    const payload = message.mOptional;
+    const payloadAtlas = (atlas as any).mOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 68n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = zigzagEncode(payload);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('nOptional' in message) {
+    const payload = message.nOptional;
+    const payloadAtlas = (atlas as any).nOptional as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 69n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        {
+          const varint = payload ? 1n : 0n;
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('oOptional' in message) {
+    const payload = message.oOptional;
+    const payloadAtlas = (atlas as any).oOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 70n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const sourceBuffer = new Uint8Array(payload);
+          const targetBuffer = new Uint8Array(
+            dataView.buffer as ArrayBuffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(sourceBuffer, offset);
+          offset += sourceBuffer.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('pOptional' in message) {
+    const payload = message.pOptional;
+    const payloadAtlas = (atlas as any).pOptional as { $size: number; $elements: Uint8Array[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 71n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+        {
+          const targetBuffer = new Uint8Array(
+            dataView.buffer as ArrayBuffer,
+            dataView.byteOffset,
+            dataView.byteLength,
+          );
+          targetBuffer.set(payloadAtlas, offset);
+          offset += payloadAtlas.byteLength;
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('qOptional' in message) {
+    const payload = message.qOptional;
+    const payloadAtlas = (atlas as any).qOptional as { $size: number; $elements: LocalStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 72n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('rOptional' in message) {
+    const payload = message.rOptional;
+    const payloadAtlas = (atlas as any).rOptional as { $size: number; $elements: _Types.EmptyStructAtlas[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 73n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+        offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('sOptional' in message) {
+    const payload = message.sOptional;
+    const payloadAtlas = (atlas as any).sOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 74n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const varint = BigInt(payload.length);
+          offset = serializeVarint(dataView, offset, varint);
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('tOptional' in message) {
+    const payload = message.tOptional;
+    const payloadAtlas = (atlas as any).tOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 75n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            dataView.setFloat64(offset, payload, true);
+            offset += 8;
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('uOptional' in message) {
+    const payload = message.uOptional;
+    const payloadAtlas = (atlas as any).uOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 76n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('vOptional' in message) {
+    const payload = message.vOptional;
+    const payloadAtlas = (atlas as any).vOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 77n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = zigzagEncode(payload);
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('wOptional' in message) {
+    const payload = message.wOptional;
+    const payloadAtlas = (atlas as any).wOptional as { $size: number; $elements: number[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 78n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+        {
+          const oldPayload = payload;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            {
+              const varint = payload ? 1n : 0n;
+              offset = serializeVarint(dataView, offset, varint);
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('xOptional' in message) {
+    const payload = message.xOptional;
+    const payloadAtlas = (atlas as any).xOptional as { $size: number; $elements: { $size: number; $elements: number[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 79n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas));
+            {
+              const sourceBuffer = new Uint8Array(payload);
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(sourceBuffer, offset);
+              offset += sourceBuffer.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('yOptional' in message) {
+    const payload = message.yOptional;
+    const payloadAtlas = (atlas as any).yOptional as { $size: number; $elements: { $size: number; $elements: Uint8Array[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 80n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.byteLength));
+            {
+              const targetBuffer = new Uint8Array(
+                dataView.buffer as ArrayBuffer,
+                dataView.byteOffset,
+                dataView.byteLength,
+              );
+              targetBuffer.set(payloadAtlas, offset);
+              offset += payloadAtlas.byteLength;
+            }
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('zOptional' in message) {
+    const payload = message.zOptional;
+    const payloadAtlas = (atlas as any).zOptional as { $size: number; $elements: { $size: number; $elements: LocalStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 81n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = LocalStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  if ('aaOptional' in message) {
+    const payload = message.aaOptional;
+    const payloadAtlas = (atlas as any).aaOptional as { $size: number; $elements: { $size: number; $elements: _Types.EmptyStructAtlas[] }[] };
+    const payloadSize = payloadAtlas.$size;
+    offset = serializeFieldHeader(dataView, offset, 82n, payloadSize, false);
+    {
+      const oldPayload = payload;
+      const oldPayloadAtlas = payloadAtlas;
+      for (let i = 0; i < oldPayload.length; i += 1) {
+        const payload = oldPayload[i];
+        const payloadAtlas = oldPayloadAtlas.$elements[i];
+        offset = serializeVarint(dataView, offset, BigInt(payloadAtlas.$size));
+        {
+          const oldPayload = payload;
+          const oldPayloadAtlas = payloadAtlas;
+          for (let i = 0; i < oldPayload.length; i += 1) {
+            const payload = oldPayload[i];
+            const payloadAtlas = oldPayloadAtlas.$elements[i];
+            offset = serializeVarint(dataView, offset, BigInt((payloadAtlas as { $size: number }).$size));
+            offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+          }
+        }
+      }
+    }
+    offset = barSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as BarAtlas);
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function barDeserializeUnsafe(dataView: DataView): BarIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        let payload = null;
+        return {
+          $field: 'aRequired',
+          aRequired: payload,
+        };
+      }
+      case 1n: {
+        let payload: number;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        return {
+          $field: 'bRequired',
+          bRequired: payload,
+        };
+      }
+      case 2n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        return {
+          $field: 'cRequired',
+          cRequired: payload,
+        };
+      }
+      case 3n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        return {
+          $field: 'dRequired',
+          dRequired: payload,
+        };
+      }
+      case 4n: {
+        let payload: boolean;
+        {
+          let newPayload: boolean;
+          {
+            let payload: bigint;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'eRequired',
+          eRequired: payload,
+        };
+      }
+      case 5n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer as ArrayBuffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        return {
+          $field: 'fRequired',
+          fRequired: payload,
+        };
+      }
+      case 6n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'gRequired',
+          gRequired: payload,
+        };
+      }
+      case 7n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'hRequired',
+          hRequired: payload,
+        };
+      }
+      case 8n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'iRequired',
+          iRequired: payload,
+        };
+      }
+      case 9n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: null[];
+        {
+          let newPayload: null[];
+          {
+            let payload: bigint;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null) as null[];
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'jRequired',
+          jRequired: payload,
+        };
+      }
+      case 10n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: number[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'kRequired',
+          kRequired: payload,
+        };
+      }
+      case 11n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: bigint;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'lRequired',
+          lRequired: payload,
+        };
+      }
+      case 12n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: bigint;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'mRequired',
+          mRequired: payload,
+        };
+      }
+      case 13n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: boolean[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: boolean;
+                {
+                  let newPayload: boolean;
+                  {
+                    let payload: bigint;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'nRequired',
+          nRequired: payload,
+        };
+      }
+      case 14n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: ArrayBuffer[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer as ArrayBuffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'oRequired',
+          oRequired: payload,
+        };
+      }
+      case 15n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: string[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'pRequired',
+          pRequired: payload,
+        };
+      }
+      case 16n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: LocalStructIn[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'qRequired',
+          qRequired: payload,
+        };
+      }
+      case 17n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: _Types.EmptyStructIn[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'rRequired',
+          rRequired: payload,
+        };
+      }
+      case 18n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: null[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: null[];
+              {
+                let newPayload: null[];
+                {
+                  let payload: bigint;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null) as null[];
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'sRequired',
+          sRequired: payload,
+        };
+      }
+      case 19n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: number[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: number[] = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'tRequired',
+          tRequired: payload,
+        };
+      }
+      case 20n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: bigint[] = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload: bigint;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'uRequired',
+          uRequired: payload,
+        };
+      }
+      case 21n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: bigint[] = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload: bigint;
+                      [offset, payload] = deserializeVarint(dataView, offset);
+                      payload = zigzagDecode(payload);
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'vRequired',
+          vRequired: payload,
+        };
+      }
+      case 22n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: boolean[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: boolean[] = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload: boolean;
+                      {
+                        let newPayload: boolean;
+                        {
+                          let payload: bigint;
+                          [offset, payload] = deserializeVarint(dataView, offset);
+                          newPayload = payload !== 0n;
+                        }
+                        payload = newPayload;
+                      }
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'wRequired',
+          wRequired: payload,
+        };
+      }
+      case 23n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: ArrayBuffer[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: ArrayBuffer[] = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer as ArrayBuffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = (dataView.buffer as ArrayBuffer).slice(
+                      dataView.byteOffset + offset,
+                      dataView.byteOffset + dataView.byteLength,
+                    );
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'xRequired',
+          xRequired: payload,
+        };
+      }
+      case 24n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: string[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: string[] = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer as ArrayBuffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = textDecoder.decode(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'yRequired',
+          yRequired: payload,
+        };
+      }
+      case 25n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: LocalStructIn[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: LocalStructIn[] = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer as ArrayBuffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = LocalStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'zRequired',
+          zRequired: payload,
+        };
+      }
+      case 26n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: _Types.EmptyStructIn[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: _Types.EmptyStructIn[] = [];
+              {
+                const dataViewAlias = dataView;
+                const payloadAlias = payload;
+                {
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
+                    try {
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                    const dataView = new DataView(
+                      dataViewAlias.buffer as ArrayBuffer,
+                      dataViewAlias.byteOffset + offset,
+                      payloadSize,
+                    );
+                    const oldOffset = offset;
+                    offset = 0;
+                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'aaRequired',
+          aaRequired: payload,
+        };
+      }
+      case 28n: {
+        let payload = null;
+        return {
+          $field: 'aAsymmetric',
+          aAsymmetric: payload,
+        };
+      }
+      case 29n: {
+        let payload: number;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        return {
+          $field: 'bAsymmetric',
+          bAsymmetric: payload,
+        };
+      }
+      case 30n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        return {
+          $field: 'cAsymmetric',
+          cAsymmetric: payload,
+        };
+      }
+      case 31n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        payload = zigzagDecode(payload);
+        return {
+          $field: 'dAsymmetric',
+          dAsymmetric: payload,
+        };
+      }
+      case 32n: {
+        let payload: boolean;
+        {
+          let newPayload: boolean;
+          {
+            let payload: bigint;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = payload !== 0n;
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'eAsymmetric',
+          eAsymmetric: payload,
+        };
+      }
+      case 33n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer as ArrayBuffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        return {
+          $field: 'fAsymmetric',
+          fAsymmetric: payload,
+        };
+      }
+      case 34n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'gAsymmetric',
+          gAsymmetric: payload,
+        };
+      }
+      case 35n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = LocalStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'hAsymmetric',
+          hAsymmetric: payload,
+        };
+      }
+      case 36n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'iAsymmetric',
+          iAsymmetric: payload,
+        };
+      }
+      case 37n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: null[];
+        {
+          let newPayload: null[];
+          {
+            let payload: bigint;
+            {
+              switch (payloadSize) {
+                case 0:
+                  payload = 0n;
+                  break;
+                case 8:
+                  payload = dataView.getBigUint64(offset, true);
+                  offset += 8;
+                  break;
+                default:
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  break;
+              }
+            }
+            newPayload = Array(Number(payload)).fill(null) as null[];
+          }
+          payload = newPayload;
+        }
+        return {
+          $field: 'jAsymmetric',
+          jAsymmetric: payload,
+        };
+      }
+      case 38n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: number[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload = dataView.getFloat64(offset, true);
+                offset += 8;
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'kAsymmetric',
+          kAsymmetric: payload,
+        };
+      }
+      case 39n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: bigint;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'lAsymmetric',
+          lAsymmetric: payload,
+        };
+      }
+      case 40n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: bigint;
+                [offset, payload] = deserializeVarint(dataView, offset);
+                payload = zigzagDecode(payload);
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'mAsymmetric',
+          mAsymmetric: payload,
+        };
+      }
+      case 41n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: boolean[] = [];
+        {
+          const payloadAlias = payload;
+          {
+            while (offset < dataView.byteLength) {
+              try {
+                let payload: boolean;
+                {
+                  let newPayload: boolean;
+                  {
+                    let payload: bigint;
+                    [offset, payload] = deserializeVarint(dataView, offset);
+                    newPayload = payload !== 0n;
+                  }
+                  payload = newPayload;
+                }
+                payloadAlias.push(payload);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+            }
+          }
+        }
+        return {
+          $field: 'nAsymmetric',
+          nAsymmetric: payload,
+        };
+      }
+      case 42n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: ArrayBuffer[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = (dataView.buffer as ArrayBuffer).slice(
+                dataView.byteOffset + offset,
+                dataView.byteOffset + dataView.byteLength,
+              );
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'oAsymmetric',
+          oAsymmetric: payload,
+        };
+      }
+      case 43n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: string[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = textDecoder.decode(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'pAsymmetric',
+          pAsymmetric: payload,
+        };
+      }
+      case 44n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: LocalStructIn[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = LocalStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'qAsymmetric',
+          qAsymmetric: payload,
+        };
+      }
+      case 45n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: _Types.EmptyStructIn[] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+              offset = dataView.byteLength;
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'rAsymmetric',
+          rAsymmetric: payload,
+        };
+      }
+      case 46n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: null[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: null[];
+              {
+                let newPayload: null[];
+                {
+                  let payload: bigint;
+                  [offset, payload] = deserializeVarint(dataView, offset);
+                  newPayload = Array(Number(payload)).fill(null) as null[];
+                }
+                payload = newPayload;
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'sAsymmetric',
+          sAsymmetric: payload,
+        };
+      }
+      case 47n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: number[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
+                dataViewAlias.buffer as ArrayBuffer,
+                dataViewAlias.byteOffset + offset,
+                payloadSize,
+              );
+              const oldOffset = offset;
+              offset = 0;
+              let payload: number[] = [];
+              {
+                const payloadAlias = payload;
+                {
+                  while (offset < dataView.byteLength) {
+                    try {
+                      let payload = dataView.getFloat64(offset, true);
+                      offset += 8;
+                      payloadAlias.push(payload);
+                    } catch (e) {
+                      if (e instanceof RangeError) {
+                        break;
+                      } else {
+                        throw e;
+                      }
+                    }
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
+          }
+        }
+        return {
+          $field: 'tAsymmetric',
+          tAsymmetric: payload,
+        };
+      }
+      case 48n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload: bigint[][] = [];
+        {
+          const dataViewAlias = dataView;
+          const payloadAlias = payload;
+          {
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
+              try {
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
+              } catch (e) {
+                if (e instanceof RangeError) {
+                  break;
+                } else {
+                  throw e;
+                }
+              }
+              const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -11889,7 +14177,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -11910,11 +14198,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'uRequired',
-          uRequired: payload,
+          $field: 'uAsymmetric',
+          uAsymmetric: payload,
         };
       }
-      case 21n: {
+      case 49n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -11927,10 +14215,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -11941,7 +14229,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -11949,7 +14237,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -11971,11 +14259,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'vRequired',
-          vRequired: payload,
+          $field: 'vAsymmetric',
+          vAsymmetric: payload,
         };
       }
-      case 22n: {
+      case 50n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -11988,10 +14276,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12002,7 +14290,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12010,7 +14298,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: boolean;
                       {
@@ -12039,11 +14327,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'wRequired',
-          wRequired: payload,
+          $field: 'wAsymmetric',
+          wAsymmetric: payload,
         };
       }
-      case 23n: {
+      case 51n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12056,10 +14344,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12070,7 +14358,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12079,10 +14367,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -12093,7 +14381,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -12113,11 +14401,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'xRequired',
-          xRequired: payload,
+          $field: 'xAsymmetric',
+          xAsymmetric: payload,
         };
       }
-      case 24n: {
+      case 52n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12130,10 +14418,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12144,7 +14432,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12153,10 +14441,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -12167,17 +14455,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
+                    let payload = textDecoder.decode(dataView);
                     offset = dataView.byteLength;
                     offset += oldOffset;
                     payloadAlias.push(payload);
@@ -12190,11 +14472,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'yRequired',
-          yRequired: payload,
+          $field: 'yAsymmetric',
+          yAsymmetric: payload,
         };
       }
-      case 25n: {
+      case 53n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12207,10 +14489,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12221,7 +14503,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12230,10 +14512,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -12244,7 +14526,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -12261,11 +14543,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'zRequired',
-          zRequired: payload,
+          $field: 'zAsymmetric',
+          zAsymmetric: payload,
         };
       }
-      case 26n: {
+      case 54n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12278,10 +14560,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12292,7 +14574,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12301,10 +14583,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -12315,7 +14597,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -12332,32 +14614,26 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         return {
-          $field: 'aaRequired',
-          aaRequired: payload,
+          $field: 'aaAsymmetric',
+          aaAsymmetric: payload,
         };
       }
-      case 28n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
+      case 56n: {
         let payload = null;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'aAsymmetric',
-          aAsymmetric: payload,
+          $field: 'aOptional',
+          aOptional: payload,
+          $fallback,
         };
       }
-      case 29n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
+      case 57n: {
         let payload: number;
         {
           switch (payloadSize) {
@@ -12370,19 +14646,20 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               break;
           }
         }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'bAsymmetric',
-          bAsymmetric: payload,
+          $field: 'bOptional',
+          bOptional: payload,
+          $fallback,
         };
       }
-      case 30n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
+      case 58n: {
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -12398,19 +14675,20 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               break;
           }
         }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'cAsymmetric',
-          cAsymmetric: payload,
+          $field: 'cOptional',
+          cOptional: payload,
+          $fallback,
         };
       }
-      case 31n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
+      case 59n: {
         let payload: bigint;
         {
           switch (payloadSize) {
@@ -12427,19 +14705,20 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
         }
         payload = zigzagDecode(payload);
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'dAsymmetric',
-          dAsymmetric: payload,
+          $field: 'dOptional',
+          dOptional: payload,
+          $fallback,
         };
       }
-      case 32n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
+      case 60n: {
         let payload: boolean;
         {
           let newPayload: boolean;
@@ -12463,12 +14742,20 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
           payload = newPayload;
         }
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'eAsymmetric',
-          eAsymmetric: payload,
+          $field: 'eOptional',
+          eOptional: payload,
+          $fallback,
         };
       }
-      case 33n: {
+      case 61n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12481,12 +14768,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           dataView.byteOffset + dataView.byteLength,
         );
         offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'fAsymmetric',
-          fAsymmetric: payload,
+          $field: 'fOptional',
+          fOptional: payload,
+          $fallback,
         };
       }
-      case 34n: {
+      case 62n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12494,20 +14790,23 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
           new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
           ),
         );
-        offset = dataView.byteLength;
         return {
-          $field: 'gAsymmetric',
-          gAsymmetric: payload,
+          $field: 'gOptional',
+          gOptional: payload,
+          $fallback,
         };
       }
-      case 35n: {
+      case 63n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12517,12 +14816,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         offset = 0;
         let payload = LocalStruct.deserializeUnsafe(dataView);
         offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'hAsymmetric',
-          hAsymmetric: payload,
+          $field: 'hOptional',
+          hOptional: payload,
+          $fallback,
         };
       }
-      case 36n: {
+      case 64n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12532,12 +14840,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         offset = 0;
         let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
         offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'iAsymmetric',
-          iAsymmetric: payload,
+          $field: 'iOptional',
+          iOptional: payload,
+          $fallback,
         };
       }
-      case 37n: {
+      case 65n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12568,12 +14885,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           }
           payload = newPayload;
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'jAsymmetric',
-          jAsymmetric: payload,
+          $field: 'jOptional',
+          jOptional: payload,
+          $fallback,
         };
       }
-      case 38n: {
+      case 66n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12585,7 +14911,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload = dataView.getFloat64(offset, true);
                 offset += 8;
@@ -12600,12 +14926,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'kAsymmetric',
-          kAsymmetric: payload,
+          $field: 'kOptional',
+          kOptional: payload,
+          $fallback,
         };
       }
-      case 39n: {
+      case 67n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12617,7 +14952,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -12632,12 +14967,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'lAsymmetric',
-          lAsymmetric: payload,
+          $field: 'lOptional',
+          lOptional: payload,
+          $fallback,
         };
       }
-      case 40n: {
+      case 68n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12649,7 +14993,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: bigint;
                 [offset, payload] = deserializeVarint(dataView, offset);
@@ -12665,12 +15009,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'mAsymmetric',
-          mAsymmetric: payload,
+          $field: 'mOptional',
+          mOptional: payload,
+          $fallback,
         };
       }
-      case 41n: {
+      case 69n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12682,7 +15035,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
         {
           const payloadAlias = payload;
           {
-            while (true) {
+            while (offset < dataView.byteLength) {
               try {
                 let payload: boolean;
                 {
@@ -12705,12 +15058,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'nAsymmetric',
-          nAsymmetric: payload,
+          $field: 'nOptional',
+          nOptional: payload,
+          $fallback,
         };
       }
-      case 42n: {
+      case 70n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12723,10 +15085,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12737,7 +15099,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12751,12 +15113,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'oAsymmetric',
-          oAsymmetric: payload,
+          $field: 'oOptional',
+          oOptional: payload,
+          $fallback,
         };
       }
-      case 43n: {
+      case 71n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12769,10 +15140,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12783,29 +15154,32 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
-              );
+              let payload = textDecoder.decode(dataView);
               offset = dataView.byteLength;
               offset += oldOffset;
               payloadAlias.push(payload);
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'pAsymmetric',
-          pAsymmetric: payload,
+          $field: 'pOptional',
+          pOptional: payload,
+          $fallback,
         };
       }
-      case 44n: {
+      case 72n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12818,10 +15192,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12832,7 +15206,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12843,12 +15217,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'qAsymmetric',
-          qAsymmetric: payload,
+          $field: 'qOptional',
+          qOptional: payload,
+          $fallback,
         };
       }
-      case 45n: {
+      case 73n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12861,10 +15244,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12875,7 +15258,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12886,12 +15269,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'rAsymmetric',
-          rAsymmetric: payload,
+          $field: 'rOptional',
+          rOptional: payload,
+          $fallback,
         };
       }
-      case 46n: {
+      case 74n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12904,10 +15296,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12918,7 +15310,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12937,12 +15329,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'sAsymmetric',
-          sAsymmetric: payload,
+          $field: 'sOptional',
+          sOptional: payload,
+          $fallback,
         };
       }
-      case 47n: {
+      case 75n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -12955,10 +15356,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -12969,7 +15370,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -12977,7 +15378,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload = dataView.getFloat64(offset, true);
                       offset += 8;
@@ -12997,12 +15398,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'tAsymmetric',
-          tAsymmetric: payload,
+          $field: 'tOptional',
+          tOptional: payload,
+          $fallback,
         };
       }
-      case 48n: {
+      case 76n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13015,10 +15425,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13029,7 +15439,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13037,7 +15447,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -13057,12 +15467,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'uAsymmetric',
-          uAsymmetric: payload,
+          $field: 'uOptional',
+          uOptional: payload,
+          $fallback,
         };
       }
-      case 49n: {
+      case 77n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13075,10 +15494,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13089,7 +15508,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13097,7 +15516,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: bigint;
                       [offset, payload] = deserializeVarint(dataView, offset);
@@ -13118,12 +15537,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'vAsymmetric',
-          vAsymmetric: payload,
+          $field: 'vOptional',
+          vOptional: payload,
+          $fallback,
         };
       }
-      case 50n: {
+      case 78n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13136,10 +15564,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13150,7 +15578,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13158,7 +15586,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               {
                 const payloadAlias = payload;
                 {
-                  while (true) {
+                  while (offset < dataView.byteLength) {
                     try {
                       let payload: boolean;
                       {
@@ -13186,12 +15614,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'wAsymmetric',
-          wAsymmetric: payload,
+          $field: 'wOptional',
+          wOptional: payload,
+          $fallback,
         };
       }
-      case 51n: {
+      case 79n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13204,10 +15641,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13218,7 +15655,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13227,10 +15664,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -13241,7 +15678,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -13260,12 +15697,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'xAsymmetric',
-          xAsymmetric: payload,
+          $field: 'xOptional',
+          xOptional: payload,
+          $fallback,
         };
       }
-      case 52n: {
+      case 80n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13278,10 +15724,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13292,7 +15738,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13301,10 +15747,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -13315,17 +15761,11 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
+                    let payload = textDecoder.decode(dataView);
                     offset = dataView.byteLength;
                     offset += oldOffset;
                     payloadAlias.push(payload);
@@ -13337,12 +15777,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'yAsymmetric',
-          yAsymmetric: payload,
+          $field: 'yOptional',
+          yOptional: payload,
+          $fallback,
         };
       }
-      case 53n: {
+      case 81n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13355,10 +15804,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13369,7 +15818,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13378,10 +15827,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -13392,7 +15841,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
@@ -13408,12 +15857,21 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
             }
           }
         }
+        offset += oldOffset;
+        const $fallback = barDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
         return {
-          $field: 'zAsymmetric',
-          zAsymmetric: payload,
+          $field: 'zOptional',
+          zOptional: payload,
+          $fallback,
         };
       }
-      case 54n: {
+      case 82n: {
         const dataView = new DataView(
           dataViewAlias.buffer as ArrayBuffer,
           dataViewAlias.byteOffset + offset,
@@ -13426,10 +15884,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           const dataViewAlias = dataView;
           const payloadAlias = payload;
           {
-            while (true) {
-              let payloadSizeBig: bigint;
+            while (offset < dataViewAlias.byteLength) {
+              let payloadSize: number;
               try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
               } catch (e) {
                 if (e instanceof RangeError) {
                   break;
@@ -13440,7 +15898,7 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
               const dataView = new DataView(
                 dataViewAlias.buffer as ArrayBuffer,
                 dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
+                payloadSize,
               );
               const oldOffset = offset;
               offset = 0;
@@ -13449,10 +15907,10 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                 const dataViewAlias = dataView;
                 const payloadAlias = payload;
                 {
-                  while (true) {
-                    let payloadSizeBig: bigint;
+                  while (offset < dataViewAlias.byteLength) {
+                    let payloadSize: number;
                     try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
+                      [offset, payloadSize] = deserializeVarintNumber(dataViewAlias, offset);
                     } catch (e) {
                       if (e instanceof RangeError) {
                         break;
@@ -13463,68 +15921,20 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
                     const dataView = new DataView(
                       dataViewAlias.buffer as ArrayBuffer,
                       dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
+                      payloadSize,
                     );
                     const oldOffset = offset;
                     offset = 0;
                     let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-                    offset = dataView.byteLength;
-                    offset += oldOffset;
-                    payloadAlias.push(payload);
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
-            }
-          }
-        }
-        return {
-          $field: 'aaAsymmetric',
-          aaAsymmetric: payload,
-        };
-      }
-      case 56n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = null;
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'aOptional',
-          aOptional: payload,
-          $fallback,
-        };
-      }
-      case 57n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number;
-        {
-          switch (payloadSize) {
-            case 0:
-              payload = 0;
-              break;
-            default:
-              payload = dataView.getFloat64(offset, true);
-              offset += 8;
-              break;
+                    offset = dataView.byteLength;
+                    offset += oldOffset;
+                    payloadAlias.push(payload);
+                  }
+                }
+              }
+              offset += oldOffset;
+              payloadAlias.push(payload);
+            }
           }
         }
         offset += oldOffset;
@@ -13536,1366 +15946,1896 @@ function barDeserializeUnsafe(dataView: DataView): BarIn {
           ),
         );
         return {
-          $field: 'bOptional',
-          bOptional: payload,
+          $field: 'aaOptional',
+          aaOptional: payload,
           $fallback,
         };
       }
-      case 58n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint;
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function barSerialize(message: BarOut): ArrayBuffer {
+  const atlasValue = barAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  barSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function barDeserialize(bytes: Deserializable): BarIn | Error {
+  try {
+    return barDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function barCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('aRequired' in message) {
+    if ('$field' in message && message.$field !== 'aRequired') {
+      return '.$field must be "aRequired"';
+    }
+    const payload = message.aRequired;
+    if (payload !== null) {
+      return '.aRequired must be null';
+    }
+    return undefined;
+  }
+
+  if ('bRequired' in message) {
+    if ('$field' in message && message.$field !== 'bRequired') {
+      return '.$field must be "bRequired"';
+    }
+    const payload = message.bRequired;
+    if (typeof payload !== 'number') {
+      return '.bRequired must be a number';
+    }
+    return undefined;
+  }
+
+  if ('cRequired' in message) {
+    if ('$field' in message && message.$field !== 'cRequired') {
+      return '.$field must be "cRequired"';
+    }
+    const payload = message.cRequired;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cRequired must be a bigint from 0 to 18446744073709551615';
+    }
+    return undefined;
+  }
+
+  if ('dRequired' in message) {
+    if ('$field' in message && message.$field !== 'dRequired') {
+      return '.$field must be "dRequired"';
+    }
+    const payload = message.dRequired;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dRequired must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    return undefined;
+  }
+
+  if ('eRequired' in message) {
+    if ('$field' in message && message.$field !== 'eRequired') {
+      return '.$field must be "eRequired"';
+    }
+    const payload = message.eRequired;
+    if (typeof payload !== 'boolean') {
+      return '.eRequired must be a boolean';
+    }
+    return undefined;
+  }
+
+  if ('fRequired' in message) {
+    if ('$field' in message && message.$field !== 'fRequired') {
+      return '.$field must be "fRequired"';
+    }
+    const payload = message.fRequired;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fRequired must be an ArrayBuffer';
+    }
+    return undefined;
+  }
+
+  if ('gRequired' in message) {
+    if ('$field' in message && message.$field !== 'gRequired') {
+      return '.$field must be "gRequired"';
+    }
+    const payload = message.gRequired;
+    if (typeof payload !== 'string') {
+      return '.gRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('hRequired' in message) {
+    if ('$field' in message && message.$field !== 'hRequired') {
+      return '.$field must be "hRequired"';
+    }
+    const payload = message.hRequired;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hRequired' + error;
+    }
+    return undefined;
+  }
+
+  if ('iRequired' in message) {
+    if ('$field' in message && message.$field !== 'iRequired') {
+      return '.$field must be "iRequired"';
+    }
+    const payload = message.iRequired;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iRequired' + error;
+    }
+    return undefined;
+  }
+
+  if ('jRequired' in message) {
+    if ('$field' in message && message.$field !== 'jRequired') {
+      return '.$field must be "jRequired"';
+    }
+    const payload = message.jRequired;
+    if (!Array.isArray(payload)) {
+      return '.jRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jRequired[' + i0 + '] must be null';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('kRequired' in message) {
+    if ('$field' in message && message.$field !== 'kRequired') {
+      return '.$field must be "kRequired"';
+    }
+    const payload = message.kRequired;
+    if (!Array.isArray(payload)) {
+      return '.kRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kRequired[' + i0 + '] must be a number';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('lRequired' in message) {
+    if ('$field' in message && message.$field !== 'lRequired') {
+      return '.$field must be "lRequired"';
+    }
+    const payload = message.lRequired;
+    if (!Array.isArray(payload)) {
+      return '.lRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lRequired[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('mRequired' in message) {
+    if ('$field' in message && message.$field !== 'mRequired') {
+      return '.$field must be "mRequired"';
+    }
+    const payload = message.mRequired;
+    if (!Array.isArray(payload)) {
+      return '.mRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mRequired[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('nRequired' in message) {
+    if ('$field' in message && message.$field !== 'nRequired') {
+      return '.$field must be "nRequired"';
+    }
+    const payload = message.nRequired;
+    if (!Array.isArray(payload)) {
+      return '.nRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nRequired[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('oRequired' in message) {
+    if ('$field' in message && message.$field !== 'oRequired') {
+      return '.$field must be "oRequired"';
+    }
+    const payload = message.oRequired;
+    if (!Array.isArray(payload)) {
+      return '.oRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oRequired[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('pRequired' in message) {
+    if ('$field' in message && message.$field !== 'pRequired') {
+      return '.$field must be "pRequired"';
+    }
+    const payload = message.pRequired;
+    if (!Array.isArray(payload)) {
+      return '.pRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pRequired[' + i0 + '] must be a string';
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('qRequired' in message) {
+    if ('$field' in message && message.$field !== 'qRequired') {
+      return '.$field must be "qRequired"';
+    }
+    const payload = message.qRequired;
+    if (!Array.isArray(payload)) {
+      return '.qRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('rRequired' in message) {
+    if ('$field' in message && message.$field !== 'rRequired') {
+      return '.$field must be "rRequired"';
+    }
+    const payload = message.rRequired;
+    if (!Array.isArray(payload)) {
+      return '.rRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rRequired[' + i0 + ']' + error;
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('sRequired' in message) {
+    if ('$field' in message && message.$field !== 'sRequired') {
+      return '.$field must be "sRequired"';
+    }
+    const payload = message.sRequired;
+    if (!Array.isArray(payload)) {
+      return '.sRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sRequired[' + i0 + '] must be an array';
+        }
         {
-          switch (payloadSize) {
-            case 0:
-              payload = 0n;
-              break;
-            case 8:
-              payload = dataView.getBigUint64(offset, true);
-              offset += 8;
-              break;
-            default:
-              [offset, payload] = deserializeVarint(dataView, offset);
-              break;
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sRequired[' + i0 + '][' + i1 + '] must be null';
+            }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'cOptional',
-          cOptional: payload,
-          $fallback,
-        };
       }
-      case 59n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint;
-        {
-          switch (payloadSize) {
-            case 0:
-              payload = 0n;
-              break;
-            case 8:
-              payload = dataView.getBigUint64(offset, true);
-              offset += 8;
-              break;
-            default:
-              [offset, payload] = deserializeVarint(dataView, offset);
-              break;
+    }
+    return undefined;
+  }
+
+  if ('tRequired' in message) {
+    if ('$field' in message && message.$field !== 'tRequired') {
+      return '.$field must be "tRequired"';
+    }
+    const payload = message.tRequired;
+    if (!Array.isArray(payload)) {
+      return '.tRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tRequired[' + i0 + '][' + i1 + '] must be a number';
+            }
           }
         }
-        payload = zigzagDecode(payload);
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'dOptional',
-          dOptional: payload,
-          $fallback,
-        };
       }
-      case 60n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: boolean;
+    }
+    return undefined;
+  }
+
+  if ('uRequired' in message) {
+    if ('$field' in message && message.$field !== 'uRequired') {
+      return '.$field must be "uRequired"';
+    }
+    const payload = message.uRequired;
+    if (!Array.isArray(payload)) {
+      return '.uRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uRequired[' + i0 + '] must be an array';
+        }
         {
-          let newPayload: boolean;
-          {
-            let payload: bigint;
-            {
-              switch (payloadSize) {
-                case 0:
-                  payload = 0n;
-                  break;
-                case 8:
-                  payload = dataView.getBigUint64(offset, true);
-                  offset += 8;
-                  break;
-                default:
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  break;
-              }
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uRequired[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
             }
-            newPayload = payload !== 0n;
           }
-          payload = newPayload;
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'eOptional',
-          eOptional: payload,
-          $fallback,
-        };
-      }
-      case 61n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = (dataView.buffer as ArrayBuffer).slice(
-          dataView.byteOffset + offset,
-          dataView.byteOffset + dataView.byteLength,
-        );
-        offset = dataView.byteLength;
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'fOptional',
-          fOptional: payload,
-          $fallback,
-        };
-      }
-      case 62n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
-        offset = dataView.byteLength;
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'gOptional',
-          gOptional: payload,
-          $fallback,
-        };
-      }
-      case 63n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = LocalStruct.deserializeUnsafe(dataView);
-        offset = dataView.byteLength;
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'hOptional',
-          hOptional: payload,
-          $fallback,
-        };
       }
-      case 64n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-        offset = dataView.byteLength;
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'iOptional',
-          iOptional: payload,
-          $fallback,
-        };
+    }
+    return undefined;
+  }
+
+  if ('vRequired' in message) {
+    if ('$field' in message && message.$field !== 'vRequired') {
+      return '.$field must be "vRequired"';
+    }
+    const payload = message.vRequired;
+    if (!Array.isArray(payload)) {
+      return '.vRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vRequired[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+            }
+          }
+        }
       }
-      case 65n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: null[];
+    }
+    return undefined;
+  }
+
+  if ('wRequired' in message) {
+    if ('$field' in message && message.$field !== 'wRequired') {
+      return '.$field must be "wRequired"';
+    }
+    const payload = message.wRequired;
+    if (!Array.isArray(payload)) {
+      return '.wRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wRequired[' + i0 + '] must be an array';
+        }
         {
-          let newPayload: null[];
-          {
-            let payload: bigint;
-            {
-              switch (payloadSize) {
-                case 0:
-                  payload = 0n;
-                  break;
-                case 8:
-                  payload = dataView.getBigUint64(offset, true);
-                  offset += 8;
-                  break;
-                default:
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  break;
-              }
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wRequired[' + i0 + '][' + i1 + '] must be a boolean';
+            }
+          }
+        }
+      }
+    }
+    return undefined;
+  }
+
+  if ('xRequired' in message) {
+    if ('$field' in message && message.$field !== 'xRequired') {
+      return '.$field must be "xRequired"';
+    }
+    const payload = message.xRequired;
+    if (!Array.isArray(payload)) {
+      return '.xRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xRequired[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xRequired[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
             }
-            newPayload = Array(Number(payload)).fill(null) as null[];
           }
-          payload = newPayload;
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'jOptional',
-          jOptional: payload,
-          $fallback,
-        };
       }
-      case 66n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number[] = [];
+    }
+    return undefined;
+  }
+
+  if ('yRequired' in message) {
+    if ('$field' in message && message.$field !== 'yRequired') {
+      return '.$field must be "yRequired"';
+    }
+    const payload = message.yRequired;
+    if (!Array.isArray(payload)) {
+      return '.yRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yRequired[' + i0 + '] must be an array';
+        }
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload = dataView.getFloat64(offset, true);
-                offset += 8;
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yRequired[' + i0 + '][' + i1 + '] must be a string';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'kOptional',
-          kOptional: payload,
-          $fallback,
-        };
       }
-      case 67n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[] = [];
+    }
+    return undefined;
+  }
+
+  if ('zRequired' in message) {
+    if ('$field' in message && message.$field !== 'zRequired') {
+      return '.$field must be "zRequired"';
+    }
+    const payload = message.zRequired;
+    if (!Array.isArray(payload)) {
+      return '.zRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zRequired[' + i0 + '] must be an array';
+        }
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: bigint;
-                [offset, payload] = deserializeVarint(dataView, offset);
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zRequired[' + i0 + '][' + i1 + ']' + error;
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'lOptional',
-          lOptional: payload,
-          $fallback,
-        };
       }
-      case 68n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[] = [];
+    }
+    return undefined;
+  }
+
+  if ('aaRequired' in message) {
+    if ('$field' in message && message.$field !== 'aaRequired') {
+      return '.$field must be "aaRequired"';
+    }
+    const payload = message.aaRequired;
+    if (!Array.isArray(payload)) {
+      return '.aaRequired must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaRequired[' + i0 + '] must be an array';
+        }
         {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: bigint;
-                [offset, payload] = deserializeVarint(dataView, offset);
-                payload = zigzagDecode(payload);
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaRequired[' + i0 + '][' + i1 + ']' + error;
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'mOptional',
-          mOptional: payload,
-          $fallback,
-        };
       }
-      case 69n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: boolean[] = [];
-        {
-          const payloadAlias = payload;
-          {
-            while (true) {
-              try {
-                let payload: boolean;
-                {
-                  let newPayload: boolean;
-                  {
-                    let payload: bigint;
-                    [offset, payload] = deserializeVarint(dataView, offset);
-                    newPayload = payload !== 0n;
-                  }
-                  payload = newPayload;
-                }
-                payloadAlias.push(payload);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-            }
-          }
+    }
+    return undefined;
+  }
+
+  if ('aAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'aAsymmetric') {
+      return '.$field must be "aAsymmetric"';
+    }
+    const payload = message.aAsymmetric;
+    if (payload !== null) {
+      return '.aAsymmetric must be null';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('bAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'bAsymmetric') {
+      return '.$field must be "bAsymmetric"';
+    }
+    const payload = message.bAsymmetric;
+    if (typeof payload !== 'number') {
+      return '.bAsymmetric must be a number';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('cAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'cAsymmetric') {
+      return '.$field must be "cAsymmetric"';
+    }
+    const payload = message.cAsymmetric;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cAsymmetric must be a bigint from 0 to 18446744073709551615';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('dAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'dAsymmetric') {
+      return '.$field must be "dAsymmetric"';
+    }
+    const payload = message.dAsymmetric;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dAsymmetric must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('eAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'eAsymmetric') {
+      return '.$field must be "eAsymmetric"';
+    }
+    const payload = message.eAsymmetric;
+    if (typeof payload !== 'boolean') {
+      return '.eAsymmetric must be a boolean';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('fAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'fAsymmetric') {
+      return '.$field must be "fAsymmetric"';
+    }
+    const payload = message.fAsymmetric;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fAsymmetric must be an ArrayBuffer';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('gAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'gAsymmetric') {
+      return '.$field must be "gAsymmetric"';
+    }
+    const payload = message.gAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.gAsymmetric must be a string';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('hAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'hAsymmetric') {
+      return '.$field must be "hAsymmetric"';
+    }
+    const payload = message.hAsymmetric;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hAsymmetric' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('iAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'iAsymmetric') {
+      return '.$field must be "iAsymmetric"';
+    }
+    const payload = message.iAsymmetric;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iAsymmetric' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('jAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'jAsymmetric') {
+      return '.$field must be "jAsymmetric"';
+    }
+    const payload = message.jAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.jAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jAsymmetric[' + i0 + '] must be null';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('kAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'kAsymmetric') {
+      return '.$field must be "kAsymmetric"';
+    }
+    const payload = message.kAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.kAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kAsymmetric[' + i0 + '] must be a number';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('lAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'lAsymmetric') {
+      return '.$field must be "lAsymmetric"';
+    }
+    const payload = message.lAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.lAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lAsymmetric[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('mAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'mAsymmetric') {
+      return '.$field must be "mAsymmetric"';
+    }
+    const payload = message.mAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.mAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mAsymmetric[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'nAsymmetric') {
+      return '.$field must be "nAsymmetric"';
+    }
+    const payload = message.nAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.nAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nAsymmetric[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('oAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'oAsymmetric') {
+      return '.$field must be "oAsymmetric"';
+    }
+    const payload = message.oAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.oAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oAsymmetric[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('pAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'pAsymmetric') {
+      return '.$field must be "pAsymmetric"';
+    }
+    const payload = message.pAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.pAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pAsymmetric[' + i0 + '] must be a string';
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'nOptional',
-          nOptional: payload,
-          $fallback,
-        };
       }
-      case 70n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: ArrayBuffer[] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('qAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'qAsymmetric') {
+      return '.$field must be "qAsymmetric"';
+    }
+    const payload = message.qAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.qAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('rAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'rAsymmetric') {
+      return '.$field must be "rAsymmetric"';
+    }
+    const payload = message.rAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.rAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rAsymmetric[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('sAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'sAsymmetric') {
+      return '.$field must be "sAsymmetric"';
+    }
+    const payload = message.sAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.sAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = (dataView.buffer as ArrayBuffer).slice(
-                dataView.byteOffset + offset,
-                dataView.byteOffset + dataView.byteLength,
-              );
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sAsymmetric[' + i0 + '][' + i1 + '] must be null';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'oOptional',
-          oOptional: payload,
-          $fallback,
-        };
       }
-      case 71n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: string[] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('tAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'tAsymmetric') {
+      return '.$field must be "tAsymmetric"';
+    }
+    const payload = message.tAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.tAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = textDecoder.decode(
-                new DataView(
-                  dataView.buffer as ArrayBuffer,
-                  dataView.byteOffset + offset,
-                  dataView.byteLength - offset,
-                ),
-              );
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tAsymmetric[' + i0 + '][' + i1 + '] must be a number';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'pOptional',
-          pOptional: payload,
-          $fallback,
-        };
       }
-      case 72n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: LocalStructIn[] = [];
-        {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = LocalStruct.deserializeUnsafe(dataView);
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('uAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'uAsymmetric') {
+      return '.$field must be "uAsymmetric"';
+    }
+    const payload = message.uAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.uAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'qOptional',
-          qOptional: payload,
-          $fallback,
-        };
       }
-      case 73n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: _Types.EmptyStructIn[] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('vAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'vAsymmetric') {
+      return '.$field must be "vAsymmetric"';
+    }
+    const payload = message.vAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.vAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-              offset = dataView.byteLength;
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vAsymmetric[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'rOptional',
-          rOptional: payload,
-          $fallback,
-        };
       }
-      case 74n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: null[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('wAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'wAsymmetric') {
+      return '.$field must be "wAsymmetric"';
+    }
+    const payload = message.wAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.wAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: null[];
-              {
-                let newPayload: null[];
-                {
-                  let payload: bigint;
-                  [offset, payload] = deserializeVarint(dataView, offset);
-                  newPayload = Array(Number(payload)).fill(null) as null[];
-                }
-                payload = newPayload;
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wAsymmetric[' + i0 + '][' + i1 + '] must be a boolean';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'sOptional',
-          sOptional: payload,
-          $fallback,
-        };
       }
-      case 75n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: number[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('xAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'xAsymmetric') {
+      return '.$field must be "xAsymmetric"';
+    }
+    const payload = message.xAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.xAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: number[] = [];
-              {
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    try {
-                      let payload = dataView.getFloat64(offset, true);
-                      offset += 8;
-                      payloadAlias.push(payload);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xAsymmetric[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'tOptional',
-          tOptional: payload,
-          $fallback,
-        };
       }
-      case 76n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[][] = [];
-        {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: bigint[] = [];
-              {
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    try {
-                      let payload: bigint;
-                      [offset, payload] = deserializeVarint(dataView, offset);
-                      payloadAlias.push(payload);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('yAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'yAsymmetric') {
+      return '.$field must be "yAsymmetric"';
+    }
+    const payload = message.yAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.yAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yAsymmetric[' + i0 + '][' + i1 + '] must be a string';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'uOptional',
-          uOptional: payload,
-          $fallback,
-        };
       }
-      case 77n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: bigint[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('zAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'zAsymmetric') {
+      return '.$field must be "zAsymmetric"';
+    }
+    const payload = message.zAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.zAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zAsymmetric[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: bigint[] = [];
-              {
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    try {
-                      let payload: bigint;
-                      [offset, payload] = deserializeVarint(dataView, offset);
-                      payload = zigzagDecode(payload);
-                      payloadAlias.push(payload);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zAsymmetric[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aaAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'aaAsymmetric') {
+      return '.$field must be "aaAsymmetric"';
+    }
+    const payload = message.aaAsymmetric;
+    if (!Array.isArray(payload)) {
+      return '.aaAsymmetric must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaAsymmetric[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaAsymmetric[' + i0 + '][' + i1 + ']' + error;
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'vOptional',
-          vOptional: payload,
-          $fallback,
-        };
       }
-      case 78n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: boolean[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aOptional' in message) {
+    if ('$field' in message && message.$field !== 'aOptional') {
+      return '.$field must be "aOptional"';
+    }
+    const payload = message.aOptional;
+    if (payload !== null) {
+      return '.aOptional must be null';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('bOptional' in message) {
+    if ('$field' in message && message.$field !== 'bOptional') {
+      return '.$field must be "bOptional"';
+    }
+    const payload = message.bOptional;
+    if (typeof payload !== 'number') {
+      return '.bOptional must be a number';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('cOptional' in message) {
+    if ('$field' in message && message.$field !== 'cOptional') {
+      return '.$field must be "cOptional"';
+    }
+    const payload = message.cOptional;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.cOptional must be a bigint from 0 to 18446744073709551615';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('dOptional' in message) {
+    if ('$field' in message && message.$field !== 'dOptional') {
+      return '.$field must be "dOptional"';
+    }
+    const payload = message.dOptional;
+    if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+      return '.dOptional must be a bigint from -9223372036854775808 to 9223372036854775807';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('eOptional' in message) {
+    if ('$field' in message && message.$field !== 'eOptional') {
+      return '.$field must be "eOptional"';
+    }
+    const payload = message.eOptional;
+    if (typeof payload !== 'boolean') {
+      return '.eOptional must be a boolean';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('fOptional' in message) {
+    if ('$field' in message && message.$field !== 'fOptional') {
+      return '.$field must be "fOptional"';
+    }
+    const payload = message.fOptional;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.fOptional must be an ArrayBuffer';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('gOptional' in message) {
+    if ('$field' in message && message.$field !== 'gOptional') {
+      return '.$field must be "gOptional"';
+    }
+    const payload = message.gOptional;
+    if (typeof payload !== 'string') {
+      return '.gOptional must be a string';
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('hOptional' in message) {
+    if ('$field' in message && message.$field !== 'hOptional') {
+      return '.$field must be "hOptional"';
+    }
+    const payload = message.hOptional;
+    const error = LocalStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.hOptional' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('iOptional' in message) {
+    if ('$field' in message && message.$field !== 'iOptional') {
+      return '.$field must be "iOptional"';
+    }
+    const payload = message.iOptional;
+    const error = _Types.EmptyStruct.checkOut(payload);
+    if (error !== undefined) {
+      return '.iOptional' + error;
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('jOptional' in message) {
+    if ('$field' in message && message.$field !== 'jOptional') {
+      return '.$field must be "jOptional"';
+    }
+    const payload = message.jOptional;
+    if (!Array.isArray(payload)) {
+      return '.jOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (payload !== null) {
+          return '.jOptional[' + i0 + '] must be null';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('kOptional' in message) {
+    if ('$field' in message && message.$field !== 'kOptional') {
+      return '.$field must be "kOptional"';
+    }
+    const payload = message.kOptional;
+    if (!Array.isArray(payload)) {
+      return '.kOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'number') {
+          return '.kOptional[' + i0 + '] must be a number';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('lOptional' in message) {
+    if ('$field' in message && message.$field !== 'lOptional') {
+      return '.$field must be "lOptional"';
+    }
+    const payload = message.lOptional;
+    if (!Array.isArray(payload)) {
+      return '.lOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+          return '.lOptional[' + i0 + '] must be a bigint from 0 to 18446744073709551615';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('mOptional' in message) {
+    if ('$field' in message && message.$field !== 'mOptional') {
+      return '.$field must be "mOptional"';
+    }
+    const payload = message.mOptional;
+    if (!Array.isArray(payload)) {
+      return '.mOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+          return '.mOptional[' + i0 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nOptional' in message) {
+    if ('$field' in message && message.$field !== 'nOptional') {
+      return '.$field must be "nOptional"';
+    }
+    const payload = message.nOptional;
+    if (!Array.isArray(payload)) {
+      return '.nOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'boolean') {
+          return '.nOptional[' + i0 + '] must be a boolean';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('oOptional' in message) {
+    if ('$field' in message && message.$field !== 'oOptional') {
+      return '.$field must be "oOptional"';
+    }
+    const payload = message.oOptional;
+    if (!Array.isArray(payload)) {
+      return '.oOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!(payload instanceof ArrayBuffer)) {
+          return '.oOptional[' + i0 + '] must be an ArrayBuffer';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('pOptional' in message) {
+    if ('$field' in message && message.$field !== 'pOptional') {
+      return '.$field must be "pOptional"';
+    }
+    const payload = message.pOptional;
+    if (!Array.isArray(payload)) {
+      return '.pOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (typeof payload !== 'string') {
+          return '.pOptional[' + i0 + '] must be a string';
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('qOptional' in message) {
+    if ('$field' in message && message.$field !== 'qOptional') {
+      return '.$field must be "qOptional"';
+    }
+    const payload = message.qOptional;
+    if (!Array.isArray(payload)) {
+      return '.qOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = LocalStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.qOptional[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('rOptional' in message) {
+    if ('$field' in message && message.$field !== 'rOptional') {
+      return '.$field must be "rOptional"';
+    }
+    const payload = message.rOptional;
+    if (!Array.isArray(payload)) {
+      return '.rOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        const error = _Types.EmptyStruct.checkOut(payload);
+        if (error !== undefined) {
+          return '.rOptional[' + i0 + ']' + error;
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('sOptional' in message) {
+    if ('$field' in message && message.$field !== 'sOptional') {
+      return '.$field must be "sOptional"';
+    }
+    const payload = message.sOptional;
+    if (!Array.isArray(payload)) {
+      return '.sOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.sOptional[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: boolean[] = [];
-              {
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    try {
-                      let payload: boolean;
-                      {
-                        let newPayload: boolean;
-                        {
-                          let payload: bigint;
-                          [offset, payload] = deserializeVarint(dataView, offset);
-                          newPayload = payload !== 0n;
-                        }
-                        payload = newPayload;
-                      }
-                      payloadAlias.push(payload);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (payload !== null) {
+              return '.sOptional[' + i0 + '][' + i1 + '] must be null';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'wOptional',
-          wOptional: payload,
-          $fallback,
-        };
       }
-      case 79n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: ArrayBuffer[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('tOptional' in message) {
+    if ('$field' in message && message.$field !== 'tOptional') {
+      return '.$field must be "tOptional"';
+    }
+    const payload = message.tOptional;
+    if (!Array.isArray(payload)) {
+      return '.tOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.tOptional[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: ArrayBuffer[] = [];
-              {
-                const dataViewAlias = dataView;
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    let payloadSizeBig: bigint;
-                    try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                    const dataView = new DataView(
-                      dataViewAlias.buffer as ArrayBuffer,
-                      dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
-                    );
-                    const oldOffset = offset;
-                    offset = 0;
-                    let payload = (dataView.buffer as ArrayBuffer).slice(
-                      dataView.byteOffset + offset,
-                      dataView.byteOffset + dataView.byteLength,
-                    );
-                    offset = dataView.byteLength;
-                    offset += oldOffset;
-                    payloadAlias.push(payload);
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'number') {
+              return '.tOptional[' + i0 + '][' + i1 + '] must be a number';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'xOptional',
-          xOptional: payload,
-          $fallback,
-        };
       }
-      case 80n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: string[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('uOptional' in message) {
+    if ('$field' in message && message.$field !== 'uOptional') {
+      return '.$field must be "uOptional"';
+    }
+    const payload = message.uOptional;
+    if (!Array.isArray(payload)) {
+      return '.uOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.uOptional[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: string[] = [];
-              {
-                const dataViewAlias = dataView;
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    let payloadSizeBig: bigint;
-                    try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                    const dataView = new DataView(
-                      dataViewAlias.buffer as ArrayBuffer,
-                      dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
-                    );
-                    const oldOffset = offset;
-                    offset = 0;
-                    let payload = textDecoder.decode(
-                      new DataView(
-                        dataView.buffer as ArrayBuffer,
-                        dataView.byteOffset + offset,
-                        dataView.byteLength - offset,
-                      ),
-                    );
-                    offset = dataView.byteLength;
-                    offset += oldOffset;
-                    payloadAlias.push(payload);
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+              return '.uOptional[' + i0 + '][' + i1 + '] must be a bigint from 0 to 18446744073709551615';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('vOptional' in message) {
+    if ('$field' in message && message.$field !== 'vOptional') {
+      return '.$field must be "vOptional"';
+    }
+    const payload = message.vOptional;
+    if (!Array.isArray(payload)) {
+      return '.vOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.vOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'bigint' || payload < -9_223_372_036_854_775_808n || payload > 9_223_372_036_854_775_807n) {
+              return '.vOptional[' + i0 + '][' + i1 + '] must be a bigint from -9223372036854775808 to 9223372036854775807';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'yOptional',
-          yOptional: payload,
-          $fallback,
-        };
       }
-      case 81n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: LocalStructIn[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('wOptional' in message) {
+    if ('$field' in message && message.$field !== 'wOptional') {
+      return '.$field must be "wOptional"';
+    }
+    const payload = message.wOptional;
+    if (!Array.isArray(payload)) {
+      return '.wOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.wOptional[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: LocalStructIn[] = [];
-              {
-                const dataViewAlias = dataView;
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    let payloadSizeBig: bigint;
-                    try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                    const dataView = new DataView(
-                      dataViewAlias.buffer as ArrayBuffer,
-                      dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
-                    );
-                    const oldOffset = offset;
-                    offset = 0;
-                    let payload = LocalStruct.deserializeUnsafe(dataView);
-                    offset = dataView.byteLength;
-                    offset += oldOffset;
-                    payloadAlias.push(payload);
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'boolean') {
+              return '.wOptional[' + i0 + '][' + i1 + '] must be a boolean';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'zOptional',
-          zOptional: payload,
-          $fallback,
-        };
       }
-      case 82n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
-        let payload: _Types.EmptyStructIn[][] = [];
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('xOptional' in message) {
+    if ('$field' in message && message.$field !== 'xOptional') {
+      return '.$field must be "xOptional"';
+    }
+    const payload = message.xOptional;
+    if (!Array.isArray(payload)) {
+      return '.xOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.xOptional[' + i0 + '] must be an array';
+        }
         {
-          const dataViewAlias = dataView;
-          const payloadAlias = payload;
-          {
-            while (true) {
-              let payloadSizeBig: bigint;
-              try {
-                [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-              } catch (e) {
-                if (e instanceof RangeError) {
-                  break;
-                } else {
-                  throw e;
-                }
-              }
-              const dataView = new DataView(
-                dataViewAlias.buffer as ArrayBuffer,
-                dataViewAlias.byteOffset + offset,
-                Number(payloadSizeBig),
-              );
-              const oldOffset = offset;
-              offset = 0;
-              let payload: _Types.EmptyStructIn[] = [];
-              {
-                const dataViewAlias = dataView;
-                const payloadAlias = payload;
-                {
-                  while (true) {
-                    let payloadSizeBig: bigint;
-                    try {
-                      [offset, payloadSizeBig] = deserializeVarint(dataViewAlias, offset);
-                    } catch (e) {
-                      if (e instanceof RangeError) {
-                        break;
-                      } else {
-                        throw e;
-                      }
-                    }
-                    const dataView = new DataView(
-                      dataViewAlias.buffer as ArrayBuffer,
-                      dataViewAlias.byteOffset + offset,
-                      Number(payloadSizeBig),
-                    );
-                    const oldOffset = offset;
-                    offset = 0;
-                    let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
-                    offset = dataView.byteLength;
-                    offset += oldOffset;
-                    payloadAlias.push(payload);
-                  }
-                }
-              }
-              offset += oldOffset;
-              payloadAlias.push(payload);
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (!(payload instanceof ArrayBuffer)) {
+              return '.xOptional[' + i0 + '][' + i1 + '] must be an ArrayBuffer';
             }
           }
         }
-        offset += oldOffset;
-        const $fallback = barDeserializeUnsafe(
-          new DataView(
-            dataViewAlias.buffer as ArrayBuffer,
-            dataViewAlias.byteOffset + offset,
-            dataViewAlias.byteLength - offset,
-          ),
-        );
-        return {
-          $field: 'aaOptional',
-          aaOptional: payload,
-          $fallback,
-        };
       }
-      default:
-        offset += payloadSize;
-        break;
     }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
   }
-}
 
-function barSerialize(message: BarOut): ArrayBuffer {
-  const atlasValue = barAtlas(message);
-  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
-  const dataView = new DataView(arrayBuffer);
-  barSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
-  return arrayBuffer;
-}
+  if ('yOptional' in message) {
+    if ('$field' in message && message.$field !== 'yOptional') {
+      return '.$field must be "yOptional"';
+    }
+    const payload = message.yOptional;
+    if (!Array.isArray(payload)) {
+      return '.yOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.yOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            if (typeof payload !== 'string') {
+              return '.yOptional[' + i0 + '][' + i1 + '] must be a string';
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
 
-function barDeserialize(bytes: Deserializable): BarIn | Error {
-  try {
-    return barDeserializeUnsafe(dataViewFromDeserializable(bytes));
-  } catch (e) {
-    return e as Error;
+  if ('zOptional' in message) {
+    if ('$field' in message && message.$field !== 'zOptional') {
+      return '.$field must be "zOptional"';
+    }
+    const payload = message.zOptional;
+    if (!Array.isArray(payload)) {
+      return '.zOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.zOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = LocalStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.zOptional[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('aaOptional' in message) {
+    if ('$field' in message && message.$field !== 'aaOptional') {
+      return '.$field must be "aaOptional"';
+    }
+    const payload = message.aaOptional;
+    if (!Array.isArray(payload)) {
+      return '.aaOptional must be an array';
+    }
+    {
+      const oldPayload = payload;
+      for (let i0 = 0; i0 < oldPayload.length; i0 += 1) {
+        const payload = oldPayload[i0];
+        if (!Array.isArray(payload)) {
+          return '.aaOptional[' + i0 + '] must be an array';
+        }
+        {
+          const oldPayload = payload;
+          for (let i1 = 0; i1 < oldPayload.length; i1 += 1) {
+            const payload = oldPayload[i1];
+            const error = _Types.EmptyStruct.checkOut(payload);
+            if (error !== undefined) {
+              return '.aaOptional[' + i0 + '][' + i1 + ']' + error;
+            }
+          }
+        }
+      }
+    }
+    const fallbackError = barCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
   }
+
+  return ' must have one of the fields aRequired, bRequired, cRequired, dRequired, eRequired, fRequired, gRequired, hRequired, iRequired, jRequired, kRequired, lRequired, mRequired, nRequired, oRequired, pRequired, qRequired, rRequired, sRequired, tRequired, uRequired, vRequired, wRequired, xRequired, yRequired, zRequired, aaRequired, aAsymmetric, bAsymmetric, cAsymmetric, dAsymmetric, eAsymmetric, fAsymmetric, gAsymmetric, hAsymmetric, iAsymmetric, jAsymmetric, kAsymmetric, lAsymmetric, mAsymmetric, nAsymmetric, oAsymmetric, pAsymmetric, qAsymmetric, rAsymmetric, sAsymmetric, tAsymmetric, uAsymmetric, vAsymmetric, wAsymmetric, xAsymmetric, yAsymmetric, zAsymmetric, aaAsymmetric, aOptional, bOptional, cOptional, dOptional, eOptional, fOptional, gOptional, hOptional, iOptional, jOptional, kOptional, lOptional, mOptional, nOptional, oOptional, pOptional, qOptional, rOptional, sOptional, tOptional, uOptional, vOptional, wOptional, xOptional, yOptional, zOptional, aaOptional';
 }
 
 export const Bar = {
@@ -14905,4 +17845,16 @@ export const Bar = {
   deserializeUnsafe: barDeserializeUnsafe,
   serialize: barSerialize,
   deserialize: barDeserialize,
+  checkOut: barCheckOut,
 };
+
+export function isBarOut(value: unknown): value is BarOut {
+  return barCheckOut(value) === undefined;
+}
+
+export function assertBarOut(value: unknown): asserts value is BarOut {
+  const error = barCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid BarOut: value' + error + '.');
+  }
+}
@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -56,7 +60,7 @@ function emptyStructDeserializeUnsafe(dataView: DataView): EmptyStructIn {
 
   let offset = 0;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -97,6 +101,14 @@ function emptyStructDeserialize(bytes: Deserializable): EmptyStructIn | Error {
   }
 }
 
+function emptyStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return undefined;
+}
+
 export const EmptyStruct = {
   atlas: emptyStructAtlas,
   size: emptyStructSize,
@@ -104,8 +116,20 @@ export const EmptyStruct = {
   deserializeUnsafe: emptyStructDeserializeUnsafe,
   serialize: emptyStructSerialize,
   deserialize: emptyStructDeserialize,
+  checkOut: emptyStructCheckOut,
 };
 
+export function isEmptyStructOut(value: unknown): value is EmptyStructOut {
+  return emptyStructCheckOut(value) === undefined;
+}
+
+export function assertEmptyStructOut(value: unknown): asserts value is EmptyStructOut {
+  const error = emptyStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyStructOut: value' + error + '.');
+  }
+}
+
 export type EmptyChoiceAtlas = never;
 
 export type EmptyChoiceOut = never;
@@ -158,6 +182,14 @@ function emptyChoiceDeserialize(bytes: Deserializable): EmptyChoiceIn | Error {
   }
 }
 
+function emptyChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  return ' must not exist, since the choice has no fields';
+}
+
 export const EmptyChoice = {
   atlas: emptyChoiceAtlas,
   size: emptyChoiceSize,
@@ -165,4 +197,16 @@ export const EmptyChoice = {
   deserializeUnsafe: emptyChoiceDeserializeUnsafe,
   serialize: emptyChoiceSerialize,
   deserialize: emptyChoiceDeserialize,
+  checkOut: emptyChoiceCheckOut,
 };
+
+export function isEmptyChoiceOut(value: unknown): value is EmptyChoiceOut {
+  return emptyChoiceCheckOut(value) === undefined;
+}
+
+export function assertEmptyChoiceOut(value: unknown): asserts value is EmptyChoiceOut {
+  const error = emptyChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyChoiceOut: value' + error + '.');
+  }
+}
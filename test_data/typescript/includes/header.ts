@@ -0,0 +1,460 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common';
+import type { Deserializable } from '../common';
+
+import * as _Types from '../degenerate/types';
+
+export type TenantAtlas = {
+  $size: number;
+  name: Uint8Array;
+};
+
+export type TenantOut = {
+  name: string;
+};
+
+export type TenantIn = {
+  name: string;
+};
+
+function tenantAtlas(message: TenantOut): TenantAtlas {
+  let size = 0;
+
+  let $name: Uint8Array;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.name;
+    payloadAtlas = textEncoder.encode(payload);
+    $name = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    name: $name,
+  };
+}
+
+function tenantSize(message: TenantOut): number {
+  return tenantAtlas(message).$size;
+}
+
+function tenantSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: TenantOut,
+  atlas: TenantAtlas,
+): number {
+  {
+    const payload = message.name;
+    const payloadAtlas = atlas.name;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function tenantDeserializeUnsafe(dataView: DataView): TenantIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $name: string | undefined;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $name = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($name === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    name: $name,
+  };
+}
+
+function tenantSerialize(message: TenantOut): ArrayBuffer {
+  const atlasValue = tenantAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  tenantSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function tenantDeserialize(bytes: Deserializable): TenantIn | Error {
+  try {
+    return tenantDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function tenantCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.name;
+    if (typeof payload !== 'string') {
+      return '.name must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const Tenant = {
+  atlas: tenantAtlas,
+  size: tenantSize,
+  serializeWithAtlasUnsafe: tenantSerializeWithAtlasUnsafe,
+  deserializeUnsafe: tenantDeserializeUnsafe,
+  serialize: tenantSerialize,
+  deserialize: tenantDeserialize,
+  checkOut: tenantCheckOut,
+};
+
+export function isTenantOut(value: unknown): value is TenantOut {
+  return tenantCheckOut(value) === undefined;
+}
+
+export function assertTenantOut(value: unknown): asserts value is TenantOut {
+  const error = tenantCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid TenantOut: value' + error + '.');
+  }
+}
+
+export type HeaderAtlas = {
+  $size: number;
+  requestId: Uint8Array;
+  tenant: TenantAtlas;
+  marker: _Types.EmptyStructAtlas | undefined;
+};
+
+export type HeaderOut = {
+  requestId: string;
+  tenant: TenantOut;
+  marker: _Types.EmptyStructOut | undefined;
+};
+
+export type HeaderIn = {
+  requestId: string;
+  tenant: TenantIn;
+  marker: _Types.EmptyStructIn | undefined;
+};
+
+function headerAtlas(message: HeaderOut): HeaderAtlas {
+  let size = 0;
+
+  let $requestId: Uint8Array;
+  let $tenant: TenantAtlas;
+  let $marker: _Types.EmptyStructAtlas | undefined;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.requestId;
+    payloadAtlas = textEncoder.encode(payload);
+    $requestId = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: TenantAtlas;
+    const payload = message.tenant;
+    payloadAtlas = Tenant.atlas(payload);
+    $tenant = payloadAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: _Types.EmptyStructAtlas;
+    const payload = message.marker;
+    if (payload !== undefined) {
+      payloadAtlas = _Types.EmptyStruct.atlas(payload);
+      $marker = payloadAtlas;
+      const payloadSize = (payloadAtlas as { $size: number }).$size;
+      size += fieldHeaderSize(3n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  return {
+    $size: size,
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+  };
+}
+
+function headerSize(message: HeaderOut): number {
+  return headerAtlas(message).$size;
+}
+
+function headerSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: HeaderOut,
+  atlas: HeaderAtlas,
+): number {
+  {
+    const payload = message.requestId;
+    const payloadAtlas = atlas.requestId;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const payloadAtlas = atlas.tenant;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    offset = Tenant.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.marker;
+    const payloadAtlas = atlas.marker;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas as { $size: number }).$size;
+      offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, false);
+      offset = _Types.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  return offset;
+}
+
+function headerDeserializeUnsafe(dataView: DataView): HeaderIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $requestId: string | undefined;
+  let $tenant: TenantIn | undefined;
+  let $marker: _Types.EmptyStructIn | undefined;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requestId = payload;
+        break;
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = Tenant.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $tenant = payload;
+        break;
+      }
+      case 3n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Types.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $marker = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($requestId === undefined
+    || $tenant === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+  };
+}
+
+function headerSerialize(message: HeaderOut): ArrayBuffer {
+  const atlasValue = headerAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  headerSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function headerDeserialize(bytes: Deserializable): HeaderIn | Error {
+  try {
+    return headerDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function headerCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.requestId;
+    if (typeof payload !== 'string') {
+      return '.requestId must be a string';
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const error = Tenant.checkOut(payload);
+    if (error !== undefined) {
+      return '.tenant' + error;
+    }
+  }
+
+  {
+    const payload = message.marker;
+    if (payload !== undefined) {
+      const error = _Types.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.marker' + error;
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const Header = {
+  atlas: headerAtlas,
+  size: headerSize,
+  serializeWithAtlasUnsafe: headerSerializeWithAtlasUnsafe,
+  deserializeUnsafe: headerDeserializeUnsafe,
+  serialize: headerSerialize,
+  deserialize: headerDeserialize,
+  checkOut: headerCheckOut,
+};
+
+export function isHeaderOut(value: unknown): value is HeaderOut {
+  return headerCheckOut(value) === undefined;
+}
+
+export function assertHeaderOut(value: unknown): asserts value is HeaderOut {
+  const error = headerCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid HeaderOut: value' + error + '.');
+  }
+}
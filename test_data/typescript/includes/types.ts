@@ -0,0 +1,348 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common';
+import type { Deserializable } from '../common';
+
+import * as _Header from './header';
+import * as _Included0 from '../degenerate/types';
+
+export type RequestAtlas = {
+  $size: number;
+  requestId: Uint8Array;
+  tenant: _Header.TenantAtlas;
+  marker: _Included0.EmptyStructAtlas | undefined;
+  body: Uint8Array;
+};
+
+export type RequestOut = {
+  requestId: string;
+  tenant: _Header.TenantOut;
+  marker: _Included0.EmptyStructOut | undefined;
+  body: string;
+};
+
+export type RequestIn = {
+  requestId: string;
+  tenant: _Header.TenantIn;
+  marker: _Included0.EmptyStructIn | undefined;
+  body: string;
+};
+
+function requestAtlas(message: RequestOut): RequestAtlas {
+  let size = 0;
+
+  let $requestId: Uint8Array;
+  let $tenant: _Header.TenantAtlas;
+  let $marker: _Included0.EmptyStructAtlas | undefined;
+  let $body: Uint8Array;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.requestId;
+    payloadAtlas = textEncoder.encode(payload);
+    $requestId = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(1n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: _Header.TenantAtlas;
+    const payload = message.tenant;
+    payloadAtlas = _Header.Tenant.atlas(payload);
+    $tenant = payloadAtlas;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    size += fieldHeaderSize(2n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: _Included0.EmptyStructAtlas;
+    const payload = message.marker;
+    if (payload !== undefined) {
+      payloadAtlas = _Included0.EmptyStruct.atlas(payload);
+      $marker = payloadAtlas;
+      const payloadSize = (payloadAtlas as { $size: number }).$size;
+      size += fieldHeaderSize(4n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.body;
+    payloadAtlas = textEncoder.encode(payload);
+    $body = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+    body: $body,
+  };
+}
+
+function requestSize(message: RequestOut): number {
+  return requestAtlas(message).$size;
+}
+
+function requestSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: RequestOut,
+  atlas: RequestAtlas,
+): number {
+  {
+    const payload = message.requestId;
+    const payloadAtlas = atlas.requestId;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const payloadAtlas = atlas.tenant;
+    const payloadSize = (payloadAtlas as { $size: number }).$size;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+    offset = _Header.Tenant.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+  }
+
+  {
+    const payload = message.marker;
+    const payloadAtlas = atlas.marker;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = (payloadAtlas as { $size: number }).$size;
+      offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, false);
+      offset = _Included0.EmptyStruct.serializeWithAtlasUnsafe(dataView, offset, payload, payloadAtlas);
+    }
+  }
+
+  {
+    const payload = message.body;
+    const payloadAtlas = atlas.body;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function requestDeserializeUnsafe(dataView: DataView): RequestIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $requestId: string | undefined;
+  let $tenant: _Header.TenantIn | undefined;
+  let $marker: _Included0.EmptyStructIn | undefined;
+  let $body: string | undefined;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $requestId = payload;
+        break;
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Header.Tenant.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $tenant = payload;
+        break;
+      }
+      case 4n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = _Included0.EmptyStruct.deserializeUnsafe(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $marker = payload;
+        break;
+      }
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $body = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($requestId === undefined
+    || $tenant === undefined
+    || $body === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    requestId: $requestId,
+    tenant: $tenant,
+    marker: $marker,
+    body: $body,
+  };
+}
+
+function requestSerialize(message: RequestOut): ArrayBuffer {
+  const atlasValue = requestAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  requestSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function requestDeserialize(bytes: Deserializable): RequestIn | Error {
+  try {
+    return requestDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function requestCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.requestId;
+    if (typeof payload !== 'string') {
+      return '.requestId must be a string';
+    }
+  }
+
+  {
+    const payload = message.tenant;
+    const error = _Header.Tenant.checkOut(payload);
+    if (error !== undefined) {
+      return '.tenant' + error;
+    }
+  }
+
+  {
+    const payload = message.marker;
+    if (payload !== undefined) {
+      const error = _Included0.EmptyStruct.checkOut(payload);
+      if (error !== undefined) {
+        return '.marker' + error;
+      }
+    }
+  }
+
+  {
+    const payload = message.body;
+    if (typeof payload !== 'string') {
+      return '.body must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const Request = {
+  atlas: requestAtlas,
+  size: requestSize,
+  serializeWithAtlasUnsafe: requestSerializeWithAtlasUnsafe,
+  deserializeUnsafe: requestDeserializeUnsafe,
+  serialize: requestSerialize,
+  deserialize: requestDeserialize,
+  checkOut: requestCheckOut,
+};
+
+export function isRequestOut(value: unknown): value is RequestOut {
+  return requestCheckOut(value) === undefined;
+}
+
+export function assertRequestOut(value: unknown): asserts value is RequestOut {
+  const error = requestCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid RequestOut: value' + error + '.');
+  }
+}
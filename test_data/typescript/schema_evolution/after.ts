@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -397,7 +401,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
   let $nonexistentToAsymmetric: null | undefined;
   let $nonexistentToOptional: null | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -420,13 +424,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToRequired = payload;
@@ -440,13 +438,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToAsymmetric = payload;
@@ -460,13 +452,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToOptional = payload;
@@ -480,13 +466,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToRequired = payload;
@@ -500,13 +480,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToAsymmetric = payload;
@@ -520,13 +494,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToOptional = payload;
@@ -540,13 +508,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToRequired = payload;
@@ -560,13 +522,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToAsymmetric = payload;
@@ -580,41 +536,19 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToOptional = payload;
         break;
       }
       case 13n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         $nonexistentToAsymmetric = payload;
         break;
       }
       case 14n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         $nonexistentToOptional = payload;
         break;
       }
@@ -661,6 +595,101 @@ function exampleStructDeserialize(bytes: Deserializable): ExampleStructIn | Erro
   }
 }
 
+function exampleStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.requiredToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.asymmetricToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToRequired;
+    if (typeof payload !== 'string') {
+      return '.optionalToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.optionalToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.optionalToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.optionalToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.nonexistentToAsymmetric;
+    if (payload !== null) {
+      return '.nonexistentToAsymmetric must be null';
+    }
+  }
+
+  {
+    const payload = message.nonexistentToOptional;
+    if (payload !== undefined) {
+      if (payload !== null) {
+        return '.nonexistentToOptional must be null';
+      }
+    }
+  }
+
+  return undefined;
+}
+
 export const ExampleStruct = {
   atlas: exampleStructAtlas,
   size: exampleStructSize,
@@ -668,8 +697,20 @@ export const ExampleStruct = {
   deserializeUnsafe: exampleStructDeserializeUnsafe,
   serialize: exampleStructSerialize,
   deserialize: exampleStructDeserialize,
+  checkOut: exampleStructCheckOut,
 };
 
+export function isExampleStructOut(value: unknown): value is ExampleStructOut {
+  return exampleStructCheckOut(value) === undefined;
+}
+
+export function assertExampleStructOut(value: unknown): asserts value is ExampleStructOut {
+  const error = exampleStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleStructOut: value' + error + '.');
+  }
+}
+
 export type ExampleChoiceAtlas =
   | { $size: number; requiredToRequired: Uint8Array }
   | { $size: number; requiredToAsymmetric: Uint8Array; $fallback: ExampleChoiceAtlas }
@@ -1006,13 +1047,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'requiredToRequired',
@@ -1027,13 +1062,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'requiredToAsymmetric',
@@ -1048,13 +1077,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToRequired',
@@ -1069,13 +1092,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToAsymmetric',
@@ -1090,13 +1107,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1120,13 +1131,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'optionalToRequired',
@@ -1141,13 +1146,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'optionalToAsymmetric',
@@ -1162,13 +1161,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1185,13 +1178,6 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         };
       }
       case 12n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
         return {
           $field: 'nonexistentToRequired',
@@ -1199,13 +1185,6 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         };
       }
       case 13n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
         return {
           $field: 'nonexistentToAsymmetric',
@@ -1213,15 +1192,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         };
       }
       case 14n: {
-        const dataView = new DataView(
-          dataViewAlias.buffer as ArrayBuffer,
-          dataViewAlias.byteOffset + offset,
-          payloadSize,
-        );
-        const oldOffset = offset;
-        offset = 0;
         let payload = null;
-        offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
           new DataView(
             dataViewAlias.buffer as ArrayBuffer,
@@ -1258,6 +1229,165 @@ function exampleChoiceDeserialize(bytes: Deserializable): ExampleChoiceIn | Erro
   }
 }
 
+function exampleChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('requiredToRequired' in message) {
+    if ('$field' in message && message.$field !== 'requiredToRequired') {
+      return '.$field must be "requiredToRequired"';
+    }
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('requiredToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'requiredToAsymmetric') {
+      return '.$field must be "requiredToAsymmetric"';
+    }
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToRequired' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToRequired') {
+      return '.$field must be "asymmetricToRequired"';
+    }
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToAsymmetric') {
+      return '.$field must be "asymmetricToAsymmetric"';
+    }
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToOptional' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToOptional') {
+      return '.$field must be "asymmetricToOptional"';
+    }
+    const payload = message.asymmetricToOptional;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToRequired' in message) {
+    if ('$field' in message && message.$field !== 'optionalToRequired') {
+      return '.$field must be "optionalToRequired"';
+    }
+    const payload = message.optionalToRequired;
+    if (typeof payload !== 'string') {
+      return '.optionalToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('optionalToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'optionalToAsymmetric') {
+      return '.$field must be "optionalToAsymmetric"';
+    }
+    const payload = message.optionalToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.optionalToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToOptional' in message) {
+    if ('$field' in message && message.$field !== 'optionalToOptional') {
+      return '.$field must be "optionalToOptional"';
+    }
+    const payload = message.optionalToOptional;
+    if (typeof payload !== 'string') {
+      return '.optionalToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToRequired' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToRequired') {
+      return '.$field must be "nonexistentToRequired"';
+    }
+    const payload = message.nonexistentToRequired;
+    if (payload !== null) {
+      return '.nonexistentToRequired must be null';
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToAsymmetric') {
+      return '.$field must be "nonexistentToAsymmetric"';
+    }
+    const payload = message.nonexistentToAsymmetric;
+    if (payload !== null) {
+      return '.nonexistentToAsymmetric must be null';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('nonexistentToOptional' in message) {
+    if ('$field' in message && message.$field !== 'nonexistentToOptional') {
+      return '.$field must be "nonexistentToOptional"';
+    }
+    const payload = message.nonexistentToOptional;
+    if (payload !== null) {
+      return '.nonexistentToOptional must be null';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields requiredToRequired, requiredToAsymmetric, asymmetricToRequired, asymmetricToAsymmetric, asymmetricToOptional, optionalToRequired, optionalToAsymmetric, optionalToOptional, nonexistentToRequired, nonexistentToAsymmetric, nonexistentToOptional';
+}
+
 export const ExampleChoice = {
   atlas: exampleChoiceAtlas,
   size: exampleChoiceSize,
@@ -1265,4 +1395,16 @@ export const ExampleChoice = {
   deserializeUnsafe: exampleChoiceDeserializeUnsafe,
   serialize: exampleChoiceSerialize,
   deserialize: exampleChoiceDeserialize,
+  checkOut: exampleChoiceCheckOut,
 };
+
+export function isExampleChoiceOut(value: unknown): value is ExampleChoiceOut {
+  return exampleChoiceCheckOut(value) === undefined;
+}
+
+export function assertExampleChoiceOut(value: unknown): asserts value is ExampleChoiceOut {
+  const error = exampleChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleChoiceOut: value' + error + '.');
+  }
+}
@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -446,7 +450,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
   let $optionalToOptional: string | undefined;
   let $optionalToNonexistent: string | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -469,13 +473,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToRequired = payload;
@@ -489,13 +487,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToAsymmetric = payload;
@@ -509,13 +501,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToOptional = payload;
@@ -529,13 +515,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $requiredToNonexistent = payload;
@@ -549,13 +529,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToRequired = payload;
@@ -569,13 +543,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToAsymmetric = payload;
@@ -589,13 +557,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToOptional = payload;
@@ -609,13 +571,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $asymmetricToNonexistent = payload;
@@ -629,13 +585,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToRequired = payload;
@@ -649,13 +599,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToAsymmetric = payload;
@@ -669,13 +613,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToOptional = payload;
@@ -689,13 +627,7 @@ function exampleStructDeserializeUnsafe(dataView: DataView): ExampleStructIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $optionalToNonexistent = payload;
@@ -746,6 +678,108 @@ function exampleStructDeserialize(bytes: Deserializable): ExampleStructIn | Erro
   }
 }
 
+function exampleStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToOptional;
+    if (typeof payload !== 'string') {
+      return '.requiredToOptional must be a string';
+    }
+  }
+
+  {
+    const payload = message.requiredToNonexistent;
+    if (typeof payload !== 'string') {
+      return '.requiredToNonexistent must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToOptional;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToOptional must be a string';
+    }
+  }
+
+  {
+    const payload = message.asymmetricToNonexistent;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToNonexistent must be a string';
+    }
+  }
+
+  {
+    const payload = message.optionalToRequired;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToRequired must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToAsymmetric;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToAsymmetric must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToOptional;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToOptional must be a string';
+      }
+    }
+  }
+
+  {
+    const payload = message.optionalToNonexistent;
+    if (payload !== undefined) {
+      if (typeof payload !== 'string') {
+        return '.optionalToNonexistent must be a string';
+      }
+    }
+  }
+
+  return undefined;
+}
+
 export const ExampleStruct = {
   atlas: exampleStructAtlas,
   size: exampleStructSize,
@@ -753,8 +787,20 @@ export const ExampleStruct = {
   deserializeUnsafe: exampleStructDeserializeUnsafe,
   serialize: exampleStructSerialize,
   deserialize: exampleStructDeserialize,
+  checkOut: exampleStructCheckOut,
 };
 
+export function isExampleStructOut(value: unknown): value is ExampleStructOut {
+  return exampleStructCheckOut(value) === undefined;
+}
+
+export function assertExampleStructOut(value: unknown): asserts value is ExampleStructOut {
+  const error = exampleStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleStructOut: value' + error + '.');
+  }
+}
+
 export type ExampleChoiceAtlas =
   | { $size: number; requiredToRequired: Uint8Array }
   | { $size: number; requiredToAsymmetric: Uint8Array }
@@ -1092,13 +1138,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'requiredToRequired',
@@ -1113,13 +1153,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'requiredToAsymmetric',
@@ -1134,13 +1168,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToRequired',
@@ -1155,13 +1183,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToAsymmetric',
@@ -1176,13 +1198,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToOptional',
@@ -1197,13 +1213,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'asymmetricToNonexistent',
@@ -1218,13 +1228,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1248,13 +1252,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1278,13 +1276,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1308,13 +1300,7 @@ function exampleChoiceDeserializeUnsafe(dataView: DataView): ExampleChoiceIn {
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         const $fallback = exampleChoiceDeserializeUnsafe(
@@ -1353,6 +1339,158 @@ function exampleChoiceDeserialize(bytes: Deserializable): ExampleChoiceIn | Erro
   }
 }
 
+function exampleChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('requiredToRequired' in message) {
+    if ('$field' in message && message.$field !== 'requiredToRequired') {
+      return '.$field must be "requiredToRequired"';
+    }
+    const payload = message.requiredToRequired;
+    if (typeof payload !== 'string') {
+      return '.requiredToRequired must be a string';
+    }
+    return undefined;
+  }
+
+  if ('requiredToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'requiredToAsymmetric') {
+      return '.$field must be "requiredToAsymmetric"';
+    }
+    const payload = message.requiredToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.requiredToAsymmetric must be a string';
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToRequired' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToRequired') {
+      return '.$field must be "asymmetricToRequired"';
+    }
+    const payload = message.asymmetricToRequired;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToRequired must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToAsymmetric') {
+      return '.$field must be "asymmetricToAsymmetric"';
+    }
+    const payload = message.asymmetricToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToOptional' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToOptional') {
+      return '.$field must be "asymmetricToOptional"';
+    }
+    const payload = message.asymmetricToOptional;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('asymmetricToNonexistent' in message) {
+    if ('$field' in message && message.$field !== 'asymmetricToNonexistent') {
+      return '.$field must be "asymmetricToNonexistent"';
+    }
+    const payload = message.asymmetricToNonexistent;
+    if (typeof payload !== 'string') {
+      return '.asymmetricToNonexistent must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToRequired' in message) {
+    if ('$field' in message && message.$field !== 'optionalToRequired') {
+      return '.$field must be "optionalToRequired"';
+    }
+    const payload = message.optionalToRequired;
+    if (typeof payload !== 'string') {
+      return '.optionalToRequired must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToAsymmetric' in message) {
+    if ('$field' in message && message.$field !== 'optionalToAsymmetric') {
+      return '.$field must be "optionalToAsymmetric"';
+    }
+    const payload = message.optionalToAsymmetric;
+    if (typeof payload !== 'string') {
+      return '.optionalToAsymmetric must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToOptional' in message) {
+    if ('$field' in message && message.$field !== 'optionalToOptional') {
+      return '.$field must be "optionalToOptional"';
+    }
+    const payload = message.optionalToOptional;
+    if (typeof payload !== 'string') {
+      return '.optionalToOptional must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  if ('optionalToNonexistent' in message) {
+    if ('$field' in message && message.$field !== 'optionalToNonexistent') {
+      return '.$field must be "optionalToNonexistent"';
+    }
+    const payload = message.optionalToNonexistent;
+    if (typeof payload !== 'string') {
+      return '.optionalToNonexistent must be a string';
+    }
+    const fallbackError = exampleChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields requiredToRequired, requiredToAsymmetric, asymmetricToRequired, asymmetricToAsymmetric, asymmetricToOptional, asymmetricToNonexistent, optionalToRequired, optionalToAsymmetric, optionalToOptional, optionalToNonexistent';
+}
+
 export const ExampleChoice = {
   atlas: exampleChoiceAtlas,
   size: exampleChoiceSize,
@@ -1360,4 +1498,16 @@ export const ExampleChoice = {
   deserializeUnsafe: exampleChoiceDeserializeUnsafe,
   serialize: exampleChoiceSerialize,
   deserialize: exampleChoiceDeserialize,
+  checkOut: exampleChoiceCheckOut,
 };
+
+export function isExampleChoiceOut(value: unknown): value is ExampleChoiceOut {
+  return exampleChoiceCheckOut(value) === undefined;
+}
+
+export function assertExampleChoiceOut(value: unknown): asserts value is ExampleChoiceOut {
+  const error = exampleChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid ExampleChoiceOut: value' + error + '.');
+  }
+}
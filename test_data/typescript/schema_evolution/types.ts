@@ -7,12 +7,16 @@ import {
   dataViewFromDeserializable,
   deserializeFieldHeader,
   deserializeVarint,
+  deserializeVarintNumber,
   fieldHeaderSize,
   missingFieldsErrorMessage,
+  payloadIntegerEncoded,
   serializeFieldHeader,
+  serializeUnknownField,
   serializeVarint,
   textDecoder,
   textEncoder,
+  unknownFieldSize,
   unreachable,
   varintSizeFromValue,
   zigzagDecode,
@@ -92,7 +96,7 @@ function singletonStructDeserializeUnsafe(dataView: DataView): SingletonStructIn
 
   let $x: string | undefined;
 
-  while (true) {
+  while (offset < dataViewAlias.byteLength) {
     let index: bigint;
     let payloadSize: number;
 
@@ -115,13 +119,7 @@ function singletonStructDeserializeUnsafe(dataView: DataView): SingletonStructIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         offset += oldOffset;
         $x = payload;
@@ -158,6 +156,23 @@ function singletonStructDeserialize(bytes: Deserializable): SingletonStructIn |
   }
 }
 
+function singletonStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.x;
+    if (typeof payload !== 'string') {
+      return '.x must be a string';
+    }
+  }
+
+  return undefined;
+}
+
 export const SingletonStruct = {
   atlas: singletonStructAtlas,
   size: singletonStructSize,
@@ -165,8 +180,20 @@ export const SingletonStruct = {
   deserializeUnsafe: singletonStructDeserializeUnsafe,
   serialize: singletonStructSerialize,
   deserialize: singletonStructDeserialize,
+  checkOut: singletonStructCheckOut,
 };
 
+export function isSingletonStructOut(value: unknown): value is SingletonStructOut {
+  return singletonStructCheckOut(value) === undefined;
+}
+
+export function assertSingletonStructOut(value: unknown): asserts value is SingletonStructOut {
+  const error = singletonStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid SingletonStructOut: value' + error + '.');
+  }
+}
+
 export type SingletonChoiceAtlas =
   | { $size: number; x: Uint8Array };
 
@@ -237,13 +264,7 @@ function singletonChoiceDeserializeUnsafe(dataView: DataView): SingletonChoiceIn
         );
         const oldOffset = offset;
         offset = 0;
-        let payload = textDecoder.decode(
-          new DataView(
-            dataView.buffer as ArrayBuffer,
-            dataView.byteOffset + offset,
-            dataView.byteLength - offset,
-          ),
-        );
+        let payload = textDecoder.decode(dataView);
         offset = dataView.byteLength;
         return {
           $field: 'x',
@@ -273,6 +294,27 @@ function singletonChoiceDeserialize(bytes: Deserializable): SingletonChoiceIn |
   }
 }
 
+function singletonChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('x' in message) {
+    if ('$field' in message && message.$field !== 'x') {
+      return '.$field must be "x"';
+    }
+    const payload = message.x;
+    if (typeof payload !== 'string') {
+      return '.x must be a string';
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields x';
+}
+
 export const SingletonChoice = {
   atlas: singletonChoiceAtlas,
   size: singletonChoiceSize,
@@ -280,4 +322,16 @@ export const SingletonChoice = {
   deserializeUnsafe: singletonChoiceDeserializeUnsafe,
   serialize: singletonChoiceSerialize,
   deserialize: singletonChoiceDeserialize,
+  checkOut: singletonChoiceCheckOut,
 };
+
+export function isSingletonChoiceOut(value: unknown): value is SingletonChoiceOut {
+  return singletonChoiceCheckOut(value) === undefined;
+}
+
+export function assertSingletonChoiceOut(value: unknown): asserts value is SingletonChoiceOut {
+  const error = singletonChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid SingletonChoiceOut: value' + error + '.');
+  }
+}
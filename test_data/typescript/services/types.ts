@@ -0,0 +1,447 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common';
+import type { Deserializable, Transport } from '../common';
+
+import * as _Types from '../degenerate/types';
+
+export type GreetRequestAtlas = {
+  $size: number;
+  name: Uint8Array;
+};
+
+export type GreetRequestOut = {
+  name: string;
+};
+
+export type GreetRequestIn = {
+  name: string;
+};
+
+function greetRequestAtlas(message: GreetRequestOut): GreetRequestAtlas {
+  let size = 0;
+
+  let $name: Uint8Array;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.name;
+    payloadAtlas = textEncoder.encode(payload);
+    $name = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  return {
+    $size: size,
+    name: $name,
+  };
+}
+
+function greetRequestSize(message: GreetRequestOut): number {
+  return greetRequestAtlas(message).$size;
+}
+
+function greetRequestSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: GreetRequestOut,
+  atlas: GreetRequestAtlas,
+): number {
+  {
+    const payload = message.name;
+    const payloadAtlas = atlas.name;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  return offset;
+}
+
+function greetRequestDeserializeUnsafe(dataView: DataView): GreetRequestIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $name: string | undefined;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $name = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($name === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    name: $name,
+  };
+}
+
+function greetRequestSerialize(message: GreetRequestOut): ArrayBuffer {
+  const atlasValue = greetRequestAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  greetRequestSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function greetRequestDeserialize(bytes: Deserializable): GreetRequestIn | Error {
+  try {
+    return greetRequestDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function greetRequestCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.name;
+    if (typeof payload !== 'string') {
+      return '.name must be a string';
+    }
+  }
+
+  return undefined;
+}
+
+export const GreetRequest = {
+  atlas: greetRequestAtlas,
+  size: greetRequestSize,
+  serializeWithAtlasUnsafe: greetRequestSerializeWithAtlasUnsafe,
+  deserializeUnsafe: greetRequestDeserializeUnsafe,
+  serialize: greetRequestSerialize,
+  deserialize: greetRequestDeserialize,
+  checkOut: greetRequestCheckOut,
+};
+
+export function isGreetRequestOut(value: unknown): value is GreetRequestOut {
+  return greetRequestCheckOut(value) === undefined;
+}
+
+export function assertGreetRequestOut(value: unknown): asserts value is GreetRequestOut {
+  const error = greetRequestCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid GreetRequestOut: value' + error + '.');
+  }
+}
+
+export type GreetResponseAtlas =
+  | { $size: number; greeting: Uint8Array }
+  | { $size: number; refusal: Uint8Array };
+
+export type GreetResponseOut =
+  | { greeting: string }
+  | { refusal: string };
+
+export type GreetResponseIn =
+  | { $field: 'greeting'; greeting: string }
+  | { $field: 'refusal'; refusal: string };
+
+function greetResponseAtlas(message: GreetResponseOut): GreetResponseAtlas {
+  if ('greeting' in message) {
+    let payloadAtlas: Uint8Array;
+    const payload = message.greeting;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, greeting: payloadAtlas };
+  }
+
+  if ('refusal' in message) {
+    let payloadAtlas: Uint8Array;
+    const payload = message.refusal;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(1n, payloadSize, false) + payloadSize, refusal: payloadAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function greetResponseSize(message: GreetResponseOut): number {
+  return greetResponseAtlas(message).$size;
+}
+
+function greetResponseSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: GreetResponseOut,
+  atlas: GreetResponseAtlas,
+): number {
+  if ('greeting' in message) {
+    const payload = message.greeting;
+    const payloadAtlas = (atlas as any).greeting as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('refusal' in message) {
+    const payload = message.refusal;
+    const payloadAtlas = (atlas as any).refusal as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function greetResponseDeserializeUnsafe(dataView: DataView): GreetResponseIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'greeting',
+          greeting: payload,
+        };
+      }
+      case 1n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'refusal',
+          refusal: payload,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function greetResponseSerialize(message: GreetResponseOut): ArrayBuffer {
+  const atlasValue = greetResponseAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  greetResponseSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function greetResponseDeserialize(bytes: Deserializable): GreetResponseIn | Error {
+  try {
+    return greetResponseDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function greetResponseCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('greeting' in message) {
+    if ('$field' in message && message.$field !== 'greeting') {
+      return '.$field must be "greeting"';
+    }
+    const payload = message.greeting;
+    if (typeof payload !== 'string') {
+      return '.greeting must be a string';
+    }
+    return undefined;
+  }
+
+  if ('refusal' in message) {
+    if ('$field' in message && message.$field !== 'refusal') {
+      return '.$field must be "refusal"';
+    }
+    const payload = message.refusal;
+    if (typeof payload !== 'string') {
+      return '.refusal must be a string';
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields greeting, refusal';
+}
+
+export const GreetResponse = {
+  atlas: greetResponseAtlas,
+  size: greetResponseSize,
+  serializeWithAtlasUnsafe: greetResponseSerializeWithAtlasUnsafe,
+  deserializeUnsafe: greetResponseDeserializeUnsafe,
+  serialize: greetResponseSerialize,
+  deserialize: greetResponseDeserialize,
+  checkOut: greetResponseCheckOut,
+};
+
+export function isGreetResponseOut(value: unknown): value is GreetResponseOut {
+  return greetResponseCheckOut(value) === undefined;
+}
+
+export function assertGreetResponseOut(value: unknown): asserts value is GreetResponseOut {
+  const error = greetResponseCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid GreetResponseOut: value' + error + '.');
+  }
+}
+
+export interface GreeterServer {
+  greet(request: GreetRequestIn): GreetResponseOut | Promise<GreetResponseOut>;
+  ping(request: _Types.EmptyStructIn): _Types.EmptyStructOut | Promise<_Types.EmptyStructOut>;
+}
+
+export async function dispatchGreeter(
+  server: GreeterServer,
+  methodIndex: bigint,
+  request: Deserializable,
+): Promise<ArrayBuffer> {
+  switch (methodIndex) {
+    case 0n: {
+      const message = GreetRequest.deserialize(request);
+      if (message instanceof Error) {
+        throw message;
+      }
+      return GreetResponse.serialize(await server.greet(message));
+    }
+    case 2n: {
+      const message = _Types.EmptyStruct.deserialize(request);
+      if (message instanceof Error) {
+        throw message;
+      }
+      return _Types.EmptyStruct.serialize(await server.ping(message));
+    }
+    default:
+      throw new Error('Unknown method index.');
+  }
+}
+
+export interface GreeterClient {
+  greet(request: GreetRequestOut): Promise<GreetResponseIn>;
+  ping(request: _Types.EmptyStructOut): Promise<_Types.EmptyStructIn>;
+}
+
+export function greeterClient(transport: Transport): GreeterClient {
+  return {
+    async greet(request: GreetRequestOut): Promise<GreetResponseIn> {
+      const response = GreetResponse.deserialize(
+        await transport(0n, GreetRequest.serialize(request)),
+      );
+      if (response instanceof Error) {
+        throw response;
+      }
+      return response;
+    },
+    async ping(request: _Types.EmptyStructOut): Promise<_Types.EmptyStructIn> {
+      const response = _Types.EmptyStruct.deserialize(
+        await transport(2n, _Types.EmptyStruct.serialize(request)),
+      );
+      if (response instanceof Error) {
+        throw response;
+      }
+      return response;
+    },
+  };
+}
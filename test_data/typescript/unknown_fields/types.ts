@@ -0,0 +1,1371 @@
+// This file was automatically generated by Typical 0.0.0.
+// Visit https://github.com/stepchowfun/typical for more information.
+
+/* eslint-disable */
+
+import {
+  checkUnknownField,
+  dataViewFromDeserializable,
+  deserializeFieldHeader,
+  deserializeVarint,
+  deserializeVarintNumber,
+  fieldHeaderSize,
+  missingFieldsErrorMessage,
+  payloadIntegerEncoded,
+  serializeFieldHeader,
+  serializeUnknownField,
+  serializeVarint,
+  textDecoder,
+  textEncoder,
+  unknownFieldSize,
+  unreachable,
+  varintSizeFromValue,
+  zigzagDecode,
+  zigzagEncode,
+} from '../common';
+import type { Deserializable, UnknownField } from '../common';
+
+export type NewChoiceAtlas =
+  | { $size: number; known: Uint8Array }
+  | { $size: number; unrecognized: number }
+  | { $size: number; preferred: number; $fallback: NewChoiceAtlas };
+
+export type NewChoiceOut =
+  | { known: string }
+  | { unrecognized: bigint }
+  | { preferred: ArrayBuffer; $fallback: NewChoiceOut };
+
+export type NewChoiceIn =
+  | { $field: 'known'; known: string }
+  | { $field: 'unrecognized'; unrecognized: bigint }
+  | { $field: 'preferred'; preferred: ArrayBuffer; $fallback: NewChoiceIn };
+
+function newChoiceAtlas(message: NewChoiceOut): NewChoiceAtlas {
+  if ('known' in message) {
+    let payloadAtlas: Uint8Array;
+    const payload = message.known;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, known: payloadAtlas };
+  }
+
+  if ('unrecognized' in message) {
+    let payloadAtlas: number;
+    const payload = message.unrecognized;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    const payloadSize = payloadAtlas;
+    return { $size: fieldHeaderSize(1n, payloadSize, true) + payloadSize, unrecognized: payloadAtlas };
+  }
+
+  if ('preferred' in message) {
+    let payloadAtlas: number;
+    const payload = message.preferred;
+    payloadAtlas = payload.byteLength;
+    const payloadSize = payloadAtlas;
+    const fallbackAtlas = newChoiceAtlas(message.$fallback);
+    return { $size: fieldHeaderSize(2n, payloadSize, false) + payloadSize + fallbackAtlas.$size, preferred: payloadAtlas, $fallback: fallbackAtlas };
+  }
+
+  return unreachable(message);
+}
+
+function newChoiceSize(message: NewChoiceOut): number {
+  return newChoiceAtlas(message).$size;
+}
+
+function newChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: NewChoiceOut,
+  atlas: NewChoiceAtlas,
+): number {
+  if ('known' in message) {
+    const payload = message.known;
+    const payloadAtlas = (atlas as any).known as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('unrecognized' in message) {
+    const payload = message.unrecognized;
+    const payloadAtlas = (atlas as any).unrecognized as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+    return offset;
+  }
+
+  if ('preferred' in message) {
+    const payload = message.preferred;
+    const payloadAtlas = (atlas as any).preferred as number;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+    {
+      const sourceBuffer = new Uint8Array(payload);
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(sourceBuffer, offset);
+      offset += sourceBuffer.byteLength;
+    }
+    offset = newChoiceSerializeWithAtlasUnsafe(dataView, offset, message.$fallback, (atlas as any).$fallback as NewChoiceAtlas);
+    return offset;
+  }
+
+  return unreachable(message);
+}
+
+function newChoiceDeserializeUnsafe(dataView: DataView): NewChoiceIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'known',
+          known: payload,
+        };
+      }
+      case 1n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        return {
+          $field: 'unrecognized',
+          unrecognized: payload,
+        };
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = (dataView.buffer as ArrayBuffer).slice(
+          dataView.byteOffset + offset,
+          dataView.byteOffset + dataView.byteLength,
+        );
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        const $fallback = newChoiceDeserializeUnsafe(
+          new DataView(
+            dataViewAlias.buffer as ArrayBuffer,
+            dataViewAlias.byteOffset + offset,
+            dataViewAlias.byteLength - offset,
+          ),
+        );
+        return {
+          $field: 'preferred',
+          preferred: payload,
+          $fallback,
+        };
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+}
+
+function newChoiceSerialize(message: NewChoiceOut): ArrayBuffer {
+  const atlasValue = newChoiceAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  newChoiceSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function newChoiceDeserialize(bytes: Deserializable): NewChoiceIn | Error {
+  try {
+    return newChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function newChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('known' in message) {
+    if ('$field' in message && message.$field !== 'known') {
+      return '.$field must be "known"';
+    }
+    const payload = message.known;
+    if (typeof payload !== 'string') {
+      return '.known must be a string';
+    }
+    return undefined;
+  }
+
+  if ('unrecognized' in message) {
+    if ('$field' in message && message.$field !== 'unrecognized') {
+      return '.$field must be "unrecognized"';
+    }
+    const payload = message.unrecognized;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.unrecognized must be a bigint from 0 to 18446744073709551615';
+    }
+    return undefined;
+  }
+
+  if ('preferred' in message) {
+    if ('$field' in message && message.$field !== 'preferred') {
+      return '.$field must be "preferred"';
+    }
+    const payload = message.preferred;
+    if (!(payload instanceof ArrayBuffer)) {
+      return '.preferred must be an ArrayBuffer';
+    }
+    const fallbackError = newChoiceCheckOut(message.$fallback);
+    if (fallbackError !== undefined) {
+      return '.$fallback' + fallbackError;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields known, unrecognized, preferred';
+}
+
+export const NewChoice = {
+  atlas: newChoiceAtlas,
+  size: newChoiceSize,
+  serializeWithAtlasUnsafe: newChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: newChoiceDeserializeUnsafe,
+  serialize: newChoiceSerialize,
+  deserialize: newChoiceDeserialize,
+  checkOut: newChoiceCheckOut,
+};
+
+export function isNewChoiceOut(value: unknown): value is NewChoiceOut {
+  return newChoiceCheckOut(value) === undefined;
+}
+
+export function assertNewChoiceOut(value: unknown): asserts value is NewChoiceOut {
+  const error = newChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid NewChoiceOut: value' + error + '.');
+  }
+}
+
+export type OldChoiceAtlas =
+  | { $size: number; known: Uint8Array }
+  | { $size: number };
+
+export type OldChoiceOut =
+  | { known: string }
+  | { unknown: { index: bigint; payload: ArrayBuffer } };
+
+export type OldChoiceIn =
+  | { $field: 'known'; known: string }
+  | { $field: 'unknown'; unknown: { index: bigint; payload: ArrayBuffer } };
+
+function oldChoiceAtlas(message: OldChoiceOut): OldChoiceAtlas {
+  if ('known' in message) {
+    let payloadAtlas: Uint8Array;
+    const payload = message.known;
+    payloadAtlas = textEncoder.encode(payload);
+    const payloadSize = payloadAtlas.byteLength;
+    return { $size: fieldHeaderSize(0n, payloadSize, false) + payloadSize, known: payloadAtlas };
+  }
+
+  if ('unknown' in message) {
+    const { index, payload } = message.unknown;
+    return {
+      $size:
+        fieldHeaderSize(index, payload.byteLength, payloadIntegerEncoded(payload)) +
+        payload.byteLength,
+    };
+  }
+
+  return unreachable(message);
+}
+
+function oldChoiceSize(message: OldChoiceOut): number {
+  return oldChoiceAtlas(message).$size;
+}
+
+function oldChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: OldChoiceOut,
+  atlas: OldChoiceAtlas,
+): number {
+  if ('known' in message) {
+    const payload = message.known;
+    const payloadAtlas = (atlas as any).known as Uint8Array;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+    return offset;
+  }
+
+  if ('unknown' in message) {
+    const { index, payload } = message.unknown;
+    offset = serializeFieldHeader(
+      dataView,
+      offset,
+      index,
+      payload.byteLength,
+      payloadIntegerEncoded(payload),
+    );
+    const sourceBuffer = new Uint8Array(payload);
+    const targetBuffer = new Uint8Array(
+      dataView.buffer as ArrayBuffer,
+      dataView.byteOffset,
+      dataView.byteLength,
+    );
+    targetBuffer.set(sourceBuffer, offset);
+    return offset + sourceBuffer.byteLength;
+  }
+
+  return unreachable(message);
+}
+
+function oldChoiceDeserializeUnsafe(dataView: DataView): OldChoiceIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        return {
+          $field: 'known',
+          known: payload,
+        };
+      }
+      default: {
+        const payload = (dataViewAlias.buffer as ArrayBuffer).slice(
+          dataViewAlias.byteOffset + offset,
+          dataViewAlias.byteOffset + offset + payloadSize,
+        );
+        offset += payloadSize;
+
+        if (offset >= dataViewAlias.byteLength) {
+          return { $field: 'unknown', unknown: { index, payload } };
+        }
+        break;
+      }
+    }
+  }
+}
+
+function oldChoiceSerialize(message: OldChoiceOut): ArrayBuffer {
+  const atlasValue = oldChoiceAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  oldChoiceSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function oldChoiceDeserialize(bytes: Deserializable): OldChoiceIn | Error {
+  try {
+    return oldChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function oldChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('known' in message) {
+    if ('$field' in message && message.$field !== 'known') {
+      return '.$field must be "known"';
+    }
+    const payload = message.known;
+    if (typeof payload !== 'string') {
+      return '.known must be a string';
+    }
+    return undefined;
+  }
+
+  if ('unknown' in message) {
+    if ('$field' in message && message.$field !== 'unknown') {
+      return '.$field must be "unknown"';
+    }
+    const error = checkUnknownField(message.unknown, false);
+    if (error !== undefined) {
+      return '.unknown' + error;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields known, unknown';
+}
+
+export const OldChoice = {
+  atlas: oldChoiceAtlas,
+  size: oldChoiceSize,
+  serializeWithAtlasUnsafe: oldChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: oldChoiceDeserializeUnsafe,
+  serialize: oldChoiceSerialize,
+  deserialize: oldChoiceDeserialize,
+  checkOut: oldChoiceCheckOut,
+};
+
+export function isOldChoiceOut(value: unknown): value is OldChoiceOut {
+  return oldChoiceCheckOut(value) === undefined;
+}
+
+export function assertOldChoiceOut(value: unknown): asserts value is OldChoiceOut {
+  const error = oldChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid OldChoiceOut: value' + error + '.');
+  }
+}
+
+export type EmptyOpenChoiceAtlas =
+  | { $size: number };
+
+export type EmptyOpenChoiceOut =
+  | { unknown: { index: bigint; payload: ArrayBuffer } };
+
+export type EmptyOpenChoiceIn =
+  | { $field: 'unknown'; unknown: { index: bigint; payload: ArrayBuffer } }
+  | { $field: never };
+
+function emptyOpenChoiceAtlas(message: EmptyOpenChoiceOut): EmptyOpenChoiceAtlas {
+  if ('unknown' in message) {
+    const { index, payload } = message.unknown;
+    return {
+      $size:
+        fieldHeaderSize(index, payload.byteLength, payloadIntegerEncoded(payload)) +
+        payload.byteLength,
+    };
+  }
+
+  return unreachable(message);
+}
+
+function emptyOpenChoiceSize(message: EmptyOpenChoiceOut): number {
+  return emptyOpenChoiceAtlas(message).$size;
+}
+
+function emptyOpenChoiceSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyOpenChoiceOut,
+  atlas: EmptyOpenChoiceAtlas,
+): number {
+  if ('unknown' in message) {
+    const { index, payload } = message.unknown;
+    offset = serializeFieldHeader(
+      dataView,
+      offset,
+      index,
+      payload.byteLength,
+      payloadIntegerEncoded(payload),
+    );
+    const sourceBuffer = new Uint8Array(payload);
+    const targetBuffer = new Uint8Array(
+      dataView.buffer as ArrayBuffer,
+      dataView.byteOffset,
+      dataView.byteLength,
+    );
+    targetBuffer.set(sourceBuffer, offset);
+    return offset + sourceBuffer.byteLength;
+  }
+
+  return unreachable(message);
+}
+
+function emptyOpenChoiceDeserializeUnsafe(dataView: DataView): EmptyOpenChoiceIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  while (true) {
+    const [newOffset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    offset = newOffset;
+
+    switch (index) {
+      default: {
+        const payload = (dataViewAlias.buffer as ArrayBuffer).slice(
+          dataViewAlias.byteOffset + offset,
+          dataViewAlias.byteOffset + offset + payloadSize,
+        );
+        offset += payloadSize;
+
+        if (offset >= dataViewAlias.byteLength) {
+          return { $field: 'unknown', unknown: { index, payload } };
+        }
+        break;
+      }
+    }
+  }
+}
+
+function emptyOpenChoiceSerialize(message: EmptyOpenChoiceOut): ArrayBuffer {
+  const atlasValue = emptyOpenChoiceAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  emptyOpenChoiceSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function emptyOpenChoiceDeserialize(bytes: Deserializable): EmptyOpenChoiceIn | Error {
+  try {
+    return emptyOpenChoiceDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function emptyOpenChoiceCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if ('unknown' in message) {
+    if ('$field' in message && message.$field !== 'unknown') {
+      return '.$field must be "unknown"';
+    }
+    const error = checkUnknownField(message.unknown, false);
+    if (error !== undefined) {
+      return '.unknown' + error;
+    }
+    return undefined;
+  }
+
+  return ' must have one of the fields unknown';
+}
+
+export const EmptyOpenChoice = {
+  atlas: emptyOpenChoiceAtlas,
+  size: emptyOpenChoiceSize,
+  serializeWithAtlasUnsafe: emptyOpenChoiceSerializeWithAtlasUnsafe,
+  deserializeUnsafe: emptyOpenChoiceDeserializeUnsafe,
+  serialize: emptyOpenChoiceSerialize,
+  deserialize: emptyOpenChoiceDeserialize,
+  checkOut: emptyOpenChoiceCheckOut,
+};
+
+export function isEmptyOpenChoiceOut(value: unknown): value is EmptyOpenChoiceOut {
+  return emptyOpenChoiceCheckOut(value) === undefined;
+}
+
+export function assertEmptyOpenChoiceOut(value: unknown): asserts value is EmptyOpenChoiceOut {
+  const error = emptyOpenChoiceCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyOpenChoiceOut: value' + error + '.');
+  }
+}
+
+export type NewStructAtlas = {
+  $size: number;
+  known: Uint8Array;
+  unrecognized: number;
+  text: Uint8Array;
+  nothing: number | undefined;
+  _number: number | undefined;
+};
+
+export type NewStructOut = {
+  known: string;
+  unrecognized: bigint;
+  text: string;
+  nothing: null | undefined;
+  _number: number | undefined;
+};
+
+export type NewStructIn = {
+  known: string;
+  unrecognized: bigint;
+  text: string;
+  nothing: null | undefined;
+  _number: number | undefined;
+};
+
+function newStructAtlas(message: NewStructOut): NewStructAtlas {
+  let size = 0;
+
+  let $known: Uint8Array;
+  let $unrecognized: number;
+  let $text: Uint8Array;
+  let $nothing: number | undefined;
+  let $_number: number | undefined;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.known;
+    payloadAtlas = textEncoder.encode(payload);
+    $known = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: number;
+    const payload = message.unrecognized;
+    if (payload === 0n) {
+      payloadAtlas = 0;
+    } else if (payload < 567_382_630_219_904n) {
+      payloadAtlas = varintSizeFromValue(payload);
+    } else {
+      payloadAtlas = 8;
+    }
+    $unrecognized = payloadAtlas;
+    const payloadSize = payloadAtlas;
+    size += fieldHeaderSize(1n, payloadSize, true) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.text;
+    payloadAtlas = textEncoder.encode(payload);
+    $text = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(2n, payloadSize, false) + payloadSize;
+  }
+
+  {
+    let payloadAtlas: number;
+    const payload = message.nothing;
+    if (payload !== undefined) {
+      payloadAtlas = 0;
+      $nothing = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(3n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  {
+    let payloadAtlas: number;
+    const payload = message._number;
+    if (payload !== undefined) {
+      if (Object.is(payload, 0)) {
+        payloadAtlas = 0;
+      } else {
+        payloadAtlas = 8;
+      }
+      $_number = payloadAtlas;
+      const payloadSize = payloadAtlas;
+      size += fieldHeaderSize(4n, payloadSize, false) + payloadSize;
+    }
+  }
+
+  return {
+    $size: size,
+    known: $known,
+    unrecognized: $unrecognized,
+    text: $text,
+    nothing: $nothing,
+    _number: $_number,
+  };
+}
+
+function newStructSize(message: NewStructOut): number {
+  return newStructAtlas(message).$size;
+}
+
+function newStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: NewStructOut,
+  atlas: NewStructAtlas,
+): number {
+  {
+    const payload = message.known;
+    const payloadAtlas = atlas.known;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.unrecognized;
+    const payloadAtlas = atlas.unrecognized;
+    const payloadSize = payloadAtlas;
+    offset = serializeFieldHeader(dataView, offset, 1n, payloadSize, true);
+    {
+      const varint = payload;
+      if (varint > 567_382_630_219_903n) {
+        dataView.setBigUint64(offset, varint, true);
+        offset += 8;
+      } else if (varint !== 0n) {
+        offset = serializeVarint(dataView, offset, varint);
+      }
+    }
+  }
+
+  {
+    const payload = message.text;
+    const payloadAtlas = atlas.text;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 2n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  {
+    const payload = message.nothing;
+    const payloadAtlas = atlas.nothing;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 3n, payloadSize, false);
+    }
+  }
+
+  {
+    const payload = message._number;
+    const payloadAtlas = atlas._number;
+    if (payload !== undefined && payloadAtlas !== undefined) {
+      const payloadSize = payloadAtlas;
+      offset = serializeFieldHeader(dataView, offset, 4n, payloadSize, false);
+      if (payloadAtlas !== 0) {
+        dataView.setFloat64(offset, payload, true);
+        offset += 8;
+      }
+    }
+  }
+
+  return offset;
+}
+
+function newStructDeserializeUnsafe(dataView: DataView): NewStructIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $known: string | undefined;
+  let $unrecognized: bigint | undefined;
+  let $text: string | undefined;
+  let $nothing: null | undefined;
+  let $_number: number | undefined;
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+
+    try {
+      [offset, index, payloadSize] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $known = payload;
+        break;
+      }
+      case 1n: {
+        let payload: bigint;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0n;
+              break;
+            case 8:
+              payload = dataView.getBigUint64(offset, true);
+              offset += 8;
+              break;
+            default:
+              [offset, payload] = deserializeVarint(dataView, offset);
+              break;
+          }
+        }
+        $unrecognized = payload;
+        break;
+      }
+      case 2n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $text = payload;
+        break;
+      }
+      case 3n: {
+        let payload = null;
+        $nothing = payload;
+        break;
+      }
+      case 4n: {
+        let payload: number;
+        {
+          switch (payloadSize) {
+            case 0:
+              payload = 0;
+              break;
+            default:
+              payload = dataView.getFloat64(offset, true);
+              offset += 8;
+              break;
+          }
+        }
+        $_number = payload;
+        break;
+      }
+      default:
+        offset += payloadSize;
+        break;
+    }
+  }
+
+  if ($known === undefined
+    || $unrecognized === undefined
+    || $text === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    known: $known,
+    unrecognized: $unrecognized,
+    text: $text,
+    nothing: $nothing,
+    _number: $_number,
+  };
+}
+
+function newStructSerialize(message: NewStructOut): ArrayBuffer {
+  const atlasValue = newStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  newStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function newStructDeserialize(bytes: Deserializable): NewStructIn | Error {
+  try {
+    return newStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function newStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.known;
+    if (typeof payload !== 'string') {
+      return '.known must be a string';
+    }
+  }
+
+  {
+    const payload = message.unrecognized;
+    if (typeof payload !== 'bigint' || payload < 0n || payload > 18_446_744_073_709_551_615n) {
+      return '.unrecognized must be a bigint from 0 to 18446744073709551615';
+    }
+  }
+
+  {
+    const payload = message.text;
+    if (typeof payload !== 'string') {
+      return '.text must be a string';
+    }
+  }
+
+  {
+    const payload = message.nothing;
+    if (payload !== undefined) {
+      if (payload !== null) {
+        return '.nothing must be null';
+      }
+    }
+  }
+
+  {
+    const payload = message._number;
+    if (payload !== undefined) {
+      if (typeof payload !== 'number') {
+        return '._number must be a number';
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const NewStruct = {
+  atlas: newStructAtlas,
+  size: newStructSize,
+  serializeWithAtlasUnsafe: newStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: newStructDeserializeUnsafe,
+  serialize: newStructSerialize,
+  deserialize: newStructDeserialize,
+  checkOut: newStructCheckOut,
+};
+
+export function isNewStructOut(value: unknown): value is NewStructOut {
+  return newStructCheckOut(value) === undefined;
+}
+
+export function assertNewStructOut(value: unknown): asserts value is NewStructOut {
+  const error = newStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid NewStructOut: value' + error + '.');
+  }
+}
+
+export type OldStructAtlas = {
+  $size: number;
+  known: Uint8Array;
+};
+
+export type OldStructOut = {
+  known: string;
+  $unknown?: UnknownField[];
+};
+
+export type OldStructIn = {
+  known: string;
+  $unknown: UnknownField[];
+};
+
+function oldStructAtlas(message: OldStructOut): OldStructAtlas {
+  let size = 0;
+
+  let $known: Uint8Array;
+
+  {
+    let payloadAtlas: Uint8Array;
+    const payload = message.known;
+    payloadAtlas = textEncoder.encode(payload);
+    $known = payloadAtlas;
+    const payloadSize = payloadAtlas.byteLength;
+    size += fieldHeaderSize(0n, payloadSize, false) + payloadSize;
+  }
+
+  if (message.$unknown !== undefined) {
+    for (const unknownField of message.$unknown) {
+      size += unknownFieldSize(unknownField);
+    }
+  }
+
+  return {
+    $size: size,
+    known: $known,
+  };
+}
+
+function oldStructSize(message: OldStructOut): number {
+  return oldStructAtlas(message).$size;
+}
+
+function oldStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: OldStructOut,
+  atlas: OldStructAtlas,
+): number {
+  {
+    const payload = message.known;
+    const payloadAtlas = atlas.known;
+    const payloadSize = payloadAtlas.byteLength;
+    offset = serializeFieldHeader(dataView, offset, 0n, payloadSize, false);
+    {
+      const targetBuffer = new Uint8Array(
+        dataView.buffer as ArrayBuffer,
+        dataView.byteOffset,
+        dataView.byteLength,
+      );
+      targetBuffer.set(payloadAtlas, offset);
+      offset += payloadAtlas.byteLength;
+    }
+  }
+
+  if (message.$unknown !== undefined) {
+    for (const unknownField of message.$unknown) {
+      offset = serializeUnknownField(dataView, offset, unknownField);
+    }
+  }
+
+  return offset;
+}
+
+function oldStructDeserializeUnsafe(dataView: DataView): OldStructIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  let $known: string | undefined;
+
+  const unknownFields: UnknownField[] = [];
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+    let sizeMode: number;
+
+    try {
+      [offset, index, payloadSize, sizeMode] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      case 0n: {
+        const dataView = new DataView(
+          dataViewAlias.buffer as ArrayBuffer,
+          dataViewAlias.byteOffset + offset,
+          payloadSize,
+        );
+        const oldOffset = offset;
+        offset = 0;
+        let payload = textDecoder.decode(dataView);
+        offset = dataView.byteLength;
+        offset += oldOffset;
+        $known = payload;
+        break;
+      }
+      default: {
+        const payload = (dataViewAlias.buffer as ArrayBuffer).slice(
+          dataViewAlias.byteOffset + offset,
+          dataViewAlias.byteOffset + offset + payloadSize,
+        );
+        unknownFields.push({ index, sizeMode, payload });
+        offset += payloadSize;
+        break;
+      }
+    }
+  }
+
+  if ($known === undefined) {
+    throw new Error(missingFieldsErrorMessage);
+  }
+
+  return {
+    known: $known,
+    $unknown: unknownFields,
+  };
+}
+
+function oldStructSerialize(message: OldStructOut): ArrayBuffer {
+  const atlasValue = oldStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  oldStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function oldStructDeserialize(bytes: Deserializable): OldStructIn | Error {
+  try {
+    return oldStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function oldStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  {
+    const payload = message.known;
+    if (typeof payload !== 'string') {
+      return '.known must be a string';
+    }
+  }
+
+  if (message.$unknown !== undefined) {
+    if (!Array.isArray(message.$unknown)) {
+      return '.$unknown must be an array';
+    }
+    for (let i0 = 0; i0 < message.$unknown.length; i0 += 1) {
+      const error = checkUnknownField(message.$unknown[i0], true);
+      if (error !== undefined) {
+        return '.$unknown[' + i0 + ']' + error;
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const OldStruct = {
+  atlas: oldStructAtlas,
+  size: oldStructSize,
+  serializeWithAtlasUnsafe: oldStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: oldStructDeserializeUnsafe,
+  serialize: oldStructSerialize,
+  deserialize: oldStructDeserialize,
+  checkOut: oldStructCheckOut,
+};
+
+export function isOldStructOut(value: unknown): value is OldStructOut {
+  return oldStructCheckOut(value) === undefined;
+}
+
+export function assertOldStructOut(value: unknown): asserts value is OldStructOut {
+  const error = oldStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid OldStructOut: value' + error + '.');
+  }
+}
+
+export type EmptyOpenStructAtlas = {
+  $size: number;
+};
+
+export type EmptyOpenStructOut = {
+  $unknown?: UnknownField[];
+};
+
+export type EmptyOpenStructIn = {
+  $unknown: UnknownField[];
+};
+
+function emptyOpenStructAtlas(message: EmptyOpenStructOut): EmptyOpenStructAtlas {
+  let size = 0;
+
+  if (message.$unknown !== undefined) {
+    for (const unknownField of message.$unknown) {
+      size += unknownFieldSize(unknownField);
+    }
+  }
+
+  return {
+    $size: size,
+  };
+}
+
+function emptyOpenStructSize(message: EmptyOpenStructOut): number {
+  return emptyOpenStructAtlas(message).$size;
+}
+
+function emptyOpenStructSerializeWithAtlasUnsafe(
+  dataView: DataView,
+  offset: number,
+  message: EmptyOpenStructOut,
+  atlas: EmptyOpenStructAtlas,
+): number {
+  if (message.$unknown !== undefined) {
+    for (const unknownField of message.$unknown) {
+      offset = serializeUnknownField(dataView, offset, unknownField);
+    }
+  }
+
+  return offset;
+}
+
+function emptyOpenStructDeserializeUnsafe(dataView: DataView): EmptyOpenStructIn {
+  const dataViewAlias = dataView;
+
+  let offset = 0;
+
+  const unknownFields: UnknownField[] = [];
+
+  while (offset < dataViewAlias.byteLength) {
+    let index: bigint;
+    let payloadSize: number;
+    let sizeMode: number;
+
+    try {
+      [offset, index, payloadSize, sizeMode] = deserializeFieldHeader(dataViewAlias, offset);
+    } catch (e) {
+      if (e instanceof RangeError) {
+        break;
+      } else {
+        throw e;
+      }
+    }
+
+    switch (index) {
+      default: {
+        const payload = (dataViewAlias.buffer as ArrayBuffer).slice(
+          dataViewAlias.byteOffset + offset,
+          dataViewAlias.byteOffset + offset + payloadSize,
+        );
+        unknownFields.push({ index, sizeMode, payload });
+        offset += payloadSize;
+        break;
+      }
+    }
+  }
+
+  return {
+    $unknown: unknownFields,
+  };
+}
+
+function emptyOpenStructSerialize(message: EmptyOpenStructOut): ArrayBuffer {
+  const atlasValue = emptyOpenStructAtlas(message);
+  const arrayBuffer = new ArrayBuffer(atlasValue.$size);
+  const dataView = new DataView(arrayBuffer);
+  emptyOpenStructSerializeWithAtlasUnsafe(dataView, 0, message, atlasValue);
+  return arrayBuffer;
+}
+
+function emptyOpenStructDeserialize(bytes: Deserializable): EmptyOpenStructIn | Error {
+  try {
+    return emptyOpenStructDeserializeUnsafe(dataViewFromDeserializable(bytes));
+  } catch (e) {
+    return e as Error;
+  }
+}
+
+function emptyOpenStructCheckOut(value: unknown): string | undefined {
+  if (typeof value !== 'object' || value === null) {
+    return ' must be an object';
+  }
+
+  const message = value as any;
+
+  if (message.$unknown !== undefined) {
+    if (!Array.isArray(message.$unknown)) {
+      return '.$unknown must be an array';
+    }
+    for (let i0 = 0; i0 < message.$unknown.length; i0 += 1) {
+      const error = checkUnknownField(message.$unknown[i0], true);
+      if (error !== undefined) {
+        return '.$unknown[' + i0 + ']' + error;
+      }
+    }
+  }
+
+  return undefined;
+}
+
+export const EmptyOpenStruct = {
+  atlas: emptyOpenStructAtlas,
+  size: emptyOpenStructSize,
+  serializeWithAtlasUnsafe: emptyOpenStructSerializeWithAtlasUnsafe,
+  deserializeUnsafe: emptyOpenStructDeserializeUnsafe,
+  serialize: emptyOpenStructSerialize,
+  deserialize: emptyOpenStructDeserialize,
+  checkOut: emptyOpenStructCheckOut,
+};
+
+export function isEmptyOpenStructOut(value: unknown): value is EmptyOpenStructOut {
+  return emptyOpenStructCheckOut(value) === undefined;
+}
+
+export function assertEmptyOpenStructOut(value: unknown): asserts value is EmptyOpenStructOut {
+  const error = emptyOpenStructCheckOut(value);
+  if (error !== undefined) {
+    throw new TypeError('Invalid EmptyOpenStructOut: value' + error + '.');
+  }
+}